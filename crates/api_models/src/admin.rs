@@ -1795,6 +1795,63 @@ pub struct TransferKeyResponse {
     #[schema(example = 32)]
     pub total_transferred: usize,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantKeyRotationId {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The identifier of the key rotation
+    #[schema(example = "keyrot_JJ2bQr6jgeDhnIt6jYQi")]
+    pub rotation_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MerchantKeyRotationResponse {
+    /// The identifier of the key rotation
+    #[schema(example = "keyrot_JJ2bQr6jgeDhnIt6jYQi")]
+    pub rotation_id: String,
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Version of the key the data is re-encrypted with
+    #[schema(example = 2)]
+    pub key_version: i32,
+    /// Status of the key rotation
+    #[schema(value_type = KeyRotationStatus, example = "in_progress")]
+    pub status: api_enums::KeyRotationStatus,
+    /// The table whose data is being re-encrypted
+    #[schema(value_type = Option<KeyRotationTable>, example = "customers")]
+    pub current_table: Option<api_enums::KeyRotationTable>,
+    /// The tables whose data was completely re-encrypted
+    #[schema(value_type = Vec<KeyRotationTable>)]
+    pub completed_tables: Vec<api_enums::KeyRotationTable>,
+    /// Number of rows re-encrypted so far
+    #[schema(example = 1024)]
+    pub rows_processed: i64,
+    /// The reason the key rotation failed
+    pub error_message: Option<String>,
+    /// Time at which the key rotation was started
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// Time at which the progress of the key rotation was last recorded
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+    /// Time at which the previous key was retired
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MerchantKeyRotationListResponse {
+    /// Number of key rotations returned
+    pub count: usize,
+    /// The key rotations of the merchant, newest first
+    pub data: Vec<MerchantKeyRotationResponse>,
+}
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToggleKVRequest {
     #[serde(skip_deserializing)]
@@ -29,3 +29,137 @@ pub struct CardInfoResponse {
     #[schema(example = "INDIA")]
     pub card_issuing_country: Option<String>,
 }
+
+/// A row of a BIN range file
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, ToSchema)]
+pub struct CardBinRangeRecord {
+    /// Position of the row in the file, filled in while parsing
+    #[serde(skip_deserializing)]
+    pub line_number: Option<usize>,
+    /// First BIN of the range, 6 or 8 digits
+    #[schema(example = "42424200")]
+    pub bin_start: String,
+    /// Last BIN of the range, having as many digits as `bin_start`. A single BIN if absent
+    #[schema(example = "42424299")]
+    pub bin_end: Option<String>,
+    /// The bank that issued the cards of the range
+    #[schema(example = "JP Morgan")]
+    pub issuer: Option<String>,
+    /// The card network of the range
+    #[schema(example = "Visa")]
+    pub network: Option<String>,
+    /// The type of the cards of the range
+    #[serde(rename = "type")]
+    #[schema(example = "CREDIT")]
+    pub card_type: Option<String>,
+    /// The country in which the cards were issued
+    #[schema(example = "UNITEDSTATES")]
+    pub country: Option<String>,
+    /// The card product, such as classic or platinum
+    #[schema(example = "CLASSIC")]
+    pub product: Option<String>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, ToSchema)]
+pub struct CardBinRangeUploadRequest {
+    /// Name of the uploaded file
+    pub file_name: Option<String>,
+    /// The source of the data, stored as the last updated provider of the ranges
+    pub provider: Option<String>,
+    /// The ranges to be created or updated
+    pub records: Vec<CardBinRangeRecord>,
+}
+
+#[derive(serde::Serialize, Debug, ToSchema)]
+pub struct CardBinRangeUploadError {
+    /// Position of the row in the file
+    pub line_number: Option<usize>,
+    /// First BIN of the range
+    pub bin_start: String,
+    /// The reason the row was rejected
+    pub reason: String,
+}
+
+#[derive(serde::Serialize, Debug, ToSchema)]
+pub struct CardBinRangeUploadResponse {
+    /// Name of the uploaded file
+    pub file_name: Option<String>,
+    /// Whether the ranges were written. Nothing is written when any of the rows is invalid
+    pub applied: bool,
+    /// Number of rows in the file
+    pub total_count: usize,
+    /// Number of ranges that did not exist yet
+    pub created_count: usize,
+    /// Number of existing ranges whose details changed
+    pub updated_count: usize,
+    /// Number of existing ranges whose details did not change
+    pub unchanged_count: usize,
+    /// The rows that failed validation
+    pub errors: Vec<CardBinRangeUploadError>,
+}
+
+#[derive(serde::Serialize, Debug, ToSchema)]
+pub struct CardBinRangeResponse {
+    /// First BIN of the range
+    #[schema(example = "42424200")]
+    pub bin_start: String,
+    /// Last BIN of the range
+    #[schema(example = "42424299")]
+    pub bin_end: String,
+    /// The bank that issued the cards of the range
+    pub card_issuer: Option<String>,
+    /// The card network of the range
+    pub card_network: Option<String>,
+    /// The type of the cards of the range
+    pub card_type: Option<String>,
+    /// The card product, such as classic or platinum
+    pub card_sub_type: Option<String>,
+    /// The country in which the cards were issued
+    pub card_issuing_country: Option<String>,
+    /// The source of the data
+    pub last_updated_provider: Option<String>,
+    /// Time at which the range was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// Time at which the range was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, ToSchema)]
+pub struct CardBinRangeListRequest {
+    /// Only list the ranges of 6 or 8 digit BINs
+    pub bin_length: Option<i32>,
+    /// Maximum number of ranges to be returned
+    pub limit: Option<i64>,
+    /// Number of ranges to be skipped
+    pub offset: Option<i64>,
+}
+
+#[derive(serde::Serialize, Debug, ToSchema)]
+pub struct CardBinRangeListResponse {
+    /// Number of ranges returned
+    pub count: usize,
+    /// The ranges, ordered by BIN
+    pub data: Vec<CardBinRangeResponse>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, ToSchema)]
+pub struct CardBinRangeDeleteRequest {
+    /// First BIN of the range
+    pub bin_start: String,
+    /// Last BIN of the range
+    pub bin_end: String,
+}
+
+#[derive(serde::Serialize, Debug, ToSchema)]
+pub struct CardBinRangeDeleteResponse {
+    /// First BIN of the range
+    pub bin_start: String,
+    /// Last BIN of the range
+    pub bin_end: String,
+    /// Whether the range was deleted
+    pub deleted: bool,
+}
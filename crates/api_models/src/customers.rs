@@ -10,7 +10,7 @@ use masking::{ExposeInterface, Secret, SwitchStrategy};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::payments;
+use crate::{enums, payments};
 
 /// The customer details
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -444,6 +444,99 @@ impl CustomerUpdateRequest {
     }
 }
 
+/// Request to export or erase all the data held about a customer
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataSubjectRequestCreateRequest {
+    /// The identifier of the customer the request is about. Either this or `email` is required.
+    #[schema(value_type = Option<String>, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// The email address of the customer the request is about, used when the customer
+    /// identifier is not known. The customer is looked up when the request is processed.
+    #[schema(value_type = Option<String>, max_length = 255, example = "JonTest@test.com")]
+    pub email: Option<pii::Email>,
+    /// Whether the data held about the customer must be exported or erased
+    #[schema(value_type = DataSubjectRequestType)]
+    pub request_type: enums::DataSubjectRequestType,
+    /// Reason recorded along with the request for audit purposes, such as a support ticket
+    /// reference
+    #[schema(max_length = 255, example = "Ticket #4521")]
+    pub reason: Option<String>,
+}
+
+/// Number of records of each kind which were exported or erased
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct DataSubjectRequestSummary {
+    /// Number of addresses
+    pub addresses: usize,
+    /// Number of saved payment methods
+    pub payment_methods: usize,
+    /// Number of mandates
+    pub mandates: usize,
+    /// Number of payments
+    pub payments: usize,
+    /// Number of refunds
+    pub refunds: usize,
+    /// Number of disputes
+    pub disputes: usize,
+    /// Number of webhook events
+    pub events: usize,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DataSubjectRequestResponse {
+    /// The identifier for the data subject request
+    pub request_id: String,
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The identifier of the customer the request is about. For requests made by email, it is
+    /// set once the customer has been identified while processing the request.
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// Whether the data is exported or erased
+    #[schema(value_type = DataSubjectRequestType)]
+    pub request_type: enums::DataSubjectRequestType,
+    /// The status of the request
+    #[schema(value_type = DataSubjectRequestStatus)]
+    pub status: enums::DataSubjectRequestStatus,
+    /// Reason recorded along with the request
+    pub reason: Option<String>,
+    /// Number of records exported or erased, available once the request is completed
+    pub summary: Option<DataSubjectRequestSummary>,
+    /// Whether the export archive can be downloaded. The archive is removed once the data of
+    /// the customer is erased.
+    pub archive_available: bool,
+    /// The reason the request could not be completed
+    pub error_message: Option<String>,
+    /// The timestamp at which the request was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// The timestamp at which the request was last updated
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+    /// The timestamp at which the request was completed or failed
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataSubjectRequestListRequest {
+    /// Only list the requests made about this customer
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DataSubjectRequestId {
+    pub request_id: String,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
 pub struct UpdateCustomerId(String);
 
@@ -0,0 +1,127 @@
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataRetentionPolicyRequest {
+    /// The table the policy applies to
+    #[schema(value_type = DataRetentionTable, example = "events")]
+    pub table_name: enums::DataRetentionTable,
+    /// Rows older than this many days are archived and removed from the table
+    #[schema(example = 180)]
+    pub retention_days: u16,
+    /// Number of rows written to a single archive file
+    #[schema(example = 1000)]
+    pub batch_size: Option<u16>,
+    /// Whether the policy is applied by the scheduler, defaults to true
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct DataRetentionPolicyResponse {
+    /// The table the policy applies to
+    #[schema(value_type = DataRetentionTable, example = "events")]
+    pub table_name: enums::DataRetentionTable,
+    /// Rows older than this many days are archived and removed from the table
+    pub retention_days: i32,
+    /// Number of rows written to a single archive file
+    pub batch_size: i32,
+    /// Whether the policy is applied by the scheduler
+    pub is_enabled: bool,
+    /// Time at which the scheduler last applied the policy
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_run_at: Option<PrimitiveDateTime>,
+    /// Time at which the policy was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Time at which the policy was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct DataRetentionTableName {
+    /// The table the policy applies to
+    #[schema(value_type = DataRetentionTable)]
+    pub table_name: enums::DataRetentionTable,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct DataRetentionPolicyDeleteResponse {
+    /// The table the policy applied to
+    #[schema(value_type = DataRetentionTable)]
+    pub table_name: enums::DataRetentionTable,
+    /// Whether the policy was deleted
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct DataRetentionArchiveListRequest {
+    /// Only list the archives of this table
+    #[schema(value_type = Option<DataRetentionTable>)]
+    pub table_name: Option<enums::DataRetentionTable>,
+    /// Maximum number of archives to be returned
+    pub limit: Option<i64>,
+    /// Number of archives to be skipped
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct DataRetentionArchiveResponse {
+    /// The identifier of the archive
+    #[schema(example = "archive_JJ2bQr6jgeDhnIt6jYQi")]
+    pub archive_id: String,
+    /// The table the rows were removed from
+    #[schema(value_type = DataRetentionTable)]
+    pub table_name: enums::DataRetentionTable,
+    /// Number of rows in the archive
+    pub row_count: i32,
+    /// Creation time of the oldest row in the archive
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub oldest_record_created_at: PrimitiveDateTime,
+    /// Creation time of the newest row in the archive
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub newest_record_created_at: PrimitiveDateTime,
+    /// Time at which the archive was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Time at which the rows of the archive were last restored
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub restored_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct DataRetentionArchiveListResponse {
+    /// Number of archives returned
+    pub count: usize,
+    /// The archives, newest first
+    pub data: Vec<DataRetentionArchiveResponse>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct DataRetentionArchiveId {
+    /// The identifier of the archive
+    pub archive_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct DataRetentionArchiveRestoreResponse {
+    /// The identifier of the archive
+    pub archive_id: String,
+    /// The table the rows were restored to
+    #[schema(value_type = DataRetentionTable)]
+    pub table_name: enums::DataRetentionTable,
+    /// Number of rows in the archive
+    pub row_count: i32,
+    /// Number of rows inserted into the table, rows which are already present are skipped
+    pub restored_count: usize,
+}
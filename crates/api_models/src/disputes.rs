@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use masking::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
@@ -204,3 +205,36 @@ pub struct DeleteEvidenceRequest {
     /// Evidence Type to be deleted
     pub evidence_type: EvidenceType,
 }
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeEvidenceDraftResponse {
+    /// The identifier for dispute
+    pub dispute_id: String,
+    /// Evidence pre-populated from the stored payment, customer and refund data, to be reviewed
+    /// and submitted through the submit evidence draft endpoint
+    pub evidence: SubmitEvidenceRequest,
+}
+
+/// Policy applied by the scheduler to disputes with an evidence submission deadline
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeDeadlinePolicy {
+    /// Hours before the evidence deadline at which reminders are sent, eg: `[72, 24]`
+    #[schema(example = json!([72, 24]))]
+    pub reminder_offsets_in_hours: Vec<u16>,
+    /// Open disputes with an amount less than or equal to this value (in minor units of the
+    /// dispute currency) are accepted automatically once their evidence deadline lapses
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub auto_accept_below_amount: Option<MinorUnit>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeDeadlineListConstraints {
+    /// Only disputes with an evidence deadline within these many hours from now are returned
+    pub within_hours: Option<u16>,
+    /// The identifier for business profile
+    pub profile_id: Option<String>,
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+}
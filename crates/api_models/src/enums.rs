@@ -131,7 +131,7 @@ pub enum Connector {
     Square,
     Stax,
     Stripe,
-    // Taxjar,
+    Taxjar,
     Threedsecureio,
     Trustpay,
     Tsys,
@@ -144,6 +144,7 @@ pub enum Connector {
     Signifyd,
     Plaid,
     Riskified,
+    NativeFrm,
     Zen,
     Zsl,
 }
@@ -197,6 +198,11 @@ impl Connector {
     pub fn requires_defend_dispute(&self) -> bool {
         matches!(self, Self::Checkout)
     }
+    /// Whether the SCA exemption of a payment is sent to the connector along with the
+    /// authorization
+    pub fn supports_sca_exemption(&self) -> bool {
+        matches!(self, Self::Adyen)
+    }
     pub fn is_separate_authentication_supported(&self) -> bool {
         match self {
             #[cfg(feature = "dummy_connector")]
@@ -209,7 +215,6 @@ impl Connector {
             | Self::DummyConnector7 => false,
             Self::Aci
             // Add Separate authentication support for connectors
-			// | Self::Taxjar
 			// | Self::Fiservemea
             | Self::Adyen
             | Self::Adyenplatform
@@ -256,6 +261,7 @@ impl Connector {
             | Self::Shift4
             | Self::Square
             | Self::Stax
+            | Self::Taxjar
             | Self::Trustpay
             | Self::Tsys
             | Self::Volt
@@ -270,6 +276,7 @@ impl Connector {
             | Self::Plaid
             | Self::Razorpay
             | Self::Riskified
+            | Self::NativeFrm
             | Self::Threedsecureio
             | Self::Datatrans
             | Self::Netcetera
@@ -405,6 +412,8 @@ pub enum FrmConnectors {
     /// Signifyd Risk Manager. Official docs: https://docs.signifyd.com/
     Signifyd,
     Riskified,
+    /// Built-in velocity and rules based fraud engine evaluated within the router
+    NativeFrm,
 }
 
 #[derive(
@@ -648,6 +657,28 @@ pub fn convert_authentication_connector(connector_name: &str) -> Option<Authenti
     AuthenticationConnectors::from_str(connector_name).ok()
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TaxConnectors {
+    Taxjar,
+}
+
+pub fn convert_tax_connector(connector_name: &str) -> Option<TaxConnectors> {
+    TaxConnectors::from_str(connector_name).ok()
+}
+
 #[derive(
     Clone,
     Debug,
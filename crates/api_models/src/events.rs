@@ -1,8 +1,11 @@
 pub mod apple_pay_certificates_migration;
 pub mod connector_onboarding;
 pub mod customer;
+pub mod data_retention;
 pub mod dispute;
 pub mod gsm;
+pub mod invoices;
+pub mod ledger;
 mod locker_migration;
 pub mod payment;
 #[cfg(feature = "payouts")]
@@ -10,9 +13,12 @@ pub mod payouts;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refund;
+pub mod reusable_payment_link;
 pub mod routing;
+pub mod scim;
 pub mod user;
 pub mod user_role;
+pub mod webhook_signing_key;
 
 use common_utils::{
     events::{ApiEventMetric, ApiEventsType},
@@ -73,6 +79,8 @@ impl_api_event_type!(
         MandateRevokedResponse,
         RetrievePaymentLinkRequest,
         PaymentLinkListConstraints,
+        PaymentLinkQrCodeRequest,
+        PaymentLinkInvoiceRequest,
         MandateId,
         DisputeListConstraints,
         RetrieveApiKeyResponse,
@@ -87,6 +95,12 @@ impl_api_event_type!(
         MerchantAccountDeleteResponse,
         MerchantAccountUpdate,
         CardInfoResponse,
+        CardBinRangeUploadRequest,
+        CardBinRangeUploadResponse,
+        CardBinRangeListRequest,
+        CardBinRangeListResponse,
+        CardBinRangeDeleteRequest,
+        CardBinRangeDeleteResponse,
         CreateApiKeyResponse,
         CreateApiKeyRequest,
         MerchantConnectorDeleteResponse,
@@ -141,7 +155,10 @@ impl_api_event_type!(
         TransferKeyResponse,
         MerchantKeyTransferRequest,
         UserKeyTransferRequest,
-        UserTransferKeyResponse
+        UserTransferKeyResponse,
+        MerchantKeyRotationId,
+        MerchantKeyRotationResponse,
+        MerchantKeyRotationListResponse
     )
 );
 
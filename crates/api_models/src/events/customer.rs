@@ -2,6 +2,8 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::customers::{
     CustomerDeleteResponse, CustomerId, CustomerRequest, CustomerResponse, CustomerUpdateRequest,
+    DataSubjectRequestCreateRequest, DataSubjectRequestId, DataSubjectRequestListRequest,
+    DataSubjectRequestResponse,
 };
 
 impl ApiEventMetric for CustomerDeleteResponse {
@@ -43,4 +45,34 @@ impl ApiEventMetric for CustomerUpdateRequest {
             .map(|cid| ApiEventsType::Customer { customer_id: cid })
     }
 }
+
+impl ApiEventMetric for DataSubjectRequestCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.customer_id
+            .clone()
+            .map(|customer_id| ApiEventsType::Customer { customer_id })
+    }
+}
+
+impl ApiEventMetric for DataSubjectRequestResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataSubjectRequest {
+            request_id: self.request_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for DataSubjectRequestId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataSubjectRequest {
+            request_id: self.request_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for DataSubjectRequestListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
 // These needs to be fixed for v2
@@ -0,0 +1,51 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::data_retention;
+
+impl ApiEventMetric for data_retention::DataRetentionPolicyRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataRetention)
+    }
+}
+
+impl ApiEventMetric for data_retention::DataRetentionPolicyResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataRetention)
+    }
+}
+
+impl ApiEventMetric for data_retention::DataRetentionTableName {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataRetention)
+    }
+}
+
+impl ApiEventMetric for data_retention::DataRetentionPolicyDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataRetention)
+    }
+}
+
+impl ApiEventMetric for data_retention::DataRetentionArchiveListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataRetention)
+    }
+}
+
+impl ApiEventMetric for data_retention::DataRetentionArchiveListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataRetention)
+    }
+}
+
+impl ApiEventMetric for data_retention::DataRetentionArchiveId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataRetention)
+    }
+}
+
+impl ApiEventMetric for data_retention::DataRetentionArchiveRestoreResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::DataRetention)
+    }
+}
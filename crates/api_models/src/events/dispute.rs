@@ -1,7 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve, SubmitEvidenceRequest,
+    DeleteEvidenceRequest, DisputeDeadlineListConstraints, DisputeDeadlinePolicy,
+    DisputeEvidenceDraftResponse, DisputeResponse, DisputeResponsePaymentsRetrieve,
+    SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -32,3 +34,20 @@ impl ApiEventMetric for DeleteEvidenceRequest {
         })
     }
 }
+impl ApiEventMetric for DisputeEvidenceDraftResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.dispute_id.clone(),
+        })
+    }
+}
+impl ApiEventMetric for DisputeDeadlinePolicy {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+impl ApiEventMetric for DisputeDeadlineListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -37,3 +37,51 @@ impl ApiEventMetric for gsm::GsmResponse {
         Some(ApiEventsType::Gsm)
     }
 }
+
+impl ApiEventMetric for gsm::GsmBulkImportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmBulkImportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmExportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmHistoryListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmHistoryListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmRevertRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmRevertResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
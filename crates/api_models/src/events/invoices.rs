@@ -0,0 +1,69 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::invoices::{
+    InvoiceCreateRequest, InvoiceId, InvoiceListConstraints, InvoiceListResponse,
+    InvoiceNumberSequenceRequest, InvoiceNumberSequenceResponse, InvoiceRecordPaymentRequest,
+    InvoiceResponse, InvoiceUpdateRequest,
+};
+
+impl ApiEventMetric for InvoiceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Invoice {
+            invoice_id: self.invoice_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for InvoiceId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Invoice {
+            invoice_id: self.invoice_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for InvoiceUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Invoice {
+            invoice_id: self.invoice_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for InvoiceRecordPaymentRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Invoice {
+            invoice_id: self.invoice_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for InvoiceCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for InvoiceNumberSequenceRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for InvoiceNumberSequenceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for InvoiceListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for InvoiceListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
@@ -23,7 +23,7 @@ use crate::{
         PaymentsExternalAuthenticationRequest, PaymentsExternalAuthenticationResponse,
         PaymentsIncrementalAuthorizationRequest, PaymentsManualUpdateRequest,
         PaymentsRejectRequest, PaymentsRequest, PaymentsResponse, PaymentsRetrieveRequest,
-        PaymentsSessionResponse, PaymentsStartRequest, RedirectionResponse,
+        PaymentsSessionResponse, PaymentsStartRequest, RedirectionResponse, ScaExemptionConfig,
     },
 };
 impl ApiEventMetric for PaymentsRetrieveRequest {
@@ -263,3 +263,9 @@ impl ApiEventMetric for PaymentsSessionResponse {
         })
     }
 }
+
+impl ApiEventMetric for ScaExemptionConfig {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -1,9 +1,11 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::payouts::{
-    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
-    PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
-    PayoutRetrieveRequest,
+    PayoutActionRequest, PayoutBatchApproveRequest, PayoutBatchCreateRequest,
+    PayoutBatchListRequest, PayoutBatchRejectRequest, PayoutBatchResponse,
+    PayoutBatchRetrieveRequest, PayoutCreateRequest, PayoutCreateResponse,
+    PayoutLinkInitiateRequest, PayoutListConstraints, PayoutListFilterConstraints,
+    PayoutListFilters, PayoutListResponse, PayoutRetrieveRequest,
 };
 
 impl ApiEventMetric for PayoutRetrieveRequest {
@@ -69,3 +71,47 @@ impl ApiEventMetric for PayoutLinkInitiateRequest {
         })
     }
 }
+
+impl ApiEventMetric for PayoutBatchCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutBatchApproveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchRejectRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PayoutBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
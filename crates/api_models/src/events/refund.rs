@@ -1,9 +1,10 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::refunds::{
-    RefundListFilters, RefundListMetaData, RefundListRequest, RefundListResponse,
-    RefundManualUpdateRequest, RefundRequest, RefundResponse, RefundUpdateRequest,
-    RefundsRetrieveRequest,
+    RefundBatchCreateRequest, RefundBatchListRequest, RefundBatchResponse,
+    RefundBatchRetrieveRequest, RefundListFilters, RefundListMetaData, RefundListRequest,
+    RefundListResponse, RefundManualUpdateRequest, RefundRequest, RefundResponse,
+    RefundUpdateRequest, RefundsRetrieveRequest,
 };
 
 impl ApiEventMetric for RefundRequest {
@@ -77,3 +78,31 @@ impl ApiEventMetric for RefundListFilters {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+impl ApiEventMetric for RefundBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::RefundBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for RefundBatchRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::RefundBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for RefundBatchListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for RefundBatchCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
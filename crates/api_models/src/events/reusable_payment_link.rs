@@ -0,0 +1,65 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::reusable_payment_link::{
+    ReusablePaymentLinkCreateRequest, ReusablePaymentLinkId, ReusablePaymentLinkListConstraints,
+    ReusablePaymentLinkListResponse, ReusablePaymentLinkResponse, ReusablePaymentLinkSalesSummary,
+    ReusablePaymentLinkUpdateRequest, ReusablePaymentLinkVisitRequest,
+};
+
+impl ApiEventMetric for ReusablePaymentLinkResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ReusablePaymentLink {
+            reusable_link_id: self.reusable_link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ReusablePaymentLink {
+            reusable_link_id: self.reusable_link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkVisitRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ReusablePaymentLink {
+            reusable_link_id: self.reusable_link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkSalesSummary {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ReusablePaymentLink {
+            reusable_link_id: self.reusable_link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ReusablePaymentLink {
+            reusable_link_id: self.reusable_link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for ReusablePaymentLinkListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
@@ -0,0 +1,31 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::scim::{
+    ScimGroupResponse, ScimListQuery, ScimListResponse, ScimPatchRequest, ScimTokenResponse,
+    ScimUserRequest, ScimUserResponse,
+};
+
+impl ApiEventMetric for ScimUserResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::User {
+            user_id: self.id.clone(),
+        })
+    }
+}
+
+impl<T> ApiEventMetric for ScimListResponse<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+common_utils::impl_api_event_type!(
+    Miscellaneous,
+    (
+        ScimUserRequest,
+        ScimGroupResponse,
+        ScimListQuery,
+        ScimPatchRequest,
+        ScimTokenResponse
+    )
+);
@@ -0,0 +1,39 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::webhook_signing_key;
+
+impl ApiEventMetric for webhook_signing_key::WebhookSigningKeyCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookSigningKey)
+    }
+}
+
+impl ApiEventMetric for webhook_signing_key::WebhookSigningKeyResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookSigningKey)
+    }
+}
+
+impl ApiEventMetric for webhook_signing_key::WebhookSigningKeyListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookSigningKey)
+    }
+}
+
+impl ApiEventMetric for webhook_signing_key::Jwks {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookSigningKey)
+    }
+}
+
+impl ApiEventMetric for webhook_signing_key::WebhookSigningKeyProfileId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookSigningKey)
+    }
+}
+
+impl ApiEventMetric for webhook_signing_key::WebhookSigningKeyId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::WebhookSigningKey)
+    }
+}
@@ -0,0 +1,183 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use euclid::frontend::ast::ComparisonType;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::FraudCheckStatus;
+
+/// Outcome of a native fraud rule. Variants are ordered by severity, so that the most severe
+/// decision among all the matched rules wins.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmRuleDecision {
+    Accept,
+    Review,
+    Decline,
+}
+
+/// The attribute of a payment on which velocity counters are maintained
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, ToSchema, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmVelocityDimension {
+    CardFingerprint,
+    Email,
+    IpAddress,
+    Customer,
+    Device,
+}
+
+/// The left hand side of a fraud rule condition
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "field")]
+pub enum FrmRuleLhs {
+    /// Amount of the payment in minor units
+    Amount,
+    /// Currency of the payment, eg: "USD"
+    Currency,
+    /// Payment method used for the payment, eg: "card"
+    PaymentMethod,
+    /// Country of the billing address, eg: "US"
+    BillingCountry,
+    /// Country of the shipping address, eg: "US"
+    ShippingCountry,
+    /// Number of payments seen for the same dimension within the window, including this one
+    Velocity {
+        dimension: FrmVelocityDimension,
+        /// Length of the fixed counting window, at most 30 days
+        window_in_seconds: i64,
+    },
+}
+
+impl FrmRuleLhs {
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Self::Amount | Self::Velocity { .. })
+    }
+}
+
+/// The value a fraud rule condition is compared against
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum FrmRuleValue {
+    Number(i64),
+    String(String),
+}
+
+/// A single comparison, eg: `amount > 10000` or `velocity(email, 3600) >= 5`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FrmRuleCondition {
+    #[serde(flatten)]
+    pub lhs: FrmRuleLhs,
+    pub comparison: ComparisonType,
+    pub value: FrmRuleValue,
+}
+
+/// A rule matches when all of its conditions hold
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FrmRule {
+    /// Name of the rule, returned as part of the fraud check reason
+    #[schema(example = "high_value_first_purchase")]
+    pub name: String,
+    pub conditions: Vec<FrmRuleCondition>,
+    pub decision: FrmRuleDecision,
+    /// Human readable reason recorded against the fraud check when the rule matches
+    pub reason: String,
+    /// Risk score contributed by the rule when it matches
+    pub score: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct FrmRulesRequest {
+    pub rules: Vec<FrmRule>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct FrmRulesRecord {
+    pub rules: Vec<FrmRule>,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+/// A rule which matched during evaluation of the native fraud engine
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct FrmMatchedRule {
+    pub name: String,
+    pub decision: FrmRuleDecision,
+    pub reason: String,
+}
+
+/// Reason recorded against a fraud check decided by the native fraud engine
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct NativeFrmReason {
+    pub decision: FrmRuleDecision,
+    pub matched_rules: Vec<FrmMatchedRule>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema)]
+pub struct FrmReviewQueueConstraints {
+    /// Limit on the number of fraud checks to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of fraud checks
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct FrmReviewQueueItem {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub frm_name: String,
+    #[schema(value_type = String, example = "ManualReview")]
+    pub frm_status: FraudCheckStatus,
+    pub frm_score: Option<i32>,
+    pub frm_reason: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct FrmReviewQueueResponse {
+    pub count: usize,
+    pub data: Vec<FrmReviewQueueItem>,
+}
+
+impl ApiEventMetric for FrmRulesRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+impl ApiEventMetric for FrmRulesRecord {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+impl ApiEventMetric for FrmReviewQueueConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+impl ApiEventMetric for FrmReviewQueueResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
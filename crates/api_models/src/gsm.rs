@@ -1,3 +1,4 @@
+use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
 use crate::enums::Connector;
@@ -142,3 +143,223 @@ pub struct GsmResponse {
     /// error message unified across the connectors
     pub unified_message: Option<String>,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmBulkImportRequest {
+    /// The rules to be created or updated, keyed by connector, flow, sub_flow, code and message
+    pub rules: Vec<GsmCreateRequest>,
+    /// Validate the rules and report the changes that would be made without applying them
+    #[serde(default)]
+    pub dry_run: bool,
+    /// A note stored along with the change in the GSM rule history
+    pub description: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmBulkImportResponse {
+    /// Whether the import was a dry run
+    pub dry_run: bool,
+    /// Whether the changes were written. Nothing is written when any of the rules is invalid
+    pub applied: bool,
+    /// The identifier of the change in the GSM rule history, present when changes were written
+    pub change_id: Option<String>,
+    /// Number of rules received
+    pub total_count: usize,
+    /// Number of rules that do not exist yet
+    pub created_count: usize,
+    /// Number of existing rules whose attributes differ from the received ones
+    pub updated_count: usize,
+    /// Number of existing rules that are identical to the received ones
+    pub unchanged_count: usize,
+    /// The rules that failed validation
+    pub errors: Vec<GsmImportError>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmImportError {
+    /// Position of the rule in the request, starting from 1. For CSV files this is the data row
+    pub row_number: usize,
+    /// The connector of the rule
+    pub connector: String,
+    /// The flow of the rule
+    pub flow: String,
+    /// The sub_flow of the rule
+    pub sub_flow: String,
+    /// The code of the rule
+    pub code: String,
+    /// The reason the rule was rejected
+    pub reason: String,
+}
+
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmExportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmExportRequest {
+    /// Only export the rules of this connector
+    pub connector: Option<String>,
+    /// Only export the rules of this flow
+    pub flow: Option<String>,
+    /// Format of the exported rules. CSV exports can be imported back as they are
+    #[serde(default)]
+    pub format: GsmExportFormat,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmExportResponse {
+    /// Number of rules exported
+    pub total_count: usize,
+    /// The exported rules
+    pub rules: Vec<GsmResponse>,
+}
+
+/// The operation that created an entry in the GSM rule history
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmChangeSource {
+    Create,
+    Update,
+    Delete,
+    Import,
+    Revert,
+}
+
+/// The effect of a change on a single GSM rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmChangeAction {
+    Created,
+    Updated,
+    Deleted,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmHistoryListRequest {
+    /// Only list the changes of this connector
+    pub connector: Option<String>,
+    /// Only list the changes of this flow
+    pub flow: Option<String>,
+    /// Only list the entries of this change
+    pub change_id: Option<String>,
+    /// Maximum number of entries to be returned
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmRuleAttributes {
+    /// status provided by the router
+    pub status: String,
+    /// optional error provided by the router
+    pub router_error: Option<String>,
+    /// decision to be taken for auto retries flow
+    pub decision: String,
+    /// indicates if step_up retry is possible
+    pub step_up_possible: bool,
+    /// error code unified across the connectors
+    pub unified_code: Option<String>,
+    /// error message unified across the connectors
+    pub unified_message: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmHistoryResponse {
+    /// The identifier shared by all the entries written by the same operation
+    pub change_id: String,
+    /// Position of the entry within the change
+    pub sequence_number: i32,
+    /// The operation that made the change
+    #[schema(value_type = String)]
+    pub source: String,
+    /// The effect of the change on the rule
+    #[schema(value_type = String)]
+    pub action: String,
+    /// The connector through which payment has gone through
+    pub connector: String,
+    /// The flow in which the code and message occurred for a connector
+    pub flow: String,
+    /// The sub_flow in which the code and message occurred  for a connector
+    pub sub_flow: String,
+    /// code received from the connector
+    pub code: String,
+    /// message received from the connector
+    pub message: String,
+    /// The attributes of the rule before the change, absent if the rule was created
+    pub previous_rule: Option<GsmRuleAttributes>,
+    /// The attributes of the rule after the change, absent if the rule was deleted
+    pub current_rule: Option<GsmRuleAttributes>,
+    /// A note stored along with the change
+    pub description: Option<String>,
+    /// Time at which the change was made
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmHistoryListResponse {
+    /// Number of entries returned
+    pub count: usize,
+    /// The history entries, latest change first
+    pub data: Vec<GsmHistoryResponse>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmRevertRequest {
+    /// The change to be reverted
+    #[serde(skip_deserializing)]
+    pub change_id: String,
+    /// A note stored along with the revert in the GSM rule history
+    pub description: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmRevertResponse {
+    /// The change that was reverted
+    pub reverted_change_id: String,
+    /// The identifier of the revert in the GSM rule history, absent if no rule had to be changed
+    pub change_id: Option<String>,
+    /// Number of rules restored to the state they had before the reverted change
+    pub restored_count: usize,
+}
@@ -0,0 +1,326 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceCreateRequest {
+    /// The business profile issuing the invoice. Defaults to the default profile of the merchant
+    pub profile_id: Option<String>,
+    /// The customer being invoiced
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The currency of the invoice
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// Description shown on the invoice
+    #[schema(max_length = 255, example = "Consulting services for August")]
+    pub description: Option<String>,
+    /// The products or services being invoiced
+    pub line_items: Vec<InvoiceLineItemRequest>,
+    /// Discount applied to the invoice as a whole, after the discounts of the line items
+    pub discount: Option<InvoiceDiscount>,
+    /// Date by which the invoice must be paid
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub due_date: Option<PrimitiveDateTime>,
+    /// Days relative to the due date on which reminders are emailed to the customer, negative
+    /// values being days before the due date. Defaults to three days before, on and seven days
+    /// after the due date
+    #[schema(example = json!([-3, 0, 7]))]
+    pub reminder_schedule: Option<Vec<i16>>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to
+    /// 500 characters long
+    #[schema(value_type = Option<Object>, example = r#"{ "project": "alpha" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceUpdateRequest {
+    /// The identifier of the invoice
+    #[serde(skip_deserializing)]
+    pub invoice_id: String,
+    /// Description shown on the invoice
+    #[schema(max_length = 255)]
+    pub description: Option<String>,
+    /// Replaces the line items of the invoice
+    pub line_items: Option<Vec<InvoiceLineItemRequest>>,
+    /// Discount applied to the invoice as a whole
+    pub discount: Option<InvoiceDiscount>,
+    /// Date by which the invoice must be paid
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub due_date: Option<PrimitiveDateTime>,
+    /// Days relative to the due date on which reminders are emailed to the customer
+    pub reminder_schedule: Option<Vec<i16>>,
+    /// Additional information about the invoice
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceLineItemRequest {
+    /// Description of the product or service
+    #[schema(max_length = 255, example = "Consulting hours")]
+    pub description: String,
+    /// Quantity of the product or service
+    #[serde(default = "default_quantity")]
+    #[schema(default = 1, example = 10)]
+    pub quantity: u16,
+    /// Price of a single unit, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 15000)]
+    pub unit_amount: MinorUnit,
+    /// Discount applied to the line item
+    pub discount: Option<InvoiceDiscount>,
+    /// Taxes applied to the line item, after discounts
+    #[serde(default)]
+    pub tax_rates: Vec<InvoiceTaxRate>,
+}
+
+fn default_quantity() -> u16 {
+    1
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum InvoiceDiscount {
+    /// A fixed amount, in the lowest denomination of the currency
+    Amount {
+        #[schema(value_type = i64, example = 500)]
+        amount: MinorUnit,
+    },
+    /// A percentage, in basis points (`1000` being 10%)
+    Percentage {
+        #[schema(maximum = 10000, example = 1000)]
+        basis_points: u16,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceTaxRate {
+    /// Name of the tax, shown on the invoice
+    #[schema(max_length = 64, example = "VAT")]
+    pub name: String,
+    /// The rate of the tax, in basis points (`1900` being 19%)
+    #[schema(maximum = 10000, example = 1900)]
+    pub basis_points: u16,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct InvoiceTaxLine {
+    /// Name of the tax
+    pub name: String,
+    /// The rate of the tax, in basis points
+    pub basis_points: u16,
+    /// Amount of the line items subject to the tax, after discounts
+    #[schema(value_type = i64)]
+    pub taxable_amount: MinorUnit,
+    /// Amount of the tax
+    #[schema(value_type = i64)]
+    pub amount: MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InvoiceResponse {
+    /// The identifier of the invoice
+    pub invoice_id: String,
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The business profile issuing the invoice
+    pub profile_id: String,
+    /// The customer being invoiced
+    #[schema(value_type = String)]
+    pub customer_id: id_type::CustomerId,
+    /// Number of the invoice, assigned on finalization
+    #[schema(example = "INV-000042")]
+    pub invoice_number: Option<String>,
+    /// The status of the invoice
+    #[schema(value_type = InvoiceStatus)]
+    pub status: enums::InvoiceStatus,
+    /// The currency of the invoice
+    #[schema(value_type = Currency)]
+    pub currency: enums::Currency,
+    /// Description shown on the invoice
+    pub description: Option<String>,
+    /// The products or services being invoiced
+    pub line_items: Vec<InvoiceLineItemResponse>,
+    /// Discount applied to the invoice as a whole
+    pub discount: Option<InvoiceDiscount>,
+    /// Taxes of the invoice, grouped by tax rate
+    pub tax_lines: Vec<InvoiceTaxLine>,
+    /// Sum of the amounts of the line items, before discounts and taxes
+    #[schema(value_type = i64)]
+    pub subtotal: MinorUnit,
+    /// Sum of the discounts of the line items and of the invoice
+    #[schema(value_type = i64)]
+    pub discount_amount: MinorUnit,
+    /// Sum of the taxes of the invoice
+    #[schema(value_type = i64)]
+    pub tax_amount: MinorUnit,
+    /// Amount to be paid by the customer
+    #[schema(value_type = i64)]
+    pub total: MinorUnit,
+    /// Amount paid by the customer so far
+    #[schema(value_type = i64)]
+    pub amount_paid: MinorUnit,
+    /// Amount that is yet to be paid
+    #[schema(value_type = i64)]
+    pub amount_remaining: MinorUnit,
+    /// Date by which the invoice must be paid
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub due_date: Option<PrimitiveDateTime>,
+    /// Days relative to the due date on which reminders are emailed to the customer
+    pub reminder_schedule: Option<Vec<i16>>,
+    /// The payment through which the customer pays the invoice
+    pub payment_id: Option<String>,
+    /// The payment link through which the customer pays the invoice
+    pub payment_link: Option<String>,
+    /// Payments made towards the invoice
+    pub payments: Vec<InvoicePaymentResponse>,
+    /// Additional information about the invoice
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    /// Time at which the invoice was finalized
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub finalized_at: Option<PrimitiveDateTime>,
+    /// Time at which the invoice was paid in full
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub paid_at: Option<PrimitiveDateTime>,
+    /// Time at which the invoice was voided
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub voided_at: Option<PrimitiveDateTime>,
+    /// Time at which the invoice was marked uncollectible
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub marked_uncollectible_at: Option<PrimitiveDateTime>,
+    /// Time at which the invoice was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InvoiceLineItemResponse {
+    /// Identifier of the line item
+    pub line_item_id: String,
+    /// Description of the product or service
+    pub description: String,
+    /// Quantity of the product or service
+    pub quantity: u16,
+    /// Price of a single unit
+    #[schema(value_type = i64)]
+    pub unit_amount: MinorUnit,
+    /// Amount of the line item before discounts and taxes
+    #[schema(value_type = i64)]
+    pub amount: MinorUnit,
+    /// Discount applied to the line item
+    pub discount: Option<InvoiceDiscount>,
+    /// Discount of the line item, including its share of the discount of the invoice
+    #[schema(value_type = i64)]
+    pub discount_amount: MinorUnit,
+    /// Taxes applied to the line item
+    pub tax_rates: Vec<InvoiceTaxRate>,
+    /// Sum of the taxes of the line item
+    #[schema(value_type = i64)]
+    pub tax_amount: MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InvoicePaymentResponse {
+    /// The identifier of the payment
+    pub payment_id: String,
+    /// How the payment was made
+    #[schema(value_type = InvoicePaymentSource)]
+    pub source: enums::InvoicePaymentSource,
+    /// Amount requested by the payment
+    #[schema(value_type = i64)]
+    pub amount: MinorUnit,
+    /// Amount received through the payment, once the payment has succeeded
+    #[schema(value_type = Option<i64>)]
+    pub amount_received: Option<MinorUnit>,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus)]
+    pub status: enums::IntentStatus,
+    /// Reference of the payment, for payments received out of band
+    pub reference: Option<String>,
+    /// Time at which the payment was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceRecordPaymentRequest {
+    /// The identifier of the invoice
+    #[serde(skip_deserializing)]
+    pub invoice_id: String,
+    /// Amount received out of band, such as by bank transfer or cheque, in the lowest
+    /// denomination of the currency
+    #[schema(value_type = i64, example = 5000)]
+    pub amount: MinorUnit,
+    /// Reference of the payment, such as the bank transfer reference
+    #[schema(max_length = 255, example = "TRX-2024-0042")]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct InvoiceListConstraints {
+    /// Only return the invoices of the customer
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// Only return the invoices in the status
+    #[schema(value_type = Option<InvoiceStatus>)]
+    pub status: Option<enums::InvoiceStatus>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InvoiceListResponse {
+    /// The number of invoices included in the list
+    pub count: usize,
+    /// The invoices of the merchant, most recent first
+    pub data: Vec<InvoiceResponse>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct InvoiceId {
+    pub invoice_id: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceNumberSequenceRequest {
+    /// The business profile of the sequence. Defaults to the default profile of the merchant
+    pub profile_id: Option<String>,
+    /// Prefix of the invoice numbers
+    #[schema(max_length = 32, example = "INV-")]
+    pub prefix: Option<String>,
+    /// Number assigned to the next invoice finalized for the profile. Can only move forward, so
+    /// that invoice numbers are never reused
+    #[schema(example = 1000)]
+    pub next_number: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InvoiceNumberSequenceResponse {
+    /// The business profile of the sequence
+    pub profile_id: String,
+    /// Prefix of the invoice numbers
+    pub prefix: String,
+    /// Number assigned to the next invoice finalized for the profile
+    pub next_number: i64,
+}
@@ -0,0 +1,188 @@
+use common_utils::{id_type, types::MinorUnit};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct LedgerBalancesRequest {
+    /// The currency of the ledger
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// Only consider the entries effective until this time. Defaults to the current time
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub as_of: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerBalancesResponse {
+    /// The currency of the ledger
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The time until which entries were considered, if one was requested
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub as_of: Option<PrimitiveDateTime>,
+    /// The balances of the accounts which have postings
+    pub accounts: Vec<LedgerAccountBalance>,
+    /// The sum of the debits of all the accounts, always equal to the sum of their credits
+    #[schema(value_type = i64, example = 6540)]
+    pub total_debits: MinorUnit,
+    /// The sum of the credits of all the accounts
+    #[schema(value_type = i64, example = 6540)]
+    pub total_credits: MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerAccountBalance {
+    /// The account of the ledger
+    #[schema(value_type = LedgerAccount, example = "processor_receivable")]
+    pub account: enums::LedgerAccount,
+    /// The sum of the debits to the account
+    #[schema(value_type = i64, example = 6540)]
+    pub debits: MinorUnit,
+    /// The sum of the credits to the account
+    #[schema(value_type = i64, example = 0)]
+    pub credits: MinorUnit,
+    /// The balance of the account on its normal side, that is debits less credits for assets and
+    /// expenses, and credits less debits for liabilities and revenue
+    #[schema(value_type = i64, example = 6540)]
+    pub balance: MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct LedgerStatementRequest {
+    /// The account of the statement
+    #[serde(skip_deserializing)]
+    #[schema(value_type = LedgerAccount)]
+    pub account: Option<enums::LedgerAccount>,
+    /// The currency of the ledger
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// Start of the period of the statement. Defaults to the first entry of the account
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-01T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_time: Option<PrimitiveDateTime>,
+    /// End of the period of the statement. Defaults to the current time
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-30T23:59:59Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerStatementResponse {
+    /// The account of the statement
+    #[schema(value_type = LedgerAccount, example = "processor_receivable")]
+    pub account: enums::LedgerAccount,
+    /// The currency of the ledger
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The balance of the account at the start of the period
+    #[schema(value_type = i64, example = 0)]
+    pub opening_balance: MinorUnit,
+    /// The balance of the account at the end of the period
+    #[schema(value_type = i64, example = 6540)]
+    pub closing_balance: MinorUnit,
+    /// The postings to the account during the period, oldest first
+    pub lines: Vec<LedgerStatementLine>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerStatementLine {
+    /// The identifier of the journal entry of the posting
+    pub entry_id: String,
+    /// The event recorded by the journal entry
+    #[schema(value_type = LedgerEntryType, example = "capture")]
+    pub entry_type: enums::LedgerEntryType,
+    /// The payment or payout of the journal entry
+    pub reference_id: String,
+    /// Whether the account was debited or credited
+    #[schema(value_type = LedgerPostingDirection, example = "debit")]
+    pub direction: enums::LedgerPostingDirection,
+    /// The amount of the posting
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The balance of the account after the posting
+    #[schema(value_type = i64, example = 6540)]
+    pub balance: MinorUnit,
+    /// Time at which the event recorded by the journal entry took place
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub effective_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct LedgerEntryListConstraints {
+    /// Only return the entries of the payment or payout
+    pub reference_id: Option<String>,
+    /// Only return the entries in the currency
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<enums::Currency>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerEntryListResponse {
+    /// The number of entries included in the list
+    pub count: usize,
+    /// The journal entries of the merchant, most recent first
+    pub data: Vec<LedgerEntryResponse>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerEntryResponse {
+    /// The identifier of the journal entry
+    pub entry_id: String,
+    /// The event recorded by the journal entry
+    #[schema(value_type = LedgerEntryType, example = "capture")]
+    pub entry_type: enums::LedgerEntryType,
+    /// The payment attempt, refund, dispute or payout the journal entry was derived from
+    pub source_id: String,
+    /// The currency of the journal entry
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The payment or payout of the journal entry
+    pub reference_id: String,
+    /// Description of the journal entry
+    pub description: Option<String>,
+    /// The postings of the journal entry, whose debits and credits are equal
+    pub postings: Vec<LedgerPostingResponse>,
+    /// Time at which the event recorded by the journal entry took place
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub effective_at: PrimitiveDateTime,
+    /// Time at which the journal entry was recorded
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerPostingResponse {
+    /// The account of the posting
+    #[schema(value_type = LedgerAccount, example = "processor_receivable")]
+    pub account: enums::LedgerAccount,
+    /// Whether the account was debited or credited
+    #[schema(value_type = LedgerPostingDirection, example = "debit")]
+    pub direction: enums::LedgerPostingDirection,
+    /// The amount of the posting
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LedgerRebuildResponse {
+    /// The merchant whose ledger is rebuilt
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Time at which the rebuild was scheduled. The entries recorded before it are replaced with
+    /// the entries derived from the payments, refunds, disputes and payouts of the merchant, a
+    /// batch of payments or payouts at a time
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub started_at: PrimitiveDateTime,
+}
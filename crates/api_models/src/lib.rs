@@ -8,6 +8,7 @@ pub mod conditional_configs;
 pub mod connector_onboarding;
 pub mod currency;
 pub mod customers;
+pub mod data_retention;
 pub mod disputes;
 pub mod enums;
 pub mod ephemeral_key;
@@ -15,7 +16,11 @@ pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
 pub mod files;
+#[cfg(feature = "frm")]
+pub mod fraud_check;
 pub mod gsm;
+pub mod invoices;
+pub mod ledger;
 pub mod health_check;
 pub mod locker_migration;
 pub mod mandates;
@@ -29,11 +34,14 @@ pub mod poll;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refunds;
+pub mod reusable_payment_link;
 pub mod routing;
+pub mod scim;
 pub mod surcharge_decision_configs;
 pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
 pub mod webhook_events;
+pub mod webhook_signing_key;
 pub mod webhooks;
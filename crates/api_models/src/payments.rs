@@ -506,6 +506,10 @@ pub struct PaymentsRequest {
     #[schema(example = true)]
     pub request_external_three_ds_authentication: Option<bool>,
 
+    /// The SCA exemption requested for the payment. The exemption is applied only if the payment is eligible for it as per the SCA exemption configuration of the merchant
+    #[schema(value_type = Option<ScaExemptionType>, example = "low_value")]
+    pub sca_exemption: Option<api_enums::ScaExemptionType>,
+
     /// Details required for recurring payment
     pub recurring_details: Option<RecurringDetails>,
 
@@ -521,6 +525,12 @@ pub struct PaymentsRequest {
         example = "Custom_Order_id_123"
     )]
     pub merchant_order_reference_id: Option<String>,
+
+    /// Level 2 and Level 3 data of the payment, passed to the connectors which support commercial card payments
+    pub l2_l3_data: Option<L2L3Data>,
+
+    /// Details of how the payment is to be split between the platform and its sub-merchants
+    pub split_payments: Option<SplitPaymentsRequest>,
 }
 
 /// Checks if the inner values of two options are equal
@@ -672,6 +682,139 @@ pub struct PaymentChargeRequest {
     pub transfer_account_id: String,
 }
 
+/// Details of how the payment is to be split between the platform and its sub-merchants
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SplitPaymentsRequest {
+    /// The sub-merchants receiving a share of the payment. The amount remaining after all the
+    /// shares is retained by the platform
+    pub recipients: Vec<SplitPaymentRecipient>,
+
+    /// The account of the recipient bearing the fees charged by the connector. The fees are borne
+    /// by the platform when not provided
+    #[schema(example = "acct_1NUl6FEsaeG6ZHhO")]
+    pub fee_bearer: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SplitPaymentRecipient {
+    /// The account of the recipient at the connector, such as the connected account for Stripe
+    /// or the balance account for Adyen for Platforms
+    #[schema(example = "acct_1NUl6FEsaeG6ZHhO")]
+    pub account_id: String,
+
+    /// The share of the payment allocated to the recipient
+    pub share: SplitShare,
+
+    /// Reference of the share, passed to the connectors which accept it
+    #[schema(max_length = 64, example = "order_1234_seller_1")]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type", content = "value")]
+pub enum SplitShare {
+    /// A fixed amount of the payment
+    Fixed {
+        #[schema(value_type = i64, example = 4000)]
+        amount: MinorUnit,
+    },
+    /// A percentage of the amount of the payment, with up to two decimal places
+    Percentage {
+        #[schema(example = 12.5)]
+        percentage: f32,
+    },
+}
+
+/// How the payment is split between the platform and its sub-merchants
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SplitPaymentsDetails {
+    /// The amounts allocated to the recipients of the payment
+    pub allocations: Vec<SplitPaymentAllocation>,
+
+    /// The amount of the payment retained by the platform
+    #[schema(value_type = i64, example = 1000)]
+    pub platform_amount: MinorUnit,
+
+    /// The account of the recipient bearing the fees charged by the connector
+    #[schema(example = "acct_1NUl6FEsaeG6ZHhO")]
+    pub fee_bearer: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SplitPaymentAllocation {
+    /// The account of the recipient at the connector
+    #[schema(example = "acct_1NUl6FEsaeG6ZHhO")]
+    pub account_id: String,
+
+    /// The amount of the payment allocated to the recipient
+    #[schema(value_type = i64, example = 4000)]
+    pub amount: MinorUnit,
+
+    /// Reference of the share, passed to the connectors which accept it
+    #[schema(example = "order_1234_seller_1")]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SplitRefundAllocation {
+    /// The account of the recipient at the connector
+    #[schema(example = "acct_1NUl6FEsaeG6ZHhO")]
+    pub account_id: String,
+
+    /// The amount reversed from the recipient
+    #[schema(value_type = i64, example = 2000)]
+    pub amount: MinorUnit,
+}
+
+/// Constraints of the report of the split payments of the merchant
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SplitPaymentsReportRequest {
+    /// The time range of the creation of the payments to be reported
+    pub time_range: TimeRange,
+
+    /// Restricts the report to a single recipient
+    #[schema(example = "acct_1NUl6FEsaeG6ZHhO")]
+    pub account_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SplitPaymentsReportResponse {
+    /// The amounts allocated to each recipient across the succeeded and partially captured
+    /// payments, per currency
+    pub recipients: Vec<SplitRecipientReport>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SplitRecipientReport {
+    /// The account of the recipient at the connector
+    #[schema(example = "acct_1NUl6FEsaeG6ZHhO")]
+    pub account_id: String,
+
+    /// The currency of the payments
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The number of payments split with the recipient
+    #[schema(example = 12)]
+    pub payment_count: i64,
+
+    /// The total amount allocated to the recipient, the shares of a partially captured payment
+    /// being reduced in proportion to the amount captured
+    #[schema(value_type = i64, example = 48000)]
+    pub allocated_amount: MinorUnit,
+
+    /// The total amount reversed from the recipient for refunds
+    #[schema(value_type = i64, example = 2000)]
+    pub reversed_amount: MinorUnit,
+
+    /// The amount allocated to the recipient net of the reversals
+    #[schema(value_type = i64, example = 46000)]
+    pub net_amount: MinorUnit,
+}
+
 impl PaymentsRequest {
     pub fn get_total_capturable_amount(&self) -> Option<MinorUnit> {
         let surcharge_amount = self
@@ -912,6 +1055,18 @@ impl PaymentsRequest {
             })
             .transpose()
     }
+
+    pub fn get_l2_l3_data_as_value(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Option<serde_json::Value>,
+        common_utils::errors::ParsingError,
+    > {
+        self.l2_l3_data
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+    }
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
@@ -3883,6 +4038,10 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<Object>, example = r#"{ "fulfillment_method" : "deliver", "coverage_request" : "fraud" }"#)]
     pub frm_metadata: Option<pii::SecretSerdeValue>,
 
+    /// The SCA exemption applied to the payment, if any
+    #[schema(value_type = Option<ScaExemptionType>, example = "low_value")]
+    pub sca_exemption: Option<api_enums::ScaExemptionType>,
+
     /// Merchant's identifier for the payment/invoice. This will be sent to the connector
     /// if the connector provides support to accept multiple reference ids.
     /// In case the connector supports only one reference id, Hyperswitch's Payment ID will be sent as reference.
@@ -3892,6 +4051,12 @@ pub struct PaymentsResponse {
         example = "Custom_Order_id_123"
     )]
     pub merchant_order_reference_id: Option<String>,
+
+    /// The sales tax calculated for the order by the tax connector of the business profile
+    pub tax_details: Option<TaxDetails>,
+
+    /// How the payment is split between the platform and its sub-merchants
+    pub split_payments: Option<SplitPaymentsDetails>,
 }
 
 /// Fee information to be charged on the payment being collected
@@ -4384,6 +4549,141 @@ pub enum ProductType {
     Accommodation,
 }
 
+/// Level 2 and Level 3 data of a commercial card payment. Connectors which support it pass this data
+/// to the card networks, which qualifies the payment for lower interchange rates
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3Data {
+    /// The sales tax included in the amount of the payment
+    #[schema(value_type = Option<i64>, example = 650)]
+    pub tax_amount: Option<MinorUnit>,
+    /// Whether the purchase is exempt from the sales tax
+    #[schema(example = false)]
+    pub tax_exempt: Option<bool>,
+    /// The reference of the payment given by the customer, printed on their card statement
+    #[schema(max_length = 17, example = "CUSTREF123")]
+    pub customer_reference: Option<String>,
+    /// The purchase order number of the customer
+    #[schema(max_length = 25, example = "PO-2024-0042")]
+    pub purchase_order_number: Option<String>,
+    /// The duty charged on the order
+    #[schema(value_type = Option<i64>, example = 0)]
+    pub duty_amount: Option<MinorUnit>,
+    /// The freight or shipping charged on the order
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub freight_amount: Option<MinorUnit>,
+    /// The discount applied on the order
+    #[schema(value_type = Option<i64>, example = 0)]
+    pub discount_amount: Option<MinorUnit>,
+    /// The line items of the order, passed as Level 3 data
+    pub line_items: Option<Vec<L2L3LineItem>>,
+}
+
+/// A line item of the Level 3 data of a commercial card payment
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3LineItem {
+    /// Name of the product
+    #[schema(max_length = 255, example = "Copy paper")]
+    pub product_name: String,
+    /// The merchant's code of the product
+    #[schema(max_length = 12, example = "CP-500")]
+    pub product_code: Option<String>,
+    /// The commodity code of the product, as per the classification used by the card networks
+    #[schema(max_length = 12, example = "44121600")]
+    pub commodity_code: Option<String>,
+    /// The quantity of the product
+    #[schema(example = 10)]
+    pub quantity: u16,
+    /// The unit of measure of the quantity of the product
+    #[schema(max_length = 12, example = "box")]
+    pub unit_of_measure: Option<String>,
+    /// The price of a unit of the product, excluding the tax
+    #[schema(value_type = i64, example = 2500)]
+    pub unit_amount: MinorUnit,
+    /// The sales tax charged on the line item
+    #[schema(value_type = Option<i64>, example = 162)]
+    pub tax_amount: Option<MinorUnit>,
+    /// The discount applied on the line item
+    #[schema(value_type = Option<i64>, example = 0)]
+    pub discount_amount: Option<MinorUnit>,
+}
+
+impl L2L3LineItem {
+    /// The total amount of the line item, including the tax and excluding the discount
+    pub fn get_total_amount(&self) -> Option<MinorUnit> {
+        self.unit_amount
+            .get_amount_as_i64()
+            .checked_mul(i64::from(self.quantity))
+            .map(MinorUnit::new)
+            .map(|amount| {
+                amount + self.tax_amount.unwrap_or_default()
+                    - self.discount_amount.unwrap_or_default()
+            })
+    }
+}
+
+/// The sales tax calculated for the order lines and the shipping address of the payment
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct TaxDetails {
+    /// The tax connector that calculated the tax
+    #[schema(value_type = TaxConnectors, example = "taxjar")]
+    pub connector: api_enums::TaxConnectors,
+    /// The merchant connector account of the tax connector
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: String,
+    /// The amount of the order on which the tax is calculated
+    #[schema(value_type = i64, example = 6540)]
+    pub taxable_amount: MinorUnit,
+    /// The total tax to be collected on the order
+    #[schema(value_type = i64, example = 475)]
+    pub order_tax_amount: MinorUnit,
+    /// The tax to be collected, split by the jurisdictions levying it
+    pub breakdown: Vec<TaxJurisdictionBreakdown>,
+    /// The reference of the transaction committed to the tax connector once the payment was captured
+    pub committed_transaction_id: Option<String>,
+    /// The tax committed to the tax connector, which is proportional to the captured amount when
+    /// the payment was partially captured
+    #[schema(value_type = Option<i64>, example = 475)]
+    pub committed_tax_amount: Option<MinorUnit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct TaxJurisdictionBreakdown {
+    /// The level of the jurisdiction levying the tax
+    #[schema(example = "state")]
+    pub jurisdiction_type: TaxJurisdictionType,
+    /// The name of the jurisdiction, if returned by the tax connector
+    #[schema(example = "CA")]
+    pub jurisdiction_name: Option<String>,
+    /// The amount on which the jurisdiction levies the tax
+    #[schema(value_type = i64, example = 6540)]
+    pub taxable_amount: MinorUnit,
+    /// The tax to be collected for the jurisdiction
+    #[schema(value_type = i64, example = 392)]
+    pub tax_amount: MinorUnit,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxJurisdictionType {
+    Country,
+    State,
+    County,
+    City,
+    SpecialDistrict,
+}
+
+/// The tax reversed on the tax connector for a refund of the payment
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct TaxReversal {
+    /// The reference of the refund transaction recorded on the tax connector
+    pub connector_transaction_id: String,
+    /// The tax reversed for the refund
+    #[schema(value_type = i64, example = 120)]
+    pub tax_amount: MinorUnit,
+}
+
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct OrderDetails {
     /// Name of the product that is being purchased
@@ -5063,6 +5363,64 @@ pub enum ThreeDsCompletionIndicator {
     NotAvailable,
 }
 
+/// Conditions under which SCA exemptions are applied to the card payments of a merchant
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScaExemptionConfig {
+    /// Currency of the amount thresholds, exemptions are applied only to payments in this currency
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+    /// Low value exemption, not applied when absent
+    pub low_value: Option<LowValueExemptionConfig>,
+    /// Transaction risk analysis exemption, not applied when absent
+    pub transaction_risk_analysis: Option<TransactionRiskAnalysisExemptionConfig>,
+    /// Whether the trusted beneficiary exemption is applied when requested in the payment
+    #[serde(default)]
+    pub trusted_beneficiary: bool,
+    /// Whether merchant initiated payments are flagged as out of scope of SCA
+    #[serde(default)]
+    pub merchant_initiated: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LowValueExemptionConfig {
+    /// Payments with an amount less than or equal to this value (in minor units) are exempted
+    #[schema(value_type = i64, example = 3000)]
+    pub max_amount: MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TransactionRiskAnalysisExemptionConfig {
+    /// Fraud rate of the acquirer in basis points
+    #[schema(example = 5)]
+    pub acquirer_fraud_rate_bps: u16,
+    /// Fraud rates in basis points by card issuer, the higher of the acquirer and the issuer fraud
+    /// rate is used for the payment
+    #[serde(default)]
+    #[schema(example = json!({"chase": 8}))]
+    pub issuer_fraud_rates_bps: HashMap<String, u16>,
+    /// Payments with a fraud score greater than this value are not exempted. Payments without a
+    /// fraud score are never exempted
+    #[schema(example = 30)]
+    pub max_fraud_score: i32,
+    /// Amount thresholds by fraud rate, defaults to the reference fraud rates of PSD2 RTS
+    /// Article 18
+    pub thresholds: Option<Vec<TransactionRiskAnalysisThreshold>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TransactionRiskAnalysisThreshold {
+    /// Highest fraud rate in basis points at which the threshold applies
+    #[schema(example = 13)]
+    pub max_fraud_rate_bps: u16,
+    /// Payments with an amount less than or equal to this value (in minor units) are exempted
+    #[schema(value_type = i64, example = 10000)]
+    pub max_amount: MinorUnit,
+}
+
 /// Device Channel indicating whether request is coming from App or Browser
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema, Eq, PartialEq)]
 pub enum DeviceChannel {
@@ -5337,6 +5695,51 @@ pub struct RetrievePaymentLinkRequest {
     pub client_secret: Option<String>,
 }
 
+/// Image format of the QR code of a payment link
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentLinkQrCodeFormat {
+    #[default]
+    Png,
+    Svg,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentLinkQrCodeRequest {
+    /// The identifier for the payment link
+    #[serde(skip_deserializing)]
+    pub payment_link_id: String,
+    /// Image format of the QR code
+    #[serde(default)]
+    pub format: PaymentLinkQrCodeFormat,
+    /// Minimum width and height of the QR code, in pixels
+    #[schema(example = 256)]
+    pub size: Option<u32>,
+}
+
+/// Document format of the invoice of a payment link
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentLinkInvoiceFormat {
+    #[default]
+    Html,
+    Pdf,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentLinkInvoiceRequest {
+    /// The identifier for the payment link
+    #[serde(skip_deserializing)]
+    pub payment_link_id: String,
+    /// Document format of the invoice
+    #[serde(default)]
+    pub format: PaymentLinkInvoiceFormat,
+    /// Due date printed on the invoice. Defaults to the expiry of the payment link
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub due_date: Option<PrimitiveDateTime>,
+}
+
 #[derive(Clone, Debug, serde::Serialize, PartialEq, ToSchema)]
 pub struct PaymentLinkResponse {
     /// URL for rendering the open payment link
@@ -784,3 +784,140 @@ pub struct PayoutLinkStatusDetails {
     #[serde(flatten)]
     pub ui_config: link_utils::GenericLinkUiConfigFormData,
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The business profile under which the payouts of the batch are created
+    pub profile_id: Option<String>,
+
+    /// The payouts to be created as part of the batch
+    pub items: Vec<PayoutBatchItemRequest>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchItemRequest {
+    /// The identifier of the customer receiving the payout
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The payout amount in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1000)]
+    pub amount: common_utils::types::MinorUnit,
+
+    /// The currency of the payout
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The type of the payout
+    #[schema(value_type = Option<PayoutType>, example = "bank")]
+    pub payout_type: Option<api_enums::PayoutType>,
+
+    /// A token of the payout method saved for the customer. The customer's default payout method is used when not provided
+    #[schema(value_type = Option<String>, example = "token_sxJdmpUnXqmCpOuRrOmR")]
+    pub payout_token: Option<String>,
+
+    /// A description of the payout
+    #[schema(value_type = Option<String>, max_length = 255, example = "Weekly seller settlement")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchApproveRequest {
+    /// The identifier of the payout batch
+    #[serde(skip_deserializing)]
+    pub batch_id: String,
+
+    /// The time at which the payouts of the batch are to be executed. The batch is executed right away when not provided
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+
+    /// A comment recorded along with the approval
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchRejectRequest {
+    /// The identifier of the payout batch
+    #[serde(skip_deserializing)]
+    pub batch_id: String,
+
+    /// The reason for rejecting the batch
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PayoutBatchRetrieveRequest {
+    pub batch_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchListRequest {
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// The identifier of the payout batch
+    pub batch_id: String,
+
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The business profile under which the payouts of the batch are created
+    pub profile_id: Option<String>,
+
+    /// The status of the payout batch
+    #[schema(value_type = PayoutBatchStatus)]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// Number of payouts in the batch
+    pub total_count: i32,
+
+    /// Number of payouts which succeeded
+    pub succeeded_count: i32,
+
+    /// Number of payouts which could not be created or failed
+    pub failed_count: i32,
+
+    /// The user who submitted the batch
+    pub created_by: String,
+
+    /// The user who approved or rejected the batch
+    pub reviewed_by: Option<String>,
+
+    /// The time at which the batch was approved or rejected
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub reviewed_at: Option<PrimitiveDateTime>,
+
+    /// The comment recorded by the reviewer
+    pub review_comment: Option<String>,
+
+    /// The time at which the payouts of the batch are to be executed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+
+    /// The time at which the batch was submitted
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the batch was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::payments::{AmountFilter, TimeRange};
+use super::payments::{AmountFilter, SplitRefundAllocation, TaxReversal, TimeRange};
 use crate::{
     admin::{self, MerchantConnectorInfo},
     enums,
@@ -162,6 +162,10 @@ pub struct RefundResponse {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<ChargeRefunds>)]
     pub charges: Option<ChargeRefunds>,
+    /// The sales tax reversed on the tax connector of the payment for this refund
+    pub tax_reversal: Option<TaxReversal>,
+    /// The amounts reversed from the recipients of the split payment for this refund
+    pub split_refunds: Option<Vec<SplitRefundAllocation>>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -271,3 +275,61 @@ impl From<RefundStatus> for enums::RefundStatus {
         }
     }
 }
+
+/// A single row of a bulk refund CSV upload
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RefundBatchRecord {
+    pub payment_id: String,
+    pub amount: MinorUnit,
+    pub reason: Option<String>,
+    #[serde(skip_deserializing)]
+    pub line_number: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RefundBatchCreateRequest {
+    pub file_name: Option<String>,
+    pub records: Vec<RefundBatchRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RefundBatchResponse {
+    /// The identifier for the refund batch
+    pub batch_id: String,
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The identifier for business profile
+    pub profile_id: Option<String>,
+    /// The status of the refund batch
+    #[schema(value_type = RefundBatchStatus)]
+    pub status: enums::RefundBatchStatus,
+    /// Name of the uploaded file
+    pub file_name: Option<String>,
+    /// Number of rows in the batch
+    pub total_count: i32,
+    /// Number of rows for which a refund was created
+    pub succeeded_count: i32,
+    /// Number of rows for which refund creation failed
+    pub failed_count: i32,
+    /// The timestamp at which the batch was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The timestamp at which the batch was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBatchListRequest {
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RefundBatchRetrieveRequest {
+    pub batch_id: String,
+}
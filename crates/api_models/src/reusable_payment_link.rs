@@ -0,0 +1,254 @@
+use common_utils::{id_type, types::MinorUnit};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::{enums, payments::PaymentCreatePaymentLinkConfig};
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkCreateRequest {
+    /// The business profile of the payments created through the link. Defaults to the default
+    /// profile of the merchant
+    pub profile_id: Option<String>,
+    /// The currency of the payments created through the link
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// Description shown to the customers visiting the link
+    #[schema(max_length = 255, example = "Spring collection")]
+    pub description: Option<String>,
+    /// The products sold through the link
+    #[serde(default)]
+    pub line_items: Vec<ReusablePaymentLinkLineItem>,
+    /// Allows the customers to choose an amount, such as a donation, which is added to the
+    /// amount of the line items
+    pub custom_amount: Option<ReusablePaymentLinkCustomAmount>,
+    /// Maximum number of payments that can be created through the link. Payments that fail or
+    /// are abandoned do not count towards the limit
+    #[schema(example = 100)]
+    pub max_uses: Option<u32>,
+    /// Time after which the link can no longer be used
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    /// The payment link configuration of the payments created through the link
+    #[schema(value_type = Option<PaymentCreatePaymentLinkConfig>)]
+    pub payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkLineItem {
+    /// Identifier of the product, unique within the link. Generated if not provided
+    #[schema(max_length = 64, example = "tshirt_black_m")]
+    pub item_id: Option<String>,
+    /// Name of the product
+    #[schema(max_length = 255, example = "T-shirt")]
+    pub name: String,
+    /// Description of the product
+    #[schema(max_length = 255)]
+    pub description: Option<String>,
+    /// Image URL of the product
+    #[schema(max_length = 255)]
+    pub image_url: Option<String>,
+    /// Price of a single unit of the product, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 2500)]
+    pub unit_amount: MinorUnit,
+    /// Quantity of the product included in a payment, unless the customer chooses the quantity
+    #[serde(default = "default_quantity")]
+    #[schema(default = 1, example = 1)]
+    pub quantity: u16,
+    /// Allows the customer to choose the quantity of the product within the bounds
+    pub adjustable_quantity: Option<AdjustableQuantity>,
+    /// Total quantity of the product that can be sold through the link
+    #[schema(example = 50)]
+    pub inventory: Option<u32>,
+}
+
+fn default_quantity() -> u16 {
+    1
+}
+
+#[derive(Debug, Clone, Copy, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AdjustableQuantity {
+    /// Minimum quantity that the customer can choose, `0` allows the customer to skip the product
+    #[schema(example = 0)]
+    pub minimum: u16,
+    /// Maximum quantity that the customer can choose
+    #[schema(example = 10)]
+    pub maximum: u16,
+}
+
+#[derive(Debug, Clone, Copy, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkCustomAmount {
+    /// Minimum amount that the customer can choose, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 500)]
+    pub minimum_amount: MinorUnit,
+    /// Maximum amount that the customer can choose, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 100000)]
+    pub maximum_amount: MinorUnit,
+    /// Amount used when the customer does not choose an amount. The customer must choose an
+    /// amount if not provided
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub default_amount: Option<MinorUnit>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkUpdateRequest {
+    /// The identifier of the link
+    #[serde(skip_deserializing)]
+    pub reusable_link_id: String,
+    /// Set to `inactive` to stop the link from creating payments, or `active` to resume it
+    #[schema(value_type = Option<ReusablePaymentLinkStatus>, example = "inactive")]
+    pub status: Option<enums::ReusablePaymentLinkStatus>,
+    /// Description shown to the customers visiting the link
+    #[schema(max_length = 255)]
+    pub description: Option<String>,
+    /// Maximum number of payments that can be created through the link
+    pub max_uses: Option<u32>,
+    /// Time after which the link can no longer be used
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkResponse {
+    /// The identifier of the link
+    pub reusable_link_id: String,
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The business profile of the payments created through the link
+    pub profile_id: String,
+    /// The URL to share with the customers, every visit creates a new payment
+    pub link: String,
+    /// The status of the link
+    #[schema(value_type = ReusablePaymentLinkStatus)]
+    pub status: enums::ReusablePaymentLinkStatus,
+    /// The currency of the payments created through the link
+    #[schema(value_type = Currency)]
+    pub currency: enums::Currency,
+    /// Description shown to the customers visiting the link
+    pub description: Option<String>,
+    /// The products sold through the link
+    pub line_items: Vec<ReusablePaymentLinkLineItemResponse>,
+    /// Bounds of the amount chosen by the customer
+    pub custom_amount: Option<ReusablePaymentLinkCustomAmount>,
+    /// Maximum number of payments that can be created through the link
+    pub max_uses: Option<u32>,
+    /// Number of payments created through the link that have not failed or been abandoned
+    pub times_used: u32,
+    /// Time after which the link can no longer be used
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    /// Time at which the link was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkLineItemResponse {
+    /// Identifier of the product
+    pub item_id: String,
+    /// Name of the product
+    pub name: String,
+    /// Description of the product
+    pub description: Option<String>,
+    /// Image URL of the product
+    pub image_url: Option<String>,
+    /// Price of a single unit of the product
+    #[schema(value_type = i64)]
+    pub unit_amount: MinorUnit,
+    /// Quantity of the product included in a payment, unless the customer chooses the quantity
+    pub quantity: u16,
+    /// Bounds of the quantity chosen by the customer
+    pub adjustable_quantity: Option<AdjustableQuantity>,
+    /// Total quantity of the product that can be sold through the link
+    pub inventory: Option<u32>,
+    /// Quantity of the product in payments that have not failed or been abandoned
+    pub quantity_sold: u32,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkListConstraints {
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkListResponse {
+    /// The number of links included in the list
+    pub count: usize,
+    /// The links of the merchant, most recent first
+    pub data: Vec<ReusablePaymentLinkResponse>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReusablePaymentLinkId {
+    pub reusable_link_id: String,
+}
+
+/// Choices of the customer visiting a reusable payment link, passed as query parameters when
+/// visiting the link and as form fields when submitting them
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkVisitParams {
+    /// Quantities of the products with an adjustable quantity, as comma separated
+    /// `item_id:quantity` pairs
+    #[schema(example = "tshirt_black_m:2,mug:1")]
+    pub quantities: Option<String>,
+    /// The amount chosen by the customer, if the link allows the customer to choose an amount
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub amount: Option<MinorUnit>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReusablePaymentLinkVisitRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub reusable_link_id: String,
+    pub params: ReusablePaymentLinkVisitParams,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkSalesSummary {
+    /// The identifier of the link
+    pub reusable_link_id: String,
+    /// The currency of the payments created through the link
+    #[schema(value_type = Currency)]
+    pub currency: enums::Currency,
+    /// Number of payments created through the link
+    pub total_payments: u32,
+    /// Number of payments that succeeded
+    pub succeeded_payments: u32,
+    /// Number of payments that are yet to complete
+    pub pending_payments: u32,
+    /// Number of payments that failed or were abandoned by the customer
+    pub failed_payments: u32,
+    /// Total amount of the payments that succeeded
+    #[schema(value_type = i64)]
+    pub amount_collected: MinorUnit,
+    /// Number of payments that can still be created through the link
+    pub remaining_uses: Option<u32>,
+    /// Sales of the products of the link
+    pub line_items: Vec<ReusablePaymentLinkItemSalesSummary>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkItemSalesSummary {
+    /// Identifier of the product
+    pub item_id: String,
+    /// Name of the product
+    pub name: String,
+    /// Quantity of the product in payments that succeeded
+    pub quantity_sold: u32,
+    /// Quantity of the product in payments that are yet to complete
+    pub quantity_pending: u32,
+    /// Quantity of the product that can still be sold through the link
+    pub remaining_inventory: Option<u32>,
+}
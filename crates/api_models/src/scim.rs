@@ -0,0 +1,147 @@
+use common_utils::pii;
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+pub const SCIM_USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const SCIM_GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const SCIM_LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+pub const SCIM_PATCH_OP_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:PatchOp";
+/// The `scimType` of the error returned for filters which are not supported, as per RFC 7644
+pub const SCIM_INVALID_FILTER_ERROR: &str = "invalidFilter";
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+    pub formatted: Option<Secret<String>>,
+    pub given_name: Option<Secret<String>>,
+    pub family_name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ScimEmail {
+    pub value: pii::Email,
+    #[serde(rename = "type")]
+    pub email_type: Option<String>,
+    pub primary: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ScimMember {
+    /// For users this is the `user_id`, for groups this is the `role_id`
+    pub value: String,
+    pub display: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: ScimResourceType,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_modified: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub enum ScimResourceType {
+    User,
+    Group,
+}
+
+/// A SCIM `User` resource as sent by the identity provider on create and replace
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserRequest {
+    #[serde(default)]
+    pub schemas: Vec<String>,
+    pub external_id: Option<String>,
+    pub user_name: pii::Email,
+    pub name: Option<ScimName>,
+    pub display_name: Option<Secret<String>>,
+    #[serde(default)]
+    pub emails: Vec<ScimEmail>,
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserResponse {
+    pub schemas: Vec<String>,
+    pub id: String,
+    pub external_id: Option<String>,
+    pub user_name: pii::Email,
+    pub name: ScimName,
+    pub display_name: Secret<String>,
+    pub emails: Vec<ScimEmail>,
+    pub active: bool,
+    pub groups: Vec<ScimMember>,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupResponse {
+    pub schemas: Vec<String>,
+    pub id: String,
+    pub display_name: String,
+    pub members: Vec<ScimMember>,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListResponse<T> {
+    pub schemas: Vec<String>,
+    pub total_results: usize,
+    pub start_index: usize,
+    pub items_per_page: usize,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<T>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListQuery {
+    /// Only `eq` filters on a single attribute are supported, e.g. `userName eq "a@b.com"`
+    pub filter: Option<String>,
+    /// 1-based index of the first result, as defined by RFC 7644
+    pub start_index: Option<usize>,
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScimPatchOperationType {
+    #[serde(alias = "Add")]
+    Add,
+    #[serde(alias = "Replace")]
+    Replace,
+    #[serde(alias = "Remove")]
+    Remove,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ScimPatchOperation {
+    pub op: ScimPatchOperationType,
+    pub path: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ScimPatchRequest {
+    #[serde(default)]
+    pub schemas: Vec<String>,
+    #[serde(rename = "Operations")]
+    pub operations: Vec<ScimPatchOperation>,
+}
+
+/// The bearer token identity providers use to call the SCIM endpoints of a merchant. It is only
+/// returned when issued.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ScimTokenResponse {
+    pub token: Secret<String>,
+    pub org_id: common_utils::id_type::OrganizationId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -3,7 +3,7 @@ use common_utils::pii;
 
 pub mod role;
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
 pub enum Permission {
     PaymentRead,
     PaymentWrite,
@@ -34,6 +34,7 @@ pub enum Permission {
     WebhookEventRead,
     PayoutWrite,
     PayoutRead,
+    PayoutApprove,
     WebhookEventWrite,
     GenerateReport,
 }
@@ -5,7 +5,13 @@ use super::Permission;
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct CreateRoleRequest {
     pub role_name: String,
+    #[serde(default)]
     pub groups: Vec<PermissionGroup>,
+    /// Individual permissions granted on top of the permission groups
+    #[serde(default)]
+    pub permissions: Vec<Permission>,
+    /// Business profiles the role is restricted to, the role applies to all profiles if not set
+    pub profile_ids: Option<Vec<String>>,
     pub role_scope: RoleScope,
     pub entity_type: Option<EntityType>,
 }
@@ -13,6 +19,15 @@ pub struct CreateRoleRequest {
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct UpdateRoleRequest {
     pub groups: Option<Vec<PermissionGroup>>,
+    pub permissions: Option<Vec<Permission>>,
+    /// Business profiles the role is restricted to, left unchanged if not set and removed if set
+    /// to `null` so that the role applies to all profiles
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::nullable::deserialize",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub profile_ids: Option<Option<Vec<String>>>,
     pub role_name: Option<String>,
 }
 
@@ -42,6 +57,7 @@ pub enum RoleInfoResponse {
 pub struct RoleInfoWithPermissionsResponse {
     pub role_id: String,
     pub permissions: Vec<Permission>,
+    pub profile_ids: Option<Vec<String>>,
     pub role_name: String,
     pub role_scope: RoleScope,
 }
@@ -50,6 +66,8 @@ pub struct RoleInfoWithPermissionsResponse {
 pub struct RoleInfoWithGroupsResponse {
     pub role_id: String,
     pub groups: Vec<PermissionGroup>,
+    pub permissions: Vec<Permission>,
+    pub profile_ids: Option<Vec<String>>,
     pub role_name: String,
     pub role_scope: RoleScope,
 }
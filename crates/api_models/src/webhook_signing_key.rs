@@ -0,0 +1,92 @@
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSigningKeyCreateRequest {
+    /// The algorithm of the new key
+    #[schema(value_type = WebhookSigningAlgorithm, example = "ed25519")]
+    pub algorithm: enums::WebhookSigningAlgorithm,
+    /// Number of seconds for which the key being replaced remains published in the JWKS of the
+    /// profile, so that webhooks signed with it can still be verified
+    #[schema(example = 86400)]
+    pub overlap_period_in_secs: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct WebhookSigningKeyResponse {
+    /// The identifier of the key, sent in the `X-Webhook-Key-Id` header of the signed webhooks
+    pub key_id: String,
+    /// The business profile whose webhooks are signed with the key
+    pub profile_id: String,
+    /// The algorithm of the key
+    #[schema(value_type = WebhookSigningAlgorithm)]
+    pub algorithm: enums::WebhookSigningAlgorithm,
+    /// The status of the key
+    #[schema(value_type = WebhookSigningKeyStatus)]
+    pub status: enums::WebhookSigningKeyStatus,
+    /// The public key in the JSON Web Key format
+    pub public_key: Jwk,
+    /// Time at which the key was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Time after which the key is no longer published
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct WebhookSigningKeyListResponse {
+    /// The number of keys included in the list
+    pub count: usize,
+    /// The keys of the profile, most recent first
+    pub data: Vec<WebhookSigningKeyResponse>,
+}
+
+/// A public key in the JSON Web Key format (RFC 7517)
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct Jwk {
+    /// The key type, `OKP` for Ed25519 keys and `EC` for ECDSA keys
+    #[schema(example = "OKP")]
+    pub kty: String,
+    /// The curve of the key
+    #[schema(example = "Ed25519")]
+    pub crv: String,
+    /// The identifier of the key
+    pub kid: String,
+    /// The algorithm the key is used with
+    #[schema(example = "EdDSA")]
+    pub alg: String,
+    /// The intended use of the key, always `sig`
+    #[serde(rename = "use")]
+    #[schema(example = "sig")]
+    pub key_use: String,
+    /// The base64url encoded x coordinate of the key
+    pub x: String,
+    /// The base64url encoded y coordinate of the key, only present for ECDSA keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+}
+
+/// The public keys which are used to verify the signatures of the webhooks of a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct Jwks {
+    pub keys: Vec<Jwk>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct WebhookSigningKeyProfileId {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct WebhookSigningKeyId {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub key_id: String,
+}
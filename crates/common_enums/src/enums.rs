@@ -1204,6 +1204,7 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    Invoices,
 }
 
 #[derive(
@@ -1240,6 +1241,8 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    /// Evidence submission deadline of an open dispute is approaching
+    DisputeDeadlineReminder,
     MandateActive,
     MandateRevoked,
     PayoutSuccess,
@@ -1249,6 +1252,13 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    InvoiceCreated,
+    InvoiceFinalized,
+    /// A payment was received for an invoice, without paying it in full
+    InvoicePartiallyPaid,
+    InvoicePaid,
+    InvoiceVoided,
+    InvoiceMarkedUncollectible,
 }
 
 #[derive(
@@ -1690,6 +1700,477 @@ pub enum RefundStatus {
     TransactionFailure,
 }
 
+/// Status of a batch of refunds uploaded in bulk
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RefundBatchStatus {
+    /// The batch has been validated and is waiting to be picked up by the scheduler
+    #[default]
+    Pending,
+    /// Refunds in the batch are being created
+    Processing,
+    /// A refund creation has been attempted for every row in the batch
+    Completed,
+}
+
+/// Status of a single row of a refund batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RefundBatchItemStatus {
+    #[default]
+    Pending,
+    /// The refund was created, its outcome is tracked on the refund itself
+    Created,
+    /// The refund could not be created, the reason is recorded on the row
+    Failed,
+}
+
+/// Kind of request made by a data subject about the data held on them
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DataSubjectRequestType {
+    /// Gather all the data held about the customer into a downloadable archive
+    Export,
+    /// Redact the data held about the customer
+    Erasure,
+}
+
+/// Status of a data subject request
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DataSubjectRequestStatus {
+    /// The request is waiting to be picked up by the scheduler
+    #[default]
+    Pending,
+    /// The request is being processed
+    Processing,
+    /// The data has been exported or erased
+    Completed,
+    /// The request could not be completed, the reason is recorded on the request
+    Failed,
+}
+
+/// Tables whose rows can be archived and removed once they are older than the configured
+/// retention period
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DataRetentionTable {
+    /// Outgoing webhook events and their delivery attempts
+    Events,
+    /// Payment attempts, archived together with their payment intent once the payment has settled
+    PaymentAttempt,
+    /// Finished scheduler tasks, pending tasks are never archived
+    ProcessTracker,
+}
+
+/// Exemptions from strong customer authentication which can be requested for a card payment
+/// under PSD2
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ScaExemptionType {
+    /// Payments below the low value threshold
+    LowValue,
+    /// Payments which were assessed as low risk by the transaction risk analysis
+    TransactionRiskAnalysis,
+    /// The cardholder has added the merchant to their list of trusted beneficiaries
+    TrustedBeneficiary,
+    /// Payments initiated by the merchant without the cardholder being present
+    MerchantInitiatedTransaction,
+}
+
+/// Status of the rotation of a merchant's encryption key
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRotationStatus {
+    /// The new key is active and the stored data is being re-encrypted
+    InProgress,
+    /// All the stored data was re-encrypted, the data written with the previous key since is being
+    /// re-encrypted before the previous key is retired
+    Verifying,
+    /// All the stored data was re-encrypted and the previous key was retired
+    Completed,
+    /// The re-encryption was stopped, the previous key is retained for decryption
+    Failed,
+}
+
+/// Tables holding data encrypted with the merchant's key, in the order in which they are
+/// re-encrypted during a key rotation
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRotationTable {
+    MerchantAccount,
+    BusinessProfile,
+    MerchantConnectorAccount,
+    Customers,
+    Address,
+    PaymentMethods,
+    PaymentIntent,
+    Events,
+    WebhookSigningKey,
+    /// The archived payments and webhook events, which are stored with the file storage client
+    DataRetentionArchive,
+}
+
+/// Algorithm used to sign outgoing webhooks with a private key
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookSigningAlgorithm {
+    /// EdDSA over Curve25519
+    Ed25519,
+    /// ECDSA over the P-256 curve with SHA-256
+    EcdsaP256Sha256,
+}
+
+/// Status of a key used to sign outgoing webhooks
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookSigningKeyStatus {
+    /// The key signs the outgoing webhooks of the profile
+    Active,
+    /// The key was replaced, it is published until it expires so that in-flight webhooks can be
+    /// verified
+    Rotated,
+    /// The key is no longer published
+    Revoked,
+}
+
+/// Status of a payment link that creates a new payment on every visit
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReusablePaymentLinkStatus {
+    /// Visits to the link create new payments
+    Active,
+    /// The link was deactivated by the merchant
+    Inactive,
+    /// The link is past its expiry time, this status is never stored
+    Expired,
+    /// The usage limit of the link was reached, this status is never stored
+    Exhausted,
+}
+
+/// Status of an invoice
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    /// The invoice can still be edited, and has not been sent to the customer
+    Draft,
+    /// The invoice was finalized and is awaiting payment
+    Open,
+    /// The invoice was paid in full
+    Paid,
+    /// The invoice was cancelled by the merchant
+    Void,
+    /// The merchant does not expect the invoice to be paid
+    Uncollectible,
+}
+
+/// How a payment towards an invoice was received
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum InvoicePaymentSource {
+    /// Paid by the customer through the payment link of the invoice
+    PaymentLink,
+    /// Received outside of the platform, such as cash or a bank transfer, and recorded by the
+    /// merchant
+    OutOfBand,
+}
+
+/// Account of the ledger of a merchant, kept separately for each currency
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerAccount {
+    /// Amounts authorized on the payment methods of customers and not yet captured or released
+    PendingAuthorizations,
+    /// Counterpart of the pending authorizations, held for the merchant until capture
+    AuthorizationHolds,
+    /// Amounts owed to the merchant by the processors, pending settlement
+    ProcessorReceivable,
+    /// Gross amounts captured from customers
+    Sales,
+    /// Amounts refunded to customers
+    Refunds,
+    /// Amounts lost to disputes raised by customers
+    Chargebacks,
+    /// Fees charged on payments
+    Fees,
+    /// Amounts paid out by the merchant
+    Payouts,
+}
+
+impl LedgerAccount {
+    /// Whether the balance of the account increases with debits, as for assets and expenses,
+    /// rather than with credits, as for liabilities and revenue
+    pub fn is_debit_normal(self) -> bool {
+        match self {
+            Self::PendingAuthorizations
+            | Self::ProcessorReceivable
+            | Self::Refunds
+            | Self::Chargebacks
+            | Self::Fees
+            | Self::Payouts => true,
+            Self::AuthorizationHolds | Self::Sales => false,
+        }
+    }
+}
+
+/// Business event recorded by a journal entry of the ledger
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerEntryType {
+    /// A payment was authorized
+    Authorization,
+    /// An authorization was voided without being captured
+    AuthorizationVoid,
+    /// A payment was captured, releasing its authorization
+    Capture,
+    /// A refund of a payment succeeded
+    Refund,
+    /// A dispute on a payment was lost
+    Chargeback,
+    /// A fee was charged on a payment
+    Fee,
+    /// A payout succeeded
+    Payout,
+}
+
+/// Side of an account on which a posting is made
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerPostingDirection {
+    Debit,
+    Credit,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -2313,6 +2794,71 @@ pub enum PayoutStatus {
     RequiresVendorAccountCreation,
 }
 
+/// Status of a batch of payouts submitted together
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The batch is waiting to be approved by a user other than the one who submitted it
+    #[default]
+    PendingApproval,
+    /// The batch was rejected by the reviewer and will not be executed
+    Rejected,
+    /// The batch was approved and is waiting for its scheduled execution time
+    Scheduled,
+    /// Payouts in the batch are being created
+    Processing,
+    /// Every payout in the batch succeeded
+    Completed,
+    /// Some payouts in the batch could not be created, failed or did not reach a final status
+    PartiallyCompleted,
+    /// Every payout in the batch could not be created or failed
+    Failed,
+}
+
+/// Status of a single payout in a payout batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    #[default]
+    Pending,
+    /// The payout was created and is waiting for its final status
+    Submitted,
+    /// The payout succeeded
+    Succeeded,
+    /// The payout could not be created or ended up in a failed state
+    Failed,
+}
+
 /// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
 #[derive(
     Clone,
@@ -2547,6 +3093,14 @@ impl AuthenticationConnectors {
             Self::Gpayments => true,
         }
     }
+
+    /// Whether the SCA exemption of a payment is requested during the authentication
+    pub fn supports_sca_exemption(&self) -> bool {
+        match self {
+            Self::Netcetera => true,
+            Self::Threedsecureio | Self::Gpayments => false,
+        }
+    }
 }
 
 #[derive(
@@ -2759,6 +3313,7 @@ pub enum TransactionStatus {
 pub enum PermissionGroup {
     OperationsView,
     OperationsManage,
+    PayoutsApprove,
     ConnectorsView,
     ConnectorsManage,
     WorkflowsView,
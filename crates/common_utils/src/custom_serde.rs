@@ -218,6 +218,23 @@ pub mod json_string {
     }
 }
 
+/// Deserialize a field which may be absent, explicitly set to `null` or set to a value, as
+/// `None`, `Some(None)` and `Some(Some(value))` respectively.
+///
+/// The field has to be marked `#[serde(default)]` for an absent field to be deserialized.
+pub mod nullable {
+    use serde::{Deserialize, Deserializer};
+
+    /// Deserialize a value which is present in the input, possibly as `null`
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::<T>::deserialize(deserializer).map(Some)
+    }
+}
+
 /// Use a custom ISO 8601 format when serializing and deserializing
 /// [`PrimitiveDateTime`][PrimitiveDateTime].
 ///
@@ -268,6 +285,7 @@ pub mod iso8601custom {
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
@@ -283,4 +301,24 @@ mod tests {
 
         assert!(deser.is_ok())
     }
+
+    #[test]
+    fn test_nullable_distinguishes_null_from_absent() {
+        #[derive(Deserialize)]
+        struct Try {
+            #[serde(
+                default,
+                deserialize_with = "crate::custom_serde::nullable::deserialize"
+            )]
+            f: Option<Option<u8>>,
+        }
+
+        let absent = serde_json::from_value::<Try>(json!({})).unwrap();
+        let null = serde_json::from_value::<Try>(json!({"f": null})).unwrap();
+        let value = serde_json::from_value::<Try>(json!({"f": 1})).unwrap();
+
+        assert_eq!(absent.f, None);
+        assert_eq!(null.f, Some(None));
+        assert_eq!(value.f, Some(Some(1)));
+    }
 }
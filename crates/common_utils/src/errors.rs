@@ -175,6 +175,8 @@ pub enum KeyManagerError {
     KeyAddFailed,
     #[error("Failed to transfer the key to the KeyManager")]
     KeyTransferFailed,
+    #[error("Failed to rotate the key in the KeyManager")]
+    KeyRotationFailed,
     #[error("Failed to Encrypt the data in the KeyManager")]
     EncryptionFailed,
     #[error("Failed to Decrypt the data in the KeyManager")]
@@ -15,6 +15,9 @@ pub enum ApiEventsType {
     Payout {
         payout_id: String,
     },
+    PayoutBatch {
+        batch_id: String,
+    },
     Payment {
         payment_id: String,
     },
@@ -22,6 +25,18 @@ pub enum ApiEventsType {
         payment_id: Option<String>,
         refund_id: String,
     },
+    ReusablePaymentLink {
+        reusable_link_id: String,
+    },
+    Invoice {
+        invoice_id: String,
+    },
+    RefundBatch {
+        batch_id: String,
+    },
+    DataSubjectRequest {
+        request_id: String,
+    },
     PaymentMethod {
         payment_method_id: String,
         payment_method: Option<PaymentMethod>,
@@ -47,6 +62,8 @@ pub enum ApiEventsType {
         payment_id: Option<String>,
     },
     Gsm,
+    DataRetention,
+    WebhookSigningKey,
     // TODO: This has to be removed once the corresponding apiEventTypes are created
     Miscellaneous,
     Keymanager,
@@ -122,6 +122,26 @@ impl MerchantId {
         format!("guard_blocklist_for_{}", self.get_string_repr())
     }
 
+    /// get_native_frm_rules_key
+    pub fn get_native_frm_rules_key(&self) -> String {
+        format!("native_frm_rules_{}", self.get_string_repr())
+    }
+
+    /// get_dispute_deadline_policy_key
+    pub fn get_dispute_deadline_policy_key(&self) -> String {
+        format!("dispute_deadline_policy_{}", self.get_string_repr())
+    }
+
+    /// get_sca_exemption_config_key
+    pub fn get_sca_exemption_config_key(&self) -> String {
+        format!("sca_exemption_config_{}", self.get_string_repr())
+    }
+
+    /// get_scim_token_hash_config_key
+    pub fn get_scim_token_hash_config_key(&self) -> String {
+        format!("scim_token_hash_{}", self.get_string_repr())
+    }
+
     /// get_merchant_fingerprint_secret_key
     pub fn get_merchant_fingerprint_secret_key(&self) -> String {
         format!("fingerprint_secret_{}", self.get_string_repr())
@@ -198,6 +218,14 @@ impl MerchantId {
         }
     }
 
+    /// get_should_fallback_payout_connector_key
+    pub fn get_should_fallback_payout_connector_key(&self) -> String {
+        format!(
+            "should_fallback_payout_connector_{}",
+            self.get_string_repr()
+        )
+    }
+
     /// Get should call gsm key for payment
     pub fn get_should_call_gsm_key(&self) -> String {
         format!("should_call_gsm_{}", self.get_string_repr())
@@ -223,6 +223,18 @@ pub async fn create_key_in_key_manager(
         .change_context(errors::KeyManagerError::KeyAddFailed)
 }
 
+/// A function to rotate the key in keymanager, data encrypted with the previous versions of the
+/// key can still be decrypted by the keymanager
+#[instrument(skip_all)]
+pub async fn rotate_key_in_key_manager(
+    state: &KeyManagerState,
+    request_body: EncryptionCreateRequest,
+) -> errors::CustomResult<DataKeyCreateResponse, errors::KeyManagerError> {
+    call_encryption_service(state, Method::POST, "key/rotate", request_body)
+        .await
+        .change_context(errors::KeyManagerError::KeyRotationFailed)
+}
+
 /// A function to transfer the key in keymanager
 #[instrument(skip_all)]
 pub async fn transfer_key_to_key_manager(
@@ -21,6 +21,31 @@ use crate::{
     transformers::{ForeignFrom, ForeignTryFrom},
 };
 
+/// Keys replaced by a key rotation, indexed by the key which replaced them. A previous key is
+/// registered when the key store holding it is loaded, so that the data which was not re-encrypted
+/// yet is decrypted with it for as long as the state is used.
+#[derive(Debug, Clone, Default)]
+pub struct PreviousKeys(std::sync::Arc<std::sync::RwLock<FxHashMap<Vec<u8>, Secret<Vec<u8>>>>>);
+
+impl PreviousKeys {
+    /// Registers the key which was replaced by `key`, or removes it once it is retired
+    pub fn set(&self, key: &[u8], previous_key: Option<Secret<Vec<u8>>>) {
+        if let Ok(mut previous_keys) = self.0.write() {
+            match previous_key {
+                Some(previous_key) => previous_keys.insert(key.to_vec(), previous_key),
+                None => previous_keys.remove(key),
+            };
+        }
+    }
+
+    pub fn get(&self, key: &[u8]) -> Option<Secret<Vec<u8>>> {
+        self.0
+            .read()
+            .ok()
+            .and_then(|previous_keys| previous_keys.get(key).cloned())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct KeyManagerState {
     pub enabled: Option<bool>,
@@ -30,6 +55,7 @@ pub struct KeyManagerState {
     pub ca: Secret<String>,
     #[cfg(feature = "keymanager_mtls")]
     pub cert: Secret<String>,
+    pub previous_keys: PreviousKeys,
 }
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 #[serde(tag = "data_identifier", content = "key_identifier")]
@@ -3,11 +3,18 @@ use std::path::PathBuf;
 /// Utility to import a hyperswitch TOML configuration file, convert it into environment variable
 /// key-value pairs, and export it in the specified format.
 #[derive(clap::Parser, Debug)]
-#[command(arg_required_else_help = true)]
+#[command(
+    arg_required_else_help = true,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 pub(crate) struct Args {
+    #[command(subcommand)]
+    pub(crate) command: Option<Command>,
+
     /// Input TOML configuration file.
-    #[arg(short, long, value_name = "FILE")]
-    pub(crate) input_file: PathBuf,
+    #[arg(short, long, value_name = "FILE", required = true)]
+    pub(crate) input_file: Option<PathBuf>,
 
     /// The format to convert the environment variables to.
     #[arg(
@@ -41,3 +48,71 @@ pub(crate) enum OutputFormat {
     /// ```
     KubernetesJson,
 }
+
+#[derive(clap::Subcommand, Debug)]
+pub(crate) enum Command {
+    /// Manage the secrets file read by the `encrypted_file` secrets manager.
+    #[command(subcommand)]
+    Secrets(SecretsCommand),
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub(crate) enum SecretsCommand {
+    /// Generate a key to encrypt a secrets file with.
+    GenerateKey {
+        /// Output key file. The key will be written to stdout if not specified.
+        #[arg(short, long, value_name = "FILE")]
+        output_file: Option<PathBuf>,
+    },
+
+    /// Encrypt a TOML file mapping the names of the secrets to their values.
+    Encrypt {
+        /// Input TOML secrets file.
+        #[arg(short, long, value_name = "FILE")]
+        input_file: PathBuf,
+
+        /// Output encrypted secrets file.
+        #[arg(short, long, value_name = "FILE")]
+        output_file: PathBuf,
+
+        #[command(flatten)]
+        key: KeySource,
+    },
+
+    /// Decrypt a secrets file to the TOML file of secrets.
+    Decrypt {
+        /// Input encrypted secrets file.
+        #[arg(short, long, value_name = "FILE")]
+        input_file: PathBuf,
+
+        /// Output TOML secrets file. Output will be written to stdout if not specified.
+        #[arg(short, long, value_name = "FILE")]
+        output_file: Option<PathBuf>,
+
+        #[command(flatten)]
+        key: KeySource,
+    },
+
+    /// Edit a secrets file in the editor specified by the `EDITOR` environment variable.
+    Edit {
+        /// The encrypted secrets file.
+        #[arg(short, long, value_name = "FILE")]
+        file: PathBuf,
+
+        #[command(flatten)]
+        key: KeySource,
+    },
+}
+
+/// The location of the key of the secrets file.
+#[derive(clap::Args, Debug)]
+#[group(required = true, multiple = false)]
+pub(crate) struct KeySource {
+    /// Environment variable holding the hex encoded key.
+    #[arg(long, value_name = "VARIABLE")]
+    pub(crate) key_env_var: Option<String>,
+
+    /// File holding the hex encoded key.
+    #[arg(long, value_name = "FILE")]
+    pub(crate) key_file: Option<PathBuf>,
+}
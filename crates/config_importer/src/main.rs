@@ -1,4 +1,5 @@
 mod cli;
+mod secrets;
 
 use std::io::{BufWriter, Write};
 
@@ -16,9 +17,13 @@ type EnvironmentVariableMap = indexmap::IndexMap<String, String>;
 fn main() -> anyhow::Result<()> {
     let args = <cli::Args as clap::Parser>::parse();
 
+    if let Some(cli::Command::Secrets(command)) = args.command {
+        return secrets::run(command);
+    }
+
     // Read input TOML file
-    let toml_contents =
-        std::fs::read_to_string(args.input_file).context("Failed to read input file")?;
+    let input_file = args.input_file.context("Input file must be specified")?;
+    let toml_contents = std::fs::read_to_string(input_file).context("Failed to read input file")?;
     let table = toml_contents
         .parse::<toml::Table>()
         .context("Failed to parse TOML file contents")?;
@@ -0,0 +1,224 @@
+use std::{
+    collections::hash_map::RandomState,
+    fs::{File, OpenOptions},
+    hash::{BuildHasher, Hasher},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use external_services::encrypted_file::core::{self as encrypted_file, EncryptedFileConfig};
+use masking::{ExposeInterface, PeekInterface, Secret};
+
+use crate::cli::{KeySource, SecretsCommand};
+
+/// The editor used to edit secrets files if the `EDITOR` environment variable is not set.
+const DEFAULT_EDITOR: &str = "vi";
+
+/// The number of names tried when creating a temporary file before giving up.
+const TEMP_FILE_ATTEMPTS: usize = 16;
+
+pub(crate) fn run(command: SecretsCommand) -> anyhow::Result<()> {
+    match command {
+        SecretsCommand::GenerateKey { output_file } => {
+            let key = encrypted_file::generate_key();
+            match output_file {
+                Some(file) => write_private_file(&file, key.peek().as_bytes())
+                    .context("Failed to write key file")?,
+                None => println!("{}", key.expose()),
+            }
+        }
+        SecretsCommand::Encrypt {
+            input_file,
+            output_file,
+            key,
+        } => {
+            let key = read_key(key)?;
+            let plaintext = std::fs::read_to_string(input_file)
+                .map(Secret::new)
+                .context("Failed to read input file")?;
+            let contents = encrypted_file::encrypt_secrets(&key, &plaintext)
+                .map_err(|error| anyhow::anyhow!("{error:?}"))?;
+
+            write_secrets_file(&output_file, &contents)?;
+        }
+        SecretsCommand::Decrypt {
+            input_file,
+            output_file,
+            key,
+        } => {
+            let key = read_key(key)?;
+            let plaintext = decrypt_secrets_file(&key, &input_file)?;
+
+            match output_file {
+                Some(file) => write_private_file(&file, plaintext.peek().as_bytes())
+                    .context("Failed to write output file")?,
+                None => print!("{}", plaintext.expose()),
+            }
+        }
+        SecretsCommand::Edit { file, key } => {
+            let key = read_key(key)?;
+            let plaintext = decrypt_secrets_file(&key, &file)?;
+
+            let (temp_file, mut handle) =
+                create_temp_file(&std::env::temp_dir(), "config_importer_secrets_", ".toml")
+                    .context("Failed to create temporary file")?;
+            let written = handle.write_all(plaintext.peek().as_bytes());
+            drop(handle);
+
+            // Remove the decrypted secrets from the disk regardless of whether editing succeeded
+            let edited = written
+                .context("Failed to write temporary file")
+                .and_then(|()| edit_file(&temp_file));
+            let remove_result = std::fs::remove_file(&temp_file);
+            let edited = edited?;
+            remove_result.context("Failed to remove temporary file")?;
+
+            if edited.peek() == plaintext.peek() {
+                eprintln!("Secrets file unchanged");
+                return Ok(());
+            }
+
+            let contents = encrypted_file::encrypt_secrets(&key, &edited)
+                .map_err(|error| anyhow::anyhow!("{error:?}"))
+                .context("Edited secrets file is invalid, discarding changes")?;
+            write_secrets_file(&file, &contents)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn read_key(key: KeySource) -> anyhow::Result<Secret<Vec<u8>>> {
+    EncryptedFileConfig {
+        file_path: PathBuf::default(),
+        key_env_var: key.key_env_var,
+        key_file: key.key_file,
+    }
+    .read_key()
+    .map_err(|error| anyhow::anyhow!("{error:?}"))
+}
+
+fn decrypt_secrets_file(key: &Secret<Vec<u8>>, file: &Path) -> anyhow::Result<Secret<String>> {
+    let contents = std::fs::read_to_string(file).context("Failed to read secrets file")?;
+
+    encrypted_file::decrypt_secrets(key, &contents).map_err(|error| anyhow::anyhow!("{error:?}"))
+}
+
+/// Opens the file in the editor specified by the `EDITOR` environment variable and returns the
+/// contents of the file once the editor exits.
+fn edit_file(file: &Path) -> anyhow::Result<Secret<String>> {
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| DEFAULT_EDITOR.to_owned());
+    let mut editor_args = editor.split_whitespace();
+    let program = editor_args.next().unwrap_or(DEFAULT_EDITOR);
+
+    let status = std::process::Command::new(program)
+        .args(editor_args)
+        .arg(file)
+        .status()
+        .with_context(|| format!("Failed to launch editor `{editor}`"))?;
+    anyhow::ensure!(status.success(), "Editor exited with {status}");
+
+    std::fs::read_to_string(file)
+        .map(Secret::new)
+        .context("Failed to read edited file")
+}
+
+/// Writes the secrets file by replacing it with a fully written file, so that the application
+/// never reads a partially written secrets file.
+fn write_secrets_file(file: &Path, contents: &str) -> anyhow::Result<()> {
+    let directory = match file.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let prefix = format!(
+        ".{}.",
+        file.file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default()
+    );
+    let (temp_file, mut handle) = create_temp_file(directory, &prefix, ".tmp")
+        .context("Failed to create temporary secrets file")?;
+
+    let result = handle
+        .write_all(contents.as_bytes())
+        .and_then(|()| handle.sync_all())
+        .context("Failed to write secrets file")
+        .and_then(|()| std::fs::rename(&temp_file, file).context("Failed to replace secrets file"));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_file);
+    }
+
+    result
+}
+
+/// Creates a new file with an unpredictable name in the directory, readable only by the current
+/// user.
+///
+/// The file is never opened if it already exists, so that a file or symbolic link planted at the
+/// path by another user cannot be written to.
+fn create_temp_file(
+    directory: &Path,
+    prefix: &str,
+    suffix: &str,
+) -> anyhow::Result<(PathBuf, File)> {
+    for _ in 0..TEMP_FILE_ATTEMPTS {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos(),
+        );
+        let file = directory.join(format!("{prefix}{:016x}{suffix}", hasher.finish()));
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        match options.open(&file) {
+            Ok(handle) => return Ok((file, handle)),
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    anyhow::bail!("Failed to find an unused temporary file name")
+}
+
+/// Creates a file readable only by the current user and writes the contents to it.
+fn write_private_file(file: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options.open(file)?.write_all(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_create_temp_file_uses_new_private_files() {
+        let directory = std::env::temp_dir();
+        let (first, _) = create_temp_file(&directory, "config_importer_test_", ".tmp").unwrap();
+        let (second, _) = create_temp_file(&directory, "config_importer_test_", ".tmp").unwrap();
+        assert_ne!(first, second);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&first).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        std::fs::remove_file(first).unwrap();
+        std::fs::remove_file(second).unwrap();
+    }
+}
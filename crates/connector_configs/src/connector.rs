@@ -171,6 +171,7 @@ pub struct ConnectorConfig {
     pub mifinity: Option<ConnectorTomlConfig>,
     pub mollie: Option<ConnectorTomlConfig>,
     pub multisafepay: Option<ConnectorTomlConfig>,
+    pub native_frm: Option<ConnectorTomlConfig>,
     pub nexinets: Option<ConnectorTomlConfig>,
     pub nmi: Option<ConnectorTomlConfig>,
     pub noon: Option<ConnectorTomlConfig>,
@@ -207,6 +208,7 @@ pub struct ConnectorConfig {
     pub worldpay: Option<ConnectorTomlConfig>,
     pub square: Option<ConnectorTomlConfig>,
     pub stax: Option<ConnectorTomlConfig>,
+    pub taxjar: Option<ConnectorTomlConfig>,
     pub dummy_connector: Option<ConnectorTomlConfig>,
     pub stripe_test: Option<ConnectorTomlConfig>,
     pub paypal_test: Option<ConnectorTomlConfig>,
@@ -334,11 +336,13 @@ impl ConnectorConfig {
             Connector::Razorpay => Ok(connector_data.razorpay),
             Connector::Rapyd => Ok(connector_data.rapyd),
             Connector::Riskified => Ok(connector_data.riskified),
+            Connector::NativeFrm => Ok(connector_data.native_frm),
             Connector::Shift4 => Ok(connector_data.shift4),
             Connector::Signifyd => Ok(connector_data.signifyd),
             Connector::Square => Ok(connector_data.square),
             Connector::Stax => Ok(connector_data.stax),
             Connector::Stripe => Ok(connector_data.stripe),
+            Connector::Taxjar => Ok(connector_data.taxjar),
             Connector::Trustpay => Ok(connector_data.trustpay),
             Connector::Threedsecureio => Ok(connector_data.threedsecureio),
             Connector::Tsys => Ok(connector_data.tsys),
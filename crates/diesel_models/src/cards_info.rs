@@ -1,7 +1,10 @@
-use diesel::{Identifiable, Queryable, Selectable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use time::PrimitiveDateTime;
 
-use crate::{enums as storage_enums, schema::cards_info};
+use crate::{
+    enums as storage_enums,
+    schema::{card_bin_range, cards_info},
+};
 
 #[derive(
     Clone, Debug, Queryable, Identifiable, Selectable, serde::Deserialize, serde::Serialize,
@@ -21,3 +24,107 @@ pub struct CardInfo {
     pub last_updated: Option<PrimitiveDateTime>,
     pub last_updated_provider: Option<String>,
 }
+
+/// A range of BINs sharing the same card details. `bin_start` and `bin_end` are inclusive and
+/// have the same number of digits, which is stored in `bin_length`.
+#[derive(
+    Clone, Debug, Queryable, Identifiable, Selectable, serde::Deserialize, serde::Serialize,
+)]
+#[diesel(table_name = card_bin_range, primary_key(bin_start, bin_end), check_for_backend(diesel::pg::Pg))]
+pub struct CardBinRange {
+    pub bin_start: String,
+    pub bin_end: String,
+    pub bin_length: i32,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<storage_enums::CardNetwork>,
+    pub card_type: Option<String>,
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub last_updated_provider: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl CardBinRange {
+    /// Builds the card details of `card_iin` from the range it falls in.
+    pub fn into_card_info(self, card_iin: String) -> CardInfo {
+        CardInfo {
+            card_iin,
+            card_issuer: self.card_issuer,
+            card_network: self.card_network,
+            card_type: self.card_type,
+            card_subtype: self.card_subtype,
+            card_issuing_country: self.card_issuing_country,
+            bank_code_id: None,
+            bank_code: None,
+            country_code: None,
+            date_created: self.created_at,
+            last_updated: Some(self.modified_at),
+            last_updated_provider: self.last_updated_provider,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = card_bin_range)]
+pub struct CardBinRangeNew {
+    pub bin_start: String,
+    pub bin_end: String,
+    pub bin_length: i32,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<storage_enums::CardNetwork>,
+    pub card_type: Option<String>,
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub last_updated_provider: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = card_bin_range)]
+pub struct CardBinRangeUpdateInternal {
+    card_issuer: Option<Option<String>>,
+    card_network: Option<Option<storage_enums::CardNetwork>>,
+    card_type: Option<Option<String>>,
+    card_subtype: Option<Option<String>>,
+    card_issuing_country: Option<Option<String>>,
+    last_updated_provider: Option<Option<String>>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum CardBinRangeUpdate {
+    /// Replaces all the card details of the range
+    DetailsUpdate {
+        card_issuer: Option<String>,
+        card_network: Option<storage_enums::CardNetwork>,
+        card_type: Option<String>,
+        card_subtype: Option<String>,
+        card_issuing_country: Option<String>,
+        last_updated_provider: Option<String>,
+    },
+}
+
+impl From<CardBinRangeUpdate> for CardBinRangeUpdateInternal {
+    fn from(value: CardBinRangeUpdate) -> Self {
+        match value {
+            CardBinRangeUpdate::DetailsUpdate {
+                card_issuer,
+                card_network,
+                card_type,
+                card_subtype,
+                card_issuing_country,
+                last_updated_provider,
+            } => Self {
+                card_issuer: Some(card_issuer),
+                card_network: Some(card_network),
+                card_type: Some(card_type),
+                card_subtype: Some(card_subtype),
+                card_issuing_country: Some(card_issuing_country),
+                last_updated_provider: Some(last_updated_provider),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
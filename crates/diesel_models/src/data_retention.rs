@@ -0,0 +1,164 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{data_retention_archive, data_retention_policy},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = data_retention_policy,
+    primary_key(table_name),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct DataRetentionPolicy {
+    pub table_name: storage_enums::DataRetentionTable,
+    pub retention_days: i32,
+    pub batch_size: i32,
+    pub is_enabled: bool,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_retention_policy)]
+pub struct DataRetentionPolicyNew {
+    pub table_name: storage_enums::DataRetentionTable,
+    pub retention_days: i32,
+    pub batch_size: i32,
+    pub is_enabled: bool,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_retention_policy)]
+pub struct DataRetentionPolicyUpdateInternal {
+    retention_days: Option<i32>,
+    batch_size: Option<i32>,
+    is_enabled: Option<bool>,
+    last_run_at: Option<PrimitiveDateTime>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum DataRetentionPolicyUpdate {
+    Update {
+        retention_days: i32,
+        batch_size: i32,
+        is_enabled: bool,
+    },
+    LastRunUpdate {
+        last_run_at: PrimitiveDateTime,
+    },
+}
+
+impl From<DataRetentionPolicyUpdate> for DataRetentionPolicyUpdateInternal {
+    fn from(value: DataRetentionPolicyUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            DataRetentionPolicyUpdate::Update {
+                retention_days,
+                batch_size,
+                is_enabled,
+            } => Self {
+                retention_days: Some(retention_days),
+                batch_size: Some(batch_size),
+                is_enabled: Some(is_enabled),
+                last_run_at: None,
+                modified_at,
+            },
+            DataRetentionPolicyUpdate::LastRunUpdate { last_run_at } => Self {
+                retention_days: None,
+                batch_size: None,
+                is_enabled: None,
+                last_run_at: Some(last_run_at),
+                modified_at,
+            },
+        }
+    }
+}
+
+impl DataRetentionPolicyUpdateInternal {
+    pub fn apply_changeset(self, source: DataRetentionPolicy) -> DataRetentionPolicy {
+        DataRetentionPolicy {
+            retention_days: self.retention_days.unwrap_or(source.retention_days),
+            batch_size: self.batch_size.unwrap_or(source.batch_size),
+            is_enabled: self.is_enabled.unwrap_or(source.is_enabled),
+            last_run_at: self.last_run_at.or(source.last_run_at),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = data_retention_archive,
+    primary_key(archive_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct DataRetentionArchive {
+    pub archive_id: String,
+    pub table_name: storage_enums::DataRetentionTable,
+    pub file_key: String,
+    pub row_count: i32,
+    pub oldest_record_created_at: PrimitiveDateTime,
+    pub newest_record_created_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub restored_at: Option<PrimitiveDateTime>,
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_retention_archive)]
+pub struct DataRetentionArchiveNew {
+    pub archive_id: String,
+    pub table_name: storage_enums::DataRetentionTable,
+    pub file_key: String,
+    pub row_count: i32,
+    pub oldest_record_created_at: PrimitiveDateTime,
+    pub newest_record_created_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub restored_at: Option<PrimitiveDateTime>,
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_retention_archive)]
+pub struct DataRetentionArchiveUpdateInternal {
+    restored_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum DataRetentionArchiveUpdate {
+    Restored { restored_at: PrimitiveDateTime },
+}
+
+impl From<DataRetentionArchiveUpdate> for DataRetentionArchiveUpdateInternal {
+    fn from(value: DataRetentionArchiveUpdate) -> Self {
+        match value {
+            DataRetentionArchiveUpdate::Restored { restored_at } => Self {
+                restored_at: Some(restored_at),
+            },
+        }
+    }
+}
+
+impl DataRetentionArchiveUpdateInternal {
+    pub fn apply_changeset(self, source: DataRetentionArchive) -> DataRetentionArchive {
+        DataRetentionArchive {
+            restored_at: self.restored_at.or(source.restored_at),
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DataRetentionTrackingData {
+    pub table_name: storage_enums::DataRetentionTable,
+}
@@ -0,0 +1,175 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::data_subject_request};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = data_subject_request,
+    primary_key(request_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct DataSubjectRequest {
+    pub request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub request_type: storage_enums::DataSubjectRequestType,
+    pub status: storage_enums::DataSubjectRequestStatus,
+    pub reason: Option<String>,
+    pub file_key: Option<String>,
+    pub summary: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+    pub email_hash: Option<String>,
+    pub erased_object_ids: Option<Vec<String>>,
+}
+
+#[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_subject_request)]
+pub struct DataSubjectRequestNew {
+    pub request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub request_type: storage_enums::DataSubjectRequestType,
+    pub status: storage_enums::DataSubjectRequestStatus,
+    pub reason: Option<String>,
+    pub file_key: Option<String>,
+    pub summary: Option<serde_json::Value>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+    pub email_hash: Option<String>,
+    pub erased_object_ids: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_subject_request)]
+pub struct DataSubjectRequestUpdateInternal {
+    customer_id: Option<id_type::CustomerId>,
+    email_hash: Option<Option<String>>,
+    status: Option<storage_enums::DataSubjectRequestStatus>,
+    file_key: Option<Option<String>>,
+    summary: Option<serde_json::Value>,
+    error_message: Option<String>,
+    modified_at: PrimitiveDateTime,
+    completed_at: Option<PrimitiveDateTime>,
+    erased_object_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug)]
+pub enum DataSubjectRequestUpdate {
+    StatusUpdate {
+        status: storage_enums::DataSubjectRequestStatus,
+    },
+    /// Records the customer found for a request made by email, the email hash is dropped then
+    CustomerResolved {
+        customer_id: id_type::CustomerId,
+    },
+    /// The identifiers of the payments, refunds, disputes and mandates of an erased customer are
+    /// retained, so that their archived webhook events are redacted when restored
+    Completed {
+        file_key: Option<String>,
+        summary: serde_json::Value,
+        erased_object_ids: Option<Vec<String>>,
+    },
+    Failed {
+        error_message: String,
+    },
+    /// Removes the reference to the export archive once the archive has been deleted
+    ArchiveRemoved,
+}
+
+impl From<DataSubjectRequestUpdate> for DataSubjectRequestUpdateInternal {
+    fn from(value: DataSubjectRequestUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            DataSubjectRequestUpdate::StatusUpdate { status } => Self {
+                customer_id: None,
+                email_hash: None,
+                status: Some(status),
+                file_key: None,
+                summary: None,
+                error_message: None,
+                modified_at,
+                completed_at: None,
+                erased_object_ids: None,
+            },
+            DataSubjectRequestUpdate::CustomerResolved { customer_id } => Self {
+                customer_id: Some(customer_id),
+                email_hash: Some(None),
+                status: None,
+                file_key: None,
+                summary: None,
+                error_message: None,
+                modified_at,
+                completed_at: None,
+                erased_object_ids: None,
+            },
+            DataSubjectRequestUpdate::Completed {
+                file_key,
+                summary,
+                erased_object_ids,
+            } => Self {
+                customer_id: None,
+                email_hash: None,
+                status: Some(storage_enums::DataSubjectRequestStatus::Completed),
+                file_key: Some(file_key),
+                summary: Some(summary),
+                error_message: None,
+                modified_at,
+                completed_at: Some(modified_at),
+                erased_object_ids,
+            },
+            DataSubjectRequestUpdate::Failed { error_message } => Self {
+                customer_id: None,
+                email_hash: None,
+                status: Some(storage_enums::DataSubjectRequestStatus::Failed),
+                file_key: None,
+                summary: None,
+                error_message: Some(error_message),
+                modified_at,
+                completed_at: Some(modified_at),
+                erased_object_ids: None,
+            },
+            DataSubjectRequestUpdate::ArchiveRemoved => Self {
+                customer_id: None,
+                email_hash: None,
+                status: None,
+                file_key: Some(None),
+                summary: None,
+                error_message: None,
+                modified_at,
+                completed_at: None,
+                erased_object_ids: None,
+            },
+        }
+    }
+}
+
+impl DataSubjectRequestUpdateInternal {
+    pub fn apply_changeset(self, source: DataSubjectRequest) -> DataSubjectRequest {
+        DataSubjectRequest {
+            customer_id: self.customer_id.or(source.customer_id),
+            email_hash: self.email_hash.unwrap_or(source.email_hash),
+            status: self.status.unwrap_or(source.status),
+            file_key: self.file_key.unwrap_or(source.file_key),
+            summary: self.summary.or(source.summary),
+            error_message: self.error_message.or(source.error_message),
+            modified_at: self.modified_at,
+            completed_at: self.completed_at.or(source.completed_at),
+            erased_object_ids: self.erased_object_ids.or(source.erased_object_ids),
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DataSubjectRequestTrackingData {
+    pub request_id: String,
+    pub merchant_id: id_type::MerchantId,
+}
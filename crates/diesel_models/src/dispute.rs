@@ -1,7 +1,7 @@
 use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use masking::Secret;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::dispute};
@@ -145,3 +145,15 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DisputeDeadlineTrackingData {
+    pub dispute_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub challenge_required_by: PrimitiveDateTime,
+    // Hours prior to the evidence deadline at which reminders have to be sent, in descending order.
+    pub reminder_offsets_in_hours: Vec<u16>,
+    // Index of the reminder offset which is scheduled next
+    pub reminder_index: usize,
+}
@@ -36,10 +36,11 @@ pub struct EventNew {
 #[diesel(table_name = events)]
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
+    pub request: Option<Encryption>,
     pub response: Option<Encryption>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Insertable, Queryable, Selectable)]
 #[diesel(table_name = events, primary_key(event_id), check_for_backend(diesel::pg::Pg))]
 pub struct Event {
     pub event_id: String,
@@ -117,6 +118,9 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    Invoice {
+        invoice_id: String,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
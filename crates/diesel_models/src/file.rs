@@ -80,3 +80,16 @@ impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
         }
     }
 }
+
+impl FileMetadataUpdateInternal {
+    pub fn apply_changeset(self, source: FileMetadata) -> FileMetadata {
+        FileMetadata {
+            provider_file_id: self.provider_file_id.or(source.provider_file_id),
+            file_upload_provider: self.file_upload_provider.or(source.file_upload_provider),
+            available: self.available,
+            profile_id: self.profile_id.or(source.profile_id),
+            merchant_connector_id: self.merchant_connector_id.or(source.merchant_connector_id),
+            ..source
+        }
+    }
+}
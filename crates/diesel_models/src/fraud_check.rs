@@ -116,3 +116,21 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
         }
     }
 }
+
+impl FraudCheckUpdateInternal {
+    pub fn apply_changeset(self, source: FraudCheck) -> FraudCheck {
+        FraudCheck {
+            frm_status: self.frm_status.unwrap_or(source.frm_status),
+            frm_transaction_id: self.frm_transaction_id.or(source.frm_transaction_id),
+            frm_reason: self.frm_reason.or(source.frm_reason),
+            frm_score: self.frm_score.or(source.frm_score),
+            frm_error: self.frm_error.unwrap_or(source.frm_error),
+            metadata: self.metadata.or(source.metadata),
+            last_step: self.last_step,
+            payment_capture_method: self
+                .payment_capture_method
+                .or(source.payment_capture_method),
+            ..source
+        }
+    }
+}
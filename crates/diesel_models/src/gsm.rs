@@ -7,7 +7,7 @@ use common_utils::{
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use time::PrimitiveDateTime;
 
-use crate::schema::gateway_status_map;
+use crate::schema::{gateway_status_map, gateway_status_map_history};
 
 #[derive(
     Clone,
@@ -78,8 +78,8 @@ pub struct GatewayStatusMapperUpdateInternal {
     pub router_error: Option<Option<String>>,
     pub decision: Option<String>,
     pub step_up_possible: Option<bool>,
-    pub unified_code: Option<String>,
-    pub unified_message: Option<String>,
+    pub unified_code: Option<Option<String>>,
+    pub unified_message: Option<Option<String>>,
 }
 
 #[derive(Debug)]
@@ -88,8 +88,8 @@ pub struct GatewayStatusMappingUpdate {
     pub router_error: Option<Option<String>>,
     pub decision: Option<String>,
     pub step_up_possible: Option<bool>,
-    pub unified_code: Option<String>,
-    pub unified_message: Option<String>,
+    pub unified_code: Option<Option<String>>,
+    pub unified_message: Option<Option<String>>,
 }
 
 impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
@@ -114,6 +114,94 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
     }
 }
 
+/// The attributes of a gateway status mapping that can change without changing its key.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GatewayStatusMapAttributes {
+    pub status: String,
+    pub router_error: Option<String>,
+    pub decision: String,
+    pub step_up_possible: bool,
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+}
+
+impl From<&GatewayStatusMap> for GatewayStatusMapAttributes {
+    fn from(value: &GatewayStatusMap) -> Self {
+        Self {
+            status: value.status.clone(),
+            router_error: value.router_error.clone(),
+            decision: value.decision.clone(),
+            step_up_possible: value.step_up_possible,
+            unified_code: value.unified_code.clone(),
+            unified_message: value.unified_message.clone(),
+        }
+    }
+}
+
+impl From<&GatewayStatusMappingNew> for GatewayStatusMapAttributes {
+    fn from(value: &GatewayStatusMappingNew) -> Self {
+        Self {
+            status: value.status.clone(),
+            router_error: value.router_error.clone(),
+            decision: value.decision.clone(),
+            step_up_possible: value.step_up_possible,
+            unified_code: value.unified_code.clone(),
+            unified_message: value.unified_message.clone(),
+        }
+    }
+}
+
+impl From<GatewayStatusMapAttributes> for GatewayStatusMappingUpdate {
+    fn from(value: GatewayStatusMapAttributes) -> Self {
+        Self {
+            status: Some(value.status),
+            router_error: Some(value.router_error),
+            decision: Some(value.decision),
+            step_up_possible: Some(value.step_up_possible),
+            unified_code: Some(value.unified_code),
+            unified_message: Some(value.unified_message),
+        }
+    }
+}
+
+/// A single entry of the gateway status mapping change log. All the entries written by one
+/// create, update, delete, import or revert operation share the same `change_id`.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = gateway_status_map_history, primary_key(change_id, sequence_number), check_for_backend(diesel::pg::Pg))]
+pub struct GatewayStatusMapHistory {
+    pub change_id: String,
+    pub sequence_number: i32,
+    pub source: String,
+    pub action: String,
+    pub connector: String,
+    pub flow: String,
+    pub sub_flow: String,
+    pub code: String,
+    pub message: String,
+    pub previous_rule: Option<serde_json::Value>,
+    pub current_rule: Option<serde_json::Value>,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = gateway_status_map_history)]
+pub struct GatewayStatusMapHistoryNew {
+    pub change_id: String,
+    pub sequence_number: i32,
+    pub source: String,
+    pub action: String,
+    pub connector: String,
+    pub flow: String,
+    pub sub_flow: String,
+    pub code: String,
+    pub message: String,
+    pub previous_rule: Option<serde_json::Value>,
+    pub current_rule: Option<serde_json::Value>,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
 impl ApiEventMetric for GatewayStatusMap {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Gsm)
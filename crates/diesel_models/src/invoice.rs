@@ -0,0 +1,482 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{invoice, invoice_line_item, invoice_number_sequence, invoice_payment},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = invoice,
+    primary_key(invoice_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct Invoice {
+    pub invoice_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: String,
+    pub customer_id: id_type::CustomerId,
+    /// Number of the invoice, assigned from the number sequence of the profile on finalization
+    pub invoice_number: Option<String>,
+    pub status: storage_enums::InvoiceStatus,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    /// Discount applied to the invoice as a whole
+    pub discount: Option<serde_json::Value>,
+    /// Tax amounts of the invoice, grouped by tax rate
+    pub tax_lines: serde_json::Value,
+    pub subtotal: MinorUnit,
+    pub discount_amount: MinorUnit,
+    pub tax_amount: MinorUnit,
+    pub total: MinorUnit,
+    pub amount_paid: MinorUnit,
+    pub due_date: Option<PrimitiveDateTime>,
+    /// Days relative to the due date on which reminders are sent to the customer
+    pub reminder_schedule: Option<serde_json::Value>,
+    /// Payment created for the invoice on finalization, paid through the payment link
+    pub payment_id: Option<String>,
+    pub payment_link: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub finalized_at: Option<PrimitiveDateTime>,
+    pub paid_at: Option<PrimitiveDateTime>,
+    pub voided_at: Option<PrimitiveDateTime>,
+    pub marked_uncollectible_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl Invoice {
+    /// The amount of the invoice that is yet to be paid
+    pub fn get_amount_remaining(&self) -> MinorUnit {
+        MinorUnit::new(
+            self.total
+                .get_amount_as_i64()
+                .saturating_sub(self.amount_paid.get_amount_as_i64())
+                .max(0),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = invoice)]
+pub struct InvoiceNew {
+    pub invoice_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: String,
+    pub customer_id: id_type::CustomerId,
+    pub invoice_number: Option<String>,
+    pub status: storage_enums::InvoiceStatus,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub discount: Option<serde_json::Value>,
+    pub tax_lines: serde_json::Value,
+    pub subtotal: MinorUnit,
+    pub discount_amount: MinorUnit,
+    pub tax_amount: MinorUnit,
+    pub total: MinorUnit,
+    pub amount_paid: MinorUnit,
+    pub due_date: Option<PrimitiveDateTime>,
+    pub reminder_schedule: Option<serde_json::Value>,
+    pub payment_id: Option<String>,
+    pub payment_link: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub finalized_at: Option<PrimitiveDateTime>,
+    pub paid_at: Option<PrimitiveDateTime>,
+    pub voided_at: Option<PrimitiveDateTime>,
+    pub marked_uncollectible_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = invoice)]
+pub struct InvoiceUpdateInternal {
+    status: Option<storage_enums::InvoiceStatus>,
+    invoice_number: Option<String>,
+    description: Option<String>,
+    discount: Option<serde_json::Value>,
+    tax_lines: Option<serde_json::Value>,
+    subtotal: Option<MinorUnit>,
+    discount_amount: Option<MinorUnit>,
+    tax_amount: Option<MinorUnit>,
+    total: Option<MinorUnit>,
+    due_date: Option<PrimitiveDateTime>,
+    reminder_schedule: Option<serde_json::Value>,
+    payment_id: Option<String>,
+    payment_link: Option<String>,
+    metadata: Option<serde_json::Value>,
+    finalized_at: Option<PrimitiveDateTime>,
+    paid_at: Option<PrimitiveDateTime>,
+    voided_at: Option<PrimitiveDateTime>,
+    marked_uncollectible_at: Option<PrimitiveDateTime>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum InvoiceUpdate {
+    DraftUpdate {
+        description: Option<String>,
+        discount: Option<serde_json::Value>,
+        tax_lines: serde_json::Value,
+        subtotal: MinorUnit,
+        discount_amount: MinorUnit,
+        tax_amount: MinorUnit,
+        total: MinorUnit,
+        due_date: Option<PrimitiveDateTime>,
+        reminder_schedule: Option<serde_json::Value>,
+        metadata: Option<serde_json::Value>,
+    },
+    Finalize {
+        invoice_number: String,
+        finalized_at: PrimitiveDateTime,
+    },
+    PaymentLinkUpdate {
+        payment_id: String,
+        payment_link: Option<String>,
+    },
+    Paid {
+        paid_at: PrimitiveDateTime,
+    },
+    Voided {
+        voided_at: PrimitiveDateTime,
+    },
+    MarkedUncollectible {
+        marked_uncollectible_at: PrimitiveDateTime,
+    },
+}
+
+impl From<InvoiceUpdate> for InvoiceUpdateInternal {
+    fn from(value: InvoiceUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match value {
+            InvoiceUpdate::DraftUpdate {
+                description,
+                discount,
+                tax_lines,
+                subtotal,
+                discount_amount,
+                tax_amount,
+                total,
+                due_date,
+                reminder_schedule,
+                metadata,
+            } => Self {
+                description,
+                discount,
+                tax_lines: Some(tax_lines),
+                subtotal: Some(subtotal),
+                discount_amount: Some(discount_amount),
+                tax_amount: Some(tax_amount),
+                total: Some(total),
+                due_date,
+                reminder_schedule,
+                metadata,
+                modified_at,
+                ..Default::default()
+            },
+            InvoiceUpdate::Finalize {
+                invoice_number,
+                finalized_at,
+            } => Self {
+                status: Some(storage_enums::InvoiceStatus::Open),
+                invoice_number: Some(invoice_number),
+                finalized_at: Some(finalized_at),
+                modified_at,
+                ..Default::default()
+            },
+            InvoiceUpdate::PaymentLinkUpdate {
+                payment_id,
+                payment_link,
+            } => Self {
+                payment_id: Some(payment_id),
+                payment_link,
+                modified_at,
+                ..Default::default()
+            },
+            InvoiceUpdate::Paid { paid_at } => Self {
+                status: Some(storage_enums::InvoiceStatus::Paid),
+                paid_at: Some(paid_at),
+                modified_at,
+                ..Default::default()
+            },
+            InvoiceUpdate::Voided { voided_at } => Self {
+                status: Some(storage_enums::InvoiceStatus::Void),
+                voided_at: Some(voided_at),
+                modified_at,
+                ..Default::default()
+            },
+            InvoiceUpdate::MarkedUncollectible {
+                marked_uncollectible_at,
+            } => Self {
+                status: Some(storage_enums::InvoiceStatus::Uncollectible),
+                marked_uncollectible_at: Some(marked_uncollectible_at),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl InvoiceUpdateInternal {
+    pub fn apply_changeset(self, source: Invoice) -> Invoice {
+        Invoice {
+            status: self.status.unwrap_or(source.status),
+            invoice_number: self.invoice_number.or(source.invoice_number),
+            description: self.description.or(source.description),
+            discount: self.discount.or(source.discount),
+            tax_lines: self.tax_lines.unwrap_or(source.tax_lines),
+            subtotal: self.subtotal.unwrap_or(source.subtotal),
+            discount_amount: self.discount_amount.unwrap_or(source.discount_amount),
+            tax_amount: self.tax_amount.unwrap_or(source.tax_amount),
+            total: self.total.unwrap_or(source.total),
+            due_date: self.due_date.or(source.due_date),
+            reminder_schedule: self.reminder_schedule.or(source.reminder_schedule),
+            payment_id: self.payment_id.or(source.payment_id),
+            payment_link: self.payment_link.or(source.payment_link),
+            metadata: self.metadata.or(source.metadata),
+            finalized_at: self.finalized_at.or(source.finalized_at),
+            paid_at: self.paid_at.or(source.paid_at),
+            voided_at: self.voided_at.or(source.voided_at),
+            marked_uncollectible_at: self
+                .marked_uncollectible_at
+                .or(source.marked_uncollectible_at),
+            modified_at: self.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = invoice_line_item,
+    primary_key(invoice_id, line_item_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct InvoiceLineItem {
+    pub invoice_id: String,
+    pub line_item_id: String,
+    /// Position of the line item on the invoice, starting from zero
+    pub position: i32,
+    pub description: String,
+    pub quantity: i32,
+    pub unit_amount: MinorUnit,
+    /// Amount of the line item before discounts and taxes
+    pub amount: MinorUnit,
+    pub discount: Option<serde_json::Value>,
+    /// Discount applied to the line item, including its share of the invoice discount
+    pub discount_amount: MinorUnit,
+    pub tax_rates: serde_json::Value,
+    pub tax_amount: MinorUnit,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = invoice_line_item)]
+pub struct InvoiceLineItemNew {
+    pub invoice_id: String,
+    pub line_item_id: String,
+    pub position: i32,
+    pub description: String,
+    pub quantity: i32,
+    pub unit_amount: MinorUnit,
+    pub amount: MinorUnit,
+    pub discount: Option<serde_json::Value>,
+    pub discount_amount: MinorUnit,
+    pub tax_rates: serde_json::Value,
+    pub tax_amount: MinorUnit,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = invoice_number_sequence,
+    primary_key(profile_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct InvoiceNumberSequence {
+    pub profile_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub prefix: String,
+    /// Number assigned to the next invoice finalized for the profile
+    pub next_number: i64,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl InvoiceNumberSequence {
+    /// Formats the next number of the sequence as an invoice number
+    pub fn get_next_invoice_number(&self) -> String {
+        format!("{}{:06}", self.prefix, self.next_number)
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = invoice_number_sequence)]
+pub struct InvoiceNumberSequenceNew {
+    pub profile_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub prefix: String,
+    pub next_number: i64,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = invoice_number_sequence)]
+pub struct InvoiceNumberSequenceUpdateInternal {
+    prefix: Option<String>,
+    next_number: Option<i64>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum InvoiceNumberSequenceUpdate {
+    Update {
+        prefix: Option<String>,
+        next_number: Option<i64>,
+    },
+}
+
+impl From<InvoiceNumberSequenceUpdate> for InvoiceNumberSequenceUpdateInternal {
+    fn from(value: InvoiceNumberSequenceUpdate) -> Self {
+        match value {
+            InvoiceNumberSequenceUpdate::Update {
+                prefix,
+                next_number,
+            } => Self {
+                prefix,
+                next_number,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl InvoiceNumberSequenceUpdateInternal {
+    pub fn apply_changeset(self, source: InvoiceNumberSequence) -> InvoiceNumberSequence {
+        InvoiceNumberSequence {
+            prefix: self.prefix.unwrap_or(source.prefix),
+            next_number: self.next_number.unwrap_or(source.next_number),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = invoice_payment,
+    primary_key(payment_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct InvoicePayment {
+    /// ID of the payment intent, or a generated ID for payments received out of band
+    pub payment_id: String,
+    pub invoice_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub source: storage_enums::InvoicePaymentSource,
+    pub amount: MinorUnit,
+    pub amount_received: Option<MinorUnit>,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::IntentStatus,
+    /// Reference of the payment provided by the merchant, for payments received out of band
+    pub reference: Option<String>,
+    /// Whether the amount received was added to the amount paid of the invoice
+    pub credited: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = invoice_payment)]
+pub struct InvoicePaymentNew {
+    pub payment_id: String,
+    pub invoice_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub source: storage_enums::InvoicePaymentSource,
+    pub amount: MinorUnit,
+    pub amount_received: Option<MinorUnit>,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::IntentStatus,
+    pub reference: Option<String>,
+    pub credited: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = invoice_payment)]
+pub struct InvoicePaymentUpdateInternal {
+    status: storage_enums::IntentStatus,
+    amount_received: Option<MinorUnit>,
+    credited: Option<bool>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum InvoicePaymentUpdate {
+    StatusUpdate {
+        status: storage_enums::IntentStatus,
+    },
+    Credited {
+        status: storage_enums::IntentStatus,
+        amount_received: MinorUnit,
+    },
+}
+
+impl From<InvoicePaymentUpdate> for InvoicePaymentUpdateInternal {
+    fn from(value: InvoicePaymentUpdate) -> Self {
+        match value {
+            InvoicePaymentUpdate::StatusUpdate { status } => Self {
+                status,
+                amount_received: None,
+                credited: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            InvoicePaymentUpdate::Credited {
+                status,
+                amount_received,
+            } => Self {
+                status,
+                amount_received: Some(amount_received),
+                credited: Some(true),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl InvoicePaymentUpdateInternal {
+    pub fn apply_changeset(self, source: InvoicePayment) -> InvoicePayment {
+        InvoicePayment {
+            status: self.status,
+            amount_received: self.amount_received.or(source.amount_received),
+            credited: self.credited.unwrap_or(source.credited),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InvoiceReminderTrackingData {
+    pub invoice_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub due_date: PrimitiveDateTime,
+    // Days relative to the due date at which reminders have to be sent, in ascending order.
+    pub reminder_offsets_in_days: Vec<i16>,
+    // Index of the reminder offset which is scheduled next
+    pub reminder_index: usize,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InvoicePaymentTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    // ID of the payment intent created for the invoice
+    pub payment_id: String,
+}
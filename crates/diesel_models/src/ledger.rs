@@ -0,0 +1,122 @@
+use common_utils::{custom_serde, id_type, types::MinorUnit};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{ledger_entry, ledger_posting},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = ledger_entry,
+    primary_key(entry_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct LedgerEntry {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub currency: storage_enums::Currency,
+    pub entry_type: storage_enums::LedgerEntryType,
+    /// ID of the record the entry was derived from, such as the payment attempt for captures or
+    /// the refund for refunds. An entry of a type is recorded only once for a record
+    pub source_id: String,
+    /// ID of the payment or payout the entry belongs to
+    pub reference_id: String,
+    pub description: Option<String>,
+    /// Time at which the event recorded by the entry took place
+    pub effective_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = ledger_entry)]
+pub struct LedgerEntryNew {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub currency: storage_enums::Currency,
+    pub entry_type: storage_enums::LedgerEntryType,
+    pub source_id: String,
+    pub reference_id: String,
+    pub description: Option<String>,
+    pub effective_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = ledger_posting,
+    primary_key(entry_id, line_number),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct LedgerPosting {
+    pub entry_id: String,
+    /// Position of the posting in its entry, starting from zero
+    pub line_number: i16,
+    pub merchant_id: id_type::MerchantId,
+    pub currency: storage_enums::Currency,
+    pub account: storage_enums::LedgerAccount,
+    pub direction: storage_enums::LedgerPostingDirection,
+    pub amount: MinorUnit,
+    /// Effective time of the entry of the posting
+    pub effective_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = ledger_posting)]
+pub struct LedgerPostingNew {
+    pub entry_id: String,
+    pub line_number: i16,
+    pub merchant_id: id_type::MerchantId,
+    pub currency: storage_enums::Currency,
+    pub account: storage_enums::LedgerAccount,
+    pub direction: storage_enums::LedgerPostingDirection,
+    pub amount: MinorUnit,
+    pub effective_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The records a ledger rebuild derives entries from, in the order in which they are processed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerRebuildStage {
+    Payments,
+    Payouts,
+}
+
+/// Progress of the rebuild of the ledger of a merchant, recorded on its process tracker task
+/// after every batch
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LedgerRebuildTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    /// The entries recorded before this time are replaced by the rebuild, those recorded since
+    /// are kept
+    #[serde(with = "custom_serde::iso8601")]
+    pub started_at: PrimitiveDateTime,
+    pub stage: LedgerRebuildStage,
+    /// ID of the last payment or payout of the stage whose entries were rebuilt
+    pub last_processed_id: Option<String>,
+    pub entries_recorded: usize,
+}
+
+impl LedgerPostingNew {
+    /// Whether the postings of an entry debit exactly the amount they credit
+    pub fn is_balanced(postings: &[Self]) -> bool {
+        let (debits, credits) = postings.iter().fold(
+            (0_i64, 0_i64),
+            |(debits, credits), posting| match posting.direction {
+                storage_enums::LedgerPostingDirection::Debit => (
+                    debits.saturating_add(posting.amount.get_amount_as_i64()),
+                    credits,
+                ),
+                storage_enums::LedgerPostingDirection::Credit => (
+                    debits,
+                    credits.saturating_add(posting.amount.get_amount_as_i64()),
+                ),
+            },
+        );
+        !postings.is_empty() && debits == credits
+    }
+}
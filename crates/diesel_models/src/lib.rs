@@ -11,6 +11,8 @@ pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod customers;
+pub mod data_retention;
+pub mod data_subject_request;
 pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
@@ -21,12 +23,15 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod invoice;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_rotation;
 pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_attempt;
@@ -34,13 +39,17 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
+pub mod refund_batch;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod scim;
 
 #[allow(unused_qualifications)]
 pub mod schema;
@@ -51,6 +60,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_signing_key;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
@@ -98,6 +98,8 @@ pub enum MandateUpdate {
         payment_method_id: String,
         original_payment_id: Option<String>,
     },
+    /// Clears the details recorded when the customer accepted the mandate
+    CustomerAcceptanceRedaction,
 }
 
 impl MandateUpdate {
@@ -135,6 +137,8 @@ pub struct MandateUpdateInternal {
     payment_method_id: Option<String>,
     original_payment_id: Option<String>,
     updated_by: Option<String>,
+    customer_ip_address: Option<Option<Secret<String, pii::IpAddress>>>,
+    customer_user_agent: Option<Option<String>>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -148,6 +152,8 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                customer_ip_address: None,
+                customer_user_agent: None,
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
                 mandate_status: None,
@@ -157,6 +163,8 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                customer_ip_address: None,
+                customer_user_agent: None,
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids,
@@ -176,6 +184,11 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 original_payment_id,
                 ..Default::default()
             },
+            MandateUpdate::CustomerAcceptanceRedaction => Self {
+                customer_ip_address: Some(None),
+                customer_user_agent: Some(None),
+                ..Default::default()
+            },
         }
     }
 }
@@ -190,6 +203,8 @@ impl MandateUpdateInternal {
             payment_method_id,
             original_payment_id,
             updated_by,
+            customer_ip_address,
+            customer_user_agent,
         } = self;
 
         Mandate {
@@ -200,6 +215,8 @@ impl MandateUpdateInternal {
             payment_method_id: payment_method_id.unwrap_or(source.payment_method_id),
             original_payment_id: original_payment_id.map_or(source.original_payment_id, Some),
             updated_by: updated_by.map_or(source.updated_by, Some),
+            customer_ip_address: customer_ip_address.unwrap_or(source.customer_ip_address),
+            customer_user_agent: customer_user_agent.unwrap_or(source.customer_user_agent),
             ..source
         }
     }
@@ -0,0 +1,168 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::merchant_key_rotation};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = merchant_key_rotation,
+    primary_key(rotation_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct MerchantKeyRotation {
+    pub rotation_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub key_version: i32,
+    pub status: storage_enums::KeyRotationStatus,
+    pub current_table: Option<storage_enums::KeyRotationTable>,
+    pub last_processed_id: Option<String>,
+    pub rows_processed: i64,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_key_rotation)]
+pub struct MerchantKeyRotationNew {
+    pub rotation_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub key_version: i32,
+    pub status: storage_enums::KeyRotationStatus,
+    pub current_table: Option<storage_enums::KeyRotationTable>,
+    pub last_processed_id: Option<String>,
+    pub rows_processed: i64,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_key_rotation)]
+pub struct MerchantKeyRotationUpdateInternal {
+    status: Option<storage_enums::KeyRotationStatus>,
+    current_table: Option<Option<storage_enums::KeyRotationTable>>,
+    last_processed_id: Option<Option<String>>,
+    rows_processed: Option<i64>,
+    error_message: Option<Option<String>>,
+    modified_at: PrimitiveDateTime,
+    completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum MerchantKeyRotationUpdate {
+    /// Records the position up to which the rows were re-encrypted
+    ProgressUpdate {
+        current_table: storage_enums::KeyRotationTable,
+        last_processed_id: Option<String>,
+        rows_processed: i64,
+    },
+    /// Starts verifying the re-encrypted data from the first table, for the values written with
+    /// the previous key while the rows were re-encrypted
+    VerificationStarted {
+        current_table: storage_enums::KeyRotationTable,
+    },
+    Completed {
+        rows_processed: i64,
+        completed_at: PrimitiveDateTime,
+    },
+    Failed {
+        error_message: String,
+    },
+    /// Continues a failed rotation from the position up to which the rows were re-encrypted or
+    /// verified, the data is verified again once all the tables are re-encrypted
+    Resumed,
+}
+
+impl From<MerchantKeyRotationUpdate> for MerchantKeyRotationUpdateInternal {
+    fn from(value: MerchantKeyRotationUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            MerchantKeyRotationUpdate::ProgressUpdate {
+                current_table,
+                last_processed_id,
+                rows_processed,
+            } => Self {
+                status: None,
+                current_table: Some(Some(current_table)),
+                last_processed_id: Some(last_processed_id),
+                rows_processed: Some(rows_processed),
+                error_message: None,
+                modified_at,
+                completed_at: None,
+            },
+            MerchantKeyRotationUpdate::VerificationStarted { current_table } => Self {
+                status: Some(storage_enums::KeyRotationStatus::Verifying),
+                current_table: Some(Some(current_table)),
+                last_processed_id: Some(None),
+                rows_processed: None,
+                error_message: None,
+                modified_at,
+                completed_at: None,
+            },
+            MerchantKeyRotationUpdate::Completed {
+                rows_processed,
+                completed_at,
+            } => Self {
+                status: Some(storage_enums::KeyRotationStatus::Completed),
+                current_table: Some(None),
+                last_processed_id: Some(None),
+                rows_processed: Some(rows_processed),
+                error_message: None,
+                modified_at,
+                completed_at: Some(completed_at),
+            },
+            MerchantKeyRotationUpdate::Failed { error_message } => Self {
+                status: Some(storage_enums::KeyRotationStatus::Failed),
+                current_table: None,
+                last_processed_id: None,
+                rows_processed: None,
+                error_message: Some(Some(error_message)),
+                modified_at,
+                completed_at: None,
+            },
+            MerchantKeyRotationUpdate::Resumed => Self {
+                status: Some(storage_enums::KeyRotationStatus::InProgress),
+                current_table: None,
+                last_processed_id: None,
+                rows_processed: None,
+                error_message: Some(None),
+                modified_at,
+                completed_at: None,
+            },
+        }
+    }
+}
+
+impl MerchantKeyRotationUpdateInternal {
+    pub fn apply_changeset(self, source: MerchantKeyRotation) -> MerchantKeyRotation {
+        MerchantKeyRotation {
+            status: self.status.unwrap_or(source.status),
+            current_table: self.current_table.unwrap_or(source.current_table),
+            last_processed_id: self.last_processed_id.unwrap_or(source.last_processed_id),
+            rows_processed: self.rows_processed.unwrap_or(source.rows_processed),
+            error_message: self.error_message.unwrap_or(source.error_message),
+            modified_at: self.modified_at,
+            completed_at: self.completed_at.or(source.completed_at),
+            ..source
+        }
+    }
+}
+
+/// The encrypted columns of a row which is re-encrypted during a key rotation, in the order in
+/// which they are listed for the table
+#[derive(Clone, Debug)]
+pub struct EncryptedRow {
+    pub id: String,
+    pub columns: Vec<Option<Encryption>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MerchantKeyRotationTrackingData {
+    pub rotation_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
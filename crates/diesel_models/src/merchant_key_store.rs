@@ -20,6 +20,8 @@ pub struct MerchantKeyStore {
     pub key: Encryption,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    pub previous_key: Option<Encryption>,
 }
 
 #[derive(
@@ -30,6 +32,8 @@ pub struct MerchantKeyStoreNew {
     pub merchant_id: common_utils::id_type::MerchantId,
     pub key: Encryption,
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    pub previous_key: Option<Encryption>,
 }
 
 #[derive(
@@ -37,6 +41,49 @@ pub struct MerchantKeyStoreNew {
 )]
 #[diesel(table_name = merchant_key_store)]
 pub struct MerchantKeyStoreUpdateInternal {
-    pub merchant_id: common_utils::id_type::MerchantId,
-    pub key: Encryption,
+    pub key: Option<Encryption>,
+    pub key_version: Option<i32>,
+    pub previous_key: Option<Option<Encryption>>,
+}
+
+#[derive(Debug)]
+pub enum MerchantKeyStoreUpdate {
+    KeyRotated {
+        key: Encryption,
+        key_version: i32,
+        previous_key: Encryption,
+    },
+    PreviousKeyRetired,
+}
+
+impl From<MerchantKeyStoreUpdate> for MerchantKeyStoreUpdateInternal {
+    fn from(value: MerchantKeyStoreUpdate) -> Self {
+        match value {
+            MerchantKeyStoreUpdate::KeyRotated {
+                key,
+                key_version,
+                previous_key,
+            } => Self {
+                key: Some(key),
+                key_version: Some(key_version),
+                previous_key: Some(Some(previous_key)),
+            },
+            MerchantKeyStoreUpdate::PreviousKeyRetired => Self {
+                key: None,
+                key_version: None,
+                previous_key: Some(None),
+            },
+        }
+    }
+}
+
+impl MerchantKeyStoreUpdateInternal {
+    pub fn apply_changeset(self, source: MerchantKeyStore) -> MerchantKeyStore {
+        MerchantKeyStore {
+            key: self.key.unwrap_or(source.key),
+            key_version: self.key_version.unwrap_or(source.key_version),
+            previous_key: self.previous_key.unwrap_or(source.previous_key),
+            ..source
+        }
+    }
 }
@@ -11,7 +11,16 @@ use crate::schema_v2::payment_attempt;
 
 #[cfg(all(feature = "v2", feature = "payment_v2"))]
 #[derive(
-    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize, Selectable,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Identifiable,
+    Insertable,
+    Queryable,
+    Serialize,
+    Deserialize,
+    Selectable,
 )]
 #[diesel(table_name = payment_attempt, primary_key(attempt_id, merchant_id), check_for_backend(diesel::pg::Pg))]
 pub struct PaymentAttempt {
@@ -78,11 +87,21 @@ pub struct PaymentAttempt {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "payment_v2")))]
 #[derive(
-    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize, Selectable,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Identifiable,
+    Insertable,
+    Queryable,
+    Serialize,
+    Deserialize,
+    Selectable,
 )]
 #[diesel(table_name = payment_attempt, primary_key(attempt_id, merchant_id), check_for_backend(diesel::pg::Pg))]
 pub struct PaymentAttempt {
@@ -149,6 +168,7 @@ pub struct PaymentAttempt {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
 }
 
 impl PaymentAttempt {
@@ -231,6 +251,7 @@ pub struct PaymentAttemptNew {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
 }
 
 impl PaymentAttemptNew {
@@ -271,6 +292,7 @@ pub enum PaymentAttemptUpdate {
         fingerprint_id: Option<String>,
         payment_method_billing_address_id: Option<String>,
         updated_by: String,
+        sca_exemption: Option<storage_enums::ScaExemptionType>,
     },
     UpdateTrackers {
         payment_token: Option<String>,
@@ -317,6 +339,7 @@ pub enum PaymentAttemptUpdate {
         client_source: Option<String>,
         client_version: Option<String>,
         customer_acceptance: Option<pii::SecretSerdeValue>,
+        sca_exemption: Option<storage_enums::ScaExemptionType>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -488,6 +511,7 @@ pub struct PaymentAttemptUpdateInternal {
     client_source: Option<String>,
     client_version: Option<String>,
     customer_acceptance: Option<pii::SecretSerdeValue>,
+    sca_exemption: Option<Option<storage_enums::ScaExemptionType>>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -557,6 +581,7 @@ impl PaymentAttemptUpdate {
             client_source,
             client_version,
             customer_acceptance,
+            sca_exemption,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -609,6 +634,7 @@ impl PaymentAttemptUpdate {
             client_source: client_source.or(source.client_source),
             client_version: client_version.or(source.client_version),
             customer_acceptance: customer_acceptance.or(source.customer_acceptance),
+            sca_exemption: sca_exemption.unwrap_or(source.sca_exemption),
             ..source
         }
     }
@@ -636,6 +662,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 fingerprint_id,
                 updated_by,
                 payment_method_billing_address_id,
+                sca_exemption,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -684,6 +711,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: Some(sca_exemption),
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -735,6 +763,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -767,6 +796,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source,
                 client_version,
                 customer_acceptance,
+                sca_exemption,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -799,6 +829,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source,
                 client_version,
                 customer_acceptance,
+                sca_exemption: Some(sca_exemption),
                 net_amount: None,
                 connector_transaction_id: None,
                 amount_to_capture: None,
@@ -866,6 +897,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -919,6 +951,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -972,6 +1005,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -1023,6 +1057,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -1092,6 +1127,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::ErrorUpdate {
                 connector,
@@ -1153,6 +1189,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
                 status: Some(status),
@@ -1201,6 +1238,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -1258,6 +1296,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -1316,6 +1355,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::PreprocessingUpdate {
                 status,
@@ -1372,6 +1412,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::CaptureUpdate {
                 multiple_capture_count,
@@ -1424,6 +1465,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -1476,6 +1518,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -1531,6 +1574,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
                 amount,
@@ -1582,6 +1626,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -1636,6 +1681,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -1692,6 +1738,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                sca_exemption: None,
             },
         }
     }
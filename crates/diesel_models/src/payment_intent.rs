@@ -11,7 +11,9 @@ use crate::schema::payment_intent;
 use crate::schema_v2::payment_intent;
 
 #[cfg(all(feature = "v2", feature = "payment_v2"))]
-#[derive(Clone, Debug, PartialEq, Identifiable, Queryable, Serialize, Deserialize, Selectable)]
+#[derive(
+    Clone, Debug, PartialEq, Identifiable, Insertable, Queryable, Serialize, Deserialize, Selectable,
+)]
 #[diesel(table_name = payment_intent, primary_key(payment_id, merchant_id), check_for_backend(diesel::pg::Pg))]
 pub struct PaymentIntent {
     pub payment_id: String,
@@ -69,10 +71,15 @@ pub struct PaymentIntent {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub tax_details: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub split_payments: Option<serde_json::Value>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "payment_v2")))]
-#[derive(Clone, Debug, PartialEq, Identifiable, Queryable, Serialize, Deserialize, Selectable)]
+#[derive(
+    Clone, Debug, PartialEq, Identifiable, Insertable, Queryable, Serialize, Deserialize, Selectable,
+)]
 #[diesel(table_name = payment_intent, primary_key(payment_id, merchant_id), check_for_backend(diesel::pg::Pg))]
 pub struct PaymentIntent {
     pub payment_id: String,
@@ -130,6 +137,9 @@ pub struct PaymentIntent {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub tax_details: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub split_payments: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -190,6 +200,9 @@ pub struct PaymentIntentNew {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub tax_details: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub split_payments: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -265,6 +278,20 @@ pub enum PaymentIntentUpdate {
         status: Option<storage_enums::IntentStatus>,
         updated_by: String,
     },
+    CustomerDetailsRedaction {
+        customer_details: Option<Encryption>,
+        billing_details: Option<Encryption>,
+        shipping_details: Option<Encryption>,
+        updated_by: String,
+    },
+    TaxDetailsUpdate {
+        tax_details: serde_json::Value,
+        updated_by: String,
+    },
+    SplitPaymentsUpdate {
+        split_payments: serde_json::Value,
+        updated_by: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -295,6 +322,8 @@ pub struct PaymentIntentUpdateFields {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub tax_details: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -336,6 +365,9 @@ pub struct PaymentIntentUpdateInternal {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub tax_details: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub split_payments: Option<serde_json::Value>,
 }
 
 impl PaymentIntentUpdate {
@@ -376,6 +408,9 @@ impl PaymentIntentUpdate {
             merchant_order_reference_id,
             shipping_details,
             is_payment_processor_token_flow,
+            tax_details,
+            l2_l3_data,
+            split_payments,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -420,6 +455,9 @@ impl PaymentIntentUpdate {
             shipping_details: shipping_details.or(source.shipping_details),
             is_payment_processor_token_flow: is_payment_processor_token_flow
                 .or(source.is_payment_processor_token_flow),
+            tax_details: tax_details.or(source.tax_details),
+            l2_l3_data: l2_l3_data.or(source.l2_l3_data),
+            split_payments: split_payments.or(source.split_payments),
             ..source
         }
     }
@@ -467,6 +505,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::Update(value) => Self {
                 amount: Some(value.amount),
@@ -505,6 +546,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 incremental_authorization_allowed: None,
                 authorization_count: None,
                 is_payment_processor_token_flow: value.is_payment_processor_token_flow,
+                tax_details: value.tax_details,
+                l2_l3_data: value.l2_l3_data,
+                split_payments: None,
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
                 return_url,
@@ -550,6 +594,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::PGStatusUpdate {
                 status,
@@ -591,6 +638,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
@@ -633,6 +683,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::ResponseUpdate {
                 // amount,
@@ -683,6 +736,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -724,6 +780,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::StatusAndAttemptUpdate {
                 status,
@@ -766,6 +825,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::ApproveUpdate {
                 status,
@@ -807,6 +869,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::RejectUpdate {
                 status,
@@ -848,6 +913,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::SurchargeApplicableUpdate {
                 surcharge_applicable,
@@ -888,6 +956,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
                 amount: Some(amount),
@@ -925,6 +996,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
@@ -964,6 +1038,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::CompleteAuthorizeUpdate {
                 shipping_address_id,
@@ -1003,6 +1080,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
             },
             PaymentIntentUpdate::ManualUpdate { status, updated_by } => Self {
                 status,
@@ -1040,6 +1120,140 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
+            },
+            PaymentIntentUpdate::CustomerDetailsRedaction {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+                amount: None,
+                currency: None,
+                status: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                merchant_order_reference_id: None,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
+            },
+            PaymentIntentUpdate::TaxDetailsUpdate {
+                tax_details,
+                updated_by,
+            } => Self {
+                tax_details: Some(tax_details),
+                l2_l3_data: None,
+                split_payments: None,
+                updated_by,
+                amount: None,
+                currency: None,
+                status: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
+            },
+            PaymentIntentUpdate::SplitPaymentsUpdate {
+                split_payments,
+                updated_by,
+            } => Self {
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: Some(split_payments),
+                updated_by,
+                amount: None,
+                currency: None,
+                status: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
             },
         }
     }
@@ -0,0 +1,253 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{payout_batch, payout_batch_item},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = payout_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub created_by: String,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<PrimitiveDateTime>,
+    pub review_comment: Option<String>,
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub created_by: String,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<PrimitiveDateTime>,
+    pub review_comment: Option<String>,
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    status: Option<storage_enums::PayoutBatchStatus>,
+    succeeded_count: Option<i32>,
+    failed_count: Option<i32>,
+    reviewed_by: Option<String>,
+    reviewed_at: Option<PrimitiveDateTime>,
+    review_comment: Option<String>,
+    scheduled_at: Option<PrimitiveDateTime>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchUpdate {
+    ReviewUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        reviewed_by: String,
+        review_comment: Option<String>,
+        scheduled_at: Option<PrimitiveDateTime>,
+    },
+    StatusUpdate {
+        status: storage_enums::PayoutBatchStatus,
+    },
+    ProgressUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        succeeded_count: i32,
+        failed_count: i32,
+    },
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(value: PayoutBatchUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            PayoutBatchUpdate::ReviewUpdate {
+                status,
+                reviewed_by,
+                review_comment,
+                scheduled_at,
+            } => Self {
+                status: Some(status),
+                succeeded_count: None,
+                failed_count: None,
+                reviewed_by: Some(reviewed_by),
+                reviewed_at: Some(modified_at),
+                review_comment,
+                scheduled_at,
+                modified_at,
+            },
+            PayoutBatchUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                succeeded_count: None,
+                failed_count: None,
+                reviewed_by: None,
+                reviewed_at: None,
+                review_comment: None,
+                scheduled_at: None,
+                modified_at,
+            },
+            PayoutBatchUpdate::ProgressUpdate {
+                status,
+                succeeded_count,
+                failed_count,
+            } => Self {
+                status: Some(status),
+                succeeded_count: Some(succeeded_count),
+                failed_count: Some(failed_count),
+                reviewed_by: None,
+                reviewed_at: None,
+                review_comment: None,
+                scheduled_at: None,
+                modified_at,
+            },
+        }
+    }
+}
+
+impl PayoutBatchUpdateInternal {
+    pub fn apply_changeset(self, source: PayoutBatch) -> PayoutBatch {
+        PayoutBatch {
+            status: self.status.unwrap_or(source.status),
+            succeeded_count: self.succeeded_count.unwrap_or(source.succeeded_count),
+            failed_count: self.failed_count.unwrap_or(source.failed_count),
+            reviewed_by: self.reviewed_by.or(source.reviewed_by),
+            reviewed_at: self.reviewed_at.or(source.reviewed_at),
+            review_comment: self.review_comment.or(source.review_comment),
+            scheduled_at: self.scheduled_at.or(source.scheduled_at),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = payout_batch_item,
+    primary_key(batch_id, line_number),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PayoutBatchItem {
+    pub batch_id: String,
+    pub line_number: i32,
+    pub customer_id: id_type::CustomerId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub payout_type: Option<storage_enums::PayoutType>,
+    pub payout_token: Option<String>,
+    pub description: Option<String>,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_id: Option<String>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemNew {
+    pub batch_id: String,
+    pub line_number: i32,
+    pub customer_id: id_type::CustomerId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub payout_type: Option<storage_enums::PayoutType>,
+    pub payout_token: Option<String>,
+    pub description: Option<String>,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_id: Option<String>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemUpdateInternal {
+    status: storage_enums::PayoutBatchItemStatus,
+    payout_id: Option<String>,
+    payout_status: Option<storage_enums::PayoutStatus>,
+    error_message: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchItemUpdate {
+    PayoutCreated {
+        status: storage_enums::PayoutBatchItemStatus,
+        payout_id: String,
+        payout_status: storage_enums::PayoutStatus,
+        error_message: Option<String>,
+    },
+    PayoutFailed {
+        error_message: String,
+    },
+}
+
+impl From<PayoutBatchItemUpdate> for PayoutBatchItemUpdateInternal {
+    fn from(value: PayoutBatchItemUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            PayoutBatchItemUpdate::PayoutCreated {
+                status,
+                payout_id,
+                payout_status,
+                error_message,
+            } => Self {
+                status,
+                payout_id: Some(payout_id),
+                payout_status: Some(payout_status),
+                error_message,
+                modified_at,
+            },
+            PayoutBatchItemUpdate::PayoutFailed { error_message } => Self {
+                status: storage_enums::PayoutBatchItemStatus::Failed,
+                payout_id: None,
+                payout_status: None,
+                error_message: Some(error_message),
+                modified_at,
+            },
+        }
+    }
+}
+
+impl PayoutBatchItemUpdateInternal {
+    pub fn apply_changeset(self, source: PayoutBatchItem) -> PayoutBatchItem {
+        PayoutBatchItem {
+            status: self.status,
+            payout_id: self.payout_id.or(source.payout_id),
+            payout_status: self.payout_status.or(source.payout_status),
+            error_message: self.error_message.or(source.error_message),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PayoutBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub locale: String,
+}
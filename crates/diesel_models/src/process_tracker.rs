@@ -13,6 +13,7 @@ use crate::{enums as storage_enums, errors, schema::process_tracker, StorageResu
     PartialEq,
     Deserialize,
     Identifiable,
+    Insertable,
     Queryable,
     Selectable,
     Serialize,
@@ -189,6 +190,21 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
     }
 }
 
+impl ProcessTrackerUpdateInternal {
+    pub fn apply_changeset(self, source: ProcessTracker) -> ProcessTracker {
+        ProcessTracker {
+            name: self.name.or(source.name),
+            retry_count: self.retry_count.unwrap_or(source.retry_count),
+            schedule_time: self.schedule_time.or(source.schedule_time),
+            tracking_data: self.tracking_data.unwrap_or(source.tracking_data),
+            business_status: self.business_status.unwrap_or(source.business_status),
+            status: self.status.unwrap_or(source.status),
+            updated_at: self.updated_at.unwrap_or(source.updated_at),
+            ..source
+        }
+    }
+}
+
 #[derive(
     serde::Serialize,
     serde::Deserialize,
@@ -210,6 +226,16 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    RefundBatchWorkflow,
+    PayoutBatchWorkflow,
+    DisputeDeadlineWorkflow,
+    DataSubjectRequestWorkflow,
+    DataRetentionWorkflow,
+    MerchantKeyRotationWorkflow,
+    InvoiceReminderWorkflow,
+    TaxTransactionWorkflow,
+    InvoicePaymentWorkflow,
+    LedgerRebuildWorkflow,
 }
 
 #[cfg(test)]
@@ -12,6 +12,8 @@ pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
+pub mod data_subject_request;
 pub mod dispute;
 pub mod events;
 pub mod file;
@@ -19,10 +21,13 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod invoice;
+pub mod ledger;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_rotation;
 pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_attempt;
@@ -30,13 +35,18 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
+pub mod refund_batch;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod scim;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_signing_key;
@@ -1,6 +1,18 @@
-use diesel::associations::HasTable;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
 
-use crate::{cards_info::CardInfo, query::generics, PgPooledConn, StorageResult};
+use crate::{
+    cards_info::{
+        CardBinRange, CardBinRangeNew, CardBinRangeUpdate, CardBinRangeUpdateInternal, CardInfo,
+    },
+    errors,
+    query::generics::{self, db_metrics},
+    schema::card_bin_range::dsl,
+    PgPooledConn, StorageResult,
+};
 
 impl CardInfo {
     pub async fn find_by_iin(conn: &PgPooledConn, card_iin: &str) -> StorageResult<Option<Self>> {
@@ -11,3 +23,119 @@ impl CardInfo {
         .await
     }
 }
+
+impl CardBinRangeNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CardBinRange> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CardBinRange {
+    /// Finds the ranges of any length starting at or before `last_bin` and ending at or after
+    /// `first_bin`, comparing the BINs as strings. These include every range sharing a BIN with a
+    /// range lying between `first_bin` and `last_bin`.
+    pub async fn find_by_bins_between(
+        conn: &PgPooledConn,
+        first_bin: &str,
+        last_bin: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::bin_start
+                .le(last_bin.to_owned())
+                .and(dsl::bin_end.ge(first_bin.to_owned())),
+            None,
+            None,
+            Some(dsl::bin_start.asc()),
+        )
+        .await
+    }
+
+    /// Finds the ranges having as many digits as `card_bin` that contain it.
+    pub async fn find_by_containing_bin(
+        conn: &PgPooledConn,
+        card_bin: &str,
+    ) -> StorageResult<Vec<Self>> {
+        let bin_length = i32::try_from(card_bin.len())
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Invalid card bin length")?;
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::bin_length
+                .eq(bin_length)
+                .and(dsl::bin_start.le(card_bin.to_owned()))
+                .and(dsl::bin_end.ge(card_bin.to_owned())),
+            None,
+            None,
+            Some(dsl::bin_start.desc()),
+        )
+        .await
+    }
+
+    pub async fn list(
+        conn: &PgPooledConn,
+        bin_length: Option<i32>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .order((
+                dsl::bin_length.asc(),
+                dsl::bin_start.asc(),
+                dsl::bin_end.asc(),
+            ))
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(bin_length) = bin_length {
+            filter = filter.filter(dsl::bin_length.eq(bin_length));
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering card bin ranges")
+    }
+
+    pub async fn update_by_bin_start_bin_end(
+        conn: &PgPooledConn,
+        bin_start: &str,
+        bin_end: &str,
+        card_bin_range_update: CardBinRangeUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::bin_start
+                .eq(bin_start.to_owned())
+                .and(dsl::bin_end.eq(bin_end.to_owned())),
+            CardBinRangeUpdateInternal::from(card_bin_range_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_bin_start_bin_end(
+        conn: &PgPooledConn,
+        bin_start: &str,
+        bin_end: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::bin_start
+                .eq(bin_start.to_owned())
+                .and(dsl::bin_end.eq(bin_end.to_owned())),
+        )
+        .await
+    }
+}
@@ -67,6 +67,23 @@ impl Customer {
         .await
     }
 
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    pub async fn list_by_merchant_id_with_pagination(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at),
+        )
+        .await
+    }
+
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     pub async fn find_optional_by_merchant_id_merchant_reference_id(
         conn: &PgPooledConn,
@@ -0,0 +1,174 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use crate::{
+    data_retention::{
+        DataRetentionArchive, DataRetentionArchiveNew, DataRetentionArchiveUpdate,
+        DataRetentionArchiveUpdateInternal, DataRetentionPolicy, DataRetentionPolicyNew,
+        DataRetentionPolicyUpdate, DataRetentionPolicyUpdateInternal,
+    },
+    enums, errors,
+    query::generics::{self, db_metrics},
+    schema::{data_retention_archive, data_retention_policy},
+    PgPooledConn, StorageResult,
+};
+
+impl DataRetentionPolicyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DataRetentionPolicy> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DataRetentionPolicy {
+    pub async fn find_by_table_name(
+        conn: &PgPooledConn,
+        table_name: enums::DataRetentionTable,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            data_retention_policy::dsl::table_name.eq(table_name),
+        )
+        .await
+    }
+
+    pub async fn list_all(conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            data_retention_policy::dsl::table_name.ne_all(vec!["".to_string()]),
+            None,
+            None,
+            Some(data_retention_policy::dsl::table_name.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_table_name(
+        conn: &PgPooledConn,
+        table_name: enums::DataRetentionTable,
+        data_retention_policy_update: DataRetentionPolicyUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            data_retention_policy::dsl::table_name.eq(table_name),
+            DataRetentionPolicyUpdateInternal::from(data_retention_policy_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_table_name(
+        conn: &PgPooledConn,
+        table_name: enums::DataRetentionTable,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            data_retention_policy::dsl::table_name.eq(table_name),
+        )
+        .await
+    }
+}
+
+impl DataRetentionArchiveNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DataRetentionArchive> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DataRetentionArchive {
+    pub async fn find_by_archive_id(conn: &PgPooledConn, archive_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            data_retention_archive::dsl::archive_id.eq(archive_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn list(
+        conn: &PgPooledConn,
+        table_name: Option<enums::DataRetentionTable>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .order(data_retention_archive::dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(table_name) = table_name {
+            filter = filter.filter(data_retention_archive::dsl::table_name.eq(table_name));
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering data retention archives")
+    }
+
+    /// Lists the archives of the given tables which hold rows of the merchant, in the order of
+    /// their identifier. Archives without a merchant hold the rows of several merchants.
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        table_names: Vec<enums::DataRetentionTable>,
+        after_archive_id: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(
+                data_retention_archive::dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .or(data_retention_archive::dsl::merchant_id.is_null()),
+            )
+            .filter(data_retention_archive::dsl::table_name.eq_any(table_names))
+            .order(data_retention_archive::dsl::archive_id.asc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(after_archive_id) = after_archive_id {
+            filter = filter.filter(data_retention_archive::dsl::archive_id.gt(after_archive_id));
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering data retention archives of merchant")
+    }
+
+    pub async fn update_by_archive_id(
+        conn: &PgPooledConn,
+        archive_id: &str,
+        data_retention_archive_update: DataRetentionArchiveUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            data_retention_archive::dsl::archive_id.eq(archive_id.to_owned()),
+            DataRetentionArchiveUpdateInternal::from(data_retention_archive_update),
+        )
+        .await
+    }
+}
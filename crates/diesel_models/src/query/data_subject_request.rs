@@ -0,0 +1,92 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::id_type;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use crate::{
+    data_subject_request::{
+        DataSubjectRequest, DataSubjectRequestNew, DataSubjectRequestUpdate,
+        DataSubjectRequestUpdateInternal,
+    },
+    errors,
+    query::generics::{self, db_metrics},
+    schema::data_subject_request::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl DataSubjectRequestNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DataSubjectRequest> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DataSubjectRequest {
+    pub async fn find_by_merchant_id_request_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::request_id.eq(request_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(customer_id) = customer_id {
+            filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering data subject requests by merchant id")
+    }
+
+    pub async fn update_by_merchant_id_request_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+        data_subject_request_update: DataSubjectRequestUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::request_id.eq(request_id.to_owned())),
+            DataSubjectRequestUpdateInternal::from(data_subject_request_update),
+        )
+        .await
+    }
+}
@@ -1,10 +1,16 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{
-    associations::HasTable, BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods,
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    NullableExpressionMethods, QueryDsl,
 };
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
+    errors,
     events::{Event, EventNew, EventUpdateInternal},
+    query::generics::db_metrics,
     schema::events::dsl,
     PgPooledConn, StorageResult,
 };
@@ -222,4 +228,53 @@ impl Event {
         )
         .await
     }
+
+    /// Lists the oldest events created before the given time
+    pub async fn find_created_before(
+        conn: &PgPooledConn,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::created_at.lt(created_before),
+            Some(limit),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_event_ids(
+        conn: &PgPooledConn,
+        event_ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        let query =
+            diesel::delete(<Self as HasTable>::table().filter(dsl::event_id.eq_any(event_ids)));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting archived events")
+    }
+
+    /// Inserts events restored from an archive, skipping the ones which already exist
+    pub async fn insert_archived(conn: &PgPooledConn, rows: Vec<Self>) -> StorageResult<usize> {
+        let query = diesel::insert_into(<Self as HasTable>::table())
+            .values(rows)
+            .on_conflict_do_nothing();
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while restoring archived events")
+    }
 }
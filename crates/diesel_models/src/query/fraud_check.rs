@@ -65,4 +65,23 @@ impl FraudCheck {
         )
         .await
     }
+
+    pub async fn list_by_merchant_id_frm_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        frm_status: crate::enums::FraudCheckStatus,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::frm_status.eq(frm_status)),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
 }
@@ -1,8 +1,16 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
-use error_stack::report;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl, Table,
+};
+use error_stack::{report, ResultExt};
 
 use crate::{
-    errors, gsm::*, query::generics, schema::gateway_status_map::dsl, PgPooledConn, StorageResult,
+    errors,
+    gsm::*,
+    query::generics::{self, db_metrics},
+    schema::{gateway_status_map::dsl, gateway_status_map_history::dsl as history_dsl},
+    PgPooledConn, StorageResult,
 };
 
 impl GatewayStatusMappingNew {
@@ -32,6 +40,51 @@ impl GatewayStatusMap {
         .await
     }
 
+    pub async fn list_by_connector_flow(
+        conn: &PgPooledConn,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .order((
+                dsl::connector.asc(),
+                dsl::flow.asc(),
+                dsl::sub_flow.asc(),
+                dsl::code.asc(),
+                dsl::message.asc(),
+            ))
+            .into_boxed();
+
+        if let Some(connector) = connector {
+            filter = filter.filter(dsl::connector.eq(connector));
+        }
+        if let Some(flow) = flow {
+            filter = filter.filter(dsl::flow.eq(flow));
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering gsm rules by connector and flow")
+    }
+
+    pub async fn list_by_connectors(
+        conn: &PgPooledConn,
+        connectors: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::connector.eq_any(connectors), None, None, None)
+        .await
+    }
+
     pub async fn retrieve_decision(
         conn: &PgPooledConn,
         connector: String,
@@ -98,3 +151,58 @@ impl GatewayStatusMap {
         .await
     }
 }
+
+impl GatewayStatusMapHistoryNew {
+    pub async fn batch_insert(entries: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, GatewayStatusMapHistory>(conn, entries).await?;
+        Ok(())
+    }
+}
+
+impl GatewayStatusMapHistory {
+    pub async fn find_by_change_id(
+        conn: &PgPooledConn,
+        change_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            history_dsl::change_id.eq(change_id.to_owned()),
+            None,
+            None,
+            Some(history_dsl::sequence_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_connector_flow(
+        conn: &PgPooledConn,
+        connector: Option<String>,
+        flow: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .order((
+                history_dsl::created_at.desc(),
+                history_dsl::change_id.asc(),
+                history_dsl::sequence_number.asc(),
+            ))
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(connector) = connector {
+            filter = filter.filter(history_dsl::connector.eq(connector));
+        }
+        if let Some(flow) = flow {
+            filter = filter.filter(history_dsl::flow.eq(flow));
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering gsm rule history by connector and flow")
+    }
+}
@@ -0,0 +1,269 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use crate::{
+    enums as storage_enums, errors,
+    invoice::{
+        Invoice, InvoiceLineItem, InvoiceLineItemNew, InvoiceNew, InvoiceNumberSequence,
+        InvoiceNumberSequenceNew, InvoiceNumberSequenceUpdate,
+        InvoiceNumberSequenceUpdateInternal, InvoicePayment, InvoicePaymentNew,
+        InvoicePaymentUpdate, InvoicePaymentUpdateInternal, InvoiceUpdate, InvoiceUpdateInternal,
+    },
+    query::generics::{self, db_metrics},
+    schema::{
+        invoice::dsl, invoice_line_item::dsl as line_item_dsl,
+        invoice_number_sequence::dsl as sequence_dsl, invoice_payment::dsl as payment_dsl,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl InvoiceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Invoice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Invoice {
+    pub async fn find_by_merchant_id_invoice_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::invoice_id.eq(invoice_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        status: Option<storage_enums::InvoiceStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(customer_id) = customer_id {
+            filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
+        }
+        if let Some(status) = status {
+            filter = filter.filter(dsl::status.eq(status));
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering invoices by merchant id")
+    }
+
+    /// Updates the invoice, provided that its status is still the status it was read with.
+    ///
+    /// Returns `NotFound` if the status of the invoice was changed concurrently.
+    pub async fn update_by_merchant_id_invoice_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        current_status: storage_enums::InvoiceStatus,
+        invoice_update: InvoiceUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::invoice_id.eq(invoice_id.to_owned()))
+                .and(dsl::status.eq(current_status)),
+            InvoiceUpdateInternal::from(invoice_update),
+        )
+        .await
+    }
+
+    pub async fn increment_amount_paid(
+        conn: &PgPooledConn,
+        invoice_id: &str,
+        amount: MinorUnit,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::invoice_id.eq(invoice_id.to_owned()),
+            (
+                dsl::amount_paid.eq(dsl::amount_paid + amount),
+                dsl::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+}
+
+impl InvoiceLineItemNew {
+    pub async fn batch_insert(line_items: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, InvoiceLineItem>(conn, line_items).await?;
+        Ok(())
+    }
+}
+
+impl InvoiceLineItem {
+    pub async fn list_by_invoice_id(
+        conn: &PgPooledConn,
+        invoice_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            line_item_dsl::invoice_id.eq(invoice_id.to_owned()),
+            None,
+            None,
+            Some(line_item_dsl::position.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_invoice_id(conn: &PgPooledConn, invoice_id: &str) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            line_item_dsl::invoice_id.eq(invoice_id.to_owned()),
+        )
+        .await
+    }
+}
+
+impl InvoiceNumberSequenceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<InvoiceNumberSequence> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl InvoiceNumberSequence {
+    pub async fn find_by_profile_id(conn: &PgPooledConn, profile_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            sequence_dsl::profile_id.eq(profile_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn update_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        invoice_number_sequence_update: InvoiceNumberSequenceUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            sequence_dsl::profile_id.eq(profile_id.to_owned()),
+            InvoiceNumberSequenceUpdateInternal::from(invoice_number_sequence_update),
+        )
+        .await
+    }
+
+    /// Increments the next number of the sequence, returning the sequence as it was before the
+    /// increment so that its next number can be assigned to an invoice.
+    ///
+    /// Returns `None` if no sequence exists for the profile.
+    pub async fn increment_next_number(
+        conn: &PgPooledConn,
+        profile_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, Self>(
+            conn,
+            sequence_dsl::profile_id.eq(profile_id.to_owned()),
+            (
+                sequence_dsl::next_number.eq(sequence_dsl::next_number + 1),
+                sequence_dsl::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+        .map(|sequences| {
+            sequences.into_iter().next().map(|sequence| Self {
+                next_number: sequence.next_number.saturating_sub(1),
+                ..sequence
+            })
+        })
+    }
+}
+
+impl InvoicePaymentNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<InvoicePayment> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl InvoicePayment {
+    pub async fn find_by_payment_id(conn: &PgPooledConn, payment_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            payment_dsl::payment_id.eq(payment_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn list_by_invoice_id(
+        conn: &PgPooledConn,
+        invoice_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            payment_dsl::invoice_id.eq(invoice_id.to_owned()),
+            None,
+            None,
+            Some(payment_dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Updates the payment, unless its amount was already added to the amount paid of the
+    /// invoice.
+    pub async fn update_uncredited_by_payment_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        invoice_payment_update: InvoicePaymentUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            payment_dsl::payment_id
+                .eq(payment_id.to_owned())
+                .and(payment_dsl::credited.eq(false)),
+            InvoicePaymentUpdateInternal::from(invoice_payment_update),
+        )
+        .await
+    }
+}
@@ -0,0 +1,223 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{
+    associations::HasTable, debug_query, dsl::sql, pg::Pg, sql_types::BigInt,
+    BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums, errors,
+    ledger::{LedgerEntry, LedgerEntryNew, LedgerPosting, LedgerPostingNew},
+    query::generics::{self, db_metrics},
+    schema::{ledger_entry::dsl, ledger_posting::dsl as posting_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl LedgerEntryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<LedgerEntry> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl LedgerEntry {
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        reference_id: Option<&str>,
+        currency: Option<storage_enums::Currency>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order((dsl::effective_at.desc(), dsl::created_at.desc()))
+            .into_boxed();
+
+        if let Some(reference_id) = reference_id {
+            filter = filter.filter(dsl::reference_id.eq(reference_id.to_owned()));
+        }
+        if let Some(currency) = currency {
+            filter = filter.filter(dsl::currency.eq(currency));
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering ledger entries by merchant id")
+    }
+
+    pub async fn list_by_merchant_id_entry_ids(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        entry_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::entry_id.eq_any(entry_ids)),
+            None,
+            None,
+            Some(dsl::effective_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_reference_ids(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        reference_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reference_id.eq_any(reference_ids)),
+            None,
+            None,
+            Some(dsl::effective_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_entry_ids(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        entry_ids: Vec<String>,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::entry_id.eq_any(entry_ids)),
+        )
+        .await
+    }
+}
+
+impl LedgerPostingNew {
+    pub async fn batch_insert(postings: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, LedgerPosting>(conn, postings).await?;
+        Ok(())
+    }
+}
+
+impl LedgerPosting {
+    pub async fn list_by_entry_ids(
+        conn: &PgPooledConn,
+        entry_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            posting_dsl::entry_id.eq_any(entry_ids),
+            None,
+            None,
+            Some(posting_dsl::line_number.asc()),
+        )
+        .await
+    }
+
+    /// Lists the postings made in the currency up to the given time, oldest first
+    pub async fn list_by_merchant_id_currency(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        currency: storage_enums::Currency,
+        account: Option<storage_enums::LedgerAccount>,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(
+                posting_dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(posting_dsl::currency.eq(currency)),
+            )
+            .order((
+                posting_dsl::effective_at.asc(),
+                posting_dsl::created_at.asc(),
+                posting_dsl::line_number.asc(),
+            ))
+            .into_boxed();
+
+        if let Some(account) = account {
+            filter = filter.filter(posting_dsl::account.eq(account));
+        }
+        if let Some(effective_until) = effective_until {
+            filter = filter.filter(posting_dsl::effective_at.le(effective_until));
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering ledger postings by merchant id and currency")
+    }
+
+    /// Sums the postings made in the currency up to the given time, per account and direction
+    pub async fn sum_by_merchant_id_currency(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        currency: storage_enums::Currency,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> StorageResult<
+        Vec<(
+            storage_enums::LedgerAccount,
+            storage_enums::LedgerPostingDirection,
+            MinorUnit,
+        )>,
+    > {
+        let query = <Self as HasTable>::table()
+            .filter(
+                posting_dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(posting_dsl::currency.eq(currency))
+                    .and(
+                        posting_dsl::effective_at
+                            .le(effective_until.unwrap_or(PrimitiveDateTime::MAX)),
+                    ),
+            )
+            .group_by((posting_dsl::account, posting_dsl::direction))
+            .select((
+                posting_dsl::account,
+                posting_dsl::direction,
+                sql::<BigInt>("CAST(SUM(amount) AS BIGINT)"),
+            ));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error summing ledger postings by merchant id and currency")
+    }
+
+    pub async fn delete_by_merchant_id_entry_ids(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        entry_ids: Vec<String>,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            posting_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(posting_dsl::entry_id.eq_any(entry_ids)),
+        )
+        .await
+    }
+}
@@ -0,0 +1,460 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::encryption::Encryption;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    NullableExpressionMethods, QueryDsl,
+};
+use error_stack::{report, ResultExt};
+
+use crate::{
+    enums, errors,
+    merchant_key_rotation::{
+        EncryptedRow, MerchantKeyRotation, MerchantKeyRotationNew, MerchantKeyRotationUpdate,
+        MerchantKeyRotationUpdateInternal,
+    },
+    query::generics::{self, db_metrics},
+    schema::merchant_key_rotation::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl MerchantKeyRotationNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<MerchantKeyRotation> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl MerchantKeyRotation {
+    pub async fn find_by_merchant_id_rotation_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        rotation_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::rotation_id.eq(rotation_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_rotation_id(
+        conn: &PgPooledConn,
+        rotation_id: &str,
+        merchant_key_rotation_update: MerchantKeyRotationUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::rotation_id.eq(rotation_id.to_owned()),
+            MerchantKeyRotationUpdateInternal::from(merchant_key_rotation_update),
+        )
+        .await
+    }
+}
+
+macro_rules! nullable_encryption {
+    ($column:ident) => {
+        Option<Encryption>
+    };
+}
+
+/// Generates the queries to read and write the encrypted columns of a table holding merchant data.
+/// Rows are read in the order of their identifier, so that a rotation can be resumed from the last
+/// processed row.
+macro_rules! impl_encrypted_table {
+    (
+        $schema:ident::$table:ident,
+        id: $id:ident,
+        merchant_id: $merchant_id:ident,
+        columns: [$($column:ident),+ $(,)?]
+    ) => {
+        mod $table {
+            use super::*;
+            use crate::$schema::$table::dsl as table_dsl;
+
+            pub(super) async fn find_batch(
+                conn: &PgPooledConn,
+                merchant_id: &common_utils::id_type::MerchantId,
+                after_id: Option<String>,
+                limit: i64,
+            ) -> StorageResult<Vec<EncryptedRow>> {
+                let mut query = table_dsl::$table
+                    .filter(table_dsl::$merchant_id.eq(merchant_id.to_owned()))
+                    .select((table_dsl::$id, $(table_dsl::$column.nullable()),+))
+                    .order(table_dsl::$id.asc())
+                    .limit(limit)
+                    .into_boxed();
+                if let Some(after_id) = after_id {
+                    query = query.filter(table_dsl::$id.gt(after_id));
+                }
+                router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+                let rows = db_metrics::track_database_call::<table_dsl::$table, _, _>(
+                    query.get_results_async::<(String, $(nullable_encryption!($column)),+)>(conn),
+                    db_metrics::DatabaseOperation::Filter,
+                )
+                .await
+                .change_context(errors::DatabaseError::Others)
+                .attach_printable(concat!(
+                    "Error filtering encrypted rows of ",
+                    stringify!($table)
+                ))?;
+
+                Ok(rows
+                    .into_iter()
+                    .map(|(id, $($column),+)| EncryptedRow {
+                        id,
+                        columns: vec![$($column),+],
+                    })
+                    .collect())
+            }
+
+            pub(super) async fn update_column(
+                conn: &PgPooledConn,
+                merchant_id: &common_utils::id_type::MerchantId,
+                id: &str,
+                column_index: usize,
+                current_value: Encryption,
+                updated_value: Encryption,
+            ) -> StorageResult<bool> {
+                let mut index = 0;
+                $(
+                    if index == column_index {
+                        // The current value is part of the predicate so that a value written by
+                        // the application in the meantime is not overwritten
+                        let query = diesel::update(
+                            table_dsl::$table
+                                .filter(table_dsl::$merchant_id.eq(merchant_id.to_owned()))
+                                .filter(table_dsl::$id.eq(id.to_owned()))
+                                .filter(table_dsl::$column.eq(current_value)),
+                        )
+                        .set(table_dsl::$column.eq(updated_value));
+                        router_env::logger::debug!(
+                            query = %debug_query::<Pg, _>(&query).to_string()
+                        );
+
+                        let updated_rows = db_metrics::track_database_call::<table_dsl::$table, _, _>(
+                            query.execute_async(conn),
+                            db_metrics::DatabaseOperation::Update,
+                        )
+                        .await
+                        .change_context(errors::DatabaseError::Others)
+                        .attach_printable_lazy(|| {
+                            format!(
+                                "Error updating encrypted column {} of {}",
+                                stringify!($column),
+                                stringify!($table)
+                            )
+                        })?;
+                        return Ok(updated_rows > 0);
+                    }
+                    index += 1;
+                )+
+
+                Err(errors::DatabaseError::Others).attach_printable_lazy(|| {
+                    format!(
+                        "Column index {column_index} is out of bounds for {} with {} encrypted columns",
+                        stringify!($table),
+                        index
+                    )
+                })
+            }
+        }
+    };
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "merchant_account_v2")
+))]
+impl_encrypted_table!(
+    schema::merchant_account,
+    id: merchant_id,
+    merchant_id: merchant_id,
+    columns: [merchant_name, merchant_details]
+);
+
+#[cfg(all(feature = "v2", feature = "merchant_account_v2"))]
+impl_encrypted_table!(
+    schema_v2::merchant_account,
+    id: id,
+    merchant_id: id,
+    columns: [merchant_name, merchant_details]
+);
+
+impl_encrypted_table!(
+    schema::business_profile,
+    id: profile_id,
+    merchant_id: merchant_id,
+    columns: [outgoing_webhook_custom_http_headers]
+);
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "merchant_connector_account_v2")
+))]
+impl_encrypted_table!(
+    schema::merchant_connector_account,
+    id: merchant_connector_id,
+    merchant_id: merchant_id,
+    columns: [
+        connector_account_details,
+        additional_merchant_data,
+        connector_wallets_details,
+    ]
+);
+
+#[cfg(all(feature = "v2", feature = "merchant_connector_account_v2"))]
+impl_encrypted_table!(
+    schema_v2::merchant_connector_account,
+    id: id,
+    merchant_id: merchant_id,
+    columns: [
+        connector_account_details,
+        additional_merchant_data,
+        connector_wallets_details,
+    ]
+);
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl_encrypted_table!(
+    schema::customers,
+    id: customer_id,
+    merchant_id: merchant_id,
+    columns: [name, email, phone]
+);
+
+#[cfg(all(feature = "v2", feature = "customer_v2"))]
+impl_encrypted_table!(
+    schema_v2::customers,
+    id: id,
+    merchant_id: merchant_id,
+    columns: [name, email, phone, default_billing_address, default_shipping_address]
+);
+
+impl_encrypted_table!(
+    schema::address,
+    id: address_id,
+    merchant_id: merchant_id,
+    columns: [
+        line1,
+        line2,
+        line3,
+        state,
+        zip,
+        first_name,
+        last_name,
+        phone_number,
+        email,
+    ]
+);
+
+impl_encrypted_table!(
+    schema::payment_methods,
+    id: payment_method_id,
+    merchant_id: merchant_id,
+    columns: [payment_method_data, payment_method_billing_address]
+);
+
+impl_encrypted_table!(
+    schema::payment_intent,
+    id: payment_id,
+    merchant_id: merchant_id,
+    columns: [customer_details, billing_details, shipping_details]
+);
+
+impl_encrypted_table!(
+    schema::events,
+    id: event_id,
+    merchant_id: merchant_id,
+    columns: [request, response]
+);
+
+impl_encrypted_table!(
+    schema::webhook_signing_key,
+    id: key_id,
+    merchant_id: merchant_id,
+    columns: [private_key]
+);
+
+impl EncryptedRow {
+    pub async fn find_batch(
+        conn: &PgPooledConn,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &common_utils::id_type::MerchantId,
+        after_id: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match table_name {
+            enums::KeyRotationTable::MerchantAccount => {
+                merchant_account::find_batch(conn, merchant_id, after_id, limit).await
+            }
+            enums::KeyRotationTable::BusinessProfile => {
+                business_profile::find_batch(conn, merchant_id, after_id, limit).await
+            }
+            enums::KeyRotationTable::MerchantConnectorAccount => {
+                merchant_connector_account::find_batch(conn, merchant_id, after_id, limit).await
+            }
+            enums::KeyRotationTable::Customers => {
+                customers::find_batch(conn, merchant_id, after_id, limit).await
+            }
+            enums::KeyRotationTable::Address => {
+                address::find_batch(conn, merchant_id, after_id, limit).await
+            }
+            enums::KeyRotationTable::PaymentMethods => {
+                payment_methods::find_batch(conn, merchant_id, after_id, limit).await
+            }
+            enums::KeyRotationTable::PaymentIntent => {
+                payment_intent::find_batch(conn, merchant_id, after_id, limit).await
+            }
+            enums::KeyRotationTable::Events => {
+                events::find_batch(conn, merchant_id, after_id, limit).await
+            }
+            enums::KeyRotationTable::WebhookSigningKey => {
+                webhook_signing_key::find_batch(conn, merchant_id, after_id, limit).await
+            }
+            enums::KeyRotationTable::DataRetentionArchive => {
+                Err(report!(errors::DatabaseError::Others))
+                    .attach_printable("Data retention archives are not stored in a database table")
+            }
+        }
+    }
+
+    /// Replaces the value of an encrypted column, returns `false` when the row was modified since
+    /// it was read
+    pub async fn update_column(
+        conn: &PgPooledConn,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+        column_index: usize,
+        current_value: Encryption,
+        updated_value: Encryption,
+    ) -> StorageResult<bool> {
+        match table_name {
+            enums::KeyRotationTable::MerchantAccount => {
+                merchant_account::update_column(
+                    conn,
+                    merchant_id,
+                    id,
+                    column_index,
+                    current_value,
+                    updated_value,
+                )
+                .await
+            }
+            enums::KeyRotationTable::BusinessProfile => {
+                business_profile::update_column(
+                    conn,
+                    merchant_id,
+                    id,
+                    column_index,
+                    current_value,
+                    updated_value,
+                )
+                .await
+            }
+            enums::KeyRotationTable::MerchantConnectorAccount => {
+                merchant_connector_account::update_column(
+                    conn,
+                    merchant_id,
+                    id,
+                    column_index,
+                    current_value,
+                    updated_value,
+                )
+                .await
+            }
+            enums::KeyRotationTable::Customers => {
+                customers::update_column(
+                    conn,
+                    merchant_id,
+                    id,
+                    column_index,
+                    current_value,
+                    updated_value,
+                )
+                .await
+            }
+            enums::KeyRotationTable::Address => {
+                address::update_column(
+                    conn,
+                    merchant_id,
+                    id,
+                    column_index,
+                    current_value,
+                    updated_value,
+                )
+                .await
+            }
+            enums::KeyRotationTable::PaymentMethods => {
+                payment_methods::update_column(
+                    conn,
+                    merchant_id,
+                    id,
+                    column_index,
+                    current_value,
+                    updated_value,
+                )
+                .await
+            }
+            enums::KeyRotationTable::PaymentIntent => {
+                payment_intent::update_column(
+                    conn,
+                    merchant_id,
+                    id,
+                    column_index,
+                    current_value,
+                    updated_value,
+                )
+                .await
+            }
+            enums::KeyRotationTable::Events => {
+                events::update_column(
+                    conn,
+                    merchant_id,
+                    id,
+                    column_index,
+                    current_value,
+                    updated_value,
+                )
+                .await
+            }
+            enums::KeyRotationTable::WebhookSigningKey => {
+                webhook_signing_key::update_column(
+                    conn,
+                    merchant_id,
+                    id,
+                    column_index,
+                    current_value,
+                    updated_value,
+                )
+                .await
+            }
+            enums::KeyRotationTable::DataRetentionArchive => {
+                Err(report!(errors::DatabaseError::Others))
+                    .attach_printable("Data retention archives are not stored in a database table")
+            }
+        }
+    }
+}
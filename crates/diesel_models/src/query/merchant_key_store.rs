@@ -2,7 +2,10 @@ use diesel::{associations::HasTable, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew},
+    merchant_key_store::{
+        MerchantKeyStore, MerchantKeyStoreNew, MerchantKeyStoreUpdate,
+        MerchantKeyStoreUpdateInternal,
+    },
     schema::merchant_key_store::dsl,
     PgPooledConn, StorageResult,
 };
@@ -25,6 +28,24 @@ impl MerchantKeyStore {
         .await
     }
 
+    pub async fn update_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_key_store_update: MerchantKeyStoreUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            MerchantKeyStoreUpdateInternal::from(merchant_key_store_update),
+        )
+        .await
+    }
+
     pub async fn delete_by_merchant_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
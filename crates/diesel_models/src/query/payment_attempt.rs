@@ -356,4 +356,60 @@ impl PaymentAttempt {
         .change_context(DatabaseError::Others)
         .attach_printable("Error filtering count of payments")
     }
+
+    pub async fn find_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq_any(payment_ids)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        let query = diesel::delete(
+            <Self as HasTable>::table().filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::payment_id.eq_any(payment_ids)),
+            ),
+        );
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while deleting archived payment attempts")
+    }
+
+    /// Inserts payment attempts restored from an archive, skipping the ones which already exist
+    pub async fn insert_archived(conn: &PgPooledConn, rows: Vec<Self>) -> StorageResult<usize> {
+        let query = diesel::insert_into(<Self as HasTable>::table())
+            .values(rows)
+            .on_conflict_do_nothing();
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while restoring archived payment attempts")
+    }
 }
@@ -1,4 +1,9 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
 
 use super::generics;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "payment_v2")))]
@@ -6,10 +11,11 @@ use crate::schema::payment_intent::dsl;
 #[cfg(all(feature = "v2", feature = "payment_v2"))]
 use crate::schema_v2::payment_intent::dsl;
 use crate::{
-    errors,
+    enums, errors,
     payment_intent::{
         PaymentIntent, PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal,
     },
+    query::generics::db_metrics,
     PgPooledConn, StorageResult,
 };
 
@@ -71,4 +77,84 @@ impl PaymentIntent {
         )
         .await
     }
+
+    /// Lists the payment intents of the merchant in the order of their IDs, starting after the
+    /// given payment ID
+    pub async fn list_by_merchant_id_after_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        after_payment_id: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.gt(after_payment_id.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::payment_id.asc()),
+        )
+        .await
+    }
+
+    /// Lists the oldest payment intents in one of the given statuses which were neither created
+    /// nor modified after the given time
+    pub async fn find_settled_before(
+        conn: &PgPooledConn,
+        statuses: Vec<enums::IntentStatus>,
+        settled_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::status
+                .eq_any(statuses)
+                .and(dsl::created_at.lt(settled_before))
+                .and(dsl::modified_at.lt(settled_before)),
+            Some(limit),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        let query = diesel::delete(
+            <Self as HasTable>::table().filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::payment_id.eq_any(payment_ids)),
+            ),
+        );
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting archived payment intents")
+    }
+
+    /// Inserts payment intents restored from an archive, skipping the ones which already exist
+    pub async fn insert_archived(conn: &PgPooledConn, rows: Vec<Self>) -> StorageResult<usize> {
+        let query = diesel::insert_into(<Self as HasTable>::table())
+            .values(rows)
+            .on_conflict_do_nothing();
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while restoring archived payment intents")
+    }
 }
@@ -0,0 +1,160 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    enums::{PayoutBatchItemStatus, PayoutBatchStatus},
+    payout_batch::{
+        PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate,
+        PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdate,
+        PayoutBatchUpdateInternal,
+    },
+    query::generics,
+    schema::{payout_batch::dsl, payout_batch_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+            PayoutBatchUpdateInternal::from(payout_batch_update),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_batch_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        status: PayoutBatchStatus,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned()))
+                .and(dsl::status.eq(status)),
+            PayoutBatchUpdateInternal::from(payout_batch_update),
+        )
+        .await
+    }
+}
+
+impl PayoutBatchItemNew {
+    pub async fn batch_insert(items: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PayoutBatchItem>(conn, items).await?;
+        Ok(())
+    }
+}
+
+impl PayoutBatchItem {
+    pub async fn list_by_batch_id(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::batch_id.eq(batch_id.to_owned()),
+            limit,
+            offset,
+            Some(item_dsl::line_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_batch_id_status(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        status: PayoutBatchItemStatus,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+            limit,
+            None,
+            Some(item_dsl::line_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_batch_id_line_number(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        line_number: i32,
+        payout_batch_item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::line_number.eq(line_number)),
+            PayoutBatchItemUpdateInternal::from(payout_batch_item_update),
+        )
+        .await
+    }
+}
@@ -93,6 +93,26 @@ impl Payouts {
         .await
     }
 
+    /// Lists the payouts of the merchant in the order of their IDs, starting after the given
+    /// payout ID
+    pub async fn list_by_merchant_id_after_payout_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        after_payout_id: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_id.gt(after_payout_id.unwrap_or_default())),
+            Some(limit),
+            None,
+            Some(dsl::payout_id.asc()),
+        )
+        .await
+    }
+
     pub async fn get_total_count_of_payouts(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
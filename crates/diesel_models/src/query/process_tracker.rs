@@ -1,4 +1,9 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl, Table,
+};
+use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
@@ -8,6 +13,7 @@ use crate::{
     process_tracker::{
         ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate, ProcessTrackerUpdateInternal,
     },
+    query::generics::db_metrics,
     schema::process_tracker::dsl,
     PgPooledConn, StorageResult,
 };
@@ -136,4 +142,51 @@ impl ProcessTracker {
         )
         .await
     }
+
+    /// Lists the oldest finished processes created before the given time
+    pub async fn find_created_before(
+        conn: &PgPooledConn,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::created_at
+                .lt(created_before)
+                .and(dsl::status.eq(enums::ProcessTrackerStatus::Finish)),
+            Some(limit),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_ids(conn: &PgPooledConn, ids: Vec<String>) -> StorageResult<usize> {
+        let query = diesel::delete(<Self as HasTable>::table().filter(dsl::id.eq_any(ids)));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting archived processes")
+    }
+
+    /// Inserts processes restored from an archive, skipping the ones which already exist
+    pub async fn insert_archived(conn: &PgPooledConn, rows: Vec<Self>) -> StorageResult<usize> {
+        let query = diesel::insert_into(<Self as HasTable>::table())
+            .values(rows)
+            .on_conflict_do_nothing();
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while restoring archived processes")
+    }
 }
@@ -126,4 +126,26 @@ impl Refund {
         )
         .await
     }
+
+    pub async fn find_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq_any(payment_ids)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
 }
@@ -0,0 +1,137 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    enums::RefundBatchItemStatus,
+    query::generics,
+    refund_batch::{
+        RefundBatch, RefundBatchItem, RefundBatchItemNew, RefundBatchItemUpdate,
+        RefundBatchItemUpdateInternal, RefundBatchNew, RefundBatchUpdate,
+        RefundBatchUpdateInternal,
+    },
+    schema::{refund_batch::dsl, refund_batch_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl RefundBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RefundBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RefundBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        refund_batch_update: RefundBatchUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+            RefundBatchUpdateInternal::from(refund_batch_update),
+        )
+        .await
+    }
+}
+
+impl RefundBatchItemNew {
+    pub async fn batch_insert(items: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, RefundBatchItem>(conn, items).await?;
+        Ok(())
+    }
+}
+
+impl RefundBatchItem {
+    pub async fn list_by_batch_id(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::batch_id.eq(batch_id.to_owned()),
+            limit,
+            offset,
+            Some(item_dsl::line_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_batch_id_status(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        status: RefundBatchItemStatus,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+            limit,
+            None,
+            Some(item_dsl::line_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_batch_id_line_number(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        line_number: i32,
+        refund_batch_item_update: RefundBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::line_number.eq(line_number)),
+            RefundBatchItemUpdateInternal::from(refund_batch_item_update),
+        )
+        .await
+    }
+}
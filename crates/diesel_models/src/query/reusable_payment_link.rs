@@ -0,0 +1,235 @@
+use common_utils::id_type;
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods,
+};
+
+use crate::{
+    query::generics,
+    reusable_payment_link::{
+        ReusablePaymentLink, ReusablePaymentLinkItem, ReusablePaymentLinkItemNew,
+        ReusablePaymentLinkNew, ReusablePaymentLinkPayment, ReusablePaymentLinkPaymentNew,
+        ReusablePaymentLinkPaymentUpdate, ReusablePaymentLinkPaymentUpdateInternal,
+        ReusablePaymentLinkUpdate, ReusablePaymentLinkUpdateInternal,
+    },
+    schema::{
+        reusable_payment_link::dsl, reusable_payment_link_item::dsl as item_dsl,
+        reusable_payment_link_payment::dsl as payment_dsl,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl ReusablePaymentLinkNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReusablePaymentLink> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReusablePaymentLink {
+    pub async fn find_by_merchant_id_reusable_link_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reusable_link_id.eq(reusable_link_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_reusable_link_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+        reusable_payment_link_update: ReusablePaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reusable_link_id.eq(reusable_link_id.to_owned())),
+            ReusablePaymentLinkUpdateInternal::from(reusable_payment_link_update),
+        )
+        .await
+    }
+
+    /// Increments the usage count of the link, unless its usage limit was reached.
+    ///
+    /// Returns `None` if the usage limit of the link was reached.
+    pub async fn increment_times_used(
+        conn: &PgPooledConn,
+        reusable_link_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::reusable_link_id.eq(reusable_link_id.to_owned()).and(
+                dsl::max_uses
+                    .is_null()
+                    .or(dsl::times_used.lt(dsl::max_uses.assume_not_null())),
+            ),
+            (
+                dsl::times_used.eq(dsl::times_used + 1),
+                dsl::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+        .map(|links| links.into_iter().next())
+    }
+
+    pub async fn decrement_times_used(
+        conn: &PgPooledConn,
+        reusable_link_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::reusable_link_id
+                .eq(reusable_link_id.to_owned())
+                .and(dsl::times_used.gt(0)),
+            (
+                dsl::times_used.eq(dsl::times_used - 1),
+                dsl::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+}
+
+impl ReusablePaymentLinkItemNew {
+    pub async fn batch_insert(items: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, ReusablePaymentLinkItem>(conn, items).await?;
+        Ok(())
+    }
+}
+
+impl ReusablePaymentLinkItem {
+    pub async fn list_by_reusable_link_id(
+        conn: &PgPooledConn,
+        reusable_link_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::reusable_link_id.eq(reusable_link_id.to_owned()),
+            None,
+            None,
+            Some(item_dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Adds the quantity to the quantity sold of the item, unless it exceeds the inventory of the
+    /// item.
+    ///
+    /// Returns `None` if the inventory of the item is insufficient.
+    pub async fn increment_quantity_sold(
+        conn: &PgPooledConn,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::reusable_link_id
+                .eq(reusable_link_id.to_owned())
+                .and(item_dsl::item_id.eq(item_id.to_owned()))
+                .and(item_dsl::inventory.is_null().or(
+                    (item_dsl::quantity_sold + quantity).le(item_dsl::inventory.assume_not_null()),
+                )),
+            item_dsl::quantity_sold.eq(item_dsl::quantity_sold + quantity),
+        )
+        .await
+        .map(|items| items.into_iter().next())
+    }
+
+    pub async fn decrement_quantity_sold(
+        conn: &PgPooledConn,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::reusable_link_id
+                .eq(reusable_link_id.to_owned())
+                .and(item_dsl::item_id.eq(item_id.to_owned()))
+                .and(item_dsl::quantity_sold.ge(quantity)),
+            item_dsl::quantity_sold.eq(item_dsl::quantity_sold - quantity),
+        )
+        .await
+    }
+}
+
+impl ReusablePaymentLinkPaymentNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReusablePaymentLinkPayment> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReusablePaymentLinkPayment {
+    pub async fn list_by_reusable_link_id(
+        conn: &PgPooledConn,
+        reusable_link_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            payment_dsl::reusable_link_id.eq(reusable_link_id.to_owned()),
+            None,
+            None,
+            Some(payment_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Updates the payment, unless its usage and inventory were already returned to the link.
+    pub async fn update_unreleased_by_payment_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        reusable_payment_link_payment_update: ReusablePaymentLinkPaymentUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            payment_dsl::payment_id
+                .eq(payment_id.to_owned())
+                .and(payment_dsl::released.eq(false)),
+            ReusablePaymentLinkPaymentUpdateInternal::from(reusable_payment_link_payment_update),
+        )
+        .await
+    }
+}
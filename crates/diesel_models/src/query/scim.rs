@@ -0,0 +1,99 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    query::generics,
+    schema::scim_users::dsl,
+    scim::{ScimUser, ScimUserNew, ScimUserUpdate, ScimUserUpdateInternal},
+    PgPooledConn, StorageResult,
+};
+
+impl ScimUserNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ScimUser> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ScimUser {
+    pub async fn find_by_merchant_id_user_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::user_id.eq(user_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_role_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        role_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::role_id.eq(role_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_user_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+        scim_user_update: ScimUserUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::user_id.eq(user_id.to_owned())),
+            ScimUserUpdateInternal::from(scim_user_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_user_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::user_id.eq(user_id.to_owned())),
+        )
+        .await
+    }
+}
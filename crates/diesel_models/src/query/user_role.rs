@@ -114,6 +114,21 @@ impl UserRole {
         .await
     }
 
+    pub async fn list_by_role_id_org_id(
+        conn: &PgPooledConn,
+        role_id: String,
+        org_id: id_type::OrganizationId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::role_id.eq(role_id).and(dsl::org_id.eq(org_id)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     pub async fn find_by_user_id_org_id_merchant_id_profile_id(
         conn: &PgPooledConn,
         user_id: String,
@@ -0,0 +1,79 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    enums,
+    query::generics,
+    schema::webhook_signing_key::dsl,
+    webhook_signing_key::{
+        WebhookSigningKey, WebhookSigningKeyNew, WebhookSigningKeyUpdate,
+        WebhookSigningKeyUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookSigningKeyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookSigningKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookSigningKey {
+    pub async fn find_by_profile_id_key_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        key_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::key_id.eq(key_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_optional_active_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::status.eq(enums::WebhookSigningKeyStatus::Active)),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_key_id(
+        conn: &PgPooledConn,
+        key_id: &str,
+        webhook_signing_key_update: WebhookSigningKeyUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::key_id.eq(key_id.to_owned()),
+            WebhookSigningKeyUpdateInternal::from(webhook_signing_key_update),
+        )
+        .await
+    }
+}
@@ -50,6 +50,8 @@ pub struct Refund {
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
     pub charges: Option<ChargeRefunds>,
+    pub tax_reversal: Option<serde_json::Value>,
+    pub split_refunds: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -92,6 +94,7 @@ pub struct RefundNew {
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
     pub charges: Option<ChargeRefunds>,
+    pub split_refunds: Option<serde_json::Value>,
 }
 
 impl Default for RefundNew {
@@ -122,6 +125,7 @@ impl Default for RefundNew {
             updated_by: Default::default(),
             merchant_connector_id: Default::default(),
             charges: Default::default(),
+            split_refunds: Default::default(),
         }
     }
 }
@@ -160,6 +164,10 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    TaxReversalUpdate {
+        tax_reversal: serde_json::Value,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -175,6 +183,7 @@ pub struct RefundUpdateInternal {
     refund_error_code: Option<String>,
     updated_by: String,
     modified_at: PrimitiveDateTime,
+    tax_reversal: Option<serde_json::Value>,
 }
 
 impl RefundUpdateInternal {
@@ -190,6 +199,7 @@ impl RefundUpdateInternal {
             refund_error_code: self.refund_error_code,
             updated_by: self.updated_by,
             modified_at: self.modified_at,
+            tax_reversal: self.tax_reversal.or(source.tax_reversal),
             ..source
         }
     }
@@ -216,6 +226,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                tax_reversal: None,
             },
             RefundUpdate::MetadataAndReasonUpdate {
                 metadata,
@@ -232,6 +243,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_arn: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                tax_reversal: None,
             },
             RefundUpdate::StatusUpdate {
                 connector_refund_id,
@@ -249,6 +261,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                tax_reversal: None,
             },
             RefundUpdate::ErrorUpdate {
                 refund_status,
@@ -267,6 +280,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                tax_reversal: None,
             },
             RefundUpdate::ManualUpdate {
                 refund_status,
@@ -284,6 +298,23 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                tax_reversal: None,
+            },
+            RefundUpdate::TaxReversalUpdate {
+                tax_reversal,
+                updated_by,
+            } => Self {
+                tax_reversal: Some(tax_reversal),
+                updated_by,
+                connector_refund_id: None,
+                refund_status: None,
+                sent_to_gateway: None,
+                refund_error_message: None,
+                refund_arn: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
             },
         }
     }
@@ -302,6 +333,7 @@ impl RefundUpdate {
             refund_error_code,
             updated_by,
             modified_at: _,
+            tax_reversal,
         } = self.into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
@@ -314,6 +346,7 @@ impl RefundUpdate {
             refund_reason: refund_reason.or(source.refund_reason),
             updated_by,
             modified_at: common_utils::date_time::now(),
+            tax_reversal: tax_reversal.or(source.tax_reversal),
             ..source
         }
     }
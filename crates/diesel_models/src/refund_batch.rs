@@ -0,0 +1,183 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{refund_batch, refund_batch_item},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = refund_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct RefundBatch {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::RefundBatchStatus,
+    pub file_name: Option<String>,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_batch)]
+pub struct RefundBatchNew {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::RefundBatchStatus,
+    pub file_name: Option<String>,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_batch)]
+pub struct RefundBatchUpdateInternal {
+    status: Option<storage_enums::RefundBatchStatus>,
+    succeeded_count: Option<i32>,
+    failed_count: Option<i32>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum RefundBatchUpdate {
+    StatusUpdate {
+        status: storage_enums::RefundBatchStatus,
+    },
+    ProgressUpdate {
+        status: storage_enums::RefundBatchStatus,
+        succeeded_count: i32,
+        failed_count: i32,
+    },
+}
+
+impl From<RefundBatchUpdate> for RefundBatchUpdateInternal {
+    fn from(value: RefundBatchUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            RefundBatchUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                succeeded_count: None,
+                failed_count: None,
+                modified_at,
+            },
+            RefundBatchUpdate::ProgressUpdate {
+                status,
+                succeeded_count,
+                failed_count,
+            } => Self {
+                status: Some(status),
+                succeeded_count: Some(succeeded_count),
+                failed_count: Some(failed_count),
+                modified_at,
+            },
+        }
+    }
+}
+
+impl RefundBatchUpdateInternal {
+    pub fn apply_changeset(self, source: RefundBatch) -> RefundBatch {
+        RefundBatch {
+            status: self.status.unwrap_or(source.status),
+            succeeded_count: self.succeeded_count.unwrap_or(source.succeeded_count),
+            failed_count: self.failed_count.unwrap_or(source.failed_count),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = refund_batch_item,
+    primary_key(batch_id, line_number),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct RefundBatchItem {
+    pub batch_id: String,
+    pub line_number: i32,
+    pub payment_id: String,
+    pub amount: MinorUnit,
+    pub reason: Option<String>,
+    pub status: storage_enums::RefundBatchItemStatus,
+    pub refund_id: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_batch_item)]
+pub struct RefundBatchItemNew {
+    pub batch_id: String,
+    pub line_number: i32,
+    pub payment_id: String,
+    pub amount: MinorUnit,
+    pub reason: Option<String>,
+    pub status: storage_enums::RefundBatchItemStatus,
+    pub refund_id: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_batch_item)]
+pub struct RefundBatchItemUpdateInternal {
+    status: storage_enums::RefundBatchItemStatus,
+    refund_id: Option<String>,
+    error_message: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum RefundBatchItemUpdate {
+    RefundCreated { refund_id: String },
+    RefundFailed { error_message: String },
+}
+
+impl From<RefundBatchItemUpdate> for RefundBatchItemUpdateInternal {
+    fn from(value: RefundBatchItemUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            RefundBatchItemUpdate::RefundCreated { refund_id } => Self {
+                status: storage_enums::RefundBatchItemStatus::Created,
+                refund_id: Some(refund_id),
+                error_message: None,
+                modified_at,
+            },
+            RefundBatchItemUpdate::RefundFailed { error_message } => Self {
+                status: storage_enums::RefundBatchItemStatus::Failed,
+                refund_id: None,
+                error_message: Some(error_message),
+                modified_at,
+            },
+        }
+    }
+}
+
+impl RefundBatchItemUpdateInternal {
+    pub fn apply_changeset(self, source: RefundBatchItem) -> RefundBatchItem {
+        RefundBatchItem {
+            status: self.status,
+            refund_id: self.refund_id.or(source.refund_id),
+            error_message: self.error_message.or(source.error_message),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RefundBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+}
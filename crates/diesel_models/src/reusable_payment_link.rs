@@ -0,0 +1,247 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{reusable_payment_link, reusable_payment_link_item, reusable_payment_link_payment},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = reusable_payment_link,
+    primary_key(reusable_link_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ReusablePaymentLink {
+    pub reusable_link_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: String,
+    pub link_to_pay: String,
+    pub status: storage_enums::ReusablePaymentLinkStatus,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    /// Bounds of the amount chosen by the customer, if the customer can choose the amount
+    pub custom_amount: Option<serde_json::Value>,
+    pub max_uses: Option<i32>,
+    /// Number of payments created through the link that have not failed or been abandoned
+    pub times_used: i32,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReusablePaymentLink {
+    /// The status of the link, accounting for its expiry time and usage limit
+    pub fn get_effective_status(
+        &self,
+        current_time: PrimitiveDateTime,
+    ) -> storage_enums::ReusablePaymentLinkStatus {
+        match self.status {
+            storage_enums::ReusablePaymentLinkStatus::Active
+                if self
+                    .expires_at
+                    .is_some_and(|expires_at| expires_at <= current_time) =>
+            {
+                storage_enums::ReusablePaymentLinkStatus::Expired
+            }
+            storage_enums::ReusablePaymentLinkStatus::Active
+                if self
+                    .max_uses
+                    .is_some_and(|max_uses| self.times_used >= max_uses) =>
+            {
+                storage_enums::ReusablePaymentLinkStatus::Exhausted
+            }
+            status => status,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reusable_payment_link)]
+pub struct ReusablePaymentLinkNew {
+    pub reusable_link_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: String,
+    pub link_to_pay: String,
+    pub status: storage_enums::ReusablePaymentLinkStatus,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    pub custom_amount: Option<serde_json::Value>,
+    pub max_uses: Option<i32>,
+    pub times_used: i32,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reusable_payment_link)]
+pub struct ReusablePaymentLinkUpdateInternal {
+    status: Option<storage_enums::ReusablePaymentLinkStatus>,
+    description: Option<String>,
+    max_uses: Option<i32>,
+    expires_at: Option<PrimitiveDateTime>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ReusablePaymentLinkUpdate {
+    Update {
+        status: Option<storage_enums::ReusablePaymentLinkStatus>,
+        description: Option<String>,
+        max_uses: Option<i32>,
+        expires_at: Option<PrimitiveDateTime>,
+    },
+}
+
+impl From<ReusablePaymentLinkUpdate> for ReusablePaymentLinkUpdateInternal {
+    fn from(value: ReusablePaymentLinkUpdate) -> Self {
+        match value {
+            ReusablePaymentLinkUpdate::Update {
+                status,
+                description,
+                max_uses,
+                expires_at,
+            } => Self {
+                status,
+                description,
+                max_uses,
+                expires_at,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl ReusablePaymentLinkUpdateInternal {
+    pub fn apply_changeset(self, source: ReusablePaymentLink) -> ReusablePaymentLink {
+        ReusablePaymentLink {
+            status: self.status.unwrap_or(source.status),
+            description: self.description.or(source.description),
+            max_uses: self.max_uses.or(source.max_uses),
+            expires_at: self.expires_at.or(source.expires_at),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = reusable_payment_link_item,
+    primary_key(reusable_link_id, item_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ReusablePaymentLinkItem {
+    pub reusable_link_id: String,
+    pub item_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub unit_amount: MinorUnit,
+    /// Quantity of the item added to a payment when the customer does not choose the quantity
+    pub quantity: i32,
+    pub min_quantity: Option<i32>,
+    pub max_quantity: Option<i32>,
+    /// Total quantity of the item that can be sold through the link
+    pub inventory: Option<i64>,
+    /// Quantity of the item in payments that have not failed or been abandoned
+    pub quantity_sold: i64,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reusable_payment_link_item)]
+pub struct ReusablePaymentLinkItemNew {
+    pub reusable_link_id: String,
+    pub item_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub unit_amount: MinorUnit,
+    pub quantity: i32,
+    pub min_quantity: Option<i32>,
+    pub max_quantity: Option<i32>,
+    pub inventory: Option<i64>,
+    pub quantity_sold: i64,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = reusable_payment_link_payment,
+    primary_key(payment_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ReusablePaymentLinkPayment {
+    pub payment_id: String,
+    pub reusable_link_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    /// Quantities of the items of the link included in the payment, keyed by item ID
+    pub item_quantities: serde_json::Value,
+    /// Status of the payment intent, as of the last time it was synced
+    pub status: storage_enums::IntentStatus,
+    /// Whether the usage and inventory held by the payment were returned to the link
+    pub released: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reusable_payment_link_payment)]
+pub struct ReusablePaymentLinkPaymentNew {
+    pub payment_id: String,
+    pub reusable_link_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub item_quantities: serde_json::Value,
+    pub status: storage_enums::IntentStatus,
+    pub released: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reusable_payment_link_payment)]
+pub struct ReusablePaymentLinkPaymentUpdateInternal {
+    status: storage_enums::IntentStatus,
+    released: bool,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ReusablePaymentLinkPaymentUpdate {
+    StatusUpdate {
+        status: storage_enums::IntentStatus,
+        released: bool,
+    },
+}
+
+impl From<ReusablePaymentLinkPaymentUpdate> for ReusablePaymentLinkPaymentUpdateInternal {
+    fn from(value: ReusablePaymentLinkPaymentUpdate) -> Self {
+        match value {
+            ReusablePaymentLinkPaymentUpdate::StatusUpdate { status, released } => Self {
+                status,
+                released,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl ReusablePaymentLinkPaymentUpdateInternal {
+    pub fn apply_changeset(self, source: ReusablePaymentLinkPayment) -> ReusablePaymentLinkPayment {
+        ReusablePaymentLinkPayment {
+            status: self.status,
+            released: self.released,
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
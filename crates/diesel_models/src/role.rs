@@ -19,6 +19,12 @@ pub struct Role {
     pub last_modified_at: PrimitiveDateTime,
     pub last_modified_by: String,
     pub entity_type: Option<enums::EntityType>,
+    /// Individual permissions granted on top of the permission groups
+    #[diesel(deserialize_as = super::DieselArray<String>)]
+    pub permissions: Vec<String>,
+    /// Business profiles the role is restricted to, `None` if it applies to all profiles
+    #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
+    pub profile_ids: Option<Vec<String>>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -36,6 +42,8 @@ pub struct RoleNew {
     pub last_modified_at: PrimitiveDateTime,
     pub last_modified_by: String,
     pub entity_type: Option<enums::EntityType>,
+    pub permissions: Vec<String>,
+    pub profile_ids: Option<Vec<String>>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -43,6 +51,8 @@ pub struct RoleNew {
 pub struct RoleUpdateInternal {
     groups: Option<Vec<enums::PermissionGroup>>,
     role_name: Option<String>,
+    permissions: Option<Vec<String>>,
+    profile_ids: Option<Option<Vec<String>>>,
     last_modified_by: String,
     last_modified_at: PrimitiveDateTime,
 }
@@ -51,6 +61,8 @@ pub enum RoleUpdate {
     UpdateDetails {
         groups: Option<Vec<enums::PermissionGroup>>,
         role_name: Option<String>,
+        permissions: Option<Vec<String>>,
+        profile_ids: Option<Option<Vec<String>>>,
         last_modified_at: PrimitiveDateTime,
         last_modified_by: String,
     },
@@ -62,11 +74,15 @@ impl From<RoleUpdate> for RoleUpdateInternal {
             RoleUpdate::UpdateDetails {
                 groups,
                 role_name,
+                permissions,
+                profile_ids,
                 last_modified_by,
                 last_modified_at,
             } => Self {
                 groups,
                 role_name,
+                permissions,
+                profile_ids,
                 last_modified_at,
                 last_modified_by,
             },
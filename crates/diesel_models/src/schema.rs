@@ -241,6 +241,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    card_bin_range (bin_start, bin_end) {
+        #[max_length = 8]
+        bin_start -> Varchar,
+        #[max_length = 8]
+        bin_end -> Varchar,
+        bin_length -> Int4,
+        card_issuer -> Nullable<Text>,
+        card_network -> Nullable<Text>,
+        card_type -> Nullable<Text>,
+        card_subtype -> Nullable<Text>,
+        card_issuing_country -> Nullable<Text>,
+        last_updated_provider -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -330,6 +351,73 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    data_retention_archive (archive_id) {
+        #[max_length = 64]
+        archive_id -> Varchar,
+        #[max_length = 64]
+        table_name -> Varchar,
+        #[max_length = 255]
+        file_key -> Varchar,
+        row_count -> Int4,
+        oldest_record_created_at -> Timestamp,
+        newest_record_created_at -> Timestamp,
+        created_at -> Timestamp,
+        restored_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    data_retention_policy (table_name) {
+        #[max_length = 64]
+        table_name -> Varchar,
+        retention_days -> Int4,
+        batch_size -> Int4,
+        is_enabled -> Bool,
+        last_run_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    data_subject_request (request_id) {
+        #[max_length = 64]
+        request_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        request_type -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 255]
+        file_key -> Nullable<Varchar>,
+        summary -> Nullable<Jsonb>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        email_hash -> Nullable<Varchar>,
+        erased_object_ids -> Nullable<Array<Nullable<Text>>>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -496,6 +584,36 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    gateway_status_map_history (change_id, sequence_number) {
+        #[max_length = 64]
+        change_id -> Varchar,
+        sequence_number -> Int4,
+        #[max_length = 32]
+        source -> Varchar,
+        #[max_length = 32]
+        action -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        flow -> Varchar,
+        #[max_length = 64]
+        sub_flow -> Varchar,
+        #[max_length = 255]
+        code -> Varchar,
+        #[max_length = 1024]
+        message -> Varchar,
+        previous_rule -> Nullable<Jsonb>,
+        current_rule -> Nullable<Jsonb>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -543,6 +661,158 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    invoice (invoice_id) {
+        #[max_length = 64]
+        invoice_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        invoice_number -> Nullable<Varchar>,
+        #[max_length = 64]
+        status -> Varchar,
+        currency -> Currency,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        discount -> Nullable<Jsonb>,
+        tax_lines -> Jsonb,
+        subtotal -> Int8,
+        discount_amount -> Int8,
+        tax_amount -> Int8,
+        total -> Int8,
+        amount_paid -> Int8,
+        due_date -> Nullable<Timestamp>,
+        reminder_schedule -> Nullable<Jsonb>,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        payment_link -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        finalized_at -> Nullable<Timestamp>,
+        paid_at -> Nullable<Timestamp>,
+        voided_at -> Nullable<Timestamp>,
+        marked_uncollectible_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    invoice_line_item (invoice_id, line_item_id) {
+        #[max_length = 64]
+        invoice_id -> Varchar,
+        #[max_length = 64]
+        line_item_id -> Varchar,
+        position -> Int4,
+        #[max_length = 255]
+        description -> Varchar,
+        quantity -> Int4,
+        unit_amount -> Int8,
+        amount -> Int8,
+        discount -> Nullable<Jsonb>,
+        discount_amount -> Int8,
+        tax_rates -> Jsonb,
+        tax_amount -> Int8,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    invoice_number_sequence (profile_id) {
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        prefix -> Varchar,
+        next_number -> Int8,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    invoice_payment (payment_id) {
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        invoice_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        source -> Varchar,
+        amount -> Int8,
+        amount_received -> Nullable<Int8>,
+        currency -> Currency,
+        status -> IntentStatus,
+        #[max_length = 255]
+        reference -> Nullable<Varchar>,
+        credited -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_entry (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        currency -> Currency,
+        #[max_length = 64]
+        entry_type -> Varchar,
+        #[max_length = 64]
+        source_id -> Varchar,
+        #[max_length = 64]
+        reference_id -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        effective_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_posting (entry_id, line_number) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        line_number -> Int2,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        currency -> Currency,
+        #[max_length = 64]
+        account -> Varchar,
+        #[max_length = 16]
+        direction -> Varchar,
+        amount -> Int8,
+        effective_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -709,6 +979,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_key_rotation (rotation_id) {
+        #[max_length = 64]
+        rotation_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        key_version -> Int4,
+        #[max_length = 64]
+        status -> Varchar,
+        #[max_length = 64]
+        current_table -> Nullable<Varchar>,
+        #[max_length = 255]
+        last_processed_id -> Nullable<Varchar>,
+        rows_processed -> Int8,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -718,6 +1012,8 @@ diesel::table! {
         merchant_id -> Varchar,
         key -> Bytea,
         created_at -> Timestamp,
+        key_version -> Int4,
+        previous_key -> Nullable<Bytea>,
     }
 }
 
@@ -826,6 +1122,8 @@ diesel::table! {
         #[max_length = 64]
         client_version -> Nullable<Varchar>,
         customer_acceptance -> Nullable<Jsonb>,
+        #[max_length = 64]
+        sca_exemption -> Nullable<Varchar>,
     }
 }
 
@@ -901,6 +1199,9 @@ diesel::table! {
         merchant_order_reference_id -> Nullable<Varchar>,
         shipping_details -> Nullable<Bytea>,
         is_payment_processor_token_flow -> Nullable<Bool>,
+        tax_details -> Nullable<Jsonb>,
+        l2_l3_data -> Nullable<Jsonb>,
+        split_payments -> Nullable<Jsonb>,
     }
 }
 
@@ -1028,6 +1329,63 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        total_count -> Int4,
+        succeeded_count -> Int4,
+        failed_count -> Int4,
+        #[max_length = 64]
+        created_by -> Varchar,
+        #[max_length = 64]
+        reviewed_by -> Nullable<Varchar>,
+        reviewed_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        review_comment -> Nullable<Varchar>,
+        scheduled_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch_item (batch_id, line_number) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        line_number -> Int4,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        payout_type -> Nullable<PayoutType>,
+        #[max_length = 64]
+        payout_token -> Nullable<Varchar>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        payout_status -> Nullable<PayoutStatus>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1145,6 +1503,128 @@ diesel::table! {
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
         charges -> Nullable<Jsonb>,
+        tax_reversal -> Nullable<Jsonb>,
+        split_refunds -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    refund_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        file_name -> Nullable<Varchar>,
+        total_count -> Int4,
+        succeeded_count -> Int4,
+        failed_count -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    refund_batch_item (batch_id, line_number) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        line_number -> Int4,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        amount -> Int8,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    reusable_payment_link (reusable_link_id) {
+        #[max_length = 64]
+        reusable_link_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        link_to_pay -> Varchar,
+        #[max_length = 64]
+        status -> Varchar,
+        currency -> Currency,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        custom_amount -> Nullable<Jsonb>,
+        max_uses -> Nullable<Int4>,
+        times_used -> Int4,
+        payment_link_config -> Nullable<Jsonb>,
+        expires_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    reusable_payment_link_item (reusable_link_id, item_id) {
+        #[max_length = 64]
+        reusable_link_id -> Varchar,
+        #[max_length = 64]
+        item_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 255]
+        image_url -> Nullable<Varchar>,
+        unit_amount -> Int8,
+        quantity -> Int4,
+        min_quantity -> Nullable<Int4>,
+        max_quantity -> Nullable<Int4>,
+        inventory -> Nullable<Int8>,
+        quantity_sold -> Int8,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    reusable_payment_link_payment (payment_id) {
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        reusable_link_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        item_quantities -> Jsonb,
+        status -> IntentStatus,
+        released -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1189,6 +1669,8 @@ diesel::table! {
         last_modified_by -> Varchar,
         #[max_length = 64]
         entity_type -> Nullable<Varchar>,
+        permissions -> Array<Nullable<Text>>,
+        profile_ids -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -1215,6 +1697,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    scim_users (merchant_id, user_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        user_id -> Varchar,
+        #[max_length = 64]
+        org_id -> Varchar,
+        #[max_length = 255]
+        external_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        role_id -> Varchar,
+        active -> Bool,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1306,6 +1809,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_signing_key (key_id) {
+        #[max_length = 64]
+        key_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        algorithm -> Varchar,
+        #[max_length = 64]
+        status -> Varchar,
+        private_key -> Bytea,
+        public_key -> Bytea,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -1315,21 +1841,33 @@ diesel::allow_tables_to_appear_in_same_query!(
     blocklist_lookup,
     business_profile,
     captures,
+    card_bin_range,
     cards_info,
     configs,
     customers,
     dashboard_metadata,
+    data_retention_archive,
+    data_retention_policy,
+    data_subject_request,
     dispute,
     events,
     file_metadata,
     fraud_check,
     gateway_status_map,
+    gateway_status_map_history,
     generic_link,
     incremental_authorization,
+    invoice,
+    invoice_line_item,
+    invoice_number_sequence,
+    invoice_payment,
+    ledger_entry,
+    ledger_posting,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
+    merchant_key_rotation,
     merchant_key_store,
     organization,
     payment_attempt,
@@ -1337,14 +1875,23 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_link,
     payment_methods,
     payout_attempt,
+    payout_batch,
+    payout_batch_item,
     payouts,
     process_tracker,
     refund,
+    refund_batch,
+    refund_batch_item,
+    reusable_payment_link,
+    reusable_payment_link_item,
+    reusable_payment_link_payment,
     reverse_lookup,
     roles,
     routing_algorithm,
+    scim_users,
     user_authentication_methods,
     user_key_store,
     user_roles,
     users,
+    webhook_signing_key,
 );
@@ -0,0 +1,85 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::scim_users;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = scim_users, primary_key(merchant_id, user_id), check_for_backend(diesel::pg::Pg))]
+pub struct ScimUser {
+    pub merchant_id: id_type::MerchantId,
+    pub user_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub external_id: Option<String>,
+    pub role_id: String,
+    pub active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = scim_users)]
+pub struct ScimUserNew {
+    pub merchant_id: id_type::MerchantId,
+    pub user_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub external_id: Option<String>,
+    pub role_id: String,
+    pub active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = scim_users)]
+pub struct ScimUserUpdateInternal {
+    external_id: Option<String>,
+    role_id: Option<String>,
+    active: Option<bool>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ScimUserUpdate {
+    ExternalIdUpdate { external_id: Option<String> },
+    RoleUpdate { role_id: String },
+    StatusUpdate { active: bool },
+}
+
+impl From<ScimUserUpdate> for ScimUserUpdateInternal {
+    fn from(value: ScimUserUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match value {
+            ScimUserUpdate::ExternalIdUpdate { external_id } => Self {
+                external_id,
+                role_id: None,
+                active: None,
+                last_modified_at,
+            },
+            ScimUserUpdate::RoleUpdate { role_id } => Self {
+                external_id: None,
+                role_id: Some(role_id),
+                active: None,
+                last_modified_at,
+            },
+            ScimUserUpdate::StatusUpdate { active } => Self {
+                external_id: None,
+                role_id: None,
+                active: Some(active),
+                last_modified_at,
+            },
+        }
+    }
+}
+
+impl ScimUserUpdateInternal {
+    pub fn apply_changeset(self, source: ScimUser) -> ScimUser {
+        ScimUser {
+            external_id: self.external_id.or(source.external_id),
+            role_id: self.role_id.unwrap_or(source.role_id),
+            active: self.active.unwrap_or(source.active),
+            last_modified_at: self.last_modified_at,
+            ..source
+        }
+    }
+}
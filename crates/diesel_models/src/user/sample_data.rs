@@ -1,6 +1,6 @@
 use common_enums::{
     AttemptStatus, AuthenticationType, CaptureMethod, Currency, PaymentExperience, PaymentMethod,
-    PaymentMethodType,
+    PaymentMethodType, ScaExemptionType,
 };
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -80,12 +80,12 @@ pub struct PaymentAttemptBatchNew {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<common_utils::pii::SecretSerdeValue>,
+    pub sca_exemption: Option<ScaExemptionType>,
 }
 
-#[allow(dead_code)]
 impl PaymentAttemptBatchNew {
     // Used to verify compatibility with PaymentAttemptTable
-    fn convert_into_normal_attempt_insert(self) -> PaymentAttemptNew {
+    pub fn convert_into_normal_attempt_insert(self) -> PaymentAttemptNew {
         PaymentAttemptNew {
             payment_id: self.payment_id,
             merchant_id: self.merchant_id,
@@ -144,6 +144,7 @@ impl PaymentAttemptBatchNew {
             client_source: self.client_source,
             client_version: self.client_version,
             customer_acceptance: self.customer_acceptance,
+            sca_exemption: self.sca_exemption,
         }
     }
 }
@@ -0,0 +1,100 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::webhook_signing_key};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = webhook_signing_key,
+    primary_key(key_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct WebhookSigningKey {
+    pub key_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub algorithm: storage_enums::WebhookSigningAlgorithm,
+    pub status: storage_enums::WebhookSigningKeyStatus,
+    /// PKCS#8 document of the private key, encrypted with the merchant's key
+    pub private_key: Encryption,
+    pub public_key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+impl WebhookSigningKey {
+    /// Whether the public key of the key is published, so that signatures made with it can be
+    /// verified by the merchant
+    pub fn is_published(&self, current_time: PrimitiveDateTime) -> bool {
+        match self.status {
+            storage_enums::WebhookSigningKeyStatus::Active => true,
+            storage_enums::WebhookSigningKeyStatus::Rotated => self
+                .expires_at
+                .is_some_and(|expires_at| expires_at > current_time),
+            storage_enums::WebhookSigningKeyStatus::Revoked => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_signing_key)]
+pub struct WebhookSigningKeyNew {
+    pub key_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub algorithm: storage_enums::WebhookSigningAlgorithm,
+    pub status: storage_enums::WebhookSigningKeyStatus,
+    pub private_key: Encryption,
+    pub public_key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_signing_key)]
+pub struct WebhookSigningKeyUpdateInternal {
+    status: storage_enums::WebhookSigningKeyStatus,
+    expires_at: Option<PrimitiveDateTime>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum WebhookSigningKeyUpdate {
+    /// The key was replaced by a new key, it remains published until `expires_at`
+    Rotated {
+        expires_at: PrimitiveDateTime,
+    },
+    Revoked,
+}
+
+impl From<WebhookSigningKeyUpdate> for WebhookSigningKeyUpdateInternal {
+    fn from(value: WebhookSigningKeyUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            WebhookSigningKeyUpdate::Rotated { expires_at } => Self {
+                status: storage_enums::WebhookSigningKeyStatus::Rotated,
+                expires_at: Some(expires_at),
+                modified_at,
+            },
+            WebhookSigningKeyUpdate::Revoked => Self {
+                status: storage_enums::WebhookSigningKeyStatus::Revoked,
+                expires_at: Some(modified_at),
+                modified_at,
+            },
+        }
+    }
+}
+
+impl WebhookSigningKeyUpdateInternal {
+    pub fn apply_changeset(self, source: WebhookSigningKey) -> WebhookSigningKey {
+        WebhookSigningKey {
+            status: self.status,
+            expires_at: self.expires_at.or(source.expires_at),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
@@ -677,7 +677,7 @@ pub enum PayoutDirKeyKind {
 
     #[strum(
         serialize = "billing_country",
-        detailed_message = "Country of the billing address of the customer",
+        detailed_message = "Destination country of the payout, taken from the billing address of the recipient",
         props(Category = "Customer")
     )]
     #[serde(rename = "billing_country")]
@@ -699,6 +699,14 @@ pub enum PayoutDirKeyKind {
     #[serde(rename = "amount")]
     PayoutAmount,
 
+    #[strum(
+        serialize = "currency",
+        detailed_message = "Destination currency of the payout",
+        props(Category = "Order details")
+    )]
+    #[serde(rename = "currency")]
+    PayoutCurrency,
+
     #[strum(
         serialize = "payment_method",
         detailed_message = "Different modes of payout - eg. cards, wallets, banks",
@@ -737,6 +745,8 @@ pub enum PayoutDirValue {
     BusinessLabel(types::StrValue),
     #[serde(rename = "amount")]
     PayoutAmount(types::NumValue),
+    #[serde(rename = "currency")]
+    PayoutCurrency(enums::PaymentCurrency),
     #[serde(rename = "payment_method")]
     PayoutType(common_enums::PayoutType),
     #[serde(rename = "wallet")]
@@ -0,0 +1,5 @@
+//! Secrets stored in a locally encrypted file
+
+pub mod core;
+
+pub mod implementers;
@@ -0,0 +1,262 @@
+//! Secrets stored in a file encrypted with AES-256-GCM
+//!
+//! The plaintext of the file is a TOML table mapping the names of the secrets to their values.
+//! The file holds the base64 encoded ciphertext of the table, prefixed with the nonce and
+//! suffixed with the authentication tag. The key is a hex encoded 32 byte key, read from an
+//! environment variable or a key file.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use base64::Engine;
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    fp_utils::when,
+};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+
+/// Length of the AES-256 key, in bytes
+const KEY_LENGTH: usize = 32;
+
+const BASE64_ENGINE: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
+
+/// Configuration for reading secrets from a locally encrypted file.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct EncryptedFileConfig {
+    /// Path of the encrypted secrets file.
+    pub file_path: PathBuf,
+
+    /// Name of the environment variable holding the hex encoded key of the file.
+    pub key_env_var: Option<String>,
+
+    /// Path of the file holding the hex encoded key of the file.
+    pub key_file: Option<PathBuf>,
+}
+
+impl EncryptedFileConfig {
+    /// Verifies that the [`EncryptedFileSecretsManager`] configuration is usable.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        when(self.file_path.as_os_str().is_empty(), || {
+            Err("Encrypted secrets file path must not be empty")
+        })?;
+
+        match (&self.key_env_var, &self.key_file) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            (None, None) => {
+                Err("Either the key environment variable or the key file of the encrypted secrets file must be specified")
+            }
+            (Some(_), Some(_)) => {
+                Err("Only one of the key environment variable or the key file of the encrypted secrets file must be specified")
+            }
+        }
+    }
+
+    /// Reads the key of the secrets file from the configured environment variable or key file.
+    pub fn read_key(&self) -> error_stack::Result<Secret<Vec<u8>>, EncryptedFileError> {
+        let encoded_key = match (&self.key_env_var, &self.key_file) {
+            (Some(key_env_var), _) => std::env::var(key_env_var)
+                .change_context(EncryptedFileError::KeyNotFound)
+                .attach_printable_lazy(|| {
+                    format!("Failed to read environment variable `{key_env_var}`")
+                })?,
+            (None, Some(key_file)) => std::fs::read_to_string(key_file)
+                .change_context(EncryptedFileError::KeyNotFound)
+                .attach_printable_lazy(|| {
+                    format!("Failed to read key file `{}`", key_file.display())
+                })?,
+            (None, None) => Err(report!(EncryptedFileError::KeyNotFound))?,
+        };
+
+        decode_key(encoded_key.trim())
+    }
+}
+
+/// Decodes a hex encoded key of a secrets file.
+pub fn decode_key(encoded_key: &str) -> error_stack::Result<Secret<Vec<u8>>, EncryptedFileError> {
+    let key = hex::decode(encoded_key)
+        .change_context(EncryptedFileError::InvalidKey)
+        .attach_printable("Key is not hex encoded")?;
+    when(key.len() != KEY_LENGTH, || {
+        Err(report!(EncryptedFileError::InvalidKey))
+            .attach_printable(format!("Key must be {KEY_LENGTH} bytes long"))
+    })?;
+
+    Ok(Secret::new(key))
+}
+
+/// Generates a random hex encoded key for a secrets file.
+pub fn generate_key() -> Secret<String> {
+    Secret::new(hex::encode(
+        common_utils::crypto::generate_cryptographically_secure_random_bytes::<KEY_LENGTH>(),
+    ))
+}
+
+/// Encrypts the TOML table of secrets, returning the contents of the secrets file.
+///
+/// The table is parsed before it is encrypted, so that a malformed file is never written.
+pub fn encrypt_secrets(
+    key: &Secret<Vec<u8>>,
+    plaintext: &Secret<String>,
+) -> error_stack::Result<String, EncryptedFileError> {
+    parse_secrets(plaintext)?;
+
+    GcmAes256
+        .encode_message(key.peek(), plaintext.peek().as_bytes())
+        .change_context(EncryptedFileError::EncryptionFailed)
+        .map(|encrypted| BASE64_ENGINE.encode(encrypted))
+}
+
+/// Decrypts the contents of a secrets file, returning the TOML table of secrets.
+pub fn decrypt_secrets(
+    key: &Secret<Vec<u8>>,
+    contents: &str,
+) -> error_stack::Result<Secret<String>, EncryptedFileError> {
+    let encrypted = BASE64_ENGINE
+        .decode(contents.trim())
+        .change_context(EncryptedFileError::DecryptionFailed)
+        .attach_printable("Secrets file is not base64 encoded")?;
+    let decrypted = GcmAes256
+        .decode_message(key.peek(), encrypted.into())
+        .change_context(EncryptedFileError::DecryptionFailed)?;
+
+    String::from_utf8(decrypted)
+        .change_context(EncryptedFileError::DecryptionFailed)
+        .attach_printable("Decrypted secrets are not valid UTF-8")
+        .map(Secret::new)
+}
+
+fn parse_secrets(
+    plaintext: &Secret<String>,
+) -> error_stack::Result<HashMap<String, Secret<String>>, EncryptedFileError> {
+    plaintext
+        .peek()
+        .parse::<toml::Table>()
+        .change_context(EncryptedFileError::ParsingFailed)?
+        .into_iter()
+        .map(|(name, value)| match value {
+            toml::Value::String(value) => Ok((name, Secret::new(value))),
+            _ => Err(report!(EncryptedFileError::ParsingFailed))
+                .attach_printable(format!("Value of secret `{name}` must be a string")),
+        })
+        .collect()
+}
+
+/// Secrets manager reading secrets from a locally encrypted file.
+///
+/// The file is read when the manager is created. The application creates a new manager to read
+/// the updated file when its secrets are reloaded.
+#[derive(Debug, Clone)]
+pub struct EncryptedFileSecretsManager {
+    secrets: HashMap<String, Secret<String>>,
+}
+
+impl EncryptedFileSecretsManager {
+    /// Creates a new secrets manager, reading the secrets from the configured file.
+    pub fn new(config: &EncryptedFileConfig) -> error_stack::Result<Self, EncryptedFileError> {
+        let key = config.read_key()?;
+        let contents = std::fs::read_to_string(&config.file_path)
+            .change_context(EncryptedFileError::FileReadFailed)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to read secrets file `{}`",
+                    config.file_path.display()
+                )
+            })?;
+        let secrets = parse_secrets(&decrypt_secrets(&key, &contents)?)?;
+
+        Ok(Self { secrets })
+    }
+
+    /// Returns the value of the secret with the specified name.
+    pub fn get(&self, name: &str) -> Option<Secret<String>> {
+        self.secrets.get(name).cloned()
+    }
+}
+
+/// Errors that may occur while reading or writing an encrypted secrets file.
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptedFileError {
+    /// The key of the secrets file could not be read
+    #[error("Failed to read the key of the secrets file")]
+    KeyNotFound,
+
+    /// The key of the secrets file is malformed
+    #[error("Invalid key for the secrets file")]
+    InvalidKey,
+
+    /// The secrets file could not be read
+    #[error("Failed to read the secrets file")]
+    FileReadFailed,
+
+    /// The secrets could not be encrypted
+    #[error("Failed to encrypt the secrets")]
+    EncryptionFailed,
+
+    /// The secrets file could not be decrypted
+    #[error("Failed to decrypt the secrets file")]
+    DecryptionFailed,
+
+    /// The decrypted secrets are not a TOML table of strings
+    #[error("Failed to parse the secrets")]
+    ParsingFailed,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const SECRETS: &str = r#"
+master_database_password = "db_pass"
+jwt_secret = "secret"
+"#;
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let key = decode_key(generate_key().peek()).unwrap();
+        let plaintext = Secret::new(SECRETS.to_string());
+
+        let contents = encrypt_secrets(&key, &plaintext).unwrap();
+        assert!(!contents.contains("db_pass"));
+
+        let decrypted = decrypt_secrets(&key, &contents).unwrap();
+        assert_eq!(decrypted.peek(), plaintext.peek());
+
+        let secrets = parse_secrets(&decrypted).unwrap();
+        assert_eq!(
+            secrets
+                .get("master_database_password")
+                .map(|value| value.peek().as_str()),
+            Some("db_pass")
+        );
+        assert_eq!(
+            secrets.get("jwt_secret").map(|value| value.peek().as_str()),
+            Some("secret")
+        );
+    }
+
+    #[test]
+    fn test_decrypt_with_another_key_fails() {
+        let key = decode_key(generate_key().peek()).unwrap();
+        let other_key = decode_key(generate_key().peek()).unwrap();
+        let contents = encrypt_secrets(&key, &Secret::new(SECRETS.to_string())).unwrap();
+
+        assert!(decrypt_secrets(&other_key, &contents).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_rejects_malformed_secrets() {
+        let key = decode_key(generate_key().peek()).unwrap();
+
+        assert!(encrypt_secrets(&key, &Secret::new("jwt_secret = ".to_string())).is_err());
+        assert!(encrypt_secrets(&key, &Secret::new("port = 8080".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_decode_key() {
+        assert!(decode_key(&"ab".repeat(KEY_LENGTH)).is_ok());
+        assert!(decode_key(&"ab".repeat(KEY_LENGTH - 1)).is_err());
+        assert!(decode_key(&"zz".repeat(KEY_LENGTH)).is_err());
+    }
+}
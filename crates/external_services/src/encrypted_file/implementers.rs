@@ -0,0 +1,23 @@
+//! Trait implementations for the encrypted file secrets manager
+
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use hyperswitch_interfaces::secrets_interface::{
+    SecretManagementInterface, SecretsManagementError,
+};
+use masking::{ExposeInterface, Secret};
+
+use crate::encrypted_file::core::EncryptedFileSecretsManager;
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for EncryptedFileSecretsManager {
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError> {
+        let name = input.expose();
+        self.get(&name)
+            .ok_or(SecretsManagementError::FetchSecretFailed)
+            .attach_printable_lazy(|| format!("Secret `{name}` not found in the secrets file"))
+    }
+}
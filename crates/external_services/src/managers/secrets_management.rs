@@ -3,7 +3,6 @@
 //!
 
 use common_utils::errors::CustomResult;
-#[cfg(feature = "hashicorp-vault")]
 use error_stack::ResultExt;
 use hyperswitch_interfaces::secrets_interface::{
     SecretManagementInterface, SecretsManagementError,
@@ -13,7 +12,7 @@ use hyperswitch_interfaces::secrets_interface::{
 use crate::aws_kms;
 #[cfg(feature = "hashicorp-vault")]
 use crate::hashicorp_vault;
-use crate::no_encryption::core::NoEncryption;
+use crate::{encrypted_file, no_encryption::core::NoEncryption};
 
 /// Enum representing configuration options for secrets management.
 #[derive(Debug, Clone, Default, serde::Deserialize)]
@@ -34,6 +33,12 @@ pub enum SecretsManagementConfig {
         hc_vault: hashicorp_vault::core::HashiCorpVaultConfig,
     },
 
+    /// Locally encrypted secrets file configuration
+    EncryptedFile {
+        /// Encrypted secrets file config
+        encrypted_file: encrypted_file::core::EncryptedFileConfig,
+    },
+
     /// Variant representing no encryption
     #[default]
     NoEncryption,
@@ -47,6 +52,7 @@ impl SecretsManagementConfig {
             Self::AwsKms { aws_kms } => aws_kms.validate(),
             #[cfg(feature = "hashicorp-vault")]
             Self::HashiCorpVault { hc_vault } => hc_vault.validate(),
+            Self::EncryptedFile { encrypted_file } => encrypted_file.validate(),
             Self::NoEncryption => Ok(()),
         }
     }
@@ -66,6 +72,11 @@ impl SecretsManagementConfig {
                     .change_context(SecretsManagementError::ClientCreationFailed)
                     .map(|inner| -> Box<dyn SecretManagementInterface> { Box::new(inner) })
             }
+            Self::EncryptedFile { encrypted_file } => {
+                encrypted_file::core::EncryptedFileSecretsManager::new(encrypted_file)
+                    .change_context(SecretsManagementError::ClientCreationFailed)
+                    .map(|inner| -> Box<dyn SecretManagementInterface> { Box::new(inner) })
+            }
             Self::NoEncryption => Ok(Box::new(NoEncryption)),
         }
     }
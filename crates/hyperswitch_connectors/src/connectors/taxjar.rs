@@ -4,25 +4,33 @@ use common_utils::{
     errors::CustomResult,
     ext_traits::BytesExt,
     request::{Method, Request, RequestBuilder, RequestContent},
-    types::{AmountConvertor, StringMinorUnit, StringMinorUnitForConnector},
+    types::{AmountConvertor, FloatMajorUnit, FloatMajorUnitForConnector, MinorUnit},
 };
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
     router_data::{AccessToken, ConnectorAuthType, ErrorResponse, RouterData},
     router_flow_types::{
         access_token_auth::AccessTokenAuth,
-        payments::{Authorize, Capture, PSync, PaymentMethodToken, Session, SetupMandate, Void},
+        payments::{
+            Authorize, CalculateTax, Capture, CommitTax, PSync, PaymentMethodToken, ReverseTax,
+            Session, SetupMandate, Void,
+        },
         refunds::{Execute, RSync},
     },
     router_request_types::{
         AccessTokenRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
         PaymentsCancelData, PaymentsCaptureData, PaymentsSessionData, PaymentsSyncData,
-        RefundsData, SetupMandateRequestData,
+        PaymentsTaxCalculationData, RefundsData, SetupMandateRequestData, TaxCommitData,
+        TaxReversalData,
+    },
+    router_response_types::{
+        PaymentsResponseData, RefundsResponseData, TaxCalculationResponseData,
+        TaxTransactionResponseData,
     },
-    router_response_types::{PaymentsResponseData, RefundsResponseData},
     types::{
         PaymentsAuthorizeRouterData, PaymentsCaptureRouterData, PaymentsSyncRouterData,
-        RefundSyncRouterData, RefundsRouterData,
+        PaymentsTaxCalculationRouterData, RefundSyncRouterData, RefundsRouterData,
+        TaxCommitRouterData, TaxReversalRouterData,
     },
 };
 use hyperswitch_interfaces::{
@@ -40,13 +48,13 @@ use crate::{constants::headers, types::ResponseRouterData, utils};
 
 #[derive(Clone)]
 pub struct Taxjar {
-    amount_converter: &'static (dyn AmountConvertor<Output = StringMinorUnit> + Sync),
+    amount_converter: &'static (dyn AmountConvertor<Output = FloatMajorUnit> + Sync),
 }
 
 impl Taxjar {
     pub fn new() -> &'static Self {
         &Self {
-            amount_converter: &StringMinorUnitForConnector,
+            amount_converter: &FloatMajorUnitForConnector,
         }
     }
 }
@@ -63,6 +71,9 @@ impl api::Refund for Taxjar {}
 impl api::RefundExecute for Taxjar {}
 impl api::RefundSync for Taxjar {}
 impl api::PaymentToken for Taxjar {}
+impl api::PaymentsCalculateTax for Taxjar {}
+impl api::PaymentsCommitTax for Taxjar {}
+impl api::PaymentsReverseTax for Taxjar {}
 
 impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData>
     for Taxjar
@@ -96,9 +107,6 @@ impl ConnectorCommon for Taxjar {
 
     fn get_currency_unit(&self) -> api::CurrencyUnit {
         api::CurrencyUnit::Base
-        //    TODO! Check connector documentation, on which unit they are processing the currency.
-        //    If the connector accepts amount in lower unit ( i.e cents for USD) then return api::CurrencyUnit::Minor,
-        //    if connector accepts amount in base unit (i.e dollars for USD) then return api::CurrencyUnit::Base
     }
 
     fn common_get_content_type(&self) -> &'static str {
@@ -117,7 +125,7 @@ impl ConnectorCommon for Taxjar {
             .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
         Ok(vec![(
             headers::AUTHORIZATION.to_string(),
-            auth.api_key.expose().into_masked(),
+            format!("Bearer {}", auth.api_key.expose()).into_masked(),
         )])
     }
 
@@ -136,9 +144,9 @@ impl ConnectorCommon for Taxjar {
 
         Ok(ErrorResponse {
             status_code: res.status_code,
-            code: response.code,
-            message: response.message,
-            reason: response.reason,
+            code: response.error,
+            message: response.detail.clone(),
+            reason: Some(response.detail),
             attempt_status: None,
             connector_transaction_id: None,
         })
@@ -538,6 +546,264 @@ impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Taxjar {
     }
 }
 
+impl ConnectorIntegration<CalculateTax, PaymentsTaxCalculationData, TaxCalculationResponseData>
+    for Taxjar
+{
+    fn get_headers(
+        &self,
+        req: &PaymentsTaxCalculationRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &PaymentsTaxCalculationRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}taxes", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsTaxCalculationRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.amount,
+            req.request.currency,
+        )?;
+
+        let connector_router_data = taxjar::TaxjarRouterData::from((amount, req));
+        let connector_req = taxjar::TaxjarTaxCalculationRequest::try_from(&connector_router_data)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsTaxCalculationRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&types::PaymentsTaxCalculationType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::PaymentsTaxCalculationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::PaymentsTaxCalculationType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsTaxCalculationRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsTaxCalculationRouterData, errors::ConnectorError> {
+        let response: taxjar::TaxjarTaxCalculationResponse = res
+            .response
+            .parse_struct("Taxjar TaxCalculationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<CommitTax, TaxCommitData, TaxTransactionResponseData> for Taxjar {
+    fn get_headers(
+        &self,
+        req: &TaxCommitRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &TaxCommitRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}transactions/orders", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &TaxCommitRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.amount,
+            req.request.currency,
+        )?;
+
+        let connector_router_data = taxjar::TaxjarRouterData::from((amount, req));
+        let connector_req =
+            taxjar::TaxjarOrderTransactionRequest::try_from(&connector_router_data)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &TaxCommitRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&types::TaxCommitType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::TaxCommitType::get_headers(self, req, connectors)?)
+                .set_body(types::TaxCommitType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &TaxCommitRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<TaxCommitRouterData, errors::ConnectorError> {
+        let response: taxjar::TaxjarOrderTransactionResponse = res
+            .response
+            .parse_struct("Taxjar OrderTransactionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<ReverseTax, TaxReversalData, TaxTransactionResponseData> for Taxjar {
+    fn get_headers(
+        &self,
+        req: &TaxReversalRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &TaxReversalRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}transactions/refunds", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &TaxReversalRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        // Refund transactions are recorded with negative amounts on TaxJar
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            MinorUnit::new(0) - req.request.amount,
+            req.request.currency,
+        )?;
+
+        let connector_router_data = taxjar::TaxjarRouterData::from((amount, req));
+        let connector_req =
+            taxjar::TaxjarRefundTransactionRequest::try_from(&connector_router_data)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &TaxReversalRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&types::TaxReversalType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::TaxReversalType::get_headers(self, req, connectors)?)
+                .set_body(types::TaxReversalType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &TaxReversalRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<TaxReversalRouterData, errors::ConnectorError> {
+        let response: taxjar::TaxjarRefundTransactionResponse = res
+            .response
+            .parse_struct("Taxjar RefundTransactionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        RouterData::try_from(ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 #[async_trait::async_trait]
 impl webhooks::IncomingWebhook for Taxjar {
     fn get_webhook_object_reference_id(
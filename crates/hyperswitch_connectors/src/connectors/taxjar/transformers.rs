@@ -1,31 +1,37 @@
+use api_models::payments::{TaxJurisdictionBreakdown, TaxJurisdictionType};
 use common_enums::enums;
-use common_utils::types::StringMinorUnit;
+use common_utils::types::{FloatMajorUnit, FloatMajorUnitForConnector, MinorUnit};
 use hyperswitch_domain_models::{
     payment_method_data::PaymentMethodData,
     router_data::{ConnectorAuthType, RouterData},
     router_flow_types::refunds::{Execute, RSync},
-    router_request_types::ResponseId,
-    router_response_types::{PaymentsResponseData, RefundsResponseData},
-    types::{PaymentsAuthorizeRouterData, RefundsRouterData},
+    router_request_types::{PaymentsTaxCalculationData, ResponseId},
+    router_response_types::{
+        PaymentsResponseData, RefundsResponseData, TaxCalculationResponseData,
+        TaxTransactionResponseData,
+    },
+    types::{
+        PaymentsAuthorizeRouterData, PaymentsTaxCalculationRouterData, RefundsRouterData,
+        TaxCommitRouterData, TaxReversalRouterData,
+    },
 };
 use hyperswitch_interfaces::errors;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 
 use crate::{
     types::{RefundsResponseRouterData, ResponseRouterData},
-    utils::PaymentsAuthorizeRequestData,
+    utils::{self, AddressDetailsData, PaymentsAuthorizeRequestData, RouterData as _},
 };
 
-//TODO: Fill the struct with respective fields
 pub struct TaxjarRouterData<T> {
-    pub amount: StringMinorUnit, // The type of amount that a connector accepts, for example, String, i64, f64, etc.
+    pub amount: FloatMajorUnit,
     pub router_data: T,
 }
 
-impl<T> From<(StringMinorUnit, T)> for TaxjarRouterData<T> {
-    fn from((amount, item): (StringMinorUnit, T)) -> Self {
-        //Todo :  use utils to convert the amount to the type of amount that a connector accepts
+impl<T> From<(FloatMajorUnit, T)> for TaxjarRouterData<T> {
+    fn from((amount, item): (FloatMajorUnit, T)) -> Self {
         Self {
             amount,
             router_data: item,
@@ -36,7 +42,7 @@ impl<T> From<(StringMinorUnit, T)> for TaxjarRouterData<T> {
 //TODO: Fill the struct with respective fields
 #[derive(Default, Debug, Serialize, PartialEq)]
 pub struct TaxjarPaymentsRequest {
-    amount: StringMinorUnit,
+    amount: FloatMajorUnit,
     card: TaxjarCard,
 }
 
@@ -64,7 +70,7 @@ impl TryFrom<&TaxjarRouterData<&PaymentsAuthorizeRouterData>> for TaxjarPayments
                     complete: item.router_data.request.is_auto_capture()?,
                 };
                 Ok(Self {
-                    amount: item.amount.clone(),
+                    amount: item.amount,
                     card,
                 })
             }
@@ -147,14 +153,14 @@ impl<F, T> TryFrom<ResponseRouterData<F, TaxjarPaymentsResponse, T, PaymentsResp
 // Type definition for RefundRequest
 #[derive(Default, Debug, Serialize)]
 pub struct TaxjarRefundRequest {
-    pub amount: StringMinorUnit,
+    pub amount: FloatMajorUnit,
 }
 
 impl<F> TryFrom<&TaxjarRouterData<&RefundsRouterData<F>>> for TaxjarRefundRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &TaxjarRouterData<&RefundsRouterData<F>>) -> Result<Self, Self::Error> {
         Ok(Self {
-            amount: item.amount.to_owned(),
+            amount: item.amount,
         })
     }
 }
@@ -218,11 +224,343 @@ impl TryFrom<RefundsResponseRouterData<RSync, RefundResponse>> for RefundsRouter
     }
 }
 
-//TODO: Fill the struct with respective fields
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct TaxjarErrorResponse {
-    pub status_code: u16,
-    pub code: String,
-    pub message: String,
-    pub reason: Option<String>,
+    pub status: u16,
+    pub error: String,
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaxjarLineItem {
+    id: Option<String>,
+    quantity: u16,
+    unit_price: FloatMajorUnit,
+}
+
+fn get_line_items(
+    order_details: &[api_models::payments::OrderDetailsWithAmount],
+    currency: enums::Currency,
+) -> Result<Vec<TaxjarLineItem>, error_stack::Report<errors::ConnectorError>> {
+    order_details
+        .iter()
+        .map(|order| {
+            Ok(TaxjarLineItem {
+                id: order.product_id.clone(),
+                quantity: order.quantity,
+                unit_price: utils::convert_amount(
+                    &FloatMajorUnitForConnector,
+                    MinorUnit::new(order.amount),
+                    currency,
+                )?,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaxjarTaxCalculationRequest {
+    to_country: enums::CountryAlpha2,
+    to_zip: Secret<String>,
+    to_state: Secret<String>,
+    to_city: Option<String>,
+    to_street: Option<Secret<String>>,
+    amount: FloatMajorUnit,
+    shipping: FloatMajorUnit,
+    line_items: Vec<TaxjarLineItem>,
+}
+
+impl TryFrom<&TaxjarRouterData<&PaymentsTaxCalculationRouterData>> for TaxjarTaxCalculationRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &TaxjarRouterData<&PaymentsTaxCalculationRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let shipping_address = item.router_data.get_shipping_address()?;
+        Ok(Self {
+            to_country: *shipping_address.get_country()?,
+            to_zip: shipping_address.get_zip()?.to_owned(),
+            to_state: shipping_address.get_state()?.to_owned(),
+            to_city: shipping_address.city.clone(),
+            to_street: shipping_address.line1.clone(),
+            amount: item.amount,
+            shipping: FloatMajorUnit::zero(),
+            line_items: get_line_items(
+                &item.router_data.request.order_details,
+                item.router_data.request.currency,
+            )?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaxjarOrderTransactionRequest {
+    transaction_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    transaction_date: PrimitiveDateTime,
+    to_country: enums::CountryAlpha2,
+    to_zip: Secret<String>,
+    to_state: Secret<String>,
+    to_city: Option<String>,
+    to_street: Option<Secret<String>>,
+    amount: FloatMajorUnit,
+    shipping: FloatMajorUnit,
+    sales_tax: FloatMajorUnit,
+    /// Not reported for partially captured orders, whose captured lines are unknown
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    line_items: Vec<TaxjarLineItem>,
+}
+
+impl TryFrom<&TaxjarRouterData<&TaxCommitRouterData>> for TaxjarOrderTransactionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &TaxjarRouterData<&TaxCommitRouterData>) -> Result<Self, Self::Error> {
+        let request = &item.router_data.request;
+        let shipping_address = item.router_data.get_shipping_address()?;
+        Ok(Self {
+            transaction_id: request.transaction_id.clone(),
+            transaction_date: request.transaction_date,
+            to_country: *shipping_address.get_country()?,
+            to_zip: shipping_address.get_zip()?.to_owned(),
+            to_state: shipping_address.get_state()?.to_owned(),
+            to_city: shipping_address.city.clone(),
+            to_street: shipping_address.line1.clone(),
+            amount: item.amount,
+            shipping: FloatMajorUnit::zero(),
+            sales_tax: utils::convert_amount(
+                &FloatMajorUnitForConnector,
+                request.tax_amount,
+                request.currency,
+            )?,
+            line_items: get_line_items(&request.order_details, request.currency)?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaxjarRefundTransactionRequest {
+    transaction_id: String,
+    transaction_reference_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    transaction_date: PrimitiveDateTime,
+    to_country: enums::CountryAlpha2,
+    to_zip: Secret<String>,
+    to_state: Secret<String>,
+    to_city: Option<String>,
+    to_street: Option<Secret<String>>,
+    amount: FloatMajorUnit,
+    shipping: FloatMajorUnit,
+    sales_tax: FloatMajorUnit,
+}
+
+impl TryFrom<&TaxjarRouterData<&TaxReversalRouterData>> for TaxjarRefundTransactionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &TaxjarRouterData<&TaxReversalRouterData>) -> Result<Self, Self::Error> {
+        let request = &item.router_data.request;
+        let shipping_address = item.router_data.get_shipping_address()?;
+        Ok(Self {
+            transaction_id: request.transaction_id.clone(),
+            transaction_reference_id: request.reference_transaction_id.clone(),
+            transaction_date: request.transaction_date,
+            to_country: *shipping_address.get_country()?,
+            to_zip: shipping_address.get_zip()?.to_owned(),
+            to_state: shipping_address.get_state()?.to_owned(),
+            to_city: shipping_address.city.clone(),
+            to_street: shipping_address.line1.clone(),
+            amount: item.amount,
+            shipping: FloatMajorUnit::zero(),
+            sales_tax: utils::convert_amount(
+                &FloatMajorUnitForConnector,
+                MinorUnit::new(0) - request.tax_amount,
+                request.currency,
+            )?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxjarTaxCalculationResponse {
+    tax: TaxjarTax,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxjarTax {
+    taxable_amount: FloatMajorUnit,
+    amount_to_collect: FloatMajorUnit,
+    jurisdictions: Option<TaxjarJurisdictions>,
+    breakdown: Option<TaxjarBreakdown>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxjarJurisdictions {
+    country: Option<String>,
+    state: Option<String>,
+    county: Option<String>,
+    city: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxjarBreakdown {
+    country_taxable_amount: Option<FloatMajorUnit>,
+    country_tax_collectable: Option<FloatMajorUnit>,
+    state_taxable_amount: Option<FloatMajorUnit>,
+    state_tax_collectable: Option<FloatMajorUnit>,
+    county_taxable_amount: Option<FloatMajorUnit>,
+    county_tax_collectable: Option<FloatMajorUnit>,
+    city_taxable_amount: Option<FloatMajorUnit>,
+    city_tax_collectable: Option<FloatMajorUnit>,
+    special_district_taxable_amount: Option<FloatMajorUnit>,
+    special_district_tax_collectable: Option<FloatMajorUnit>,
+}
+
+fn convert_back_amount(
+    amount: FloatMajorUnit,
+    currency: enums::Currency,
+) -> Result<MinorUnit, error_stack::Report<errors::ConnectorError>> {
+    utils::convert_back_amount_to_minor_units(&FloatMajorUnitForConnector, amount, currency)
+}
+
+impl TaxjarBreakdown {
+    fn get_jurisdiction_breakdown(
+        &self,
+        jurisdictions: Option<&TaxjarJurisdictions>,
+        currency: enums::Currency,
+    ) -> Result<Vec<TaxJurisdictionBreakdown>, error_stack::Report<errors::ConnectorError>> {
+        [
+            (
+                TaxJurisdictionType::Country,
+                jurisdictions.and_then(|jurisdiction| jurisdiction.country.clone()),
+                self.country_taxable_amount,
+                self.country_tax_collectable,
+            ),
+            (
+                TaxJurisdictionType::State,
+                jurisdictions.and_then(|jurisdiction| jurisdiction.state.clone()),
+                self.state_taxable_amount,
+                self.state_tax_collectable,
+            ),
+            (
+                TaxJurisdictionType::County,
+                jurisdictions.and_then(|jurisdiction| jurisdiction.county.clone()),
+                self.county_taxable_amount,
+                self.county_tax_collectable,
+            ),
+            (
+                TaxJurisdictionType::City,
+                jurisdictions.and_then(|jurisdiction| jurisdiction.city.clone()),
+                self.city_taxable_amount,
+                self.city_tax_collectable,
+            ),
+            (
+                TaxJurisdictionType::SpecialDistrict,
+                None,
+                self.special_district_taxable_amount,
+                self.special_district_tax_collectable,
+            ),
+        ]
+        .into_iter()
+        .filter_map(
+            |(jurisdiction_type, jurisdiction_name, taxable_amount, tax_amount)| {
+                tax_amount.map(|tax_amount| {
+                    Ok(TaxJurisdictionBreakdown {
+                        jurisdiction_type,
+                        jurisdiction_name,
+                        taxable_amount: convert_back_amount(
+                            taxable_amount.unwrap_or(FloatMajorUnit::zero()),
+                            currency,
+                        )?,
+                        tax_amount: convert_back_amount(tax_amount, currency)?,
+                    })
+                })
+            },
+        )
+        .collect()
+    }
+}
+
+impl<F>
+    TryFrom<
+        ResponseRouterData<
+            F,
+            TaxjarTaxCalculationResponse,
+            PaymentsTaxCalculationData,
+            TaxCalculationResponseData,
+        >,
+    > for RouterData<F, PaymentsTaxCalculationData, TaxCalculationResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<
+            F,
+            TaxjarTaxCalculationResponse,
+            PaymentsTaxCalculationData,
+            TaxCalculationResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let currency = item.data.request.currency;
+        let tax = item.response.tax;
+        let breakdown = tax
+            .breakdown
+            .as_ref()
+            .map(|breakdown| {
+                breakdown.get_jurisdiction_breakdown(tax.jurisdictions.as_ref(), currency)
+            })
+            .transpose()?
+            .unwrap_or_default();
+        Ok(Self {
+            response: Ok(TaxCalculationResponseData {
+                taxable_amount: convert_back_amount(tax.taxable_amount, currency)?,
+                order_tax_amount: convert_back_amount(tax.amount_to_collect, currency)?,
+                breakdown,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxjarTransaction {
+    transaction_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxjarOrderTransactionResponse {
+    order: TaxjarTransaction,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxjarRefundTransactionResponse {
+    refund: TaxjarTransaction,
+}
+
+impl<F, T>
+    TryFrom<ResponseRouterData<F, TaxjarOrderTransactionResponse, T, TaxTransactionResponseData>>
+    for RouterData<F, T, TaxTransactionResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<F, TaxjarOrderTransactionResponse, T, TaxTransactionResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(TaxTransactionResponseData {
+                connector_transaction_id: item.response.order.transaction_id,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl<F, T>
+    TryFrom<ResponseRouterData<F, TaxjarRefundTransactionResponse, T, TaxTransactionResponseData>>
+    for RouterData<F, T, TaxTransactionResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: ResponseRouterData<F, TaxjarRefundTransactionResponse, T, TaxTransactionResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(TaxTransactionResponseData {
+                connector_transaction_id: item.response.refund.transaction_id,
+            }),
+            ..item.data
+        })
+    }
 }
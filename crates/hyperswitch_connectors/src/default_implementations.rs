@@ -28,8 +28,9 @@ use hyperswitch_domain_models::{
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
-            Approve, AuthorizeSessionToken, CompleteAuthorize, CreateConnectorCustomer,
-            IncrementalAuthorization, PostProcessing, PreProcessing, Reject,
+            Approve, AuthorizeSessionToken, CalculateTax, CommitTax, CompleteAuthorize,
+            CreateConnectorCustomer, IncrementalAuthorization, PostProcessing, PreProcessing,
+            Reject, ReverseTax,
         },
         webhooks::VerifyWebhookSource,
     },
@@ -37,12 +38,14 @@ use hyperswitch_domain_models::{
         AcceptDisputeRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
         ConnectorCustomerData, DefendDisputeRequestData, MandateRevokeRequestData,
         PaymentsApproveData, PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
-        PaymentsPreProcessingData, PaymentsRejectData, RetrieveFileRequestData,
-        SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
+        PaymentsPreProcessingData, PaymentsRejectData, PaymentsTaxCalculationData,
+        RetrieveFileRequestData, SubmitEvidenceRequestData, TaxCommitData, TaxReversalData,
+        UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
         AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
-        PaymentsResponseData, RetrieveFileResponse, SubmitEvidenceResponse, UploadFileResponse,
+        PaymentsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
+        TaxCalculationResponseData, TaxTransactionResponseData, UploadFileResponse,
         VerifyWebhookSourceResponseData,
     },
 };
@@ -63,8 +66,9 @@ use hyperswitch_interfaces::{
         files::{FileUpload, RetrieveFile, UploadFile},
         payments::{
             ConnectorCustomer, PaymentApprove, PaymentAuthorizeSessionToken,
-            PaymentIncrementalAuthorization, PaymentReject, PaymentsCompleteAuthorize,
-            PaymentsPostProcessing, PaymentsPreProcessing,
+            PaymentIncrementalAuthorization, PaymentReject, PaymentsCalculateTax,
+            PaymentsCommitTax, PaymentsCompleteAuthorize, PaymentsPostProcessing,
+            PaymentsPreProcessing, PaymentsReverseTax,
         },
         ConnectorIntegration, ConnectorMandateRevoke, ConnectorRedirectResponse,
     },
@@ -245,6 +249,78 @@ default_imp_for_post_processing_steps!(
     connectors::Taxjar
 );
 
+macro_rules! default_imp_for_calculate_tax {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl PaymentsCalculateTax for $path::$connector {}
+            impl
+            ConnectorIntegration<
+            CalculateTax,
+            PaymentsTaxCalculationData,
+            TaxCalculationResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_calculate_tax!(
+    connectors::Bambora,
+    connectors::Bitpay,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Helcim,
+    connectors::Stax
+);
+
+macro_rules! default_imp_for_commit_tax {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl PaymentsCommitTax for $path::$connector {}
+            impl
+            ConnectorIntegration<
+            CommitTax,
+            TaxCommitData,
+            TaxTransactionResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_commit_tax!(
+    connectors::Bambora,
+    connectors::Bitpay,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Helcim,
+    connectors::Stax
+);
+
+macro_rules! default_imp_for_reverse_tax {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl PaymentsReverseTax for $path::$connector {}
+            impl
+            ConnectorIntegration<
+            ReverseTax,
+            TaxReversalData,
+            TaxTransactionResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_reverse_tax!(
+    connectors::Bambora,
+    connectors::Bitpay,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Helcim,
+    connectors::Stax
+);
+
 macro_rules! default_imp_for_approve {
     ($($path:ident::$connector:ident),*) => {
         $(
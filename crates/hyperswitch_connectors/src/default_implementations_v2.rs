@@ -12,9 +12,10 @@ use hyperswitch_domain_models::{
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
-            Approve, Authorize, AuthorizeSessionToken, Capture, CompleteAuthorize,
-            CreateConnectorCustomer, IncrementalAuthorization, PSync, PaymentMethodToken,
-            PostProcessing, PreProcessing, Reject, Session, SetupMandate, Void,
+            Approve, Authorize, AuthorizeSessionToken, CalculateTax, Capture, CommitTax,
+            CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, PSync,
+            PaymentMethodToken, PostProcessing, PreProcessing, Reject, ReverseTax, Session,
+            SetupMandate, Void,
         },
         refunds::{Execute, RSync},
         webhooks::VerifyWebhookSource,
@@ -27,13 +28,15 @@ use hyperswitch_domain_models::{
         PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
         PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
         PaymentsPreProcessingData, PaymentsRejectData, PaymentsSessionData, PaymentsSyncData,
-        RefundsData, RetrieveFileRequestData, SetupMandateRequestData, SubmitEvidenceRequestData,
-        UploadFileRequestData, VerifyWebhookSourceRequestData,
+        PaymentsTaxCalculationData, RefundsData, RetrieveFileRequestData, SetupMandateRequestData,
+        SubmitEvidenceRequestData, TaxCommitData, TaxReversalData, UploadFileRequestData,
+        VerifyWebhookSourceRequestData,
     },
     router_response_types::{
         AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
         PaymentsResponseData, RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        UploadFileResponse, VerifyWebhookSourceResponseData,
+        TaxCalculationResponseData, TaxTransactionResponseData, UploadFileResponse,
+        VerifyWebhookSourceResponseData,
     },
 };
 #[cfg(feature = "frm")]
@@ -74,8 +77,9 @@ use hyperswitch_interfaces::{
             ConnectorCustomerV2, MandateSetupV2, PaymentApproveV2, PaymentAuthorizeSessionTokenV2,
             PaymentAuthorizeV2, PaymentCaptureV2, PaymentIncrementalAuthorizationV2,
             PaymentRejectV2, PaymentSessionV2, PaymentSyncV2, PaymentTokenV2, PaymentV2,
-            PaymentVoidV2, PaymentsCompleteAuthorizeV2, PaymentsPostProcessingV2,
-            PaymentsPreProcessingV2,
+            PaymentVoidV2, PaymentsCalculateTaxV2, PaymentsCommitTaxV2,
+            PaymentsCompleteAuthorizeV2, PaymentsPostProcessingV2, PaymentsPreProcessingV2,
+            PaymentsReverseTaxV2,
         },
         refunds_v2::{RefundExecuteV2, RefundSyncV2, RefundV2},
         ConnectorAccessTokenV2, ConnectorMandateRevokeV2, ConnectorVerifyWebhookSourceV2,
@@ -104,6 +108,9 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             impl ConnectorCustomerV2 for $path::$connector{}
             impl PaymentsPreProcessingV2 for $path::$connector{}
             impl PaymentsPostProcessingV2 for $path::$connector{}
+            impl PaymentsCalculateTaxV2 for $path::$connector{}
+            impl PaymentsCommitTaxV2 for $path::$connector{}
+            impl PaymentsReverseTaxV2 for $path::$connector{}
             impl
             ConnectorIntegrationV2<Authorize,PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData>
             for $path::$connector{}
@@ -169,6 +176,24 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 PaymentsPostProcessingData,
                 PaymentsResponseData,
             > for $path::$connector{}
+            impl ConnectorIntegrationV2<
+            CalculateTax,
+            PaymentFlowData,
+                PaymentsTaxCalculationData,
+                TaxCalculationResponseData,
+            > for $path::$connector{}
+            impl ConnectorIntegrationV2<
+            CommitTax,
+            PaymentFlowData,
+                TaxCommitData,
+                TaxTransactionResponseData,
+            > for $path::$connector{}
+            impl ConnectorIntegrationV2<
+            ReverseTax,
+            PaymentFlowData,
+                TaxReversalData,
+                TaxTransactionResponseData,
+            > for $path::$connector{}
             impl
             ConnectorIntegrationV2<
                 AuthorizeSessionToken,
@@ -17,6 +17,10 @@ pub struct MerchantKeyStore {
     pub key: Encryptable<Secret<Vec<u8>>>,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    /// The key which was replaced by the latest key rotation, retained until all the data is
+    /// re-encrypted with the current key
+    pub previous_key: Option<Encryptable<Secret<Vec<u8>>>>,
 }
 
 #[async_trait::async_trait]
@@ -28,6 +32,8 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             key: self.key.into(),
             merchant_id: self.merchant_id,
             created_at: self.created_at,
+            key_version: self.key_version,
+            previous_key: self.previous_key.map(Into::into),
         })
     }
 
@@ -42,21 +48,45 @@ impl super::behaviour::Conversion for MerchantKeyStore {
     {
         let identifier = keymanager::Identifier::Merchant(item.merchant_id.clone());
 
+        let decrypted_key: Encryptable<Secret<Vec<u8>>> = crypto_operation(
+            state,
+            type_name!(Self::DstType),
+            CryptoOperation::Decrypt(item.key),
+            identifier.clone(),
+            key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting customer data".to_string(),
+        })?;
+        let previous_key: Option<Encryptable<Secret<Vec<u8>>>> = crypto_operation(
+            state,
+            type_name!(Self::DstType),
+            CryptoOperation::DecryptOptional(item.previous_key),
+            identifier,
+            key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_optionaloperation())
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting previous merchant key".to_string(),
+        })?;
+        // The previous key is registered for the state the key store is loaded with, so that the
+        // data which was not re-encrypted yet is decrypted with it
+        state.previous_keys.set(
+            decrypted_key.get_inner().peek(),
+            previous_key
+                .as_ref()
+                .map(|previous_key| previous_key.get_inner().clone()),
+        );
+
         Ok(Self {
-            key: crypto_operation(
-                state,
-                type_name!(Self::DstType),
-                CryptoOperation::Decrypt(item.key),
-                identifier,
-                key.peek(),
-            )
-            .await
-            .and_then(|val| val.try_into_operation())
-            .change_context(ValidationError::InvalidValue {
-                message: "Failed while decrypting customer data".to_string(),
-            })?,
+            key: decrypted_key,
             merchant_id: item.merchant_id,
             created_at: item.created_at,
+            key_version: item.key_version,
+            previous_key,
         })
     }
 
@@ -65,6 +95,8 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             merchant_id: self.merchant_id,
             key: self.key.into(),
             created_at: date_time::now(),
+            key_version: self.key_version,
+            previous_key: self.previous_key.map(Into::into),
         })
     }
 }
@@ -67,4 +67,7 @@ pub struct PaymentIntent {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub tax_details: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub split_payments: Option<serde_json::Value>,
 }
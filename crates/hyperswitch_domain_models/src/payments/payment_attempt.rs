@@ -181,6 +181,7 @@ pub struct PaymentAttempt {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
 }
 
 impl PaymentAttempt {
@@ -271,6 +272,7 @@ pub struct PaymentAttemptNew {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
 }
 
 impl PaymentAttemptNew {
@@ -308,6 +310,7 @@ pub enum PaymentAttemptUpdate {
         fingerprint_id: Option<String>,
         payment_method_billing_address_id: Option<String>,
         updated_by: String,
+        sca_exemption: Option<storage_enums::ScaExemptionType>,
     },
     UpdateTrackers {
         payment_token: Option<String>,
@@ -354,6 +357,7 @@ pub enum PaymentAttemptUpdate {
         client_source: Option<String>,
         client_version: Option<String>,
         customer_acceptance: Option<pii::SecretSerdeValue>,
+        sca_exemption: Option<storage_enums::ScaExemptionType>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -542,6 +546,9 @@ impl behaviour::Conversion for PaymentIntent {
             merchant_order_reference_id: self.merchant_order_reference_id,
             shipping_details: self.shipping_details.map(Encryption::from),
             is_payment_processor_token_flow: self.is_payment_processor_token_flow,
+            tax_details: self.tax_details,
+            l2_l3_data: self.l2_l3_data,
+            split_payments: self.split_payments,
         })
     }
     async fn convert_back(
@@ -624,6 +631,9 @@ impl behaviour::Conversion for PaymentIntent {
                     .async_lift(inner_decrypt)
                     .await?,
                 is_payment_processor_token_flow: storage_model.is_payment_processor_token_flow,
+                tax_details: storage_model.tax_details,
+                l2_l3_data: storage_model.l2_l3_data,
+                split_payments: storage_model.split_payments,
             })
         }
         .await
@@ -682,6 +692,9 @@ impl behaviour::Conversion for PaymentIntent {
             merchant_order_reference_id: self.merchant_order_reference_id,
             shipping_details: self.shipping_details.map(Encryption::from),
             is_payment_processor_token_flow: self.is_payment_processor_token_flow,
+            tax_details: self.tax_details,
+            l2_l3_data: self.l2_l3_data,
+            split_payments: self.split_payments,
         })
     }
 }
@@ -742,6 +755,9 @@ impl behaviour::Conversion for PaymentIntent {
             merchant_order_reference_id: self.merchant_order_reference_id,
             shipping_details: self.shipping_details.map(Encryption::from),
             is_payment_processor_token_flow: self.is_payment_processor_token_flow,
+            tax_details: self.tax_details,
+            l2_l3_data: self.l2_l3_data,
+            split_payments: self.split_payments,
         })
     }
 
@@ -825,6 +841,9 @@ impl behaviour::Conversion for PaymentIntent {
                     .async_lift(inner_decrypt)
                     .await?,
                 is_payment_processor_token_flow: storage_model.is_payment_processor_token_flow,
+                tax_details: storage_model.tax_details,
+                l2_l3_data: storage_model.l2_l3_data,
+                split_payments: storage_model.split_payments,
             })
         }
         .await
@@ -883,6 +902,9 @@ impl behaviour::Conversion for PaymentIntent {
             merchant_order_reference_id: self.merchant_order_reference_id,
             shipping_details: self.shipping_details.map(Encryption::from),
             is_payment_processor_token_flow: self.is_payment_processor_token_flow,
+            tax_details: self.tax_details,
+            l2_l3_data: self.l2_l3_data,
+            split_payments: self.split_payments,
         })
     }
 }
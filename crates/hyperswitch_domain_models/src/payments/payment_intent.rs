@@ -144,6 +144,9 @@ pub struct PaymentIntentNew {
     pub billing_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub tax_details: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub split_payments: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -174,6 +177,8 @@ pub struct PaymentIntentUpdateFields {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub tax_details: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -249,6 +254,20 @@ pub enum PaymentIntentUpdate {
         status: Option<storage_enums::IntentStatus>,
         updated_by: String,
     },
+    CustomerDetailsRedaction {
+        customer_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        billing_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        updated_by: String,
+    },
+    TaxDetailsUpdate {
+        tax_details: serde_json::Value,
+        updated_by: String,
+    },
+    SplitPaymentsUpdate {
+        split_payments: serde_json::Value,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -291,6 +310,9 @@ pub struct PaymentIntentUpdateInternal {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub tax_details: Option<serde_json::Value>,
+    pub l2_l3_data: Option<serde_json::Value>,
+    pub split_payments: Option<serde_json::Value>,
 }
 
 impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
@@ -305,6 +327,24 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentIntentUpdate::TaxDetailsUpdate {
+                tax_details,
+                updated_by,
+            } => Self {
+                tax_details: Some(tax_details),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
+            PaymentIntentUpdate::SplitPaymentsUpdate {
+                split_payments,
+                updated_by,
+            } => Self {
+                split_payments: Some(split_payments),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
             PaymentIntentUpdate::Update(value) => Self {
                 amount: Some(value.amount),
                 currency: Some(value.currency),
@@ -333,6 +373,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: value.merchant_order_reference_id,
                 shipping_details: value.shipping_details,
                 is_payment_processor_token_flow: value.is_payment_processor_token_flow,
+                tax_details: value.tax_details,
+                l2_l3_data: value.l2_l3_data,
                 ..Default::default()
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
@@ -473,6 +515,19 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentIntentUpdate::CustomerDetailsRedaction {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                customer_details,
+                billing_details,
+                shipping_details,
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -536,6 +591,8 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                     merchant_order_reference_id: value.merchant_order_reference_id,
                     shipping_details: value.shipping_details.map(Encryption::from),
                     is_payment_processor_token_flow: value.is_payment_processor_token_flow,
+                    tax_details: value.tax_details,
+                    l2_l3_data: value.l2_l3_data,
                 }))
             }
             PaymentIntentUpdate::PaymentCreateUpdate {
@@ -636,6 +693,31 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
             PaymentIntentUpdate::ManualUpdate { status, updated_by } => {
                 Self::ManualUpdate { status, updated_by }
             }
+            PaymentIntentUpdate::CustomerDetailsRedaction {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self::CustomerDetailsRedaction {
+                customer_details: customer_details.map(Encryption::from),
+                billing_details: billing_details.map(Encryption::from),
+                shipping_details: shipping_details.map(Encryption::from),
+                updated_by,
+            },
+            PaymentIntentUpdate::TaxDetailsUpdate {
+                tax_details,
+                updated_by,
+            } => Self::TaxDetailsUpdate {
+                tax_details,
+                updated_by,
+            },
+            PaymentIntentUpdate::SplitPaymentsUpdate {
+                split_payments,
+                updated_by,
+            } => Self::SplitPaymentsUpdate {
+                split_payments,
+                updated_by,
+            },
         }
     }
 }
@@ -680,6 +762,9 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             merchant_order_reference_id,
             shipping_details,
             is_payment_processor_token_flow,
+            tax_details,
+            l2_l3_data,
+            split_payments,
         } = value;
 
         Self {
@@ -718,6 +803,9 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             merchant_order_reference_id,
             shipping_details: shipping_details.map(Encryption::from),
             is_payment_processor_token_flow,
+            tax_details,
+            l2_l3_data,
+            split_payments,
         }
     }
 }
@@ -49,3 +49,12 @@ pub struct IncrementalAuthorization;
 
 #[derive(Debug, Clone)]
 pub struct PostProcessing;
+
+#[derive(Debug, Clone)]
+pub struct CalculateTax;
+
+#[derive(Debug, Clone)]
+pub struct CommitTax;
+
+#[derive(Debug, Clone)]
+pub struct ReverseTax;
@@ -59,6 +59,8 @@ pub struct PaymentsAuthorizeData {
     pub metadata: Option<serde_json::Value>,
     pub authentication_data: Option<AuthenticationData>,
     pub charges: Option<PaymentCharges>,
+    /// The SCA exemption decided for the payment, to be flagged to the connector
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
 
     // New amount for amount frame work
     pub minor_amount: MinorUnit,
@@ -68,6 +70,10 @@ pub struct PaymentsAuthorizeData {
     /// In case the connector supports only one reference id, Hyperswitch's Payment ID will be sent as reference.
     pub merchant_order_reference_id: Option<String>,
     pub integrity_object: Option<AuthoriseIntegrityObject>,
+    /// Level 2 / Level 3 commercial card data to be sent to connectors supporting it
+    pub l2_l3_data: Option<api_models::payments::L2L3Data>,
+    /// How the payment is split between the platform and its sub-merchants
+    pub split_payments: Option<api_models::payments::SplitPaymentsDetails>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -441,6 +447,39 @@ pub struct PaymentsCancelData {
     pub minor_amount: Option<MinorUnit>,
 }
 
+#[derive(Debug, Clone)]
+pub struct PaymentsTaxCalculationData {
+    /// total amount of the order lines the tax is calculated on
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub order_details: Vec<api_models::payments::OrderDetailsWithAmount>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TaxCommitData {
+    /// payment id, used as the transaction reference on the tax connector
+    pub transaction_id: String,
+    pub transaction_date: time::PrimitiveDateTime,
+    /// total amount of the order lines, excluding the tax
+    pub amount: MinorUnit,
+    pub tax_amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub order_details: Vec<api_models::payments::OrderDetailsWithAmount>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TaxReversalData {
+    /// refund id, used as the transaction reference on the tax connector
+    pub transaction_id: String,
+    /// transaction reference of the committed payment being refunded
+    pub reference_transaction_id: String,
+    pub transaction_date: time::PrimitiveDateTime,
+    /// refunded amount, excluding the tax
+    pub amount: MinorUnit,
+    pub tax_amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct PaymentsRejectData {
     pub amount: Option<i64>,
@@ -574,6 +613,8 @@ pub struct RefundsData {
     pub browser_info: Option<BrowserInformation>,
     /// Charges associated with the payment
     pub charges: Option<ChargeRefunds>,
+    /// The amounts to be reversed from the recipients of a split payment
+    pub split_refunds: Option<Vec<api_models::payments::SplitRefundAllocation>>,
 
     // New amount for amount frame work
     pub minor_payment_amount: MinorUnit,
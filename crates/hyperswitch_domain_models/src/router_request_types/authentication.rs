@@ -91,6 +91,8 @@ pub struct ConnectorAuthenticationRequestData {
     pub threeds_method_comp_ind: api_models::payments::ThreeDsCompletionIndicator,
     pub three_ds_requestor_url: String,
     pub webhook_url: String,
+    /// The SCA exemption to be requested from the 3DS server
+    pub sca_exemption: Option<common_enums::ScaExemptionType>,
 }
 
 #[derive(Clone, serde::Deserialize, Debug, serde::Serialize, PartialEq, Eq)]
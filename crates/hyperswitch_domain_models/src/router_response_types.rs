@@ -70,6 +70,18 @@ pub enum PaymentsResponseData {
     },
 }
 
+#[derive(Debug, Clone)]
+pub struct TaxCalculationResponseData {
+    pub taxable_amount: MinorUnit,
+    pub order_tax_amount: MinorUnit,
+    pub breakdown: Vec<api_models::payments::TaxJurisdictionBreakdown>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TaxTransactionResponseData {
+    pub connector_transaction_id: String,
+}
+
 #[derive(serde::Serialize, Debug, Clone)]
 pub struct MandateReference {
     pub connector_mandate_id: Option<String>,
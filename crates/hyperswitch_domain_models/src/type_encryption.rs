@@ -8,7 +8,7 @@ use common_utils::{
     types::keymanager::{Identifier, KeyManagerState},
 };
 use encrypt::TypeEncryption;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use router_env::{instrument, tracing};
 use rustc_hash::FxHashMap;
 
@@ -925,13 +925,49 @@ async fn decrypt<T: Clone, S: masking::Strategy<T>>(
 where
     crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
 {
-    record_operation_time(
-        crypto::Encryptable::decrypt_via_api(state, inner, identifier, key, crypto::GcmAes256),
+    let Some(previous_key) = state.previous_keys.get(key) else {
+        return record_operation_time(
+            crypto::Encryptable::decrypt_via_api(state, inner, identifier, key, crypto::GcmAes256),
+            &metrics::DECRYPTION_TIME,
+            &metrics::CONTEXT,
+            &[],
+        )
+        .await;
+    };
+
+    let decrypted = record_operation_time(
+        crypto::Encryptable::decrypt_via_api(
+            state,
+            inner.clone(),
+            identifier.clone(),
+            key,
+            crypto::GcmAes256,
+        ),
         &metrics::DECRYPTION_TIME,
         &metrics::CONTEXT,
         &[],
     )
-    .await
+    .await;
+    match decrypted {
+        Ok(decrypted) => Ok(decrypted),
+        // The data was not re-encrypted since the key was rotated
+        Err(_) => {
+            metrics::PREVIOUS_KEY_DECRYPTION_COUNT.add(&metrics::CONTEXT, 1, &[]);
+            record_operation_time(
+                crypto::Encryptable::decrypt_via_api(
+                    state,
+                    inner,
+                    identifier,
+                    previous_key.peek(),
+                    crypto::GcmAes256,
+                ),
+                &metrics::DECRYPTION_TIME,
+                &metrics::CONTEXT,
+                &[],
+            )
+            .await
+        }
+    }
 }
 
 #[inline]
@@ -945,8 +981,11 @@ where
     S: masking::Strategy<E>,
     crypto::Encryptable<Secret<E, S>>: TypeEncryption<E, crypto::GcmAes256, S>,
 {
-    if !inner.is_empty() {
-        record_operation_time(
+    if inner.is_empty() {
+        return Ok(FxHashMap::default());
+    }
+    if state.previous_keys.get(key).is_none() {
+        return record_operation_time(
             crypto::Encryptable::batch_decrypt_via_api(
                 state,
                 inner,
@@ -958,9 +997,36 @@ where
             &metrics::CONTEXT,
             &[],
         )
+        .await;
+    }
+
+    let decrypted = record_operation_time(
+        crypto::Encryptable::batch_decrypt_via_api(
+            state,
+            inner.clone(),
+            identifier.clone(),
+            key,
+            crypto::GcmAes256,
+        ),
+        &metrics::ENCRYPTION_TIME,
+        &metrics::CONTEXT,
+        &[],
+    )
+    .await;
+    match decrypted {
+        Ok(decrypted) => Ok(decrypted),
+        // The values may be encrypted with either key while the data is being re-encrypted, so they
+        // are decrypted one by one
+        Err(_) => futures::future::try_join_all(inner.into_iter().map(|(field, value)| {
+            let identifier = identifier.clone();
+            async move {
+                decrypt(state, value, identifier, key)
+                    .await
+                    .map(|decrypted| (field, decrypted))
+            }
+        }))
         .await
-    } else {
-        Ok(FxHashMap::default())
+        .map(|decrypted| decrypted.into_iter().collect()),
     }
 }
 
@@ -1036,4 +1102,5 @@ pub(crate) mod metrics {
     counter_metric!(DECRYPTION_API_FAILURES, GLOBAL_METER);
     counter_metric!(APPLICATION_ENCRYPTION_COUNT, GLOBAL_METER);
     counter_metric!(APPLICATION_DECRYPTION_COUNT, GLOBAL_METER);
+    counter_metric!(PREVIOUS_KEY_DECRYPTION_COUNT, GLOBAL_METER);
 }
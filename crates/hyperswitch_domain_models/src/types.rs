@@ -1,15 +1,19 @@
 use crate::{
     router_data::RouterData,
     router_flow_types::{
-        Authorize, Capture, CompleteAuthorize, CreateConnectorCustomer, PSync, PaymentMethodToken,
-        RSync, SetupMandate, Void,
+        Authorize, CalculateTax, Capture, CommitTax, CompleteAuthorize, CreateConnectorCustomer,
+        PSync, PaymentMethodToken, RSync, ReverseTax, SetupMandate, Void,
     },
     router_request_types::{
         CompleteAuthorizeData, ConnectorCustomerData, PaymentMethodTokenizationData,
         PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData, PaymentsSyncData,
-        RefundsData, SetupMandateRequestData,
+        PaymentsTaxCalculationData, RefundsData, SetupMandateRequestData, TaxCommitData,
+        TaxReversalData,
+    },
+    router_response_types::{
+        PaymentsResponseData, RefundsResponseData, TaxCalculationResponseData,
+        TaxTransactionResponseData,
     },
-    router_response_types::{PaymentsResponseData, RefundsResponseData},
 };
 
 pub type PaymentsAuthorizeRouterData =
@@ -27,3 +31,8 @@ pub type ConnectorCustomerRouterData =
     RouterData<CreateConnectorCustomer, ConnectorCustomerData, PaymentsResponseData>;
 pub type PaymentsCompleteAuthorizeRouterData =
     RouterData<CompleteAuthorize, CompleteAuthorizeData, PaymentsResponseData>;
+pub type PaymentsTaxCalculationRouterData =
+    RouterData<CalculateTax, PaymentsTaxCalculationData, TaxCalculationResponseData>;
+pub type TaxCommitRouterData = RouterData<CommitTax, TaxCommitData, TaxTransactionResponseData>;
+pub type TaxReversalRouterData =
+    RouterData<ReverseTax, TaxReversalData, TaxTransactionResponseData>;
@@ -2,18 +2,22 @@
 
 use hyperswitch_domain_models::{
     router_flow_types::payments::{
-        Approve, Authorize, AuthorizeSessionToken, Capture, CompleteAuthorize,
-        CreateConnectorCustomer, IncrementalAuthorization, PSync, PaymentMethodToken,
-        PostProcessing, PreProcessing, Reject, Session, SetupMandate, Void,
+        Approve, Authorize, AuthorizeSessionToken, CalculateTax, Capture, CommitTax,
+        CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, PSync,
+        PaymentMethodToken, PostProcessing, PreProcessing, Reject, ReverseTax, Session,
+        SetupMandate, Void,
     },
     router_request_types::{
         AuthorizeSessionTokenData, CompleteAuthorizeData, ConnectorCustomerData,
         PaymentMethodTokenizationData, PaymentsApproveData, PaymentsAuthorizeData,
         PaymentsCancelData, PaymentsCaptureData, PaymentsIncrementalAuthorizationData,
         PaymentsPostProcessingData, PaymentsPreProcessingData, PaymentsRejectData,
-        PaymentsSessionData, PaymentsSyncData, SetupMandateRequestData,
+        PaymentsSessionData, PaymentsSyncData, PaymentsTaxCalculationData, SetupMandateRequestData,
+        TaxCommitData, TaxReversalData,
+    },
+    router_response_types::{
+        PaymentsResponseData, TaxCalculationResponseData, TaxTransactionResponseData,
     },
-    router_response_types::PaymentsResponseData,
 };
 
 use crate::api;
@@ -37,6 +41,9 @@ pub trait Payment:
     + PaymentsPostProcessing
     + ConnectorCustomer
     + PaymentIncrementalAuthorization
+    + PaymentsCalculateTax
+    + PaymentsCommitTax
+    + PaymentsReverseTax
 {
 }
 
@@ -133,3 +140,21 @@ pub trait PaymentsPostProcessing:
     api::ConnectorIntegration<PostProcessing, PaymentsPostProcessingData, PaymentsResponseData>
 {
 }
+
+/// trait PaymentsCalculateTax
+pub trait PaymentsCalculateTax:
+    api::ConnectorIntegration<CalculateTax, PaymentsTaxCalculationData, TaxCalculationResponseData>
+{
+}
+
+/// trait PaymentsCommitTax
+pub trait PaymentsCommitTax:
+    api::ConnectorIntegration<CommitTax, TaxCommitData, TaxTransactionResponseData>
+{
+}
+
+/// trait PaymentsReverseTax
+pub trait PaymentsReverseTax:
+    api::ConnectorIntegration<ReverseTax, TaxReversalData, TaxTransactionResponseData>
+{
+}
@@ -3,18 +3,22 @@
 use hyperswitch_domain_models::{
     router_data_v2::PaymentFlowData,
     router_flow_types::payments::{
-        Approve, Authorize, AuthorizeSessionToken, Capture, CompleteAuthorize,
-        CreateConnectorCustomer, IncrementalAuthorization, PSync, PaymentMethodToken,
-        PostProcessing, PreProcessing, Reject, Session, SetupMandate, Void,
+        Approve, Authorize, AuthorizeSessionToken, CalculateTax, Capture, CommitTax,
+        CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, PSync,
+        PaymentMethodToken, PostProcessing, PreProcessing, Reject, ReverseTax, Session,
+        SetupMandate, Void,
     },
     router_request_types::{
         AuthorizeSessionTokenData, CompleteAuthorizeData, ConnectorCustomerData,
         PaymentMethodTokenizationData, PaymentsApproveData, PaymentsAuthorizeData,
         PaymentsCancelData, PaymentsCaptureData, PaymentsIncrementalAuthorizationData,
         PaymentsPostProcessingData, PaymentsPreProcessingData, PaymentsRejectData,
-        PaymentsSessionData, PaymentsSyncData, SetupMandateRequestData,
+        PaymentsSessionData, PaymentsSyncData, PaymentsTaxCalculationData, SetupMandateRequestData,
+        TaxCommitData, TaxReversalData,
+    },
+    router_response_types::{
+        PaymentsResponseData, TaxCalculationResponseData, TaxTransactionResponseData,
     },
-    router_response_types::PaymentsResponseData,
 };
 
 use crate::api::{ConnectorCommon, ConnectorIntegrationV2, ConnectorValidation};
@@ -144,6 +148,29 @@ pub trait PaymentsPostProcessingV2:
 {
 }
 
+/// trait PaymentsCalculateTaxV2
+pub trait PaymentsCalculateTaxV2:
+    ConnectorIntegrationV2<
+    CalculateTax,
+    PaymentFlowData,
+    PaymentsTaxCalculationData,
+    TaxCalculationResponseData,
+>
+{
+}
+
+/// trait PaymentsCommitTaxV2
+pub trait PaymentsCommitTaxV2:
+    ConnectorIntegrationV2<CommitTax, PaymentFlowData, TaxCommitData, TaxTransactionResponseData>
+{
+}
+
+/// trait PaymentsReverseTaxV2
+pub trait PaymentsReverseTaxV2:
+    ConnectorIntegrationV2<ReverseTax, PaymentFlowData, TaxReversalData, TaxTransactionResponseData>
+{
+}
+
 /// trait PaymentV2
 pub trait PaymentV2:
     ConnectorCommon
@@ -163,5 +190,8 @@ pub trait PaymentV2:
     + PaymentsPostProcessingV2
     + ConnectorCustomerV2
     + PaymentIncrementalAuthorizationV2
+    + PaymentsCalculateTaxV2
+    + PaymentsCommitTaxV2
+    + PaymentsReverseTaxV2
 {
 }
@@ -239,11 +239,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorType,
         api_models::enums::PayoutConnectors,
         api_models::enums::AuthenticationConnectors,
+        api_models::enums::TaxConnectors,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
+        api_models::enums::ScaExemptionType,
         api_models::enums::Connector,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
@@ -381,6 +383,21 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ApplePayAddressParameters,
         api_models::payments::AmountInfo,
         api_models::payments::ProductType,
+        api_models::payments::TaxDetails,
+        api_models::payments::L2L3Data,
+        api_models::payments::L2L3LineItem,
+        api_models::payments::SplitPaymentsRequest,
+        api_models::payments::SplitPaymentRecipient,
+        api_models::payments::SplitShare,
+        api_models::payments::SplitPaymentsDetails,
+        api_models::payments::SplitPaymentAllocation,
+        api_models::payments::SplitRefundAllocation,
+        api_models::payments::SplitPaymentsReportRequest,
+        api_models::payments::SplitPaymentsReportResponse,
+        api_models::payments::SplitRecipientReport,
+        api_models::payments::TaxJurisdictionBreakdown,
+        api_models::payments::TaxJurisdictionType,
+        api_models::payments::TaxReversal,
         api_models::payments::GooglePayWalletData,
         api_models::payments::PayPalWalletData,
         api_models::payments::PaypalRedirection,
@@ -441,6 +458,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::SdkInformation,
         api_models::payments::DeviceChannel,
         api_models::payments::ThreeDsCompletionIndicator,
+        api_models::payments::ScaExemptionConfig,
+        api_models::payments::LowValueExemptionConfig,
+        api_models::payments::TransactionRiskAnalysisExemptionConfig,
+        api_models::payments::TransactionRiskAnalysisThreshold,
         api_models::payments::MifinityData,
         api_models::enums::TransactionStatus,
         api_models::payments::BrowserInformation,
@@ -0,0 +1,938 @@
+//! An in-process stand-in for a Redis server.
+//!
+//! Enabled through the `in_memory` feature, [`InMemoryRedis`] is plugged into fred's mocking layer
+//! so that every command issued by a [`RedisConnectionPool`](crate::RedisConnectionPool) is
+//! answered from process memory instead of a network connection. Only the subset of commands used
+//! by this crate is supported. All pools created within a process share the same keyspace, the
+//! same way separate connections to a single Redis server would.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use fred::{
+    error::{RedisError, RedisErrorKind},
+    mocks::{MockCommand, Mocks},
+    types::RedisValue,
+};
+
+static KEYSPACE: OnceLock<Arc<Mutex<Keyspace>>> = OnceLock::new();
+
+#[derive(Debug, Clone)]
+pub struct InMemoryRedis {
+    keyspace: Arc<Mutex<Keyspace>>,
+}
+
+impl Default for InMemoryRedis {
+    fn default() -> Self {
+        Self {
+            keyspace: Arc::clone(KEYSPACE.get_or_init(Default::default)),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Keyspace {
+    entries: HashMap<String, Entry>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    value: Value,
+    expires_at: Option<SystemTime>,
+}
+
+#[derive(Debug)]
+enum Value {
+    String(RedisValue),
+    Hash(HashMap<String, RedisValue>),
+    Set(HashSet<String>),
+    List(VecDeque<RedisValue>),
+    Stream(Stream),
+}
+
+#[derive(Debug, Default)]
+struct Stream {
+    entries: Vec<(StreamId, Vec<(String, RedisValue)>)>,
+    last_id: StreamId,
+    groups: HashMap<String, ConsumerGroup>,
+}
+
+#[derive(Debug, Default)]
+struct ConsumerGroup {
+    last_delivered: StreamId,
+    /// Entries delivered to a consumer but not yet acknowledged, along with the owning consumer.
+    pending: HashMap<StreamId, String>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct StreamId(u64, u64);
+
+impl StreamId {
+    fn parse(id: &str) -> Result<Self, RedisError> {
+        let (millis, sequence) = id.split_once('-').unwrap_or((id, "0"));
+        match (millis.parse(), sequence.parse()) {
+            (Ok(millis), Ok(sequence)) => Ok(Self(millis, sequence)),
+            _ => Err(invalid_argument(format!("Invalid stream ID: {id}"))),
+        }
+    }
+}
+
+impl std::fmt::Display for StreamId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.0, self.1)
+    }
+}
+
+impl Mocks for InMemoryRedis {
+    fn process_command(&self, command: MockCommand) -> Result<RedisValue, RedisError> {
+        let mut keyspace = self
+            .keyspace
+            .lock()
+            .map_err(|_| RedisError::new(RedisErrorKind::Unknown, "Keyspace lock poisoned"))?;
+        keyspace.evict_expired();
+
+        let MockCommand {
+            cmd,
+            subcommand,
+            args,
+        } = command;
+        let mut args = Args::new(args);
+
+        match (&*cmd, subcommand.as_deref()) {
+            ("PING", _) => Ok(RedisValue::from("PONG")),
+            ("GET", _) => keyspace.get(&args.key()?),
+            ("SET", _) => keyspace.set(&mut args),
+            ("MSETNX", _) => keyspace.msetnx(&mut args),
+            ("DEL", _) => Ok(count(
+                args.keys()?
+                    .iter()
+                    .filter(|key| keyspace.entries.remove(*key).is_some()),
+            )),
+            ("EXISTS", _) => Ok(count(
+                args.keys()?
+                    .iter()
+                    .filter(|key| keyspace.entries.contains_key(*key)),
+            )),
+            ("EXPIRE", _) => {
+                let key = args.key()?;
+                let seconds = args.u64()?;
+                keyspace.expire(&key, SystemTime::now() + Duration::from_secs(seconds))
+            }
+            ("EXPIREAT", _) => {
+                let key = args.key()?;
+                let timestamp = args.u64()?;
+                keyspace.expire(&key, UNIX_EPOCH + Duration::from_secs(timestamp))
+            }
+            ("HSET", _) => keyspace.hset(&mut args, false),
+            ("HSETNX", _) => keyspace.hset(&mut args, true),
+            ("HGET", _) => {
+                let key = args.key()?;
+                let field = args.key()?;
+                Ok(keyspace
+                    .hash(&key)?
+                    .and_then(|hash| hash.get(&field).cloned())
+                    .unwrap_or(RedisValue::Null))
+            }
+            ("HINCRBY", _) => keyspace.hincrby(&mut args),
+            ("HSCAN", _) => keyspace.hscan(&mut args),
+            ("SADD", _) => keyspace.sadd(&mut args),
+            ("RPUSH", _) => keyspace.rpush(&mut args),
+            ("LRANGE", _) => keyspace.lrange(&mut args),
+            ("LLEN", _) => Ok(RedisValue::Integer(
+                keyspace
+                    .list(&args.key()?)?
+                    .map_or(0, |list| to_i64(list.len())),
+            )),
+            ("LPOP", _) => keyspace.lpop(&mut args),
+            ("XADD", _) => keyspace.xadd(&mut args),
+            ("XLEN", _) => Ok(RedisValue::Integer(
+                keyspace
+                    .stream(&args.key()?)?
+                    .map_or(0, |stream| to_i64(stream.entries.len())),
+            )),
+            ("XDEL", _) => keyspace.xdel(&mut args),
+            ("XTRIM", _) => keyspace.xtrim(&mut args),
+            ("XGROUP", Some(subcommand)) => keyspace.xgroup(subcommand, &mut args),
+            ("XACK", _) => keyspace.xack(&mut args),
+            ("XREAD", _) => keyspace.xread(&mut args, None),
+            ("XREADGROUP", _) => {
+                args.expect("GROUP")?;
+                let group = args.key()?;
+                let consumer = args.key()?;
+                keyspace.xread(&mut args, Some((group, consumer)))
+            }
+            ("XCLAIM", _) => keyspace.xclaim(&mut args),
+            // Published messages are never delivered to subscribers, see `storage_impl`'s pub/sub
+            // implementation for how cache invalidation is handled in this mode.
+            ("PUBLISH", _) => Ok(RedisValue::Integer(0)),
+            ("SUBSCRIBE" | "UNSUBSCRIBE" | "PSUBSCRIBE" | "PUNSUBSCRIBE", _) => {
+                Ok(RedisValue::Null)
+            }
+            (cmd, subcommand) => Err(RedisError::new(
+                RedisErrorKind::InvalidCommand,
+                format!(
+                    "Command not supported by the in-memory redis: {cmd} {}",
+                    subcommand.unwrap_or_default()
+                ),
+            )),
+        }
+    }
+}
+
+impl Keyspace {
+    fn evict_expired(&mut self) {
+        let now = SystemTime::now();
+        self.entries
+            .retain(|_, entry| entry.expires_at.map_or(true, |expiry| expiry > now));
+    }
+
+    fn insert(&mut self, key: String, value: Value) -> &mut Value {
+        &mut self
+            .entries
+            .entry(key)
+            .or_insert(Entry {
+                value,
+                expires_at: None,
+            })
+            .value
+    }
+
+    fn get(&self, key: &str) -> Result<RedisValue, RedisError> {
+        match self.entries.get(key).map(|entry| &entry.value) {
+            Some(Value::String(value)) => Ok(value.clone()),
+            Some(_) => Err(wrong_type()),
+            None => Ok(RedisValue::Null),
+        }
+    }
+
+    fn set(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        let value = args.value()?;
+        let mut expires_at = None;
+        let mut only_if_absent = false;
+        let mut only_if_present = false;
+
+        while let Some(option) = args.next_string() {
+            match option.to_ascii_uppercase().as_str() {
+                "EX" => expires_at = Some(SystemTime::now() + Duration::from_secs(args.u64()?)),
+                "PX" => expires_at = Some(SystemTime::now() + Duration::from_millis(args.u64()?)),
+                "EXAT" => expires_at = Some(UNIX_EPOCH + Duration::from_secs(args.u64()?)),
+                "PXAT" => expires_at = Some(UNIX_EPOCH + Duration::from_millis(args.u64()?)),
+                "NX" => only_if_absent = true,
+                "XX" => only_if_present = true,
+                other => return Err(invalid_argument(format!("Unsupported SET option: {other}"))),
+            }
+        }
+
+        let exists = self.entries.contains_key(&key);
+        if (only_if_absent && exists) || (only_if_present && !exists) {
+            return Ok(RedisValue::Null);
+        }
+
+        self.entries.insert(
+            key,
+            Entry {
+                value: Value::String(value),
+                expires_at,
+            },
+        );
+        Ok(RedisValue::from("OK"))
+    }
+
+    fn msetnx(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let mut pairs = Vec::new();
+        while !args.is_empty() {
+            pairs.push((args.key()?, args.value()?));
+        }
+
+        if pairs.iter().any(|(key, _)| self.entries.contains_key(key)) {
+            return Ok(RedisValue::Integer(0));
+        }
+
+        for (key, value) in pairs {
+            self.entries.insert(
+                key,
+                Entry {
+                    value: Value::String(value),
+                    expires_at: None,
+                },
+            );
+        }
+        Ok(RedisValue::Integer(1))
+    }
+
+    fn expire(&mut self, key: &str, expires_at: SystemTime) -> Result<RedisValue, RedisError> {
+        Ok(match self.entries.get_mut(key) {
+            Some(entry) => {
+                entry.expires_at = Some(expires_at);
+                RedisValue::Integer(1)
+            }
+            None => RedisValue::Integer(0),
+        })
+    }
+
+    fn hash(&self, key: &str) -> Result<Option<&HashMap<String, RedisValue>>, RedisError> {
+        match self.entries.get(key).map(|entry| &entry.value) {
+            Some(Value::Hash(hash)) => Ok(Some(hash)),
+            Some(_) => Err(wrong_type()),
+            None => Ok(None),
+        }
+    }
+
+    fn hash_mut(&mut self, key: String) -> Result<&mut HashMap<String, RedisValue>, RedisError> {
+        match self.insert(key, Value::Hash(HashMap::new())) {
+            Value::Hash(hash) => Ok(hash),
+            _ => Err(wrong_type()),
+        }
+    }
+
+    fn hset(&mut self, args: &mut Args, only_if_absent: bool) -> Result<RedisValue, RedisError> {
+        let hash = self.hash_mut(args.key()?)?;
+        let mut added = 0;
+        while !args.is_empty() {
+            let field = args.key()?;
+            let value = args.value()?;
+            if only_if_absent && hash.contains_key(&field) {
+                continue;
+            }
+            if hash.insert(field, value).is_none() {
+                added += 1;
+            }
+        }
+        Ok(RedisValue::Integer(added))
+    }
+
+    fn hincrby(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let hash = self.hash_mut(args.key()?)?;
+        let field = args.key()?;
+        let increment = args.i64()?;
+        let current = match hash.get(&field) {
+            Some(value) => value
+                .as_i64()
+                .ok_or_else(|| invalid_argument("Hash value is not an integer"))?,
+            None => 0,
+        };
+        let updated = current + increment;
+        hash.insert(field, RedisValue::Integer(updated));
+        Ok(RedisValue::Integer(updated))
+    }
+
+    fn hscan(&self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        // The whole hash is returned in a single page, so the cursor is ignored.
+        let _cursor = args.value()?;
+        let mut pattern = None;
+        while let Some(option) = args.next_string() {
+            match option.to_ascii_uppercase().as_str() {
+                "MATCH" => pattern = Some(args.key()?),
+                "COUNT" => {
+                    args.u64()?;
+                }
+                other => {
+                    return Err(invalid_argument(format!(
+                        "Unsupported HSCAN option: {other}"
+                    )))
+                }
+            }
+        }
+
+        let fields = self
+            .hash(&key)?
+            .into_iter()
+            .flatten()
+            .filter(|(field, _)| {
+                pattern
+                    .as_deref()
+                    .map_or(true, |pattern| glob_match(pattern, field))
+            })
+            .flat_map(|(field, value)| [RedisValue::from(field.as_str()), value.clone()])
+            .collect();
+
+        Ok(RedisValue::Array(vec![
+            RedisValue::from("0"),
+            RedisValue::Array(fields),
+        ]))
+    }
+
+    fn sadd(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let set = match self.insert(args.key()?, Value::Set(HashSet::new())) {
+            Value::Set(set) => set,
+            _ => return Err(wrong_type()),
+        };
+        let mut added = 0;
+        while !args.is_empty() {
+            if set.insert(args.key()?) {
+                added += 1;
+            }
+        }
+        Ok(RedisValue::Integer(added))
+    }
+
+    fn list(&self, key: &str) -> Result<Option<&VecDeque<RedisValue>>, RedisError> {
+        match self.entries.get(key).map(|entry| &entry.value) {
+            Some(Value::List(list)) => Ok(Some(list)),
+            Some(_) => Err(wrong_type()),
+            None => Ok(None),
+        }
+    }
+
+    fn rpush(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let list = match self.insert(args.key()?, Value::List(VecDeque::new())) {
+            Value::List(list) => list,
+            _ => return Err(wrong_type()),
+        };
+        while !args.is_empty() {
+            list.push_back(args.value()?);
+        }
+        Ok(RedisValue::Integer(to_i64(list.len())))
+    }
+
+    fn lrange(&self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        let start = args.i64()?;
+        let stop = args.i64()?;
+        let Some(list) = self.list(&key)? else {
+            return Ok(RedisValue::Array(Vec::new()));
+        };
+
+        let len = to_i64(list.len());
+        let resolve = |index: i64| if index < 0 { len + index } else { index };
+        let start = resolve(start).max(0);
+        let stop = resolve(stop).min(len - 1);
+        if start > stop {
+            return Ok(RedisValue::Array(Vec::new()));
+        }
+
+        Ok(RedisValue::Array(
+            list.iter()
+                .skip(to_usize(start))
+                .take(to_usize(stop - start + 1))
+                .cloned()
+                .collect(),
+        ))
+    }
+
+    fn lpop(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        let count = (!args.is_empty()).then(|| args.u64()).transpose()?;
+        let list = match self.entries.get_mut(&key).map(|entry| &mut entry.value) {
+            Some(Value::List(list)) => list,
+            Some(_) => return Err(wrong_type()),
+            None => return Ok(RedisValue::Null),
+        };
+
+        let popped = match count {
+            Some(count) => {
+                let count = usize::try_from(count).unwrap_or(usize::MAX).min(list.len());
+                RedisValue::Array(list.drain(..count).collect())
+            }
+            None => list.pop_front().unwrap_or(RedisValue::Null),
+        };
+        if list.is_empty() {
+            self.entries.remove(&key);
+        }
+        Ok(popped)
+    }
+
+    fn stream(&self, key: &str) -> Result<Option<&Stream>, RedisError> {
+        match self.entries.get(key).map(|entry| &entry.value) {
+            Some(Value::Stream(stream)) => Ok(Some(stream)),
+            Some(_) => Err(wrong_type()),
+            None => Ok(None),
+        }
+    }
+
+    fn stream_mut(&mut self, key: &str) -> Result<Option<&mut Stream>, RedisError> {
+        match self.entries.get_mut(key).map(|entry| &mut entry.value) {
+            Some(Value::Stream(stream)) => Ok(Some(stream)),
+            Some(_) => Err(wrong_type()),
+            None => Ok(None),
+        }
+    }
+
+    fn xadd(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        let mut create_stream = true;
+        let mut max_len = None;
+
+        let id = loop {
+            let token = args.key()?;
+            match token.to_ascii_uppercase().as_str() {
+                "NOMKSTREAM" => create_stream = false,
+                "MAXLEN" => max_len = Some(args.trim_threshold()?),
+                _ => break token,
+            }
+        };
+
+        if !create_stream && !self.entries.contains_key(&key) {
+            return Ok(RedisValue::Null);
+        }
+
+        let stream = match self.insert(key, Value::Stream(Stream::default())) {
+            Value::Stream(stream) => stream,
+            _ => return Err(wrong_type()),
+        };
+
+        let id = if id == "*" {
+            let millis = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX))
+                .unwrap_or_default();
+            if millis > stream.last_id.0 {
+                StreamId(millis, 0)
+            } else {
+                StreamId(stream.last_id.0, stream.last_id.1 + 1)
+            }
+        } else {
+            let id = StreamId::parse(&id)?;
+            if id <= stream.last_id {
+                return Err(invalid_argument(
+                    "The ID specified in XADD is equal or smaller than the target stream top item",
+                ));
+            }
+            id
+        };
+
+        let mut fields = Vec::new();
+        while !args.is_empty() {
+            fields.push((args.key()?, args.value()?));
+        }
+
+        stream.entries.push((id, fields));
+        stream.last_id = id;
+        if let Some(max_len) = max_len {
+            stream.trim(max_len);
+        }
+
+        Ok(RedisValue::from(id.to_string()))
+    }
+
+    fn xdel(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        let ids = args
+            .keys()?
+            .iter()
+            .map(|id| StreamId::parse(id))
+            .collect::<Result<HashSet<_>, _>>()?;
+        let Some(stream) = self.stream_mut(&key)? else {
+            return Ok(RedisValue::Integer(0));
+        };
+
+        let before = stream.entries.len();
+        stream.entries.retain(|(id, _)| !ids.contains(id));
+        Ok(RedisValue::Integer(to_i64(before - stream.entries.len())))
+    }
+
+    fn xtrim(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        args.expect("MAXLEN")?;
+        let max_len = args.trim_threshold()?;
+        Ok(RedisValue::Integer(
+            self.stream_mut(&key)?
+                .map_or(0, |stream| to_i64(stream.trim(max_len))),
+        ))
+    }
+
+    fn xgroup(&mut self, subcommand: &str, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        let group = args.key()?;
+
+        match subcommand {
+            "CREATE" => {
+                let id = args.key()?;
+                let create_stream = args
+                    .next_string()
+                    .is_some_and(|option| option.eq_ignore_ascii_case("MKSTREAM"));
+                if !create_stream && !self.entries.contains_key(&key) {
+                    return Err(RedisError::new(
+                        RedisErrorKind::NotFound,
+                        "The XGROUP subcommand requires the key to exist",
+                    ));
+                }
+
+                let stream = match self.insert(key, Value::Stream(Stream::default())) {
+                    Value::Stream(stream) => stream,
+                    _ => return Err(wrong_type()),
+                };
+                if stream.groups.contains_key(&group) {
+                    return Err(RedisError::new(
+                        RedisErrorKind::Unknown,
+                        "BUSYGROUP Consumer Group name already exists",
+                    ));
+                }
+
+                let last_delivered = stream.resolve_id(&id)?;
+                stream.groups.insert(
+                    group,
+                    ConsumerGroup {
+                        last_delivered,
+                        pending: HashMap::new(),
+                    },
+                );
+                Ok(RedisValue::from("OK"))
+            }
+            "DESTROY" => Ok(RedisValue::Integer(
+                self.stream_mut(&key)?
+                    .and_then(|stream| stream.groups.remove(&group))
+                    .map_or(0, |_| 1),
+            )),
+            "DELCONSUMER" => {
+                let consumer = args.key()?;
+                let group = self
+                    .stream_mut(&key)?
+                    .and_then(|stream| stream.groups.get_mut(&group))
+                    .ok_or_else(no_group)?;
+                let before = group.pending.len();
+                group.pending.retain(|_, owner| *owner != consumer);
+                Ok(RedisValue::Integer(to_i64(before - group.pending.len())))
+            }
+            "SETID" => {
+                let id = args.key()?;
+                let stream = self.stream_mut(&key)?.ok_or_else(no_group)?;
+                let last_delivered = stream.resolve_id(&id)?;
+                stream
+                    .groups
+                    .get_mut(&group)
+                    .ok_or_else(no_group)?
+                    .last_delivered = last_delivered;
+                Ok(RedisValue::from("OK"))
+            }
+            other => Err(RedisError::new(
+                RedisErrorKind::InvalidCommand,
+                format!("XGROUP {other} is not supported by the in-memory redis"),
+            )),
+        }
+    }
+
+    fn xack(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        let group = args.key()?;
+        let ids = args.keys()?;
+        let Some(group) = self
+            .stream_mut(&key)?
+            .and_then(|stream| stream.groups.get_mut(&group))
+        else {
+            return Ok(RedisValue::Integer(0));
+        };
+
+        let mut acknowledged = 0;
+        for id in ids {
+            if group.pending.remove(&StreamId::parse(&id)?).is_some() {
+                acknowledged += 1;
+            }
+        }
+        Ok(RedisValue::Integer(acknowledged))
+    }
+
+    /// Handles both `XREAD` and `XREADGROUP`, the latter when a `(group, consumer)` pair is
+    /// provided.
+    fn xread(
+        &mut self,
+        args: &mut Args,
+        group: Option<(String, String)>,
+    ) -> Result<RedisValue, RedisError> {
+        let mut count = None;
+        let mut no_ack = false;
+        loop {
+            let option = args.key()?;
+            match option.to_ascii_uppercase().as_str() {
+                "COUNT" => count = Some(to_usize(args.i64()?)),
+                // Reads never block, there is no other client that could append to the stream.
+                "BLOCK" => {
+                    args.u64()?;
+                }
+                "NOACK" => no_ack = true,
+                "STREAMS" => break,
+                other => {
+                    return Err(invalid_argument(format!(
+                        "Unsupported XREAD option: {other}"
+                    )))
+                }
+            }
+        }
+
+        let remaining = args.keys()?;
+        let (keys, ids) = remaining.split_at(remaining.len() / 2);
+        let mut response = Vec::new();
+
+        for (key, id) in keys.iter().zip(ids) {
+            let Some(stream) = self.stream_mut(key)? else {
+                if group.is_some() {
+                    return Err(no_group());
+                }
+                continue;
+            };
+
+            let entries: Vec<_> = match &group {
+                Some((group, consumer)) => {
+                    let group = stream.groups.get_mut(group).ok_or_else(no_group)?;
+                    if id == ">" {
+                        let delivered: Vec<_> = stream
+                            .entries
+                            .iter()
+                            .filter(|(entry_id, _)| *entry_id > group.last_delivered)
+                            .take(count.unwrap_or(usize::MAX))
+                            .collect();
+                        if let Some((last, _)) = delivered.last() {
+                            group.last_delivered = *last;
+                        }
+                        if !no_ack {
+                            group.pending.extend(
+                                delivered
+                                    .iter()
+                                    .map(|(entry_id, _)| (*entry_id, consumer.clone())),
+                            );
+                        }
+                        delivered
+                    } else {
+                        let after = StreamId::parse(id)?;
+                        stream
+                            .entries
+                            .iter()
+                            .filter(|(entry_id, _)| {
+                                *entry_id > after && group.pending.get(entry_id) == Some(consumer)
+                            })
+                            .take(count.unwrap_or(usize::MAX))
+                            .collect()
+                    }
+                }
+                None => {
+                    let after = stream.resolve_id(id)?;
+                    stream
+                        .entries
+                        .iter()
+                        .filter(|(entry_id, _)| *entry_id > after)
+                        .take(count.unwrap_or(usize::MAX))
+                        .collect()
+                }
+            };
+
+            if !entries.is_empty() {
+                response.push(RedisValue::Array(vec![
+                    RedisValue::from(key.as_str()),
+                    RedisValue::Array(entries.into_iter().map(stream_entry).collect()),
+                ]));
+            }
+        }
+
+        Ok(if response.is_empty() {
+            RedisValue::Null
+        } else {
+            RedisValue::Array(response)
+        })
+    }
+
+    fn xclaim(&mut self, args: &mut Args) -> Result<RedisValue, RedisError> {
+        let key = args.key()?;
+        let group = args.key()?;
+        let consumer = args.key()?;
+        // Idle times are not tracked, every pending entry is considered claimable.
+        let _min_idle_time = args.u64()?;
+        let mut ids = Vec::new();
+        while let Some(id) = args.next_string() {
+            match StreamId::parse(&id) {
+                Ok(id) => ids.push(id),
+                // The remaining arguments are XCLAIM options
+                Err(_) => break,
+            }
+        }
+
+        let stream = self.stream_mut(&key)?.ok_or_else(no_group)?;
+        let group = stream.groups.get_mut(&group).ok_or_else(no_group)?;
+        let mut claimed = Vec::new();
+        for entry in stream.entries.iter().filter(|(id, _)| ids.contains(id)) {
+            if let Some(owner) = group.pending.get_mut(&entry.0) {
+                owner.clone_from(&consumer);
+                claimed.push(stream_entry(entry));
+            }
+        }
+
+        Ok(RedisValue::Array(claimed))
+    }
+}
+
+impl Stream {
+    /// Removes the oldest entries so that at most `max_len` entries remain, returning the number
+    /// of entries removed.
+    fn trim(&mut self, max_len: usize) -> usize {
+        let excess = self.entries.len().saturating_sub(max_len);
+        self.entries.drain(..excess);
+        excess
+    }
+
+    fn resolve_id(&self, id: &str) -> Result<StreamId, RedisError> {
+        match id {
+            "$" => Ok(self.last_id),
+            "0" => Ok(StreamId::default()),
+            id => StreamId::parse(id),
+        }
+    }
+}
+
+fn stream_entry((id, fields): &(StreamId, Vec<(String, RedisValue)>)) -> RedisValue {
+    RedisValue::Array(vec![
+        RedisValue::from(id.to_string()),
+        RedisValue::Array(
+            fields
+                .iter()
+                .flat_map(|(field, value)| [RedisValue::from(field.as_str()), value.clone()])
+                .collect(),
+        ),
+    ])
+}
+
+/// Cursor over the arguments of a single command.
+struct Args(VecDeque<RedisValue>);
+
+impl Args {
+    fn new(args: Vec<RedisValue>) -> Self {
+        Self(args.into())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn value(&mut self) -> Result<RedisValue, RedisError> {
+        self.0
+            .pop_front()
+            .ok_or_else(|| invalid_argument("Wrong number of arguments"))
+    }
+
+    fn next_string(&mut self) -> Option<String> {
+        self.0.pop_front().and_then(|value| value.as_string())
+    }
+
+    fn key(&mut self) -> Result<String, RedisError> {
+        self.value()?
+            .as_string()
+            .ok_or_else(|| invalid_argument("Expected a string argument"))
+    }
+
+    fn keys(&mut self) -> Result<Vec<String>, RedisError> {
+        let mut keys = Vec::new();
+        while !self.is_empty() {
+            keys.push(self.key()?);
+        }
+        Ok(keys)
+    }
+
+    fn i64(&mut self) -> Result<i64, RedisError> {
+        self.value()?
+            .as_i64()
+            .ok_or_else(|| invalid_argument("Expected an integer argument"))
+    }
+
+    fn u64(&mut self) -> Result<u64, RedisError> {
+        self.value()?
+            .as_u64()
+            .ok_or_else(|| invalid_argument("Expected a non-negative integer argument"))
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), RedisError> {
+        self.key()?
+            .eq_ignore_ascii_case(token)
+            .then_some(())
+            .ok_or_else(|| invalid_argument(format!("Expected `{token}`")))
+    }
+
+    /// Parses the `[= | ~] threshold` part of a `MAXLEN` trimming strategy.
+    fn trim_threshold(&mut self) -> Result<usize, RedisError> {
+        let threshold = match self.key()?.as_str() {
+            "=" | "~" => self.key()?,
+            threshold => threshold.to_string(),
+        };
+        threshold
+            .parse()
+            .map_err(|_| invalid_argument("Invalid MAXLEN threshold"))
+    }
+}
+
+/// Matches `value` against a Redis glob-style `pattern`, supporting `*` and `?`.
+fn glob_match(pattern: &str, value: &str) -> bool {
+    fn matches(pattern: &[char], value: &[char]) -> bool {
+        match (pattern.split_first(), value.split_first()) {
+            (None, None) => true,
+            (Some(('*', rest)), _) => {
+                matches(rest, value) || (!value.is_empty() && matches(pattern, &value[1..]))
+            }
+            (Some(('?', rest)), Some((_, value_rest))) => matches(rest, value_rest),
+            (Some((expected, rest)), Some((actual, value_rest))) => {
+                expected == actual && matches(rest, value_rest)
+            }
+            _ => false,
+        }
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+    matches(&pattern, &value)
+}
+
+fn count<I: Iterator>(iter: I) -> RedisValue {
+    RedisValue::Integer(to_i64(iter.count()))
+}
+
+fn to_i64(value: usize) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+fn to_usize(value: i64) -> usize {
+    usize::try_from(value).unwrap_or_default()
+}
+
+fn invalid_argument(details: impl Into<std::borrow::Cow<'static, str>>) -> RedisError {
+    RedisError::new(RedisErrorKind::InvalidArgument, details)
+}
+
+fn wrong_type() -> RedisError {
+    RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        "WRONGTYPE Operation against a key holding the wrong kind of value",
+    )
+}
+
+fn no_group() -> RedisError {
+    RedisError::new(
+        RedisErrorKind::NotFound,
+        "NOGROUP No such key or consumer group",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(cmd: &'static str, args: Vec<&str>) -> MockCommand {
+        MockCommand {
+            cmd: cmd.into(),
+            subcommand: None,
+            args: args.into_iter().map(RedisValue::from).collect(),
+        }
+    }
+
+    #[test]
+    fn test_set_if_not_exists() {
+        let redis = InMemoryRedis {
+            keyspace: Arc::default(),
+        };
+
+        let first = redis.process_command(command("SET", vec!["key", "first", "NX"]));
+        let second = redis.process_command(command("SET", vec!["key", "second", "NX"]));
+        let value = redis.process_command(command("GET", vec!["key"]));
+
+        assert_eq!(first.ok(), Some(RedisValue::from("OK")));
+        assert_eq!(second.ok(), Some(RedisValue::Null));
+        assert_eq!(value.ok(), Some(RedisValue::from("first")));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("pa_*", "pa_1234"));
+        assert!(glob_match("*_?", "ref_1"));
+        assert!(!glob_match("pa_*", "ref_1234"));
+    }
+}
@@ -17,6 +17,8 @@
 
 pub mod commands;
 pub mod errors;
+#[cfg(feature = "in_memory")]
+pub mod in_memory;
 pub mod types;
 
 use std::sync::{atomic, Arc};
@@ -142,6 +144,10 @@ impl RedisConnectionPool {
         }
         config.tracing = fred::types::TracingConfig::new(true);
         config.blocking = fred::types::Blocking::Error;
+        #[cfg(feature = "in_memory")]
+        {
+            config.mocks = Arc::new(in_memory::InMemoryRedis::default());
+        }
         let reconnect_policy = fred::types::ReconnectPolicy::new_constant(
             conf.reconnect_max_attempts,
             conf.reconnect_delay,
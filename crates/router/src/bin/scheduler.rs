@@ -320,6 +320,90 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::RefundBatchWorkflow => {
+                    Ok(Box::new(workflows::refund_batch::RefundBatchWorkflow))
+                }
+                storage::ProcessTrackerRunner::DisputeDeadlineWorkflow => Ok(Box::new(
+                    workflows::dispute_deadline::DisputeDeadlineWorkflow,
+                )),
+                storage::ProcessTrackerRunner::InvoiceReminderWorkflow => Ok(Box::new(
+                    workflows::invoice_reminder::InvoiceReminderWorkflow,
+                )),
+                storage::ProcessTrackerRunner::TaxTransactionWorkflow => {
+                    Ok(Box::new(workflows::tax_transaction::TaxTransactionWorkflow))
+                }
+                storage::ProcessTrackerRunner::InvoicePaymentWorkflow => {
+                    Ok(Box::new(workflows::invoice_payment::InvoicePaymentWorkflow))
+                }
+                storage::ProcessTrackerRunner::LedgerRebuildWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::ledger_rebuild::LedgerRebuildWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run ledger rebuild workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::DataSubjectRequestWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::data_subject_request::DataSubjectRequestWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run data subject request workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::DataRetentionWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::data_retention::DataRetentionWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run data retention workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::MerchantKeyRotationWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::merchant_key_rotation::MerchantKeyRotationWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run merchant key rotation workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
@@ -5,8 +5,8 @@ use api_models::{
     webhooks::{self as api},
 };
 #[cfg(feature = "payouts")]
-use common_utils::pii::{self, Email};
-use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
+use common_utils::pii::Email;
+use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode, pii};
 use error_stack::ResultExt;
 use router_env::logger;
 use serde::Serialize;
@@ -87,6 +87,7 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    Invoice(StripeInvoiceResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -107,6 +108,51 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripeInvoiceResponse {
+    pub id: String,
+    pub number: Option<String>,
+    pub status: common_enums::InvoiceStatus,
+    pub customer: common_utils::id_type::CustomerId,
+    pub currency: String,
+    pub subtotal: i64,
+    pub tax: i64,
+    pub total: i64,
+    pub amount_due: i64,
+    pub amount_paid: i64,
+    pub amount_remaining: i64,
+    pub due_date: Option<i64>,
+    pub payment_intent: Option<String>,
+    pub hosted_invoice_url: Option<String>,
+    pub created: i64,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl From<api_models::invoices::InvoiceResponse> for StripeInvoiceResponse {
+    fn from(res: api_models::invoices::InvoiceResponse) -> Self {
+        Self {
+            id: res.invoice_id,
+            number: res.invoice_number,
+            status: res.status,
+            customer: res.customer_id,
+            currency: res.currency.to_string().to_lowercase(),
+            subtotal: res.subtotal.get_amount_as_i64(),
+            tax: res.tax_amount.get_amount_as_i64(),
+            total: res.total.get_amount_as_i64(),
+            amount_due: res.total.get_amount_as_i64(),
+            amount_paid: res.amount_paid.get_amount_as_i64(),
+            amount_remaining: res.amount_remaining.get_amount_as_i64(),
+            due_date: res
+                .due_date
+                .map(|due_date| due_date.assume_utc().unix_timestamp()),
+            payment_intent: res.payment_id,
+            hosted_invoice_url: res.payment_link,
+            created: res.created_at.assume_utc().unix_timestamp(),
+            metadata: res.metadata,
+        }
+    }
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Clone, Serialize, Debug)]
 pub struct StripePayoutResponse {
@@ -280,6 +326,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeDeadlineReminder => "dispute.deadline_reminder",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
 
@@ -296,6 +343,12 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::InvoiceCreated => "invoice.created",
+        api_models::enums::EventType::InvoiceFinalized => "invoice.finalized",
+        api_models::enums::EventType::InvoicePartiallyPaid => "invoice.partially_paid",
+        api_models::enums::EventType::InvoicePaid => "invoice.paid",
+        api_models::enums::EventType::InvoiceVoided => "invoice.voided",
+        api_models::enums::EventType::InvoiceMarkedUncollectible => "invoice.marked_uncollectible",
     }
 }
 
@@ -338,6 +391,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout(payout.into()),
+            api::OutgoingWebhookContent::InvoiceDetails(invoice) => {
+                Self::Invoice((*invoice).into())
+            }
         }
     }
 }
@@ -1,4 +1,5 @@
 use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
 use hyperswitch_interfaces::secrets_interface::{
     secret_handler::SecretsHandler,
     secret_state::{RawSecret, SecretStateContainer, SecuredSecret},
@@ -279,105 +280,90 @@ impl SecretsHandler for settings::UserAuthMethodSettings {
     }
 }
 
-/// # Panics
-///
-/// Will panic even if kms decryption fails for at least one field
+/// Resolves the secrets of the settings using the secrets management client
 pub(crate) async fn fetch_raw_secrets(
     conf: Settings<SecuredSecret>,
     secret_management_client: &dyn SecretManagementInterface,
-) -> Settings<RawSecret> {
-    #[allow(clippy::expect_used)]
+) -> CustomResult<Settings<RawSecret>, SecretsManagementError> {
     let master_database =
         settings::Database::convert_to_raw_secret(conf.master_database, secret_management_client)
             .await
-            .expect("Failed to decrypt master database configuration");
+            .attach_printable("Failed to decrypt master database configuration")?;
 
     #[cfg(feature = "olap")]
-    #[allow(clippy::expect_used)]
     let analytics =
         analytics::AnalyticsConfig::convert_to_raw_secret(conf.analytics, secret_management_client)
             .await
-            .expect("Failed to decrypt analytics configuration");
+            .attach_printable("Failed to decrypt analytics configuration")?;
 
     #[cfg(feature = "olap")]
-    #[allow(clippy::expect_used)]
     let replica_database =
         settings::Database::convert_to_raw_secret(conf.replica_database, secret_management_client)
             .await
-            .expect("Failed to decrypt replica database configuration");
+            .attach_printable("Failed to decrypt replica database configuration")?;
 
-    #[allow(clippy::expect_used)]
     let secrets = settings::Secrets::convert_to_raw_secret(conf.secrets, secret_management_client)
         .await
-        .expect("Failed to decrypt secrets");
+        .attach_printable("Failed to decrypt secrets")?;
 
-    #[allow(clippy::expect_used)]
     let forex_api =
         settings::ForexApi::convert_to_raw_secret(conf.forex_api, secret_management_client)
             .await
-            .expect("Failed to decrypt forex api configs");
+            .attach_printable("Failed to decrypt forex api configs")?;
 
-    #[allow(clippy::expect_used)]
     let jwekey = settings::Jwekey::convert_to_raw_secret(conf.jwekey, secret_management_client)
         .await
-        .expect("Failed to decrypt jwekey configs");
+        .attach_printable("Failed to decrypt jwekey configs")?;
 
-    #[allow(clippy::expect_used)]
     let api_keys =
         settings::ApiKeys::convert_to_raw_secret(conf.api_keys, secret_management_client)
             .await
-            .expect("Failed to decrypt api_keys configs");
+            .attach_printable("Failed to decrypt api_keys configs")?;
 
     #[cfg(feature = "olap")]
-    #[allow(clippy::expect_used)]
     let connector_onboarding = settings::ConnectorOnboarding::convert_to_raw_secret(
         conf.connector_onboarding,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt connector_onboarding configs");
+    .attach_printable("Failed to decrypt connector_onboarding configs")?;
 
-    #[allow(clippy::expect_used)]
     let applepay_decrypt_keys = settings::ApplePayDecryptConifg::convert_to_raw_secret(
         conf.applepay_decrypt_keys,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt applepay decrypt configs");
+    .attach_printable("Failed to decrypt applepay decrypt configs")?;
 
-    #[allow(clippy::expect_used)]
     let applepay_merchant_configs = settings::ApplepayMerchantConfigs::convert_to_raw_secret(
         conf.applepay_merchant_configs,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt applepay merchant configs");
+    .attach_printable("Failed to decrypt applepay merchant configs")?;
 
-    #[allow(clippy::expect_used)]
     let payment_method_auth = settings::PaymentMethodAuth::convert_to_raw_secret(
         conf.payment_method_auth,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt payment method auth configs");
+    .attach_printable("Failed to decrypt payment method auth configs")?;
 
-    #[allow(clippy::expect_used)]
     let key_manager = settings::KeyManagerConfig::convert_to_raw_secret(
         conf.key_manager,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt keymanager configs");
+    .attach_printable("Failed to decrypt keymanager configs")?;
 
-    #[allow(clippy::expect_used)]
     let user_auth_methods = settings::UserAuthMethodSettings::convert_to_raw_secret(
         conf.user_auth_methods,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt user_auth_methods configs");
+    .attach_printable("Failed to decrypt user_auth_methods configs")?;
 
-    Settings {
+    Ok(Settings {
         server: conf.server,
         master_database,
         redis: conf.redis,
@@ -449,5 +435,97 @@ pub(crate) async fn fetch_raw_secrets(
         user_auth_methods,
         decision: conf.decision,
         locker_based_open_banking_connectors: conf.locker_based_open_banking_connectors,
+    })
+}
+
+/// Resolves the secrets of the settings again with a new secrets management client, which reads
+/// the secrets from their source again, and replaces the reloadable settings. The settings are
+/// left unchanged if any of the secrets cannot be resolved.
+pub(crate) async fn reload_raw_secrets(
+    conf: &Settings<SecuredSecret>,
+    reloadable_conf: &settings::ReloadableSettings,
+) -> CustomResult<(), SecretsManagementError> {
+    let secret_management_client = conf
+        .secrets_management
+        .get_secret_management_client()
+        .await?;
+    let raw_conf = fetch_raw_secrets(conf.clone(), &*secret_management_client).await?;
+    reloadable_conf.store(raw_conf);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::{path::Path, sync::Arc};
+
+    use external_services::{
+        encrypted_file::core::{decode_key, encrypt_secrets, generate_key, EncryptedFileConfig},
+        managers::secrets_management::SecretsManagementConfig,
+    };
+    use masking::{PeekInterface, Secret};
+
+    use super::*;
+
+    fn write_secrets_file(path: &Path, key: &Secret<String>, jwt_secret: &str) {
+        // The secrets which are not configured in the settings are looked up by an empty name
+        let secrets = format!("\"\" = \"unused\"\njwt_secret = \"{jwt_secret}\"\n");
+        let contents =
+            encrypt_secrets(&decode_key(key.peek()).unwrap(), &Secret::new(secrets)).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    fn jwt_secret(conf: &Settings<RawSecret>) -> String {
+        conf.secrets.get_inner().jwt_secret.peek().clone()
+    }
+
+    #[tokio::test]
+    async fn test_reload_picks_up_the_updated_secrets() {
+        let directory =
+            std::env::temp_dir().join(format!("reload_secrets_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&directory).unwrap();
+        let file_path = directory.join("secrets.enc");
+        let key_file = directory.join("secrets.key");
+        let key = generate_key();
+        std::fs::write(&key_file, key.peek()).unwrap();
+
+        let conf = Settings::<SecuredSecret> {
+            secrets_management: SecretsManagementConfig::EncryptedFile {
+                encrypted_file: EncryptedFileConfig {
+                    file_path: file_path.clone(),
+                    key_env_var: None,
+                    key_file: Some(key_file),
+                },
+            },
+            secrets: serde_json::from_value(serde_json::json!({ "jwt_secret": "jwt_secret" }))
+                .unwrap(),
+            ..Default::default()
+        };
+
+        write_secrets_file(&file_path, &key, "first");
+        let secret_management_client = conf
+            .secrets_management
+            .get_secret_management_client()
+            .await
+            .unwrap();
+        let reloadable_conf = settings::ReloadableSettings::new(Arc::new(
+            fetch_raw_secrets(conf.clone(), &*secret_management_client)
+                .await
+                .unwrap(),
+        ));
+        let conf_before_reload = reloadable_conf.load();
+        assert_eq!(jwt_secret(&conf_before_reload), "first");
+
+        write_secrets_file(&file_path, &key, "second");
+        reload_raw_secrets(&conf, &reloadable_conf).await.unwrap();
+        assert_eq!(jwt_secret(&reloadable_conf.load()), "second");
+        assert_eq!(jwt_secret(&conf_before_reload), "first");
+
+        std::fs::write(&file_path, "not encrypted").unwrap();
+        assert!(reload_raw_secrets(&conf, &reloadable_conf).await.is_err());
+        assert_eq!(jwt_secret(&reloadable_conf.load()), "second");
+
+        std::fs::remove_dir_all(directory).unwrap();
     }
 }
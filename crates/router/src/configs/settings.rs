@@ -1,6 +1,7 @@
 use std::{
     collections::{HashMap, HashSet},
     path::PathBuf,
+    sync::{Arc, PoisonError, RwLock},
 };
 
 #[cfg(feature = "olap")]
@@ -364,6 +365,7 @@ pub struct DummyConnector {
     pub refund_retrieve_duration: u64,
     pub refund_retrieve_tolerance: u64,
     pub authorize_ttl: i64,
+    pub scenario_ttl: i64,
     pub assets_base_url: String,
     pub default_return_url: String,
     pub slack_invite_url: String,
@@ -834,6 +836,28 @@ impl Settings<RawSecret> {
     }
 }
 
+/// Settings with the secrets resolved, which are replaced while the application is running
+/// whenever the secrets are reloaded
+#[derive(Clone)]
+pub struct ReloadableSettings(Arc<RwLock<Arc<Settings<RawSecret>>>>);
+
+impl ReloadableSettings {
+    pub fn new(conf: Arc<Settings<RawSecret>>) -> Self {
+        Self(Arc::new(RwLock::new(conf)))
+    }
+
+    /// Returns the current settings. The returned settings are not affected by later reloads, so
+    /// that a request sees the same settings until it completes.
+    pub fn load(&self) -> Arc<Settings<RawSecret>> {
+        Arc::clone(&self.0.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Replaces the settings in a single step
+    pub fn store(&self, conf: Settings<RawSecret>) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(conf);
+    }
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Payouts {
@@ -84,6 +84,28 @@ pub enum AdyenRecurringModel {
     CardOnFile,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AdyenScaExemption {
+    LowValue,
+    TransactionRiskAnalysis,
+    TrustedBeneficiary,
+}
+
+impl AdyenScaExemption {
+    /// Merchant initiated payments are flagged through the shopper interaction instead
+    fn from_sca_exemption(sca_exemption: storage_enums::ScaExemptionType) -> Option<Self> {
+        match sca_exemption {
+            storage_enums::ScaExemptionType::LowValue => Some(Self::LowValue),
+            storage_enums::ScaExemptionType::TransactionRiskAnalysis => {
+                Some(Self::TransactionRiskAnalysis)
+            }
+            storage_enums::ScaExemptionType::TrustedBeneficiary => Some(Self::TrustedBeneficiary),
+            storage_enums::ScaExemptionType::MerchantInitiatedTransaction => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub enum AuthType {
     #[default]
@@ -105,6 +127,7 @@ pub struct AdditionalData {
     #[cfg(feature = "payouts")]
     payout_eligible: Option<PayoutEligibility>,
     funds_availability: Option<String>,
+    sca_exemption: Option<AdyenScaExemption>,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -166,6 +189,7 @@ pub struct AdyenPaymentRequest<'a> {
     channel: Option<Channel>,
     metadata: Option<pii::SecretSerdeValue>,
     merchant_order_reference: Option<String>,
+    splits: Option<Vec<AdyenSplitData>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -487,6 +511,29 @@ pub struct Amount {
     pub value: MinorUnit,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenSplitData {
+    #[serde(rename = "type")]
+    split_type: AdyenSplitType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum AdyenSplitType {
+    /// The share of a sub-merchant, booked to its balance account
+    BalanceAccount,
+    /// The share of the platform, booked to the liable balance account
+    Commission,
+    /// All the transaction fees, booked to the given balance account or to the liable one
+    PaymentFee,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "lowercase")]
@@ -1183,6 +1230,7 @@ pub struct AdyenRefundRequest {
     amount: Amount,
     merchant_refund_reason: Option<String>,
     reference: String,
+    splits: Option<Vec<AdyenSplitData>>,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -1691,6 +1739,10 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
         recurring_detail_reference: None,
         recurring_shopper_reference: None,
         recurring_processing_model: None,
+        sca_exemption: item
+            .request
+            .sca_exemption
+            .and_then(AdyenScaExemption::from_sca_exemption),
         ..AdditionalData::default()
     })
 }
@@ -1758,6 +1810,87 @@ fn get_line_items(item: &AdyenRouterData<&types::PaymentsAuthorizeRouterData>) -
     }
 }
 
+/// The split of the payment between the balance accounts of the sub-merchants and the platform
+fn get_splits(item: &types::PaymentsAuthorizeRouterData) -> Option<Vec<AdyenSplitData>> {
+    let split_payments = item.request.split_payments.as_ref()?;
+    let currency = item.request.currency;
+    let mut splits = split_payments
+        .allocations
+        .iter()
+        .map(|allocation| AdyenSplitData {
+            split_type: AdyenSplitType::BalanceAccount,
+            amount: Some(Amount {
+                currency,
+                value: allocation.amount,
+            }),
+            account: Some(allocation.account_id.clone()),
+            reference: Some(
+                allocation
+                    .reference
+                    .clone()
+                    .unwrap_or_else(|| allocation.account_id.clone()),
+            ),
+        })
+        .collect::<Vec<_>>();
+    if split_payments.platform_amount > MinorUnit::zero() {
+        splits.push(AdyenSplitData {
+            split_type: AdyenSplitType::Commission,
+            amount: Some(Amount {
+                currency,
+                value: split_payments.platform_amount,
+            }),
+            account: None,
+            reference: Some(item.connector_request_reference_id.clone()),
+        });
+    }
+    splits.push(AdyenSplitData {
+        split_type: AdyenSplitType::PaymentFee,
+        amount: None,
+        account: split_payments.fee_bearer.clone(),
+        reference: None,
+    });
+    Some(splits)
+}
+
+/// The amounts of the refund reversed from the sub-merchants, the rest being reversed from the
+/// platform
+fn get_refund_splits<F>(
+    item: &types::RefundsRouterData<F>,
+    refund_amount: MinorUnit,
+) -> Option<Vec<AdyenSplitData>> {
+    let split_refunds = item.request.split_refunds.as_ref()?;
+    let currency = item.request.currency;
+    let mut splits = split_refunds
+        .iter()
+        .map(|allocation| AdyenSplitData {
+            split_type: AdyenSplitType::BalanceAccount,
+            amount: Some(Amount {
+                currency,
+                value: allocation.amount,
+            }),
+            account: Some(allocation.account_id.clone()),
+            reference: Some(allocation.account_id.clone()),
+        })
+        .collect::<Vec<_>>();
+    let platform_amount = split_refunds
+        .iter()
+        .fold(refund_amount, |amount, allocation| {
+            amount - allocation.amount
+        });
+    if platform_amount > MinorUnit::zero() {
+        splits.push(AdyenSplitData {
+            split_type: AdyenSplitType::Commission,
+            amount: Some(Amount {
+                currency,
+                value: platform_amount,
+            }),
+            account: None,
+            reference: Some(item.request.refund_id.clone()),
+        });
+    }
+    Some(splits)
+}
+
 fn get_telephone_number(item: &types::PaymentsAuthorizeRouterData) -> Option<Secret<String>> {
     let phone = item
         .get_optional_billing()
@@ -2595,6 +2728,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         })
     }
 }
@@ -2658,6 +2792,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         })
     }
 }
@@ -2713,6 +2848,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         };
         Ok(request)
     }
@@ -2768,6 +2904,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         };
         Ok(request)
     }
@@ -2819,6 +2956,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         };
         Ok(request)
     }
@@ -2870,6 +3008,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         };
         Ok(request)
     }
@@ -2931,6 +3070,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         })
     }
 }
@@ -3027,6 +3167,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         })
     }
 }
@@ -3103,6 +3244,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         })
     }
 }
@@ -3162,6 +3304,7 @@ impl<'a>
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
+            splits: get_splits(item.router_data),
         })
     }
 }
@@ -4012,6 +4155,7 @@ impl<F> TryFrom<&AdyenRouterData<&types::RefundsRouterData<F>>> for AdyenRefundR
             },
             merchant_refund_reason: item.router_data.request.reason.clone(),
             reference: item.router_data.request.refund_id.clone(),
+            splits: get_refund_splits(item.router_data, item.amount),
         })
     }
 }
@@ -138,6 +138,8 @@ struct TransactionRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     profile: Option<ProfileDetails>,
     order: Order,
+    #[serde(flatten)]
+    level_2_3_details: Option<Level2And3Details>,
     #[serde(skip_serializing_if = "Option::is_none")]
     customer: Option<CustomerDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -205,6 +207,148 @@ pub struct Order {
     description: String,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Level2And3Details {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<LineItems>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_exempt: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    po_number: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItems {
+    line_item: Vec<LineItem>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    item_id: String,
+    name: String,
+    quantity: u16,
+    unit_price: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<f64>,
+    total_amount: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendedAmount {
+    amount: f64,
+    name: String,
+}
+
+// Authorize.net limits the item id and name of a line item to 31 characters
+const LINE_ITEM_FIELD_MAX_LENGTH: usize = 31;
+
+fn get_base_unit_amount(
+    amount: common_utils::types::MinorUnit,
+    currency: enums::Currency,
+) -> Result<f64, error_stack::Report<errors::ConnectorError>> {
+    utils::get_amount_as_f64(
+        &api::CurrencyUnit::Base,
+        amount.get_amount_as_i64(),
+        currency,
+    )
+}
+
+fn get_extended_amount(
+    amount: Option<common_utils::types::MinorUnit>,
+    name: &str,
+    currency: enums::Currency,
+) -> Result<Option<ExtendedAmount>, error_stack::Report<errors::ConnectorError>> {
+    amount
+        .map(|amount| {
+            Ok(ExtendedAmount {
+                amount: get_base_unit_amount(amount, currency)?,
+                name: name.to_string(),
+            })
+        })
+        .transpose()
+}
+
+fn get_level_2_3_details(
+    l2_l3_data: Option<&api_models::payments::L2L3Data>,
+    currency: enums::Currency,
+) -> Result<Option<Level2And3Details>, error_stack::Report<errors::ConnectorError>> {
+    l2_l3_data
+        .map(|l2_l3_data| {
+            let line_items = l2_l3_data
+                .line_items
+                .as_ref()
+                .map(|line_items| {
+                    line_items
+                        .iter()
+                        .enumerate()
+                        .map(|(index, line_item)| {
+                            let total_amount = line_item
+                                .get_total_amount()
+                                .ok_or(errors::ConnectorError::RequestEncodingFailed)?;
+                            Ok(LineItem {
+                                item_id: line_item
+                                    .product_code
+                                    .clone()
+                                    .unwrap_or_else(|| (index + 1).to_string()),
+                                name: line_item
+                                    .product_name
+                                    .chars()
+                                    .take(LINE_ITEM_FIELD_MAX_LENGTH)
+                                    .collect(),
+                                quantity: line_item.quantity,
+                                unit_price: get_base_unit_amount(line_item.unit_amount, currency)?,
+                                unit_of_measure: line_item.unit_of_measure.clone(),
+                                tax_amount: line_item
+                                    .tax_amount
+                                    .map(|amount| get_base_unit_amount(amount, currency))
+                                    .transpose()?,
+                                total_amount: get_base_unit_amount(total_amount, currency)?,
+                                commodity_code: line_item.commodity_code.clone(),
+                                product_code: line_item.product_code.clone(),
+                                discount_amount: line_item
+                                    .discount_amount
+                                    .map(|amount| get_base_unit_amount(amount, currency))
+                                    .transpose()?,
+                            })
+                        })
+                        .collect::<Result<Vec<_>, error_stack::Report<errors::ConnectorError>>>()
+                        .map(|line_item| LineItems { line_item })
+                })
+                .transpose()?;
+            Ok(Level2And3Details {
+                line_items,
+                tax: get_extended_amount(l2_l3_data.tax_amount, "Tax", currency)?,
+                duty: get_extended_amount(l2_l3_data.duty_amount, "Duty", currency)?,
+                shipping: get_extended_amount(l2_l3_data.freight_amount, "Freight", currency)?,
+                tax_exempt: l2_l3_data.tax_exempt,
+                // The customer reference is sent as the purchase order number when no PO number is present
+                po_number: l2_l3_data
+                    .purchase_order_number
+                    .clone()
+                    .or_else(|| l2_l3_data.customer_reference.clone()),
+            })
+        })
+        .transpose()
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubsequentAuthInformation {
@@ -594,6 +738,10 @@ impl
             order: Order {
                 description: item.router_data.connector_request_reference_id.clone(),
             },
+            level_2_3_details: get_level_2_3_details(
+                item.router_data.request.l2_l3_data.as_ref(),
+                item.router_data.request.currency,
+            )?,
             customer: None,
             bill_to: item
                 .router_data
@@ -659,6 +807,10 @@ impl
             order: Order {
                 description: item.router_data.connector_request_reference_id.clone(),
             },
+            level_2_3_details: get_level_2_3_details(
+                item.router_data.request.l2_l3_data.as_ref(),
+                item.router_data.request.currency,
+            )?,
             customer: None,
             bill_to: None,
             processing_options: Some(ProcessingOptions {
@@ -734,6 +886,10 @@ impl
             order: Order {
                 description: item.router_data.connector_request_reference_id.clone(),
             },
+            level_2_3_details: get_level_2_3_details(
+                item.router_data.request.l2_l3_data.as_ref(),
+                item.router_data.request.currency,
+            )?,
             customer,
             bill_to: item
                 .router_data
@@ -785,6 +941,10 @@ impl
             order: Order {
                 description: item.router_data.connector_request_reference_id.clone(),
             },
+            level_2_3_details: get_level_2_3_details(
+                item.router_data.request.l2_l3_data.as_ref(),
+                item.router_data.request.currency,
+            )?,
             customer: None,
             bill_to: item
                 .router_data
@@ -1850,3 +2010,96 @@ impl TryFrom<&AuthorizedotnetRouterData<&types::PaymentsCompleteAuthorizeRouterD
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::payments::{L2L3Data, L2L3LineItem};
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn line_item(product_name: &str, product_code: Option<&str>) -> L2L3LineItem {
+        L2L3LineItem {
+            product_name: product_name.to_string(),
+            product_code: product_code.map(ToString::to_string),
+            commodity_code: None,
+            quantity: 2,
+            unit_of_measure: None,
+            unit_amount: MinorUnit::new(500),
+            tax_amount: Some(MinorUnit::new(100)),
+            discount_amount: Some(MinorUnit::new(50)),
+        }
+    }
+
+    #[test]
+    fn test_level_2_3_details_are_sent_in_the_base_unit() {
+        let l2_l3_data = L2L3Data {
+            tax_amount: Some(MinorUnit::new(100)),
+            duty_amount: Some(MinorUnit::new(0)),
+            freight_amount: Some(MinorUnit::new(1000)),
+            tax_exempt: Some(false),
+            customer_reference: Some("CUSTREF123".to_string()),
+            line_items: Some(vec![
+                line_item("Copy paper", Some("CP-500")),
+                line_item("Heavy duty stapler with staple remover", None),
+            ]),
+            ..Default::default()
+        };
+
+        let details = get_level_2_3_details(Some(&l2_l3_data), enums::Currency::USD).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(details).unwrap(),
+            serde_json::json!({
+                "lineItems": {
+                    "lineItem": [
+                        {
+                            "itemId": "CP-500",
+                            "name": "Copy paper",
+                            "quantity": 2,
+                            "unitPrice": 5.0,
+                            "taxAmount": 1.0,
+                            "totalAmount": 10.5,
+                            "productCode": "CP-500",
+                            "discountAmount": 0.5,
+                        },
+                        {
+                            "itemId": "2",
+                            "name": "Heavy duty stapler with staple ",
+                            "quantity": 2,
+                            "unitPrice": 5.0,
+                            "taxAmount": 1.0,
+                            "totalAmount": 10.5,
+                            "discountAmount": 0.5,
+                        },
+                    ],
+                },
+                "tax": { "amount": 1.0, "name": "Tax" },
+                "duty": { "amount": 0.0, "name": "Duty" },
+                "shipping": { "amount": 10.0, "name": "Freight" },
+                "taxExempt": false,
+                "poNumber": "CUSTREF123",
+            })
+        );
+    }
+
+    #[test]
+    fn test_level_2_3_details_prefer_the_purchase_order_number() {
+        let l2_l3_data = L2L3Data {
+            customer_reference: Some("CUSTREF123".to_string()),
+            purchase_order_number: Some("PO-2024-0042".to_string()),
+            ..Default::default()
+        };
+
+        let details = get_level_2_3_details(Some(&l2_l3_data), enums::Currency::USD)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(details.po_number.as_deref(), Some("PO-2024-0042"));
+        assert!(details.line_items.is_none());
+        assert!(get_level_2_3_details(None, enums::Currency::USD)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -6,10 +6,15 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::{
-    connector::utils::{
-        self, AddressDetailsData, ApplePayDecrypt, CardData, CardIssuer,
-        PaymentsAuthorizeRequestData, PaymentsSetupMandateRequestData, PaymentsSyncRequestData,
-        RecurringMandateData, RouterData,
+    connector::{
+        cybersource::transformers::{
+            get_l2_l3_details, InvoiceDetails, Level2AmountDetails, LineItem,
+        },
+        utils::{
+            self, AddressDetailsData, ApplePayDecrypt, CardData, CardIssuer,
+            PaymentsAuthorizeRequestData, PaymentsSetupMandateRequestData, PaymentsSyncRequestData,
+            RecurringMandateData, RouterData,
+        },
     },
     consts,
     core::errors,
@@ -245,6 +250,10 @@ pub struct FluidData {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
 }
 
 #[derive(Debug, Serialize)]
@@ -252,6 +261,8 @@ pub struct OrderInformationWithBill {
 pub struct Amount {
     total_amount: String,
     currency: api_models::enums::Currency,
+    #[serde(flatten)]
+    level_2_details: Option<Level2AmountDetails>,
 }
 
 #[derive(Debug, Serialize)]
@@ -557,24 +568,31 @@ pub enum TransactionType {
 }
 
 impl
-    From<(
+    TryFrom<(
         &BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>,
         Option<BillTo>,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>,
             Option<BillTo>,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let currency = item.router_data.request.currency;
+        let l2_l3_details =
+            get_l2_l3_details(item.router_data.request.l2_l3_data.as_ref(), currency)?;
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
-                currency: item.router_data.request.currency,
+                currency,
+                level_2_details: l2_l3_details.level_2_details,
             },
             bill_to,
-        }
+            line_items: l2_l3_details.line_items,
+            invoice_details: l2_l3_details.invoice_details,
+        })
     }
 }
 
@@ -816,7 +834,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = PaymentInformation::try_from(&ccard)?;
         let processing_information = ProcessingInformation::try_from((item, None, None))?;
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -855,7 +873,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -911,7 +929,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = PaymentInformation::from(&google_pay_data);
         let processing_information =
             ProcessingInformation::try_from((item, Some(PaymentSolution::GooglePay), None))?;
@@ -968,7 +986,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>>
                                         email,
                                     )?;
                                     let order_information: OrderInformationWithBill =
-                                        OrderInformationWithBill::from((item, Some(bill_to)));
+                                        OrderInformationWithBill::try_from((item, Some(bill_to)))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1110,7 +1128,7 @@ impl
             item.router_data.request.get_email().ok().and_then(|email| {
                 build_bill_to(item.router_data.get_optional_billing(), email).ok()
             });
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(Box::new(MandatePaymentInformation {
                 payment_instrument,
@@ -1874,6 +1892,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsCaptureRouterData>>
                 amount_details: Amount {
                     total_amount: value.amount.to_owned(),
                     currency: value.router_data.request.currency,
+                    level_2_details: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -1926,6 +1945,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsCancelRouterData>>
                             field_name: "Currency",
                         },
                     )?,
+                    level_2_details: None,
                 },
                 reason: value
                     .router_data
@@ -1960,6 +1980,7 @@ impl<F> TryFrom<&BankOfAmericaRouterData<&types::RefundsRouterData<F>>>
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_2_details: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2406,8 +2427,11 @@ impl TryFrom<&types::SetupMandateRouterData> for OrderInformationWithBill {
             amount_details: Amount {
                 total_amount: "0".to_string(),
                 currency: item.request.currency,
+                level_2_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
         })
     }
 }
@@ -2583,3 +2607,52 @@ pub fn get_error_reason(
         (None, None, None) => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::connector::cybersource::transformers::test_utils::l2_l3_data;
+
+    #[test]
+    fn test_l2_l3_details_are_sent_in_the_order_information() {
+        let l2_l3_details =
+            get_l2_l3_details(Some(&l2_l3_data()), api_models::enums::Currency::USD).unwrap();
+        let order_information = OrderInformationWithBill {
+            amount_details: Amount {
+                total_amount: "251.62".to_string(),
+                currency: api_models::enums::Currency::USD,
+                level_2_details: l2_l3_details.level_2_details,
+            },
+            bill_to: None,
+            line_items: l2_l3_details.line_items,
+            invoice_details: l2_l3_details.invoice_details,
+        };
+
+        assert_eq!(
+            serde_json::to_value(order_information).unwrap(),
+            serde_json::json!({
+                "amountDetails": {
+                    "totalAmount": "251.62",
+                    "currency": "USD",
+                    "taxAmount": "1.62",
+                    "freightAmount": "10.00",
+                },
+                "billTo": null,
+                "lineItems": [{
+                    "productName": "Copy paper",
+                    "productCode": "CP-500",
+                    "quantity": 10,
+                    "unitOfMeasure": "box",
+                    "unitPrice": "25.00",
+                    "taxAmount": "1.62",
+                    "totalAmount": "251.62",
+                }],
+                "invoiceDetails": {
+                    "purchaseOrderNumber": "CUSTREF123",
+                    "taxable": true,
+                },
+            })
+        );
+    }
+}
@@ -73,8 +73,11 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
             amount_details: Amount {
                 total_amount: "0".to_string(),
                 currency: item.request.currency,
+                level_2_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
         };
         let (action_list, action_token_types, authorization_options) = (
             Some(vec![CybersourceActionsList::TokenCreate]),
@@ -430,6 +433,10 @@ pub struct Card {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
 }
 
 #[derive(Debug, Serialize)]
@@ -449,6 +456,144 @@ pub struct OrderInformation {
 pub struct Amount {
     total_amount: String,
     currency: api_models::enums::Currency,
+    #[serde(flatten)]
+    level_2_details: Option<Level2AmountDetails>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Level2AmountDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freight_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    unit_price: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    total_amount: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    taxable: Option<bool>,
+}
+
+fn get_optional_base_unit_amount(
+    amount: Option<common_utils::types::MinorUnit>,
+    currency: api_models::enums::Currency,
+) -> Result<Option<String>, error_stack::Report<errors::ConnectorError>> {
+    amount
+        .map(|amount| utils::to_currency_base_unit(amount.get_amount_as_i64(), currency))
+        .transpose()
+}
+
+impl TryFrom<(&payments::L2L3Data, api_models::enums::Currency)> for Level2AmountDetails {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (l2_l3_data, currency): (&payments::L2L3Data, api_models::enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tax_amount: get_optional_base_unit_amount(l2_l3_data.tax_amount, currency)?,
+            duty_amount: get_optional_base_unit_amount(l2_l3_data.duty_amount, currency)?,
+            freight_amount: get_optional_base_unit_amount(l2_l3_data.freight_amount, currency)?,
+            discount_amount: get_optional_base_unit_amount(l2_l3_data.discount_amount, currency)?,
+        })
+    }
+}
+
+impl TryFrom<(&payments::L2L3LineItem, api_models::enums::Currency)> for LineItem {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (line_item, currency): (&payments::L2L3LineItem, api_models::enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        let total_amount = line_item
+            .get_total_amount()
+            .ok_or(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Self {
+            product_name: line_item.product_name.clone(),
+            product_code: line_item.product_code.clone(),
+            commodity_code: line_item.commodity_code.clone(),
+            quantity: line_item.quantity,
+            unit_of_measure: line_item.unit_of_measure.clone(),
+            unit_price: utils::to_currency_base_unit(
+                line_item.unit_amount.get_amount_as_i64(),
+                currency,
+            )?,
+            tax_amount: get_optional_base_unit_amount(line_item.tax_amount, currency)?,
+            discount_amount: get_optional_base_unit_amount(line_item.discount_amount, currency)?,
+            total_amount: utils::to_currency_base_unit(total_amount.get_amount_as_i64(), currency)?,
+        })
+    }
+}
+
+impl From<&payments::L2L3Data> for InvoiceDetails {
+    fn from(l2_l3_data: &payments::L2L3Data) -> Self {
+        // The customer reference is sent as the purchase order number when no PO number is present,
+        // as that is the field used as the customer code for commercial cards
+        Self {
+            purchase_order_number: l2_l3_data
+                .purchase_order_number
+                .clone()
+                .or_else(|| l2_l3_data.customer_reference.clone()),
+            taxable: l2_l3_data.tax_exempt.map(|tax_exempt| !tax_exempt),
+        }
+    }
+}
+
+/// The Level 2 / Level 3 details of a payment, in the format shared by the connectors built on the
+/// Cybersource API
+#[derive(Debug, Default)]
+pub struct L2L3Details {
+    pub level_2_details: Option<Level2AmountDetails>,
+    pub line_items: Option<Vec<LineItem>>,
+    pub invoice_details: Option<InvoiceDetails>,
+}
+
+pub fn get_l2_l3_details(
+    l2_l3_data: Option<&payments::L2L3Data>,
+    currency: api_models::enums::Currency,
+) -> Result<L2L3Details, error_stack::Report<errors::ConnectorError>> {
+    let Some(l2_l3_data) = l2_l3_data else {
+        return Ok(L2L3Details::default());
+    };
+    let line_items = l2_l3_data
+        .line_items
+        .as_ref()
+        .map(|line_items| {
+            line_items
+                .iter()
+                .map(|line_item| LineItem::try_from((line_item, currency)))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+    Ok(L2L3Details {
+        level_2_details: Some(Level2AmountDetails::try_from((l2_l3_data, currency))?),
+        line_items,
+        invoice_details: Some(InvoiceDetails::from(l2_l3_data)),
+    })
 }
 
 #[derive(Debug, Serialize)]
@@ -837,24 +982,31 @@ impl
 }
 
 impl
-    From<(
+    TryFrom<(
         &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
         Option<BillTo>,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
             Option<BillTo>,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let currency = item.router_data.request.currency;
+        let l2_l3_details =
+            get_l2_l3_details(item.router_data.request.l2_l3_data.as_ref(), currency)?;
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
-                currency: item.router_data.request.currency,
+                currency,
+                level_2_details: l2_l3_details.level_2_details,
             },
             bill_to,
-        }
+            line_items: l2_l3_details.line_items,
+            invoice_details: l2_l3_details.invoice_details,
+        })
     }
 }
 
@@ -874,8 +1026,11 @@ impl
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                level_2_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
         }
     }
 }
@@ -987,7 +1142,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let card_issuer = ccard.get_card_issuer();
         let card_type = match card_issuer {
@@ -1150,7 +1305,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -1219,7 +1374,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information =
             PaymentInformation::GooglePay(Box::new(GooglePayPaymentInformation {
@@ -1285,7 +1440,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                                         email,
                                     )?;
                                     let order_information =
-                                        OrderInformationWithBill::from((item, Some(bill_to)));
+                                        OrderInformationWithBill::try_from((item, Some(bill_to)))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1435,7 +1590,7 @@ impl
             item.router_data.request.get_email().ok().and_then(|email| {
                 build_bill_to(item.router_data.get_optional_billing(), email).ok()
             });
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(Box::new(MandatePaymentInformation {
                 payment_instrument,
@@ -1567,8 +1722,11 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCaptureRouterData>>
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_2_details: None,
                 },
                 bill_to: None,
+                line_items: None,
+                invoice_details: None,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(item.router_data.connector_request_reference_id.clone()),
@@ -1656,6 +1814,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCancelRouterData>> for Cyber
                             field_name: "Currency",
                         },
                     )?,
+                    level_2_details: None,
                 },
                 reason: value
                     .router_data
@@ -2244,6 +2403,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
                     field_name: "currency",
                 },
             )?,
+            level_2_details: None,
         };
 
         match redirect_response.params {
@@ -2262,6 +2422,8 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
                 let order_information = OrderInformationWithBill {
                     amount_details,
                     bill_to: Some(bill_to),
+                    line_items: None,
+                    invoice_details: None,
                 };
                 Ok(Self::AuthEnrollment(Box::new(
                     CybersourceAuthEnrollmentRequest {
@@ -2914,6 +3076,7 @@ impl<F> TryFrom<&CybersourceRouterData<&types::RefundsRouterData<F>>> for Cybers
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_2_details: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -3143,6 +3306,7 @@ impl TryFrom<&CybersourceRouterData<&types::PayoutsRouterData<api::PoFulfill>>>
                     amount_details: Amount {
                         total_amount: item.amount.to_owned(),
                         currency: item.router_data.request.destination_currency,
+                        level_2_details: None,
                     },
                 };
 
@@ -3446,3 +3610,112 @@ pub fn get_error_reason(
         (None, None, None) => None,
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use api_models::payments;
+    use common_utils::types::MinorUnit;
+
+    /// Returns the Level 2 / Level 3 data of an order of copy paper, for the tests of the
+    /// connectors built on the Cybersource API
+    pub(crate) fn l2_l3_data() -> payments::L2L3Data {
+        payments::L2L3Data {
+            tax_amount: Some(MinorUnit::new(162)),
+            tax_exempt: Some(false),
+            customer_reference: Some("CUSTREF123".to_string()),
+            freight_amount: Some(MinorUnit::new(1000)),
+            line_items: Some(vec![payments::L2L3LineItem {
+                product_name: "Copy paper".to_string(),
+                product_code: Some("CP-500".to_string()),
+                commodity_code: None,
+                quantity: 10,
+                unit_of_measure: Some("box".to_string()),
+                unit_amount: MinorUnit::new(2500),
+                tax_amount: Some(MinorUnit::new(162)),
+                discount_amount: None,
+            }]),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::types::MinorUnit;
+
+    use super::{test_utils::l2_l3_data, *};
+
+    #[test]
+    fn test_level_2_amounts_are_sent_in_the_base_unit_alongside_the_total() {
+        let amount = Amount {
+            total_amount: "251.62".to_string(),
+            currency: api_models::enums::Currency::USD,
+            level_2_details: Some(
+                Level2AmountDetails::try_from((&l2_l3_data(), api_models::enums::Currency::USD))
+                    .unwrap(),
+            ),
+        };
+
+        assert_eq!(
+            serde_json::to_value(amount).unwrap(),
+            serde_json::json!({
+                "totalAmount": "251.62",
+                "currency": "USD",
+                "taxAmount": "1.62",
+                "freightAmount": "10.00",
+            })
+        );
+    }
+
+    #[test]
+    fn test_line_items_include_the_total_of_the_line() {
+        let l2_l3_data = l2_l3_data();
+        let line_item = &l2_l3_data.line_items.as_ref().unwrap()[0];
+
+        assert_eq!(
+            serde_json::to_value(
+                LineItem::try_from((line_item, api_models::enums::Currency::USD)).unwrap()
+            )
+            .unwrap(),
+            serde_json::json!({
+                "productName": "Copy paper",
+                "productCode": "CP-500",
+                "quantity": 10,
+                "unitOfMeasure": "box",
+                "unitPrice": "25.00",
+                "taxAmount": "1.62",
+                "totalAmount": "251.62",
+            })
+        );
+
+        let overflowing_line_item = payments::L2L3LineItem {
+            unit_amount: MinorUnit::new(i64::MAX),
+            ..line_item.clone()
+        };
+        assert!(
+            LineItem::try_from((&overflowing_line_item, api_models::enums::Currency::USD)).is_err()
+        );
+    }
+
+    #[test]
+    fn test_invoice_details_fall_back_to_the_customer_reference() {
+        let invoice_details = InvoiceDetails::from(&l2_l3_data());
+        assert_eq!(
+            invoice_details.purchase_order_number.as_deref(),
+            Some("CUSTREF123")
+        );
+        assert_eq!(invoice_details.taxable, Some(true));
+
+        let invoice_details = InvoiceDetails::from(&payments::L2L3Data {
+            purchase_order_number: Some("PO-2024-0042".to_string()),
+            tax_exempt: Some(true),
+            ..l2_l3_data()
+        });
+        assert_eq!(
+            invoice_details.purchase_order_number.as_deref(),
+            Some("PO-2024-0042")
+        );
+        assert_eq!(invoice_details.taxable, Some(false));
+    }
+}
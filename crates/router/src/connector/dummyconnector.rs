@@ -4,7 +4,7 @@ use std::fmt::Debug;
 
 use common_utils::request::RequestContent;
 use diesel_models::enums;
-use error_stack::{report, ResultExt};
+use error_stack::ResultExt;
 
 use super::utils::RefundsRequestData;
 use crate::{
@@ -592,22 +592,49 @@ impl<const T: u8> ConnectorIntegration<api::RSync, types::RefundsData, types::Re
 impl<const T: u8> api::IncomingWebhook for DummyConnector<T> {
     fn get_webhook_object_reference_id(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        match webhook.event {
+            transformers::DummyConnectorWebhookEvent::PaymentSucceeded
+            | transformers::DummyConnectorWebhookEvent::PaymentFailed
+            | transformers::DummyConnectorWebhookEvent::PaymentPartiallyCaptured => {
+                Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
+                    api_models::payments::PaymentIdType::ConnectorTransactionId(webhook.id),
+                ))
+            }
+            transformers::DummyConnectorWebhookEvent::RefundSucceeded
+            | transformers::DummyConnectorWebhookEvent::RefundFailed => {
+                Ok(api_models::webhooks::ObjectReferenceId::RefundId(
+                    api_models::webhooks::RefundIdType::ConnectorRefundId(webhook.id),
+                ))
+            }
+        }
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Ok(api::IncomingWebhookEvent::EventNotSupported)
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        Ok(api::IncomingWebhookEvent::from(&webhook.event))
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        Ok(Box::new(webhook))
     }
 }
@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use diesel_models::enums::Currency;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
@@ -65,6 +66,8 @@ pub struct DummyConnectorPaymentsRequest<const T: u8> {
     payment_method_data: PaymentMethodData,
     return_url: Option<String>,
     connector: DummyConnectors,
+    metadata: Option<serde_json::Value>,
+    merchant_id: common_utils::id_type::MerchantId,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -175,6 +178,8 @@ impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
             payment_method_data: payment_method_data?,
             return_url: item.request.router_return_url.clone(),
             connector: Into::<DummyConnectors>::into(T),
+            metadata: item.request.metadata.clone(),
+            merchant_id: item.merchant_id.clone(),
         })
     }
 }
@@ -204,6 +209,9 @@ pub enum DummyConnectorPaymentStatus {
     Failed,
     #[default]
     Processing,
+    Pending,
+    #[serde(rename = "partially_captured")]
+    PartiallyCaptured,
 }
 
 impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
@@ -212,6 +220,8 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
             DummyConnectorPaymentStatus::Succeeded => Self::Charged,
             DummyConnectorPaymentStatus::Failed => Self::Failure,
             DummyConnectorPaymentStatus::Processing => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Pending => Self::Pending,
+            DummyConnectorPaymentStatus::PartiallyCaptured => Self::PartialCharged,
         }
     }
 }
@@ -225,6 +235,8 @@ pub struct PaymentsResponse {
     created: String,
     payment_method_type: PaymentMethodType,
     next_action: Option<DummyConnectorNextAction>,
+    amount_captured: Option<i64>,
+    error: Option<ErrorData>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -249,9 +261,17 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
             .map(|redirection_url| {
                 services::RedirectForm::from((redirection_url, services::Method::Get))
             });
-        Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
+        let status = enums::AttemptStatus::from(item.response.status);
+        let response = match item.response.error {
+            Some(error) => Err(types::ErrorResponse {
+                status_code: item.http_code,
+                code: error.code,
+                message: error.message,
+                reason: error.reason,
+                attempt_status: Some(status),
+                connector_transaction_id: Some(item.response.id),
+            }),
+            None => Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data,
                 mandate_reference: None,
@@ -261,6 +281,16 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
                 incremental_authorization_allowed: None,
                 charge_id: None,
             }),
+        };
+        Ok(Self {
+            status,
+            response,
+            amount_captured: item.response.amount_captured.or(item.data.amount_captured),
+            minor_amount_captured: item
+                .response
+                .amount_captured
+                .map(MinorUnit::new)
+                .or(item.data.minor_amount_captured),
             ..item.data
         })
     }
@@ -327,6 +357,29 @@ pub struct RefundResponse {
     created: String,
     payment_amount: i64,
     refund_amount: i64,
+    error: Option<ErrorData>,
+}
+
+impl RefundResponse {
+    fn get_refunds_response_data(
+        self,
+        http_code: u16,
+    ) -> Result<types::RefundsResponseData, types::ErrorResponse> {
+        match self.error {
+            Some(error) => Err(types::ErrorResponse {
+                status_code: http_code,
+                code: error.code,
+                message: error.message,
+                reason: error.reason,
+                attempt_status: None,
+                connector_transaction_id: Some(self.id),
+            }),
+            None => Ok(types::RefundsResponseData {
+                connector_refund_id: self.id,
+                refund_status: enums::RefundStatus::from(self.status),
+            }),
+        }
+    }
 }
 
 impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
@@ -337,10 +390,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
         item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
     ) -> Result<Self, Self::Error> {
         Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id.to_string(),
-                refund_status: enums::RefundStatus::from(item.response.status),
-            }),
+            response: item.response.get_refunds_response_data(item.http_code),
             ..item.data
         })
     }
@@ -354,10 +404,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
         item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
     ) -> Result<Self, Self::Error> {
         Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id.to_string(),
-                refund_status: enums::RefundStatus::from(item.response.status),
-            }),
+            response: item.response.get_refunds_response_data(item.http_code),
             ..item.data
         })
     }
@@ -368,9 +415,39 @@ pub struct DummyConnectorErrorResponse {
     pub error: ErrorData,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ErrorData {
     pub code: String,
     pub message: String,
     pub reason: Option<String>,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEvent {
+    PaymentSucceeded,
+    PaymentFailed,
+    PaymentPartiallyCaptured,
+    RefundSucceeded,
+    RefundFailed,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DummyConnectorWebhook {
+    pub event: DummyConnectorWebhookEvent,
+    /// Identifier of the payment or the refund the event is about
+    pub id: String,
+    pub payment_id: String,
+}
+
+impl From<&DummyConnectorWebhookEvent> for api::IncomingWebhookEvent {
+    fn from(event: &DummyConnectorWebhookEvent) -> Self {
+        match event {
+            DummyConnectorWebhookEvent::PaymentSucceeded
+            | DummyConnectorWebhookEvent::PaymentPartiallyCaptured => Self::PaymentIntentSuccess,
+            DummyConnectorWebhookEvent::PaymentFailed => Self::PaymentIntentFailure,
+            DummyConnectorWebhookEvent::RefundSucceeded => Self::RefundSuccess,
+            DummyConnectorWebhookEvent::RefundFailed => Self::RefundFailure,
+        }
+    }
+}
@@ -253,6 +253,23 @@ pub enum ThreeDSRequestorChallengeIndicator {
     ChallengeRequestedWhitelistPrompt,
 }
 
+impl From<common_enums::ScaExemptionType> for ThreeDSRequestorChallengeIndicator {
+    fn from(sca_exemption: common_enums::ScaExemptionType) -> Self {
+        match sca_exemption {
+            common_enums::ScaExemptionType::TransactionRiskAnalysis => {
+                Self::NoChallengeRequestedTransactionalRiskAnalysis
+            }
+            common_enums::ScaExemptionType::TrustedBeneficiary => {
+                Self::NoChallengeRequestedWhitelistExemption
+            }
+            common_enums::ScaExemptionType::LowValue
+            | common_enums::ScaExemptionType::MerchantInitiatedTransaction => {
+                Self::NoChallengeRequested
+            }
+        }
+    }
+}
+
 /// This field contains information about how the 3DS Requestor authenticated the cardholder as part of a previous 3DS transaction.
 /// Format of this field was changed with EMV 3DS 2.3.1 version:
 /// In versions prior to 2.3.1, this field is a single object.
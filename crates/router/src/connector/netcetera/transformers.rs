@@ -460,7 +460,9 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
             three_ds_requestor_authentication_ind:
                 netcetera_types::ThreeDSRequestorAuthenticationIndicator::Payment,
             three_ds_requestor_authentication_info: None,
-            three_ds_requestor_challenge_ind: None,
+            three_ds_requestor_challenge_ind: request.sca_exemption.map(|sca_exemption| {
+                netcetera_types::SingleOrListElement::new_single(sca_exemption.into())
+            }),
             three_ds_requestor_prior_authentication_info: None,
             three_ds_requestor_dec_req_ind: None,
             three_ds_requestor_dec_max_time: None,
@@ -159,6 +159,8 @@ pub struct PaymentIntentRequest {
     pub browser_info: Option<StripeBrowserInformation>,
     #[serde(flatten)]
     pub charges: Option<IntentCharges>,
+    #[serde(flatten)]
+    pub split_transfer: Option<IntentSplitTransfer>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -171,6 +173,18 @@ pub struct IntentCharges {
     pub destination_account_id: Option<String>,
 }
 
+/// Stripe supports a single destination for the funds of a payment, so a split payment with one
+/// recipient is made as a destination charge transferring the share of the recipient
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct IntentSplitTransfer {
+    #[serde(rename = "transfer_data[destination]")]
+    pub destination_account_id: String,
+    #[serde(rename = "transfer_data[amount]")]
+    pub amount: MinorUnit,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<String>,
+}
+
 // Field rename is required only in case of serialization as it is passed in the request to the connector.
 // Deserialization is happening only in case of webhooks, where fields name should be used as defined in the struct.
 // Whenever adding new fields, Please ensure it doesn't break the webhook flow
@@ -1882,6 +1896,29 @@ impl TryFrom<(&types::PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntent
             None => (None, item.connector_customer.to_owned().map(Secret::new)),
         };
 
+        let split_transfer = item
+            .request
+            .split_payments
+            .as_ref()
+            .map(
+                |split_payments| match split_payments.allocations.as_slice() {
+                    [allocation] => Ok(IntentSplitTransfer {
+                        destination_account_id: allocation.account_id.clone(),
+                        amount: allocation.amount,
+                        on_behalf_of: split_payments
+                            .fee_bearer
+                            .as_ref()
+                            .filter(|fee_bearer| **fee_bearer == allocation.account_id)
+                            .cloned(),
+                    }),
+                    _ => Err(errors::ConnectorError::NotSupported {
+                        message: "Split payments with more than one recipient".to_string(),
+                        connector: "Stripe",
+                    }),
+                },
+            )
+            .transpose()?;
+
         Ok(Self {
             amount,                                      //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
@@ -1909,6 +1946,7 @@ impl TryFrom<(&types::PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntent
             expand: Some(ExpandableObjects::LatestCharge),
             browser_info,
             charges,
+            split_transfer,
         })
     }
 }
@@ -2892,6 +2930,8 @@ pub struct BacsFinancialDetails {
 pub struct RefundRequest {
     pub amount: Option<MinorUnit>, //amount in cents, hence passed as integer
     pub payment_intent: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_transfer: Option<bool>,
     #[serde(flatten)]
     pub meta_data: StripeMetadata,
 }
@@ -2905,6 +2945,8 @@ impl<F> TryFrom<(&types::RefundsRouterData<F>, MinorUnit)> for RefundRequest {
         Ok(Self {
             amount: Some(refund_amount),
             payment_intent,
+            // Stripe reverses the transfer of a split payment in proportion to the refunded amount
+            reverse_transfer: item.request.split_refunds.as_ref().map(|_| true),
             meta_data: StripeMetadata {
                 order_id: Some(item.request.refund_id.clone()),
                 is_refund_id_as_reference: Some("true".to_string()),
@@ -7,9 +7,15 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::{
-    connector::utils::{
-        self, AddressDetailsData, ApplePayDecrypt, CardData, PaymentsAuthorizeRequestData,
-        PaymentsSetupMandateRequestData, PaymentsSyncRequestData, RecurringMandateData, RouterData,
+    connector::{
+        cybersource::transformers::{
+            get_l2_l3_details, InvoiceDetails, Level2AmountDetails, LineItem,
+        },
+        utils::{
+            self, AddressDetailsData, ApplePayDecrypt, CardData, PaymentsAuthorizeRequestData,
+            PaymentsSetupMandateRequestData, PaymentsSyncRequestData, RecurringMandateData,
+            RouterData,
+        },
     },
     consts,
     core::errors,
@@ -63,8 +69,11 @@ impl TryFrom<&types::SetupMandateRouterData> for WellsfargoZeroMandateRequest {
             amount_details: Amount {
                 total_amount: "0".to_string(),
                 currency: item.request.currency,
+                level_2_details: None,
             },
             bill_to: Some(bill_to),
+            line_items: None,
+            invoice_details: None,
         };
         let (action_list, action_token_types, authorization_options) = (
             Some(vec![WellsfargoActionsList::TokenCreate]),
@@ -450,6 +459,10 @@ pub struct Card {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
 }
 
 #[derive(Debug, Serialize)]
@@ -469,6 +482,8 @@ pub struct OrderInformation {
 pub struct Amount {
     total_amount: String,
     currency: api_models::enums::Currency,
+    #[serde(flatten)]
+    level_2_details: Option<Level2AmountDetails>,
 }
 
 #[derive(Debug, Serialize)]
@@ -775,24 +790,31 @@ fn get_commerce_indicator_for_external_authentication(
 }
 
 impl
-    From<(
+    TryFrom<(
         &WellsfargoRouterData<&types::PaymentsAuthorizeRouterData>,
         Option<BillTo>,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &WellsfargoRouterData<&types::PaymentsAuthorizeRouterData>,
             Option<BillTo>,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let currency = item.router_data.request.currency;
+        let l2_l3_details =
+            get_l2_l3_details(item.router_data.request.l2_l3_data.as_ref(), currency)?;
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
-                currency: item.router_data.request.currency,
+                currency,
+                level_2_details: l2_l3_details.level_2_details,
             },
             bill_to,
-        }
+            line_items: l2_l3_details.line_items,
+            invoice_details: l2_l3_details.invoice_details,
+        })
     }
 }
 
@@ -876,7 +898,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let card_issuer = ccard.get_card_issuer();
         let card_type = match card_issuer {
@@ -958,7 +980,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -1027,7 +1049,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
 
         let payment_information =
             PaymentInformation::GooglePay(Box::new(GooglePayPaymentInformation {
@@ -1090,7 +1112,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_optional_billing(), email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = match bank_debit_data {
             domain::BankDebitData::AchBankDebit {
                 account_number,
@@ -1164,7 +1186,7 @@ impl TryFrom<&WellsfargoRouterData<&types::PaymentsAuthorizeRouterData>>
                                         email,
                                     )?;
                                     let order_information =
-                                        OrderInformationWithBill::from((item, Some(bill_to)));
+                                        OrderInformationWithBill::try_from((item, Some(bill_to)))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1314,7 +1336,7 @@ impl
             item.router_data.request.get_email().ok().and_then(|email| {
                 build_bill_to(item.router_data.get_optional_billing(), email).ok()
             });
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(Box::new(MandatePaymentInformation {
                 payment_instrument,
@@ -1384,8 +1406,11 @@ impl TryFrom<&WellsfargoRouterData<&types::PaymentsCaptureRouterData>>
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_2_details: None,
                 },
                 bill_to: None,
+                line_items: None,
+                invoice_details: None,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(item.router_data.connector_request_reference_id.clone()),
@@ -1473,6 +1498,7 @@ impl TryFrom<&WellsfargoRouterData<&types::PaymentsCancelRouterData>> for Wellsf
                             field_name: "Currency",
                         },
                     )?,
+                    level_2_details: None,
                 },
                 reason: value
                     .router_data
@@ -2182,6 +2208,7 @@ impl<F> TryFrom<&WellsfargoRouterData<&types::RefundsRouterData<F>>> for Wellsfa
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    level_2_details: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2494,3 +2521,52 @@ pub fn get_error_reason(
         (None, None, None) => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::connector::cybersource::transformers::test_utils::l2_l3_data;
+
+    #[test]
+    fn test_l2_l3_details_are_sent_in_the_order_information() {
+        let l2_l3_details =
+            get_l2_l3_details(Some(&l2_l3_data()), api_models::enums::Currency::USD).unwrap();
+        let order_information = OrderInformationWithBill {
+            amount_details: Amount {
+                total_amount: "251.62".to_string(),
+                currency: api_models::enums::Currency::USD,
+                level_2_details: l2_l3_details.level_2_details,
+            },
+            bill_to: None,
+            line_items: l2_l3_details.line_items,
+            invoice_details: l2_l3_details.invoice_details,
+        };
+
+        assert_eq!(
+            serde_json::to_value(order_information).unwrap(),
+            serde_json::json!({
+                "amountDetails": {
+                    "totalAmount": "251.62",
+                    "currency": "USD",
+                    "taxAmount": "1.62",
+                    "freightAmount": "10.00",
+                },
+                "billTo": null,
+                "lineItems": [{
+                    "productName": "Copy paper",
+                    "productCode": "CP-500",
+                    "quantity": 10,
+                    "unitOfMeasure": "box",
+                    "unitPrice": "25.00",
+                    "taxAmount": "1.62",
+                    "totalAmount": "251.62",
+                }],
+                "invoiceDetails": {
+                    "purchaseOrderNumber": "CUSTREF123",
+                    "taxable": true,
+                },
+            })
+        );
+    }
+}
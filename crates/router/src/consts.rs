@@ -50,6 +50,9 @@ pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose = consts::BASE64_
 pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
+pub(crate) const BASE64_ENGINE_URL_SAFE_NO_PAD: base64::engine::GeneralPurpose =
+    base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
 pub(crate) const API_KEY_LENGTH: usize = 64;
 
 // Apple Pay validation url
@@ -130,3 +133,96 @@ pub const CONNECTOR_CREDS_TOKEN_TTL: i64 = 900;
 
 //max_amount allowed is 999999999 in minor units
 pub const MAX_ALLOWED_AMOUNT: i64 = 999999999;
+
+/// Prefix for the redis keys holding the velocity counters of the native fraud engine
+pub const NATIVE_FRM_VELOCITY_PREFIX: &str = "native_frm_velocity";
+
+/// Longest counting window accepted in the velocity conditions of the native fraud engine
+pub const NATIVE_FRM_MAX_VELOCITY_WINDOW_IN_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Default number of fraud checks returned from the manual review queue
+pub const DEFAULT_FRM_REVIEW_QUEUE_LIMIT: i64 = 20;
+
+/// Hours before the evidence deadline of a dispute at which reminders are sent, when the
+/// merchant has not configured a dispute deadline policy
+pub const DEFAULT_DISPUTE_REMINDER_OFFSETS_IN_HOURS: [u16; 2] = [72, 24];
+
+/// Default window in hours used when listing upcoming dispute evidence deadlines
+pub const DEFAULT_DISPUTE_DEADLINE_WINDOW_IN_HOURS: u16 = 168;
+
+/// Default number of data subject requests returned when listing them
+pub const DEFAULT_DATA_SUBJECT_REQUEST_LIST_LIMIT: i64 = 20;
+
+/// Default number of rows written to a single data retention archive
+pub const DEFAULT_DATA_RETENTION_BATCH_SIZE: u16 = 1000;
+
+/// Maximum number of archives written for a table in a single run of the data retention workflow,
+/// the workflow is rescheduled immediately when more expired rows are left
+pub const DATA_RETENTION_MAX_BATCHES_PER_RUN: u16 = 10;
+
+/// Interval in seconds between two runs of the data retention workflow of a table
+pub const DATA_RETENTION_RUN_INTERVAL_IN_SECS: i64 = 86400;
+
+/// Default number of data retention archives returned when listing them
+pub const DEFAULT_DATA_RETENTION_ARCHIVE_LIST_LIMIT: i64 = 20;
+
+/// Number of data retention archives listed at once when they are rewritten, for the erasure of a
+/// customer or the key rotation of a merchant
+pub const DATA_RETENTION_ARCHIVE_BATCH_SIZE: i64 = 10;
+
+/// Amount thresholds (in minor units) of the transaction risk analysis exemption by the fraud rate
+/// in basis points, as per the reference fraud rates of PSD2 RTS Article 18 for card payments
+pub const DEFAULT_SCA_TRA_THRESHOLDS: [(u16, i64); 3] = [(13, 10000), (6, 25000), (1, 50000)];
+
+/// Number of rows re-encrypted between two progress updates of a merchant key rotation
+pub const KEY_ROTATION_BATCH_SIZE: i64 = 500;
+
+/// Maximum number of batches re-encrypted in a single run of the key rotation workflow, the
+/// workflow is rescheduled immediately when rows are left
+pub const KEY_ROTATION_MAX_BATCHES_PER_RUN: u16 = 20;
+
+/// Number of seconds to wait once the stored data of a merchant is re-encrypted before it is
+/// verified, so that the merchant key stores cached before the rotation, which encrypt with the
+/// previous key, have expired
+pub const KEY_ROTATION_VERIFICATION_DELAY_IN_SECS: u64 = storage_impl::redis::cache::CACHE_TTL;
+
+/// Default number of merchant key rotations returned when listing them
+pub const DEFAULT_KEY_ROTATION_LIST_LIMIT: i64 = 20;
+
+/// Default number of seconds for which a replaced webhook signing key remains published
+pub const DEFAULT_WEBHOOK_SIGNING_KEY_OVERLAP_PERIOD_IN_SECS: u32 = 7 * 24 * 60 * 60;
+
+/// Default number of invoices returned when listing them
+pub const DEFAULT_INVOICE_LIST_LIMIT: i64 = 20;
+
+/// Default number of ledger entries returned when listing them
+pub const DEFAULT_LEDGER_ENTRY_LIST_LIMIT: i64 = 20;
+
+/// Number of payments or payouts whose ledger entries are rebuilt together, atomically
+pub const LEDGER_REBUILD_BATCH_SIZE: i64 = 100;
+
+/// Maximum number of batches rebuilt in a single run of the ledger rebuild workflow, the
+/// workflow is rescheduled immediately when payments or payouts are left
+pub const LEDGER_REBUILD_MAX_BATCHES_PER_RUN: u16 = 20;
+
+/// Maximum number of line items on a single invoice
+pub const MAX_INVOICE_LINE_ITEMS: usize = 250;
+
+/// Prefix of the invoice numbers of a business profile which has not configured its invoice
+/// number sequence
+pub const DEFAULT_INVOICE_NUMBER_PREFIX: &str = "INV-";
+
+/// Maximum length of the prefix of an invoice number sequence
+pub const MAX_INVOICE_NUMBER_PREFIX_LENGTH: usize = 32;
+
+/// Key of the payment metadata under which the invoice paid by a payment created for an invoice
+/// is recorded
+pub const INVOICE_PAYMENT_METADATA_KEY: &str = "hyperswitch_invoice";
+
+/// Days relative to the due date of an invoice on which reminders are emailed to the customer,
+/// when the invoice does not specify a reminder schedule
+pub const DEFAULT_INVOICE_REMINDER_OFFSETS_IN_DAYS: [i16; 3] = [-3, 0, 7];
+
+/// Maximum number of days before or after the due date of an invoice on which a reminder can be
+/// sent
+pub const MAX_INVOICE_REMINDER_OFFSET_IN_DAYS: i16 = 365;
@@ -27,3 +27,8 @@ pub const REDIS_TOTP_SECRET_TTL_IN_SECS: i64 = 15 * 60; // 15 minutes
 
 pub const REDIS_SSO_PREFIX: &str = "SSO_";
 pub const REDIS_SSO_TTL: i64 = 5 * 60; // 5 minutes
+
+/// Identifier recorded as the creator of user roles provisioned through SCIM
+pub const SCIM_PROVISIONER_ID: &str = "scim";
+/// Default page size for SCIM list responses
+pub const SCIM_DEFAULT_PAGE_SIZE: usize = 100;
@@ -4,6 +4,7 @@ pub mod api_locking;
 pub mod apple_pay_certificates_migration;
 pub mod authentication;
 pub mod blocklist;
+pub mod bulk_import;
 pub mod cache;
 pub mod cards_info;
 pub mod conditional_config;
@@ -13,6 +14,8 @@ pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(feature = "olap")]
+pub mod data_retention;
 pub mod disputes;
 pub mod encryption;
 pub mod errors;
@@ -21,8 +24,12 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod invoices;
+pub mod ledger;
 pub mod locker_migration;
 pub mod mandate;
+#[cfg(feature = "olap")]
+pub mod merchant_key_rotation;
 pub mod metrics;
 pub mod payment_link;
 pub mod payment_methods;
@@ -35,6 +42,8 @@ pub mod pm_auth;
 pub mod poll;
 pub mod refunds;
 pub mod routing;
+#[cfg(feature = "olap")]
+pub mod scim;
 pub mod surcharge_decision_config;
 #[cfg(feature = "olap")]
 pub mod user;
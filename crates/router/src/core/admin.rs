@@ -230,6 +230,8 @@ pub async fn create_merchant_account(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to decrypt data from key store")?,
         created_at: date_time::now(),
+        key_version: 1,
+        previous_key: None,
     };
 
     let domain_merchant_account = req
@@ -1486,6 +1488,10 @@ impl<'a> ConnectorAuthTypeAndMetadataValidation<'a> {
                 stax::transformers::StaxAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            api_enums::Connector::Taxjar => {
+                taxjar::transformers::TaxjarAuthType::try_from(self.auth_type)?;
+                Ok(())
+            }
             api_enums::Connector::Stripe => {
                 stripe::transformers::StripeAuthType::try_from(self.auth_type)?;
                 Ok(())
@@ -1534,6 +1540,10 @@ impl<'a> ConnectorAuthTypeAndMetadataValidation<'a> {
                 riskified::transformers::RiskifiedAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            api_enums::Connector::NativeFrm => match self.auth_type {
+                types::ConnectorAuthType::NoKey => Ok(()),
+                _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+            },
             api_enums::Connector::Plaid => {
                 PlaidAuthType::foreign_try_from(self.auth_type)?;
                 Ok(())
@@ -1841,6 +1851,8 @@ impl<'a> ConnectorTypeAndConnectorName<'a> {
             api_enums::convert_pm_auth_connector(self.connector_name.to_string().as_str());
         let authentication_connector =
             api_enums::convert_authentication_connector(self.connector_name.to_string().as_str());
+        let tax_connector =
+            api_enums::convert_tax_connector(self.connector_name.to_string().as_str());
 
         if pm_auth_connector.is_some() {
             if self.connector_type != &api_enums::ConnectorType::PaymentMethodAuth
@@ -1858,6 +1870,13 @@ impl<'a> ConnectorTypeAndConnectorName<'a> {
                 }
                 .into());
             }
+        } else if tax_connector.is_some() {
+            if self.connector_type != &api_enums::ConnectorType::FinOperations {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Invalid connector type given".to_string(),
+                }
+                .into());
+            }
         } else {
             let routable_connector_option = self
                 .connector_name
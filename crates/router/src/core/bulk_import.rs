@@ -0,0 +1,146 @@
+//! Helpers shared by the bulk imports of configuration records, such as GSM rules and card BIN
+//! ranges. Every record of an import is validated before anything is written, so that an import
+//! with errors can be corrected and uploaded again as a whole.
+
+use std::{collections::HashSet, hash::Hash};
+
+/// A validated record of an import along with the stored record it replaces, if any
+pub trait PlannedImportRecord {
+    fn is_new(&self) -> bool;
+
+    /// Whether the stored record already matches the imported one, in which case nothing has to
+    /// be written for it
+    fn is_unchanged(&self) -> bool;
+}
+
+/// The records of an import which have to be written, along with the counts of the changes
+pub struct ImportPlan<P> {
+    pub pending: Vec<P>,
+    pub created_count: usize,
+    pub updated_count: usize,
+    pub unchanged_count: usize,
+}
+
+impl<P: PlannedImportRecord> ImportPlan<P> {
+    pub fn new(planned_records: Vec<P>) -> Self {
+        let (unchanged, pending): (Vec<_>, Vec<_>) = planned_records
+            .into_iter()
+            .partition(PlannedImportRecord::is_unchanged);
+        let created_count = pending.iter().filter(|planned| planned.is_new()).count();
+
+        Self {
+            updated_count: pending.len() - created_count,
+            created_count,
+            unchanged_count: unchanged.len(),
+            pending,
+        }
+    }
+}
+
+/// Validates the records of an import, returning the validated records along with the errors of
+/// the records which are invalid or whose key appears more than once in the import.
+///
+/// Row numbers start at 1. `import_error` builds the error reported for a record from its row
+/// number and the reason it was rejected.
+pub fn validate_import_records<T, K, R, E>(
+    records: impl IntoIterator<Item = T>,
+    mut validate: impl FnMut(&T) -> Result<(K, R), String>,
+    mut import_error: impl FnMut(usize, &T, String) -> E,
+) -> (Vec<(T, R)>, Vec<E>)
+where
+    K: Eq + Hash,
+{
+    let mut validated = Vec::new();
+    let mut errors = Vec::new();
+    let mut seen_keys = HashSet::new();
+
+    for (index, record) in records.into_iter().enumerate() {
+        let row_number = index + 1;
+        match validate(&record) {
+            Ok((key, validated_record)) => {
+                if seen_keys.insert(key) {
+                    validated.push((record, validated_record));
+                } else {
+                    errors.push(import_error(
+                        row_number,
+                        &record,
+                        "The record appears more than once in the import".to_string(),
+                    ));
+                }
+            }
+            Err(reason) => errors.push(import_error(row_number, &record, reason)),
+        }
+    }
+
+    (validated, errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Planned {
+        existing: Option<u8>,
+        value: u8,
+    }
+
+    impl PlannedImportRecord for Planned {
+        fn is_new(&self) -> bool {
+            self.existing.is_none()
+        }
+
+        fn is_unchanged(&self) -> bool {
+            self.existing == Some(self.value)
+        }
+    }
+
+    #[test]
+    fn test_invalid_and_duplicate_records_are_reported() {
+        let (validated, errors) = validate_import_records(
+            ["a", "b", "", "a"],
+            |record| {
+                if record.is_empty() {
+                    Err("empty".to_string())
+                } else {
+                    Ok((record.to_string(), record.len()))
+                }
+            },
+            |row_number, _, reason| (row_number, reason),
+        );
+
+        assert_eq!(validated, vec![("a", 1), ("b", 1)]);
+        assert_eq!(
+            errors,
+            vec![
+                (3, "empty".to_string()),
+                (
+                    4,
+                    "The record appears more than once in the import".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_plan_counts_changes() {
+        let plan = ImportPlan::new(vec![
+            Planned {
+                existing: None,
+                value: 1,
+            },
+            Planned {
+                existing: Some(1),
+                value: 2,
+            },
+            Planned {
+                existing: Some(3),
+                value: 3,
+            },
+        ]);
+
+        assert_eq!(plan.created_count, 1);
+        assert_eq!(plan.updated_count, 1);
+        assert_eq!(plan.unchanged_count, 1);
+        assert_eq!(plan.pending.len(), 2);
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "olap")]
+pub mod bin_ranges;
+
 use common_utils::fp_utils::when;
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
@@ -0,0 +1,640 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::cards_info::{
+    CardBinRangeDeleteRequest, CardBinRangeDeleteResponse, CardBinRangeListRequest,
+    CardBinRangeListResponse, CardBinRangeRecord, CardBinRangeResponse, CardBinRangeUploadError,
+    CardBinRangeUploadRequest, CardBinRangeUploadResponse,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::cache::{self, CacheKind};
+use strum::IntoEnumIterator;
+
+use crate::{
+    core::{
+        bulk_import,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::{cards_info::CARD_BIN_RANGE_LENGTHS, StorageInterface},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const MAX_CARD_BIN_RANGE_UPLOAD_SIZE: usize = 50000;
+const CARD_BIN_RANGE_LIST_DEFAULT_LIMIT: i64 = 100;
+const CARD_BIN_RANGE_LIST_MAX_LIMIT: i64 = 1000;
+
+#[derive(Debug, MultipartForm)]
+pub struct CardBinRangeForm {
+    #[multipart(limit = "5MB")]
+    pub file: Bytes,
+    pub provider: Option<Text<String>>,
+}
+
+fn parse_csv(data: &[u8]) -> csv::Result<Vec<CardBinRangeRecord>> {
+    let mut csv_reader = csv::Reader::from_reader(data);
+    let mut records = Vec::new();
+    let mut line_number = 0;
+    for result in csv_reader.deserialize() {
+        let mut record: CardBinRangeRecord = result?;
+        line_number += 1;
+        record.line_number = Some(line_number);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+pub fn get_card_bin_range_records(
+    form: CardBinRangeForm,
+) -> Result<CardBinRangeUploadRequest, errors::ApiErrorResponse> {
+    let records = parse_csv(&form.file.data).map_err(|error| {
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Failed to parse the uploaded file: {error}"),
+        }
+    })?;
+
+    Ok(CardBinRangeUploadRequest {
+        file_name: form.file.file_name,
+        provider: form.provider.map(Text::into_inner),
+        records,
+    })
+}
+
+impl ForeignFrom<storage::CardBinRange> for CardBinRangeResponse {
+    fn foreign_from(range: storage::CardBinRange) -> Self {
+        Self {
+            bin_start: range.bin_start,
+            bin_end: range.bin_end,
+            card_issuer: range.card_issuer,
+            card_network: range.card_network.map(|network| network.to_string()),
+            card_type: range.card_type,
+            card_sub_type: range.card_subtype,
+            card_issuing_country: range.card_issuing_country,
+            last_updated_provider: range.last_updated_provider,
+            created_at: range.created_at,
+            modified_at: range.modified_at,
+        }
+    }
+}
+
+/// Card networks are matched ignoring case, since BIN files from different providers do not
+/// agree on the spelling.
+fn parse_card_network(network: &str) -> Result<enums::CardNetwork, String> {
+    enums::CardNetwork::iter()
+        .find(|card_network| card_network.to_string().eq_ignore_ascii_case(network))
+        .ok_or_else(|| format!("Unknown card network `{network}`"))
+}
+
+fn validate_bin(field: &str, bin: &str) -> Result<(), String> {
+    if !bin.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("`{field}` must contain only digits"));
+    }
+    if !CARD_BIN_RANGE_LENGTHS.contains(&bin.len()) {
+        return Err(format!("`{field}` must have 6 or 8 digits"));
+    }
+    Ok(())
+}
+
+/// A validated row of the upload, along with the range it replaces, if any.
+struct PlannedCardBinRange {
+    bin_start: String,
+    bin_end: String,
+    details: storage::CardBinRangeUpdate,
+    existing: Option<storage::CardBinRange>,
+}
+
+fn get_range_details(
+    record: &CardBinRangeRecord,
+    provider: Option<String>,
+) -> Result<(String, String, storage::CardBinRangeUpdate), String> {
+    let bin_start = record.bin_start.trim().to_string();
+    let bin_end = record
+        .bin_end
+        .as_deref()
+        .map(str::trim)
+        .filter(|bin_end| !bin_end.is_empty())
+        .unwrap_or(&bin_start)
+        .to_string();
+
+    validate_bin("bin_start", &bin_start)?;
+    validate_bin("bin_end", &bin_end)?;
+    if bin_start.len() != bin_end.len() {
+        return Err("`bin_start` and `bin_end` must have the same number of digits".to_string());
+    }
+    if bin_start > bin_end {
+        return Err("`bin_start` must not be greater than `bin_end`".to_string());
+    }
+
+    let card_network = record
+        .network
+        .as_deref()
+        .map(str::trim)
+        .filter(|network| !network.is_empty())
+        .map(parse_card_network)
+        .transpose()?;
+    let non_empty = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+
+    Ok((
+        bin_start,
+        bin_end,
+        storage::CardBinRangeUpdate::DetailsUpdate {
+            card_issuer: non_empty(&record.issuer),
+            card_network,
+            card_type: non_empty(&record.card_type),
+            card_subtype: non_empty(&record.product),
+            card_issuing_country: non_empty(&record.country),
+            last_updated_provider: provider,
+        },
+    ))
+}
+
+/// Finds the ranges of the upload which overlap a range starting before them, returning their
+/// positions along with the reason they are rejected. `ranges` holds the first and last BIN of
+/// each range.
+fn find_overlapping_ranges(ranges: &[(&str, &str)]) -> Vec<(usize, String)> {
+    let mut sorted_ranges: Vec<_> = ranges.iter().enumerate().collect();
+    sorted_ranges.sort_by_key(|(_, (bin_start, bin_end))| (bin_start.len(), *bin_start, *bin_end));
+
+    let mut overlapping = Vec::new();
+    // The range reaching the furthest among the ranges of the same length seen so far
+    let mut furthest_range: Option<(&str, &str)> = None;
+    for (position, &(bin_start, bin_end)) in sorted_ranges {
+        match furthest_range {
+            Some((other_start, other_end))
+                if other_start.len() == bin_start.len() && other_end >= bin_start =>
+            {
+                overlapping.push((
+                    position,
+                    format!("The range overlaps the range {other_start}-{other_end} of the file"),
+                ));
+                if bin_end > other_end {
+                    furthest_range = Some((bin_start, bin_end));
+                }
+            }
+            _ => furthest_range = Some((bin_start, bin_end)),
+        }
+    }
+    overlapping
+}
+
+impl bulk_import::PlannedImportRecord for PlannedCardBinRange {
+    fn is_new(&self) -> bool {
+        self.existing.is_none()
+    }
+
+    fn is_unchanged(&self) -> bool {
+        let Some(existing) = &self.existing else {
+            return false;
+        };
+        let storage::CardBinRangeUpdate::DetailsUpdate {
+            card_issuer,
+            card_network,
+            card_type,
+            card_subtype,
+            card_issuing_country,
+            last_updated_provider,
+        } = &self.details;
+        existing.card_issuer == *card_issuer
+            && existing.card_network == *card_network
+            && existing.card_type == *card_type
+            && existing.card_subtype == *card_subtype
+            && existing.card_issuing_country == *card_issuing_country
+            && existing.last_updated_provider == *last_updated_provider
+    }
+}
+
+/// Existing ranges of a single BIN length sorted by their first BIN, along with the furthest last
+/// BIN among each range and the ranges before it, so that the overlapping ranges are found without
+/// scanning all of them.
+struct ExistingCardBinRanges {
+    ranges: Vec<storage::CardBinRange>,
+    furthest_bin_ends: Vec<String>,
+}
+
+impl ExistingCardBinRanges {
+    fn new(ranges: Vec<storage::CardBinRange>) -> Self {
+        let furthest_bin_ends = ranges
+            .iter()
+            .scan(String::new(), |furthest_bin_end, range| {
+                if range.bin_end > *furthest_bin_end {
+                    furthest_bin_end.clone_from(&range.bin_end);
+                }
+                Some(furthest_bin_end.clone())
+            })
+            .collect();
+        Self {
+            ranges,
+            furthest_bin_ends,
+        }
+    }
+
+    /// The ranges sharing at least one BIN with the range from `bin_start` to `bin_end`
+    fn find_overlapping<'a>(
+        &'a self,
+        bin_start: &'a str,
+        bin_end: &'a str,
+    ) -> impl Iterator<Item = &'a storage::CardBinRange> {
+        let first = self
+            .furthest_bin_ends
+            .partition_point(|furthest_bin_end| furthest_bin_end.as_str() < bin_start);
+        self.ranges
+            .iter()
+            .skip(first)
+            .take_while(move |range| range.bin_start.as_str() <= bin_end)
+            .filter(move |range| range.bin_end.as_str() >= bin_start)
+    }
+}
+
+/// Loads in a single query the existing ranges which may overlap the ranges of the upload, grouped
+/// by BIN length
+async fn find_existing_card_bin_ranges(
+    db: &dyn StorageInterface,
+    ranges: &[(&str, &str)],
+) -> RouterResult<HashMap<usize, ExistingCardBinRanges>> {
+    let first_bin = ranges.iter().map(|(bin_start, _)| *bin_start).min();
+    let last_bin = ranges.iter().map(|(_, bin_end)| *bin_end).max();
+    let (Some(first_bin), Some(last_bin)) = (first_bin, last_bin) else {
+        return Ok(HashMap::new());
+    };
+
+    let mut ranges_by_length: HashMap<usize, Vec<_>> = HashMap::new();
+    for range in db
+        .find_card_bin_ranges_between(first_bin, last_bin)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching overlapping card bin ranges")?
+    {
+        ranges_by_length
+            .entry(range.bin_start.len())
+            .or_default()
+            .push(range);
+    }
+    Ok(ranges_by_length
+        .into_iter()
+        .map(|(bin_length, ranges)| (bin_length, ExistingCardBinRanges::new(ranges)))
+        .collect())
+}
+
+/// Publishes the invalidation of the card details cached for the BINs of the ranges. The ranges
+/// are already written at this point and the cache entries expire on their own, so a failure is
+/// only logged.
+async fn invalidate_card_info_cache(
+    db: &dyn StorageInterface,
+    ranges: impl IntoIterator<Item = (String, String)>,
+) {
+    cache::publish_into_redact_channel(
+        db.get_cache_store().as_ref(),
+        ranges.into_iter().map(|(bin_start, bin_end)| {
+            CacheKind::CardInfo(format!("{bin_start}-{bin_end}").into())
+        }),
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to invalidate card info cache"))
+    .ok();
+}
+
+/// Creates the ranges of the upload that do not exist yet and replaces the details of the
+/// existing ones, leaving the ranges not present in the upload untouched. The upload is
+/// validated as a whole before anything is written.
+#[instrument(skip_all)]
+pub async fn upload_card_bin_ranges(
+    state: SessionState,
+    request: CardBinRangeUploadRequest,
+) -> RouterResponse<CardBinRangeUploadResponse> {
+    let db = state.store.as_ref();
+    let total_count = request.records.len();
+
+    if total_count == 0 {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No records found".to_string(),
+        }
+        .into());
+    }
+
+    if total_count > MAX_CARD_BIN_RANGE_UPLOAD_SIZE {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "An upload can contain at most {MAX_CARD_BIN_RANGE_UPLOAD_SIZE} records"
+            ),
+        }
+        .into());
+    }
+
+    let upload_error = |record: &CardBinRangeRecord, reason| CardBinRangeUploadError {
+        line_number: record.line_number,
+        bin_start: record.bin_start.clone(),
+        reason,
+    };
+    let (validated_ranges, mut upload_errors) = bulk_import::validate_import_records(
+        request.records.iter(),
+        |record| {
+            let (bin_start, bin_end, details) =
+                get_range_details(record, request.provider.clone())?;
+            Ok((
+                (bin_start.clone(), bin_end.clone()),
+                (bin_start, bin_end, details),
+            ))
+        },
+        |_, record, reason| upload_error(*record, reason),
+    );
+
+    let overlapping_ranges = find_overlapping_ranges(
+        &validated_ranges
+            .iter()
+            .map(|(_, (bin_start, bin_end, _))| (bin_start.as_str(), bin_end.as_str()))
+            .collect::<Vec<_>>(),
+    );
+    let mut overlapping_positions = HashSet::new();
+    for (position, reason) in overlapping_ranges {
+        if let Some((record, _)) = validated_ranges.get(position) {
+            upload_errors.push(upload_error(record, reason));
+        }
+        overlapping_positions.insert(position);
+    }
+
+    let existing_ranges = find_existing_card_bin_ranges(
+        db,
+        &validated_ranges
+            .iter()
+            .enumerate()
+            .filter(|(position, _)| !overlapping_positions.contains(position))
+            .map(|(_, (_, (bin_start, bin_end, _)))| (bin_start.as_str(), bin_end.as_str()))
+            .collect::<Vec<_>>(),
+    )
+    .await?;
+
+    let mut planned_ranges = Vec::new();
+    for (position, (record, (bin_start, bin_end, details))) in
+        validated_ranges.into_iter().enumerate()
+    {
+        if overlapping_positions.contains(&position) {
+            continue;
+        }
+
+        // Ranges of the same length must not share any BIN, other than the range being replaced
+        let (mut existing, overlapping): (Vec<_>, Vec<_>) = existing_ranges
+            .get(&bin_start.len())
+            .into_iter()
+            .flat_map(|ranges| ranges.find_overlapping(&bin_start, &bin_end))
+            .cloned()
+            .partition(|range| range.bin_start == bin_start && range.bin_end == bin_end);
+        if let Some(range) = overlapping.first() {
+            upload_errors.push(upload_error(
+                record,
+                format!(
+                    "The range overlaps the existing range {}-{}",
+                    range.bin_start, range.bin_end
+                ),
+            ));
+            continue;
+        }
+
+        planned_ranges.push(PlannedCardBinRange {
+            bin_start,
+            bin_end,
+            details,
+            existing: existing.pop(),
+        });
+    }
+    upload_errors.sort_by_key(|upload_error| upload_error.line_number);
+
+    let plan = bulk_import::ImportPlan::new(planned_ranges);
+    let applied = upload_errors.is_empty();
+
+    if applied && !plan.pending.is_empty() {
+        let written_ranges = apply_card_bin_ranges(db, plan.pending).await?;
+        invalidate_card_info_cache(db, written_ranges).await;
+    }
+
+    Ok(ApplicationResponse::Json(CardBinRangeUploadResponse {
+        file_name: request.file_name,
+        applied,
+        total_count,
+        created_count: plan.created_count,
+        updated_count: plan.updated_count,
+        unchanged_count: plan.unchanged_count,
+        errors: upload_errors,
+    }))
+}
+
+/// Writes the ranges of the upload in a single transaction, so that either the whole upload is
+/// applied or nothing is written. Returns the first and last BIN of the ranges written.
+async fn apply_card_bin_ranges(
+    db: &dyn StorageInterface,
+    pending: Vec<PlannedCardBinRange>,
+) -> RouterResult<Vec<(String, String)>> {
+    let mut written_ranges = Vec::with_capacity(pending.len());
+    let mut new_ranges = Vec::new();
+    let mut updated_ranges = Vec::new();
+    let now = common_utils::date_time::now();
+    for PlannedCardBinRange {
+        bin_start,
+        bin_end,
+        details,
+        existing,
+    } in pending
+    {
+        written_ranges.push((bin_start.clone(), bin_end.clone()));
+        if existing.is_some() {
+            updated_ranges.push((bin_start, bin_end, details));
+            continue;
+        }
+
+        let storage::CardBinRangeUpdate::DetailsUpdate {
+            card_issuer,
+            card_network,
+            card_type,
+            card_subtype,
+            card_issuing_country,
+            last_updated_provider,
+        } = details;
+        new_ranges.push(storage::CardBinRangeNew {
+            bin_length: i32::try_from(bin_start.len())
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            bin_start,
+            bin_end,
+            card_issuer,
+            card_network,
+            card_type,
+            card_subtype,
+            card_issuing_country,
+            last_updated_provider,
+            created_at: now,
+            modified_at: now,
+        });
+    }
+
+    db.import_card_bin_ranges(new_ranges, updated_ranges)
+        .await
+        .map_err(|error| {
+            // The ranges were planned against a snapshot, so a missing or duplicate range means
+            // they were changed by someone else in the meantime
+            if error.current_context().is_db_not_found()
+                || error.current_context().is_db_unique_violation()
+            {
+                error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                    message:
+                        "The card bin ranges were changed while uploading, please retry the upload"
+                            .to_string(),
+                })
+            } else {
+                error.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })
+        .attach_printable("Failed while importing card bin ranges")?;
+
+    Ok(written_ranges)
+}
+
+#[instrument(skip_all)]
+pub async fn list_card_bin_ranges(
+    state: SessionState,
+    request: CardBinRangeListRequest,
+) -> RouterResponse<CardBinRangeListResponse> {
+    let limit = request
+        .limit
+        .unwrap_or(CARD_BIN_RANGE_LIST_DEFAULT_LIMIT)
+        .clamp(1, CARD_BIN_RANGE_LIST_MAX_LIMIT);
+    let data = state
+        .store
+        .list_card_bin_ranges(request.bin_length, limit, request.offset)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing card bin ranges")?
+        .into_iter()
+        .map(CardBinRangeResponse::foreign_from)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(CardBinRangeListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_card_bin_range(
+    state: SessionState,
+    request: CardBinRangeDeleteRequest,
+) -> RouterResponse<CardBinRangeDeleteResponse> {
+    let db = state.store.as_ref();
+    let deleted = db
+        .delete_card_bin_range(&request.bin_start, &request.bin_end)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Card bin range does not exist in our records".to_string(),
+        })
+        .attach_printable("Failed while deleting card bin range")?;
+
+    if !deleted {
+        return Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Card bin range does not exist in our records".to_string(),
+        }
+        .into());
+    }
+
+    invalidate_card_info_cache(db, [(request.bin_start.clone(), request.bin_end.clone())]).await;
+
+    Ok(ApplicationResponse::Json(CardBinRangeDeleteResponse {
+        bin_start: request.bin_start,
+        bin_end: request.bin_end,
+        deleted,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping_ranges_are_rejected() {
+        let overlapping = find_overlapping_ranges(&[
+            ("42424250", "42424299"),
+            ("42424200", "42424260"),
+            ("424242", "424242"),
+            ("42424300", "42424399"),
+            ("42424299", "42424299"),
+        ]);
+
+        assert_eq!(
+            overlapping,
+            vec![
+                (
+                    0,
+                    "The range overlaps the range 42424200-42424260 of the file".to_string()
+                ),
+                (
+                    4,
+                    "The range overlaps the range 42424250-42424299 of the file".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_existing_overlapping_ranges_are_found() {
+        let range = |bin_start: &str, bin_end: &str| {
+            let now = common_utils::date_time::now();
+            storage::CardBinRange {
+                bin_start: bin_start.to_string(),
+                bin_end: bin_end.to_string(),
+                bin_length: 8,
+                card_issuer: None,
+                card_network: None,
+                card_type: None,
+                card_subtype: None,
+                card_issuing_country: None,
+                last_updated_provider: None,
+                created_at: now,
+                modified_at: now,
+            }
+        };
+        // The second range was stored before overlapping ranges were rejected
+        let existing_ranges = ExistingCardBinRanges::new(vec![
+            range("42424200", "42424299"),
+            range("42424210", "42424220"),
+            range("42424300", "42424399"),
+            range("42424500", "42424599"),
+        ]);
+        let find_overlapping = |bin_start, bin_end| {
+            existing_ranges
+                .find_overlapping(bin_start, bin_end)
+                .map(|range| (range.bin_start.as_str(), range.bin_end.as_str()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            find_overlapping("42424250", "42424350"),
+            vec![("42424200", "42424299"), ("42424300", "42424399")]
+        );
+        assert_eq!(
+            find_overlapping("42424215", "42424215"),
+            vec![("42424200", "42424299"), ("42424210", "42424220")]
+        );
+        assert!(find_overlapping("42424400", "42424499").is_empty());
+        assert_eq!(
+            find_overlapping("42424599", "42424699"),
+            vec![("42424500", "42424599")]
+        );
+    }
+
+    #[test]
+    fn test_adjacent_ranges_and_ranges_of_other_lengths_do_not_overlap() {
+        assert!(find_overlapping_ranges(&[
+            ("42424200", "42424249"),
+            ("42424250", "42424299"),
+            ("424242", "424242"),
+        ])
+        .is_empty());
+    }
+}
@@ -0,0 +1,1602 @@
+use std::collections::HashSet;
+
+use api_models::{
+    customers::{
+        DataSubjectRequestCreateRequest, DataSubjectRequestListRequest, DataSubjectRequestResponse,
+        DataSubjectRequestSummary,
+    },
+    disputes::DisputeResponse,
+    payment_methods::PaymentMethodsData,
+    payments::AddressDetails,
+    webhook_events::{
+        EventRetrieveResponse, OutgoingWebhookRequestContent, OutgoingWebhookResponseContent,
+    },
+};
+use common_utils::{
+    crypto::{self, Encryptable, SignMessage},
+    ext_traits::{Encode, StringExt, ValueExt},
+    id_type, type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use serde::Serialize;
+
+use super::REDACTED;
+use crate::{
+    consts,
+    core::{
+        data_retention,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::{self, customers},
+        domain::{self, types},
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const DATA_SUBJECT_REQUEST_TASK: &str = "PROCESS_DATA_SUBJECT_REQUEST";
+const DATA_SUBJECT_REQUEST_TAG: &str = "DATA_SUBJECT_REQUEST";
+
+/// Number of payments of the customer fetched in a single query
+const PAYMENT_LOOKUP_PAGE_SIZE: u32 = 100;
+
+/// Number of customers of the merchant decrypted at a time when looking up a data subject by email
+const CUSTOMER_LOOKUP_PAGE_SIZE: i64 = 100;
+
+impl ForeignFrom<storage::DataSubjectRequest> for DataSubjectRequestResponse {
+    fn foreign_from(request: storage::DataSubjectRequest) -> Self {
+        Self {
+            request_id: request.request_id,
+            merchant_id: request.merchant_id,
+            customer_id: request.customer_id,
+            request_type: request.request_type,
+            status: request.status,
+            reason: request.reason,
+            summary: request
+                .summary
+                .and_then(|summary| serde_json::from_value(summary).ok()),
+            archive_available: request.file_key.is_some(),
+            error_message: request.error_message,
+            created_at: request.created_at,
+            modified_at: request.modified_at,
+            completed_at: request.completed_at,
+        }
+    }
+}
+
+/// A saved payment method as handed over in the export archive. Card details are limited to
+/// the masked details stored alongside the payment method, the locker is never queried.
+#[derive(Debug, Serialize)]
+struct ExportedPaymentMethod {
+    payment_method_id: String,
+    payment_method: Option<enums::PaymentMethod>,
+    payment_method_type: Option<enums::PaymentMethodType>,
+    card: Option<api::CardDetailFromLocker>,
+    status: enums::PaymentMethodStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    last_used_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+struct ExportedMandate {
+    mandate_id: String,
+    mandate_status: enums::MandateStatus,
+    mandate_type: enums::MandateType,
+    payment_method_id: String,
+    mandate_amount: Option<i64>,
+    mandate_currency: Option<enums::Currency>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    customer_accepted_at: Option<time::PrimitiveDateTime>,
+    customer_ip_address: Option<Secret<String, common_utils::pii::IpAddress>>,
+    customer_user_agent: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: time::PrimitiveDateTime,
+}
+
+impl From<storage::Mandate> for ExportedMandate {
+    fn from(mandate: storage::Mandate) -> Self {
+        Self {
+            mandate_id: mandate.mandate_id,
+            mandate_status: mandate.mandate_status,
+            mandate_type: mandate.mandate_type,
+            payment_method_id: mandate.payment_method_id,
+            mandate_amount: mandate.mandate_amount,
+            mandate_currency: mandate.mandate_currency,
+            customer_accepted_at: mandate.customer_accepted_at,
+            customer_ip_address: mandate.customer_ip_address,
+            customer_user_agent: mandate.customer_user_agent,
+            created_at: mandate.created_at,
+        }
+    }
+}
+
+/// The downloadable archive of an export request
+#[derive(Debug, Serialize)]
+struct DataSubjectExportArchive {
+    request_id: String,
+    merchant_id: id_type::MerchantId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    generated_at: time::PrimitiveDateTime,
+    customer: customers::CustomerResponse,
+    addresses: Vec<AddressDetails>,
+    payment_methods: Vec<ExportedPaymentMethod>,
+    mandates: Vec<ExportedMandate>,
+    payments: Vec<api::PaymentsResponse>,
+    refunds: Vec<api::RefundResponse>,
+    disputes: Vec<DisputeResponse>,
+    events: Vec<EventRetrieveResponse>,
+}
+
+/// Records held about a customer, across all the tables which reference the customer either
+/// directly or through one of their payments
+struct DataSubjectRecords {
+    /// `None` when the customer has been redacted already by an earlier attempt at erasure
+    customer: Option<domain::Customer>,
+    payment_methods: Vec<storage::PaymentMethod>,
+    mandates: Vec<storage::Mandate>,
+    payment_intents: Vec<storage::PaymentIntent>,
+    refunds: Vec<storage::Refund>,
+    disputes: Vec<storage::Dispute>,
+    events: Vec<domain::Event>,
+}
+
+/// Hashes the email address with the key of the merchant, so that a request made by email can be
+/// matched with the customer when it is processed without the address itself being stored
+fn hash_email(key: &[u8], email: &str) -> RouterResult<String> {
+    crypto::HmacSha256
+        .sign_message(key, email.to_lowercase().as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash email of the data subject")
+}
+
+/// Finds the customer a request made by email is about. Emails are stored encrypted, so the
+/// customers of the merchant have to be decrypted and compared one by one, which is why this is
+/// done by the workflow rather than when the request is created. Customers are decrypted a page
+/// at a time, so that merchants with many customers are not loaded in memory at once.
+#[instrument(skip_all)]
+async fn find_data_subject_by_email_hash(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    email_hash: &str,
+) -> RouterResult<id_type::CustomerId> {
+    let key = key_store.key.get_inner().peek();
+    let mut matching_customer_ids = Vec::new();
+    let mut offset = 0;
+    loop {
+        let customers = state
+            .store
+            .list_customers_by_merchant_id_with_pagination(
+                &state.into(),
+                merchant_account.get_id(),
+                key_store,
+                CUSTOMER_LOOKUP_PAGE_SIZE,
+                offset,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while listing customers")?;
+        let is_last_page = customers.len()
+            < usize::try_from(CUSTOMER_LOOKUP_PAGE_SIZE)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        offset += CUSTOMER_LOOKUP_PAGE_SIZE;
+
+        for customer in customers {
+            if let Some(customer_email) = customer.email.as_ref() {
+                if hash_email(key, customer_email.get_inner().peek())? == email_hash {
+                    matching_customer_ids.push(customer.customer_id);
+                }
+            }
+        }
+        if is_last_page {
+            break;
+        }
+    }
+
+    match matching_customer_ids.len() {
+        0 => Err(errors::ApiErrorResponse::CustomerNotFound.into()),
+        1 => Ok(matching_customer_ids.remove(0)),
+        _ => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "More than one customer has the given email, specify the customer_id instead"
+                .to_string(),
+        }
+        .into()),
+    }
+}
+
+fn has_active_mandates(mandates: &[storage::Mandate]) -> bool {
+    mandates
+        .iter()
+        .any(|mandate| mandate.mandate_status == enums::MandateStatus::Active)
+}
+
+#[instrument(skip_all)]
+pub async fn create_data_subject_request(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: DataSubjectRequestCreateRequest,
+) -> RouterResponse<DataSubjectRequestResponse> {
+    let db = state.store.as_ref();
+    let (customer_id, email_hash) = match (req.customer_id, req.email) {
+        (Some(customer_id), _) => {
+            match db
+                .find_customer_by_customer_id_merchant_id(
+                    &(&state).into(),
+                    &customer_id,
+                    merchant_account.get_id(),
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(_) => {}
+                // An erasure which failed after the customer was redacted can be requested again
+                // to redact the remaining records
+                Err(error)
+                    if req.request_type == enums::DataSubjectRequestType::Erasure
+                        && matches!(
+                            error.current_context(),
+                            errors::StorageError::CustomerRedacted
+                        ) => {}
+                Err(error) => {
+                    Err(error).to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?
+                }
+            }
+            (Some(customer_id), None)
+        }
+        (None, Some(email)) => (
+            None,
+            Some(hash_email(key_store.key.get_inner().peek(), email.peek())?),
+        ),
+        (None, None) => Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "customer_id or email",
+        })?,
+    };
+
+    if let (enums::DataSubjectRequestType::Erasure, Some(customer_id)) =
+        (req.request_type, customer_id.as_ref())
+    {
+        let mandates = db
+            .find_mandate_by_merchant_id_customer_id(merchant_account.get_id(), customer_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching mandates of the customer")?;
+        if has_active_mandates(&mandates) {
+            return Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The customer has active mandates, revoke them before requesting erasure"
+                    .to_string(),
+            }
+            .into());
+        }
+    }
+
+    let now = common_utils::date_time::now();
+    let data_subject_request = db
+        .insert_data_subject_request(storage::DataSubjectRequestNew {
+            request_id: common_utils::generate_id_with_default_len("dsr"),
+            merchant_id: merchant_account.get_id().to_owned(),
+            customer_id,
+            request_type: req.request_type,
+            status: enums::DataSubjectRequestStatus::Pending,
+            reason: req.reason,
+            file_key: None,
+            summary: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+            completed_at: None,
+            email_hash,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting data subject request")?;
+
+    add_data_subject_request_process_task(db, &data_subject_request).await?;
+
+    Ok(ApplicationResponse::Json(
+        DataSubjectRequestResponse::foreign_from(data_subject_request),
+    ))
+}
+
+#[instrument(skip_all)]
+async fn add_data_subject_request_process_task(
+    db: &dyn StorageInterface,
+    data_subject_request: &storage::DataSubjectRequest,
+) -> RouterResult<storage::ProcessTracker> {
+    let runner = storage::ProcessTrackerRunner::DataSubjectRequestWorkflow;
+    let task = DATA_SUBJECT_REQUEST_TASK;
+    let process_tracker_id = format!("{runner}_{task}_{}", data_subject_request.request_id);
+    let tracking_data = storage::DataSubjectRequestTrackingData {
+        request_id: data_subject_request.request_id.clone(),
+        merchant_id: data_subject_request.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        [DATA_SUBJECT_REQUEST_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct data subject request process tracker task")?;
+
+    let response = db
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: request_id: {}",
+                data_subject_request.request_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "DataSubjectRequest")]),
+    );
+
+    Ok(response)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_data_subject_request(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request_id: String,
+) -> RouterResponse<DataSubjectRequestResponse> {
+    let data_subject_request = state
+        .store
+        .find_data_subject_request_by_merchant_id_request_id(merchant_account.get_id(), &request_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Data subject request not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        DataSubjectRequestResponse::foreign_from(data_subject_request),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_data_subject_requests(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: DataSubjectRequestListRequest,
+) -> RouterResponse<Vec<DataSubjectRequestResponse>> {
+    let data_subject_requests = state
+        .store
+        .list_data_subject_requests_by_merchant_id(
+            merchant_account.get_id(),
+            req.customer_id.as_ref(),
+            Some(
+                req.limit
+                    .unwrap_or(consts::DEFAULT_DATA_SUBJECT_REQUEST_LIST_LIMIT),
+            ),
+            req.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing data subject requests")?;
+
+    Ok(ApplicationResponse::Json(
+        data_subject_requests
+            .into_iter()
+            .map(DataSubjectRequestResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn download_data_subject_archive(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let data_subject_request = state
+        .store
+        .find_data_subject_request_by_merchant_id_request_id(merchant_account.get_id(), &request_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Data subject request not found".to_string(),
+        })?;
+
+    let file_key =
+        data_subject_request
+            .file_key
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message: "No archive is available for this data subject request".to_string(),
+            })?;
+    let file = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve data subject archive")?;
+
+    Ok(ApplicationResponse::FileData((
+        file,
+        mime::APPLICATION_JSON,
+    )))
+}
+
+/// Gathers every record held about the customer. Webhook events are limited to the initial
+/// delivery attempt of each event, the retries carry the same content.
+#[instrument(skip_all)]
+async fn gather_data_subject_records(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<DataSubjectRecords> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+
+    let customer = match db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(customer) => Some(customer),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::StorageError::CustomerRedacted
+            ) =>
+        {
+            None
+        }
+        Err(error) => {
+            Err(error).to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?
+        }
+    };
+
+    let payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(customer_id, merchant_id, None)
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching payment methods of the customer")?,
+    };
+
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching mandates of the customer")?;
+
+    let mut payment_intents = Vec::new();
+    loop {
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset: u32::try_from(payment_intents.len())
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            starting_at: None,
+            ending_at: None,
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: None,
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: None,
+            customer_id: Some(customer_id.clone()),
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(PAYMENT_LOOKUP_PAGE_SIZE),
+            order: Default::default(),
+        }));
+        let page = db
+            .filter_payment_intent_by_constraints(
+                key_manager_state,
+                merchant_id,
+                &constraints,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching payments of the customer")?;
+        let is_last_page = page.len() < PAYMENT_LOOKUP_PAGE_SIZE as usize;
+        payment_intents.extend(page);
+        if is_last_page {
+            break;
+        }
+    }
+
+    let mut refunds = Vec::new();
+    let mut disputes = Vec::new();
+    for payment_intent in &payment_intents {
+        refunds.extend(
+            db.find_refund_by_payment_id_merchant_id(
+                &payment_intent.payment_id,
+                merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching refunds of the customer")?,
+        );
+        disputes.extend(
+            db.find_disputes_by_merchant_id_payment_id(merchant_id, &payment_intent.payment_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching disputes of the customer")?,
+        );
+    }
+
+    let primary_object_ids = payment_intents
+        .iter()
+        .map(|payment_intent| payment_intent.payment_id.as_str())
+        .chain(refunds.iter().map(|refund| refund.refund_id.as_str()))
+        .chain(disputes.iter().map(|dispute| dispute.dispute_id.as_str()))
+        .chain(mandates.iter().map(|mandate| mandate.mandate_id.as_str()));
+    let mut events = Vec::new();
+    for primary_object_id in primary_object_ids {
+        events.extend(
+            db.list_initial_events_by_merchant_id_primary_object_id(
+                key_manager_state,
+                merchant_id,
+                primary_object_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching webhook events of the customer")?,
+        );
+    }
+
+    Ok(DataSubjectRecords {
+        customer,
+        payment_methods,
+        mandates,
+        payment_intents,
+        refunds,
+        disputes,
+        events,
+    })
+}
+
+/// Builds the export archive of the customer and stores it with the file storage client.
+/// Returns the key of the stored archive.
+#[instrument(skip_all)]
+async fn export_data_subject_records(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    data_subject_request: &storage::DataSubjectRequest,
+    records: DataSubjectRecords,
+) -> RouterResult<String> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+    let customer = records
+        .customer
+        .ok_or(errors::ApiErrorResponse::CustomerRedacted)?;
+
+    let customer_address = match customer.address_id.as_ref() {
+        Some(address_id) => Some(AddressDetails::from(
+            db.find_address_by_address_id(key_manager_state, address_id, key_store)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching address of the customer")?,
+        )),
+        None => None,
+    };
+
+    let mut addresses = customer_address.iter().cloned().collect::<Vec<_>>();
+    let mut payments = Vec::with_capacity(records.payment_intents.len());
+    for payment_intent in records.payment_intents {
+        let address_ids = [
+            payment_intent.billing_address_id.as_ref(),
+            payment_intent.shipping_address_id.as_ref(),
+        ];
+        for address_id in address_ids.into_iter().flatten() {
+            match db
+                .find_address_by_merchant_id_payment_id_address_id(
+                    key_manager_state,
+                    merchant_id,
+                    &payment_intent.payment_id,
+                    address_id,
+                    key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(address) => addresses.push(AddressDetails::from(address.address)),
+                Err(error) if error.current_context().is_db_not_found() => {}
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while fetching payment address")?,
+            }
+        }
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &payment_intent.payment_id,
+                merchant_id,
+                &payment_intent.active_attempt.get_id(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching payment attempt")?;
+        payments.push(api::PaymentsResponse::foreign_from((
+            payment_intent,
+            payment_attempt,
+        )));
+    }
+
+    let mut payment_methods = Vec::with_capacity(records.payment_methods.len());
+    for payment_method in records.payment_methods {
+        let card = cards::decrypt_generic_data::<PaymentMethodsData>(
+            state,
+            payment_method.payment_method_data.clone(),
+            key_store,
+        )
+        .await?
+        .and_then(|payment_method_data| match payment_method_data {
+            PaymentMethodsData::Card(card) => Some(api::CardDetailFromLocker {
+                card_number: None,
+                card_token: None,
+                ..api::CardDetailFromLocker::from(card)
+            }),
+            _ => None,
+        });
+        payment_methods.push(ExportedPaymentMethod {
+            payment_method_id: payment_method.payment_method_id,
+            payment_method: payment_method.payment_method,
+            payment_method_type: payment_method.payment_method_type,
+            card,
+            status: payment_method.status,
+            created_at: payment_method.created_at,
+            last_used_at: payment_method.last_used_at,
+        });
+    }
+
+    let events = records
+        .events
+        .into_iter()
+        .filter_map(|event| {
+            let event_id = event.event_id.clone();
+            EventRetrieveResponse::try_from(event)
+                .map_err(|error| {
+                    logger::warn!(?error, %event_id, "Skipping webhook event in data subject export")
+                })
+                .ok()
+        })
+        .collect();
+
+    let archive = DataSubjectExportArchive {
+        request_id: data_subject_request.request_id.clone(),
+        merchant_id: merchant_id.to_owned(),
+        generated_at: common_utils::date_time::now(),
+        customer: customers::CustomerResponse::foreign_from((customer, customer_address)),
+        addresses,
+        payment_methods,
+        mandates: records
+            .mandates
+            .into_iter()
+            .map(ExportedMandate::from)
+            .collect(),
+        payments,
+        refunds: records
+            .refunds
+            .into_iter()
+            .map(api::RefundResponse::foreign_from)
+            .collect(),
+        disputes: records
+            .disputes
+            .into_iter()
+            .map(DisputeResponse::foreign_from)
+            .collect(),
+        events,
+    };
+    let file = serde_json::to_vec_pretty(&archive)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize data subject archive")?;
+
+    let file_key = format!(
+        "{}/{}",
+        merchant_id.get_string_repr(),
+        data_subject_request.request_id
+    );
+    state
+        .file_storage_client
+        .upload_file(&file_key, file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store data subject archive")?;
+
+    Ok(file_key)
+}
+
+async fn encrypt_redacted_value(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    value: String,
+) -> RouterResult<Encryptable<Secret<String>>> {
+    types::crypto_operation(
+        key_manager_state,
+        type_name!(domain::Event),
+        types::CryptoOperation::Encrypt(Secret::new(value)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|value| value.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt redacted webhook content")
+}
+
+/// Replaces the bodies of the webhook request and response of an event, the headers and status
+/// are retained so that the delivery history stays intact
+pub(crate) async fn redact_event_content(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    event: domain::Event,
+) -> RouterResult<domain::Event> {
+    let request = match event.request {
+        Some(request) => {
+            let mut content: OutgoingWebhookRequestContent = request
+                .get_inner()
+                .peek()
+                .parse_struct("OutgoingWebhookRequestContent")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            content.body = Secret::new(REDACTED.to_string());
+            let content = content
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Some(encrypt_redacted_value(key_manager_state, key_store, content).await?)
+        }
+        None => None,
+    };
+    let response = match event.response {
+        Some(response) => {
+            let mut content: OutgoingWebhookResponseContent = response
+                .get_inner()
+                .peek()
+                .parse_struct("OutgoingWebhookResponseContent")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            content.body = content.body.map(|_| Secret::new(REDACTED.to_string()));
+            let content = content
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Some(encrypt_redacted_value(key_manager_state, key_store, content).await?)
+        }
+        None => None,
+    };
+
+    Ok(domain::Event {
+        request,
+        response,
+        ..event
+    })
+}
+
+#[instrument(skip_all)]
+async fn redact_event(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    event: domain::Event,
+) -> RouterResult<()> {
+    let key_manager_state = &state.into();
+    let event = redact_event_content(key_manager_state, key_store, event).await?;
+
+    state
+        .store
+        .update_event_by_merchant_id_event_id(
+            key_manager_state,
+            merchant_id,
+            &event.event_id,
+            domain::EventUpdate::ContentRedaction {
+                request: event.request,
+                response: event.response,
+            },
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while redacting webhook event")?;
+
+    Ok(())
+}
+
+/// Redacts the data held about the customer. The customer details stored on their payments, the
+/// acceptance details of their mandates, the webhook content about their payments, the same data
+/// held in the data retention archives and any archives of earlier export requests are removed
+/// first. The customer record, their addresses and saved payment methods, including the cards in
+/// the locker, are then handled the same way as when a customer is deleted. The customer is
+/// redacted last so that an erasure which fails part way can be requested again, the redactions
+/// done already are simply repeated. Returns the identifiers of the payments, refunds, disputes
+/// and mandates erased, so that they are redacted again when an archive holding them is restored.
+#[instrument(skip_all)]
+async fn erase_data_subject_records(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+    records: DataSubjectRecords,
+) -> RouterResult<Vec<String>> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+
+    if has_active_mandates(&records.mandates) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The customer has active mandates, revoke them before requesting erasure"
+                .to_string(),
+        }
+        .into());
+    }
+
+    let object_ids = records
+        .payment_intents
+        .iter()
+        .map(|payment_intent| payment_intent.payment_id.clone())
+        .chain(
+            records
+                .refunds
+                .iter()
+                .map(|refund| refund.refund_id.clone()),
+        )
+        .chain(
+            records
+                .disputes
+                .iter()
+                .map(|dispute| dispute.dispute_id.clone()),
+        )
+        .chain(
+            records
+                .mandates
+                .iter()
+                .map(|mandate| mandate.mandate_id.clone()),
+        )
+        .collect::<HashSet<_>>();
+
+    let redacted_details = cards::create_encrypted_data(state, key_store, serde_json::json!({}))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt redacted customer details")?;
+    for payment_intent in records.payment_intents {
+        let payment_intent_update = storage::PaymentIntentUpdate::CustomerDetailsRedaction {
+            customer_details: payment_intent
+                .customer_details
+                .as_ref()
+                .map(|_| redacted_details.clone()),
+            billing_details: payment_intent
+                .billing_details
+                .as_ref()
+                .map(|_| redacted_details.clone()),
+            shipping_details: payment_intent
+                .shipping_details
+                .as_ref()
+                .map(|_| redacted_details.clone()),
+            updated_by: merchant_account.storage_scheme.to_string(),
+        };
+        db.update_payment_intent(
+            key_manager_state,
+            payment_intent,
+            payment_intent_update,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while redacting customer details of payment")?;
+    }
+
+    for mandate in records.mandates {
+        db.update_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &mandate.mandate_id.clone(),
+            storage::MandateUpdate::CustomerAcceptanceRedaction,
+            mandate,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while redacting mandate acceptance details")?;
+    }
+
+    for initial_event in records.events {
+        let initial_attempt_id = initial_event
+            .initial_attempt_id
+            .clone()
+            .unwrap_or_else(|| initial_event.event_id.clone());
+        let delivery_attempts = db
+            .list_events_by_merchant_id_initial_attempt_id(
+                key_manager_state,
+                merchant_id,
+                &initial_attempt_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching webhook delivery attempts")?;
+        for event in delivery_attempts {
+            redact_event(state, key_store, merchant_id, event).await?;
+        }
+    }
+
+    let mut erased_object_ids = Vec::from_iter(
+        data_retention::erase_data_subject_from_archives(state, key_store, customer_id, object_ids)
+            .await?,
+    );
+    erased_object_ids.sort();
+
+    let earlier_requests = db
+        .list_data_subject_requests_by_merchant_id(merchant_id, Some(customer_id), None, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing data subject requests of the customer")?;
+    for earlier_request in earlier_requests {
+        if let Some(file_key) = earlier_request.file_key {
+            state
+                .file_storage_client
+                .delete_file(&file_key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to delete data subject archive")?;
+            db.update_data_subject_request_by_merchant_id_request_id(
+                merchant_id,
+                &earlier_request.request_id,
+                storage::DataSubjectRequestUpdate::ArchiveRemoved,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while updating data subject request")?;
+        }
+    }
+
+    if records.customer.is_some() {
+        Box::pin(super::delete_customer(
+            state.clone(),
+            merchant_account.clone(),
+            customers::CustomerId {
+                customer_id: customer_id.clone(),
+            },
+            key_store.clone(),
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while redacting the customer")?;
+    }
+
+    Ok(erased_object_ids)
+}
+
+/// Exports or erases the data of the customer and returns the update to be recorded on the
+/// request. The customer of a request made by email is identified first.
+async fn process_data_subject_request(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    data_subject_request: &storage::DataSubjectRequest,
+) -> RouterResult<storage::DataSubjectRequestUpdate> {
+    let customer_id = match data_subject_request.customer_id.clone() {
+        Some(customer_id) => customer_id,
+        None => {
+            let email_hash = data_subject_request
+                .email_hash
+                .as_deref()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Data subject request has neither a customer_id nor an email")?;
+            let customer_id =
+                find_data_subject_by_email_hash(state, merchant_account, key_store, email_hash)
+                    .await?;
+            state
+                .store
+                .update_data_subject_request_by_merchant_id_request_id(
+                    merchant_account.get_id(),
+                    &data_subject_request.request_id,
+                    storage::DataSubjectRequestUpdate::CustomerResolved {
+                        customer_id: customer_id.clone(),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while updating data subject request")?;
+            customer_id
+        }
+    };
+
+    let records =
+        gather_data_subject_records(state, merchant_account, key_store, &customer_id).await?;
+
+    let addresses = usize::from(
+        records
+            .customer
+            .as_ref()
+            .is_some_and(|customer| customer.address_id.is_some()),
+    ) + records
+        .payment_intents
+        .iter()
+        .map(|payment_intent| {
+            usize::from(payment_intent.billing_address_id.is_some())
+                + usize::from(payment_intent.shipping_address_id.is_some())
+        })
+        .sum::<usize>();
+    let summary = DataSubjectRequestSummary {
+        addresses,
+        payment_methods: records.payment_methods.len(),
+        mandates: records.mandates.len(),
+        payments: records.payment_intents.len(),
+        refunds: records.refunds.len(),
+        disputes: records.disputes.len(),
+        events: records.events.len(),
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let (file_key, erased_object_ids) = match data_subject_request.request_type {
+        enums::DataSubjectRequestType::Export => (
+            Some(
+                export_data_subject_records(
+                    state,
+                    merchant_account,
+                    key_store,
+                    data_subject_request,
+                    records,
+                )
+                .await?,
+            ),
+            None,
+        ),
+        enums::DataSubjectRequestType::Erasure => (
+            None,
+            Some(
+                Box::pin(erase_data_subject_records(
+                    state,
+                    merchant_account,
+                    key_store,
+                    &customer_id,
+                    records,
+                ))
+                .await?,
+            ),
+        ),
+    };
+
+    Ok(storage::DataSubjectRequestUpdate::Completed {
+        file_key,
+        summary,
+        erased_object_ids,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn start_data_subject_request_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::DataSubjectRequestTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("DataSubjectRequestTrackingData")?;
+
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+
+    let data_subject_request = db
+        .find_data_subject_request_by_merchant_id_request_id(
+            &tracking_data.merchant_id,
+            &tracking_data.request_id,
+        )
+        .await?;
+
+    if matches!(
+        data_subject_request.status,
+        enums::DataSubjectRequestStatus::Pending | enums::DataSubjectRequestStatus::Processing
+    ) {
+        let data_subject_request = db
+            .update_data_subject_request_by_merchant_id_request_id(
+                &data_subject_request.merchant_id,
+                &data_subject_request.request_id,
+                storage::DataSubjectRequestUpdate::StatusUpdate {
+                    status: enums::DataSubjectRequestStatus::Processing,
+                },
+            )
+            .await?;
+
+        // Failures are recorded on the request rather than retried, so that the merchant can
+        // see what went wrong and raise a new request
+        let data_subject_request_update = match Box::pin(process_data_subject_request(
+            state,
+            &merchant_account,
+            &key_store,
+            &data_subject_request,
+        ))
+        .await
+        {
+            Ok(update) => update,
+            Err(error) => {
+                logger::error!(
+                    request_id = %data_subject_request.request_id,
+                    ?error,
+                    "Failed to process data subject request"
+                );
+                storage::DataSubjectRequestUpdate::Failed {
+                    error_message: error.current_context().to_string(),
+                }
+            }
+        };
+
+        db.update_data_subject_request_by_merchant_id_request_id(
+            &data_subject_request.merchant_id,
+            &data_subject_request.request_id,
+            data_subject_request_update,
+        )
+        .await?;
+    }
+
+    db.as_scheduler()
+        .finish_process_with_business_status(process.clone(), "COMPLETED_BY_PT")
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::{borrow::Cow, sync::Arc};
+
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{
+        routes::{
+            self,
+            app::{settings::Settings, StorageImpl},
+        },
+        services,
+    };
+
+    async fn get_session_state() -> SessionState {
+        let conf = Settings::new().unwrap();
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state("public", || {})
+            .unwrap()
+    }
+
+    /// Stores a merchant account along with its key store
+    async fn create_merchant(
+        state: &SessionState,
+        merchant_id: &str,
+    ) -> (domain::MerchantAccount, domain::MerchantKeyStore) {
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let master_key = db.get_master_key();
+        let merchant_id =
+            id_type::MerchantId::try_from(Cow::from(merchant_id.to_string())).unwrap();
+        let key_store = db
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    merchant_id: merchant_id.clone(),
+                    key: types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        types::CryptoOperation::Encrypt(
+                            services::generate_aes256_key().unwrap().to_vec().into(),
+                        ),
+                        Identifier::Merchant(merchant_id.clone()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    created_at: common_utils::date_time::now(),
+                    key_version: 1,
+                    previous_key: None,
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        let merchant_account = db
+            .insert_merchant(
+                key_manager_state,
+                domain::MerchantAccount::from(domain::MerchantAccountSetter {
+                    merchant_id,
+                    return_url: None,
+                    enable_payment_response_hash: false,
+                    payment_response_hash_key: None,
+                    redirect_to_merchant_with_http_post: false,
+                    merchant_name: None,
+                    merchant_details: None,
+                    webhook_details: None,
+                    sub_merchants_enabled: None,
+                    parent_merchant_id: None,
+                    publishable_key: format!("pk_{}", key_store.merchant_id.get_string_repr()),
+                    storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
+                    locker_id: None,
+                    metadata: None,
+                    routing_algorithm: None,
+                    primary_business_details: serde_json::json!([]),
+                    frm_routing_algorithm: None,
+                    created_at: common_utils::date_time::now(),
+                    modified_at: common_utils::date_time::now(),
+                    intent_fulfillment_time: None,
+                    payout_routing_algorithm: None,
+                    organization_id: id_type::OrganizationId::default(),
+                    is_recon_enabled: false,
+                    default_profile: None,
+                    recon_status: enums::ReconStatus::NotRequested,
+                    payment_link_config: None,
+                    pm_collect_link_config: None,
+                    version: hyperswitch_domain_models::consts::API_VERSION,
+                }),
+                &key_store,
+            )
+            .await
+            .unwrap();
+
+        (merchant_account, key_store)
+    }
+
+    async fn create_customer(
+        state: &SessionState,
+        key_store: &domain::MerchantKeyStore,
+        customer_id: &str,
+        email: Option<&str>,
+    ) -> domain::Customer {
+        let key_manager_state = &state.into();
+        let encrypt = |value: &str| {
+            types::crypto_operation(
+                key_manager_state,
+                type_name!(domain::Customer),
+                types::CryptoOperation::Encrypt(Secret::new(value.to_string())),
+                Identifier::Merchant(key_store.merchant_id.clone()),
+                key_store.key.get_inner().peek(),
+            )
+        };
+        let email = match email {
+            Some(email) => Some(
+                encrypt(email)
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+            ),
+            None => None,
+        };
+        state
+            .store
+            .insert_customer(
+                domain::Customer {
+                    customer_id: id_type::CustomerId::try_from(Cow::from(customer_id.to_string()))
+                        .unwrap(),
+                    merchant_id: key_store.merchant_id.clone(),
+                    name: Some(
+                        encrypt("John Test")
+                            .await
+                            .and_then(|val| val.try_into_operation())
+                            .unwrap(),
+                    ),
+                    email,
+                    phone: None,
+                    phone_country_code: None,
+                    description: None,
+                    created_at: common_utils::date_time::now(),
+                    metadata: None,
+                    modified_at: common_utils::date_time::now(),
+                    connector_customer: None,
+                    address_id: None,
+                    default_payment_method_id: None,
+                    updated_by: None,
+                    version: hyperswitch_domain_models::consts::API_VERSION,
+                },
+                key_manager_state,
+                key_store,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap()
+    }
+
+    /// Creates the request through the API and runs its workflow task once
+    async fn create_and_process_request(
+        state: &SessionState,
+        merchant_account: &domain::MerchantAccount,
+        key_store: &domain::MerchantKeyStore,
+        req: DataSubjectRequestCreateRequest,
+    ) -> storage::DataSubjectRequest {
+        let db = &*state.store;
+        create_data_subject_request(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            req,
+        )
+        .await
+        .unwrap();
+        let data_subject_request = db
+            .list_data_subject_requests_by_merchant_id(merchant_account.get_id(), None, None, None)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        let process_tracker_id = format!(
+            "{}_{DATA_SUBJECT_REQUEST_TASK}_{}",
+            storage::ProcessTrackerRunner::DataSubjectRequestWorkflow,
+            data_subject_request.request_id
+        );
+        let process = db
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .unwrap()
+            .unwrap();
+        start_data_subject_request_workflow(state, &process)
+            .await
+            .unwrap();
+
+        let process = db
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            process.business_status,
+            storage::business_status::COMPLETED_BY_PT
+        );
+        db.find_data_subject_request_by_merchant_id_request_id(
+            merchant_account.get_id(),
+            &data_subject_request.request_id,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn test_hash_email_ignores_case() {
+        let key = [7u8; 32];
+        assert_eq!(
+            hash_email(&key, "JonTest@test.com").unwrap(),
+            hash_email(&key, "jontest@TEST.com").unwrap()
+        );
+        assert_ne!(
+            hash_email(&key, "jontest@test.com").unwrap(),
+            hash_email(&key, "jontest2@test.com").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hash_email_is_keyed_per_merchant() {
+        let email_hash = hash_email(&[7u8; 32], "jontest@test.com").unwrap();
+        assert_eq!(email_hash.len(), 64);
+        assert_ne!(
+            email_hash,
+            hash_email(&[8u8; 32], "jontest@test.com").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_data_subject_by_email_hash_pages_through_the_customers() {
+        let state = get_session_state().await;
+        let (merchant_account, key_store) = create_merchant(&state, "merchant_dsr_1").await;
+        for index in 0..CUSTOMER_LOOKUP_PAGE_SIZE {
+            create_customer(&state, &key_store, &format!("cus_{index}"), None).await;
+        }
+        // Only found on the second page
+        create_customer(&state, &key_store, "cus_last", Some("JonTest@test.com")).await;
+
+        let key = key_store.key.get_inner().peek();
+        let customer_id = find_data_subject_by_email_hash(
+            &state,
+            &merchant_account,
+            &key_store,
+            &hash_email(key, "jontest@test.com").unwrap(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(customer_id.get_string_repr(), "cus_last");
+
+        let error = find_data_subject_by_email_hash(
+            &state,
+            &merchant_account,
+            &key_store,
+            &hash_email(key, "jontest2@test.com").unwrap(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::CustomerNotFound
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_export_request_stores_the_archive_of_the_customer() {
+        let state = get_session_state().await;
+        let (merchant_account, key_store) = create_merchant(&state, "merchant_dsr_2").await;
+        create_customer(&state, &key_store, "cus_1", Some("jontest@test.com")).await;
+
+        let data_subject_request = create_and_process_request(
+            &state,
+            &merchant_account,
+            &key_store,
+            DataSubjectRequestCreateRequest {
+                customer_id: None,
+                email: Some("JonTest@test.com".parse().unwrap()),
+                request_type: enums::DataSubjectRequestType::Export,
+                reason: None,
+            },
+        )
+        .await;
+
+        assert_eq!(
+            data_subject_request.status,
+            enums::DataSubjectRequestStatus::Completed
+        );
+        assert_eq!(
+            data_subject_request
+                .customer_id
+                .as_ref()
+                .map(id_type::CustomerId::get_string_repr),
+            Some("cus_1")
+        );
+        let file_key = data_subject_request.file_key.unwrap();
+        let archive: serde_json::Value = serde_json::from_slice(
+            &state
+                .file_storage_client
+                .retrieve_file(&file_key)
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        state
+            .file_storage_client
+            .delete_file(&file_key)
+            .await
+            .unwrap();
+        assert_eq!(archive["request_id"], data_subject_request.request_id);
+        assert_eq!(archive["customer"]["customer_id"], "cus_1");
+    }
+
+    #[tokio::test]
+    async fn test_erasure_request_redacts_the_customer() {
+        let state = get_session_state().await;
+        let (merchant_account, key_store) = create_merchant(&state, "merchant_dsr_3").await;
+        let customer = create_customer(&state, &key_store, "cus_1", Some("jontest@test.com")).await;
+
+        let data_subject_request = create_and_process_request(
+            &state,
+            &merchant_account,
+            &key_store,
+            DataSubjectRequestCreateRequest {
+                customer_id: Some(customer.customer_id.clone()),
+                email: None,
+                request_type: enums::DataSubjectRequestType::Erasure,
+                reason: Some("Ticket #4521".to_string()),
+            },
+        )
+        .await;
+
+        assert_eq!(
+            data_subject_request.status,
+            enums::DataSubjectRequestStatus::Completed
+        );
+        assert!(data_subject_request.file_key.is_none());
+        assert!(data_subject_request.completed_at.is_some());
+        let customer = state
+            .store
+            .find_customer_by_customer_id_merchant_id(
+                &(&state).into(),
+                &customer.customer_id,
+                merchant_account.get_id(),
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(customer.name.unwrap().into_inner().peek(), REDACTED);
+        assert_eq!(customer.email.unwrap().into_inner().peek(), REDACTED);
+    }
+
+    #[tokio::test]
+    async fn test_failed_request_is_recorded_and_not_processed_again() {
+        let state = get_session_state().await;
+        let db = &*state.store;
+        let (merchant_account, key_store) = create_merchant(&state, "merchant_dsr_4").await;
+        create_customer(&state, &key_store, "cus_1", Some("jontest@test.com")).await;
+
+        let data_subject_request = create_and_process_request(
+            &state,
+            &merchant_account,
+            &key_store,
+            DataSubjectRequestCreateRequest {
+                customer_id: None,
+                email: Some("jontest2@test.com".parse().unwrap()),
+                request_type: enums::DataSubjectRequestType::Export,
+                reason: None,
+            },
+        )
+        .await;
+
+        assert_eq!(
+            data_subject_request.status,
+            enums::DataSubjectRequestStatus::Failed
+        );
+        assert!(data_subject_request.error_message.is_some());
+        assert!(data_subject_request.customer_id.is_none());
+
+        // A task picked up again once the request has finished leaves the request as it is
+        let process = db
+            .find_process_by_id(&format!(
+                "{}_{DATA_SUBJECT_REQUEST_TASK}_{}",
+                storage::ProcessTrackerRunner::DataSubjectRequestWorkflow,
+                data_subject_request.request_id
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        start_data_subject_request_workflow(&state, &process)
+            .await
+            .unwrap();
+        let unchanged_request = db
+            .find_data_subject_request_by_merchant_id_request_id(
+                merchant_account.get_id(),
+                &data_subject_request.request_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(unchanged_request.status, data_subject_request.status);
+        assert_eq!(
+            unchanged_request.modified_at,
+            data_subject_request.modified_at
+        );
+    }
+}
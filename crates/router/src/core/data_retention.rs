@@ -0,0 +1,1289 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    io::{BufRead, BufReader, Write},
+};
+
+use api_models::data_retention::{
+    DataRetentionArchiveListRequest, DataRetentionArchiveListResponse,
+    DataRetentionArchiveResponse, DataRetentionArchiveRestoreResponse,
+    DataRetentionPolicyDeleteResponse, DataRetentionPolicyRequest, DataRetentionPolicyResponse,
+};
+use common_utils::{
+    encryption::Encryption,
+    ext_traits::{Encode, ValueExt},
+    id_type,
+};
+use diesel_models::{
+    events::Event, payment_attempt::PaymentAttempt, payment_intent::PaymentIntent,
+};
+use error_stack::ResultExt;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use storage_impl::redis::kv_store::PartitionKey;
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        customers::data_subject_request,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        merchant_key_rotation,
+        payment_methods::cards,
+    },
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain::{self, behaviour::Conversion},
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const DATA_RETENTION_TASK: &str = "APPLY_DATA_RETENTION_POLICY";
+const DATA_RETENTION_TAG: &str = "DATA_RETENTION";
+const DATA_RETENTION_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::DataRetentionWorkflow;
+
+/// Restored rows are inserted in chunks to stay well within the bind parameter limit of postgres
+const RESTORE_INSERT_CHUNK_SIZE: usize = 500;
+
+/// Archived payments are restored in smaller chunks since each carries all of its attempts
+const RESTORE_PAYMENT_INSERT_CHUNK_SIZE: usize = 100;
+
+/// Payments in these statuses are not expected to change anymore, only their attempts are
+/// archived once the retention period has elapsed since they were last modified
+const SETTLED_INTENT_STATUSES: [enums::IntentStatus; 4] = [
+    enums::IntentStatus::Succeeded,
+    enums::IntentStatus::Failed,
+    enums::IntentStatus::Cancelled,
+    enums::IntentStatus::PartiallyCaptured,
+];
+
+/// Tables whose archived rows hold data encrypted with the key of their merchant
+const MERCHANT_ENCRYPTED_TABLES: [enums::DataRetentionTable; 2] = [
+    enums::DataRetentionTable::PaymentAttempt,
+    enums::DataRetentionTable::Events,
+];
+
+fn generate_task_id_for_data_retention_workflow(table_name: enums::DataRetentionTable) -> String {
+    format!("{DATA_RETENTION_RUNNER}_{DATA_RETENTION_TASK}_{table_name}")
+}
+
+impl ForeignFrom<storage::DataRetentionPolicy> for DataRetentionPolicyResponse {
+    fn foreign_from(policy: storage::DataRetentionPolicy) -> Self {
+        Self {
+            table_name: policy.table_name,
+            retention_days: policy.retention_days,
+            batch_size: policy.batch_size,
+            is_enabled: policy.is_enabled,
+            last_run_at: policy.last_run_at,
+            created_at: policy.created_at,
+            modified_at: policy.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::DataRetentionArchive> for DataRetentionArchiveResponse {
+    fn foreign_from(archive: storage::DataRetentionArchive) -> Self {
+        Self {
+            archive_id: archive.archive_id,
+            table_name: archive.table_name,
+            row_count: archive.row_count,
+            oldest_record_created_at: archive.oldest_record_created_at,
+            newest_record_created_at: archive.newest_record_created_at,
+            created_at: archive.created_at,
+            restored_at: archive.restored_at,
+        }
+    }
+}
+
+/// A row which can be written to a data retention archive
+trait ArchivableRow: Serialize + DeserializeOwned {
+    fn created_at(&self) -> PrimitiveDateTime;
+}
+
+impl ArchivableRow for Event {
+    fn created_at(&self) -> PrimitiveDateTime {
+        self.created_at
+    }
+}
+
+/// A payment intent archived together with all of its attempts, so that the attempts are never
+/// removed while the intent still references them
+#[derive(Debug, Serialize, Deserialize)]
+struct ArchivedPayment {
+    payment_intent: PaymentIntent,
+    payment_attempts: Vec<PaymentAttempt>,
+}
+
+impl ArchivableRow for ArchivedPayment {
+    fn created_at(&self) -> PrimitiveDateTime {
+        self.payment_intent.created_at
+    }
+}
+
+/// Pairs the payment intents of a merchant with the attempts made for them
+fn group_payment_attempts(
+    payment_intents: Vec<PaymentIntent>,
+    payment_attempts: Vec<PaymentAttempt>,
+) -> Vec<ArchivedPayment> {
+    let mut attempts_by_payment_id = HashMap::<String, Vec<PaymentAttempt>>::new();
+    for payment_attempt in payment_attempts {
+        attempts_by_payment_id
+            .entry(payment_attempt.payment_id.clone())
+            .or_default()
+            .push(payment_attempt);
+    }
+
+    payment_intents
+        .into_iter()
+        .map(|payment_intent| ArchivedPayment {
+            payment_attempts: attempts_by_payment_id
+                .remove(&payment_intent.payment_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|payment_attempt| payment_attempt.merchant_id == payment_intent.merchant_id)
+                .collect(),
+            payment_intent,
+        })
+        .collect()
+}
+
+fn group_by_merchant<K: Clone + Eq + Hash, T>(
+    rows: Vec<T>,
+    merchant_id: impl Fn(&T) -> &K,
+) -> HashMap<K, Vec<T>> {
+    let mut rows_by_merchant = HashMap::<K, Vec<T>>::new();
+    for row in rows {
+        rows_by_merchant
+            .entry(merchant_id(&row).clone())
+            .or_default()
+            .push(row);
+    }
+    rows_by_merchant
+}
+
+impl ArchivableRow for storage::ProcessTracker {
+    fn created_at(&self) -> PrimitiveDateTime {
+        self.created_at
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_data_retention_policy(
+    state: SessionState,
+    req: DataRetentionPolicyRequest,
+) -> RouterResponse<DataRetentionPolicyResponse> {
+    let db = state.store.as_ref();
+
+    if req.retention_days == 0 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "retention_days must be greater than zero".to_string(),
+        }
+        .into());
+    }
+    let batch_size = req
+        .batch_size
+        .unwrap_or(consts::DEFAULT_DATA_RETENTION_BATCH_SIZE);
+    if batch_size == 0 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "batch_size must be greater than zero".to_string(),
+        }
+        .into());
+    }
+    let is_enabled = req.is_enabled.unwrap_or(true);
+
+    let policy = match db
+        .find_data_retention_policy_by_table_name(req.table_name)
+        .await
+    {
+        Ok(_) => db
+            .update_data_retention_policy_by_table_name(
+                req.table_name,
+                storage::DataRetentionPolicyUpdate::Update {
+                    retention_days: i32::from(req.retention_days),
+                    batch_size: i32::from(batch_size),
+                    is_enabled,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while updating data retention policy")?,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let now = common_utils::date_time::now();
+            db.insert_data_retention_policy(storage::DataRetentionPolicyNew {
+                table_name: req.table_name,
+                retention_days: i32::from(req.retention_days),
+                batch_size: i32::from(batch_size),
+                is_enabled,
+                last_run_at: None,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .to_duplicate_response(
+                errors::ApiErrorResponse::GenericDuplicateError {
+                    message: "Data retention policy for the table already exists".to_string(),
+                },
+            )?
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching data retention policy")?,
+    };
+
+    if policy.is_enabled {
+        schedule_data_retention_task(&state, policy.table_name).await?;
+    }
+
+    Ok(ApplicationResponse::Json(
+        DataRetentionPolicyResponse::foreign_from(policy),
+    ))
+}
+
+/// Schedules the data retention workflow of the table to run immediately. A single task is kept
+/// per table, it is rescheduled by the workflow itself after every run.
+#[instrument(skip_all)]
+async fn schedule_data_retention_task(
+    state: &SessionState,
+    table_name: enums::DataRetentionTable,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let current_time = common_utils::date_time::now();
+    let task_id = generate_task_id_for_data_retention_workflow(table_name);
+    let tracking_data = storage::DataRetentionTrackingData { table_name };
+
+    let existing_task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch data retention task")?;
+
+    match existing_task {
+        Some(_) => {
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize data retention tracking data")?;
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(current_time),
+                tracking_data: Some(tracking_data),
+                business_status: Some(String::from(storage::business_status::PENDING)),
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.process_tracker_update_process_status_by_ids(
+                vec![task_id],
+                updated_process_tracker_data,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update data retention task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "DataRetention")]),
+            );
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                DATA_RETENTION_TASK,
+                DATA_RETENTION_RUNNER,
+                [DATA_RETENTION_TAG],
+                tracking_data,
+                current_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct data retention process tracker task")?;
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while inserting data retention task to process_tracker: table_name: {table_name}"
+                    )
+                })?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "DataRetention")]),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn list_data_retention_policies(
+    state: SessionState,
+) -> RouterResponse<Vec<DataRetentionPolicyResponse>> {
+    let policies = state
+        .store
+        .list_data_retention_policies()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing data retention policies")?;
+
+    Ok(ApplicationResponse::Json(
+        policies
+            .into_iter()
+            .map(DataRetentionPolicyResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Deletes the policy of the table. The scheduled task finishes on its next run once it finds
+/// the policy missing, the archives written so far are retained.
+#[instrument(skip_all)]
+pub async fn delete_data_retention_policy(
+    state: SessionState,
+    table_name: enums::DataRetentionTable,
+) -> RouterResponse<DataRetentionPolicyDeleteResponse> {
+    let deleted = state
+        .store
+        .delete_data_retention_policy_by_table_name(table_name)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Data retention policy not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        DataRetentionPolicyDeleteResponse {
+            table_name,
+            deleted,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_data_retention_archives(
+    state: SessionState,
+    req: DataRetentionArchiveListRequest,
+) -> RouterResponse<DataRetentionArchiveListResponse> {
+    let archives = state
+        .store
+        .list_data_retention_archives(
+            req.table_name,
+            Some(
+                req.limit
+                    .unwrap_or(consts::DEFAULT_DATA_RETENTION_ARCHIVE_LIST_LIMIT),
+            ),
+            req.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing data retention archives")?;
+
+    let data = archives
+        .into_iter()
+        .map(DataRetentionArchiveResponse::foreign_from)
+        .collect::<Vec<_>>();
+    Ok(ApplicationResponse::Json(
+        DataRetentionArchiveListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Inserts the rows of an archive back into their table. Rows which are present in the table
+/// already are skipped, so an archive can be restored more than once. The data of customers
+/// erased since the archive was written is redacted again before it is inserted. Restored rows
+/// are older than the retention period of the table, they are archived again on the next run of
+/// the workflow unless the policy is disabled or its retention period extended beforehand.
+#[instrument(skip_all)]
+pub async fn restore_data_retention_archive(
+    state: SessionState,
+    archive_id: String,
+) -> RouterResponse<DataRetentionArchiveRestoreResponse> {
+    let db = state.store.as_ref();
+    let archive = db
+        .find_data_retention_archive_by_archive_id(&archive_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Data retention archive not found".to_string(),
+        })?;
+
+    let file = retrieve_archive_file(&state, &archive).await?;
+
+    let mut restored_count = 0;
+    match archive.table_name {
+        enums::DataRetentionTable::Events => {
+            let events_by_merchant =
+                group_by_merchant(decode_archive::<Event>(&file)?, |event| &event.merchant_id);
+            for (merchant_id, mut events) in events_by_merchant {
+                if let Some(merchant_id) = merchant_id {
+                    let key_store = get_merchant_key_store(&state, &merchant_id).await?;
+                    let erased_data_subjects =
+                        find_erased_data_subjects(&state, &merchant_id).await?;
+                    redact_archived_events(
+                        &state,
+                        &key_store,
+                        &mut events,
+                        &erased_data_subjects.object_ids,
+                    )
+                    .await?;
+                }
+                for rows in events.chunks(RESTORE_INSERT_CHUNK_SIZE) {
+                    restored_count += db
+                        .insert_archived_events(rows.to_vec())
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed while restoring events")?;
+                }
+            }
+        }
+        enums::DataRetentionTable::PaymentAttempt => {
+            let key_manager_state = &(&state).into();
+            let payments_by_merchant =
+                group_by_merchant(decode_archive::<ArchivedPayment>(&file)?, |payment| {
+                    &payment.payment_intent.merchant_id
+                });
+            for (merchant_id, mut payments) in payments_by_merchant {
+                let key_store = get_merchant_key_store(&state, &merchant_id).await?;
+                let erased_data_subjects = find_erased_data_subjects(&state, &merchant_id).await?;
+                redact_archived_payments(
+                    &state,
+                    &key_store,
+                    &mut payments,
+                    &erased_data_subjects.customer_ids,
+                )
+                .await?;
+                for chunk in payments.chunks(RESTORE_PAYMENT_INSERT_CHUNK_SIZE) {
+                    let (payment_intents, payment_attempts) = chunk.iter().fold(
+                        (Vec::new(), Vec::new()),
+                        |(mut payment_intents, mut payment_attempts), payment| {
+                            payment_intents.push(payment.payment_intent.clone());
+                            payment_attempts.extend(payment.payment_attempts.iter().cloned());
+                            (payment_intents, payment_attempts)
+                        },
+                    );
+                    restored_count += db
+                        .insert_archived_payments(
+                            key_manager_state,
+                            payment_intents,
+                            payment_attempts,
+                            &key_store,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed while restoring payments")?;
+                }
+            }
+        }
+        enums::DataRetentionTable::ProcessTracker => {
+            for rows in
+                decode_archive::<storage::ProcessTracker>(&file)?.chunks(RESTORE_INSERT_CHUNK_SIZE)
+            {
+                restored_count += db
+                    .insert_archived_processes(rows.to_vec())
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while restoring processes")?;
+            }
+        }
+    }
+
+    let archive = db
+        .update_data_retention_archive_by_archive_id(
+            &archive.archive_id,
+            storage::DataRetentionArchiveUpdate::Restored {
+                restored_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while updating data retention archive")?;
+
+    Ok(ApplicationResponse::Json(
+        DataRetentionArchiveRestoreResponse {
+            archive_id: archive.archive_id,
+            table_name: archive.table_name,
+            row_count: archive.row_count,
+            restored_count,
+        },
+    ))
+}
+
+/// The customers of a merchant whose data was erased, along with the payments, refunds, disputes
+/// and mandates of theirs which were erased
+struct ErasedDataSubjects {
+    customer_ids: HashSet<id_type::CustomerId>,
+    object_ids: HashSet<String>,
+}
+
+#[instrument(skip_all)]
+async fn find_erased_data_subjects(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<ErasedDataSubjects> {
+    let data_subject_requests = state
+        .store
+        .list_data_subject_requests_by_merchant_id(merchant_id, None, None, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing data subject requests")?;
+
+    let mut erased_data_subjects = ErasedDataSubjects {
+        customer_ids: HashSet::new(),
+        object_ids: HashSet::new(),
+    };
+    for data_subject_request in data_subject_requests.into_iter().filter(|request| {
+        request.request_type == enums::DataSubjectRequestType::Erasure
+            && request.status == enums::DataSubjectRequestStatus::Completed
+    }) {
+        erased_data_subjects
+            .customer_ids
+            .extend(data_subject_request.customer_id);
+        erased_data_subjects
+            .object_ids
+            .extend(data_subject_request.erased_object_ids.unwrap_or_default());
+    }
+
+    Ok(erased_data_subjects)
+}
+
+async fn get_merchant_key_store(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<domain::MerchantKeyStore> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(
+        &state.into(),
+        merchant_id,
+        &db.get_master_key().to_vec().into(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
+
+async fn retrieve_archive_file(
+    state: &SessionState,
+    archive: &storage::DataRetentionArchive,
+) -> RouterResult<Vec<u8>> {
+    state
+        .file_storage_client
+        .retrieve_file(&archive.file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve data retention archive")
+}
+
+/// Overwrites the file of an archive with the rewritten rows, the archive keeps its key and
+/// metadata
+async fn replace_archive_file<T: ArchivableRow>(
+    state: &SessionState,
+    archive: &storage::DataRetentionArchive,
+    rows: &[T],
+) -> RouterResult<()> {
+    state
+        .file_storage_client
+        .upload_file(&archive.file_key, encode_archive(rows)?)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to rewrite data retention archive")
+}
+
+/// Lists all the archives which may hold rows of the merchant. Archives written before they were
+/// recorded per merchant are included too.
+async fn list_merchant_archives(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    table_name: enums::DataRetentionTable,
+) -> RouterResult<Vec<storage::DataRetentionArchive>> {
+    let mut archives = Vec::new();
+    loop {
+        let batch = state
+            .store
+            .list_data_retention_archives_by_merchant_id(
+                merchant_id,
+                vec![table_name],
+                archives
+                    .last()
+                    .map(|archive: &storage::DataRetentionArchive| archive.archive_id.clone()),
+                consts::DATA_RETENTION_ARCHIVE_BATCH_SIZE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while listing data retention archives of merchant")?;
+        let has_remaining_archives = i64::try_from(batch.len())
+            .is_ok_and(|count| count >= consts::DATA_RETENTION_ARCHIVE_BATCH_SIZE);
+        archives.extend(batch);
+        if !has_remaining_archives {
+            return Ok(archives);
+        }
+    }
+}
+
+/// Replaces the customer details of the archived payments of the erased customers, the same way
+/// as the details of their payments in the database. Returns the identifiers of the payments
+/// redacted.
+async fn redact_archived_payments(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payments: &mut [ArchivedPayment],
+    customer_ids: &HashSet<id_type::CustomerId>,
+) -> RouterResult<Vec<String>> {
+    let is_erased = |payment: &ArchivedPayment| {
+        payment.payment_intent.merchant_id == key_store.merchant_id
+            && payment
+                .payment_intent
+                .customer_id
+                .as_ref()
+                .is_some_and(|customer_id| customer_ids.contains(customer_id))
+    };
+    if !payments.iter().any(is_erased) {
+        return Ok(Vec::new());
+    }
+
+    let redacted_details = Encryption::from(
+        cards::create_encrypted_data(state, key_store, serde_json::json!({}))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt redacted customer details")?,
+    );
+    let mut redacted_payment_ids = Vec::new();
+    for payment in payments.iter_mut().filter(|payment| is_erased(payment)) {
+        let payment_intent = &mut payment.payment_intent;
+        payment_intent.customer_details = payment_intent
+            .customer_details
+            .as_ref()
+            .map(|_| redacted_details.clone());
+        payment_intent.billing_details = payment_intent
+            .billing_details
+            .as_ref()
+            .map(|_| redacted_details.clone());
+        payment_intent.shipping_details = payment_intent
+            .shipping_details
+            .as_ref()
+            .map(|_| redacted_details.clone());
+        redacted_payment_ids.push(payment_intent.payment_id.clone());
+    }
+
+    Ok(redacted_payment_ids)
+}
+
+/// Redacts the webhook content of the archived events about the erased objects. Returns whether
+/// any event was redacted.
+async fn redact_archived_events(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    events: &mut [Event],
+    object_ids: &HashSet<String>,
+) -> RouterResult<bool> {
+    let key_manager_state = &state.into();
+    let mut is_redacted = false;
+    for event in events.iter_mut().filter(|event| {
+        event.merchant_id.as_ref() == Some(&key_store.merchant_id)
+            && object_ids.contains(&event.primary_object_id)
+    }) {
+        let domain_event = domain::Event::convert_back(
+            key_manager_state,
+            event.clone(),
+            key_store.key.get_inner(),
+            key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt archived webhook event")?;
+        *event =
+            data_subject_request::redact_event_content(key_manager_state, key_store, domain_event)
+                .await?
+                .convert()
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encrypt redacted webhook event")?;
+        is_redacted = true;
+    }
+
+    Ok(is_redacted)
+}
+
+/// Redacts the data of an erased customer held in the archives of the merchant. The customer
+/// details of their archived payments are redacted first, then the webhook content about their
+/// archived payments and about the given objects. Returns the given objects along with the
+/// archived payments of the customer.
+#[instrument(skip_all)]
+pub(crate) async fn erase_data_subject_from_archives(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+    mut object_ids: HashSet<String>,
+) -> RouterResult<HashSet<String>> {
+    let customer_ids = HashSet::from([customer_id.clone()]);
+    for archive in list_merchant_archives(
+        state,
+        &key_store.merchant_id,
+        enums::DataRetentionTable::PaymentAttempt,
+    )
+    .await?
+    {
+        let mut payments =
+            decode_archive::<ArchivedPayment>(&retrieve_archive_file(state, &archive).await?)?;
+        let payment_ids =
+            redact_archived_payments(state, key_store, &mut payments, &customer_ids).await?;
+        if !payment_ids.is_empty() {
+            replace_archive_file(state, &archive, &payments).await?;
+            object_ids.extend(payment_ids);
+        }
+    }
+
+    for archive in list_merchant_archives(
+        state,
+        &key_store.merchant_id,
+        enums::DataRetentionTable::Events,
+    )
+    .await?
+    {
+        let mut events = decode_archive::<Event>(&retrieve_archive_file(state, &archive).await?)?;
+        if redact_archived_events(state, key_store, &mut events, &object_ids).await? {
+            replace_archive_file(state, &archive, &events).await?;
+        }
+    }
+
+    Ok(object_ids)
+}
+
+async fn reencrypt_optional_value(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    value: Option<Encryption>,
+) -> RouterResult<Option<Encryption>> {
+    match value {
+        Some(value) => {
+            merchant_key_rotation::reencrypt_value(&state.into(), key_store, value.clone())
+                .await
+                .map(|reencrypted_value| Some(reencrypted_value.unwrap_or(value)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Re-encrypts the rows of the merchant held in the next batch of their archives with the current
+/// key of the merchant. Returns the identifiers of the archives processed.
+#[instrument(skip_all)]
+pub(crate) async fn reencrypt_data_retention_archives(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    after_archive_id: Option<String>,
+    limit: i64,
+) -> RouterResult<Vec<String>> {
+    let archives = state
+        .store
+        .list_data_retention_archives_by_merchant_id(
+            &key_store.merchant_id,
+            MERCHANT_ENCRYPTED_TABLES.to_vec(),
+            after_archive_id,
+            limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing data retention archives of merchant")?;
+
+    for archive in &archives {
+        let file = retrieve_archive_file(state, archive).await?;
+        match archive.table_name {
+            enums::DataRetentionTable::Events => {
+                let mut events = decode_archive::<Event>(&file)?;
+                for event in events
+                    .iter_mut()
+                    .filter(|event| event.merchant_id.as_ref() == Some(&key_store.merchant_id))
+                {
+                    event.request =
+                        reencrypt_optional_value(state, key_store, event.request.take()).await?;
+                    event.response =
+                        reencrypt_optional_value(state, key_store, event.response.take()).await?;
+                }
+                replace_archive_file(state, archive, &events).await?;
+            }
+            enums::DataRetentionTable::PaymentAttempt => {
+                let mut payments = decode_archive::<ArchivedPayment>(&file)?;
+                for payment in payments
+                    .iter_mut()
+                    .filter(|payment| payment.payment_intent.merchant_id == key_store.merchant_id)
+                {
+                    let payment_intent = &mut payment.payment_intent;
+                    payment_intent.customer_details = reencrypt_optional_value(
+                        state,
+                        key_store,
+                        payment_intent.customer_details.take(),
+                    )
+                    .await?;
+                    payment_intent.billing_details = reencrypt_optional_value(
+                        state,
+                        key_store,
+                        payment_intent.billing_details.take(),
+                    )
+                    .await?;
+                    payment_intent.shipping_details = reencrypt_optional_value(
+                        state,
+                        key_store,
+                        payment_intent.shipping_details.take(),
+                    )
+                    .await?;
+                }
+                replace_archive_file(state, archive, &payments).await?;
+            }
+            // Processes hold no data encrypted with the key of a merchant
+            enums::DataRetentionTable::ProcessTracker => {}
+        }
+    }
+
+    Ok(archives
+        .into_iter()
+        .map(|archive| archive.archive_id)
+        .collect())
+}
+
+/// Archives the oldest settled payments along with their attempts, then purges their copies from
+/// the KV store and deletes them from the database, merchant by merchant. The KV copies are
+/// purged first so that a failure leaves the payments in the database to be archived again.
+/// Returns the number of payments archived.
+#[instrument(skip_all)]
+async fn archive_settled_payments(
+    state: &SessionState,
+    table_name: enums::DataRetentionTable,
+    settled_before: PrimitiveDateTime,
+    batch_size: i64,
+) -> RouterResult<usize> {
+    let db = state.store.as_ref();
+    let payment_intents = db
+        .find_settled_payment_intents(SETTLED_INTENT_STATUSES.to_vec(), settled_before, batch_size)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching settled payment intents")?;
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let mut archived_count = 0;
+    for (merchant_id, payment_intents) in group_by_merchant(payment_intents, |payment_intent| {
+        &payment_intent.merchant_id
+    }) {
+        let payment_ids = payment_intents
+            .iter()
+            .map(|payment_intent| payment_intent.payment_id.clone())
+            .collect::<Vec<_>>();
+        let payment_attempts = db
+            .find_payment_attempts_by_merchant_id_payment_ids(&merchant_id, payment_ids.clone())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching attempts of settled payments")?;
+        let payments = group_payment_attempts(payment_intents, payment_attempts);
+
+        if store_archive(state, table_name, Some(&merchant_id), &payments)
+            .await?
+            .is_none()
+        {
+            continue;
+        }
+
+        for payment_id in &payment_ids {
+            let key = PartitionKey::MerchantIdPaymentId {
+                merchant_id: &merchant_id,
+                payment_id,
+            };
+            redis_conn
+                .delete_key(&key.to_string())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to purge archived payment from the KV store")?;
+        }
+
+        db.delete_archived_payments(&merchant_id, payment_ids)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while deleting archived payments")?;
+        archived_count += payments.len();
+    }
+
+    Ok(archived_count)
+}
+
+/// Encodes the rows as gzip compressed newline delimited JSON
+fn encode_archive<T: ArchivableRow>(rows: &[T]) -> RouterResult<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    for row in rows {
+        serde_json::to_writer(&mut encoder, row)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize archived row")?;
+        encoder
+            .write_all(b"\n")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    }
+    encoder
+        .finish()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compress data retention archive")
+}
+
+fn decode_archive<T: ArchivableRow>(file: &[u8]) -> RouterResult<Vec<T>> {
+    BufReader::new(GzDecoder::new(file))
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |line| !line.is_empty()))
+        .map(|line| {
+            let line = line
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to decompress data retention archive")?;
+            serde_json::from_str(&line)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize archived row")
+        })
+        .collect()
+}
+
+/// Writes the rows to the file storage and records the archive. Rows holding data of a merchant
+/// are archived per merchant, so that their archives can be found when the data of one of their
+/// customers is erased or their key is rotated. Returns `None` when there are no rows to archive.
+#[instrument(skip_all)]
+async fn store_archive<T: ArchivableRow>(
+    state: &SessionState,
+    table_name: enums::DataRetentionTable,
+    merchant_id: Option<&id_type::MerchantId>,
+    rows: &[T],
+) -> RouterResult<Option<storage::DataRetentionArchive>> {
+    let (Some(oldest_record_created_at), Some(newest_record_created_at)) = (
+        rows.iter().map(ArchivableRow::created_at).min(),
+        rows.iter().map(ArchivableRow::created_at).max(),
+    ) else {
+        return Ok(None);
+    };
+
+    let archive_id = common_utils::generate_id_with_default_len("archive");
+    let file_key = format!("data_retention/{table_name}/{archive_id}.ndjson.gz");
+    state
+        .file_storage_client
+        .upload_file(&file_key, encode_archive(rows)?)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store data retention archive")?;
+
+    let archive = state
+        .store
+        .insert_data_retention_archive(storage::DataRetentionArchiveNew {
+            archive_id,
+            table_name,
+            merchant_id: merchant_id.cloned(),
+            file_key,
+            row_count: i32::try_from(rows.len())
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            oldest_record_created_at,
+            newest_record_created_at,
+            created_at: common_utils::date_time::now(),
+            restored_at: None,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting data retention archive")?;
+
+    Ok(Some(archive))
+}
+
+/// Archives and deletes the oldest expired rows of the table. The rows are only deleted once the
+/// archive is stored, if the deletion fails they are archived again on the next run. Returns the
+/// number of rows archived.
+#[instrument(skip_all)]
+async fn archive_expired_rows(
+    state: &SessionState,
+    table_name: enums::DataRetentionTable,
+    created_before: PrimitiveDateTime,
+    batch_size: i64,
+) -> RouterResult<usize> {
+    let db = state.store.as_ref();
+    let row_count = match table_name {
+        enums::DataRetentionTable::Events => {
+            let rows = db
+                .find_events_created_before(created_before, batch_size)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching expired events")?;
+            let row_count = rows.len();
+            for (merchant_id, rows) in group_by_merchant(rows, |row| &row.merchant_id) {
+                if store_archive(state, table_name, merchant_id.as_ref(), &rows)
+                    .await?
+                    .is_some()
+                {
+                    db.delete_events_by_event_ids(
+                        rows.iter().map(|row| row.event_id.clone()).collect(),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while deleting archived events")?;
+                }
+            }
+            row_count
+        }
+        enums::DataRetentionTable::PaymentAttempt => {
+            archive_settled_payments(state, table_name, created_before, batch_size).await?
+        }
+        enums::DataRetentionTable::ProcessTracker => {
+            let rows = db
+                .find_finished_processes_created_before(created_before, batch_size)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching expired processes")?;
+            if store_archive(state, table_name, None, &rows)
+                .await?
+                .is_some()
+            {
+                db.delete_processes_by_ids(rows.iter().map(|row| row.id.clone()).collect())
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while deleting archived processes")?;
+            }
+            rows.len()
+        }
+    };
+
+    Ok(row_count)
+}
+
+#[instrument(skip_all)]
+pub async fn start_data_retention_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::DataRetentionTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("DataRetentionTrackingData")?;
+
+    let policy = match db
+        .find_data_retention_policy_by_table_name(tracking_data.table_name)
+        .await
+    {
+        Ok(policy) if policy.is_enabled => policy,
+        Ok(_) => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process.clone(), "POLICY_DISABLED")
+                .await?;
+            return Ok(());
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process.clone(), "POLICY_DELETED")
+                .await?;
+            return Ok(());
+        }
+        Err(error) => Err(error)?,
+    };
+
+    let started_at = common_utils::date_time::now();
+    let created_before = started_at - time::Duration::days(i64::from(policy.retention_days));
+    let batch_size = i64::from(policy.batch_size);
+
+    let mut archived_rows = 0;
+    let mut has_remaining_rows = false;
+    for _ in 0..consts::DATA_RETENTION_MAX_BATCHES_PER_RUN {
+        let row_count =
+            archive_expired_rows(state, policy.table_name, created_before, batch_size).await?;
+        archived_rows += row_count;
+        has_remaining_rows = i64::try_from(row_count).is_ok_and(|count| count >= batch_size);
+        if !has_remaining_rows {
+            break;
+        }
+    }
+    logger::info!(
+        table_name = %policy.table_name,
+        archived_rows,
+        has_remaining_rows,
+        "Applied data retention policy"
+    );
+
+    db.update_data_retention_policy_by_table_name(
+        policy.table_name,
+        storage::DataRetentionPolicyUpdate::LastRunUpdate {
+            last_run_at: started_at,
+        },
+    )
+    .await?;
+
+    // Continue in the next scheduler cycle while expired rows are left, so that a large backlog
+    // does not hold up the consumer
+    let schedule_time = if has_remaining_rows {
+        common_utils::date_time::now()
+    } else {
+        started_at + time::Duration::seconds(consts::DATA_RETENTION_RUN_INTERVAL_IN_SECS)
+    };
+    db.as_scheduler()
+        .reset_process(process.clone(), schedule_time)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::db::{data_retention::DataRetentionInterface, MockDb};
+
+    fn payment_intent(merchant_id: &str, payment_id: &str) -> PaymentIntent {
+        serde_json::from_value(serde_json::json!({
+            "payment_id": payment_id,
+            "merchant_id": merchant_id,
+            "status": "succeeded",
+            "amount": 1000,
+            "created_at": "2024-01-01T00:00:00.000Z",
+            "modified_at": "2024-01-01T00:00:00.000Z",
+            "active_attempt_id": format!("{payment_id}_1"),
+            "attempt_count": 1,
+            "updated_by": "postgres_only",
+        }))
+        .unwrap()
+    }
+
+    fn payment_attempt(merchant_id: &str, payment_id: &str, attempt_id: &str) -> PaymentAttempt {
+        serde_json::from_value(serde_json::json!({
+            "payment_id": payment_id,
+            "merchant_id": merchant_id,
+            "attempt_id": attempt_id,
+            "status": "charged",
+            "amount": 1000,
+            "confirm": true,
+            "created_at": "2024-01-01T00:00:00.000Z",
+            "modified_at": "2024-01-01T00:00:00.000Z",
+            "amount_capturable": 0,
+            "updated_by": "postgres_only",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_group_payment_attempts_pairs_attempts_with_their_intent() {
+        let payments = group_payment_attempts(
+            vec![
+                payment_intent("merchant_1", "pay_1"),
+                payment_intent("merchant_1", "pay_2"),
+            ],
+            vec![
+                payment_attempt("merchant_1", "pay_1", "pay_1_1"),
+                payment_attempt("merchant_1", "pay_1", "pay_1_2"),
+                payment_attempt("merchant_2", "pay_2", "pay_2_1"),
+            ],
+        );
+
+        let attempt_ids = payments
+            .iter()
+            .map(|payment| {
+                (
+                    payment.payment_intent.payment_id.as_str(),
+                    payment
+                        .payment_attempts
+                        .iter()
+                        .map(|payment_attempt| payment_attempt.attempt_id.as_str())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attempt_ids,
+            vec![("pay_1", vec!["pay_1_1", "pay_1_2"]), ("pay_2", vec![])]
+        );
+    }
+
+    #[test]
+    fn test_archive_round_trip() {
+        let payments = group_payment_attempts(
+            vec![payment_intent("merchant_1", "pay_1")],
+            vec![payment_attempt("merchant_1", "pay_1", "pay_1_1")],
+        );
+
+        let decoded =
+            decode_archive::<ArchivedPayment>(&encode_archive(&payments).unwrap()).unwrap();
+
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].payment_intent, payments[0].payment_intent);
+        assert_eq!(decoded[0].payment_attempts, payments[0].payment_attempts);
+        assert_eq!(
+            decoded[0].created_at(),
+            payments[0].payment_intent.created_at
+        );
+    }
+    #[tokio::test]
+    async fn test_list_data_retention_archives_by_merchant_id() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let merchant_id = |merchant_id: &str| {
+            id_type::MerchantId::try_from(std::borrow::Cow::from(merchant_id.to_string())).unwrap()
+        };
+        for (archive_id, table_name, archive_merchant_id) in [
+            (
+                "archive_1",
+                enums::DataRetentionTable::PaymentAttempt,
+                Some("merchant_1"),
+            ),
+            (
+                "archive_2",
+                enums::DataRetentionTable::PaymentAttempt,
+                Some("merchant_2"),
+            ),
+            ("archive_3", enums::DataRetentionTable::Events, None),
+            ("archive_4", enums::DataRetentionTable::ProcessTracker, None),
+            (
+                "archive_5",
+                enums::DataRetentionTable::Events,
+                Some("merchant_1"),
+            ),
+        ] {
+            db.insert_data_retention_archive(storage::DataRetentionArchiveNew {
+                archive_id: archive_id.to_string(),
+                table_name,
+                merchant_id: archive_merchant_id.map(merchant_id),
+                file_key: format!("data_retention/{table_name}/{archive_id}.ndjson.gz"),
+                row_count: 1,
+                oldest_record_created_at: common_utils::date_time::now(),
+                newest_record_created_at: common_utils::date_time::now(),
+                created_at: common_utils::date_time::now(),
+                restored_at: None,
+            })
+            .await
+            .unwrap();
+        }
+
+        let list_archive_ids = |after_archive_id: Option<&str>, limit| {
+            let db = &db;
+            let merchant_id = merchant_id("merchant_1");
+            let after_archive_id = after_archive_id.map(str::to_string);
+            async move {
+                db.list_data_retention_archives_by_merchant_id(
+                    &merchant_id,
+                    MERCHANT_ENCRYPTED_TABLES.to_vec(),
+                    after_archive_id,
+                    limit,
+                )
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|archive| archive.archive_id)
+                .collect::<Vec<_>>()
+            }
+        };
+
+        // Archives of other merchants and of tables without merchant data are left out, archives
+        // written before they were recorded per merchant are included
+        assert_eq!(
+            list_archive_ids(None, 10).await,
+            vec!["archive_1", "archive_3", "archive_5"]
+        );
+        assert_eq!(
+            list_archive_ids(None, 2).await,
+            vec!["archive_1", "archive_3"]
+        );
+        assert_eq!(
+            list_archive_ids(Some("archive_3"), 2).await,
+            vec!["archive_5"]
+        );
+    }
+}
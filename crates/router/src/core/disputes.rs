@@ -2,6 +2,9 @@ use api_models::{disputes as dispute_models, files as files_api_models};
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
+pub mod deadlines;
+#[cfg(feature = "olap")]
+pub mod evidence_draft;
 pub mod transformers;
 
 use super::{
@@ -0,0 +1,576 @@
+use api_models::disputes::{
+    DisputeDeadlineListConstraints, DisputeDeadlinePolicy, DisputeResponse,
+};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt, ValueExt},
+    types::MinorUnit,
+};
+use diesel_models::{configs, process_tracker::business_status};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        webhooks,
+    },
+    routes::{metrics, SessionState},
+    services,
+    types::{
+        api::{self, disputes},
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const DISPUTE_DEADLINE_TAG: &str = "DISPUTE";
+const DISPUTE_DEADLINE_NAME: &str = "DISPUTE_DEADLINE";
+const DISPUTE_DEADLINE_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::DisputeDeadlineWorkflow;
+
+fn generate_task_id_for_dispute_deadline_workflow(dispute_id: &str) -> String {
+    format!("{DISPUTE_DEADLINE_RUNNER}_{DISPUTE_DEADLINE_NAME}_{dispute_id}")
+}
+
+async fn find_dispute_deadline_policy(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<Option<DisputeDeadlinePolicy>> {
+    match state
+        .store
+        .find_config_by_key(&merchant_id.get_dispute_deadline_policy_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("DisputeDeadlinePolicy")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse dispute deadline policy")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch dispute deadline policy")),
+    }
+}
+
+/// Policy in effect for the merchant, falling back to the default reminder offsets when the
+/// merchant has not configured one
+async fn get_effective_dispute_deadline_policy(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<DisputeDeadlinePolicy> {
+    Ok(find_dispute_deadline_policy(state, merchant_id)
+        .await?
+        .unwrap_or_else(|| DisputeDeadlinePolicy {
+            reminder_offsets_in_hours: consts::DEFAULT_DISPUTE_REMINDER_OFFSETS_IN_HOURS.to_vec(),
+            auto_accept_below_amount: None,
+        }))
+}
+
+/// Reminder offsets ordered from the earliest reminder to the latest one
+fn get_sorted_reminder_offsets(policy: &DisputeDeadlinePolicy) -> Vec<u16> {
+    let mut offsets = policy.reminder_offsets_in_hours.clone();
+    offsets.sort_unstable_by(|a, b| b.cmp(a));
+    offsets.dedup();
+    offsets
+}
+
+fn get_reminder_time(
+    challenge_required_by: time::PrimitiveDateTime,
+    offset_in_hours: u16,
+) -> time::PrimitiveDateTime {
+    challenge_required_by.saturating_sub(time::Duration::hours(i64::from(offset_in_hours)))
+}
+
+/// Returns the index of the next reminder to be sent along with the time at which the task has to
+/// run. Once all the reminders have been sent, the task runs at the deadline itself.
+fn get_next_schedule(
+    challenge_required_by: time::PrimitiveDateTime,
+    reminder_offsets_in_hours: &[u16],
+    starting_index: usize,
+    current_time: time::PrimitiveDateTime,
+) -> (usize, time::PrimitiveDateTime) {
+    reminder_offsets_in_hours
+        .iter()
+        .enumerate()
+        .skip(starting_index)
+        .map(|(index, offset)| (index, get_reminder_time(challenge_required_by, *offset)))
+        .find(|(_, reminder_time)| *reminder_time > current_time)
+        .unwrap_or((reminder_offsets_in_hours.len(), challenge_required_by))
+}
+
+fn validate_policy(policy: &DisputeDeadlinePolicy) -> RouterResult<()> {
+    if policy.reminder_offsets_in_hours.contains(&0) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Reminder offsets must be greater than zero hours".to_string(),
+        })?
+    }
+    if policy
+        .auto_accept_below_amount
+        .is_some_and(|amount| amount < MinorUnit::zero())
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Auto accept amount threshold cannot be negative".to_string(),
+        })?
+    }
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_dispute_deadline_policy(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    policy: DisputeDeadlinePolicy,
+) -> RouterResponse<DisputeDeadlinePolicy> {
+    validate_policy(&policy)?;
+
+    let db = state.store.as_ref();
+    let key = merchant_account.get_id().get_dispute_deadline_policy_key();
+    let config = policy
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize dispute deadline policy")?;
+
+    match find_dispute_deadline_policy(&state, merchant_account.get_id()).await? {
+        Some(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update dispute deadline policy")?,
+        None => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert dispute deadline policy")?,
+    };
+
+    Ok(services::ApplicationResponse::Json(policy))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_dispute_deadline_policy(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<DisputeDeadlinePolicy> {
+    get_effective_dispute_deadline_policy(&state, merchant_account.get_id())
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+/// Lists the open disputes whose evidence deadline falls within the requested window, the most
+/// urgent one first
+#[instrument(skip_all)]
+pub async fn list_upcoming_deadlines(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: DisputeDeadlineListConstraints,
+) -> RouterResponse<Vec<DisputeResponse>> {
+    let current_time = date_time::now();
+    let window_in_hours = constraints
+        .within_hours
+        .unwrap_or(consts::DEFAULT_DISPUTE_DEADLINE_WINDOW_IN_HOURS);
+    let window_end = current_time.saturating_add(time::Duration::hours(i64::from(window_in_hours)));
+
+    let disputes = state
+        .store
+        .find_open_disputes_by_merchant_id_challenge_required_by(
+            merchant_account.get_id(),
+            constraints.profile_id,
+            (current_time, window_end),
+            constraints.limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list disputes with upcoming evidence deadlines")?;
+
+    Ok(services::ApplicationResponse::Json(
+        disputes
+            .into_iter()
+            .map(DisputeResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Schedules the reminders and the deadline handling for an open dispute. When the dispute already
+/// has a task, it is rescheduled as per the latest evidence deadline received from the connector.
+#[instrument(skip_all)]
+pub async fn add_or_update_dispute_deadline_task(
+    state: &SessionState,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let challenge_required_by = match dispute.challenge_required_by {
+        Some(challenge_required_by)
+            if dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened =>
+        {
+            challenge_required_by
+        }
+        _ => return Ok(()),
+    };
+
+    let policy = get_effective_dispute_deadline_policy(state, &dispute.merchant_id).await?;
+    let reminder_offsets_in_hours = get_sorted_reminder_offsets(&policy);
+    let current_time = date_time::now();
+    let (reminder_index, schedule_time) = get_next_schedule(
+        challenge_required_by,
+        &reminder_offsets_in_hours,
+        0,
+        current_time,
+    );
+
+    let tracking_data = storage::DisputeDeadlineTrackingData {
+        dispute_id: dispute.dispute_id.clone(),
+        merchant_id: dispute.merchant_id.clone(),
+        challenge_required_by,
+        reminder_offsets_in_hours,
+        reminder_index,
+    };
+
+    let db = &*state.store;
+    let task_id = generate_task_id_for_dispute_deadline_workflow(&dispute.dispute_id);
+    let existing_task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch dispute deadline task")?;
+
+    match existing_task {
+        Some(_) => {
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize dispute deadline tracking data")?;
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data),
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.process_tracker_update_process_status_by_ids(
+                vec![task_id],
+                updated_process_tracker_data,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update dispute deadline task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "DisputeDeadline")]),
+            );
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                DISPUTE_DEADLINE_NAME,
+                DISPUTE_DEADLINE_RUNNER,
+                [DISPUTE_DEADLINE_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct dispute deadline process tracker task")?;
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while inserting dispute deadline task to process_tracker: dispute_id: {}",
+                        dispute.dispute_id
+                    )
+                })?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "DisputeDeadline")]),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn start_dispute_deadline_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::DisputeDeadlineTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("DisputeDeadlineTrackingData")?;
+
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(
+            &tracking_data.merchant_id,
+            &tracking_data.dispute_id,
+        )
+        .await?;
+
+    // The dispute has been responded to, or its deadline has changed since the task was scheduled
+    if dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+        || dispute.challenge_required_by != Some(tracking_data.challenge_required_by)
+    {
+        db.as_scheduler()
+            .finish_process_with_business_status(
+                process.clone(),
+                business_status::RESOURCE_STATUS_MISMATCH,
+            )
+            .await?;
+        return Ok(());
+    }
+
+    let current_time = date_time::now();
+    match tracking_data
+        .reminder_offsets_in_hours
+        .get(tracking_data.reminder_index)
+        .copied()
+    {
+        Some(offset_in_hours) if current_time < tracking_data.challenge_required_by => {
+            send_dispute_deadline_reminder(
+                state,
+                &merchant_account,
+                &key_store,
+                &dispute,
+                offset_in_hours,
+            )
+            .await;
+
+            let (reminder_index, schedule_time) = get_next_schedule(
+                tracking_data.challenge_required_by,
+                &tracking_data.reminder_offsets_in_hours,
+                tracking_data.reminder_index + 1,
+                current_time,
+            );
+            let tracking_data = storage::DisputeDeadlineTrackingData {
+                reminder_index,
+                ..tracking_data
+            };
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(process.retry_count),
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data.encode_to_value()?),
+                business_status: None,
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.process_tracker_update_process_status_by_ids(
+                vec![process.id.clone()],
+                updated_process_tracker_data,
+            )
+            .await?;
+        }
+        Some(_) | None => {
+            let policy =
+                get_effective_dispute_deadline_policy(state, &tracking_data.merchant_id).await?;
+            if policy
+                .auto_accept_below_amount
+                .is_some_and(|threshold| MinorUnit::new(dispute.dispute_amount) <= threshold)
+            {
+                logger::info!(
+                    dispute_id = %dispute.dispute_id,
+                    "Evidence deadline lapsed, accepting dispute as per the merchant policy"
+                );
+                Box::pin(super::accept_dispute(
+                    state.clone(),
+                    merchant_account,
+                    None,
+                    key_store,
+                    disputes::DisputeId {
+                        dispute_id: dispute.dispute_id.clone(),
+                    },
+                ))
+                .await?;
+            }
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process.clone(),
+                    business_status::COMPLETED_BY_PT,
+                )
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Notifies the merchant about an approaching evidence deadline through an outgoing webhook and,
+/// when enabled, an email. Failures are logged and do not block the subsequent reminders.
+async fn send_dispute_deadline_reminder(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+    offset_in_hours: u16,
+) {
+    let event_type = storage_enums::EventType::DisputeDeadlineReminder;
+    match dispute.profile_id.as_ref() {
+        Some(profile_id) => {
+            let business_profile = state
+                .store
+                .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+                .await;
+            match business_profile {
+                Ok(business_profile) => {
+                    let idempotent_event_id =
+                        format!("{}_{event_type}_{offset_in_hours}", dispute.dispute_id);
+                    let dispute_response = Box::new(DisputeResponse::foreign_from(dispute.clone()));
+                    let _ = Box::pin(
+                        webhooks::create_event_with_idempotent_id_and_trigger_outgoing_webhook(
+                            state.clone(),
+                            merchant_account.clone(),
+                            business_profile,
+                            key_store,
+                            event_type,
+                            storage_enums::EventClass::Disputes,
+                            dispute.dispute_id.clone(),
+                            storage_enums::EventObjectType::DisputeDetails,
+                            api::OutgoingWebhookContent::DisputeDetails(dispute_response),
+                            Some(dispute.created_at),
+                            idempotent_event_id,
+                        ),
+                    )
+                    .await
+                    .map_err(|error| {
+                        logger::error!(
+                            ?error,
+                            "Failed to trigger dispute deadline reminder webhook"
+                        )
+                    });
+                }
+                Err(error) => {
+                    logger::error!(?error, "Failed to fetch business profile of the dispute")
+                }
+            }
+        }
+        None => logger::warn!(
+            dispute_id = %dispute.dispute_id,
+            "Dispute is not associated with a business profile, skipping reminder webhook"
+        ),
+    }
+
+    #[cfg(feature = "email")]
+    {
+        let _ = send_dispute_deadline_reminder_email(state, merchant_account, dispute)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to send dispute deadline reminder email")
+            });
+    }
+}
+
+#[cfg(feature = "email")]
+async fn send_dispute_deadline_reminder_email(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &storage::Dispute,
+) -> Result<(), errors::ProcessTrackerError> {
+    use crate::services::email::types::DisputeDeadlineReminder;
+
+    let email_id = merchant_account
+        .merchant_details
+        .parse_value::<api::MerchantDetails>("MerchantDetails")?
+        .primary_email
+        .ok_or(errors::ProcessTrackerError::EValidationError(
+            common_utils::errors::ValidationError::MissingRequiredField {
+                field_name: "email".to_string(),
+            }
+            .into(),
+        ))?;
+
+    let hours_remaining = dispute
+        .challenge_required_by
+        .map(|challenge_required_by| (challenge_required_by - date_time::now()).whole_hours())
+        .and_then(|hours| u16::try_from(hours).ok())
+        .unwrap_or_default();
+
+    let email_contents = DisputeDeadlineReminder {
+        recipient_email: domain::UserEmail::from_pii_email(email_id).map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to convert recipient's email to UserEmail from pii::Email"
+            );
+            errors::ProcessTrackerError::EApiErrorResponse
+        })?,
+        subject: "Dispute Evidence Deadline Reminder",
+        dispute_id: dispute.dispute_id.clone(),
+        payment_id: dispute.payment_id.clone(),
+        amount: dispute.amount.clone(),
+        currency: dispute.currency.clone(),
+        hours_remaining,
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .map_err(errors::ProcessTrackerError::EEmailError)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_next_schedule_skips_lapsed_reminders() {
+        let deadline = datetime!(2024-08-30 12:00);
+        let offsets = [72, 24];
+
+        assert_eq!(
+            get_next_schedule(deadline, &offsets, 0, datetime!(2024-08-20 0:00)),
+            (0, datetime!(2024-08-27 12:00))
+        );
+        assert_eq!(
+            get_next_schedule(deadline, &offsets, 0, datetime!(2024-08-28 0:00)),
+            (1, datetime!(2024-08-29 12:00))
+        );
+        assert_eq!(
+            get_next_schedule(deadline, &offsets, 0, datetime!(2024-08-30 0:00)),
+            (2, deadline)
+        );
+    }
+
+    #[test]
+    fn test_reminder_offsets_are_sorted_and_deduplicated() {
+        let policy = DisputeDeadlinePolicy {
+            reminder_offsets_in_hours: vec![24, 72, 24, 6],
+            auto_accept_below_amount: None,
+        };
+        assert_eq!(get_sorted_reminder_offsets(&policy), vec![72, 24, 6]);
+    }
+}
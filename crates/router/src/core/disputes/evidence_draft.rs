@@ -0,0 +1,949 @@
+use api_models::{
+    disputes::{DisputeEvidenceDraftResponse, DisputeResponse, SubmitEvidenceRequest},
+    payments::{AdditionalCardInfo, AdditionalPaymentData, AddressDetails, BrowserInformation},
+};
+use common_utils::{
+    ext_traits::ValueExt,
+    id_type,
+    types::{AmountConvertor, MinorUnit, StringMajorUnitForCore},
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files, utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, disputes},
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// The successful payments the customer made before the disputed payment
+struct CustomerHistory {
+    successful_payment_count: usize,
+    first_payment_at: Option<PrimitiveDateTime>,
+}
+
+/// Data gathered from storage which makes up the evidence package of a dispute
+struct EvidencePackage {
+    dispute: storage::Dispute,
+    payment_intent: storage::PaymentIntent,
+    payment_attempt: storage::PaymentAttempt,
+    card_info: Option<AdditionalCardInfo>,
+    browser_info: Option<BrowserInformation>,
+    authentication: Option<storage::Authentication>,
+    billing_address: Option<AddressDetails>,
+    shipping_address: Option<AddressDetails>,
+    customer_name: Option<String>,
+    customer_email: Option<String>,
+    customer_history: Option<CustomerHistory>,
+    refunds: Vec<storage::Refund>,
+    attached_files: api::DisputeEvidence,
+}
+
+/// Formats an amount in the base unit of its currency, eg: `10.50 USD`. The amount is left in
+/// minor units if the currency is unknown.
+fn format_amount(amount: MinorUnit, currency: Option<storage_enums::Currency>) -> String {
+    let Some(currency) = currency else {
+        return amount.get_amount_as_i64().to_string();
+    };
+    StringMajorUnitForCore
+        .convert(amount, currency)
+        .map(|amount| format!("{} {currency}", amount.get_amount_as_string()))
+        .unwrap_or_else(|_| format!("{} {currency}", amount.get_amount_as_i64()))
+}
+
+fn format_address(address: &AddressDetails) -> Option<String> {
+    let name = [address.first_name.as_ref(), address.last_name.as_ref()]
+        .into_iter()
+        .flatten()
+        .map(|name| name.peek().clone())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let components = [
+        Some(name).filter(|name| !name.is_empty()),
+        address.line1.as_ref().map(|line| line.peek().clone()),
+        address.line2.as_ref().map(|line| line.peek().clone()),
+        address.line3.as_ref().map(|line| line.peek().clone()),
+        address.city.clone(),
+        address.state.as_ref().map(|state| state.peek().clone()),
+        address.zip.as_ref().map(|zip| zip.peek().clone()),
+        address.country.map(|country| country.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    (!components.is_empty()).then(|| components.join(", "))
+}
+
+fn push_section(document: &mut Vec<String>, title: &str, lines: Vec<String>) {
+    if lines.is_empty() {
+        return;
+    }
+    document.push(format!("{title}\n{}", "-".repeat(title.len())));
+    document.push(lines.join("\n"));
+}
+
+impl EvidencePackage {
+    fn get_dispute_amount(&self) -> String {
+        match self.dispute.amount.parse::<i64>() {
+            Ok(amount) => format_amount(
+                MinorUnit::new(amount),
+                self.dispute
+                    .currency
+                    .parse::<storage_enums::Currency>()
+                    .ok(),
+            ),
+            Err(_) => format!("{} {}", self.dispute.amount, self.dispute.currency),
+        }
+    }
+
+    fn get_access_activity_log(&self) -> Option<String> {
+        let browser_info = self.browser_info.as_ref()?;
+        let mut activity = vec![format!(
+            "Payment {} was made on {}",
+            self.payment_intent.payment_id, self.payment_attempt.created_at
+        )];
+        if let Some(ip_address) = browser_info.ip_address {
+            activity.push(format!("from IP address {ip_address}"));
+        }
+        if let Some(user_agent) = &browser_info.user_agent {
+            activity.push(format!("using {user_agent}"));
+        }
+        Some(activity.join(" "))
+    }
+
+    fn get_product_description(&self) -> Option<String> {
+        let product_names = self
+            .payment_intent
+            .order_details
+            .as_ref()
+            .map(|order_details| {
+                order_details
+                    .iter()
+                    .filter_map(|order| {
+                        order
+                            .peek()
+                            .get("product_name")
+                            .and_then(serde_json::Value::as_str)
+                            .map(ToOwned::to_owned)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|product_names| !product_names.is_empty())
+            .map(|product_names| product_names.join(", "));
+
+        self.payment_intent.description.clone().or(product_names)
+    }
+
+    /// Renders the package as a plain text document, which is attached to the dispute and also
+    /// submitted as the additional evidence statement
+    fn render_document(&self) -> String {
+        let mut document = vec![format!(
+            "Evidence for dispute {} on payment {}",
+            self.dispute.dispute_id, self.dispute.payment_id
+        )];
+
+        push_section(
+            &mut document,
+            "Dispute",
+            vec![
+                format!("Amount: {}", self.get_dispute_amount()),
+                format!(
+                    "Connector dispute id: {}",
+                    self.dispute.connector_dispute_id
+                ),
+                format!(
+                    "Reason: {}",
+                    self.dispute
+                        .connector_reason
+                        .as_deref()
+                        .unwrap_or("not provided")
+                ),
+            ],
+        );
+
+        let mut payment = vec![
+            format!(
+                "Amount: {}",
+                format_amount(
+                    self.payment_attempt.amount,
+                    self.payment_attempt
+                        .currency
+                        .or(self.payment_intent.currency)
+                ),
+            ),
+            format!("Created at: {}", self.payment_intent.created_at),
+            format!("Status: {}", self.payment_attempt.status),
+        ];
+        payment.extend(
+            self.payment_attempt
+                .connector_transaction_id
+                .as_ref()
+                .map(|id| format!("Connector transaction id: {id}")),
+        );
+        payment.extend(
+            self.get_product_description()
+                .map(|description| format!("Description: {description}")),
+        );
+        if let Some(card_info) = &self.card_info {
+            payment.extend(card_info.card_network.as_ref().map(|network| {
+                format!(
+                    "Card: {network} ending in {}",
+                    card_info.last4.as_deref().unwrap_or("****")
+                )
+            }));
+            payment.extend(
+                card_info
+                    .payment_checks
+                    .as_ref()
+                    .map(|checks| format!("AVS and CVV checks: {checks}")),
+            );
+        }
+        payment.extend(self.get_access_activity_log());
+        push_section(&mut document, "Payment", payment);
+
+        let mut authentication = Vec::new();
+        if let Some(authentication_record) = &self.authentication {
+            authentication.push(format!(
+                "3DS authentication {} via {}",
+                authentication_record.authentication_status,
+                authentication_record.authentication_connector
+            ));
+            authentication.extend(
+                authentication_record
+                    .message_version
+                    .as_ref()
+                    .map(|version| format!("Protocol version: {version}")),
+            );
+            authentication.extend(
+                authentication_record
+                    .trans_status
+                    .as_ref()
+                    .map(|status| format!("Transaction status: {status}")),
+            );
+            authentication.extend(
+                authentication_record
+                    .eci
+                    .as_ref()
+                    .map(|eci| format!("ECI: {eci}")),
+            );
+            authentication.extend(
+                authentication_record
+                    .ds_trans_id
+                    .as_ref()
+                    .map(|id| format!("Directory server transaction id: {id}")),
+            );
+        }
+        authentication.extend(
+            self.card_info
+                .as_ref()
+                .and_then(|card_info| card_info.authentication_data.as_ref())
+                .map(|data| format!("Authentication data: {data}")),
+        );
+        push_section(&mut document, "Authentication", authentication);
+
+        let mut customer = Vec::new();
+        customer.extend(
+            self.customer_name
+                .as_ref()
+                .map(|name| format!("Name: {name}")),
+        );
+        customer.extend(
+            self.customer_email
+                .as_ref()
+                .map(|email| format!("Email: {email}")),
+        );
+        customer.extend(
+            self.billing_address
+                .as_ref()
+                .and_then(format_address)
+                .map(|address| format!("Billing address: {address}")),
+        );
+        customer.extend(
+            self.shipping_address
+                .as_ref()
+                .and_then(format_address)
+                .map(|address| format!("Shipping address: {address}")),
+        );
+        if let Some(customer_history) = &self.customer_history {
+            customer.push(format!(
+                "Previous successful payments: {}",
+                customer_history.successful_payment_count
+            ));
+            customer.extend(
+                customer_history
+                    .first_payment_at
+                    .map(|first_payment| format!("Customer since: {first_payment}")),
+            );
+        }
+        push_section(&mut document, "Customer", customer);
+
+        push_section(
+            &mut document,
+            "Refunds",
+            self.refunds
+                .iter()
+                .map(|refund| {
+                    format!(
+                        "{}: {} {} on {}",
+                        refund.refund_id,
+                        format_amount(refund.refund_amount, Some(refund.currency)),
+                        refund.refund_status,
+                        refund.created_at
+                    )
+                })
+                .collect(),
+        );
+
+        document.join("\n\n")
+    }
+
+    fn into_submit_evidence_request(self) -> SubmitEvidenceRequest {
+        let uncategorized_text = self.render_document();
+        let access_activity_log = self.get_access_activity_log();
+        let product_description = self.get_product_description();
+        SubmitEvidenceRequest {
+            dispute_id: self.dispute.dispute_id.clone(),
+            access_activity_log,
+            billing_address: self.billing_address.as_ref().and_then(format_address),
+            customer_email_address: self.customer_email,
+            customer_name: self.customer_name,
+            customer_purchase_ip: self
+                .browser_info
+                .as_ref()
+                .and_then(|browser_info| browser_info.ip_address)
+                .map(|ip_address| ip_address.to_string()),
+            product_description,
+            service_date: Some(self.payment_intent.created_at.date().to_string()),
+            shipping_address: self.shipping_address.as_ref().and_then(format_address),
+            cancellation_policy: self.attached_files.cancellation_policy,
+            customer_communication: self.attached_files.customer_communication,
+            customer_signature: self.attached_files.customer_signature,
+            receipt: self.attached_files.receipt,
+            refund_policy: self.attached_files.refund_policy,
+            service_documentation: self.attached_files.service_documentation,
+            shipping_documentation: self.attached_files.shipping_documentation,
+            invoice_showing_distinct_transactions: self
+                .attached_files
+                .invoice_showing_distinct_transactions,
+            recurring_transaction_agreement: self.attached_files.recurring_transaction_agreement,
+            uncategorized_file: self.attached_files.uncategorized_file,
+            uncategorized_text: Some(uncategorized_text),
+            ..Default::default()
+        }
+    }
+}
+
+async fn find_payment_address(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+    address_id: Option<&String>,
+) -> Option<AddressDetails> {
+    let address_id = address_id?;
+    state
+        .store
+        .find_address_by_merchant_id_payment_id_address_id(
+            &state.into(),
+            merchant_account.get_id(),
+            payment_id,
+            address_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| logger::warn!(?error, "Failed to fetch address for dispute evidence"))
+        .ok()
+        .map(|payment_address| AddressDetails::from(payment_address.address))
+}
+
+/// Counts the successful payments the customer made before the given time and finds the
+/// earliest of them
+async fn find_customer_history(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+    before: PrimitiveDateTime,
+) -> Option<CustomerHistory> {
+    let db = &*state.store;
+    let constraints = |limit, order| {
+        PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset: 0,
+            starting_at: None,
+            ending_at: Some(before - time::Duration::microseconds(1)),
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: Some(vec![storage_enums::IntentStatus::Succeeded]),
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: None,
+            customer_id: Some(customer_id.clone()),
+            starting_after_id: None,
+            ending_before_id: None,
+            limit,
+            order,
+        }))
+    };
+
+    let successful_payment_count = db
+        .get_filtered_active_attempt_ids_for_total_count(
+            merchant_account.get_id(),
+            &constraints(None, Default::default()),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| logger::warn!(?error, "Failed to count the payments of the customer"))
+        .ok()?
+        .len();
+    if successful_payment_count == 0 {
+        return Some(CustomerHistory {
+            successful_payment_count,
+            first_payment_at: None,
+        });
+    }
+
+    let first_payment_at = db
+        .get_filtered_payment_intents_attempt(
+            &state.into(),
+            merchant_account.get_id(),
+            &constraints(
+                Some(1),
+                api_models::payments::Order {
+                    on: api_models::payments::SortOn::Created,
+                    by: api_models::payments::SortBy::Asc,
+                },
+            ),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| logger::warn!(?error, "Failed to fetch the first payment of the customer"))
+        .ok()
+        .and_then(|payments| payments.into_iter().next())
+        .map(|(payment_intent, _)| payment_intent.created_at);
+
+    Some(CustomerHistory {
+        successful_payment_count,
+        first_payment_at,
+    })
+}
+
+async fn collect_evidence_package(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: storage::Dispute,
+) -> RouterResult<EvidencePackage> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &dispute.payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            merchant_account.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let card_info = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|payment_method_data| match payment_method_data {
+            AdditionalPaymentData::Card(card_info) => Some(*card_info),
+            _ => None,
+        });
+    let browser_info = payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            browser_info
+                .parse_value::<BrowserInformation>("BrowserInformation")
+                .ok()
+        });
+
+    let authentication = match payment_attempt.authentication_id.clone() {
+        Some(authentication_id) => db
+            .find_authentication_by_merchant_id_authentication_id(
+                merchant_account.get_id(),
+                authentication_id,
+            )
+            .await
+            .map_err(|error| {
+                logger::warn!(
+                    ?error,
+                    "Failed to fetch authentication for dispute evidence"
+                )
+            })
+            .ok(),
+        None => None,
+    };
+
+    let billing_address = find_payment_address(
+        state,
+        merchant_account,
+        key_store,
+        &payment_intent.payment_id,
+        payment_intent.billing_address_id.as_ref(),
+    )
+    .await;
+    let shipping_address = find_payment_address(
+        state,
+        merchant_account,
+        key_store,
+        &payment_intent.payment_id,
+        payment_intent.shipping_address_id.as_ref(),
+    )
+    .await;
+
+    let (customer_name, customer_email, customer_history) =
+        match payment_intent.customer_id.as_ref() {
+            Some(customer_id) => {
+                let customer = db
+                    .find_customer_optional_by_customer_id_merchant_id(
+                        key_manager_state,
+                        customer_id,
+                        merchant_account.get_id(),
+                        key_store,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .map_err(|error| {
+                        logger::warn!(?error, "Failed to fetch customer for dispute evidence")
+                    })
+                    .ok()
+                    .flatten();
+                let customer_history = find_customer_history(
+                    state,
+                    merchant_account,
+                    key_store,
+                    customer_id,
+                    payment_intent.created_at,
+                )
+                .await;
+                (
+                    customer
+                        .as_ref()
+                        .and_then(|customer| customer.name.clone())
+                        .map(|name| name.into_inner().expose()),
+                    customer
+                        .as_ref()
+                        .and_then(|customer| customer.email.clone())
+                        .map(|email| email.into_inner().peek().to_owned()),
+                    customer_history,
+                )
+            }
+            None => (None, None, None),
+        };
+
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            merchant_account.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch refunds of the disputed payment")?;
+
+    let attached_files: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+
+    Ok(EvidencePackage {
+        dispute,
+        payment_intent,
+        payment_attempt,
+        card_info,
+        browser_info,
+        authentication,
+        billing_address,
+        shipping_address,
+        customer_name,
+        customer_email,
+        customer_history,
+        refunds,
+        attached_files,
+    })
+}
+
+async fn find_open_dispute(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<String>,
+    dispute_id: &str,
+) -> RouterResult<storage::Dispute> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.to_owned(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+    common_utils::fp_utils::when(
+        !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
+        || {
+            Err(errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                reason: format!(
+                    "Evidence cannot be assembled because the dispute is in {} stage and has {} status",
+                    dispute.dispute_stage, dispute.dispute_status
+                ),
+            })
+        },
+    )?;
+    Ok(dispute)
+}
+
+/// Assembles a draft of the evidence for a dispute from the data stored against the payment.
+/// Nothing is stored or uploaded, the draft is returned for review and is submitted through
+/// [`submit_evidence_draft`].
+#[instrument(skip_all)]
+pub async fn create_evidence_draft(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
+    key_store: domain::MerchantKeyStore,
+    req: disputes::DisputeId,
+) -> RouterResponse<DisputeEvidenceDraftResponse> {
+    let dispute = find_open_dispute(&state, &merchant_account, profile_id, &req.dispute_id).await?;
+    let dispute_id = dispute.dispute_id.clone();
+    let package = collect_evidence_package(&state, &merchant_account, &key_store, dispute).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        DisputeEvidenceDraftResponse {
+            dispute_id,
+            evidence: package.into_submit_evidence_request(),
+        },
+    ))
+}
+
+/// Submits a reviewed evidence draft. Unless the merchant has attached an uncategorized file of
+/// their own, the evidence document is rendered again from the current data and uploaded as the
+/// uncategorized file, so that the connector receives an up to date document.
+#[instrument(skip_all)]
+pub async fn submit_evidence_draft(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
+    key_store: domain::MerchantKeyStore,
+    mut req: SubmitEvidenceRequest,
+) -> RouterResponse<DisputeResponse> {
+    if req.uncategorized_file.is_none() {
+        let dispute = find_open_dispute(
+            &state,
+            &merchant_account,
+            profile_id.clone(),
+            &req.dispute_id,
+        )
+        .await?;
+        let package =
+            collect_evidence_package(&state, &merchant_account, &key_store, dispute).await?;
+        let document = package.render_document().into_bytes();
+        let create_file_request = api::CreateFileRequest {
+            file_size: i32::try_from(document.len())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Evidence document is too large")?,
+            file: document,
+            file_name: Some(format!("{}_evidence.txt", req.dispute_id)),
+            file_type: mime::TEXT_PLAIN,
+            purpose: api::FilePurpose::DisputeEvidence,
+            dispute_id: Some(req.dispute_id.clone()),
+        };
+        req.uncategorized_file = match Box::pin(files::files_create_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            create_file_request,
+        ))
+        .await
+        {
+            Ok(services::ApplicationResponse::Json(create_file_response)) => {
+                Some(create_file_response.file_id)
+            }
+            Ok(_) => None,
+            Err(error) => {
+                // Connectors may not accept plain text documents, the rendered document is still
+                // submitted as the additional evidence statement
+                logger::warn!(?error, "Failed to upload the evidence document");
+                None
+            }
+        };
+    }
+
+    Box::pin(super::submit_evidence(
+        state,
+        merchant_account,
+        profile_id,
+        key_store,
+        req,
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn dispute() -> storage::Dispute {
+        let now = common_utils::date_time::now();
+        storage::Dispute {
+            dispute_id: "dp_1".to_string(),
+            amount: "1050".to_string(),
+            currency: "USD".to_string(),
+            dispute_stage: storage_enums::DisputeStage::Dispute,
+            dispute_status: storage_enums::DisputeStatus::DisputeOpened,
+            payment_id: "pay_1".to_string(),
+            attempt_id: "pay_1_1".to_string(),
+            merchant_id: id_type::MerchantId::get_irrelevant_merchant_id(),
+            connector_status: "needs_response".to_string(),
+            connector_dispute_id: "dp_connector_1".to_string(),
+            connector_reason: Some("fraudulent".to_string()),
+            connector_reason_code: None,
+            challenge_required_by: None,
+            connector_created_at: None,
+            connector_updated_at: None,
+            created_at: now,
+            modified_at: now,
+            connector: "stripe".to_string(),
+            evidence: Secret::new(serde_json::json!({})),
+            profile_id: None,
+            merchant_connector_id: None,
+            dispute_amount: 1050,
+        }
+    }
+
+    fn refund() -> storage::Refund {
+        storage::Refund {
+            total_amount: MinorUnit::new(1050),
+            ..storage::refund::test_utils::get_refund(
+                "ref_1",
+                storage_enums::RefundStatus::Success,
+                500,
+            )
+        }
+    }
+
+    fn address() -> AddressDetails {
+        AddressDetails {
+            first_name: Some(Secret::new("John".to_string())),
+            last_name: Some(Secret::new("Doe".to_string())),
+            line1: Some(Secret::new("1 Main Street".to_string())),
+            city: Some("Berlin".to_string()),
+            zip: Some(Secret::new("10115".to_string())),
+            country: Some(api_models::enums::CountryAlpha2::DE),
+            ..Default::default()
+        }
+    }
+
+    fn package() -> EvidencePackage {
+        EvidencePackage {
+            dispute: dispute(),
+            payment_intent: storage::PaymentIntent {
+                payment_id: "pay_1".to_string(),
+                merchant_id: id_type::MerchantId::get_irrelevant_merchant_id(),
+                status: storage_enums::IntentStatus::Succeeded,
+                amount: MinorUnit::new(1050),
+                currency: Some(storage_enums::Currency::USD),
+                amount_captured: Some(MinorUnit::new(1050)),
+                customer_id: None,
+                description: Some("Running shoes".to_string()),
+                return_url: None,
+                metadata: None,
+                connector_id: None,
+                shipping_address_id: None,
+                billing_address_id: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                created_at: datetime!(2024-01-01 0:00),
+                modified_at: datetime!(2024-01-01 0:00),
+                last_synced: None,
+                setup_future_usage: None,
+                fingerprint_id: None,
+                off_session: None,
+                client_secret: None,
+                active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
+                    "pay_1_1".to_string(),
+                ),
+                business_country: None,
+                business_label: None,
+                order_details: None,
+                allowed_payment_method_types: None,
+                connector_metadata: None,
+                feature_metadata: None,
+                attempt_count: 1,
+                payment_link_id: None,
+                profile_id: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
+                request_incremental_authorization: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                request_external_three_ds_authentication: None,
+                charges: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                l2_l3_data: None,
+                split_payments: None,
+            },
+            payment_attempt: serde_json::from_value(serde_json::json!({
+                "payment_id": "pay_1",
+                "merchant_id": "merchant_1",
+                "attempt_id": "pay_1_1",
+                "status": "charged",
+                "amount": 1050,
+                "currency": "USD",
+                "connector_transaction_id": "txn_1",
+                "confirm": true,
+                "created_at": "2024-01-01T00:00:00.000Z",
+                "modified_at": "2024-01-01T00:00:00.000Z",
+                "amount_capturable": 0,
+                "updated_by": "postgres_only",
+            }))
+            .unwrap(),
+            card_info: None,
+            browser_info: Some(
+                serde_json::from_value(serde_json::json!({
+                    "ip_address": "127.0.0.1",
+                    "user_agent": "Mozilla/5.0",
+                }))
+                .unwrap(),
+            ),
+            authentication: None,
+            billing_address: Some(address()),
+            shipping_address: None,
+            customer_name: Some("John Doe".to_string()),
+            customer_email: Some("john@example.com".to_string()),
+            customer_history: Some(CustomerHistory {
+                successful_payment_count: 25,
+                first_payment_at: None,
+            }),
+            refunds: vec![refund()],
+            attached_files: api::DisputeEvidence {
+                receipt: Some("file_receipt".to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_format_amount_uses_the_base_unit_of_the_currency() {
+        assert_eq!(
+            format_amount(MinorUnit::new(1050), Some(storage_enums::Currency::USD)),
+            "10.50 USD"
+        );
+        assert_eq!(
+            format_amount(MinorUnit::new(1050), Some(storage_enums::Currency::JPY)),
+            "1050 JPY"
+        );
+        assert_eq!(
+            format_amount(MinorUnit::new(1050), Some(storage_enums::Currency::BHD)),
+            "1.050 BHD"
+        );
+        assert_eq!(format_amount(MinorUnit::new(1050), None), "1050");
+    }
+
+    #[test]
+    fn test_format_address_joins_the_present_components() {
+        assert_eq!(
+            format_address(&address()).as_deref(),
+            Some("John Doe, 1 Main Street, Berlin, 10115, DE")
+        );
+        assert_eq!(format_address(&AddressDetails::default()), None);
+    }
+
+    #[test]
+    fn test_render_document_includes_the_sections_with_data() {
+        let document = package().render_document();
+
+        assert!(document.starts_with("Evidence for dispute dp_1 on payment pay_1"));
+        assert!(document.contains("Dispute\n-------\n\nAmount: 10.50 USD\n"));
+        assert!(document.contains("Reason: fraudulent"));
+        assert!(document.contains("Payment\n-------\n\nAmount: 10.50 USD\n"));
+        assert!(document.contains("Connector transaction id: txn_1"));
+        assert!(document.contains("Description: Running shoes"));
+        assert!(document.contains("from IP address 127.0.0.1 using Mozilla/5.0"));
+        assert!(document.contains("Billing address: John Doe, 1 Main Street, Berlin, 10115, DE"));
+        assert!(document.contains("Previous successful payments: 25"));
+        assert!(!document.contains("Customer since"));
+        assert!(document.contains(&format!(
+            "ref_1: 5.00 USD {}",
+            storage_enums::RefundStatus::Success
+        )));
+        // No authentication was performed and no shipping address was provided
+        assert!(!document.contains("Authentication"));
+        assert!(!document.contains("Shipping address"));
+    }
+
+    #[test]
+    fn test_into_submit_evidence_request_keeps_the_attached_files() {
+        let package = package();
+        let document = package.render_document();
+        let request = package.into_submit_evidence_request();
+
+        assert_eq!(request.dispute_id, "dp_1");
+        assert_eq!(request.receipt.as_deref(), Some("file_receipt"));
+        assert_eq!(request.uncategorized_file, None);
+        assert_eq!(request.uncategorized_text, Some(document));
+        assert_eq!(request.customer_purchase_ip.as_deref(), Some("127.0.0.1"));
+        assert_eq!(request.customer_name.as_deref(), Some("John Doe"));
+        assert_eq!(
+            request.billing_address.as_deref(),
+            Some("John Doe, 1 Main Street, Berlin, 10115, DE")
+        );
+        assert_eq!(request.shipping_address, None);
+        assert_eq!(
+            request.product_description.as_deref(),
+            Some("Running shoes")
+        );
+        assert_eq!(request.service_date.as_deref(), Some("2024-01-01"));
+    }
+}
@@ -88,11 +88,19 @@ pub enum UserErrors {
     AuthConfigParsingError,
     #[error("Invalid SSO request")]
     SSOFailed,
+    #[error("SCIM resource not found")]
+    ScimResourceNotFound,
+    #[error("Invalid SCIM request: {0}")]
+    InvalidScimRequest(String),
+    #[error("SCIM operation not supported")]
+    ScimOperationNotSupported,
+    #[error("Invalid SCIM filter: {0}")]
+    ScimInvalidFilter(String),
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
     fn switch(&self) -> api_models::errors::types::ApiErrorResponse {
-        use api_models::errors::types::{ApiError, ApiErrorResponse as AER};
+        use api_models::errors::types::{ApiError, ApiErrorResponse as AER, Extra};
         let sub_code = "UR";
         match self {
             Self::InternalServerError => {
@@ -224,6 +232,24 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::SSOFailed => {
                 AER::BadRequest(ApiError::new(sub_code, 46, self.get_error_message(), None))
             }
+            Self::ScimResourceNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 47, self.get_error_message(), None))
+            }
+            Self::InvalidScimRequest(_) => {
+                AER::BadRequest(ApiError::new(sub_code, 48, self.get_error_message(), None))
+            }
+            Self::ScimOperationNotSupported => {
+                AER::NotImplemented(ApiError::new(sub_code, 49, self.get_error_message(), None))
+            }
+            Self::ScimInvalidFilter(_) => AER::BadRequest(ApiError::new(
+                sub_code,
+                50,
+                self.get_error_message(),
+                Some(Extra {
+                    reason: Some(api_models::scim::SCIM_INVALID_FILTER_ERROR.to_string()),
+                    ..Default::default()
+                }),
+            )),
         }
     }
 }
@@ -271,6 +297,10 @@ impl UserErrors {
             Self::InvalidUserAuthMethodOperation => "Invalid user auth method operation",
             Self::AuthConfigParsingError => "Auth config parsing error",
             Self::SSOFailed => "Invalid SSO request",
+            Self::ScimResourceNotFound => "SCIM resource not found",
+            Self::InvalidScimRequest(error_message) => error_message,
+            Self::ScimOperationNotSupported => "SCIM operation not supported",
+            Self::ScimInvalidFilter(error_message) => error_message,
         }
     }
 }
@@ -44,6 +44,7 @@ use crate::{
     utils::ValueExt,
 };
 pub mod flows;
+pub mod native;
 pub mod operation;
 pub mod types;
 
@@ -105,16 +106,24 @@ where
         frm_data.fraud_check.last_step = FraudCheckLastStep::TransactionOrRecordRefund
     }
 
-    let connector =
-        FraudCheckConnectorData::get_connector_by_name(&frm_data.connector_details.connector_name)?;
-    let router_data_res = router_data
-        .decide_frm_flows(
-            state,
-            &connector,
-            payments::CallConnectorAction::Trigger,
-            merchant_account,
-        )
-        .await?;
+    let router_data_res = if matches!(
+        api_enums::convert_frm_connector(&frm_data.connector_details.connector_name),
+        Some(api_enums::FrmConnectors::NativeFrm)
+    ) {
+        native::decide_frm(state, router_data, payment_data, frm_data).await?
+    } else {
+        let connector = FraudCheckConnectorData::get_connector_by_name(
+            &frm_data.connector_details.connector_name,
+        )?;
+        router_data
+            .decide_frm_flows(
+                state,
+                &connector,
+                payments::CallConnectorAction::Trigger,
+                merchant_account,
+            )
+            .await?
+    };
 
     Ok(router_data_res)
 }
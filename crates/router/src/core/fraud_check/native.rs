@@ -0,0 +1,674 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::{
+    fraud_check::{
+        FrmMatchedRule, FrmReviewQueueConstraints, FrmReviewQueueItem, FrmReviewQueueResponse,
+        FrmRule, FrmRuleCondition, FrmRuleDecision, FrmRuleLhs, FrmRuleValue, FrmRulesRecord,
+        FrmRulesRequest, FrmVelocityDimension, NativeFrmReason,
+    },
+    payments::BrowserInformation,
+};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    ext_traits::{Encode, StringExt, ValueExt},
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use euclid::frontend::ast::ComparisonType;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use super::types::FrmData;
+use crate::{
+    consts,
+    core::{
+        blocklist::utils as blocklist_utils,
+        errors::{self, RouterResponse, RouterResult},
+        payments,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        self as oss_types, domain, fraud_check as frm_types, storage::enums::FraudCheckStatus,
+        transformers::ForeignFrom, ResponseId,
+    },
+};
+
+/// Attributes of a payment which the native fraud rules are evaluated against
+struct NativeFrmInput {
+    amount: i64,
+    currency: Option<String>,
+    payment_method: Option<String>,
+    billing_country: Option<String>,
+    shipping_country: Option<String>,
+    identifiers: HashMap<FrmVelocityDimension, String>,
+}
+
+impl NativeFrmInput {
+    async fn collect<D: Clone>(
+        state: &SessionState,
+        payment_data: &payments::PaymentData<D>,
+        frm_data: &FrmData,
+    ) -> Self {
+        let payment_attempt = &frm_data.payment_attempt;
+        let mut identifiers = HashMap::new();
+
+        let card_fingerprint = match payment_data.payment_attempt.fingerprint_id.clone() {
+            Some(fingerprint_id) => Some(fingerprint_id),
+            None => blocklist_utils::generate_payment_fingerprint(
+                state,
+                frm_data.merchant_account.get_id().clone(),
+                payment_data.payment_method_data.clone(),
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to fingerprint payment method"))
+            .ok()
+            .flatten(),
+        };
+        let email = payment_data
+            .email
+            .as_ref()
+            .map(|email| email.peek().to_lowercase());
+        let ip_address = payment_data
+            .payment_attempt
+            .browser_info
+            .clone()
+            .and_then(|browser_info| {
+                browser_info
+                    .parse_value::<BrowserInformation>("BrowserInformation")
+                    .ok()
+            })
+            .and_then(|browser_info| browser_info.ip_address)
+            .map(|ip_address| ip_address.to_string());
+        let customer = frm_data
+            .payment_intent
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_owned());
+        let device = frm_data.frm_metadata.as_ref().and_then(|metadata| {
+            metadata
+                .peek()
+                .get("device_id")
+                .and_then(serde_json::Value::as_str)
+                .map(ToOwned::to_owned)
+        });
+
+        for (dimension, value) in [
+            (FrmVelocityDimension::CardFingerprint, card_fingerprint),
+            (FrmVelocityDimension::Email, email),
+            (FrmVelocityDimension::IpAddress, ip_address),
+            (FrmVelocityDimension::Customer, customer),
+            (FrmVelocityDimension::Device, device),
+        ] {
+            if let Some(value) = value {
+                identifiers.insert(dimension, value);
+            }
+        }
+
+        let country_of = |address: Option<&api_models::payments::Address>| {
+            address
+                .and_then(|address| address.address.as_ref())
+                .and_then(|details| details.country)
+                .map(|country| country.to_string())
+        };
+
+        Self {
+            amount: payment_attempt.amount.get_amount_as_i64(),
+            currency: payment_attempt
+                .currency
+                .map(|currency| currency.to_string()),
+            payment_method: payment_attempt
+                .payment_method
+                .map(|payment_method| payment_method.to_string()),
+            billing_country: country_of(
+                frm_data
+                    .address
+                    .get_payment_method_billing()
+                    .or(frm_data.address.get_payment_billing()),
+            ),
+            shipping_country: country_of(frm_data.address.get_shipping()),
+            identifiers,
+        }
+    }
+
+    fn get_string_field(&self, lhs: &FrmRuleLhs) -> Option<&str> {
+        match lhs {
+            FrmRuleLhs::Currency => self.currency.as_deref(),
+            FrmRuleLhs::PaymentMethod => self.payment_method.as_deref(),
+            FrmRuleLhs::BillingCountry => self.billing_country.as_deref(),
+            FrmRuleLhs::ShippingCountry => self.shipping_country.as_deref(),
+            FrmRuleLhs::Amount | FrmRuleLhs::Velocity { .. } => None,
+        }
+    }
+}
+
+type VelocityCounts = HashMap<(FrmVelocityDimension, i64), i64>;
+
+/// Counts the current payment against every velocity window referenced by the rules and returns
+/// the updated counts. Counters use fixed windows, so a window of an hour counts payments since
+/// the start of the current hour bucket. Counting failures are logged and the affected velocity
+/// conditions do not match, so that an unavailable redis does not block payments.
+async fn record_velocity(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    rules: &[FrmRule],
+    identifiers: &HashMap<FrmVelocityDimension, String>,
+) -> VelocityCounts {
+    let mut counts = VelocityCounts::new();
+    let windows = rules
+        .iter()
+        .flat_map(|rule| rule.conditions.iter())
+        .filter_map(|condition| match condition.lhs {
+            FrmRuleLhs::Velocity {
+                dimension,
+                window_in_seconds,
+            } => Some((dimension, window_in_seconds)),
+            _ => None,
+        })
+        // Windows out of range are rejected when the rules are saved, rules stored before the
+        // limit was enforced are skipped
+        .filter(|(_, window_in_seconds)| is_valid_velocity_window(*window_in_seconds))
+        .collect::<HashSet<_>>();
+    if windows.is_empty() {
+        return counts;
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for FRM velocity");
+            return counts;
+        }
+    };
+    let now = common_utils::date_time::now_unix_timestamp();
+
+    for (dimension, window_in_seconds) in windows {
+        let Some(value) = identifiers.get(&dimension) else {
+            continue;
+        };
+        let digest = match crypto::Sha256.generate_digest(value.as_bytes()) {
+            Ok(digest) => hex::encode(digest),
+            Err(error) => {
+                logger::error!(?error, "Failed to hash FRM velocity identifier");
+                continue;
+            }
+        };
+        let key = format!(
+            "{}_{}_{dimension}_{window_in_seconds}_{digest}",
+            consts::NATIVE_FRM_VELOCITY_PREFIX,
+            merchant_id.get_string_repr(),
+        );
+        let bucket = (now / window_in_seconds).to_string();
+
+        let count = redis_conn.increment_field_in_hash(&key, &bucket, 1).await;
+        match count {
+            Ok(count) => {
+                if let Err(error) = redis_conn
+                    .set_expiry(&key, window_in_seconds.saturating_mul(2))
+                    .await
+                {
+                    logger::error!(?error, "Failed to set expiry on FRM velocity counter");
+                }
+                counts.insert(
+                    (dimension, window_in_seconds),
+                    i64::try_from(count).unwrap_or(i64::MAX),
+                );
+            }
+            Err(error) => logger::error!(?error, "Failed to increment FRM velocity counter"),
+        }
+    }
+
+    counts
+}
+
+fn compare_numbers(lhs: i64, comparison: &ComparisonType, rhs: i64) -> bool {
+    match comparison {
+        ComparisonType::Equal => lhs == rhs,
+        ComparisonType::NotEqual => lhs != rhs,
+        ComparisonType::LessThan => lhs < rhs,
+        ComparisonType::LessThanEqual => lhs <= rhs,
+        ComparisonType::GreaterThan => lhs > rhs,
+        ComparisonType::GreaterThanEqual => lhs >= rhs,
+    }
+}
+
+fn evaluate_condition(
+    condition: &FrmRuleCondition,
+    input: &NativeFrmInput,
+    velocity: &VelocityCounts,
+) -> bool {
+    match (&condition.lhs, &condition.value) {
+        (FrmRuleLhs::Amount, FrmRuleValue::Number(number)) => {
+            compare_numbers(input.amount, &condition.comparison, *number)
+        }
+        (
+            FrmRuleLhs::Velocity {
+                dimension,
+                window_in_seconds,
+            },
+            FrmRuleValue::Number(number),
+        ) => velocity
+            .get(&(*dimension, *window_in_seconds))
+            .is_some_and(|count| compare_numbers(*count, &condition.comparison, *number)),
+        (lhs, FrmRuleValue::String(value)) => {
+            input
+                .get_string_field(lhs)
+                .is_some_and(|field| match condition.comparison {
+                    ComparisonType::Equal => field.eq_ignore_ascii_case(value),
+                    ComparisonType::NotEqual => !field.eq_ignore_ascii_case(value),
+                    _ => false,
+                })
+        }
+        (_, FrmRuleValue::Number(_)) => false,
+    }
+}
+
+/// Evaluates all the rules, the most severe decision among the matched rules wins and the
+/// scores of all the matched rules are added up
+fn evaluate_rules(
+    rules: &[FrmRule],
+    input: &NativeFrmInput,
+    velocity: &VelocityCounts,
+) -> (NativeFrmReason, i32) {
+    let matched = rules
+        .iter()
+        .filter(|rule| {
+            rule.conditions
+                .iter()
+                .all(|condition| evaluate_condition(condition, input, velocity))
+        })
+        .collect::<Vec<_>>();
+
+    let decision = matched
+        .iter()
+        .map(|rule| rule.decision)
+        .max()
+        .unwrap_or(FrmRuleDecision::Accept);
+    let score = matched
+        .iter()
+        .filter_map(|rule| rule.score)
+        .fold(0i32, i32::saturating_add);
+    let matched_rules = matched
+        .into_iter()
+        .map(|rule| FrmMatchedRule {
+            name: rule.name.clone(),
+            decision: rule.decision,
+            reason: rule.reason.clone(),
+        })
+        .collect();
+
+    (
+        NativeFrmReason {
+            decision,
+            matched_rules,
+        },
+        score,
+    )
+}
+
+impl ForeignFrom<FrmRuleDecision> for FraudCheckStatus {
+    fn foreign_from(decision: FrmRuleDecision) -> Self {
+        match decision {
+            FrmRuleDecision::Accept => Self::Legit,
+            FrmRuleDecision::Review => Self::ManualReview,
+            FrmRuleDecision::Decline => Self::Fraud,
+        }
+    }
+}
+
+/// Decides the fraud check in-process instead of calling an external FRM connector. An attempt
+/// is evaluated only once, the later flows of the same attempt reuse the recorded decision.
+#[instrument(skip_all)]
+pub async fn decide_frm<D: Clone, F, Req>(
+    state: &SessionState,
+    mut router_data: oss_types::RouterData<F, Req, frm_types::FraudCheckResponseData>,
+    payment_data: &payments::PaymentData<D>,
+    frm_data: &FrmData,
+) -> RouterResult<oss_types::RouterData<F, Req, frm_types::FraudCheckResponseData>> {
+    let response = if frm_data.refund.is_some() {
+        frm_types::FraudCheckResponseData::RecordReturnResponse {
+            resource_id: ResponseId::NoResponseId,
+            connector_metadata: None,
+            return_id: None,
+        }
+    } else if frm_data.fraud_check.frm_status != FraudCheckStatus::Pending {
+        frm_types::FraudCheckResponseData::TransactionResponse {
+            resource_id: ResponseId::NoResponseId,
+            status: frm_data.fraud_check.frm_status,
+            connector_metadata: None,
+            reason: frm_data.fraud_check.frm_reason.clone(),
+            score: frm_data.fraud_check.frm_score,
+        }
+    } else {
+        let merchant_id = frm_data.merchant_account.get_id();
+        let rules = find_native_frm_rules(state, merchant_id)
+            .await?
+            .map(|record| record.rules)
+            .unwrap_or_default();
+        let input = NativeFrmInput::collect(state, payment_data, frm_data).await;
+        let velocity = record_velocity(state, merchant_id, &rules, &input.identifiers).await;
+        let (reason, score) = evaluate_rules(&rules, &input, &velocity);
+
+        logger::info!(
+            decision = %reason.decision,
+            matched_rules = reason.matched_rules.len(),
+            "Native FRM decision"
+        );
+
+        frm_types::FraudCheckResponseData::TransactionResponse {
+            resource_id: ResponseId::NoResponseId,
+            status: FraudCheckStatus::foreign_from(reason.decision),
+            connector_metadata: None,
+            reason: Some(
+                reason
+                    .encode_to_value()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to encode native FRM reason")?,
+            ),
+            score: Some(score),
+        }
+    };
+
+    router_data.response = Ok(response);
+    Ok(router_data)
+}
+
+async fn find_native_frm_rules(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<Option<FrmRulesRecord>> {
+    match state
+        .store
+        .find_config_by_key(&merchant_id.get_native_frm_rules_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("FrmRulesRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse native FRM rules")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch native FRM rules")),
+    }
+}
+
+fn is_valid_velocity_window(window_in_seconds: i64) -> bool {
+    (1..=consts::NATIVE_FRM_MAX_VELOCITY_WINDOW_IN_SECONDS).contains(&window_in_seconds)
+}
+
+fn validate_rules(rules: &[FrmRule]) -> RouterResult<()> {
+    let mut names = HashSet::new();
+    for rule in rules {
+        if !names.insert(rule.name.as_str()) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate FRM rule name: {}", rule.name),
+            })?
+        }
+        for condition in &rule.conditions {
+            if let FrmRuleLhs::Velocity {
+                window_in_seconds, ..
+            } = condition.lhs
+            {
+                if !is_valid_velocity_window(window_in_seconds) {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "Velocity window must be between 1 and {} seconds in FRM rule {}",
+                            consts::NATIVE_FRM_MAX_VELOCITY_WINDOW_IN_SECONDS,
+                            rule.name
+                        ),
+                    })?
+                }
+            }
+            let is_valid = match condition.value {
+                FrmRuleValue::Number(_) => condition.lhs.is_numeric(),
+                FrmRuleValue::String(_) => {
+                    !condition.lhs.is_numeric()
+                        && matches!(
+                            condition.comparison,
+                            ComparisonType::Equal | ComparisonType::NotEqual
+                        )
+                }
+            };
+            if !is_valid {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Invalid comparison in FRM rule {}", rule.name),
+                })?
+            }
+        }
+    }
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_native_frm_rules(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: FrmRulesRequest,
+) -> RouterResponse<FrmRulesRecord> {
+    validate_rules(&request.rules)?;
+
+    let db = state.store.as_ref();
+    let key = merchant_account.get_id().get_native_frm_rules_key();
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let existing_record = find_native_frm_rules(&state, merchant_account.get_id()).await?;
+
+    let record = FrmRulesRecord {
+        rules: request.rules,
+        created_at: existing_record
+            .as_ref()
+            .map_or(timestamp, |record| record.created_at),
+        modified_at: timestamp,
+    };
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize native FRM rules")?;
+
+    match existing_record {
+        Some(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update native FRM rules")?,
+        None => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert native FRM rules")?,
+    };
+
+    Ok(services::ApplicationResponse::Json(record))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_native_frm_rules(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<FrmRulesRecord> {
+    find_native_frm_rules(&state, merchant_account.get_id())
+        .await?
+        .ok_or(
+            errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Native FRM rules not found".to_string(),
+            }
+            .into(),
+        )
+        .map(services::ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn delete_native_frm_rules(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    let key = merchant_account.get_id().get_native_frm_rules_key();
+    find_native_frm_rules(&state, merchant_account.get_id())
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Native FRM rules not found".to_string(),
+        })?;
+
+    state
+        .store
+        .delete_config_by_key(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete native FRM rules")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Lists the fraud checks awaiting a manual review. Reviewed payments are resolved through the
+/// payments approve and reject endpoints.
+#[instrument(skip_all)]
+pub async fn list_review_queue(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: FrmReviewQueueConstraints,
+) -> RouterResponse<FrmReviewQueueResponse> {
+    let fraud_checks = state
+        .store
+        .list_fraud_checks_by_merchant_id_frm_status(
+            merchant_account.get_id(),
+            FraudCheckStatus::ManualReview,
+            constraints
+                .limit
+                .unwrap_or(consts::DEFAULT_FRM_REVIEW_QUEUE_LIMIT),
+            constraints.offset.unwrap_or(0),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list fraud checks under manual review")?;
+
+    let data = fraud_checks
+        .into_iter()
+        .map(|fraud_check| FrmReviewQueueItem {
+            payment_id: fraud_check.payment_id,
+            attempt_id: fraud_check.attempt_id,
+            frm_name: fraud_check.frm_name,
+            frm_status: fraud_check.frm_status,
+            frm_score: fraud_check.frm_score,
+            frm_reason: fraud_check.frm_reason,
+            created_at: fraud_check.created_at,
+            modified_at: fraud_check.modified_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        FrmReviewQueueResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn input() -> NativeFrmInput {
+        NativeFrmInput {
+            amount: 50000,
+            currency: Some("USD".to_string()),
+            payment_method: Some("card".to_string()),
+            billing_country: Some("US".to_string()),
+            shipping_country: Some("GB".to_string()),
+            identifiers: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_most_severe_decision_wins() {
+        let rules: Vec<FrmRule> = serde_json::from_value(serde_json::json!([
+            {
+                "name": "high_amount",
+                "conditions": [{ "field": "amount", "comparison": "greater_than", "value": 10000 }],
+                "decision": "review",
+                "reason": "High value payment",
+                "score": 40
+            },
+            {
+                "name": "email_velocity",
+                "conditions": [{
+                    "field": "velocity",
+                    "dimension": "email",
+                    "window_in_seconds": 3600,
+                    "comparison": "greater_than_equal",
+                    "value": 3
+                }],
+                "decision": "decline",
+                "reason": "Too many payments from the same email",
+                "score": 50
+            },
+            {
+                "name": "domestic",
+                "conditions": [{ "field": "shipping_country", "comparison": "equal", "value": "us" }],
+                "decision": "accept",
+                "reason": "Domestic shipping"
+            }
+        ]))
+        .unwrap();
+        assert!(validate_rules(&rules).is_ok());
+
+        let (reason, score) = evaluate_rules(&rules, &input(), &VelocityCounts::new());
+        assert_eq!(reason.decision, FrmRuleDecision::Review);
+        assert_eq!(score, 40);
+
+        let velocity = VelocityCounts::from([((FrmVelocityDimension::Email, 3600), 3)]);
+        let (reason, score) = evaluate_rules(&rules, &input(), &velocity);
+        assert_eq!(reason.decision, FrmRuleDecision::Decline);
+        assert_eq!(reason.matched_rules.len(), 2);
+        assert_eq!(score, 90);
+    }
+
+    #[test]
+    fn test_invalid_comparison_is_rejected() {
+        let rules: Vec<FrmRule> = serde_json::from_value(serde_json::json!([{
+            "name": "currency",
+            "conditions": [{ "field": "currency", "comparison": "greater_than", "value": "USD" }],
+            "decision": "decline",
+            "reason": "Unsupported"
+        }]))
+        .unwrap();
+        assert!(validate_rules(&rules).is_err());
+    }
+
+    #[test]
+    fn test_velocity_window_out_of_range_is_rejected() {
+        let rules_with_window = |window_in_seconds: i64| -> Vec<FrmRule> {
+            serde_json::from_value(serde_json::json!([{
+                "name": "email_velocity",
+                "conditions": [{
+                    "field": "velocity",
+                    "dimension": "email",
+                    "window_in_seconds": window_in_seconds,
+                    "comparison": "greater_than_equal",
+                    "value": 3
+                }],
+                "decision": "decline",
+                "reason": "Too many payments from the same email"
+            }]))
+            .unwrap()
+        };
+
+        assert!(validate_rules(&rules_with_window(0)).is_err());
+        assert!(validate_rules(&rules_with_window(
+            consts::NATIVE_FRM_MAX_VELOCITY_WINDOW_IN_SECONDS
+        ))
+        .is_ok());
+        assert!(validate_rules(&rules_with_window(
+            consts::NATIVE_FRM_MAX_VELOCITY_WINDOW_IN_SECONDS + 1
+        ))
+        .is_err());
+        assert!(validate_rules(&rules_with_window(i64::MAX)).is_err());
+    }
+}
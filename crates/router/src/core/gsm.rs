@@ -1,3 +1,6 @@
+pub mod bulk;
+pub mod history;
+
 use api_models::gsm as gsm_api_types;
 use diesel_models::gsm as storage;
 use error_stack::ResultExt;
@@ -20,12 +23,24 @@ pub async fn create_gsm_rule(
     gsm_rule: gsm_api_types::GsmCreateRequest,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
     let db = state.store.as_ref();
-    GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
+    let gsm = GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
         .await
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
             message: "GSM with given key already exists in our records".to_string(),
-        })
-        .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+        })?;
+
+    history::record_gsm_rule_change_or_log(
+        &state,
+        gsm_api_types::GsmChangeSource::Create,
+        history::GsmRuleChange {
+            key: history::GsmRuleKey::from(&gsm),
+            previous: None,
+            current: Some(storage::GatewayStatusMapAttributes::from(&gsm)),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm.foreign_into()))
 }
 
 #[instrument(skip_all)]
@@ -68,7 +83,19 @@ pub async fn update_gsm_rule(
         unified_code,
         unified_message,
     } = gsm_request;
-    GsmInterface::update_gsm_rule(
+    let previous = history::GsmRuleKey {
+        connector: connector.clone(),
+        flow: flow.clone(),
+        sub_flow: sub_flow.clone(),
+        code: code.clone(),
+        message: message.clone(),
+    }
+    .find_rule(db)
+    .await?
+    .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "GSM with given key does not exist in our records".to_string(),
+    })?;
+    let gsm = GsmInterface::update_gsm_rule(
         db,
         connector.to_string(),
         flow,
@@ -80,16 +107,28 @@ pub async fn update_gsm_rule(
             status,
             router_error: Some(router_error),
             step_up_possible,
-            unified_code,
-            unified_message,
+            unified_code: unified_code.map(Some),
+            unified_message: unified_message.map(Some),
         },
     )
     .await
     .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
         message: "GSM with given key does not exist in our records".to_string(),
     })
-    .attach_printable("Failed while updating Gsm rule")
-    .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+    .attach_printable("Failed while updating Gsm rule")?;
+
+    history::record_gsm_rule_change_or_log(
+        &state,
+        gsm_api_types::GsmChangeSource::Update,
+        history::GsmRuleChange {
+            key: history::GsmRuleKey::from(&gsm),
+            previous: Some(storage::GatewayStatusMapAttributes::from(&previous)),
+            current: Some(storage::GatewayStatusMapAttributes::from(&gsm)),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm.foreign_into()))
 }
 
 #[instrument(skip_all)]
@@ -105,6 +144,14 @@ pub async fn delete_gsm_rule(
         code,
         message,
     } = gsm_request;
+    let key = history::GsmRuleKey {
+        connector: connector.clone(),
+        flow: flow.clone(),
+        sub_flow: sub_flow.clone(),
+        code: code.clone(),
+        message: message.clone(),
+    };
+    let previous = key.find_rule(db).await?;
     match GsmInterface::delete_gsm_rule(
         db,
         connector.to_string(),
@@ -121,6 +168,18 @@ pub async fn delete_gsm_rule(
     {
         Ok(is_deleted) => {
             if is_deleted {
+                if let Some(previous) = previous {
+                    history::record_gsm_rule_change_or_log(
+                        &state,
+                        gsm_api_types::GsmChangeSource::Delete,
+                        history::GsmRuleChange {
+                            key,
+                            previous: Some(storage::GatewayStatusMapAttributes::from(&previous)),
+                            current: None,
+                        },
+                    )
+                    .await;
+                }
                 Ok(services::ApplicationResponse::Json(
                     gsm_api_types::GsmDeleteResponse {
                         gsm_rule_delete: true,
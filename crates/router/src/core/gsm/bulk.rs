@@ -0,0 +1,456 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::gsm as gsm_api_types;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::history::{self, GsmRuleChange, GsmRuleKey};
+use crate::{
+    core::{
+        bulk_import,
+        errors::{self, RouterResponse, RouterResult},
+    },
+    db::StorageInterface,
+    services::ApplicationResponse,
+    types::{storage, transformers::ForeignFrom},
+    SessionState,
+};
+
+const MAX_GSM_IMPORT_SIZE: usize = 10000;
+
+#[derive(Debug, MultipartForm)]
+pub struct GsmImportForm {
+    #[multipart(limit = "5MB")]
+    pub file: Bytes,
+    pub dry_run: Option<Text<bool>>,
+    pub description: Option<Text<String>>,
+}
+
+fn parse_csv(data: &[u8]) -> csv::Result<Vec<gsm_api_types::GsmCreateRequest>> {
+    let mut csv_reader = csv::Reader::from_reader(data);
+    csv_reader.deserialize().collect()
+}
+
+pub fn get_gsm_import_request(
+    form: GsmImportForm,
+) -> Result<gsm_api_types::GsmBulkImportRequest, errors::ApiErrorResponse> {
+    let rules = parse_csv(&form.file.data).map_err(|error| {
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Failed to parse the uploaded file: {error}"),
+        }
+    })?;
+
+    Ok(gsm_api_types::GsmBulkImportRequest {
+        rules,
+        dry_run: form.dry_run.map(Text::into_inner).unwrap_or_default(),
+        description: form.description.map(Text::into_inner),
+    })
+}
+
+/// Checks a rule against the column limits of `gateway_status_map`, so that a bad row is
+/// reported before anything is written instead of failing halfway through the import.
+fn validate_gsm_rule(rule: &storage::GatewayStatusMappingNew) -> Result<(), String> {
+    let required_fields = [
+        ("flow", &rule.flow),
+        ("sub_flow", &rule.sub_flow),
+        ("status", &rule.status),
+    ];
+    if let Some((field, _)) = required_fields.iter().find(|(_, value)| value.is_empty()) {
+        return Err(format!("`{field}` must not be empty"));
+    }
+
+    let bounded_fields = [
+        ("flow", Some(&rule.flow), 64),
+        ("sub_flow", Some(&rule.sub_flow), 64),
+        ("code", Some(&rule.code), 255),
+        ("message", Some(&rule.message), 1024),
+        ("status", Some(&rule.status), 64),
+        ("router_error", rule.router_error.as_ref(), 64),
+        ("unified_code", rule.unified_code.as_ref(), 255),
+        ("unified_message", rule.unified_message.as_ref(), 1024),
+    ];
+    for (field, value, max_length) in bounded_fields {
+        if value.is_some_and(|value| value.len() > max_length) {
+            return Err(format!(
+                "`{field}` must be at most {max_length} characters long"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn import_error(
+    row_number: usize,
+    rule: &storage::GatewayStatusMappingNew,
+    reason: String,
+) -> gsm_api_types::GsmImportError {
+    gsm_api_types::GsmImportError {
+        row_number,
+        connector: rule.connector.clone(),
+        flow: rule.flow.clone(),
+        sub_flow: rule.sub_flow.clone(),
+        code: rule.code.clone(),
+        reason,
+    }
+}
+
+/// A rule of the import along with the rule it replaces, if any.
+struct PlannedGsmRule {
+    rule: storage::GatewayStatusMappingNew,
+    existing: Option<storage::GatewayStatusMap>,
+}
+
+impl bulk_import::PlannedImportRecord for PlannedGsmRule {
+    fn is_new(&self) -> bool {
+        self.existing.is_none()
+    }
+
+    fn is_unchanged(&self) -> bool {
+        self.existing.as_ref().is_some_and(|existing| {
+            storage::GatewayStatusMapAttributes::from(existing)
+                == storage::GatewayStatusMapAttributes::from(&self.rule)
+        })
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn import_gsm_rules(
+    state: SessionState,
+    request: gsm_api_types::GsmBulkImportRequest,
+) -> RouterResponse<gsm_api_types::GsmBulkImportResponse> {
+    let db = state.store.as_ref();
+    let total_count = request.rules.len();
+
+    if total_count == 0 {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No rules found".to_string(),
+        }
+        .into());
+    }
+
+    if total_count > MAX_GSM_IMPORT_SIZE {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("An import can contain at most {MAX_GSM_IMPORT_SIZE} rules"),
+        }
+        .into());
+    }
+
+    let (validated_rules, import_errors) = bulk_import::validate_import_records(
+        request
+            .rules
+            .into_iter()
+            .map(storage::GatewayStatusMappingNew::foreign_from),
+        |rule| {
+            validate_gsm_rule(rule)?;
+            Ok((GsmRuleKey::from(rule), ()))
+        },
+        import_error,
+    );
+
+    let plan = plan_gsm_rules(
+        db,
+        validated_rules.into_iter().map(|(rule, ())| rule).collect(),
+    )
+    .await?;
+    let apply = !request.dry_run && import_errors.is_empty();
+
+    let change_id = if apply && !plan.pending.is_empty() {
+        Some(apply_gsm_rules(db, plan.pending, request.description).await?)
+    } else {
+        None
+    };
+
+    Ok(ApplicationResponse::Json(
+        gsm_api_types::GsmBulkImportResponse {
+            dry_run: request.dry_run,
+            applied: apply,
+            change_id,
+            total_count,
+            created_count: plan.created_count,
+            updated_count: plan.updated_count,
+            unchanged_count: plan.unchanged_count,
+            errors: import_errors,
+        },
+    ))
+}
+
+/// Pairs every rule with the stored rule it replaces, loading the stored rules of all the
+/// connectors of the import at once
+async fn plan_gsm_rules(
+    db: &dyn StorageInterface,
+    rules: Vec<storage::GatewayStatusMappingNew>,
+) -> RouterResult<bulk_import::ImportPlan<PlannedGsmRule>> {
+    let connectors = rules
+        .iter()
+        .map(|rule| rule.connector.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let mut existing_rules = db
+        .find_gsm_rules_by_connectors(connectors)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching gsm rules")?
+        .into_iter()
+        .map(|rule| (GsmRuleKey::from(&rule), rule))
+        .collect::<HashMap<_, _>>();
+
+    let planned_rules = rules
+        .into_iter()
+        .map(|rule| PlannedGsmRule {
+            existing: existing_rules.remove(&GsmRuleKey::from(&rule)),
+            rule,
+        })
+        .collect();
+
+    Ok(bulk_import::ImportPlan::new(planned_rules))
+}
+
+/// Writes the rules and their history as a single change set, so that either the whole import
+/// is applied and can be reverted, or nothing is written. Returns the id of the change set.
+async fn apply_gsm_rules(
+    db: &dyn StorageInterface,
+    pending: Vec<PlannedGsmRule>,
+    description: Option<String>,
+) -> RouterResult<String> {
+    let mut changes = Vec::with_capacity(pending.len());
+    let mut new_rules = Vec::new();
+    let mut updated_rules = Vec::new();
+    for PlannedGsmRule { rule, existing } in pending {
+        changes.push(GsmRuleChange {
+            key: GsmRuleKey::from(&rule),
+            previous: existing
+                .as_ref()
+                .map(storage::GatewayStatusMapAttributes::from),
+            current: Some(storage::GatewayStatusMapAttributes::from(&rule)),
+        });
+        if existing.is_some() {
+            updated_rules.push(rule);
+        } else {
+            new_rules.push(rule);
+        }
+    }
+
+    let change_id = common_utils::generate_id_with_default_len("gsm_change");
+    let history_entries = history::build_gsm_history_entries(
+        &change_id,
+        gsm_api_types::GsmChangeSource::Import,
+        description,
+        changes,
+    )?;
+
+    db.apply_gsm_rule_changes(new_rules, updated_rules, Vec::new(), history_entries)
+        .await
+        .map_err(|error| {
+            // The rules were planned against a snapshot, so a missing or duplicate rule means
+            // they were changed by someone else in the meantime
+            if error.current_context().is_db_not_found()
+                || error.current_context().is_db_unique_violation()
+            {
+                error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The GSM rules were changed while importing, please retry the import"
+                        .to_string(),
+                })
+            } else {
+                error.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })
+        .attach_printable("Failed while importing gsm rules")?;
+
+    Ok(change_id)
+}
+
+#[instrument(skip_all)]
+pub async fn export_gsm_rules(
+    state: SessionState,
+    request: gsm_api_types::GsmExportRequest,
+) -> RouterResponse<gsm_api_types::GsmExportResponse> {
+    let rules = state
+        .store
+        .list_gsm_rules(request.connector, request.flow)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing gsm rules")?
+        .into_iter()
+        .map(gsm_api_types::GsmResponse::foreign_from)
+        .collect::<Vec<_>>();
+
+    match request.format {
+        gsm_api_types::GsmExportFormat::Json => Ok(ApplicationResponse::Json(
+            gsm_api_types::GsmExportResponse {
+                total_count: rules.len(),
+                rules,
+            },
+        )),
+        gsm_api_types::GsmExportFormat::Csv => {
+            // The columns match the import format, so the file can be imported elsewhere as is
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for rule in rules {
+                writer
+                    .serialize(rule)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize gsm rule row")?;
+            }
+            let file = writer
+                .into_inner()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to build gsm export file")?;
+
+            Ok(ApplicationResponse::FileData((file, mime::TEXT_CSV)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::db::gsm::GsmInterface;
+
+    fn gsm_rule(code: &str, status: &str) -> storage::GatewayStatusMappingNew {
+        storage::GatewayStatusMappingNew {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: code.to_string(),
+            message: "message".to_string(),
+            status: status.to_string(),
+            router_error: None,
+            decision: "retry".to_string(),
+            step_up_possible: false,
+            unified_code: None,
+            unified_message: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_gsm_rule_checks_required_fields_and_lengths() {
+        assert!(validate_gsm_rule(&gsm_rule("code", "failure")).is_ok());
+        assert_eq!(
+            validate_gsm_rule(&gsm_rule("code", "")),
+            Err("`status` must not be empty".to_string())
+        );
+        assert_eq!(
+            validate_gsm_rule(&gsm_rule(&"c".repeat(256), "failure")),
+            Err("`code` must be at most 255 characters long".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_creates_and_updates_rules_in_one_change() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        db.add_gsm_rule(gsm_rule("unchanged", "failure"))
+            .await
+            .unwrap();
+        db.add_gsm_rule(gsm_rule("updated", "failure"))
+            .await
+            .unwrap();
+
+        let plan = plan_gsm_rules(
+            &db,
+            vec![
+                gsm_rule("unchanged", "failure"),
+                gsm_rule("updated", "pending"),
+                gsm_rule("created", "failure"),
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(plan.created_count, 1);
+        assert_eq!(plan.updated_count, 1);
+        assert_eq!(plan.unchanged_count, 1);
+
+        let change_id = apply_gsm_rules(&db, plan.pending, Some("import".to_string()))
+            .await
+            .unwrap();
+
+        let rules = db
+            .list_gsm_rules(Some("stripe".to_string()), None)
+            .await
+            .unwrap();
+        let statuses = rules
+            .iter()
+            .map(|rule| (rule.code.as_str(), rule.status.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                ("created", "failure"),
+                ("unchanged", "failure"),
+                ("updated", "pending"),
+            ]
+        );
+
+        let history_entries = db
+            .find_gsm_rule_history_by_change_id(&change_id)
+            .await
+            .unwrap();
+        let actions = history_entries
+            .iter()
+            .map(|entry| (entry.code.as_str(), entry.action.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            vec![
+                (
+                    "updated",
+                    gsm_api_types::GsmChangeAction::Updated.to_string()
+                ),
+                (
+                    "created",
+                    gsm_api_types::GsmChangeAction::Created.to_string()
+                ),
+            ]
+        );
+        assert!(history_entries.iter().all(|entry| entry.source
+            == gsm_api_types::GsmChangeSource::Import.to_string()
+            && entry.description.as_deref() == Some("import")));
+    }
+
+    #[tokio::test]
+    async fn test_import_writes_nothing_if_the_rules_changed_after_planning() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        db.add_gsm_rule(gsm_rule("updated", "failure"))
+            .await
+            .unwrap();
+
+        let plan = plan_gsm_rules(
+            &db,
+            vec![
+                gsm_rule("updated", "pending"),
+                gsm_rule("created", "failure"),
+            ],
+        )
+        .await
+        .unwrap();
+        db.add_gsm_rule(gsm_rule("created", "pending"))
+            .await
+            .unwrap();
+
+        let error = apply_gsm_rules(&db, plan.pending, None).await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+
+        let updated = GsmRuleKey::from(&gsm_rule("updated", "pending"))
+            .find_rule(&db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated.status, "failure");
+        assert!(db
+            .list_gsm_rule_history(None, None, 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,638 @@
+use std::collections::HashSet;
+
+use api_models::gsm as gsm_api_types;
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    services,
+    types::{storage, transformers::ForeignFrom},
+    SessionState,
+};
+
+const GSM_HISTORY_DEFAULT_LIMIT: i64 = 100;
+const GSM_HISTORY_MAX_LIMIT: i64 = 1000;
+
+/// The primary key of a GSM rule.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GsmRuleKey {
+    pub connector: String,
+    pub flow: String,
+    pub sub_flow: String,
+    pub code: String,
+    pub message: String,
+}
+
+impl From<&storage::GatewayStatusMap> for GsmRuleKey {
+    fn from(value: &storage::GatewayStatusMap) -> Self {
+        Self {
+            connector: value.connector.clone(),
+            flow: value.flow.clone(),
+            sub_flow: value.sub_flow.clone(),
+            code: value.code.clone(),
+            message: value.message.clone(),
+        }
+    }
+}
+
+impl From<&storage::GatewayStatusMappingNew> for GsmRuleKey {
+    fn from(value: &storage::GatewayStatusMappingNew) -> Self {
+        Self {
+            connector: value.connector.clone(),
+            flow: value.flow.clone(),
+            sub_flow: value.sub_flow.clone(),
+            code: value.code.clone(),
+            message: value.message.clone(),
+        }
+    }
+}
+
+impl From<&storage::GatewayStatusMapHistory> for GsmRuleKey {
+    fn from(value: &storage::GatewayStatusMapHistory) -> Self {
+        Self {
+            connector: value.connector.clone(),
+            flow: value.flow.clone(),
+            sub_flow: value.sub_flow.clone(),
+            code: value.code.clone(),
+            message: value.message.clone(),
+        }
+    }
+}
+
+impl GsmRuleKey {
+    fn to_rule(
+        &self,
+        attributes: &storage::GatewayStatusMapAttributes,
+    ) -> storage::GatewayStatusMappingNew {
+        storage::GatewayStatusMappingNew {
+            connector: self.connector.clone(),
+            flow: self.flow.clone(),
+            sub_flow: self.sub_flow.clone(),
+            code: self.code.clone(),
+            message: self.message.clone(),
+            status: attributes.status.clone(),
+            router_error: attributes.router_error.clone(),
+            decision: attributes.decision.clone(),
+            step_up_possible: attributes.step_up_possible,
+            unified_code: attributes.unified_code.clone(),
+            unified_message: attributes.unified_message.clone(),
+        }
+    }
+
+    pub async fn find_rule(
+        &self,
+        db: &dyn StorageInterface,
+    ) -> RouterResult<Option<storage::GatewayStatusMap>> {
+        match db
+            .find_gsm_rule(
+                self.connector.clone(),
+                self.flow.clone(),
+                self.sub_flow.clone(),
+                self.code.clone(),
+                self.message.clone(),
+            )
+            .await
+        {
+            Ok(rule) => Ok(Some(rule)),
+            Err(error) if error.current_context().is_db_not_found() => Ok(None),
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching gsm rule"),
+        }
+    }
+}
+
+/// The state of a single GSM rule before and after a change. `None` means the rule did not
+/// exist.
+#[derive(Debug)]
+pub struct GsmRuleChange {
+    pub key: GsmRuleKey,
+    pub previous: Option<storage::GatewayStatusMapAttributes>,
+    pub current: Option<storage::GatewayStatusMapAttributes>,
+}
+
+impl GsmRuleChange {
+    fn action(&self) -> gsm_api_types::GsmChangeAction {
+        match (&self.previous, &self.current) {
+            (None, _) => gsm_api_types::GsmChangeAction::Created,
+            (Some(_), Some(_)) => gsm_api_types::GsmChangeAction::Updated,
+            (Some(_), None) => gsm_api_types::GsmChangeAction::Deleted,
+        }
+    }
+}
+
+/// Builds the history entries of a change set, in the order of the changes.
+pub fn build_gsm_history_entries(
+    change_id: &str,
+    source: gsm_api_types::GsmChangeSource,
+    description: Option<String>,
+    changes: Vec<GsmRuleChange>,
+) -> RouterResult<Vec<storage::GatewayStatusMapHistoryNew>> {
+    let now = common_utils::date_time::now();
+    changes
+        .into_iter()
+        .enumerate()
+        .map(|(index, change)| {
+            let action = change.action();
+            let previous_rule = change
+                .previous
+                .map(|rule| rule.encode_to_value())
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode the previous gsm rule")?;
+            let current_rule = change
+                .current
+                .map(|rule| rule.encode_to_value())
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode the current gsm rule")?;
+            Ok(storage::GatewayStatusMapHistoryNew {
+                change_id: change_id.to_owned(),
+                sequence_number: i32::try_from(index)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Too many gsm rules in a single change")?,
+                source: source.to_string(),
+                action: action.to_string(),
+                connector: change.key.connector,
+                flow: change.key.flow,
+                sub_flow: change.key.sub_flow,
+                code: change.key.code,
+                message: change.key.message,
+                previous_rule,
+                current_rule,
+                description: description.clone(),
+                created_at: now,
+            })
+        })
+        .collect()
+}
+
+/// Writes the changes to the GSM rule history as a single change set and returns its id.
+/// Nothing is written if there are no changes.
+#[instrument(skip_all)]
+pub async fn record_gsm_rule_changes(
+    state: &SessionState,
+    source: gsm_api_types::GsmChangeSource,
+    description: Option<String>,
+    changes: Vec<GsmRuleChange>,
+) -> RouterResult<Option<String>> {
+    if changes.is_empty() {
+        return Ok(None);
+    }
+
+    let change_id = common_utils::generate_id_with_default_len("gsm_change");
+    let entries = build_gsm_history_entries(&change_id, source, description, changes)?;
+
+    state
+        .store
+        .insert_gsm_rule_history(entries)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting gsm rule history")?;
+
+    Ok(Some(change_id))
+}
+
+/// Records the changes made through the single rule APIs. The rule has already been changed at
+/// this point, so a failure is only logged.
+pub async fn record_gsm_rule_change_or_log(
+    state: &SessionState,
+    source: gsm_api_types::GsmChangeSource,
+    change: GsmRuleChange,
+) {
+    record_gsm_rule_changes(state, source, None, vec![change])
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to record gsm rule history"))
+        .ok();
+}
+
+impl ForeignFrom<storage::GatewayStatusMapAttributes> for gsm_api_types::GsmRuleAttributes {
+    fn foreign_from(value: storage::GatewayStatusMapAttributes) -> Self {
+        Self {
+            status: value.status,
+            router_error: value.router_error,
+            decision: value.decision,
+            step_up_possible: value.step_up_possible,
+            unified_code: value.unified_code,
+            unified_message: value.unified_message,
+        }
+    }
+}
+
+fn parse_rule_snapshot(
+    value: Option<serde_json::Value>,
+) -> RouterResult<Option<storage::GatewayStatusMapAttributes>> {
+    value
+        .map(|value| value.parse_value("GatewayStatusMapAttributes"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse gsm rule snapshot from history")
+}
+
+fn to_history_response(
+    entry: storage::GatewayStatusMapHistory,
+) -> RouterResult<gsm_api_types::GsmHistoryResponse> {
+    Ok(gsm_api_types::GsmHistoryResponse {
+        previous_rule: parse_rule_snapshot(entry.previous_rule)?.map(ForeignFrom::foreign_from),
+        current_rule: parse_rule_snapshot(entry.current_rule)?.map(ForeignFrom::foreign_from),
+        change_id: entry.change_id,
+        sequence_number: entry.sequence_number,
+        source: entry.source,
+        action: entry.action,
+        connector: entry.connector,
+        flow: entry.flow,
+        sub_flow: entry.sub_flow,
+        code: entry.code,
+        message: entry.message,
+        description: entry.description,
+        created_at: entry.created_at,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn list_gsm_rule_history(
+    state: SessionState,
+    request: gsm_api_types::GsmHistoryListRequest,
+) -> RouterResponse<gsm_api_types::GsmHistoryListResponse> {
+    let db = state.store.as_ref();
+    let limit = request
+        .limit
+        .unwrap_or(GSM_HISTORY_DEFAULT_LIMIT)
+        .clamp(1, GSM_HISTORY_MAX_LIMIT);
+
+    let entries = match request.change_id {
+        Some(change_id) => db
+            .find_gsm_rule_history_by_change_id(&change_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching gsm rule history")?
+            .into_iter()
+            .filter(|entry| {
+                request
+                    .connector
+                    .as_ref()
+                    .map_or(true, |connector| &entry.connector == connector)
+                    && request
+                        .flow
+                        .as_ref()
+                        .map_or(true, |flow| &entry.flow == flow)
+            })
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect(),
+        None => db
+            .list_gsm_rule_history(request.connector, request.flow, limit)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while listing gsm rule history")?,
+    };
+
+    let data = entries
+        .into_iter()
+        .map(to_history_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        gsm_api_types::GsmHistoryListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Restores every rule touched by the change to the state it had right before the change, and
+/// records the restore as a new change in the same transaction. Later changes to the same rules
+/// are overwritten.
+#[instrument(skip_all)]
+pub async fn revert_gsm_rule_change(
+    state: SessionState,
+    request: gsm_api_types::GsmRevertRequest,
+) -> RouterResponse<gsm_api_types::GsmRevertResponse> {
+    let db = state.store.as_ref();
+    let entries = db
+        .find_gsm_rule_history_by_change_id(&request.change_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching gsm rule history")?;
+
+    if entries.is_empty() {
+        return Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "GSM change with given id does not exist in our records".to_string(),
+        }
+        .into());
+    }
+
+    let mut plan = GsmRuleRestorePlan::default();
+    let mut restored_keys = HashSet::new();
+    // The earliest entry of a rule holds its state right before the change, in case the change
+    // touched it more than once
+    for entry in entries {
+        let key = GsmRuleKey::from(&entry);
+        if restored_keys.insert(key.clone()) {
+            plan.add_rule(db, key, entry.previous_rule).await?;
+        }
+    }
+
+    let restored_count = plan.changes.len();
+    let description = request
+        .description
+        .or_else(|| Some(format!("Revert of {}", request.change_id)));
+    let change_id = apply_gsm_rule_restore_plan(db, plan, description).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        gsm_api_types::GsmRevertResponse {
+            reverted_change_id: request.change_id,
+            change_id,
+            restored_count,
+        },
+    ))
+}
+
+/// The writes which restore the rules touched by a change, planned before anything is written
+#[derive(Default)]
+struct GsmRuleRestorePlan {
+    new_rules: Vec<storage::GatewayStatusMappingNew>,
+    updated_rules: Vec<storage::GatewayStatusMappingNew>,
+    deleted_rules: Vec<storage::GatewayStatusMap>,
+    changes: Vec<GsmRuleChange>,
+}
+
+impl GsmRuleRestorePlan {
+    /// Plans restoring the rule to the target state. Nothing is planned if the rule is in that
+    /// state already.
+    async fn add_rule(
+        &mut self,
+        db: &dyn StorageInterface,
+        key: GsmRuleKey,
+        target: Option<serde_json::Value>,
+    ) -> RouterResult<()> {
+        let target = parse_rule_snapshot(target)?;
+        let current_rule = key.find_rule(db).await?;
+        let current = current_rule
+            .as_ref()
+            .map(storage::GatewayStatusMapAttributes::from);
+        if current == target {
+            return Ok(());
+        }
+
+        match (current_rule, &target) {
+            (None, None) => return Ok(()),
+            (None, Some(target)) => self.new_rules.push(key.to_rule(target)),
+            (Some(_), Some(target)) => self.updated_rules.push(key.to_rule(target)),
+            (Some(current_rule), None) => self.deleted_rules.push(current_rule),
+        }
+        self.changes.push(GsmRuleChange {
+            key,
+            previous: current,
+            current: target,
+        });
+        Ok(())
+    }
+}
+
+/// Writes the restored rules and their history as a single change set, so that either every
+/// rule is restored or nothing is written. Returns the id of the change set, if anything changed.
+async fn apply_gsm_rule_restore_plan(
+    db: &dyn StorageInterface,
+    plan: GsmRuleRestorePlan,
+    description: Option<String>,
+) -> RouterResult<Option<String>> {
+    if plan.changes.is_empty() {
+        return Ok(None);
+    }
+
+    let change_id = common_utils::generate_id_with_default_len("gsm_change");
+    let history_entries = build_gsm_history_entries(
+        &change_id,
+        gsm_api_types::GsmChangeSource::Revert,
+        description,
+        plan.changes,
+    )?;
+
+    db.apply_gsm_rule_changes(
+        plan.new_rules,
+        plan.updated_rules,
+        plan.deleted_rules,
+        history_entries,
+    )
+    .await
+    .map_err(|error| {
+        // The restore was planned against the rules as they were read, so a missing or
+        // duplicate rule means they were changed by someone else in the meantime
+        if error.current_context().is_db_not_found()
+            || error.current_context().is_db_unique_violation()
+        {
+            error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The GSM rules were changed while reverting, please retry the revert"
+                    .to_string(),
+            })
+        } else {
+            error.change_context(errors::ApiErrorResponse::InternalServerError)
+        }
+    })
+    .attach_printable("Failed while reverting gsm rules")?;
+
+    Ok(Some(change_id))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::db::gsm::GsmInterface;
+
+    fn gsm_rule(status: &str) -> storage::GatewayStatusMappingNew {
+        storage::GatewayStatusMappingNew {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: "code".to_string(),
+            message: "message".to_string(),
+            status: status.to_string(),
+            router_error: None,
+            decision: "retry".to_string(),
+            step_up_possible: false,
+            unified_code: None,
+            unified_message: None,
+        }
+    }
+
+    fn attributes(status: &str) -> storage::GatewayStatusMapAttributes {
+        storage::GatewayStatusMapAttributes::from(&gsm_rule(status))
+    }
+
+    #[test]
+    fn test_history_entries_record_the_rule_snapshots_in_order() {
+        let key = GsmRuleKey::from(&gsm_rule("failure"));
+        let entries = build_gsm_history_entries(
+            "gsm_change_1",
+            gsm_api_types::GsmChangeSource::Import,
+            Some("description".to_string()),
+            vec![
+                GsmRuleChange {
+                    key: key.clone(),
+                    previous: None,
+                    current: Some(attributes("failure")),
+                },
+                GsmRuleChange {
+                    key: key.clone(),
+                    previous: Some(attributes("failure")),
+                    current: Some(attributes("pending")),
+                },
+                GsmRuleChange {
+                    key,
+                    previous: Some(attributes("pending")),
+                    current: None,
+                },
+            ],
+        )
+        .unwrap();
+
+        let actions = entries
+            .iter()
+            .map(|entry| (entry.sequence_number, entry.action.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            vec![
+                (0, gsm_api_types::GsmChangeAction::Created.to_string()),
+                (1, gsm_api_types::GsmChangeAction::Updated.to_string()),
+                (2, gsm_api_types::GsmChangeAction::Deleted.to_string()),
+            ]
+        );
+        assert!(entries[0].previous_rule.is_none());
+        assert_eq!(
+            parse_rule_snapshot(entries[1].previous_rule.clone()).unwrap(),
+            Some(attributes("failure"))
+        );
+        assert_eq!(
+            parse_rule_snapshot(entries[1].current_rule.clone()).unwrap(),
+            Some(attributes("pending"))
+        );
+        assert!(entries[2].current_rule.is_none());
+        assert!(entries.iter().all(|entry| entry.change_id == "gsm_change_1"
+            && entry.description.as_deref() == Some("description")));
+    }
+
+    async fn restore_gsm_rule(
+        db: &MockDb,
+        key: &GsmRuleKey,
+        target: Option<serde_json::Value>,
+    ) -> RouterResult<Option<GsmRuleChange>> {
+        let mut plan = GsmRuleRestorePlan::default();
+        plan.add_rule(db, key.clone(), target).await?;
+        let change_id = apply_gsm_rule_restore_plan(db, plan, None).await?;
+        Ok(match change_id {
+            Some(change_id) => {
+                let mut entries = db
+                    .find_gsm_rule_history_by_change_id(&change_id)
+                    .await
+                    .unwrap();
+                assert_eq!(entries.len(), 1);
+                let entry = entries.remove(0);
+                Some(GsmRuleChange {
+                    key: GsmRuleKey::from(&entry),
+                    previous: parse_rule_snapshot(entry.previous_rule).unwrap(),
+                    current: parse_rule_snapshot(entry.current_rule).unwrap(),
+                })
+            }
+            None => None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_restoring_a_rule_undoes_each_kind_of_change() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let key = GsmRuleKey::from(&gsm_rule("failure"));
+        let snapshot = |status: &str| Some(attributes(status).encode_to_value().unwrap());
+
+        // Undoing a delete creates the rule again
+        let change = restore_gsm_rule(&db, &key, snapshot("failure"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(change.previous.is_none());
+        assert_eq!(key.find_rule(&db).await.unwrap().unwrap().status, "failure");
+
+        // Restoring the current state is a no-op
+        assert!(restore_gsm_rule(&db, &key, snapshot("failure"))
+            .await
+            .unwrap()
+            .is_none());
+
+        // Undoing an update restores the previous attributes
+        db.update_gsm_rule(
+            key.connector.clone(),
+            key.flow.clone(),
+            key.sub_flow.clone(),
+            key.code.clone(),
+            key.message.clone(),
+            attributes("pending").into(),
+        )
+        .await
+        .unwrap();
+        let change = restore_gsm_rule(&db, &key, snapshot("failure"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(change.previous, Some(attributes("pending")));
+        assert_eq!(key.find_rule(&db).await.unwrap().unwrap().status, "failure");
+
+        // Undoing a create deletes the rule
+        let change = restore_gsm_rule(&db, &key, None).await.unwrap().unwrap();
+        assert_eq!(change.action(), gsm_api_types::GsmChangeAction::Deleted);
+        assert!(key.find_rule(&db).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failed_restore_leaves_the_rules_as_they_were() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let key = GsmRuleKey::from(&gsm_rule("failure"));
+        let other_key = GsmRuleKey {
+            code: "other_code".to_string(),
+            ..key.clone()
+        };
+        db.add_gsm_rule(gsm_rule("pending")).await.unwrap();
+        db.add_gsm_rule(other_key.to_rule(&attributes("pending")))
+            .await
+            .unwrap();
+
+        let mut plan = GsmRuleRestorePlan::default();
+        plan.add_rule(
+            &db,
+            key.clone(),
+            Some(attributes("failure").encode_to_value().unwrap()),
+        )
+        .await
+        .unwrap();
+        plan.add_rule(&db, other_key.clone(), None).await.unwrap();
+        assert_eq!(plan.changes.len(), 2);
+
+        // The rule is deleted by someone else before the restore is written
+        db.delete_gsm_rule(
+            other_key.connector.clone(),
+            other_key.flow.clone(),
+            other_key.sub_flow.clone(),
+            other_key.code.clone(),
+            other_key.message.clone(),
+        )
+        .await
+        .unwrap();
+
+        assert!(apply_gsm_rule_restore_plan(&db, plan, None).await.is_err());
+        assert_eq!(key.find_rule(&db).await.unwrap().unwrap().status, "pending");
+        assert!(db
+            .list_gsm_rule_history(None, None, 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
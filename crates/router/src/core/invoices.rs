@@ -0,0 +1,1986 @@
+//! Invoices issued by a merchant to a customer, made up of line items with their own discounts
+//! and taxes.
+//!
+//! An invoice is created as a draft which can be edited freely. Finalizing the invoice assigns it
+//! the next number of the invoice number sequence of its business profile and creates a payment
+//! link for the total amount, after which the invoice is open for payment. Payments made through
+//! the payment links of the invoice, along with payments received out of band, are added to the
+//! amount paid of the invoice, which is marked paid once the total amount has been received.
+
+pub mod reminders;
+
+use api_models::invoices::{
+    InvoiceCreateRequest, InvoiceDiscount, InvoiceId, InvoiceLineItemRequest,
+    InvoiceLineItemResponse, InvoiceListConstraints, InvoiceListResponse,
+    InvoiceNumberSequenceRequest, InvoiceNumberSequenceResponse, InvoicePaymentResponse,
+    InvoiceRecordPaymentRequest, InvoiceResponse, InvoiceTaxLine, InvoiceTaxRate,
+    InvoiceUpdateRequest,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::{keymanager::KeyManagerState, MinorUnit},
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use masking::{ExposeInterface, Secret};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils, webhooks,
+    },
+    routes::{app::ReqState, metrics, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+/// Amounts of a line item after applying the discounts and taxes of the invoice
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LineItemAmounts {
+    amount: MinorUnit,
+    discount_amount: MinorUnit,
+    tax_amount: MinorUnit,
+}
+
+/// Amounts of an invoice, computed from its line items and discount
+#[derive(Debug, Clone)]
+struct InvoiceTotals {
+    line_items: Vec<LineItemAmounts>,
+    tax_lines: Vec<InvoiceTaxLine>,
+    subtotal: MinorUnit,
+    discount_amount: MinorUnit,
+    tax_amount: MinorUnit,
+    total: MinorUnit,
+}
+
+#[instrument(skip_all)]
+pub async fn create_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: InvoiceCreateRequest,
+) -> RouterResponse<InvoiceResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state: &KeyManagerState = &(&state).into();
+    validate_invoice_details(
+        req.description.as_deref(),
+        Some(&req.line_items),
+        req.discount,
+        req.reminder_schedule.as_deref(),
+    )?;
+    let totals = compute_totals(&req.line_items, req.discount)?;
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        key_manager_state,
+        &key_store,
+        None,
+        None,
+        &merchant_account,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+    db.find_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        &req.customer_id,
+        merchant_account.get_id(),
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let invoice_id = utils::generate_id(consts::ID_LENGTH, "inv");
+    let now = common_utils::date_time::now();
+    let invoice = db
+        .insert_invoice(storage::InvoiceNew {
+            invoice_id: invoice_id.clone(),
+            merchant_id: merchant_account.get_id().clone(),
+            profile_id,
+            customer_id: req.customer_id,
+            invoice_number: None,
+            status: enums::InvoiceStatus::Draft,
+            currency: req.currency,
+            description: req.description,
+            discount: encode_optional(req.discount.as_ref(), "discount")?,
+            tax_lines: encode(&totals.tax_lines, "tax lines")?,
+            subtotal: totals.subtotal,
+            discount_amount: totals.discount_amount,
+            tax_amount: totals.tax_amount,
+            total: totals.total,
+            amount_paid: MinorUnit::zero(),
+            due_date: req.due_date,
+            reminder_schedule: encode_optional(
+                req.reminder_schedule.as_ref(),
+                "reminder schedule",
+            )?,
+            payment_id: None,
+            payment_link: None,
+            metadata: req.metadata.map(ExposeInterface::expose),
+            finalized_at: None,
+            paid_at: None,
+            voided_at: None,
+            marked_uncollectible_at: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Invoice already exists".to_string(),
+        })?;
+
+    insert_line_items(&state, &invoice_id, &req.line_items, &totals, now).await?;
+
+    let invoice_response = get_invoice_response(&state, invoice).await?;
+    trigger_invoice_webhook(
+        &state,
+        &merchant_account,
+        &key_store,
+        &invoice_response,
+        enums::EventType::InvoiceCreated,
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(invoice_response))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: InvoiceId,
+) -> RouterResponse<InvoiceResponse> {
+    let invoice = find_invoice(&state, merchant_account.get_id(), &req.invoice_id).await?;
+
+    get_invoice_response(&state, invoice)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn list_invoices(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: InvoiceListConstraints,
+) -> RouterResponse<InvoiceListResponse> {
+    let limit = constraints
+        .limit
+        .unwrap_or(consts::DEFAULT_INVOICE_LIST_LIMIT);
+    let invoices = state
+        .store
+        .list_invoices_by_merchant_id(
+            merchant_account.get_id(),
+            constraints.customer_id.as_ref(),
+            constraints.status,
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list invoices")?;
+
+    let mut data = Vec::with_capacity(invoices.len());
+    for invoice in invoices {
+        data.push(get_invoice_response(&state, invoice).await?);
+    }
+
+    Ok(ApplicationResponse::Json(InvoiceListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+/// Updates a draft invoice, recomputing its amounts. The line items of the invoice are replaced
+/// when provided.
+#[instrument(skip_all)]
+pub async fn update_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: InvoiceUpdateRequest,
+) -> RouterResponse<InvoiceResponse> {
+    let db = state.store.as_ref();
+    let invoice = find_invoice(&state, merchant_account.get_id(), &req.invoice_id).await?;
+    ensure_status(&invoice, &[enums::InvoiceStatus::Draft], "updated")?;
+    validate_invoice_details(
+        req.description.as_deref(),
+        req.line_items.as_deref(),
+        req.discount,
+        req.reminder_schedule.as_deref(),
+    )?;
+
+    let line_items = match req.line_items.as_ref() {
+        Some(line_items) => line_items.clone(),
+        None => db
+            .list_invoice_line_items_by_invoice_id(&invoice.invoice_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list invoice line items")?
+            .into_iter()
+            .map(get_line_item_request)
+            .collect::<RouterResult<Vec<_>>>()?,
+    };
+    let discount = match req.discount {
+        Some(discount) => Some(discount),
+        None => parse_discount(invoice.discount.as_ref())?,
+    };
+    let totals = compute_totals(&line_items, discount)?;
+
+    let updated_invoice = update_invoice_in_status(
+        &state,
+        &invoice,
+        storage::InvoiceUpdate::DraftUpdate {
+            description: req.description,
+            discount: encode_optional(req.discount.as_ref(), "discount")?,
+            tax_lines: encode(&totals.tax_lines, "tax lines")?,
+            subtotal: totals.subtotal,
+            discount_amount: totals.discount_amount,
+            tax_amount: totals.tax_amount,
+            total: totals.total,
+            due_date: req.due_date,
+            reminder_schedule: encode_optional(
+                req.reminder_schedule.as_ref(),
+                "reminder schedule",
+            )?,
+            metadata: req.metadata.map(ExposeInterface::expose),
+        },
+    )
+    .await?;
+
+    // The share of the invoice discount of every line item changes along with the discount
+    if req.line_items.is_some() || req.discount.is_some() {
+        db.delete_invoice_line_items_by_invoice_id(&invoice.invoice_id)
+            .await
+            .or_else(|error| {
+                if error.current_context().is_db_not_found() {
+                    Ok(false)
+                } else {
+                    Err(error)
+                }
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete invoice line items")?;
+        insert_line_items(
+            &state,
+            &invoice.invoice_id,
+            &line_items,
+            &totals,
+            common_utils::date_time::now(),
+        )
+        .await?;
+    }
+
+    get_invoice_response(&state, updated_invoice)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Finalizes a draft invoice: assigns it the next invoice number of its business profile along
+/// with the status transition, creates a payment link for its total amount and schedules the
+/// payment reminders of the customer.
+#[instrument(skip_all)]
+pub async fn finalize_invoice(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: InvoiceId,
+) -> RouterResponse<InvoiceResponse> {
+    let db = state.store.as_ref();
+    let invoice = find_invoice(&state, merchant_account.get_id(), &req.invoice_id).await?;
+    ensure_status(&invoice, &[enums::InvoiceStatus::Draft], "finalized")?;
+
+    let line_items = db
+        .list_invoice_line_items_by_invoice_id(&invoice.invoice_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list invoice line items")?;
+    if line_items.is_empty() {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "An invoice without line items cannot be finalized".to_string(),
+        }))?
+    }
+    if invoice.total <= MinorUnit::zero() {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "An invoice with no amount to be paid cannot be finalized".to_string(),
+        }))?
+    }
+    db.find_customer_by_customer_id_merchant_id(
+        &(&state).into(),
+        &invoice.customer_id,
+        merchant_account.get_id(),
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    create_invoice_number_sequence_if_absent(&state, &invoice).await?;
+    let invoice = db
+        .finalize_invoice_with_next_invoice_number(
+            &invoice.merchant_id,
+            &invoice.invoice_id,
+            &invoice.profile_id,
+            common_utils::date_time::now(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The invoice was modified concurrently, please retry".to_string(),
+        })?;
+
+    // The invoice stays finalized if its payment cannot be created, a payment link can then be
+    // created for it separately
+    let invoice = match create_invoice_payment_for_finalized_invoice(
+        &state,
+        req_state,
+        &merchant_account,
+        &key_store,
+        &invoice,
+    )
+    .await
+    {
+        Ok(invoice) => invoice,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to create the payment of the finalized invoice"
+            );
+            invoice
+        }
+    };
+
+    reminders::add_invoice_reminder_task(&state, &invoice)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to schedule invoice reminders"))
+        .ok();
+
+    let invoice_response = get_invoice_response(&state, invoice).await?;
+    trigger_invoice_webhook(
+        &state,
+        &merchant_account,
+        &key_store,
+        &invoice_response,
+        enums::EventType::InvoiceFinalized,
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(invoice_response))
+}
+
+#[instrument(skip_all)]
+pub async fn void_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: InvoiceId,
+) -> RouterResponse<InvoiceResponse> {
+    let invoice = find_invoice(&state, merchant_account.get_id(), &req.invoice_id).await?;
+    ensure_status(
+        &invoice,
+        &[
+            enums::InvoiceStatus::Draft,
+            enums::InvoiceStatus::Open,
+            enums::InvoiceStatus::Uncollectible,
+        ],
+        "voided",
+    )?;
+    if invoice.amount_paid > MinorUnit::zero() {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "An invoice which has been partially paid cannot be voided".to_string(),
+        }))?
+    }
+
+    let invoice = update_invoice_in_status(
+        &state,
+        &invoice,
+        storage::InvoiceUpdate::Voided {
+            voided_at: common_utils::date_time::now(),
+        },
+    )
+    .await?;
+
+    let invoice_response = get_invoice_response(&state, invoice).await?;
+    trigger_invoice_webhook(
+        &state,
+        &merchant_account,
+        &key_store,
+        &invoice_response,
+        enums::EventType::InvoiceVoided,
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(invoice_response))
+}
+
+#[instrument(skip_all)]
+pub async fn mark_invoice_uncollectible(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: InvoiceId,
+) -> RouterResponse<InvoiceResponse> {
+    let invoice = find_invoice(&state, merchant_account.get_id(), &req.invoice_id).await?;
+    ensure_status(
+        &invoice,
+        &[enums::InvoiceStatus::Open],
+        "marked uncollectible",
+    )?;
+
+    let invoice = update_invoice_in_status(
+        &state,
+        &invoice,
+        storage::InvoiceUpdate::MarkedUncollectible {
+            marked_uncollectible_at: common_utils::date_time::now(),
+        },
+    )
+    .await?;
+
+    let invoice_response = get_invoice_response(&state, invoice).await?;
+    trigger_invoice_webhook(
+        &state,
+        &merchant_account,
+        &key_store,
+        &invoice_response,
+        enums::EventType::InvoiceMarkedUncollectible,
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(invoice_response))
+}
+
+/// Records a payment of the invoice received outside of the payment links of the invoice, such as
+/// a bank transfer.
+#[instrument(skip_all)]
+pub async fn record_invoice_payment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: InvoiceRecordPaymentRequest,
+) -> RouterResponse<InvoiceResponse> {
+    let db = state.store.as_ref();
+    let invoice = find_invoice(&state, merchant_account.get_id(), &req.invoice_id).await?;
+    ensure_status(
+        &invoice,
+        &[
+            enums::InvoiceStatus::Open,
+            enums::InvoiceStatus::Uncollectible,
+        ],
+        "paid",
+    )?;
+    if req.amount <= MinorUnit::zero() || req.amount > invoice.get_amount_remaining() {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "amount must be greater than zero and at most the amount remaining of {}",
+                invoice.get_amount_remaining()
+            ),
+        }))?
+    }
+    if req
+        .reference
+        .as_ref()
+        .is_some_and(|reference| reference.len() > 255)
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "reference must be at most 255 characters long".to_string(),
+        }))?
+    }
+
+    let now = common_utils::date_time::now();
+    let payment = db
+        .insert_invoice_payment(storage::InvoicePaymentNew {
+            payment_id: utils::generate_id(consts::ID_LENGTH, "invpay"),
+            invoice_id: invoice.invoice_id.clone(),
+            merchant_id: invoice.merchant_id.clone(),
+            source: enums::InvoicePaymentSource::OutOfBand,
+            amount: req.amount,
+            amount_received: None,
+            currency: invoice.currency,
+            status: enums::IntentStatus::Succeeded,
+            reference: req.reference,
+            credited: false,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Invoice payment already exists".to_string(),
+        })?;
+
+    let business_profile = find_business_profile(&state, &key_store, &invoice.profile_id).await?;
+    let invoice = credit_invoice_payment(
+        &state,
+        &merchant_account,
+        &business_profile,
+        &key_store,
+        &payment.payment_id,
+        enums::IntentStatus::Succeeded,
+        req.amount,
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Invoice payment was credited concurrently")?;
+
+    get_invoice_response(&state, invoice)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Creates a new payment link for the amount remaining to be paid on the invoice, for instance
+/// after a partial payment or once the previous payment link has expired.
+#[instrument(skip_all)]
+pub async fn create_invoice_payment_link(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: InvoiceId,
+) -> RouterResponse<InvoiceResponse> {
+    let invoice = find_invoice(&state, merchant_account.get_id(), &req.invoice_id).await?;
+    ensure_status(
+        &invoice,
+        &[
+            enums::InvoiceStatus::Open,
+            enums::InvoiceStatus::Uncollectible,
+        ],
+        "paid",
+    )?;
+    let amount_remaining = invoice.get_amount_remaining();
+    if amount_remaining <= MinorUnit::zero() {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The invoice has no amount remaining to be paid".to_string(),
+        }))?
+    }
+    let invoice_number = invoice
+        .invoice_number
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Finalized invoice does not have an invoice number")?;
+
+    let (payment_id, payment_link) = create_invoice_payment(
+        &state,
+        req_state,
+        &merchant_account,
+        &key_store,
+        &invoice,
+        &invoice_number,
+        amount_remaining,
+    )
+    .await?;
+    let invoice = update_invoice_in_status(
+        &state,
+        &invoice,
+        storage::InvoiceUpdate::PaymentLinkUpdate {
+            payment_id,
+            payment_link,
+        },
+    )
+    .await?;
+
+    get_invoice_response(&state, invoice)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Creates or updates the invoice number sequence of a business profile
+#[instrument(skip_all)]
+pub async fn upsert_invoice_number_sequence(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: InvoiceNumberSequenceRequest,
+) -> RouterResponse<InvoiceNumberSequenceResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state: &KeyManagerState = &(&state).into();
+    if req
+        .prefix
+        .as_ref()
+        .is_some_and(|prefix| prefix.len() > consts::MAX_INVOICE_NUMBER_PREFIX_LENGTH)
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "prefix must be at most {} characters long",
+                consts::MAX_INVOICE_NUMBER_PREFIX_LENGTH
+            ),
+        }))?
+    }
+    if req.next_number == Some(0) {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "next_number must be greater than zero".to_string(),
+        }))?
+    }
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        key_manager_state,
+        &key_store,
+        None,
+        None,
+        &merchant_account,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+    let next_number = req.next_number.map(i64::from);
+
+    let sequence = match db
+        .find_invoice_number_sequence_by_profile_id(&profile_id)
+        .await
+    {
+        Ok(sequence) => {
+            if next_number.is_some_and(|next_number| next_number < sequence.next_number) {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "next_number cannot be lower than the current next number {}, as invoice numbers cannot be reused",
+                        sequence.next_number
+                    ),
+                }))?
+            }
+            db.update_invoice_number_sequence_by_profile_id(
+                &profile_id,
+                storage::InvoiceNumberSequenceUpdate::Update {
+                    prefix: req.prefix,
+                    next_number,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update invoice number sequence")?
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let now = common_utils::date_time::now();
+            db.insert_invoice_number_sequence(storage::InvoiceNumberSequenceNew {
+                profile_id,
+                merchant_id: merchant_account.get_id().clone(),
+                prefix: req
+                    .prefix
+                    .unwrap_or_else(|| consts::DEFAULT_INVOICE_NUMBER_PREFIX.to_string()),
+                next_number: next_number.unwrap_or(1),
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .to_duplicate_response(
+                errors::ApiErrorResponse::GenericDuplicateError {
+                    message: "Invoice number sequence was created concurrently".to_string(),
+                },
+            )?
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch invoice number sequence")?,
+    };
+
+    Ok(ApplicationResponse::Json(InvoiceNumberSequenceResponse {
+        profile_id: sequence.profile_id,
+        prefix: sequence.prefix,
+        next_number: sequence.next_number,
+    }))
+}
+
+/// Returns the invoice paid by the payment, if the payment was created for an invoice.
+///
+/// The invoice is recorded under a key of its own, so that the metadata merchants set on their
+/// payments is never mistaken for it. The invoice payment remains the link used to credit the
+/// invoice.
+pub fn get_invoice_id_from_payment_metadata(metadata: Option<&serde_json::Value>) -> Option<&str> {
+    metadata
+        .and_then(|metadata| metadata.get(consts::INVOICE_PAYMENT_METADATA_KEY))
+        .and_then(|invoice| invoice.get("invoice_id"))
+        .and_then(serde_json::Value::as_str)
+}
+
+const INVOICE_PAYMENT_TAG: &str = "INVOICE";
+const INVOICE_PAYMENT_NAME: &str = "INVOICE_PAYMENT";
+const INVOICE_PAYMENT_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::InvoicePaymentWorkflow;
+
+/// Key of the config of the retries of invoice payment updates that failed
+const INVOICE_PAYMENT_RETRY_MAPPING_KEY: &str = "pt_mapping_invoice_payment";
+
+/// Each status of the payment is tracked by its own task, so that a payment which failed and was
+/// then retried successfully is still credited to the invoice
+fn generate_task_id_for_invoice_payment_workflow(
+    payment_id: &str,
+    status: enums::IntentStatus,
+) -> String {
+    format!("{INVOICE_PAYMENT_RUNNER}_{INVOICE_PAYMENT_NAME}_{payment_id}_{status}")
+}
+
+/// Schedules the invoice payment of a payment created for an invoice to be updated with the
+/// status of the payment, so that the invoice is credited even if the update has to be retried.
+#[instrument(skip_all)]
+pub async fn add_invoice_payment_task(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_id: &str,
+    status: enums::IntentStatus,
+) -> RouterResult<()> {
+    let task_id = generate_task_id_for_invoice_payment_workflow(payment_id, status);
+    let tracking_data = storage::InvoicePaymentTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        payment_id: payment_id.to_owned(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        task_id.clone(),
+        INVOICE_PAYMENT_NAME,
+        INVOICE_PAYMENT_RUNNER,
+        [INVOICE_PAYMENT_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct invoice payment process tracker task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "InvoicePayment")]),
+            );
+            Ok(())
+        }
+        // The invoice payment is already scheduled to be updated with this status
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed while inserting task in process_tracker: id: {task_id}")
+            }),
+    }
+}
+
+pub async fn start_invoice_payment_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::InvoicePaymentTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("InvoicePaymentTrackingData")?;
+
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+    // The latest state of the payment is used, in case it changed since the task was scheduled
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &tracking_data.payment_id,
+            &tracking_data.merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+    let result = async {
+        let profile_id = payment_intent
+            .profile_id
+            .as_deref()
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Payment created for an invoice does not have a business profile")?;
+        let business_profile = find_business_profile(state, &key_store, profile_id).await?;
+        update_invoice_payment_status(
+            state,
+            &merchant_account,
+            &business_profile,
+            &key_store,
+            &payment_intent.payment_id,
+            payment_intent.status,
+            payment_intent
+                .amount_captured
+                .unwrap_or(payment_intent.amount),
+        )
+        .await
+    }
+    .await;
+
+    match result {
+        Ok(()) => {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process.clone(),
+                    business_status::COMPLETED_BY_PT,
+                )
+                .await?
+        }
+        Err(error) => {
+            logger::error!(?error, %process.id, "Failed to update the invoice payment");
+            core_utils::retry_process_tracker_task(
+                db,
+                INVOICE_PAYMENT_RETRY_MAPPING_KEY,
+                &tracking_data.merchant_id,
+                process.clone(),
+            )
+            .await?
+        }
+    }
+
+    Ok(())
+}
+
+/// Updates the invoice payment as per the status of the payment intent, crediting the amount
+/// received to the invoice once the payment succeeds.
+#[instrument(skip_all)]
+async fn update_invoice_payment_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+    status: enums::IntentStatus,
+    amount_received: MinorUnit,
+) -> RouterResult<()> {
+    match status {
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured => {
+            credit_invoice_payment(
+                state,
+                merchant_account,
+                business_profile,
+                key_store,
+                payment_id,
+                status,
+                amount_received,
+            )
+            .await?;
+        }
+        _ => {
+            state
+                .store
+                .update_uncredited_invoice_payment_by_payment_id(
+                    payment_id,
+                    storage::InvoicePaymentUpdate::StatusUpdate { status },
+                )
+                .await
+                .map(|_| ())
+                .or_else(|error| {
+                    // The payment was already credited, or was not created for an invoice
+                    if error.current_context().is_db_not_found() {
+                        Ok(())
+                    } else {
+                        Err(error)
+                    }
+                })
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update invoice payment status")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the amount received through the payment to the amount paid of its invoice, marking the
+/// invoice paid once the total amount has been received.
+///
+/// Returns `None` if the payment was already credited to the invoice.
+async fn credit_invoice_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+    status: enums::IntentStatus,
+    amount_received: MinorUnit,
+) -> RouterResult<Option<storage::Invoice>> {
+    let db = state.store.as_ref();
+    let invoice = match db
+        .credit_invoice_payment_by_payment_id(payment_id, status, amount_received)
+        .await
+    {
+        Ok(invoice) => invoice,
+        Err(error) if error.current_context().is_db_not_found() => {
+            logger::debug!(%payment_id, "Invoice payment already credited or not found");
+            return Ok(None);
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to credit invoice payment")?,
+    };
+
+    let (invoice, event_type) = match invoice.status {
+        enums::InvoiceStatus::Open | enums::InvoiceStatus::Uncollectible
+            if invoice.amount_paid >= invoice.total =>
+        {
+            let paid_invoice = db
+                .update_invoice_by_merchant_id_invoice_id_status(
+                    &invoice.merchant_id,
+                    &invoice.invoice_id,
+                    invoice.status,
+                    storage::InvoiceUpdate::Paid {
+                        paid_at: common_utils::date_time::now(),
+                    },
+                )
+                .await;
+            match paid_invoice {
+                Ok(paid_invoice) => (paid_invoice, Some(enums::EventType::InvoicePaid)),
+                // The status of the invoice was changed concurrently
+                Err(error) if error.current_context().is_db_not_found() => (invoice, None),
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to mark invoice as paid")?,
+            }
+        }
+        enums::InvoiceStatus::Open | enums::InvoiceStatus::Uncollectible => {
+            (invoice, Some(enums::EventType::InvoicePartiallyPaid))
+        }
+        enums::InvoiceStatus::Draft | enums::InvoiceStatus::Paid | enums::InvoiceStatus::Void => {
+            logger::warn!(
+                invoice_id = %invoice.invoice_id,
+                invoice_status = %invoice.status,
+                %payment_id,
+                "Payment received for an invoice which is not open for payment"
+            );
+            (invoice, None)
+        }
+    };
+
+    if let Some(event_type) = event_type {
+        let invoice_response = get_invoice_response(state, invoice.clone()).await?;
+        let idempotent_event_id = match event_type {
+            enums::EventType::InvoicePartiallyPaid => {
+                Some(format!("{}_{event_type}_{payment_id}", invoice.invoice_id))
+            }
+            _ => None,
+        };
+        trigger_invoice_webhook_for_profile(
+            state,
+            merchant_account,
+            business_profile,
+            key_store,
+            &invoice_response,
+            event_type,
+            idempotent_event_id,
+        )
+        .await;
+    }
+
+    Ok(Some(invoice))
+}
+
+async fn find_invoice(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    invoice_id: &str,
+) -> RouterResult<storage::Invoice> {
+    state
+        .store
+        .find_invoice_by_merchant_id_invoice_id(merchant_id, invoice_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Invoice not found".to_string(),
+        })
+}
+
+async fn find_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+) -> RouterResult<domain::BusinessProfile> {
+    state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })
+}
+
+fn ensure_status(
+    invoice: &storage::Invoice,
+    allowed_statuses: &[enums::InvoiceStatus],
+    action: &str,
+) -> RouterResult<()> {
+    if allowed_statuses.contains(&invoice.status) {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "An invoice in the `{}` status cannot be {action}",
+                invoice.status
+            ),
+        }))
+    }
+}
+
+/// Updates the invoice, provided that its status has not changed since it was read
+async fn update_invoice_in_status(
+    state: &SessionState,
+    invoice: &storage::Invoice,
+    invoice_update: storage::InvoiceUpdate,
+) -> RouterResult<storage::Invoice> {
+    state
+        .store
+        .update_invoice_by_merchant_id_invoice_id_status(
+            &invoice.merchant_id,
+            &invoice.invoice_id,
+            invoice.status,
+            invoice_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The invoice was modified concurrently, please retry".to_string(),
+        })
+}
+
+/// Creates the invoice number sequence of the profile of the invoice with the default prefix, if
+/// the profile does not have one yet
+async fn create_invoice_number_sequence_if_absent(
+    state: &SessionState,
+    invoice: &storage::Invoice,
+) -> RouterResult<()> {
+    let now = common_utils::date_time::now();
+    state
+        .store
+        .insert_invoice_number_sequence(storage::InvoiceNumberSequenceNew {
+            profile_id: invoice.profile_id.clone(),
+            merchant_id: invoice.merchant_id.clone(),
+            prefix: consts::DEFAULT_INVOICE_NUMBER_PREFIX.to_string(),
+            next_number: 1,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .map(|_| ())
+        .or_else(|error| {
+            // The sequence already exists
+            if error.current_context().is_db_unique_violation() {
+                Ok(())
+            } else {
+                Err(error)
+            }
+        })
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create invoice number sequence")
+}
+
+/// Creates the payment of the total amount of a finalized invoice and attaches its payment link to
+/// the invoice
+async fn create_invoice_payment_for_finalized_invoice(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &storage::Invoice,
+) -> RouterResult<storage::Invoice> {
+    let invoice_number = invoice
+        .invoice_number
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Finalized invoice does not have an invoice number")?;
+    let (payment_id, payment_link) = create_invoice_payment(
+        state,
+        req_state,
+        merchant_account,
+        key_store,
+        invoice,
+        &invoice_number,
+        invoice.total,
+    )
+    .await?;
+
+    update_invoice_in_status(
+        state,
+        invoice,
+        storage::InvoiceUpdate::PaymentLinkUpdate {
+            payment_id,
+            payment_link,
+        },
+    )
+    .await
+}
+
+/// Creates a payment with a payment link for the amount of the invoice, recording it as a payment
+/// of the invoice
+async fn create_invoice_payment(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &storage::Invoice,
+    invoice_number: &str,
+    amount: MinorUnit,
+) -> RouterResult<(String, Option<String>)> {
+    let payment_id = utils::generate_id(consts::ID_LENGTH, "pay");
+    let payment_request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id.clone())),
+        amount: Some(amount.into()),
+        currency: Some(invoice.currency),
+        profile_id: Some(invoice.profile_id.clone()),
+        customer_id: Some(invoice.customer_id.clone()),
+        description: Some(format!("Invoice {invoice_number}")),
+        metadata: Some(serde_json::json!({
+            (consts::INVOICE_PAYMENT_METADATA_KEY): {
+                "invoice_id": invoice.invoice_id,
+                "invoice_number": invoice_number,
+            },
+        })),
+        payment_link: Some(true),
+        session_expiry: Some(consts::MAX_SESSION_EXPIRY),
+        ..Default::default()
+    };
+
+    let payment_response = match Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        Some(invoice.profile_id.clone()),
+        key_store.clone(),
+        payments::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?
+    {
+        ApplicationResponse::Json(response)
+        | ApplicationResponse::JsonWithHeaders((response, _)) => response,
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response from payments core")?,
+    };
+
+    let now = common_utils::date_time::now();
+    state
+        .store
+        .insert_invoice_payment(storage::InvoicePaymentNew {
+            payment_id: payment_id.clone(),
+            invoice_id: invoice.invoice_id.clone(),
+            merchant_id: invoice.merchant_id.clone(),
+            source: enums::InvoicePaymentSource::PaymentLink,
+            amount,
+            amount_received: None,
+            currency: invoice.currency,
+            status: payment_response.status,
+            reference: None,
+            credited: false,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record invoice payment")?;
+
+    Ok((
+        payment_id,
+        payment_response
+            .payment_link
+            .map(|payment_link| payment_link.link),
+    ))
+}
+
+async fn insert_line_items(
+    state: &SessionState,
+    invoice_id: &str,
+    line_items: &[InvoiceLineItemRequest],
+    totals: &InvoiceTotals,
+    created_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    if line_items.is_empty() {
+        return Ok(());
+    }
+
+    let line_items = line_items
+        .iter()
+        .zip(totals.line_items.iter())
+        .zip(0..)
+        .map(|((line_item, amounts), position)| {
+            Ok(storage::InvoiceLineItemNew {
+                invoice_id: invoice_id.to_owned(),
+                line_item_id: utils::generate_id(consts::ID_LENGTH, "invli"),
+                position,
+                description: line_item.description.clone(),
+                quantity: i32::from(line_item.quantity),
+                unit_amount: line_item.unit_amount,
+                amount: amounts.amount,
+                discount: encode_optional(line_item.discount.as_ref(), "discount")?,
+                discount_amount: amounts.discount_amount,
+                tax_rates: encode(&line_item.tax_rates, "tax rates")?,
+                tax_amount: amounts.tax_amount,
+                created_at,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    state
+        .store
+        .insert_invoice_line_items(line_items)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert invoice line items")
+}
+
+pub(crate) async fn get_invoice_response(
+    state: &SessionState,
+    invoice: storage::Invoice,
+) -> RouterResult<InvoiceResponse> {
+    let db = state.store.as_ref();
+    let line_items = db
+        .list_invoice_line_items_by_invoice_id(&invoice.invoice_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list invoice line items")?
+        .into_iter()
+        .map(|line_item| {
+            Ok(InvoiceLineItemResponse {
+                line_item_id: line_item.line_item_id,
+                description: line_item.description,
+                quantity: u16::try_from(line_item.quantity).unwrap_or_default(),
+                unit_amount: line_item.unit_amount,
+                amount: line_item.amount,
+                discount: parse_discount(line_item.discount.as_ref())?,
+                discount_amount: line_item.discount_amount,
+                tax_rates: parse(line_item.tax_rates, "Vec<InvoiceTaxRate>")?,
+                tax_amount: line_item.tax_amount,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+    let payments = db
+        .list_invoice_payments_by_invoice_id(&invoice.invoice_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list invoice payments")?
+        .into_iter()
+        .map(|payment| InvoicePaymentResponse {
+            payment_id: payment.payment_id,
+            source: payment.source,
+            amount: payment.amount,
+            amount_received: payment.amount_received,
+            status: payment.status,
+            reference: payment.reference,
+            created_at: payment.created_at,
+        })
+        .collect();
+    let amount_remaining = invoice.get_amount_remaining();
+
+    Ok(InvoiceResponse {
+        invoice_id: invoice.invoice_id,
+        merchant_id: invoice.merchant_id,
+        profile_id: invoice.profile_id,
+        customer_id: invoice.customer_id,
+        invoice_number: invoice.invoice_number,
+        status: invoice.status,
+        currency: invoice.currency,
+        description: invoice.description,
+        line_items,
+        discount: parse_discount(invoice.discount.as_ref())?,
+        tax_lines: parse(invoice.tax_lines, "Vec<InvoiceTaxLine>")?,
+        subtotal: invoice.subtotal,
+        discount_amount: invoice.discount_amount,
+        tax_amount: invoice.tax_amount,
+        total: invoice.total,
+        amount_paid: invoice.amount_paid,
+        amount_remaining,
+        due_date: invoice.due_date,
+        reminder_schedule: invoice
+            .reminder_schedule
+            .map(|reminder_schedule| parse(reminder_schedule, "Vec<i16>"))
+            .transpose()?,
+        payment_id: invoice.payment_id,
+        payment_link: invoice.payment_link,
+        payments,
+        metadata: invoice.metadata.map(Secret::new),
+        finalized_at: invoice.finalized_at,
+        paid_at: invoice.paid_at,
+        voided_at: invoice.voided_at,
+        marked_uncollectible_at: invoice.marked_uncollectible_at,
+        created_at: invoice.created_at,
+    })
+}
+
+/// Sends an outgoing webhook about the invoice, through the business profile of the invoice.
+/// Failures are logged, as the invoice has already been updated.
+async fn trigger_invoice_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &InvoiceResponse,
+    event_type: enums::EventType,
+    idempotent_event_id: Option<String>,
+) {
+    match find_business_profile(state, key_store, &invoice.profile_id).await {
+        Ok(business_profile) => {
+            trigger_invoice_webhook_for_profile(
+                state,
+                merchant_account,
+                &business_profile,
+                key_store,
+                invoice,
+                event_type,
+                idempotent_event_id,
+            )
+            .await
+        }
+        Err(error) => logger::error!(?error, "Failed to fetch business profile of the invoice"),
+    }
+}
+
+async fn trigger_invoice_webhook_for_profile(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &InvoiceResponse,
+    event_type: enums::EventType,
+    idempotent_event_id: Option<String>,
+) {
+    let content = api::OutgoingWebhookContent::InvoiceDetails(Box::new(invoice.clone()));
+    let result = match idempotent_event_id {
+        Some(idempotent_event_id) => {
+            Box::pin(
+                webhooks::create_event_with_idempotent_id_and_trigger_outgoing_webhook(
+                    state.clone(),
+                    merchant_account.clone(),
+                    business_profile.clone(),
+                    key_store,
+                    event_type,
+                    enums::EventClass::Invoices,
+                    invoice.invoice_id.clone(),
+                    enums::EventObjectType::InvoiceDetails,
+                    content,
+                    Some(invoice.created_at),
+                    idempotent_event_id,
+                ),
+            )
+            .await
+        }
+        None => {
+            Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+                state.clone(),
+                merchant_account.clone(),
+                business_profile.clone(),
+                key_store,
+                event_type,
+                enums::EventClass::Invoices,
+                invoice.invoice_id.clone(),
+                enums::EventObjectType::InvoiceDetails,
+                content,
+                Some(invoice.created_at),
+            ))
+            .await
+        }
+    };
+
+    result
+        .map_err(|error| logger::error!(?error, %event_type, "Failed to trigger invoice webhook"))
+        .ok();
+}
+
+fn validate_invoice_details(
+    description: Option<&str>,
+    line_items: Option<&[InvoiceLineItemRequest]>,
+    discount: Option<InvoiceDiscount>,
+    reminder_schedule: Option<&[i16]>,
+) -> RouterResult<()> {
+    let invalid_request = |message: String| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message
+        }))
+    };
+
+    if description.is_some_and(|description| description.len() > 255) {
+        return invalid_request("description must be at most 255 characters long".to_string());
+    }
+    if let Some(discount) = discount {
+        validate_discount(discount, "discount")?;
+    }
+    if reminder_schedule.is_some_and(|reminder_schedule| {
+        reminder_schedule
+            .iter()
+            .any(|offset| offset.abs() > consts::MAX_INVOICE_REMINDER_OFFSET_IN_DAYS)
+    }) {
+        return invalid_request(format!(
+            "reminder_schedule must contain days within {} days of the due date",
+            consts::MAX_INVOICE_REMINDER_OFFSET_IN_DAYS
+        ));
+    }
+
+    let line_items = line_items.unwrap_or_default();
+    if line_items.len() > consts::MAX_INVOICE_LINE_ITEMS {
+        return invalid_request(format!(
+            "An invoice can have at most {} line_items",
+            consts::MAX_INVOICE_LINE_ITEMS
+        ));
+    }
+    for line_item in line_items {
+        if line_item.description.is_empty() || line_item.description.len() > 255 {
+            return invalid_request(
+                "description of the line_items must be between 1 and 255 characters long"
+                    .to_string(),
+            );
+        }
+        if line_item.quantity == 0 {
+            return invalid_request(
+                "quantity of the line_items must be greater than zero".to_string(),
+            );
+        }
+        if line_item.unit_amount < MinorUnit::zero() {
+            return invalid_request("unit_amount of the line_items cannot be negative".to_string());
+        }
+        if let Some(discount) = line_item.discount {
+            validate_discount(discount, "discount of the line_items")?;
+        }
+        for tax_rate in &line_item.tax_rates {
+            if tax_rate.name.is_empty() || tax_rate.name.len() > 64 {
+                return invalid_request(
+                    "name of the tax_rates must be between 1 and 64 characters long".to_string(),
+                );
+            }
+            if tax_rate.basis_points > 10000 {
+                return invalid_request(
+                    "basis_points of the tax_rates must be at most 10000".to_string(),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_discount(discount: InvoiceDiscount, field_name: &str) -> RouterResult<()> {
+    let is_valid = match discount {
+        InvoiceDiscount::Amount { amount } => amount >= MinorUnit::zero(),
+        InvoiceDiscount::Percentage { basis_points } => basis_points <= 10000,
+    };
+    if is_valid {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "{field_name} must be a non-negative amount or a percentage of at most 10000 basis points"
+            ),
+        }))
+    }
+}
+
+/// Applies the basis points to the amount, rounding half up
+fn apply_basis_points(amount: i64, basis_points: u16) -> Option<i64> {
+    let result = (i128::from(amount) * i128::from(basis_points) + 5000) / 10000;
+    i64::try_from(result).ok()
+}
+
+fn get_discount_amount(discount: Option<InvoiceDiscount>, base_amount: i64) -> Option<i64> {
+    let discount_amount = match discount {
+        None => 0,
+        Some(InvoiceDiscount::Amount { amount }) => amount.get_amount_as_i64(),
+        Some(InvoiceDiscount::Percentage { basis_points }) => {
+            apply_basis_points(base_amount, basis_points)?
+        }
+    };
+    Some(discount_amount.clamp(0, base_amount.max(0)))
+}
+
+/// Computes the amounts of the invoice.
+///
+/// The discount of every line item is applied to its amount first. The discount of the invoice is
+/// then applied to the sum of the discounted line items, and allocated to the line items in
+/// proportion to their discounted amounts so that taxes are computed on the amount actually paid.
+/// Taxes are computed per line item and grouped by tax rate.
+fn compute_totals(
+    line_items: &[InvoiceLineItemRequest],
+    discount: Option<InvoiceDiscount>,
+) -> RouterResult<InvoiceTotals> {
+    let overflow = || {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The amounts of the invoice are too large".to_string(),
+        })
+    };
+
+    let mut gross_amounts = Vec::with_capacity(line_items.len());
+    let mut net_amounts = Vec::with_capacity(line_items.len());
+    for line_item in line_items {
+        let gross_amount = line_item
+            .unit_amount
+            .get_amount_as_i64()
+            .checked_mul(i64::from(line_item.quantity))
+            .ok_or_else(overflow)?;
+        let line_discount_amount =
+            get_discount_amount(line_item.discount, gross_amount).ok_or_else(overflow)?;
+        gross_amounts.push(gross_amount);
+        net_amounts.push(gross_amount - line_discount_amount);
+    }
+
+    let net_total = checked_sum(net_amounts.iter().copied()).ok_or_else(overflow)?;
+    let invoice_discount_amount = get_discount_amount(discount, net_total).ok_or_else(overflow)?;
+
+    // Proportional shares are rounded down, the remainder is spread one unit at a time over the
+    // line items which still have an amount left to discount
+    let mut shares = net_amounts
+        .iter()
+        .map(|net_amount| {
+            if net_total == 0 {
+                Some(0)
+            } else {
+                i64::try_from(
+                    i128::from(invoice_discount_amount) * i128::from(*net_amount)
+                        / i128::from(net_total),
+                )
+                .ok()
+            }
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(overflow)?;
+    let mut remainder = invoice_discount_amount - shares.iter().sum::<i64>();
+    for (share, net_amount) in shares.iter_mut().zip(net_amounts.iter()) {
+        let allocation = remainder.min(net_amount - *share);
+        *share += allocation;
+        remainder -= allocation;
+    }
+
+    let mut tax_lines: Vec<InvoiceTaxLine> = Vec::new();
+    let mut line_item_amounts = Vec::with_capacity(line_items.len());
+    for (((line_item, gross_amount), net_amount), share) in line_items
+        .iter()
+        .zip(gross_amounts.iter())
+        .zip(net_amounts.iter())
+        .zip(shares.iter())
+    {
+        let taxable_amount = net_amount - share;
+        let mut line_tax_amount = 0i64;
+        for tax_rate in &line_item.tax_rates {
+            let tax_amount =
+                apply_basis_points(taxable_amount, tax_rate.basis_points).ok_or_else(overflow)?;
+            line_tax_amount = line_tax_amount
+                .checked_add(tax_amount)
+                .ok_or_else(overflow)?;
+            match tax_lines.iter_mut().find(|tax_line| {
+                tax_line.name == tax_rate.name && tax_line.basis_points == tax_rate.basis_points
+            }) {
+                Some(tax_line) => {
+                    tax_line.taxable_amount =
+                        tax_line.taxable_amount + MinorUnit::new(taxable_amount);
+                    tax_line.amount = tax_line.amount + MinorUnit::new(tax_amount);
+                }
+                None => tax_lines.push(InvoiceTaxLine {
+                    name: tax_rate.name.clone(),
+                    basis_points: tax_rate.basis_points,
+                    taxable_amount: MinorUnit::new(taxable_amount),
+                    amount: MinorUnit::new(tax_amount),
+                }),
+            }
+        }
+        line_item_amounts.push(LineItemAmounts {
+            amount: MinorUnit::new(*gross_amount),
+            discount_amount: MinorUnit::new(gross_amount - taxable_amount),
+            tax_amount: MinorUnit::new(line_tax_amount),
+        });
+    }
+
+    let subtotal = checked_sum(gross_amounts.iter().copied()).ok_or_else(overflow)?;
+    let discount_amount = checked_sum(
+        line_item_amounts
+            .iter()
+            .map(|amounts| amounts.discount_amount.get_amount_as_i64()),
+    )
+    .ok_or_else(overflow)?;
+    let tax_amount = checked_sum(
+        line_item_amounts
+            .iter()
+            .map(|amounts| amounts.tax_amount.get_amount_as_i64()),
+    )
+    .ok_or_else(overflow)?;
+    let total = subtotal
+        .checked_sub(discount_amount)
+        .and_then(|total| total.checked_add(tax_amount))
+        .ok_or_else(overflow)?;
+
+    Ok(InvoiceTotals {
+        line_items: line_item_amounts,
+        tax_lines,
+        subtotal: MinorUnit::new(subtotal),
+        discount_amount: MinorUnit::new(discount_amount),
+        tax_amount: MinorUnit::new(tax_amount),
+        total: MinorUnit::new(total),
+    })
+}
+
+fn checked_sum(amounts: impl Iterator<Item = i64>) -> Option<i64> {
+    amounts.fold(Some(0i64), |total, amount| total?.checked_add(amount))
+}
+
+fn get_line_item_request(
+    line_item: storage::InvoiceLineItem,
+) -> RouterResult<InvoiceLineItemRequest> {
+    Ok(InvoiceLineItemRequest {
+        description: line_item.description,
+        quantity: u16::try_from(line_item.quantity).unwrap_or_default(),
+        unit_amount: line_item.unit_amount,
+        discount: parse_discount(line_item.discount.as_ref())?,
+        tax_rates: parse::<Vec<InvoiceTaxRate>>(line_item.tax_rates, "Vec<InvoiceTaxRate>")?,
+    })
+}
+
+fn parse_discount(discount: Option<&serde_json::Value>) -> RouterResult<Option<InvoiceDiscount>> {
+    discount
+        .cloned()
+        .map(|discount| parse(discount, "InvoiceDiscount"))
+        .transpose()
+}
+
+fn parse<T: serde::de::DeserializeOwned>(
+    value: serde_json::Value,
+    type_name: &'static str,
+) -> RouterResult<T> {
+    value
+        .parse_value(type_name)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to parse {type_name} of invoice"))
+}
+
+fn encode<T: serde::Serialize>(value: &T, field_name: &str) -> RouterResult<serde_json::Value> {
+    value
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to encode {field_name} of invoice"))
+}
+
+fn encode_optional<T: serde::Serialize>(
+    value: Option<&T>,
+    field_name: &str,
+) -> RouterResult<Option<serde_json::Value>> {
+    value.map(|value| encode(value, field_name)).transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn line_item(
+        unit_amount: i64,
+        quantity: u16,
+        discount: Option<InvoiceDiscount>,
+        tax_rates: &[(&str, u16)],
+    ) -> InvoiceLineItemRequest {
+        InvoiceLineItemRequest {
+            description: "Item".to_string(),
+            quantity,
+            unit_amount: MinorUnit::new(unit_amount),
+            discount,
+            tax_rates: tax_rates
+                .iter()
+                .map(|(name, basis_points)| InvoiceTaxRate {
+                    name: (*name).to_string(),
+                    basis_points: *basis_points,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_totals_apply_line_discounts_before_taxes() {
+        let line_items = [
+            line_item(
+                1000,
+                3,
+                Some(InvoiceDiscount::Amount {
+                    amount: MinorUnit::new(500),
+                }),
+                &[("VAT", 1900)],
+            ),
+            line_item(2500, 1, None, &[("VAT", 1900), ("City tax", 250)]),
+        ];
+        let totals = compute_totals(&line_items, None).unwrap();
+
+        assert_eq!(totals.subtotal, MinorUnit::new(5500));
+        assert_eq!(totals.discount_amount, MinorUnit::new(500));
+        // 19% of 2500 and 2500, plus 2.5% of 2500 rounded half up
+        assert_eq!(totals.tax_amount, MinorUnit::new(475 + 475 + 63));
+        assert_eq!(totals.total, MinorUnit::new(5000 + 1013));
+        assert_eq!(totals.tax_lines.len(), 2);
+        assert_eq!(
+            totals.tax_lines.first().unwrap().taxable_amount,
+            MinorUnit::new(5000)
+        );
+        assert_eq!(
+            totals.tax_lines.first().unwrap().amount,
+            MinorUnit::new(950)
+        );
+    }
+
+    #[test]
+    fn test_invoice_discount_is_allocated_to_all_line_items() {
+        let line_items = [
+            line_item(1000, 1, None, &[("VAT", 1000)]),
+            line_item(1000, 1, None, &[]),
+            line_item(1000, 1, None, &[]),
+        ];
+        let totals = compute_totals(
+            &line_items,
+            Some(InvoiceDiscount::Amount {
+                amount: MinorUnit::new(100),
+            }),
+        )
+        .unwrap();
+
+        let discounts = totals
+            .line_items
+            .iter()
+            .map(|amounts| amounts.discount_amount.get_amount_as_i64())
+            .collect::<Vec<_>>();
+        assert_eq!(discounts, vec![34, 33, 33]);
+        assert_eq!(totals.discount_amount, MinorUnit::new(100));
+        assert_eq!(totals.tax_amount, MinorUnit::new(97));
+        assert_eq!(totals.total, MinorUnit::new(2997));
+    }
+
+    #[test]
+    fn test_discounts_do_not_exceed_the_amount() {
+        let line_items = [line_item(
+            1000,
+            2,
+            Some(InvoiceDiscount::Percentage {
+                basis_points: 10000,
+            }),
+            &[("VAT", 1900)],
+        )];
+        let totals = compute_totals(
+            &line_items,
+            Some(InvoiceDiscount::Amount {
+                amount: MinorUnit::new(500),
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(totals.discount_amount, MinorUnit::new(2000));
+        assert_eq!(totals.tax_amount, MinorUnit::zero());
+        assert_eq!(totals.total, MinorUnit::zero());
+    }
+
+    #[test]
+    fn test_invoice_number_is_zero_padded() {
+        let now = common_utils::date_time::now();
+        let sequence = |prefix: &str, next_number| storage::InvoiceNumberSequence {
+            profile_id: "pro_1".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::get_irrelevant_merchant_id(),
+            prefix: prefix.to_string(),
+            next_number,
+            created_at: now,
+            modified_at: now,
+        };
+
+        assert_eq!(sequence("INV-", 42).get_next_invoice_number(), "INV-000042");
+        assert_eq!(sequence("", 1234567).get_next_invoice_number(), "1234567");
+    }
+
+    #[test]
+    fn test_invoice_payment_task_is_tracked_per_status() {
+        assert_ne!(
+            generate_task_id_for_invoice_payment_workflow("pay_1", enums::IntentStatus::Failed),
+            generate_task_id_for_invoice_payment_workflow("pay_1", enums::IntentStatus::Succeeded)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invoice_payment_is_credited_once() {
+        use crate::db::invoice::InvoiceInterface;
+
+        let db = storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let merchant_id = common_utils::id_type::MerchantId::get_irrelevant_merchant_id();
+        let now = common_utils::date_time::now();
+        db.insert_invoice(storage::InvoiceNew {
+            invoice_id: "inv_1".to_string(),
+            merchant_id: merchant_id.clone(),
+            profile_id: "pro_1".to_string(),
+            customer_id: common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from(
+                "cus_1",
+            ))
+            .unwrap(),
+            invoice_number: Some("INV-000001".to_string()),
+            status: enums::InvoiceStatus::Open,
+            currency: enums::Currency::USD,
+            description: None,
+            discount: None,
+            tax_lines: serde_json::json!([]),
+            subtotal: MinorUnit::new(1000),
+            discount_amount: MinorUnit::zero(),
+            tax_amount: MinorUnit::zero(),
+            total: MinorUnit::new(1000),
+            amount_paid: MinorUnit::zero(),
+            due_date: None,
+            reminder_schedule: None,
+            payment_id: Some("pay_1".to_string()),
+            payment_link: None,
+            metadata: None,
+            finalized_at: Some(now),
+            paid_at: None,
+            voided_at: None,
+            marked_uncollectible_at: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .unwrap();
+        db.insert_invoice_payment(storage::InvoicePaymentNew {
+            payment_id: "pay_1".to_string(),
+            invoice_id: "inv_1".to_string(),
+            merchant_id: merchant_id.clone(),
+            source: enums::InvoicePaymentSource::PaymentLink,
+            amount: MinorUnit::new(1000),
+            amount_received: None,
+            currency: enums::Currency::USD,
+            status: enums::IntentStatus::RequiresPaymentMethod,
+            reference: None,
+            credited: false,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .unwrap();
+
+        let invoice = db
+            .credit_invoice_payment_by_payment_id(
+                "pay_1",
+                enums::IntentStatus::PartiallyCaptured,
+                MinorUnit::new(600),
+            )
+            .await
+            .unwrap();
+        assert_eq!(invoice.amount_paid, MinorUnit::new(600));
+
+        // A retried update of the same payment is not credited again
+        let error = db
+            .credit_invoice_payment_by_payment_id(
+                "pay_1",
+                enums::IntentStatus::PartiallyCaptured,
+                MinorUnit::new(600),
+            )
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+        let invoice = db
+            .find_invoice_by_merchant_id_invoice_id(&merchant_id, "inv_1")
+            .await
+            .unwrap();
+        assert_eq!(invoice.amount_paid, MinorUnit::new(600));
+
+        let payment = db
+            .find_invoice_payment_by_payment_id("pay_1")
+            .await
+            .unwrap();
+        assert!(payment.credited);
+        assert_eq!(payment.amount_received, Some(MinorUnit::new(600)));
+    }
+
+    #[test]
+    fn test_only_namespaced_invoice_metadata_links_a_payment_to_an_invoice() {
+        let metadata = serde_json::json!({
+            (consts::INVOICE_PAYMENT_METADATA_KEY): { "invoice_id": "inv_1" },
+        });
+        assert_eq!(
+            get_invoice_id_from_payment_metadata(Some(&metadata)),
+            Some("inv_1")
+        );
+
+        let merchant_metadata = serde_json::json!({ "invoice_id": "inv_1" });
+        assert_eq!(
+            get_invoice_id_from_payment_metadata(Some(&merchant_metadata)),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invoice_number_is_taken_only_when_the_invoice_is_finalized() {
+        use crate::db::invoice::InvoiceInterface;
+
+        let db = storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let merchant_id = common_utils::id_type::MerchantId::get_irrelevant_merchant_id();
+        let now = common_utils::date_time::now();
+        db.insert_invoice_number_sequence(storage::InvoiceNumberSequenceNew {
+            profile_id: "pro_1".to_string(),
+            merchant_id: merchant_id.clone(),
+            prefix: "INV-".to_string(),
+            next_number: 1,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .unwrap();
+        db.insert_invoice(storage::InvoiceNew {
+            invoice_id: "inv_1".to_string(),
+            merchant_id: merchant_id.clone(),
+            profile_id: "pro_1".to_string(),
+            customer_id: common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from(
+                "cus_1",
+            ))
+            .unwrap(),
+            invoice_number: None,
+            status: enums::InvoiceStatus::Draft,
+            currency: enums::Currency::USD,
+            description: None,
+            discount: None,
+            tax_lines: serde_json::json!([]),
+            subtotal: MinorUnit::new(1000),
+            discount_amount: MinorUnit::zero(),
+            tax_amount: MinorUnit::zero(),
+            total: MinorUnit::new(1000),
+            amount_paid: MinorUnit::zero(),
+            due_date: None,
+            reminder_schedule: None,
+            payment_id: None,
+            payment_link: None,
+            metadata: None,
+            finalized_at: None,
+            paid_at: None,
+            voided_at: None,
+            marked_uncollectible_at: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .unwrap();
+
+        let invoice = db
+            .finalize_invoice_with_next_invoice_number(&merchant_id, "inv_1", "pro_1", now)
+            .await
+            .unwrap();
+        assert_eq!(invoice.status, enums::InvoiceStatus::Open);
+        assert_eq!(invoice.invoice_number.as_deref(), Some("INV-000001"));
+
+        // An invoice that is no longer a draft does not take a number
+        let error = db
+            .finalize_invoice_with_next_invoice_number(&merchant_id, "inv_1", "pro_1", now)
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+        let sequence = db
+            .find_invoice_number_sequence_by_profile_id("pro_1")
+            .await
+            .unwrap();
+        assert_eq!(sequence.next_number, 2);
+    }
+}
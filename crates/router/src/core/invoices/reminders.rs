@@ -0,0 +1,386 @@
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, ValueExt},
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    routes::{metrics, SessionState},
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const INVOICE_REMINDER_TAG: &str = "INVOICE";
+const INVOICE_REMINDER_NAME: &str = "INVOICE_REMINDER";
+const INVOICE_REMINDER_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::InvoiceReminderWorkflow;
+
+fn generate_task_id_for_invoice_reminder_workflow(invoice_id: &str) -> String {
+    format!("{INVOICE_REMINDER_RUNNER}_{INVOICE_REMINDER_NAME}_{invoice_id}")
+}
+
+/// Reminder offsets of the invoice ordered from the earliest reminder to the latest one, falling
+/// back to the default offsets when the invoice does not specify a reminder schedule
+fn get_sorted_reminder_offsets(invoice: &storage::Invoice) -> RouterResult<Vec<i16>> {
+    let mut offsets = match invoice.reminder_schedule.clone() {
+        Some(reminder_schedule) => reminder_schedule
+            .parse_value::<Vec<i16>>("Vec<i16>")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse reminder schedule of invoice")?,
+        None => consts::DEFAULT_INVOICE_REMINDER_OFFSETS_IN_DAYS.to_vec(),
+    };
+    offsets.sort_unstable();
+    offsets.dedup();
+    Ok(offsets)
+}
+
+fn get_reminder_time(
+    due_date: time::PrimitiveDateTime,
+    offset_in_days: i16,
+) -> time::PrimitiveDateTime {
+    due_date.saturating_add(time::Duration::days(i64::from(offset_in_days)))
+}
+
+/// Returns the index of the next reminder to be sent along with the time at which it has to be
+/// sent, or `None` once all the reminders have been sent.
+fn get_next_schedule(
+    due_date: time::PrimitiveDateTime,
+    reminder_offsets_in_days: &[i16],
+    starting_index: usize,
+    current_time: time::PrimitiveDateTime,
+) -> Option<(usize, time::PrimitiveDateTime)> {
+    reminder_offsets_in_days
+        .iter()
+        .enumerate()
+        .skip(starting_index)
+        .map(|(index, offset)| (index, get_reminder_time(due_date, *offset)))
+        .find(|(_, reminder_time)| *reminder_time > current_time)
+}
+
+/// Describes the due date of the invoice relative to the day on which the reminder is sent
+fn get_due_date_message(
+    due_date: time::PrimitiveDateTime,
+    current_time: time::PrimitiveDateTime,
+) -> String {
+    let days = (due_date.date() - current_time.date()).whole_days();
+    match days {
+        0 => "is due today".to_string(),
+        1 => "is due tomorrow".to_string(),
+        -1 => "was due yesterday".to_string(),
+        days if days > 0 => format!("is due in {days} days"),
+        days => format!("was due {} days ago", days.saturating_abs()),
+    }
+}
+
+/// Schedules the payment reminders of a finalized invoice, when the invoice has a due date and a
+/// reminder left to be sent.
+#[instrument(skip_all)]
+pub async fn add_invoice_reminder_task(
+    state: &SessionState,
+    invoice: &storage::Invoice,
+) -> RouterResult<()> {
+    let Some(due_date) = invoice.due_date else {
+        return Ok(());
+    };
+
+    let reminder_offsets_in_days = get_sorted_reminder_offsets(invoice)?;
+    let Some((reminder_index, schedule_time)) =
+        get_next_schedule(due_date, &reminder_offsets_in_days, 0, date_time::now())
+    else {
+        return Ok(());
+    };
+
+    let tracking_data = storage::InvoiceReminderTrackingData {
+        invoice_id: invoice.invoice_id.clone(),
+        merchant_id: invoice.merchant_id.clone(),
+        due_date,
+        reminder_offsets_in_days,
+        reminder_index,
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_invoice_reminder_workflow(&invoice.invoice_id),
+        INVOICE_REMINDER_NAME,
+        INVOICE_REMINDER_RUNNER,
+        [INVOICE_REMINDER_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct invoice reminder process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting invoice reminder task to process_tracker: invoice_id: {}",
+                invoice.invoice_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "InvoiceReminder")]),
+    );
+
+    Ok(())
+}
+
+pub async fn start_invoice_reminder_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::InvoiceReminderTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("InvoiceReminderTrackingData")?;
+
+    let invoice = db
+        .find_invoice_by_merchant_id_invoice_id(
+            &tracking_data.merchant_id,
+            &tracking_data.invoice_id,
+        )
+        .await?;
+
+    // The invoice has been paid, voided or marked uncollectible since the task was scheduled
+    if invoice.status != storage_enums::InvoiceStatus::Open
+        || invoice.due_date != Some(tracking_data.due_date)
+    {
+        db.as_scheduler()
+            .finish_process_with_business_status(
+                process.clone(),
+                business_status::RESOURCE_STATUS_MISMATCH,
+            )
+            .await?;
+        return Ok(());
+    }
+
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+
+    let current_time = date_time::now();
+    send_invoice_reminder(state, &merchant_account, &key_store, &invoice, current_time).await;
+
+    match get_next_schedule(
+        tracking_data.due_date,
+        &tracking_data.reminder_offsets_in_days,
+        tracking_data.reminder_index + 1,
+        current_time,
+    ) {
+        Some((reminder_index, schedule_time)) => {
+            let tracking_data = storage::InvoiceReminderTrackingData {
+                reminder_index,
+                ..tracking_data
+            };
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(process.retry_count),
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data.encode_to_value()?),
+                business_status: None,
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.process_tracker_update_process_status_by_ids(
+                vec![process.id.clone()],
+                updated_process_tracker_data,
+            )
+            .await?;
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process.clone(),
+                    business_status::COMPLETED_BY_PT,
+                )
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Emails a payment reminder to the customer of the invoice, when emails are enabled. Failures are
+/// logged and do not block the subsequent reminders.
+#[allow(unused_variables)]
+async fn send_invoice_reminder(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &storage::Invoice,
+    current_time: time::PrimitiveDateTime,
+) {
+    #[cfg(feature = "email")]
+    {
+        let _ =
+            send_invoice_reminder_email(state, merchant_account, key_store, invoice, current_time)
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to send invoice reminder email"));
+    }
+    #[cfg(not(feature = "email"))]
+    logger::debug!(
+        invoice_id = %invoice.invoice_id,
+        "Emails are disabled, skipping invoice reminder"
+    );
+}
+
+#[cfg(feature = "email")]
+async fn send_invoice_reminder_email(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &storage::Invoice,
+    current_time: time::PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    use common_utils::{
+        pii,
+        types::{AmountConvertor, StringMajorUnitForCore},
+    };
+    use masking::PeekInterface;
+
+    use crate::services::email::types::InvoiceReminder;
+
+    let customer = state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &state.into(),
+            &invoice.customer_id,
+            &invoice.merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    let email_id = customer.email.map(pii::Email::from).ok_or(
+        errors::ProcessTrackerError::EValidationError(
+            common_utils::errors::ValidationError::MissingRequiredField {
+                field_name: "email".to_string(),
+            }
+            .into(),
+        ),
+    )?;
+    let payment_link =
+        invoice
+            .payment_link
+            .clone()
+            .ok_or(errors::ProcessTrackerError::EValidationError(
+                common_utils::errors::ValidationError::MissingRequiredField {
+                    field_name: "payment_link".to_string(),
+                }
+                .into(),
+            ))?;
+    let amount_remaining = StringMajorUnitForCore
+        .convert(invoice.get_amount_remaining(), invoice.currency)
+        .map_err(|error| {
+            logger::error!(?error, "Failed to convert amount remaining of the invoice");
+            errors::ProcessTrackerError::EApiErrorResponse
+        })?
+        .get_amount_as_string();
+    let merchant_name = merchant_account
+        .merchant_name
+        .clone()
+        .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+        .unwrap_or_default();
+    let invoice_number = invoice.invoice_number.clone().unwrap_or_default();
+
+    let email_contents = InvoiceReminder {
+        recipient_email: domain::UserEmail::from_pii_email(email_id).map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to convert recipient's email to UserEmail from pii::Email"
+            );
+            errors::ProcessTrackerError::EApiErrorResponse
+        })?,
+        subject: format!("Payment reminder for invoice {invoice_number}"),
+        merchant_name,
+        invoice_number,
+        amount_remaining,
+        currency: invoice.currency.to_string(),
+        due_date_message: invoice
+            .due_date
+            .map(|due_date| get_due_date_message(due_date, current_time))
+            .unwrap_or_default(),
+        payment_link,
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .map_err(errors::ProcessTrackerError::EEmailError)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_next_schedule_skips_lapsed_reminders() {
+        let due_date = datetime!(2024-09-30 0:00);
+        let offsets = [-3, 0, 7];
+
+        assert_eq!(
+            get_next_schedule(due_date, &offsets, 0, datetime!(2024-09-20 0:00)),
+            Some((0, datetime!(2024-09-27 0:00)))
+        );
+        assert_eq!(
+            get_next_schedule(due_date, &offsets, 0, datetime!(2024-09-28 0:00)),
+            Some((1, due_date))
+        );
+        assert_eq!(
+            get_next_schedule(due_date, &offsets, 2, datetime!(2024-09-28 0:00)),
+            Some((2, datetime!(2024-10-07 0:00)))
+        );
+        assert_eq!(
+            get_next_schedule(due_date, &offsets, 0, datetime!(2024-10-08 0:00)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_due_date_message() {
+        let due_date = datetime!(2024-09-30 12:00);
+
+        assert_eq!(
+            get_due_date_message(due_date, datetime!(2024-09-27 8:00)),
+            "is due in 3 days"
+        );
+        assert_eq!(
+            get_due_date_message(due_date, datetime!(2024-09-30 18:00)),
+            "is due today"
+        );
+        assert_eq!(
+            get_due_date_message(due_date, datetime!(2024-10-07 0:00)),
+            "was due 7 days ago"
+        );
+    }
+}
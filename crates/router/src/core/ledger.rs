@@ -0,0 +1,1405 @@
+//! Double-entry ledger of the money movements of a merchant, per currency.
+//!
+//! Journal entries are derived from payment attempts, refunds, disputes and payouts as they reach
+//! the states which move money, each entry debiting exactly the amount it credits. An entry of a
+//! given type is recorded at most once for the record it was derived from, so that deriving the
+//! entries of a record again, as done when the ledger is rebuilt from historical data, is
+//! harmless.
+//!
+//! Authorizations are tracked off balance, debiting the pending authorizations and crediting the
+//! authorization holds until the payment is captured or voided. Captures recognize the sale
+//! against the amount receivable from the processor, which refunds, chargebacks, fees and payouts
+//! then reduce.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[cfg(feature = "olap")]
+use api_models::ledger::LedgerRebuildResponse;
+use api_models::{
+    ledger::{
+        LedgerAccountBalance, LedgerBalancesRequest, LedgerBalancesResponse,
+        LedgerEntryListConstraints, LedgerEntryListResponse, LedgerEntryResponse,
+        LedgerPostingResponse, LedgerStatementLine, LedgerStatementRequest,
+        LedgerStatementResponse,
+    },
+    payments::PaymentChargeRequest,
+};
+#[cfg(feature = "olap")]
+use common_utils::ext_traits::Encode;
+use common_utils::{ext_traits::ValueExt, id_type, pii, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::{payment_attempt::PaymentAttempt, PaymentIntent};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+#[cfg(feature = "olap")]
+use router_env::{logger, metrics::add_attributes};
+#[cfg(feature = "olap")]
+use storage_impl::DataModelExt;
+use time::PrimitiveDateTime;
+
+#[cfg(feature = "olap")]
+use crate::routes::metrics;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+    utils::{self, OptionExt},
+};
+
+/// A journal entry derived from a payment attempt, refund, dispute or payout, not yet recorded
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct JournalEntry {
+    entry_type: enums::LedgerEntryType,
+    currency: enums::Currency,
+    source_id: String,
+    reference_id: String,
+    description: String,
+    effective_at: PrimitiveDateTime,
+    postings: Vec<JournalPosting>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct JournalPosting {
+    account: enums::LedgerAccount,
+    direction: enums::LedgerPostingDirection,
+    amount: MinorUnit,
+}
+
+impl JournalEntry {
+    fn new(
+        entry_type: enums::LedgerEntryType,
+        currency: enums::Currency,
+        source_id: &str,
+        reference_id: &str,
+        description: String,
+        effective_at: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            entry_type,
+            currency,
+            source_id: source_id.to_string(),
+            reference_id: reference_id.to_string(),
+            description,
+            effective_at,
+            postings: Vec::new(),
+        }
+    }
+
+    /// Debits the first account and credits the second one with the amount, unless the amount is
+    /// zero
+    fn transfer(
+        mut self,
+        debit: enums::LedgerAccount,
+        credit: enums::LedgerAccount,
+        amount: MinorUnit,
+    ) -> Self {
+        if amount != MinorUnit::zero() {
+            self.postings.push(JournalPosting {
+                account: debit,
+                direction: enums::LedgerPostingDirection::Debit,
+                amount,
+            });
+            self.postings.push(JournalPosting {
+                account: credit,
+                direction: enums::LedgerPostingDirection::Credit,
+                amount,
+            });
+        }
+        self
+    }
+}
+
+/// Applies a posting to the balance of an account, on the normal side of the account
+fn apply_posting(
+    account: enums::LedgerAccount,
+    balance: MinorUnit,
+    direction: enums::LedgerPostingDirection,
+    amount: MinorUnit,
+) -> MinorUnit {
+    let is_debit = direction == enums::LedgerPostingDirection::Debit;
+    if is_debit == account.is_debit_normal() {
+        balance + amount
+    } else {
+        balance - amount
+    }
+}
+
+/// The details of a payment from which the entries of its attempts are derived
+#[derive(Clone, Debug)]
+struct PaymentDetails {
+    currency: Option<enums::Currency>,
+    amount_captured: Option<MinorUnit>,
+    active_attempt_id: String,
+    charges: Option<pii::SecretSerdeValue>,
+}
+
+impl From<&PaymentIntent> for PaymentDetails {
+    fn from(payment_intent: &PaymentIntent) -> Self {
+        Self {
+            currency: payment_intent.currency,
+            amount_captured: payment_intent.amount_captured,
+            active_attempt_id: payment_intent.active_attempt.get_id(),
+            charges: payment_intent.charges.clone(),
+        }
+    }
+}
+
+impl From<&diesel_models::PaymentIntent> for PaymentDetails {
+    fn from(payment_intent: &diesel_models::PaymentIntent) -> Self {
+        Self {
+            currency: payment_intent.currency,
+            amount_captured: payment_intent.amount_captured,
+            active_attempt_id: payment_intent.active_attempt_id.clone(),
+            charges: payment_intent.charges.clone(),
+        }
+    }
+}
+
+/// Derives the authorization, capture, fee and void entries of a payment attempt from its status
+fn get_payment_attempt_entries(
+    payment: &PaymentDetails,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<Vec<JournalEntry>> {
+    let Some(currency) = payment_attempt.currency.or(payment.currency) else {
+        return Ok(Vec::new());
+    };
+    let payment_id = payment_attempt.payment_id.as_str();
+    let attempt_id = payment_attempt.attempt_id.as_str();
+    let authorized_amount = payment_attempt.net_amount;
+    let mut entries = Vec::new();
+
+    let is_authorized = matches!(
+        payment_attempt.status,
+        enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::PartialChargedAndChargeable
+            | enums::AttemptStatus::CaptureInitiated
+            | enums::AttemptStatus::CaptureFailed
+            | enums::AttemptStatus::VoidInitiated
+            | enums::AttemptStatus::VoidFailed
+            | enums::AttemptStatus::Voided
+    );
+    if is_authorized {
+        entries.push(
+            JournalEntry::new(
+                enums::LedgerEntryType::Authorization,
+                currency,
+                attempt_id,
+                payment_id,
+                format!("Authorization of payment {payment_id}"),
+                payment_attempt.created_at,
+            )
+            .transfer(
+                enums::LedgerAccount::PendingAuthorizations,
+                enums::LedgerAccount::AuthorizationHolds,
+                authorized_amount,
+            ),
+        );
+    }
+
+    // Only final captures are recorded, further captures may follow a partial capture which
+    // leaves the payment chargeable
+    let is_captured = matches!(
+        payment_attempt.status,
+        enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged
+    );
+    if is_captured {
+        // The amount captured of the payment is that of its active attempt
+        let captured_amount = payment
+            .amount_captured
+            .filter(|_| payment.active_attempt_id == payment_attempt.attempt_id)
+            .or(payment_attempt.amount_to_capture)
+            .unwrap_or(authorized_amount);
+        entries.push(
+            JournalEntry::new(
+                enums::LedgerEntryType::Capture,
+                currency,
+                attempt_id,
+                payment_id,
+                format!("Capture of payment {payment_id}"),
+                payment_attempt.modified_at,
+            )
+            .transfer(
+                enums::LedgerAccount::AuthorizationHolds,
+                enums::LedgerAccount::PendingAuthorizations,
+                authorized_amount,
+            )
+            .transfer(
+                enums::LedgerAccount::ProcessorReceivable,
+                enums::LedgerAccount::Sales,
+                captured_amount,
+            ),
+        );
+
+        if let Some(charges) = payment.charges.as_ref() {
+            let charges: PaymentChargeRequest = charges
+                .peek()
+                .clone()
+                .parse_value("PaymentChargeRequest")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the charges of the payment")?;
+            entries.push(
+                JournalEntry::new(
+                    enums::LedgerEntryType::Fee,
+                    currency,
+                    attempt_id,
+                    payment_id,
+                    format!("Fees on payment {payment_id}"),
+                    payment_attempt.modified_at,
+                )
+                .transfer(
+                    enums::LedgerAccount::Fees,
+                    enums::LedgerAccount::ProcessorReceivable,
+                    charges.fees,
+                ),
+            );
+        }
+    }
+
+    if payment_attempt.status == enums::AttemptStatus::Voided {
+        entries.push(
+            JournalEntry::new(
+                enums::LedgerEntryType::AuthorizationVoid,
+                currency,
+                attempt_id,
+                payment_id,
+                format!("Void of the authorization of payment {payment_id}"),
+                payment_attempt.modified_at,
+            )
+            .transfer(
+                enums::LedgerAccount::AuthorizationHolds,
+                enums::LedgerAccount::PendingAuthorizations,
+                authorized_amount,
+            ),
+        );
+    }
+
+    Ok(entries)
+}
+
+fn get_refund_entries(refund: &storage::Refund) -> Vec<JournalEntry> {
+    if refund.refund_status != enums::RefundStatus::Success {
+        return Vec::new();
+    }
+    vec![JournalEntry::new(
+        enums::LedgerEntryType::Refund,
+        refund.currency,
+        &refund.refund_id,
+        &refund.payment_id,
+        format!(
+            "Refund {} of payment {}",
+            refund.refund_id, refund.payment_id
+        ),
+        refund.modified_at,
+    )
+    .transfer(
+        enums::LedgerAccount::Refunds,
+        enums::LedgerAccount::ProcessorReceivable,
+        refund.refund_amount,
+    )]
+}
+
+fn get_dispute_entries(dispute: &storage::Dispute) -> RouterResult<Vec<JournalEntry>> {
+    if dispute.dispute_status != enums::DisputeStatus::DisputeLost {
+        return Ok(Vec::new());
+    }
+    let currency = dispute
+        .currency
+        .parse::<enums::Currency>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Invalid currency {} of dispute {}",
+                dispute.currency, dispute.dispute_id
+            )
+        })?;
+    Ok(vec![JournalEntry::new(
+        enums::LedgerEntryType::Chargeback,
+        currency,
+        &dispute.dispute_id,
+        &dispute.payment_id,
+        format!(
+            "Chargeback of dispute {} on payment {}",
+            dispute.dispute_id, dispute.payment_id
+        ),
+        dispute.modified_at,
+    )
+    .transfer(
+        enums::LedgerAccount::Chargebacks,
+        enums::LedgerAccount::ProcessorReceivable,
+        MinorUnit::new(dispute.dispute_amount),
+    )])
+}
+
+#[cfg(feature = "payouts")]
+fn get_payout_entries(payouts: &storage::Payouts) -> Vec<JournalEntry> {
+    if payouts.status != enums::PayoutStatus::Success {
+        return Vec::new();
+    }
+    vec![JournalEntry::new(
+        enums::LedgerEntryType::Payout,
+        payouts.destination_currency,
+        &payouts.payout_id,
+        &payouts.payout_id,
+        format!("Payout {}", payouts.payout_id),
+        payouts.last_modified_at,
+    )
+    .transfer(
+        enums::LedgerAccount::Payouts,
+        enums::LedgerAccount::ProcessorReceivable,
+        payouts.amount,
+    )]
+}
+
+/// Records the entries which have postings, returning the number of entries newly recorded
+async fn record_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    entries: Vec<JournalEntry>,
+) -> RouterResult<usize> {
+    let mut recorded = 0;
+    for entry in entries
+        .into_iter()
+        .filter(|entry| !entry.postings.is_empty())
+    {
+        if record_entry(state, merchant_id, entry).await? {
+            recorded += 1;
+        }
+    }
+    Ok(recorded)
+}
+
+/// Converts the entry into the records of the entry and of its postings, checking that the entry
+/// is balanced
+fn into_ledger_entry(
+    merchant_id: &id_type::MerchantId,
+    entry: JournalEntry,
+    created_at: PrimitiveDateTime,
+) -> RouterResult<(storage::LedgerEntryNew, Vec<storage::LedgerPostingNew>)> {
+    let entry_id = utils::generate_id(consts::ID_LENGTH, "le");
+    let postings = entry
+        .postings
+        .iter()
+        .enumerate()
+        .map(|(line_number, posting)| {
+            Ok(storage::LedgerPostingNew {
+                entry_id: entry_id.clone(),
+                line_number: i16::try_from(line_number)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Too many postings in the ledger entry")?,
+                merchant_id: merchant_id.clone(),
+                currency: entry.currency,
+                account: posting.account,
+                direction: posting.direction,
+                amount: posting.amount,
+                effective_at: entry.effective_at,
+                created_at,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    // An unbalanced entry would throw all the balances of the ledger off, it is never recorded
+    if !storage::LedgerPostingNew::is_balanced(&postings) {
+        Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable_lazy(|| {
+            format!(
+                "Unbalanced {} ledger entry for {}",
+                entry.entry_type, entry.source_id
+            )
+        })?
+    }
+
+    Ok((
+        storage::LedgerEntryNew {
+            entry_id,
+            merchant_id: merchant_id.clone(),
+            currency: entry.currency,
+            entry_type: entry.entry_type,
+            source_id: entry.source_id,
+            reference_id: entry.reference_id,
+            description: Some(entry.description),
+            effective_at: entry.effective_at,
+            created_at,
+        },
+        postings,
+    ))
+}
+
+/// Records the entry along with its postings. An entry already recorded for the same record is
+/// left untouched, in which case `false` is returned.
+async fn record_entry(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    entry: JournalEntry,
+) -> RouterResult<bool> {
+    let source_id = entry.source_id.clone();
+    let (entry, postings) = into_ledger_entry(merchant_id, entry, common_utils::date_time::now())?;
+    let inserted = state.store.insert_ledger_entry(entry, postings).await;
+    match inserted {
+        Ok(_) => Ok(true),
+        Err(error)
+            if error.current_context().is_db_unique_violation()
+                || matches!(
+                    error.current_context(),
+                    errors::StorageError::DuplicateValue { .. }
+                ) =>
+        {
+            Ok(false)
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to record the ledger entry for {source_id}")),
+    }
+}
+
+/// Records the entries of a payment attempt which have not been recorded yet
+#[instrument(skip_all)]
+pub async fn record_payment_attempt_entries(
+    state: &SessionState,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<()> {
+    let entries =
+        get_payment_attempt_entries(&PaymentDetails::from(payment_intent), payment_attempt)?;
+    record_entries(state, &payment_attempt.merchant_id, entries).await?;
+    Ok(())
+}
+
+/// Records the entry of a refund once it has succeeded
+#[instrument(skip_all)]
+pub async fn record_refund_entries(
+    state: &SessionState,
+    refund: &storage::Refund,
+) -> RouterResult<()> {
+    record_entries(state, &refund.merchant_id, get_refund_entries(refund)).await?;
+    Ok(())
+}
+
+/// Records the chargeback of a dispute once it has been lost
+#[instrument(skip_all)]
+pub async fn record_dispute_entries(
+    state: &SessionState,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let entries = get_dispute_entries(dispute)?;
+    record_entries(state, &dispute.merchant_id, entries).await?;
+    Ok(())
+}
+
+/// Records the entry of a payout once it has succeeded
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn record_payout_entries(
+    state: &SessionState,
+    payouts: &storage::Payouts,
+) -> RouterResult<()> {
+    record_entries(state, &payouts.merchant_id, get_payout_entries(payouts)).await?;
+    Ok(())
+}
+
+/// Computes the balances of all the accounts of the ledger in a currency
+#[instrument(skip_all)]
+pub async fn retrieve_ledger_balances(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: LedgerBalancesRequest,
+) -> RouterResponse<LedgerBalancesResponse> {
+    let sums = state
+        .store
+        .sum_ledger_postings_by_merchant_id_currency(
+            merchant_account.get_id(),
+            req.currency,
+            req.as_of,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to sum ledger postings")?;
+
+    let mut totals: BTreeMap<enums::LedgerAccount, (MinorUnit, MinorUnit)> = BTreeMap::new();
+    for (account, direction, amount) in sums {
+        let (debits, credits) = totals
+            .entry(account)
+            .or_insert((MinorUnit::zero(), MinorUnit::zero()));
+        match direction {
+            enums::LedgerPostingDirection::Debit => *debits = *debits + amount,
+            enums::LedgerPostingDirection::Credit => *credits = *credits + amount,
+        }
+    }
+
+    let (total_debits, total_credits) = totals.values().fold(
+        (MinorUnit::zero(), MinorUnit::zero()),
+        |(total_debits, total_credits), (debits, credits)| {
+            (total_debits + *debits, total_credits + *credits)
+        },
+    );
+    let accounts = totals
+        .into_iter()
+        .map(|(account, (debits, credits))| LedgerAccountBalance {
+            account,
+            debits,
+            credits,
+            balance: if account.is_debit_normal() {
+                debits - credits
+            } else {
+                credits - debits
+            },
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(LedgerBalancesResponse {
+        currency: req.currency,
+        as_of: req.as_of,
+        accounts,
+        total_debits,
+        total_credits,
+    }))
+}
+
+/// Lists the postings to an account over a period, with the balance of the account after each of
+/// them
+#[instrument(skip_all)]
+pub async fn retrieve_ledger_statement(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: LedgerStatementRequest,
+) -> RouterResponse<LedgerStatementResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let account = req.account.get_required_value("account")?;
+    if let (Some(start_time), Some(end_time)) = (req.start_time, req.end_time) {
+        if start_time > end_time {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "start_time must not be later than end_time".to_string(),
+            }))?
+        }
+    }
+
+    let postings = db
+        .list_ledger_postings_by_merchant_id_currency(
+            merchant_id,
+            req.currency,
+            Some(account),
+            req.end_time,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list ledger postings")?;
+    let (opening_postings, period_postings): (Vec<_>, Vec<_>) =
+        postings.into_iter().partition(|posting| {
+            req.start_time
+                .is_some_and(|start_time| posting.effective_at < start_time)
+        });
+
+    let opening_balance = opening_postings
+        .iter()
+        .fold(MinorUnit::zero(), |balance, posting| {
+            apply_posting(account, balance, posting.direction, posting.amount)
+        });
+
+    let mut entry_ids: Vec<String> = period_postings
+        .iter()
+        .map(|posting| posting.entry_id.clone())
+        .collect();
+    entry_ids.sort();
+    entry_ids.dedup();
+    let entries: HashMap<String, storage::LedgerEntry> = if entry_ids.is_empty() {
+        HashMap::new()
+    } else {
+        db.list_ledger_entries_by_merchant_id_entry_ids(merchant_id, entry_ids)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list ledger entries")?
+            .into_iter()
+            .map(|entry| (entry.entry_id.clone(), entry))
+            .collect()
+    };
+
+    let mut balance = opening_balance;
+    let mut lines = Vec::with_capacity(period_postings.len());
+    for posting in period_postings {
+        let entry = entries
+            .get(&posting.entry_id)
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Ledger entry {} of the posting not found", posting.entry_id)
+            })?;
+        balance = apply_posting(account, balance, posting.direction, posting.amount);
+        lines.push(LedgerStatementLine {
+            entry_id: posting.entry_id,
+            entry_type: entry.entry_type,
+            reference_id: entry.reference_id.clone(),
+            direction: posting.direction,
+            amount: posting.amount,
+            balance,
+            effective_at: posting.effective_at,
+        });
+    }
+
+    Ok(ApplicationResponse::Json(LedgerStatementResponse {
+        account,
+        currency: req.currency,
+        opening_balance,
+        closing_balance: balance,
+        lines,
+    }))
+}
+
+/// Lists the journal entries of the merchant along with their postings, most recent first
+#[instrument(skip_all)]
+pub async fn list_ledger_entries(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: LedgerEntryListConstraints,
+) -> RouterResponse<LedgerEntryListResponse> {
+    let db = state.store.as_ref();
+    let limit = constraints
+        .limit
+        .unwrap_or(consts::DEFAULT_LEDGER_ENTRY_LIST_LIMIT);
+    let entries = db
+        .list_ledger_entries_by_merchant_id(
+            merchant_account.get_id(),
+            constraints.reference_id.as_deref(),
+            constraints.currency,
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list ledger entries")?;
+
+    let mut postings: HashMap<String, Vec<LedgerPostingResponse>> = HashMap::new();
+    if !entries.is_empty() {
+        let entry_ids = entries.iter().map(|entry| entry.entry_id.clone()).collect();
+        for posting in db
+            .list_ledger_postings_by_entry_ids(entry_ids)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list ledger postings")?
+        {
+            postings
+                .entry(posting.entry_id)
+                .or_default()
+                .push(LedgerPostingResponse {
+                    account: posting.account,
+                    direction: posting.direction,
+                    amount: posting.amount,
+                });
+        }
+    }
+
+    let data: Vec<_> = entries
+        .into_iter()
+        .map(|entry| LedgerEntryResponse {
+            postings: postings.remove(&entry.entry_id).unwrap_or_default(),
+            entry_id: entry.entry_id,
+            entry_type: entry.entry_type,
+            source_id: entry.source_id,
+            currency: entry.currency,
+            reference_id: entry.reference_id,
+            description: entry.description,
+            effective_at: entry.effective_at,
+            created_at: entry.created_at,
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(LedgerEntryListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+#[cfg(feature = "olap")]
+const LEDGER_REBUILD_TASK: &str = "REBUILD_LEDGER";
+#[cfg(feature = "olap")]
+const LEDGER_REBUILD_TAG: &str = "LEDGER";
+#[cfg(feature = "olap")]
+const LEDGER_REBUILD_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::LedgerRebuildWorkflow;
+
+#[cfg(feature = "olap")]
+fn generate_task_id_for_ledger_rebuild_workflow(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{LEDGER_REBUILD_RUNNER}_{LEDGER_REBUILD_TASK}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Schedules the ledger of the merchant to be recorded again from its payments, refunds, disputes
+/// and payouts. A rebuild of the ledger which is running already starts over.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn rebuild_ledger(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<LedgerRebuildResponse> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(
+        &(&state).into(),
+        &merchant_id,
+        &db.get_master_key().to_vec().into(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let started_at = common_utils::date_time::now();
+    let task_id = generate_task_id_for_ledger_rebuild_workflow(&merchant_id);
+    let tracking_data = storage::LedgerRebuildTrackingData {
+        merchant_id: merchant_id.clone(),
+        started_at,
+        stage: storage::LedgerRebuildStage::Payments,
+        last_processed_id: None,
+        entries_recorded: 0,
+    };
+
+    let existing_task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch ledger rebuild task")?;
+
+    match existing_task {
+        Some(_) => {
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(started_at),
+                tracking_data: Some(
+                    tracking_data
+                        .encode_to_value()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                ),
+                business_status: Some(String::from(storage::business_status::PENDING)),
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(started_at),
+            };
+            db.process_tracker_update_process_status_by_ids(
+                vec![task_id],
+                updated_process_tracker_data,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update ledger rebuild task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "LedgerRebuild")]),
+            );
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                LEDGER_REBUILD_TASK,
+                LEDGER_REBUILD_RUNNER,
+                [LEDGER_REBUILD_TAG],
+                tracking_data,
+                started_at,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct ledger rebuild process tracker task")?;
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while inserting ledger rebuild task to process_tracker: merchant_id: {}",
+                        merchant_id.get_string_repr()
+                    )
+                })?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "LedgerRebuild")]),
+            );
+        }
+    }
+
+    Ok(ApplicationResponse::Json(LedgerRebuildResponse {
+        merchant_id,
+        started_at,
+    }))
+}
+
+/// Derives the entries of the next batch of payments of the merchant, from their attempts,
+/// refunds and disputes. Returns the IDs of the payments of the batch along with the entries.
+#[cfg(feature = "olap")]
+async fn derive_next_payment_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    after_payment_id: Option<String>,
+) -> RouterResult<(Vec<String>, Vec<JournalEntry>)> {
+    let db = state.store.as_ref();
+    let payment_intents = db
+        .list_payment_intents_by_merchant_id_after_payment_id(
+            merchant_id,
+            after_payment_id,
+            consts::LEDGER_REBUILD_BATCH_SIZE,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payments of the merchant")?;
+    let payment_ids: Vec<_> = payment_intents
+        .iter()
+        .map(|payment_intent| payment_intent.payment_id.clone())
+        .collect();
+    if payment_ids.is_empty() {
+        return Ok((payment_ids, Vec::new()));
+    }
+
+    let payment_attempts = db
+        .find_payment_attempts_by_merchant_id_payment_ids(merchant_id, payment_ids.clone())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the attempts of the payments")?;
+    let refunds = db
+        .find_refunds_by_merchant_id_payment_ids(merchant_id, payment_ids.clone())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the refunds of the payments")?;
+    let disputes = db
+        .find_disputes_by_merchant_id_payment_ids(merchant_id, payment_ids.clone())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the disputes of the payments")?;
+
+    let payments: HashMap<_, _> = payment_intents
+        .iter()
+        .map(|payment_intent| {
+            (
+                payment_intent.payment_id.as_str(),
+                PaymentDetails::from(payment_intent),
+            )
+        })
+        .collect();
+    let mut entries = Vec::new();
+    for payment_attempt in payment_attempts {
+        if let Some(payment) = payments.get(payment_attempt.payment_id.as_str()) {
+            entries.extend(get_payment_attempt_entries(
+                payment,
+                &PaymentAttempt::from_storage_model(payment_attempt),
+            )?);
+        }
+    }
+    entries.extend(refunds.iter().flat_map(get_refund_entries));
+    for dispute in &disputes {
+        entries.extend(get_dispute_entries(dispute)?);
+    }
+
+    Ok((payment_ids, entries))
+}
+
+/// Derives the entries of the next batch of payouts of the merchant. Returns the IDs of the
+/// payouts of the batch along with the entries.
+#[cfg(all(feature = "olap", feature = "payouts"))]
+async fn derive_next_payout_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    after_payout_id: Option<String>,
+) -> RouterResult<(Vec<String>, Vec<JournalEntry>)> {
+    let payouts = state
+        .store
+        .list_payouts_by_merchant_id_after_payout_id(
+            merchant_id,
+            after_payout_id,
+            consts::LEDGER_REBUILD_BATCH_SIZE,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payouts of the merchant")?;
+
+    Ok((
+        payouts
+            .iter()
+            .map(|payouts| payouts.payout_id.clone())
+            .collect(),
+        payouts.iter().flat_map(get_payout_entries).collect(),
+    ))
+}
+
+/// The stage rebuilt after `stage`, `None` once the ledger is rebuilt
+#[cfg(feature = "olap")]
+fn next_rebuild_stage(stage: storage::LedgerRebuildStage) -> Option<storage::LedgerRebuildStage> {
+    match stage {
+        storage::LedgerRebuildStage::Payments => {
+            cfg!(feature = "payouts").then_some(storage::LedgerRebuildStage::Payouts)
+        }
+        storage::LedgerRebuildStage::Payouts => None,
+    }
+}
+
+/// Converts the entries which have postings into ledger entries, keeping a single entry of a given
+/// type for a record
+#[cfg(feature = "olap")]
+fn into_rebuilt_ledger_entries(
+    merchant_id: &id_type::MerchantId,
+    entries: Vec<JournalEntry>,
+    created_at: PrimitiveDateTime,
+) -> RouterResult<Vec<(storage::LedgerEntryNew, Vec<storage::LedgerPostingNew>)>> {
+    let mut derived = HashSet::new();
+    entries
+        .into_iter()
+        .filter(|entry| {
+            !entry.postings.is_empty()
+                && derived.insert((entry.entry_type, entry.source_id.clone()))
+        })
+        .map(|entry| into_ledger_entry(merchant_id, entry, created_at))
+        .collect()
+}
+
+#[cfg(feature = "olap")]
+enum RebuildProgress {
+    Pending(storage::LedgerRebuildTrackingData),
+    Finished { entries_recorded: usize },
+}
+
+/// Rebuilds the entries of the next batch of payments or payouts. The entries of the batch
+/// recorded before the rebuild started are swapped with the derived entries in a single
+/// transaction, so that the entries of a payment are never seen partially rebuilt, while the
+/// entries of payments which are no longer stored, such as archived payments, are kept.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+async fn rebuild_next_batch(
+    state: &SessionState,
+    tracking_data: storage::LedgerRebuildTrackingData,
+) -> RouterResult<RebuildProgress> {
+    let merchant_id = &tracking_data.merchant_id;
+    let (reference_ids, entries) = match tracking_data.stage {
+        storage::LedgerRebuildStage::Payments => {
+            derive_next_payment_entries(state, merchant_id, tracking_data.last_processed_id.clone())
+                .await?
+        }
+        #[cfg(feature = "payouts")]
+        storage::LedgerRebuildStage::Payouts => {
+            derive_next_payout_entries(state, merchant_id, tracking_data.last_processed_id.clone())
+                .await?
+        }
+        #[cfg(not(feature = "payouts"))]
+        storage::LedgerRebuildStage::Payouts => (Vec::new(), Vec::new()),
+    };
+
+    let entries =
+        into_rebuilt_ledger_entries(merchant_id, entries, common_utils::date_time::now())?;
+    let has_remaining_records = i64::try_from(reference_ids.len())
+        .is_ok_and(|count| count >= consts::LEDGER_REBUILD_BATCH_SIZE);
+    let last_processed_id = reference_ids.last().cloned();
+    let entries_recorded = if reference_ids.is_empty() {
+        0
+    } else {
+        state
+            .store
+            .replace_ledger_by_merchant_id(
+                merchant_id,
+                reference_ids,
+                tracking_data.started_at,
+                entries,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to replace the ledger entries of the batch")?
+    };
+    let entries_recorded = tracking_data.entries_recorded + entries_recorded;
+
+    let (stage, last_processed_id) = if has_remaining_records {
+        (Some(tracking_data.stage), last_processed_id)
+    } else {
+        (next_rebuild_stage(tracking_data.stage), None)
+    };
+    Ok(match stage {
+        Some(stage) => RebuildProgress::Pending(storage::LedgerRebuildTrackingData {
+            stage,
+            last_processed_id,
+            entries_recorded,
+            ..tracking_data
+        }),
+        None => RebuildProgress::Finished { entries_recorded },
+    })
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn start_ledger_rebuild_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let mut tracking_data: storage::LedgerRebuildTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("LedgerRebuildTrackingData")?;
+
+    for _ in 0..consts::LEDGER_REBUILD_MAX_BATCHES_PER_RUN {
+        match rebuild_next_batch(state, tracking_data.clone()).await? {
+            RebuildProgress::Pending(updated_tracking_data) => {
+                tracking_data = updated_tracking_data;
+                // The progress is recorded so that a failed run resumes from the last batch
+                db.as_scheduler()
+                    .update_process(
+                        process.clone(),
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: None,
+                            schedule_time: None,
+                            tracking_data: Some(tracking_data.encode_to_value()?),
+                            business_status: None,
+                            status: None,
+                            updated_at: Some(common_utils::date_time::now()),
+                        },
+                    )
+                    .await?;
+            }
+            RebuildProgress::Finished { entries_recorded } => {
+                logger::info!(
+                    merchant_id = %tracking_data.merchant_id.get_string_repr(),
+                    entries_recorded,
+                    "Completed ledger rebuild"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process.clone(),
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await?;
+                return Ok(());
+            }
+        }
+    }
+
+    // Continue in the next scheduler cycle so that a large merchant does not hold up the consumer
+    db.as_scheduler()
+        .reset_process(process.clone(), common_utils::date_time::now())
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::db::ledger::LedgerInterface;
+
+    fn is_balanced(entry: &JournalEntry) -> bool {
+        let sum = |direction| {
+            entry
+                .postings
+                .iter()
+                .filter(|posting| posting.direction == direction)
+                .fold(MinorUnit::zero(), |sum, posting| sum + posting.amount)
+        };
+        sum(enums::LedgerPostingDirection::Debit) == sum(enums::LedgerPostingDirection::Credit)
+    }
+
+    fn refund(status: enums::RefundStatus, amount: i64) -> storage::Refund {
+        storage::refund::test_utils::get_refund("ref_1", status, amount)
+    }
+
+    fn payment(amount_captured: Option<i64>, charges: Option<serde_json::Value>) -> PaymentDetails {
+        PaymentDetails {
+            currency: Some(enums::Currency::USD),
+            amount_captured: amount_captured.map(MinorUnit::new),
+            active_attempt_id: "pay_1_1".to_string(),
+            charges: charges.map(pii::SecretSerdeValue::new),
+        }
+    }
+
+    fn payment_attempt(status: &str, amount_to_capture: Option<i64>) -> PaymentAttempt {
+        serde_json::from_value(serde_json::json!({
+            "payment_id": "pay_1",
+            "merchant_id": "merchant_1",
+            "attempt_id": "pay_1_1",
+            "status": status,
+            "amount": 1000,
+            "net_amount": 1000,
+            "amount_to_capture": amount_to_capture,
+            "currency": "USD",
+            "confirm": true,
+            "created_at": "2024-01-01T00:00:00.000Z",
+            "modified_at": "2024-01-01T00:00:00.000Z",
+            "amount_capturable": 0,
+            "updated_by": "postgres_only",
+        }))
+        .unwrap()
+    }
+
+    fn entry_types(entries: &[JournalEntry]) -> Vec<enums::LedgerEntryType> {
+        entries.iter().map(|entry| entry.entry_type).collect()
+    }
+
+    fn posting(
+        account: enums::LedgerAccount,
+        direction: enums::LedgerPostingDirection,
+        amount: i64,
+    ) -> JournalPosting {
+        JournalPosting {
+            account,
+            direction,
+            amount: MinorUnit::new(amount),
+        }
+    }
+
+    #[test]
+    fn partial_capture_releases_the_authorization_and_recognizes_the_captured_amount() {
+        let entries = get_payment_attempt_entries(
+            &payment(Some(600), None),
+            &payment_attempt("partial_charged", None),
+        )
+        .unwrap();
+
+        assert_eq!(
+            entry_types(&entries),
+            vec![
+                enums::LedgerEntryType::Authorization,
+                enums::LedgerEntryType::Capture
+            ]
+        );
+        assert!(entries.iter().all(is_balanced));
+        assert_eq!(
+            entries[1].postings,
+            vec![
+                posting(
+                    enums::LedgerAccount::AuthorizationHolds,
+                    enums::LedgerPostingDirection::Debit,
+                    1000
+                ),
+                posting(
+                    enums::LedgerAccount::PendingAuthorizations,
+                    enums::LedgerPostingDirection::Credit,
+                    1000
+                ),
+                posting(
+                    enums::LedgerAccount::ProcessorReceivable,
+                    enums::LedgerPostingDirection::Debit,
+                    600
+                ),
+                posting(
+                    enums::LedgerAccount::Sales,
+                    enums::LedgerPostingDirection::Credit,
+                    600
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn capture_of_an_inactive_attempt_uses_the_amount_to_capture_of_the_attempt() {
+        let mut payment = payment(Some(600), None);
+        payment.active_attempt_id = "pay_1_2".to_string();
+        let entries =
+            get_payment_attempt_entries(&payment, &payment_attempt("charged", Some(800))).unwrap();
+
+        assert_eq!(entries[1].entry_type, enums::LedgerEntryType::Capture);
+        assert_eq!(entries[1].postings[2].amount, MinorUnit::new(800));
+    }
+
+    #[test]
+    fn void_releases_the_authorization() {
+        let entries =
+            get_payment_attempt_entries(&payment(None, None), &payment_attempt("voided", None))
+                .unwrap();
+
+        assert_eq!(
+            entry_types(&entries),
+            vec![
+                enums::LedgerEntryType::Authorization,
+                enums::LedgerEntryType::AuthorizationVoid
+            ]
+        );
+        assert!(entries.iter().all(is_balanced));
+        assert_eq!(
+            entries[1].postings[0],
+            posting(
+                enums::LedgerAccount::AuthorizationHolds,
+                enums::LedgerPostingDirection::Debit,
+                1000
+            )
+        );
+    }
+
+    #[test]
+    fn fees_are_recorded_along_with_the_capture() {
+        let charges = serde_json::json!({
+            "charge_type": "direct",
+            "fees": 150,
+            "transfer_account_id": "acct_1",
+        });
+        let entries = get_payment_attempt_entries(
+            &payment(Some(1000), Some(charges.clone())),
+            &payment_attempt("charged", None),
+        )
+        .unwrap();
+
+        assert_eq!(
+            entry_types(&entries),
+            vec![
+                enums::LedgerEntryType::Authorization,
+                enums::LedgerEntryType::Capture,
+                enums::LedgerEntryType::Fee
+            ]
+        );
+        assert!(entries.iter().all(is_balanced));
+        assert_eq!(
+            entries[2].postings[0],
+            posting(
+                enums::LedgerAccount::Fees,
+                enums::LedgerPostingDirection::Debit,
+                150
+            )
+        );
+
+        // The fees are charged only once the payment is captured
+        let entries = get_payment_attempt_entries(
+            &payment(None, Some(charges)),
+            &payment_attempt("authorized", None),
+        )
+        .unwrap();
+        assert_eq!(
+            entry_types(&entries),
+            vec![enums::LedgerEntryType::Authorization]
+        );
+    }
+
+    #[test]
+    fn failed_attempts_have_no_entries() {
+        let entries =
+            get_payment_attempt_entries(&payment(None, None), &payment_attempt("failure", None))
+                .unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn replacing_the_ledger_keeps_recent_entries_and_those_of_other_payments() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let merchant_id = id_type::MerchantId::get_irrelevant_merchant_id();
+        let started_at = common_utils::date_time::now();
+        let ledger_entry = |source_id: &str, payment_id: &str, amount: i64, created_at| {
+            into_ledger_entry(
+                &merchant_id,
+                get_refund_entries(&storage::Refund {
+                    refund_id: source_id.to_string(),
+                    payment_id: payment_id.to_string(),
+                    ..refund(enums::RefundStatus::Success, amount)
+                })
+                .remove(0),
+                created_at,
+            )
+            .unwrap()
+        };
+
+        // Recorded before the rebuild started, while it was running, and for a payment which is
+        // not rebuilt, such as an archived one
+        for (entry, postings) in [
+            ledger_entry("ref_1", "pay_1", 100, started_at - time::Duration::hours(1)),
+            ledger_entry(
+                "ref_2",
+                "pay_1",
+                200,
+                started_at + time::Duration::seconds(1),
+            ),
+            ledger_entry("ref_3", "pay_2", 300, started_at - time::Duration::hours(1)),
+        ] {
+            db.insert_ledger_entry(entry, postings).await.unwrap();
+        }
+
+        let inserted = db
+            .replace_ledger_by_merchant_id(
+                &merchant_id,
+                vec!["pay_1".to_string()],
+                started_at,
+                vec![
+                    ledger_entry("ref_1", "pay_1", 150, started_at),
+                    ledger_entry("ref_2", "pay_1", 250, started_at),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(inserted, 1);
+
+        let sums = db
+            .sum_ledger_postings_by_merchant_id_currency(&merchant_id, enums::Currency::USD, None)
+            .await
+            .unwrap();
+        assert!(sums.contains(&(
+            enums::LedgerAccount::Refunds,
+            enums::LedgerPostingDirection::Debit,
+            MinorUnit::new(650)
+        )));
+        assert!(sums.contains(&(
+            enums::LedgerAccount::ProcessorReceivable,
+            enums::LedgerPostingDirection::Credit,
+            MinorUnit::new(650)
+        )));
+    }
+
+    #[test]
+    fn transfer_skips_zero_amounts() {
+        let now = common_utils::date_time::now();
+        let entry = JournalEntry::new(
+            enums::LedgerEntryType::Fee,
+            enums::Currency::USD,
+            "pay_1_1",
+            "pay_1",
+            "Fees".to_string(),
+            now,
+        )
+        .transfer(
+            enums::LedgerAccount::Fees,
+            enums::LedgerAccount::ProcessorReceivable,
+            MinorUnit::zero(),
+        );
+        assert!(entry.postings.is_empty());
+    }
+
+    #[test]
+    fn refund_entries_are_recorded_once_succeeded() {
+        assert!(get_refund_entries(&refund(enums::RefundStatus::Pending, 500)).is_empty());
+
+        let entries = get_refund_entries(&refund(enums::RefundStatus::Success, 500));
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.entry_type, enums::LedgerEntryType::Refund);
+        assert_eq!(entry.source_id, "ref_1");
+        assert_eq!(entry.reference_id, "pay_1");
+        assert!(is_balanced(entry));
+        assert_eq!(
+            entry.postings[0],
+            JournalPosting {
+                account: enums::LedgerAccount::Refunds,
+                direction: enums::LedgerPostingDirection::Debit,
+                amount: MinorUnit::new(500),
+            }
+        );
+    }
+
+    #[test]
+    fn balances_follow_the_normal_side_of_the_account() {
+        let balance = apply_posting(
+            enums::LedgerAccount::ProcessorReceivable,
+            MinorUnit::zero(),
+            enums::LedgerPostingDirection::Debit,
+            MinorUnit::new(1000),
+        );
+        let balance = apply_posting(
+            enums::LedgerAccount::ProcessorReceivable,
+            balance,
+            enums::LedgerPostingDirection::Credit,
+            MinorUnit::new(300),
+        );
+        assert_eq!(balance, MinorUnit::new(700));
+
+        let balance = apply_posting(
+            enums::LedgerAccount::Sales,
+            MinorUnit::zero(),
+            enums::LedgerPostingDirection::Credit,
+            MinorUnit::new(1000),
+        );
+        assert_eq!(balance, MinorUnit::new(1000));
+    }
+}
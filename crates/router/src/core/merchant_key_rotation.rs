@@ -0,0 +1,1130 @@
+use api_models::admin::{
+    MerchantKeyRotationId, MerchantKeyRotationListResponse, MerchantKeyRotationResponse,
+};
+use common_utils::{
+    errors::{CryptoError, CustomResult},
+    ext_traits::ValueExt,
+    id_type, type_name,
+    types::keymanager::{self as km_types, KeyManagerState},
+};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use storage_impl::redis::kv_store::PartitionKey;
+use strum::IntoEnumIterator;
+
+use crate::{
+    consts,
+    core::{
+        data_retention,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::MasterKeyInterface,
+    routes::{metrics, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const KEY_ROTATION_TASK: &str = "ROTATE_MERCHANT_KEY";
+const KEY_ROTATION_TAG: &str = "MERCHANT_KEY_ROTATION";
+const KEY_ROTATION_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::MerchantKeyRotationWorkflow;
+
+fn generate_task_id_for_key_rotation_workflow(rotation_id: &str) -> String {
+    format!("{KEY_ROTATION_RUNNER}_{KEY_ROTATION_TASK}_{rotation_id}")
+}
+
+/// The table re-encrypted after `table_name`, `None` once all the tables are re-encrypted
+fn next_table(table_name: enums::KeyRotationTable) -> Option<enums::KeyRotationTable> {
+    enums::KeyRotationTable::iter()
+        .skip_while(|table| *table != table_name)
+        .nth(1)
+}
+
+impl ForeignFrom<storage::MerchantKeyRotation> for MerchantKeyRotationResponse {
+    fn foreign_from(rotation: storage::MerchantKeyRotation) -> Self {
+        let completed_tables = match (rotation.status, rotation.current_table) {
+            (enums::KeyRotationStatus::Completed | enums::KeyRotationStatus::Verifying, _)
+            | (_, None) => enums::KeyRotationTable::iter().collect(),
+            (_, Some(current_table)) => enums::KeyRotationTable::iter()
+                .take_while(|table| *table != current_table)
+                .collect(),
+        };
+        Self {
+            rotation_id: rotation.rotation_id,
+            merchant_id: rotation.merchant_id,
+            key_version: rotation.key_version,
+            status: rotation.status,
+            current_table: rotation.current_table,
+            completed_tables,
+            rows_processed: rotation.rows_processed,
+            error_message: rotation.error_message,
+            created_at: rotation.created_at,
+            modified_at: rotation.modified_at,
+            completed_at: rotation.completed_at,
+        }
+    }
+}
+
+/// Replaces the key of the merchant with a new key and schedules the re-encryption of the stored
+/// data. The previous key is retained for decryption until all the data is re-encrypted and
+/// verified. A failed rotation is resumed instead of generating another key.
+#[instrument(skip_all)]
+pub async fn start_merchant_key_rotation(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<MerchantKeyRotationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let master_key = db.get_master_key();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &master_key.to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let rotation = if key_store.previous_key.is_some() {
+        resume_merchant_key_rotation(&state, &merchant_id).await?
+    } else {
+        rotate_merchant_key(&state, key_manager_state, key_store).await?
+    };
+
+    schedule_key_rotation_task(&state, &rotation).await?;
+
+    Ok(ApplicationResponse::Json(
+        MerchantKeyRotationResponse::foreign_from(rotation),
+    ))
+}
+
+async fn resume_merchant_key_rotation(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<storage::MerchantKeyRotation> {
+    let db = state.store.as_ref();
+    let latest_rotation = db
+        .list_merchant_key_rotations_by_merchant_id(merchant_id, Some(1))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the latest merchant key rotation")?
+        .pop()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Merchant key store has a previous key without a key rotation")?;
+
+    match latest_rotation.status {
+        enums::KeyRotationStatus::Failed => db
+            .update_merchant_key_rotation_by_rotation_id(
+                &latest_rotation.rotation_id,
+                storage::MerchantKeyRotationUpdate::Resumed,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to resume merchant key rotation"),
+        enums::KeyRotationStatus::InProgress | enums::KeyRotationStatus::Verifying => {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Key rotation {} is in progress for the merchant",
+                    latest_rotation.rotation_id
+                ),
+            }
+            .into())
+        }
+        enums::KeyRotationStatus::Completed => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Previous merchant key was not retired by the completed key rotation",
+            ),
+    }
+}
+
+async fn rotate_merchant_key(
+    state: &SessionState,
+    key_manager_state: &KeyManagerState,
+    key_store: domain::MerchantKeyStore,
+) -> RouterResult<storage::MerchantKeyRotation> {
+    let db = state.store.as_ref();
+    let key_version = key_store.key_version + 1;
+
+    let current_time = common_utils::date_time::now();
+    let rotation = db
+        .insert_merchant_key_rotation(storage::MerchantKeyRotationNew {
+            rotation_id: common_utils::generate_id_with_default_len("keyrot"),
+            merchant_id: key_store.merchant_id.clone(),
+            key_version,
+            status: enums::KeyRotationStatus::InProgress,
+            current_table: enums::KeyRotationTable::iter().next(),
+            last_processed_id: None,
+            rows_processed: 0,
+            error_message: None,
+            created_at: current_time,
+            modified_at: current_time,
+            completed_at: None,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A key rotation is already in progress for the merchant".to_string(),
+        })?;
+
+    if let Err(error) = replace_merchant_key(state, key_manager_state, key_store, key_version).await
+    {
+        db.update_merchant_key_rotation_by_rotation_id(
+            &rotation.rotation_id,
+            storage::MerchantKeyRotationUpdate::Failed {
+                error_message: "Failed to replace the merchant key".to_string(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update merchant key rotation")?;
+        return Err(error);
+    }
+
+    Ok(rotation)
+}
+
+/// Generates a new key for the merchant, the current key is stored as the previous key
+async fn replace_merchant_key(
+    state: &SessionState,
+    key_manager_state: &KeyManagerState,
+    key_store: domain::MerchantKeyStore,
+    key_version: i32,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let master_key = db.get_master_key();
+    let identifier = km_types::Identifier::Merchant(key_store.merchant_id.clone());
+
+    let key = services::generate_aes256_key()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to generate aes 256 key")?;
+
+    #[cfg(feature = "keymanager_create")]
+    {
+        common_utils::keymanager::rotate_key_in_key_manager(
+            key_manager_state,
+            km_types::EncryptionCreateRequest {
+                identifier: identifier.clone(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to rotate key in KeyManager")?;
+    }
+
+    let encrypted_key: common_utils::crypto::Encryptable<Secret<Vec<u8>>> =
+        domain::types::crypto_operation(
+            key_manager_state,
+            type_name!(domain::MerchantKeyStore),
+            domain::types::CryptoOperation::Encrypt(key.to_vec().into()),
+            identifier,
+            master_key,
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the new merchant key")?;
+
+    db.update_merchant_key_store_by_merchant_id(
+        key_manager_state,
+        &key_store.merchant_id,
+        storage::MerchantKeyStoreUpdate::KeyRotated {
+            key: encrypted_key.into(),
+            key_version,
+            previous_key: key_store.key.into(),
+        },
+        &master_key.to_vec().into(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to replace the merchant key")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+async fn schedule_key_rotation_task(
+    state: &SessionState,
+    rotation: &storage::MerchantKeyRotation,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let current_time = common_utils::date_time::now();
+    let task_id = generate_task_id_for_key_rotation_workflow(&rotation.rotation_id);
+    let tracking_data = storage::MerchantKeyRotationTrackingData {
+        rotation_id: rotation.rotation_id.clone(),
+        merchant_id: rotation.merchant_id.clone(),
+    };
+
+    let existing_task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant key rotation task")?;
+
+    match existing_task {
+        Some(_) => {
+            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(current_time),
+                tracking_data: None,
+                business_status: Some(String::from(storage::business_status::PENDING)),
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(current_time),
+            };
+            db.process_tracker_update_process_status_by_ids(
+                vec![task_id],
+                updated_process_tracker_data,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update merchant key rotation task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "MerchantKeyRotation")]),
+            );
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                KEY_ROTATION_TASK,
+                KEY_ROTATION_RUNNER,
+                [KEY_ROTATION_TAG],
+                tracking_data,
+                current_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct merchant key rotation process tracker task")?;
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while inserting merchant key rotation task to process_tracker: rotation_id: {}",
+                        rotation.rotation_id
+                    )
+                })?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "MerchantKeyRotation")]),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_merchant_key_rotation(
+    state: SessionState,
+    req: MerchantKeyRotationId,
+) -> RouterResponse<MerchantKeyRotationResponse> {
+    let rotation = state
+        .store
+        .find_merchant_key_rotation_by_merchant_id_rotation_id(&req.merchant_id, &req.rotation_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Key rotation {} does not exist", req.rotation_id),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        MerchantKeyRotationResponse::foreign_from(rotation),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_merchant_key_rotations(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<MerchantKeyRotationListResponse> {
+    let rotations = state
+        .store
+        .list_merchant_key_rotations_by_merchant_id(
+            &merchant_id,
+            Some(consts::DEFAULT_KEY_ROTATION_LIST_LIMIT),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant key rotations")?;
+
+    let data: Vec<_> = rotations
+        .into_iter()
+        .map(MerchantKeyRotationResponse::foreign_from)
+        .collect();
+    Ok(ApplicationResponse::Json(MerchantKeyRotationListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+/// State which decrypts with the current keys only, the values which were not re-encrypted since
+/// the key was rotated fail to decrypt with it
+fn without_previous_keys(key_manager_state: &KeyManagerState) -> KeyManagerState {
+    KeyManagerState {
+        previous_keys: km_types::PreviousKeys::default(),
+        ..key_manager_state.clone()
+    }
+}
+
+async fn decrypt_value(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    value: common_utils::encryption::Encryption,
+) -> CustomResult<Secret<Vec<u8>>, CryptoError> {
+    let decrypted: common_utils::crypto::Encryptable<Secret<Vec<u8>>> =
+        domain::types::crypto_operation(
+            key_manager_state,
+            type_name!(storage::EncryptedRow),
+            domain::types::CryptoOperation::Decrypt(value),
+            km_types::Identifier::Merchant(key_store.merchant_id.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())?;
+    Ok(decrypted.into_inner())
+}
+
+/// Re-encrypts a value with the current key of the merchant, the value is decrypted with the
+/// previous key. Returns `None` when the value is already encrypted with the current key.
+pub(crate) async fn reencrypt_value(
+    key_manager_state: &KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    value: common_utils::encryption::Encryption,
+) -> RouterResult<Option<common_utils::encryption::Encryption>> {
+    if decrypt_value(
+        &without_previous_keys(key_manager_state),
+        key_store,
+        value.clone(),
+    )
+    .await
+    .is_ok()
+    {
+        return Ok(None);
+    }
+
+    let decrypted = decrypt_value(key_manager_state, key_store, value)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt value with the merchant keys")?;
+
+    domain::types::crypto_operation(
+        key_manager_state,
+        type_name!(storage::EncryptedRow),
+        domain::types::CryptoOperation::Encrypt(decrypted),
+        km_types::Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(|encrypted| Some(common_utils::encryption::Encryption::from(encrypted)))
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt value with the new merchant key")
+}
+
+/// Redis key of the KV copy of a row. The payment addresses are stored along with the payment
+/// intent and the payment methods along with the customer.
+fn get_kv_partition_key(
+    table_name: enums::KeyRotationTable,
+    merchant_id: &id_type::MerchantId,
+    row_id: &str,
+) -> Option<String> {
+    match table_name {
+        enums::KeyRotationTable::PaymentIntent => Some(
+            PartitionKey::MerchantIdPaymentId {
+                merchant_id,
+                payment_id: row_id,
+            }
+            .to_string(),
+        ),
+        #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+        enums::KeyRotationTable::Customers => Some(
+            PartitionKey::MerchantIdCustomerId {
+                merchant_id,
+                customer_id: row_id,
+            }
+            .to_string(),
+        ),
+        #[cfg(all(feature = "v2", feature = "customer_v2"))]
+        enums::KeyRotationTable::Customers => {
+            Some(PartitionKey::GlobalId { id: row_id }.to_string())
+        }
+        enums::KeyRotationTable::MerchantAccount
+        | enums::KeyRotationTable::BusinessProfile
+        | enums::KeyRotationTable::MerchantConnectorAccount
+        | enums::KeyRotationTable::Address
+        | enums::KeyRotationTable::PaymentMethods
+        | enums::KeyRotationTable::Events
+        | enums::KeyRotationTable::WebhookSigningKey
+        | enums::KeyRotationTable::DataRetentionArchive => None,
+    }
+}
+
+/// Deletes the KV copies of the re-encrypted rows, which still hold the values encrypted with the
+/// previous key, so that the rows are read from the database until they are written again
+async fn flush_kv_copies(
+    state: &SessionState,
+    table_name: enums::KeyRotationTable,
+    merchant_id: &id_type::MerchantId,
+    rows: &[storage::EncryptedRow],
+) -> RouterResult<()> {
+    let kv_keys: Vec<_> = rows
+        .iter()
+        .filter_map(|row| get_kv_partition_key(table_name, merchant_id, &row.id))
+        .collect();
+    if kv_keys.is_empty() {
+        return Ok(());
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    for kv_key in kv_keys {
+        redis_conn
+            .delete_key(&kv_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to flush the KV copy of a row of {table_name}")
+            })?;
+    }
+    Ok(())
+}
+
+enum RotationProgress {
+    Pending(storage::MerchantKeyRotation),
+    /// All the rows were re-encrypted, they are verified once the cached key stores have expired
+    Verifying(storage::MerchantKeyRotation),
+    Finished {
+        rows_processed: i64,
+    },
+}
+
+/// Re-encrypts the values of the next batch of rows of the table which are not encrypted with the
+/// current key yet and flushes the KV copies of the rows. Returns the identifiers of the rows
+/// processed.
+async fn reencrypt_next_rows(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    rotation: &storage::MerchantKeyRotation,
+    table_name: enums::KeyRotationTable,
+) -> RouterResult<Vec<String>> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let rows = db
+        .find_encrypted_rows_for_key_rotation(
+            table_name,
+            &rotation.merchant_id,
+            rotation.last_processed_id.clone(),
+            consts::KEY_ROTATION_BATCH_SIZE,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to fetch encrypted rows of {table_name}"))?;
+
+    for row in &rows {
+        for (column_index, value) in row.columns.iter().enumerate() {
+            let Some(value) = value else {
+                continue;
+            };
+            let Some(reencrypted_value) =
+                reencrypt_value(key_manager_state, key_store, value.clone()).await?
+            else {
+                continue;
+            };
+            let is_updated = db
+                .update_encrypted_column_for_key_rotation(
+                    table_name,
+                    &rotation.merchant_id,
+                    &row.id,
+                    column_index,
+                    value.clone(),
+                    reencrypted_value,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("Failed to update encrypted row {} of {table_name}", row.id)
+                })?;
+            if !is_updated {
+                // The value was written again after the row was read, it is verified before the
+                // previous key is retired
+                logger::debug!(row_id = %row.id, %table_name, "Encrypted value was modified during key rotation");
+            }
+        }
+    }
+    flush_kv_copies(state, table_name, &rotation.merchant_id, &rows).await?;
+
+    Ok(rows.into_iter().map(|row| row.id).collect())
+}
+
+/// Re-encrypts the next batch of rows of the rotation and records the progress. The archives of
+/// the merchant are re-encrypted a few at a time, each archive counts as a single row. Once all the
+/// tables are re-encrypted they are verified from the first table, for the values written with the
+/// previous key by the key stores cached before the rotation.
+#[instrument(skip_all)]
+async fn reencrypt_next_batch(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    rotation: storage::MerchantKeyRotation,
+) -> RouterResult<RotationProgress> {
+    let db = &*state.store;
+    let Some(table_name) = rotation.current_table else {
+        return Ok(RotationProgress::Finished {
+            rows_processed: rotation.rows_processed,
+        });
+    };
+
+    let (row_ids, batch_size) = if table_name == enums::KeyRotationTable::DataRetentionArchive {
+        (
+            data_retention::reencrypt_data_retention_archives(
+                state,
+                key_store,
+                rotation.last_processed_id.clone(),
+                consts::DATA_RETENTION_ARCHIVE_BATCH_SIZE,
+            )
+            .await?,
+            consts::DATA_RETENTION_ARCHIVE_BATCH_SIZE,
+        )
+    } else {
+        (
+            reencrypt_next_rows(state, key_store, &rotation, table_name).await?,
+            consts::KEY_ROTATION_BATCH_SIZE,
+        )
+    };
+
+    let is_verifying = rotation.status == enums::KeyRotationStatus::Verifying;
+    let rows_processed = if is_verifying {
+        rotation.rows_processed
+    } else {
+        rotation.rows_processed
+            + i64::try_from(row_ids.len())
+                .change_context(errors::ApiErrorResponse::InternalServerError)?
+    };
+    let has_remaining_rows = i64::try_from(row_ids.len()).is_ok_and(|count| count >= batch_size);
+    let (current_table, last_processed_id) = if has_remaining_rows {
+        (Some(table_name), row_ids.last().cloned())
+    } else {
+        (next_table(table_name), None)
+    };
+
+    let Some(current_table) = current_table else {
+        if is_verifying {
+            return Ok(RotationProgress::Finished { rows_processed });
+        }
+        return db
+            .update_merchant_key_rotation_by_rotation_id(
+                &rotation.rotation_id,
+                storage::MerchantKeyRotationUpdate::VerificationStarted {
+                    current_table: enums::KeyRotationTable::iter().next().unwrap_or(table_name),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to start verifying the merchant key rotation")
+            .map(RotationProgress::Verifying);
+    };
+    db.update_merchant_key_rotation_by_rotation_id(
+        &rotation.rotation_id,
+        storage::MerchantKeyRotationUpdate::ProgressUpdate {
+            current_table,
+            last_processed_id,
+            rows_processed,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to record merchant key rotation progress")
+    .map(RotationProgress::Pending)
+}
+
+/// Retires the previous key of the merchant once all the rows are re-encrypted and verified, and
+/// their KV copies flushed
+#[instrument(skip_all)]
+async fn complete_merchant_key_rotation(
+    state: &SessionState,
+    rotation: &storage::MerchantKeyRotation,
+    rows_processed: i64,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    db.update_merchant_key_store_by_merchant_id(
+        &state.into(),
+        &rotation.merchant_id,
+        storage::MerchantKeyStoreUpdate::PreviousKeyRetired,
+        &db.get_master_key().to_vec().into(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to retire the previous merchant key")?;
+
+    db.update_merchant_key_rotation_by_rotation_id(
+        &rotation.rotation_id,
+        storage::MerchantKeyRotationUpdate::Completed {
+            rows_processed,
+            completed_at: common_utils::date_time::now(),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to complete merchant key rotation")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn start_merchant_key_rotation_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::MerchantKeyRotationTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("MerchantKeyRotationTrackingData")?;
+
+    let mut rotation = db
+        .find_merchant_key_rotation_by_merchant_id_rotation_id(
+            &tracking_data.merchant_id,
+            &tracking_data.rotation_id,
+        )
+        .await?;
+    if !matches!(
+        rotation.status,
+        enums::KeyRotationStatus::InProgress | enums::KeyRotationStatus::Verifying
+    ) {
+        db.as_scheduler()
+            .finish_process_with_business_status(process.clone(), "ROTATION_NOT_IN_PROGRESS")
+            .await?;
+        return Ok(());
+    }
+
+    // Fetching the key store also registers the previous key for the decryption of the rows
+    // which are not re-encrypted yet
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            &rotation.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    for _ in 0..consts::KEY_ROTATION_MAX_BATCHES_PER_RUN {
+        match reencrypt_next_batch(state, &key_store, rotation.clone()).await {
+            Ok(RotationProgress::Pending(updated_rotation)) => rotation = updated_rotation,
+            Ok(RotationProgress::Verifying(updated_rotation)) => {
+                logger::info!(
+                    rotation_id = %updated_rotation.rotation_id,
+                    rows_processed = updated_rotation.rows_processed,
+                    "Re-encrypted the stored data, verifying it once the cached key stores expire"
+                );
+                // A key store cached before the rotation may still write values with the previous
+                // key until it expires
+                db.as_scheduler()
+                    .reset_process(
+                        process.clone(),
+                        common_utils::date_time::now()
+                            + std::time::Duration::from_secs(
+                                consts::KEY_ROTATION_VERIFICATION_DELAY_IN_SECS,
+                            ),
+                    )
+                    .await?;
+                return Ok(());
+            }
+            Ok(RotationProgress::Finished { rows_processed }) => {
+                complete_merchant_key_rotation(state, &rotation, rows_processed).await?;
+                logger::info!(
+                    rotation_id = %rotation.rotation_id,
+                    rows_processed,
+                    "Completed merchant key rotation"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process.clone(),
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await?;
+                return Ok(());
+            }
+            Err(error) => {
+                logger::error!(?error, rotation_id = %rotation.rotation_id, "Merchant key rotation failed");
+                // The previous key is retained so that the rotation can be resumed
+                db.update_merchant_key_rotation_by_rotation_id(
+                    &rotation.rotation_id,
+                    storage::MerchantKeyRotationUpdate::Failed {
+                        error_message: format!(
+                            "Failed to re-encrypt {}",
+                            rotation
+                                .current_table
+                                .map(|table| table.to_string())
+                                .unwrap_or_default()
+                        ),
+                    },
+                )
+                .await?;
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process.clone(),
+                        storage::business_status::FAILURE,
+                    )
+                    .await?;
+                return Ok(());
+            }
+        }
+    }
+
+    // Continue in the next scheduler cycle so that a large merchant does not hold up the consumer
+    db.as_scheduler()
+        .reset_process(process.clone(), common_utils::date_time::now())
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::{borrow::Cow, sync::Arc};
+
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::routes::{
+        self,
+        app::{settings::Settings, StorageImpl},
+    };
+
+    async fn get_session_state() -> SessionState {
+        let conf = Settings::new().unwrap();
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state("public", || {})
+            .unwrap()
+    }
+
+    fn get_key_store(merchant_id: &id_type::MerchantId) -> domain::MerchantKeyStore {
+        domain::MerchantKeyStore {
+            merchant_id: merchant_id.clone(),
+            key: common_utils::crypto::Encryptable::new(
+                services::generate_aes256_key().unwrap().to_vec().into(),
+                Vec::new().into(),
+            ),
+            created_at: common_utils::date_time::now(),
+            key_version: 1,
+            previous_key: None,
+        }
+    }
+
+    async fn encrypt_value(
+        key_manager_state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        value: &[u8],
+    ) -> common_utils::encryption::Encryption {
+        domain::types::crypto_operation::<Vec<u8>, masking::WithType>(
+            key_manager_state,
+            type_name!(storage::EncryptedRow),
+            domain::types::CryptoOperation::Encrypt(value.to_vec().into()),
+            km_types::Identifier::Merchant(key_store.merchant_id.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .map(common_utils::encryption::Encryption::from)
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_reencrypt_value_decrypts_with_the_previous_key() {
+        let state = get_session_state().await;
+        let key_manager_state = &(&state).into();
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant1")).unwrap();
+        let previous_key_store = get_key_store(&merchant_id);
+        let key_store = get_key_store(&merchant_id);
+        let value = encrypt_value(key_manager_state, &previous_key_store, b"secret").await;
+
+        // The previous key is only tried once it is registered for the current key
+        assert!(
+            reencrypt_value(key_manager_state, &key_store, value.clone())
+                .await
+                .is_err()
+        );
+
+        key_manager_state.previous_keys.set(
+            key_store.key.get_inner().peek(),
+            Some(previous_key_store.key.get_inner().clone()),
+        );
+        let reencrypted_value = reencrypt_value(key_manager_state, &key_store, value)
+            .await
+            .unwrap()
+            .unwrap();
+        let decrypted = decrypt_value(
+            &without_previous_keys(key_manager_state),
+            &key_store,
+            reencrypted_value.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(decrypted.peek(), &b"secret".to_vec());
+        // Values encrypted with the current key are left as they are
+        assert!(
+            reencrypt_value(key_manager_state, &key_store, reencrypted_value)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_key_rotation_workflow_verifies_the_data_before_retiring_the_previous_key() {
+        let state = get_session_state().await;
+        let db = &*state.store;
+        let key_manager_state = &(&state).into();
+        let master_key = db.get_master_key();
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant1")).unwrap();
+        let previous_key = get_key_store(&merchant_id).key.into_inner();
+        let previous_key_store = db
+            .insert_merchant_key_store(
+                key_manager_state,
+                domain::MerchantKeyStore {
+                    key: domain::types::crypto_operation(
+                        key_manager_state,
+                        type_name!(domain::MerchantKeyStore),
+                        domain::types::CryptoOperation::Encrypt(previous_key),
+                        km_types::Identifier::Merchant(merchant_id.clone()),
+                        master_key,
+                    )
+                    .await
+                    .and_then(|val| val.try_into_operation())
+                    .unwrap(),
+                    ..get_key_store(&merchant_id)
+                },
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        let insert_signing_key = |key_id: &str, private_key| {
+            let current_time = common_utils::date_time::now();
+            db.insert_webhook_signing_key(storage::WebhookSigningKeyNew {
+                key_id: key_id.to_string(),
+                merchant_id: merchant_id.clone(),
+                profile_id: "pro_1".to_string(),
+                algorithm: enums::WebhookSigningAlgorithm::Ed25519,
+                status: enums::WebhookSigningKeyStatus::Revoked,
+                private_key,
+                public_key: Vec::new(),
+                created_at: current_time,
+                modified_at: current_time,
+                expires_at: None,
+            })
+        };
+        let find_private_key = |key_id: &'static str| async move {
+            db.find_webhook_signing_key_by_profile_id_key_id("pro_1", key_id)
+                .await
+                .unwrap()
+                .private_key
+        };
+
+        insert_signing_key(
+            "whsk_1",
+            encrypt_value(key_manager_state, &previous_key_store, b"private_key_1").await,
+        )
+        .await
+        .unwrap();
+        start_merchant_key_rotation(state.clone(), merchant_id.clone())
+            .await
+            .unwrap();
+        let rotation = db
+            .list_merchant_key_rotations_by_merchant_id(&merchant_id, Some(1))
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let task_id = generate_task_id_for_key_rotation_workflow(&rotation.rotation_id);
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+
+        // Written with the new key while the rows are re-encrypted
+        let private_key_2 = encrypt_value(key_manager_state, &key_store, b"private_key_2").await;
+        insert_signing_key("whsk_2", private_key_2.clone())
+            .await
+            .unwrap();
+
+        let process = db.find_process_by_id(&task_id).await.unwrap().unwrap();
+        start_merchant_key_rotation_workflow(&state, &process)
+            .await
+            .unwrap();
+
+        let rotation = db
+            .find_merchant_key_rotation_by_merchant_id_rotation_id(
+                &merchant_id,
+                &rotation.rotation_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(rotation.status, enums::KeyRotationStatus::Verifying);
+        assert_eq!(
+            rotation.current_table,
+            enums::KeyRotationTable::iter().next()
+        );
+        assert_eq!(rotation.rows_processed, 2);
+        // The verification waits for the key stores cached with the previous key to expire
+        let process = db.find_process_by_id(&task_id).await.unwrap().unwrap();
+        assert!(
+            process.schedule_time
+                > Some(common_utils::date_time::now() + time::Duration::minutes(1))
+        );
+
+        let private_key_1 = find_private_key("whsk_1").await;
+        assert!(decrypt_value(
+            &without_previous_keys(key_manager_state),
+            &key_store,
+            private_key_1.clone()
+        )
+        .await
+        .is_ok());
+        assert_eq!(find_private_key("whsk_2").await, private_key_2);
+        // A row read before it was re-encrypted does not overwrite the re-encrypted value
+        assert!(!db
+            .update_encrypted_column_for_key_rotation(
+                enums::KeyRotationTable::WebhookSigningKey,
+                &merchant_id,
+                "whsk_1",
+                0,
+                encrypt_value(key_manager_state, &previous_key_store, b"private_key_1").await,
+                private_key_2,
+            )
+            .await
+            .unwrap());
+        assert_eq!(find_private_key("whsk_1").await, private_key_1);
+
+        // Written with the previous key by a key store cached before the rotation
+        insert_signing_key(
+            "whsk_3",
+            encrypt_value(key_manager_state, &previous_key_store, b"private_key_3").await,
+        )
+        .await
+        .unwrap();
+
+        start_merchant_key_rotation_workflow(&state, &process)
+            .await
+            .unwrap();
+
+        let rotation = db
+            .find_merchant_key_rotation_by_merchant_id_rotation_id(
+                &merchant_id,
+                &rotation.rotation_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(rotation.status, enums::KeyRotationStatus::Completed);
+        assert_eq!(rotation.current_table, None);
+        assert_eq!(rotation.rows_processed, 2);
+        assert!(rotation.completed_at.is_some());
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &master_key.to_vec().into(),
+            )
+            .await
+            .unwrap();
+        assert!(key_store.previous_key.is_none());
+        assert!(key_manager_state
+            .previous_keys
+            .get(key_store.key.get_inner().peek())
+            .is_none());
+        for key_id in ["whsk_1", "whsk_2", "whsk_3"] {
+            assert!(decrypt_value(
+                key_manager_state,
+                &key_store,
+                find_private_key(key_id).await
+            )
+            .await
+            .is_ok());
+        }
+        let process = db.find_process_by_id(&task_id).await.unwrap().unwrap();
+        assert_eq!(
+            process.business_status,
+            storage::business_status::COMPLETED_BY_PT
+        );
+    }
+
+    #[test]
+    fn test_next_table() {
+        assert_eq!(
+            next_table(enums::KeyRotationTable::MerchantAccount),
+            Some(enums::KeyRotationTable::BusinessProfile)
+        );
+        assert_eq!(
+            next_table(enums::KeyRotationTable::Events),
+            Some(enums::KeyRotationTable::WebhookSigningKey)
+        );
+        assert_eq!(
+            next_table(enums::KeyRotationTable::WebhookSigningKey),
+            Some(enums::KeyRotationTable::DataRetentionArchive)
+        );
+        assert_eq!(
+            next_table(enums::KeyRotationTable::DataRetentionArchive),
+            None
+        );
+        assert_eq!(
+            enums::KeyRotationTable::iter()
+                .skip(1)
+                .map(Some)
+                .collect::<Vec<_>>(),
+            enums::KeyRotationTable::iter()
+                .map(next_table)
+                .take_while(Option::is_some)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_get_kv_partition_key() {
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant1")).unwrap();
+
+        assert_eq!(
+            get_kv_partition_key(
+                enums::KeyRotationTable::PaymentIntent,
+                &merchant_id,
+                "pay_1"
+            ),
+            Some(
+                PartitionKey::MerchantIdPaymentId {
+                    merchant_id: &merchant_id,
+                    payment_id: "pay_1",
+                }
+                .to_string()
+            )
+        );
+        assert!(
+            get_kv_partition_key(enums::KeyRotationTable::Customers, &merchant_id, "cus_1")
+                .is_some()
+        );
+        // Payment addresses are flushed along with their payment intent
+        assert_eq!(
+            get_kv_partition_key(enums::KeyRotationTable::Address, &merchant_id, "add_1"),
+            None
+        );
+    }
+}
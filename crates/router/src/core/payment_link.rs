@@ -1,3 +1,5 @@
+pub mod invoice;
+pub mod reusable;
 pub mod validator;
 use actix_web::http::header;
 use api_models::{
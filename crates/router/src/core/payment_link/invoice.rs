@@ -0,0 +1,736 @@
+//! QR codes and print-ready invoices of payment links, for merchants that share payment links
+//! in person rather than online.
+
+use api_models::{
+    admin::PaymentLinkConfig,
+    payments::{
+        PaymentLinkInvoiceFormat, PaymentLinkInvoiceRequest, PaymentLinkQrCodeFormat,
+        PaymentLinkQrCodeRequest,
+    },
+};
+use common_utils::{
+    consts::{DEFAULT_BACKGROUND_COLOR, DEFAULT_SESSION_EXPIRY},
+    ext_traits::ValueExt,
+    types::{AmountConvertor, MinorUnit, StringMajorUnitForCore},
+};
+use error_stack::{report, ResultExt};
+use image::Luma;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use tera::{Context, Tera};
+use time::PrimitiveDateTime;
+
+use super::{capitalize_first_char, extract_payment_link_config};
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage::payment_link::PaymentLink},
+};
+
+const DEFAULT_QR_CODE_SIZE: u32 = 256;
+const MAX_QR_CODE_SIZE: u32 = 2048;
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_link_qr_code(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: PaymentLinkQrCodeRequest,
+) -> RouterResponse<serde_json::Value> {
+    let payment_link =
+        find_payment_link_of_merchant(&state, &merchant_account, &req.payment_link_id).await?;
+
+    let size = req.size.unwrap_or(DEFAULT_QR_CODE_SIZE);
+    if size == 0 || size > MAX_QR_CODE_SIZE {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("size must be between 1 and {MAX_QR_CODE_SIZE}"),
+        }))?
+    }
+
+    let qr_code = qrcode::QrCode::new(payment_link.link_to_pay.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create QR code of payment link")?;
+
+    match req.format {
+        PaymentLinkQrCodeFormat::Png => {
+            let image = qr_code
+                .render::<Luma<u8>>()
+                .min_dimensions(size, size)
+                .build();
+            let mut image_bytes = std::io::Cursor::new(Vec::new());
+            image::DynamicImage::ImageLuma8(image)
+                .write_to(&mut image_bytes, image::ImageFormat::Png)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode QR code of payment link")?;
+            Ok(ApplicationResponse::FileData((
+                image_bytes.into_inner(),
+                mime::IMAGE_PNG,
+            )))
+        }
+        PaymentLinkQrCodeFormat::Svg => {
+            let image = qr_code
+                .render::<qrcode::render::svg::Color<'_>>()
+                .min_dimensions(size, size)
+                .build();
+            Ok(ApplicationResponse::FileData((
+                image.into_bytes(),
+                mime::IMAGE_SVG,
+            )))
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_link_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentLinkInvoiceRequest,
+) -> RouterResponse<serde_json::Value> {
+    let db = &*state.store;
+    let payment_link =
+        find_payment_link_of_merchant(&state, &merchant_account, &req.payment_link_id).await?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_link.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let currency = payment_link
+        .currency
+        .or(payment_intent.currency)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Currency missing in payment link and payment intent")?;
+
+    let payment_link_config = match payment_link.payment_link_config.clone() {
+        Some(payment_link_config) => extract_payment_link_config(payment_link_config)?,
+        None => PaymentLinkConfig {
+            theme: DEFAULT_BACKGROUND_COLOR.to_string(),
+            // Invoices are handed to customers, so the default logo of the payment page is not
+            // used in place of the logo of the merchant
+            logo: String::new(),
+            seller_name: payment_link
+                .custom_merchant_name
+                .clone()
+                .or_else(|| {
+                    merchant_account
+                        .merchant_name
+                        .clone()
+                        .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+                })
+                .unwrap_or_default(),
+            sdk_layout: String::new(),
+            display_sdk_only: false,
+            enabled_saved_payment_method: false,
+            allowed_domains: None,
+            transaction_details: None,
+        },
+    };
+
+    let line_items = payment_intent
+        .order_details
+        .unwrap_or_default()
+        .into_iter()
+        .map(|order_details| {
+            let order_details = order_details
+                .peek()
+                .clone()
+                .parse_value::<api_models::payments::OrderDetailsWithAmount>(
+                    "OrderDetailsWithAmount",
+                )
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "OrderDetailsWithAmount",
+                })?;
+            let total_amount = order_details
+                .amount
+                .checked_mul(i64::from(order_details.quantity))
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Order details amount overflowed")?;
+            Ok(InvoiceLineItem {
+                name: capitalize_first_char(&order_details.product_name),
+                quantity: order_details.quantity,
+                unit_amount: format_amount(MinorUnit::new(order_details.amount), currency)?,
+                amount: format_amount(MinorUnit::new(total_amount), currency)?,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let due_date = req
+        .due_date
+        .or(payment_link.fulfilment_time)
+        .unwrap_or_else(|| {
+            payment_link
+                .created_at
+                .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
+        });
+
+    let qr_code = qrcode::QrCode::new(payment_link.link_to_pay.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create QR code of payment link")?;
+
+    let invoice = Invoice {
+        invoice_number: payment_link.payment_link_id.clone(),
+        seller_name: capitalize_first_char(&payment_link_config.seller_name),
+        logo: payment_link_config.logo.clone(),
+        theme: parse_hex_color(&payment_link_config.theme)
+            .map(|_| payment_link_config.theme.clone())
+            .unwrap_or_else(|| DEFAULT_BACKGROUND_COLOR.to_string()),
+        description: payment_link
+            .description
+            .clone()
+            .or(payment_intent.description),
+        issue_date: format_date(payment_link.created_at),
+        due_date: format_date(due_date),
+        currency: currency.to_string(),
+        amount: format_amount(payment_link.amount, currency)?,
+        line_items,
+        transaction_details: get_transaction_details(&payment_link_config),
+        link: payment_link.link_to_pay.clone(),
+        qr_code_svg: qr_code
+            .render::<qrcode::render::svg::Color<'_>>()
+            .min_dimensions(DEFAULT_QR_CODE_SIZE, DEFAULT_QR_CODE_SIZE)
+            .build(),
+    };
+
+    match req.format {
+        PaymentLinkInvoiceFormat::Html => Ok(ApplicationResponse::FileData((
+            build_invoice_html(&invoice)?.into_bytes(),
+            mime::TEXT_HTML_UTF_8,
+        ))),
+        PaymentLinkInvoiceFormat::Pdf => Ok(ApplicationResponse::FileData((
+            build_invoice_pdf(&invoice, &qr_code),
+            mime::APPLICATION_PDF,
+        ))),
+    }
+}
+
+async fn find_payment_link_of_merchant(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_link_id: &str,
+) -> RouterResult<PaymentLink> {
+    state
+        .store
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)
+        .and_then(|payment_link| {
+            if &payment_link.merchant_id == merchant_account.get_id() {
+                Ok(payment_link)
+            } else {
+                Err(report!(errors::ApiErrorResponse::PaymentLinkNotFound))
+            }
+        })
+}
+
+#[derive(Debug, serde::Serialize)]
+struct Invoice {
+    invoice_number: String,
+    seller_name: String,
+    logo: String,
+    theme: String,
+    description: Option<String>,
+    issue_date: String,
+    due_date: String,
+    currency: String,
+    amount: String,
+    line_items: Vec<InvoiceLineItem>,
+    transaction_details: Vec<InvoiceDetail>,
+    link: String,
+    qr_code_svg: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct InvoiceLineItem {
+    name: String,
+    quantity: u16,
+    unit_amount: String,
+    amount: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct InvoiceDetail {
+    label: String,
+    value: String,
+}
+
+fn format_amount(amount: MinorUnit, currency: api_models::enums::Currency) -> RouterResult<String> {
+    StringMajorUnitForCore
+        .convert(amount, currency)
+        .map(|amount| amount.get_amount_as_string())
+        .change_context(errors::ApiErrorResponse::AmountConversionFailed {
+            amount_type: "StringMajorUnit",
+        })
+}
+
+fn format_date(date_time: PrimitiveDateTime) -> String {
+    date_time.date().to_string()
+}
+
+/// Parses the key-value pairs of the `transaction_details` of the payment link, which are
+/// displayed in the same order on the payment page
+fn get_transaction_details(payment_link_config: &PaymentLinkConfig) -> Vec<InvoiceDetail> {
+    let Some(transaction_details) = payment_link_config.transaction_details.as_ref() else {
+        return Vec::new();
+    };
+
+    match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(transaction_details) {
+        Ok(transaction_details) => transaction_details
+            .into_iter()
+            .map(|(label, value)| InvoiceDetail {
+                label,
+                value: match value {
+                    serde_json::Value::String(value) => value,
+                    value => value.to_string(),
+                },
+            })
+            .collect(),
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to parse transaction details of payment link"
+            );
+            Vec::new()
+        }
+    }
+}
+
+/// Parses a `#rrggbb` color into its components, as fractions of the maximum intensity
+fn parse_hex_color(color: &str) -> Option<(f32, f32, f32)> {
+    let hex = color.strip_prefix('#').filter(|hex| hex.len() == 6)?;
+    let component = |range: std::ops::Range<usize>| {
+        hex.get(range)
+            .and_then(|component| u8::from_str_radix(component, 16).ok())
+            .map(|component| f32::from(component) / 255.0)
+    };
+    Some((component(0..2)?, component(2..4)?, component(4..6)?))
+}
+
+fn build_invoice_html(invoice: &Invoice) -> RouterResult<String> {
+    let mut tera = Tera::default();
+    // The `.html` suffix enables escaping of the merchant provided values
+    tera.add_raw_template(
+        "invoice.html",
+        include_str!("payment_link_invoice/invoice.html"),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add payment link invoice template")?;
+
+    let mut context = Context::new();
+    context.insert("invoice", invoice);
+    tera.render("invoice.html", &context)
+        .map_err(|tera_error| {
+            logger::warn!("{tera_error}");
+            report!(errors::ApiErrorResponse::InternalServerError)
+        })
+        .attach_printable("Error while rendering payment link invoice's HTML template")
+}
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const PAGE_MARGIN: f32 = 40.0;
+const QR_CODE_WIDTH: f32 = 120.0;
+
+/// Lays out the invoice on a single A4 page.
+///
+/// Only the standard Helvetica fonts are used, so that no font has to be embedded. Characters
+/// outside of Latin-1 are printed as `?`, and the logo of the merchant is not included since it
+/// is only available as a URL. Line items that do not fit on the page are summarized in a single
+/// line.
+fn build_invoice_pdf(invoice: &Invoice, qr_code: &qrcode::QrCode) -> Vec<u8> {
+    let theme = parse_hex_color(&invoice.theme).unwrap_or((0.13, 0.18, 0.27));
+    let mut page = PdfContent::default();
+
+    // Header band in the theme color of the merchant
+    page.fill_color(theme);
+    page.rectangle(0.0, PAGE_HEIGHT - 70.0, PAGE_WIDTH, 70.0);
+    page.fill_color((1.0, 1.0, 1.0));
+    page.text(
+        PdfFont::Bold,
+        20.0,
+        PAGE_MARGIN,
+        PAGE_HEIGHT - 43.0,
+        &invoice.seller_name,
+    );
+    page.text(PdfFont::Bold, 20.0, 440.0, PAGE_HEIGHT - 43.0, "INVOICE");
+
+    // QR code in the top right corner, drawn as vector modules
+    page.fill_color((0.0, 0.0, 0.0));
+    let qr_code_top = PAGE_HEIGHT - 100.0;
+    let modules = qr_code.width();
+    let module_width = QR_CODE_WIDTH / f32::from(u16::try_from(modules).unwrap_or(u16::MAX));
+    let qr_code_left = PAGE_WIDTH - PAGE_MARGIN - QR_CODE_WIDTH;
+    for (row, colors) in qr_code.to_colors().chunks(modules.max(1)).enumerate() {
+        for (column, color) in colors.iter().enumerate() {
+            if *color == qrcode::Color::Dark {
+                page.rectangle(
+                    qr_code_left + module_offset(column, module_width),
+                    qr_code_top - module_offset(row + 1, module_width),
+                    module_width,
+                    module_width,
+                );
+            }
+        }
+    }
+    page.fill_color((0.48, 0.48, 0.48));
+    page.text(
+        PdfFont::Regular,
+        9.0,
+        qr_code_left + 35.0,
+        qr_code_top - QR_CODE_WIDTH - 12.0,
+        "Scan to pay",
+    );
+
+    // Invoice details
+    let mut y = PAGE_HEIGHT - 110.0;
+    let details = [
+        ("Invoice number", invoice.invoice_number.as_str()),
+        ("Issue date", invoice.issue_date.as_str()),
+        ("Due date", invoice.due_date.as_str()),
+    ]
+    .into_iter()
+    .chain(
+        invoice
+            .transaction_details
+            .iter()
+            .map(|detail| (detail.label.as_str(), detail.value.as_str())),
+    );
+    for (label, value) in details {
+        page.fill_color((0.48, 0.48, 0.48));
+        page.text(PdfFont::Regular, 10.0, PAGE_MARGIN, y, label);
+        page.fill_color((0.18, 0.18, 0.18));
+        page.text(PdfFont::Regular, 10.0, 150.0, y, &truncate(value, 45));
+        y -= 16.0;
+    }
+
+    y = y.min(qr_code_top - QR_CODE_WIDTH - 40.0);
+    if let Some(description) = &invoice.description {
+        page.text(
+            PdfFont::Regular,
+            11.0,
+            PAGE_MARGIN,
+            y,
+            &truncate(description, 95),
+        );
+        y -= 30.0;
+    }
+
+    // Line items
+    if !invoice.line_items.is_empty() {
+        page.fill_color((0.48, 0.48, 0.48));
+        page.text(PdfFont::Bold, 10.0, PAGE_MARGIN, y, "Item");
+        page.text(PdfFont::Bold, 10.0, 300.0, y, "Quantity");
+        page.text(PdfFont::Bold, 10.0, 370.0, y, "Unit price");
+        page.text(
+            PdfFont::Bold,
+            10.0,
+            460.0,
+            y,
+            &format!("Amount ({})", invoice.currency),
+        );
+        page.fill_color(theme);
+        page.rectangle(PAGE_MARGIN, y - 6.0, PAGE_WIDTH - 2.0 * PAGE_MARGIN, 1.5);
+        y -= 22.0;
+
+        page.fill_color((0.18, 0.18, 0.18));
+        for (index, item) in invoice.line_items.iter().enumerate() {
+            if y < 120.0 {
+                page.text(
+                    PdfFont::Regular,
+                    10.0,
+                    PAGE_MARGIN,
+                    y,
+                    &format!(
+                        "... and {} more items",
+                        invoice.line_items.len().saturating_sub(index)
+                    ),
+                );
+                y -= 20.0;
+                break;
+            }
+            page.text(
+                PdfFont::Regular,
+                10.0,
+                PAGE_MARGIN,
+                y,
+                &truncate(&item.name, 45),
+            );
+            page.text(PdfFont::Regular, 10.0, 300.0, y, &item.quantity.to_string());
+            page.text(PdfFont::Regular, 10.0, 370.0, y, &item.unit_amount);
+            page.text(PdfFont::Regular, 10.0, 460.0, y, &item.amount);
+            y -= 20.0;
+        }
+    }
+
+    // Total and payment instructions
+    y -= 10.0;
+    page.text(
+        PdfFont::Bold,
+        16.0,
+        PAGE_MARGIN,
+        y,
+        &format!("Amount due: {} {}", invoice.amount, invoice.currency),
+    );
+    page.fill_color((0.48, 0.48, 0.48));
+    page.text(PdfFont::Regular, 9.0, PAGE_MARGIN, 60.0, "Pay online at");
+    page.text(
+        PdfFont::Regular,
+        8.0,
+        PAGE_MARGIN,
+        48.0,
+        &truncate(&invoice.link, 120),
+    );
+
+    page.into_pdf()
+}
+
+fn module_offset(modules: usize, module_width: f32) -> f32 {
+    f32::from(u16::try_from(modules).unwrap_or(u16::MAX)) * module_width
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() > max_chars {
+        let mut truncated = text
+            .chars()
+            .take(max_chars.saturating_sub(3))
+            .collect::<String>();
+        truncated.push_str("...");
+        truncated
+    } else {
+        text.to_owned()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum PdfFont {
+    Regular,
+    Bold,
+}
+
+/// Content stream of a PDF page, using PDF points with the origin in the bottom left corner
+#[derive(Default)]
+struct PdfContent(String);
+
+impl PdfContent {
+    fn fill_color(&mut self, (red, green, blue): (f32, f32, f32)) {
+        self.0
+            .push_str(&format!("{red:.3} {green:.3} {blue:.3} rg\n"));
+    }
+
+    fn rectangle(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.0
+            .push_str(&format!("{x:.2} {y:.2} {width:.2} {height:.2} re f\n"));
+    }
+
+    fn text(&mut self, font: PdfFont, size: f32, x: f32, y: f32, text: &str) {
+        let font = match font {
+            PdfFont::Regular => "F1",
+            PdfFont::Bold => "F2",
+        };
+        self.0.push_str(&format!(
+            "BT /{font} {size:.1} Tf {x:.2} {y:.2} Td ({}) Tj ET\n",
+            escape_pdf_text(text)
+        ));
+    }
+
+    /// Wraps the content stream in a single page document
+    fn into_pdf(self) -> Vec<u8> {
+        let objects = [
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 5 0 R /F2 6 0 R >> >> /Contents 4 0 R >>"
+            ),
+            format!(
+                "<< /Length {} >>\nstream\n{}endstream",
+                self.0.len(),
+                self.0
+            ),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+        ];
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", index + 1).as_bytes());
+        }
+
+        let xref_offset = pdf.len();
+        let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            xref.push_str(&format!("{offset:010} 00000 n \n"));
+        }
+        xref.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            objects.len() + 1
+        ));
+        pdf.extend_from_slice(xref.as_bytes());
+        pdf
+    }
+}
+
+/// Escapes text for a PDF string literal in `WinAnsiEncoding`, which matches Latin-1 for the
+/// printable characters above ASCII
+fn escape_pdf_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '(' | ')' | '\\' => {
+                escaped.push('\\');
+                escaped.push(character);
+            }
+            ' '..='~' => escaped.push(character),
+            '\u{a0}'..='\u{ff}' => escaped.push_str(&format!("\\{:03o}", u32::from(character))),
+            _ => escaped.push('?'),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn invoice(line_items: Vec<InvoiceLineItem>) -> Invoice {
+        Invoice {
+            invoice_number: "plink_123".to_string(),
+            seller_name: "Café (Downtown)".to_string(),
+            logo: String::new(),
+            theme: "#1a2b3c".to_string(),
+            description: Some("Monthly subscription".to_string()),
+            issue_date: "2024-05-01".to_string(),
+            due_date: "2024-05-16".to_string(),
+            currency: "USD".to_string(),
+            amount: "10.50".to_string(),
+            line_items,
+            transaction_details: vec![InvoiceDetail {
+                label: "Order".to_string(),
+                value: "ord_42".to_string(),
+            }],
+            link: "https://example.com/payment_link/plink_123".to_string(),
+            qr_code_svg: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_escape_pdf_text() {
+        assert_eq!(escape_pdf_text("Total (net)"), "Total \\(net\\)");
+        assert_eq!(escape_pdf_text("C:\\invoices"), "C:\\\\invoices");
+        assert_eq!(escape_pdf_text("Café £5"), "Caf\\351 \\2435");
+        assert_eq!(escape_pdf_text("Tokyo 東京\n"), "Tokyo ???");
+    }
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(parse_hex_color("#ff0000"), Some((1.0, 0.0, 0.0)));
+        assert_eq!(parse_hex_color("#FFFFFF"), Some((1.0, 1.0, 1.0)));
+        assert_eq!(parse_hex_color("ff0000"), None);
+        assert_eq!(parse_hex_color("#fff"), None);
+        assert_eq!(parse_hex_color("#gg0000"), None);
+        assert_eq!(parse_hex_color("#ff00é"), None);
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("Copy paper", 10), "Copy paper");
+        assert_eq!(truncate("Copy paper, A4", 10), "Copy pa...");
+        assert_eq!(truncate("Cafés crème", 8), "Cafés...");
+        assert_eq!(truncate("Copy paper", 2), "...");
+    }
+
+    #[test]
+    fn test_pdf_content_operators() {
+        let mut page = PdfContent::default();
+        page.fill_color((1.0, 0.5, 0.0));
+        page.rectangle(40.0, 772.0, 515.0, 1.5);
+        page.text(PdfFont::Bold, 20.0, 40.0, 799.0, "INVOICE (copy)");
+        page.text(PdfFont::Regular, 9.5, 40.0, 60.0, "Pay online at");
+
+        assert_eq!(
+            page.0,
+            "1.000 0.500 0.000 rg\n\
+             40.00 772.00 515.00 1.50 re f\n\
+             BT /F2 20.0 Tf 40.00 799.00 Td (INVOICE \\(copy\\)) Tj ET\n\
+             BT /F1 9.5 Tf 40.00 60.00 Td (Pay online at) Tj ET\n"
+        );
+    }
+
+    #[test]
+    fn test_into_pdf_offsets_point_at_the_objects() {
+        let mut page = PdfContent::default();
+        page.text(PdfFont::Regular, 10.0, 40.0, 40.0, "Amount due");
+        let pdf = String::from_utf8(page.into_pdf()).unwrap();
+
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF\n"));
+
+        let xref_offset = pdf
+            .lines()
+            .skip_while(|line| *line != "startxref")
+            .nth(1)
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+        let xref = &pdf[xref_offset..];
+        assert!(xref.starts_with("xref\n0 7\n0000000000 65535 f \n"));
+        assert!(xref.contains("trailer\n<< /Size 7 /Root 1 0 R >>"));
+
+        let offsets = xref
+            .lines()
+            .skip(3)
+            .take(6)
+            .map(|entry| {
+                assert_eq!(entry.len(), 19);
+                assert!(entry.ends_with(" 00000 n "));
+                entry[..10].parse::<usize>().unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(offsets.len(), 6);
+        for (index, offset) in offsets.into_iter().enumerate() {
+            assert!(pdf[offset..].starts_with(&format!("{} 0 obj\n", index + 1)));
+        }
+
+        let stream = "BT /F1 10.0 Tf 40.00 40.00 Td (Amount due) Tj ET\n";
+        assert!(pdf.contains(&format!(
+            "<< /Length {} >>\nstream\n{stream}endstream",
+            stream.len()
+        )));
+    }
+
+    #[test]
+    fn test_build_invoice_pdf() {
+        let qr_code = qrcode::QrCode::new(b"https://example.com/payment_link/plink_123").unwrap();
+        let line_items = (1..=40)
+            .map(|index| InvoiceLineItem {
+                name: format!("Item {index}"),
+                quantity: 1,
+                unit_amount: "0.25".to_string(),
+                amount: "0.25".to_string(),
+            })
+            .collect();
+
+        let pdf = String::from_utf8(build_invoice_pdf(&invoice(line_items), &qr_code)).unwrap();
+
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.contains("0.102 0.169 0.235 rg\n"));
+        assert!(pdf.contains("(Caf\\351 \\(Downtown\\)) Tj"));
+        assert!(pdf.contains("(Invoice number) Tj"));
+        assert!(pdf.contains("(ord_42) Tj"));
+        assert!(pdf.contains("(Item 1) Tj"));
+        assert!(pdf.contains("more items) Tj"));
+        assert!(!pdf.contains("(Item 40) Tj"));
+        assert!(pdf.contains("(Amount due: 10.50 USD) Tj"));
+    }
+}
@@ -0,0 +1,1420 @@
+//! Payment links that create a new payment on every visit, so that a single link can be shared
+//! with any number of customers.
+//!
+//! Visiting a link only validates the choices of the customer. The usage count of the link and the
+//! quantity sold of its items are reserved when the browser of the customer submits the choices and
+//! a payment is created, and returned to the link when the payment fails or is abandoned by the
+//! customer.
+//! Outcomes of the payments are synced lazily, whenever the sales summary of the link is requested
+//! or a visit finds the link exhausted.
+
+use std::collections::HashMap;
+
+use api_models::{
+    payments::{OrderDetailsWithAmount, PaymentCreatePaymentLinkConfig, RedirectionResponse},
+    reusable_payment_link::{
+        AdjustableQuantity, ReusablePaymentLinkCreateRequest, ReusablePaymentLinkCustomAmount,
+        ReusablePaymentLinkId, ReusablePaymentLinkItemSalesSummary,
+        ReusablePaymentLinkLineItemResponse, ReusablePaymentLinkListConstraints,
+        ReusablePaymentLinkListResponse, ReusablePaymentLinkResponse,
+        ReusablePaymentLinkSalesSummary, ReusablePaymentLinkUpdateRequest,
+        ReusablePaymentLinkVisitParams, ReusablePaymentLinkVisitRequest,
+    },
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::{keymanager::KeyManagerState, MinorUnit},
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, SessionState},
+    services::{self, ApplicationResponse},
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+/// Quantities of the items of a link included in a payment, keyed by item ID
+type ItemQuantities = HashMap<String, u16>;
+
+#[instrument(skip_all)]
+pub async fn create_reusable_payment_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: ReusablePaymentLinkCreateRequest,
+) -> RouterResponse<ReusablePaymentLinkResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state: &KeyManagerState = &(&state).into();
+    validate_create_request(&req)?;
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        key_manager_state,
+        &key_store,
+        None,
+        None,
+        &merchant_account,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    let reusable_link_id = utils::generate_id(consts::ID_LENGTH, "rplink");
+    let link_to_pay = format!(
+        "{}/payment_link/r/{}/{}",
+        state.base_url,
+        merchant_account.get_id().get_string_repr(),
+        reusable_link_id
+    );
+    let custom_amount = req
+        .custom_amount
+        .map(|custom_amount| custom_amount.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode custom amount")?;
+    let payment_link_config = req
+        .payment_link_config
+        .map(|payment_link_config| payment_link_config.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_link_config",
+        })?;
+    let now = common_utils::date_time::now();
+
+    let reusable_payment_link = db
+        .insert_reusable_payment_link(storage::ReusablePaymentLinkNew {
+            reusable_link_id: reusable_link_id.clone(),
+            merchant_id: merchant_account.get_id().clone(),
+            profile_id,
+            link_to_pay,
+            status: enums::ReusablePaymentLinkStatus::Active,
+            currency: req.currency,
+            description: req.description,
+            custom_amount,
+            max_uses: req.max_uses.map(to_i32).transpose()?,
+            times_used: 0,
+            payment_link_config,
+            expires_at: req.expires_at,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Reusable payment link already exists".to_string(),
+        })?;
+
+    let items = req
+        .line_items
+        .into_iter()
+        .map(|line_item| {
+            let (min_quantity, max_quantity) = line_item
+                .adjustable_quantity
+                .map(|adjustable| (Some(adjustable.minimum), Some(adjustable.maximum)))
+                .unwrap_or_default();
+            storage::ReusablePaymentLinkItemNew {
+                reusable_link_id: reusable_link_id.clone(),
+                item_id: line_item
+                    .item_id
+                    .unwrap_or_else(|| utils::generate_id(consts::ID_LENGTH, "item")),
+                name: line_item.name,
+                description: line_item.description,
+                image_url: line_item.image_url,
+                unit_amount: line_item.unit_amount,
+                quantity: i32::from(line_item.quantity),
+                min_quantity: min_quantity.map(i32::from),
+                max_quantity: max_quantity.map(i32::from),
+                inventory: line_item.inventory.map(i64::from),
+                quantity_sold: 0,
+                created_at: now,
+            }
+        })
+        .collect::<Vec<_>>();
+    if !items.is_empty() {
+        db.insert_reusable_payment_link_items(items)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert reusable payment link items")?;
+    }
+
+    let items = db
+        .list_reusable_payment_link_items_by_reusable_link_id(&reusable_link_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list reusable payment link items")?;
+
+    build_reusable_payment_link_response(reusable_payment_link, items)
+        .map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn list_reusable_payment_links(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: ReusablePaymentLinkListConstraints,
+) -> RouterResponse<ReusablePaymentLinkListResponse> {
+    let db = state.store.as_ref();
+    let reusable_payment_links = db
+        .list_reusable_payment_links_by_merchant_id(
+            merchant_account.get_id(),
+            constraints.limit,
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list reusable payment links")?;
+
+    let mut data = Vec::with_capacity(reusable_payment_links.len());
+    for reusable_payment_link in reusable_payment_links {
+        let items = db
+            .list_reusable_payment_link_items_by_reusable_link_id(
+                &reusable_payment_link.reusable_link_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list reusable payment link items")?;
+        data.push(build_reusable_payment_link_response(
+            reusable_payment_link,
+            items,
+        )?);
+    }
+
+    Ok(ApplicationResponse::Json(ReusablePaymentLinkListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_reusable_payment_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: ReusablePaymentLinkId,
+) -> RouterResponse<ReusablePaymentLinkResponse> {
+    let db = state.store.as_ref();
+    let reusable_payment_link = db
+        .find_reusable_payment_link_by_merchant_id_reusable_link_id(
+            merchant_account.get_id(),
+            &req.reusable_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    let items = db
+        .list_reusable_payment_link_items_by_reusable_link_id(&req.reusable_link_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list reusable payment link items")?;
+
+    build_reusable_payment_link_response(reusable_payment_link, items)
+        .map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn update_reusable_payment_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: ReusablePaymentLinkUpdateRequest,
+) -> RouterResponse<ReusablePaymentLinkResponse> {
+    let db = state.store.as_ref();
+    match req.status {
+        Some(enums::ReusablePaymentLinkStatus::Expired)
+        | Some(enums::ReusablePaymentLinkStatus::Exhausted) => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "status can only be updated to `active` or `inactive`".to_string(),
+            }))?
+        }
+        Some(enums::ReusablePaymentLinkStatus::Active)
+        | Some(enums::ReusablePaymentLinkStatus::Inactive)
+        | None => {}
+    }
+    if req.max_uses == Some(0) {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "max_uses must be greater than zero".to_string(),
+        }))?
+    }
+
+    let reusable_payment_link = db
+        .update_reusable_payment_link_by_merchant_id_reusable_link_id(
+            merchant_account.get_id(),
+            &req.reusable_link_id,
+            storage::ReusablePaymentLinkUpdate::Update {
+                status: req.status,
+                description: req.description,
+                max_uses: req.max_uses.map(to_i32).transpose()?,
+                expires_at: req.expires_at,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    let items = db
+        .list_reusable_payment_link_items_by_reusable_link_id(&req.reusable_link_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list reusable payment link items")?;
+
+    build_reusable_payment_link_response(reusable_payment_link, items)
+        .map(ApplicationResponse::Json)
+}
+
+/// Summarizes the payments created through the link, after syncing the outcomes of the payments
+/// that were pending.
+#[instrument(skip_all)]
+pub async fn retrieve_reusable_payment_link_sales_summary(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: ReusablePaymentLinkId,
+) -> RouterResponse<ReusablePaymentLinkSalesSummary> {
+    let db = state.store.as_ref();
+    let reusable_payment_link = db
+        .find_reusable_payment_link_by_merchant_id_reusable_link_id(
+            merchant_account.get_id(),
+            &req.reusable_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let payments = sync_reusable_payment_link_payments(
+        &state,
+        &merchant_account,
+        &key_store,
+        &req.reusable_link_id,
+    )
+    .await?;
+
+    // The usage count and the quantities sold may have changed while syncing the payments
+    let reusable_payment_link = db
+        .find_reusable_payment_link_by_merchant_id_reusable_link_id(
+            merchant_account.get_id(),
+            &reusable_payment_link.reusable_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    let items = db
+        .list_reusable_payment_link_items_by_reusable_link_id(&req.reusable_link_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list reusable payment link items")?;
+
+    let mut succeeded_payments = 0;
+    let mut pending_payments = 0;
+    let mut failed_payments = 0;
+    let mut amount_collected = MinorUnit::new(0);
+    let mut quantities_sold = ItemQuantities::new();
+    let mut quantities_pending = ItemQuantities::new();
+    for payment in &payments {
+        let quantities = parse_item_quantities(payment)?;
+        match PaymentOutcome::from_payment(payment) {
+            PaymentOutcome::Succeeded => {
+                succeeded_payments += 1;
+                amount_collected = amount_collected + payment.amount;
+                add_item_quantities(&mut quantities_sold, quantities);
+            }
+            PaymentOutcome::Pending => {
+                pending_payments += 1;
+                add_item_quantities(&mut quantities_pending, quantities);
+            }
+            PaymentOutcome::Failed => failed_payments += 1,
+        }
+    }
+
+    let line_items = items
+        .into_iter()
+        .map(|item| ReusablePaymentLinkItemSalesSummary {
+            quantity_sold: quantities_sold
+                .get(&item.item_id)
+                .copied()
+                .map(u32::from)
+                .unwrap_or_default(),
+            quantity_pending: quantities_pending
+                .get(&item.item_id)
+                .copied()
+                .map(u32::from)
+                .unwrap_or_default(),
+            remaining_inventory: item
+                .inventory
+                .map(|inventory| to_u32(inventory.saturating_sub(item.quantity_sold))),
+            item_id: item.item_id,
+            name: item.name,
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(ReusablePaymentLinkSalesSummary {
+        reusable_link_id: reusable_payment_link.reusable_link_id,
+        currency: reusable_payment_link.currency,
+        total_payments: to_u32(payments.len()),
+        succeeded_payments,
+        pending_payments,
+        failed_payments,
+        amount_collected,
+        remaining_uses: reusable_payment_link
+            .max_uses
+            .map(|max_uses| to_u32(max_uses.saturating_sub(reusable_payment_link.times_used))),
+        line_items,
+    }))
+}
+
+/// Validates the choices of the customer visiting the link, and has the browser of the customer
+/// submit them to the link.
+///
+/// Visiting the link reserves nothing, so that previews of the link fetched by crawlers and
+/// messaging apps do not use up the link. The link is reserved once the browser submits the
+/// choices, by [`initiate_reusable_payment_link`].
+#[instrument(skip_all)]
+pub async fn visit_reusable_payment_link(
+    state: SessionState,
+    req: ReusablePaymentLinkVisitRequest,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    let (reusable_payment_link, _, _, amount) = get_link_for_visit(db, &req).await?;
+
+    let form_fields = [
+        req.params
+            .quantities
+            .map(|quantities| ("quantities".to_string(), quantities)),
+        req.params
+            .amount
+            .map(|amount| ("amount".to_string(), amount.to_string())),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    Ok(ApplicationResponse::Form(Box::new(
+        services::RedirectionFormData {
+            redirect_form: services::RedirectForm::Form {
+                endpoint: reusable_payment_link.link_to_pay,
+                method: services::Method::Post,
+                form_fields,
+            },
+            payment_method_data: None,
+            amount: amount.to_string(),
+            currency: reusable_payment_link.currency.to_string(),
+        },
+    )))
+}
+
+/// Creates a new payment for the customer who submitted their choices on the link, and redirects
+/// the customer to the payment link of the payment.
+#[instrument(skip_all)]
+pub async fn initiate_reusable_payment_link(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: ReusablePaymentLinkVisitRequest,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    let (reusable_payment_link, items, quantities, amount) = get_link_for_visit(db, &req).await?;
+
+    if let Err(message) = reserve(db, &reusable_payment_link, &items, &quantities).await? {
+        logger::info!(%message, "Syncing payments of exhausted reusable payment link");
+        sync_reusable_payment_link_payments(
+            &state,
+            &merchant_account,
+            &key_store,
+            &reusable_payment_link.reusable_link_id,
+        )
+        .await?;
+        reserve(db, &reusable_payment_link, &items, &quantities)
+            .await?
+            .map_err(|message| report!(errors::ApiErrorResponse::PreconditionFailed { message }))?;
+    }
+
+    // The payment is recorded before it is created, so that the reservation is never held by a
+    // payment the link does not know about
+    let payment_id = utils::generate_id(consts::ID_LENGTH, "pay");
+    let now = common_utils::date_time::now();
+    let item_quantities = quantities
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode item quantities");
+    let recorded_payment = match item_quantities {
+        Ok(item_quantities) => db
+            .insert_reusable_payment_link_payment(storage::ReusablePaymentLinkPaymentNew {
+                payment_id: payment_id.clone(),
+                reusable_link_id: reusable_payment_link.reusable_link_id.clone(),
+                merchant_id: req.merchant_id.clone(),
+                amount,
+                currency: reusable_payment_link.currency,
+                item_quantities,
+                status: enums::IntentStatus::RequiresPaymentMethod,
+                released: false,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record reusable payment link payment"),
+        Err(error) => Err(error),
+    };
+    if let Err(error) = recorded_payment {
+        release(db, &reusable_payment_link.reusable_link_id, &quantities).await;
+        Err(error)?
+    }
+
+    let payment_link_config = reusable_payment_link
+        .payment_link_config
+        .clone()
+        .map(|config| {
+            config.parse_value::<PaymentCreatePaymentLinkConfig>("PaymentCreatePaymentLinkConfig")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payment link config of reusable payment link");
+    let payment_response = match payment_link_config {
+        Ok(payment_link_config) => {
+            let payment_request = build_payment_request(
+                &reusable_payment_link,
+                &items,
+                &quantities,
+                payment_id.clone(),
+                amount,
+                payment_link_config,
+            );
+            Box::pin(payments::payments_core::<
+                api::Authorize,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
+                req_state,
+                merchant_account.clone(),
+                Some(reusable_payment_link.profile_id.clone()),
+                key_store,
+                payments::PaymentCreate,
+                payment_request,
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+            ))
+            .await
+        }
+        Err(error) => Err(error),
+    };
+    let payment_response = match payment_response {
+        Ok(ApplicationResponse::Json(response))
+        | Ok(ApplicationResponse::JsonWithHeaders((response, _))) => response,
+        Ok(_) => {
+            release_payment(db, &payment_id, &reusable_payment_link.reusable_link_id).await;
+            Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Unexpected response from payments core")?
+        }
+        Err(error) => {
+            release_payment(db, &payment_id, &reusable_payment_link.reusable_link_id).await;
+            Err(error)?
+        }
+    };
+
+    let link = payment_response
+        .payment_link
+        .map(|payment_link| payment_link.link)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payment link not created for reusable payment link payment")?;
+
+    Ok(ApplicationResponse::JsonForRedirection(
+        RedirectionResponse {
+            headers: Vec::with_capacity(0),
+            return_url: String::new(),
+            http_method: String::new(),
+            params: Vec::with_capacity(0),
+            return_url_with_query_params: link,
+        },
+    ))
+}
+
+/// Finds the link visited by the customer, and validates the choices of the customer against it.
+async fn get_link_for_visit(
+    db: &dyn StorageInterface,
+    req: &ReusablePaymentLinkVisitRequest,
+) -> RouterResult<(
+    storage::ReusablePaymentLink,
+    Vec<storage::ReusablePaymentLinkItem>,
+    ItemQuantities,
+    MinorUnit,
+)> {
+    let reusable_payment_link = db
+        .find_reusable_payment_link_by_merchant_id_reusable_link_id(
+            &req.merchant_id,
+            &req.reusable_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    match reusable_payment_link.get_effective_status(common_utils::date_time::now()) {
+        enums::ReusablePaymentLinkStatus::Inactive => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment link is no longer active".to_string(),
+            }))?
+        }
+        enums::ReusablePaymentLinkStatus::Expired => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment link has expired".to_string(),
+            }))?
+        }
+        // Payments abandoned since the usage limit was reached may free up the link, which is
+        // checked while reserving the usage
+        enums::ReusablePaymentLinkStatus::Active | enums::ReusablePaymentLinkStatus::Exhausted => {}
+    }
+
+    let items = db
+        .list_reusable_payment_link_items_by_reusable_link_id(&req.reusable_link_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list reusable payment link items")?;
+    let quantities = get_item_quantities(&items, &req.params)?;
+    let amount = get_payment_amount(&reusable_payment_link, &items, &quantities, &req.params)?;
+
+    Ok((reusable_payment_link, items, quantities, amount))
+}
+
+fn build_payment_request(
+    reusable_payment_link: &storage::ReusablePaymentLink,
+    items: &[storage::ReusablePaymentLinkItem],
+    quantities: &ItemQuantities,
+    payment_id: String,
+    amount: MinorUnit,
+    payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
+) -> api::PaymentsRequest {
+    let order_details = items
+        .iter()
+        .filter_map(|item| {
+            quantities
+                .get(&item.item_id)
+                .filter(|quantity| **quantity > 0)
+                .map(|quantity| OrderDetailsWithAmount {
+                    product_name: item.name.clone(),
+                    quantity: *quantity,
+                    amount: item.unit_amount.get_amount_as_i64(),
+                    requires_shipping: None,
+                    product_img_link: item.image_url.clone(),
+                    product_id: Some(item.item_id.clone()),
+                    category: None,
+                    sub_category: None,
+                    brand: None,
+                    product_type: None,
+                })
+        })
+        .collect::<Vec<_>>();
+
+    api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id)),
+        amount: Some(amount.into()),
+        currency: Some(reusable_payment_link.currency),
+        profile_id: Some(reusable_payment_link.profile_id.clone()),
+        description: reusable_payment_link.description.clone(),
+        order_details: (!order_details.is_empty()).then_some(order_details),
+        metadata: Some(serde_json::json!({
+            "reusable_link_id": reusable_payment_link.reusable_link_id,
+        })),
+        payment_link: Some(true),
+        payment_link_config,
+        ..Default::default()
+    }
+}
+
+fn validate_create_request(req: &ReusablePaymentLinkCreateRequest) -> RouterResult<()> {
+    let invalid_request = |message: &str| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_string(),
+        }))
+    };
+
+    if req.line_items.is_empty() && req.custom_amount.is_none() {
+        return invalid_request("Either line_items or custom_amount must be provided");
+    }
+    if req.max_uses == Some(0) {
+        return invalid_request("max_uses must be greater than zero");
+    }
+    if req
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= common_utils::date_time::now())
+    {
+        return invalid_request("expires_at must be in the future");
+    }
+
+    if let Some(custom_amount) = req.custom_amount {
+        if custom_amount.minimum_amount < MinorUnit::new(0)
+            || custom_amount.maximum_amount < custom_amount.minimum_amount
+            || custom_amount.maximum_amount == MinorUnit::new(0)
+        {
+            return invalid_request(
+                "custom_amount must have a non-negative minimum_amount not exceeding a positive maximum_amount",
+            );
+        }
+        if custom_amount.default_amount.is_some_and(|default_amount| {
+            default_amount < custom_amount.minimum_amount
+                || default_amount > custom_amount.maximum_amount
+        }) {
+            return invalid_request(
+                "custom_amount.default_amount must be within minimum_amount and maximum_amount",
+            );
+        }
+    }
+
+    let mut item_ids = std::collections::HashSet::new();
+    for line_item in &req.line_items {
+        if let Some(item_id) = &line_item.item_id {
+            if item_id.is_empty() || item_id.len() > 64 {
+                return invalid_request("item_id must be between 1 and 64 characters long");
+            }
+            if !item_ids.insert(item_id) {
+                return invalid_request("item_id must be unique within the line_items");
+            }
+        }
+        if line_item.unit_amount <= MinorUnit::new(0) {
+            return invalid_request("unit_amount of the line_items must be greater than zero");
+        }
+        match line_item.adjustable_quantity {
+            Some(AdjustableQuantity { minimum, maximum }) => {
+                if maximum == 0
+                    || minimum > maximum
+                    || line_item.quantity < minimum
+                    || line_item.quantity > maximum
+                {
+                    return invalid_request(
+                        "quantity of the line_items must be within the bounds of adjustable_quantity",
+                    );
+                }
+            }
+            None => {
+                if line_item.quantity == 0 {
+                    return invalid_request("quantity of the line_items must be greater than zero");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves the quantities of the items chosen by the customer, falling back to the default
+/// quantities of the items.
+fn get_item_quantities(
+    items: &[storage::ReusablePaymentLinkItem],
+    params: &ReusablePaymentLinkVisitParams,
+) -> RouterResult<ItemQuantities> {
+    let mut chosen_quantities = ItemQuantities::new();
+    for pair in params
+        .quantities
+        .iter()
+        .flat_map(|quantities| quantities.split(','))
+        .filter(|pair| !pair.trim().is_empty())
+    {
+        let (item_id, quantity) = pair
+            .split_once(':')
+            .and_then(|(item_id, quantity)| {
+                quantity
+                    .trim()
+                    .parse::<u16>()
+                    .ok()
+                    .map(|quantity| (item_id.trim().to_owned(), quantity))
+            })
+            .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: "quantities must be comma separated `item_id:quantity` pairs".to_string(),
+            })?;
+        chosen_quantities.insert(item_id, quantity);
+    }
+
+    if let Some(item_id) = chosen_quantities
+        .keys()
+        .find(|item_id| !items.iter().any(|item| &item.item_id == *item_id))
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unknown item `{item_id}` in quantities"),
+        }))?
+    }
+
+    items
+        .iter()
+        .map(|item| {
+            let default_quantity = u16::try_from(item.quantity).unwrap_or_default();
+            let quantity = match (
+                chosen_quantities.get(&item.item_id),
+                item.min_quantity,
+                item.max_quantity,
+            ) {
+                (None, _, _) => default_quantity,
+                (Some(&quantity), Some(min_quantity), Some(max_quantity))
+                    if (min_quantity..=max_quantity).contains(&i32::from(quantity)) =>
+                {
+                    quantity
+                }
+                (Some(&quantity), None, None) if quantity == default_quantity => quantity,
+                (Some(_), _, _) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Invalid quantity for item `{}`", item.item_id),
+                }))?,
+            };
+            Ok((item.item_id.clone(), quantity))
+        })
+        .collect()
+}
+
+fn get_payment_amount(
+    reusable_payment_link: &storage::ReusablePaymentLink,
+    items: &[storage::ReusablePaymentLinkItem],
+    quantities: &ItemQuantities,
+    params: &ReusablePaymentLinkVisitParams,
+) -> RouterResult<MinorUnit> {
+    let custom_amount = match parse_custom_amount(reusable_payment_link)? {
+        Some(custom_amount) => {
+            let amount = params.amount.or(custom_amount.default_amount).ok_or(
+                errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "amount",
+                },
+            )?;
+            if amount < custom_amount.minimum_amount || amount > custom_amount.maximum_amount {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "amount must be between {} and {}",
+                        custom_amount.minimum_amount, custom_amount.maximum_amount
+                    ),
+                }))?
+            }
+            amount.get_amount_as_i64()
+        }
+        None if params.amount.is_some() => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The payment link does not allow choosing the amount".to_string(),
+            }))?
+        }
+        None => 0,
+    };
+
+    let amount = items
+        .iter()
+        .try_fold(custom_amount, |total, item| {
+            let quantity = quantities.get(&item.item_id).copied().unwrap_or_default();
+            item.unit_amount
+                .get_amount_as_i64()
+                .checked_mul(i64::from(quantity))
+                .and_then(|item_amount| total.checked_add(item_amount))
+        })
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The amount of the payment is too large".to_string(),
+        })?;
+
+    if amount <= 0 {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The amount of the payment must be greater than zero".to_string(),
+        }))?
+    }
+
+    Ok(MinorUnit::new(amount))
+}
+
+/// Reserves a use of the link and the quantities of the items for a new payment.
+///
+/// Returns the reason as an error if the usage limit of the link or the inventory of an item was
+/// reached, after returning whatever was reserved.
+async fn reserve(
+    db: &dyn StorageInterface,
+    reusable_payment_link: &storage::ReusablePaymentLink,
+    items: &[storage::ReusablePaymentLinkItem],
+    quantities: &ItemQuantities,
+) -> RouterResult<Result<(), String>> {
+    let reusable_link_id = &reusable_payment_link.reusable_link_id;
+
+    let reserved_link = db
+        .increment_reusable_payment_link_times_used(reusable_link_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reserve reusable payment link usage")?;
+    if reserved_link.is_none() {
+        return Ok(Err(
+            "The payment link has reached its usage limit".to_string()
+        ));
+    }
+
+    let mut reserved_quantities = ItemQuantities::new();
+    for item in items {
+        let quantity = quantities.get(&item.item_id).copied().unwrap_or_default();
+        if quantity == 0 {
+            continue;
+        }
+
+        let reserved_item = db
+            .increment_reusable_payment_link_item_quantity_sold(
+                reusable_link_id,
+                &item.item_id,
+                i64::from(quantity),
+            )
+            .await;
+        match reserved_item {
+            Ok(Some(_)) => {
+                reserved_quantities.insert(item.item_id.clone(), quantity);
+            }
+            Ok(None) => {
+                release(db, reusable_link_id, &reserved_quantities).await;
+                return Ok(Err(format!("`{}` is sold out", item.name)));
+            }
+            Err(error) => {
+                release(db, reusable_link_id, &reserved_quantities).await;
+                Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to reserve reusable payment link item")?
+            }
+        }
+    }
+
+    Ok(Ok(()))
+}
+
+/// Returns a use of the link and the quantities of the items reserved for a payment.
+async fn release(db: &dyn StorageInterface, reusable_link_id: &str, quantities: &ItemQuantities) {
+    db.decrement_reusable_payment_link_times_used(reusable_link_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to release reusable payment link usage"))
+        .ok();
+
+    for (item_id, quantity) in quantities.iter().filter(|(_, quantity)| **quantity > 0) {
+        db.decrement_reusable_payment_link_item_quantity_sold(
+            reusable_link_id,
+            item_id,
+            i64::from(*quantity),
+        )
+        .await
+        .map_err(|error| logger::error!(?error, %item_id, "Failed to release reusable payment link item"))
+        .ok();
+    }
+}
+
+/// Marks a recorded payment that could not be created as failed, and returns what was reserved
+/// for it, unless a concurrent sync already did.
+async fn release_payment(db: &dyn StorageInterface, payment_id: &str, reusable_link_id: &str) {
+    let released_payment = db
+        .update_unreleased_reusable_payment_link_payment_by_payment_id(
+            payment_id,
+            storage::ReusablePaymentLinkPaymentUpdate::StatusUpdate {
+                status: enums::IntentStatus::Failed,
+                released: true,
+            },
+        )
+        .await;
+    match released_payment {
+        Ok(released_payment) => match parse_item_quantities(&released_payment) {
+            Ok(quantities) => release(db, reusable_link_id, &quantities).await,
+            Err(error) => logger::error!(?error),
+        },
+        Err(error) if error.current_context().is_db_not_found() => {}
+        Err(error) => {
+            logger::error!(?error, "Failed to release reusable payment link payment")
+        }
+    }
+}
+
+/// Syncs the statuses of the pending payments created through the link, releasing the usage and
+/// inventory held by the payments that failed or were abandoned.
+async fn sync_reusable_payment_link_payments(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    reusable_link_id: &str,
+) -> RouterResult<Vec<storage::ReusablePaymentLinkPayment>> {
+    let db = state.store.as_ref();
+    let key_manager_state: &KeyManagerState = &state.into();
+    let now = common_utils::date_time::now();
+    let payments = db
+        .list_reusable_payment_link_payments_by_reusable_link_id(reusable_link_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list reusable payment link payments")?;
+
+    let mut synced_payments = Vec::with_capacity(payments.len());
+    for payment in payments {
+        if payment.released || is_final_status(payment.status) {
+            synced_payments.push(payment);
+            continue;
+        }
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &payment.payment_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await;
+        let payment_intent = match payment_intent {
+            Ok(payment_intent) => payment_intent,
+            // The payment is recorded before it is created, and is left without a payment if
+            // the application stopped in between
+            Err(error) if error.current_context().is_db_not_found() => {
+                let created_before_expiry = payment.created_at
+                    + time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)
+                    > now;
+                if created_before_expiry {
+                    synced_payments.push(payment);
+                } else {
+                    release_payment(db, &payment.payment_id, reusable_link_id).await;
+                    synced_payments.push(storage::ReusablePaymentLinkPayment {
+                        status: enums::IntentStatus::Failed,
+                        released: true,
+                        ..payment
+                    });
+                }
+                continue;
+            }
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find reusable payment link payment")?,
+        };
+        let abandoned = payment_intent.status == enums::IntentStatus::RequiresPaymentMethod
+            && payment_intent
+                .session_expiry
+                .is_some_and(|session_expiry| session_expiry <= now);
+        let released = abandoned
+            || matches!(
+                payment_intent.status,
+                enums::IntentStatus::Failed | enums::IntentStatus::Cancelled
+            );
+        if payment_intent.status == payment.status && !released {
+            synced_payments.push(payment);
+            continue;
+        }
+
+        let updated_payment = db
+            .update_unreleased_reusable_payment_link_payment_by_payment_id(
+                &payment.payment_id,
+                storage::ReusablePaymentLinkPaymentUpdate::StatusUpdate {
+                    status: payment_intent.status,
+                    released,
+                },
+            )
+            .await;
+        match updated_payment {
+            Ok(updated_payment) => {
+                if released {
+                    let quantities = parse_item_quantities(&updated_payment)?;
+                    release(db, reusable_link_id, &quantities).await;
+                }
+                synced_payments.push(updated_payment);
+            }
+            // The payment was released by a concurrent sync
+            Err(error) if error.current_context().is_db_not_found() => {
+                synced_payments.push(storage::ReusablePaymentLinkPayment {
+                    status: payment_intent.status,
+                    released: true,
+                    ..payment
+                });
+            }
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update reusable payment link payment")?,
+        }
+    }
+
+    Ok(synced_payments)
+}
+
+/// Whether the status of the payment can no longer change in a way that affects the link
+fn is_final_status(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::Succeeded
+            | enums::IntentStatus::Failed
+            | enums::IntentStatus::Cancelled
+            | enums::IntentStatus::PartiallyCaptured
+    )
+}
+
+enum PaymentOutcome {
+    Succeeded,
+    Pending,
+    Failed,
+}
+
+impl PaymentOutcome {
+    fn from_payment(payment: &storage::ReusablePaymentLinkPayment) -> Self {
+        match payment.status {
+            _ if payment.released => Self::Failed,
+            enums::IntentStatus::Succeeded
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::PartiallyCapturedAndCapturable => Self::Succeeded,
+            enums::IntentStatus::Failed | enums::IntentStatus::Cancelled => Self::Failed,
+            enums::IntentStatus::Processing
+            | enums::IntentStatus::RequiresCustomerAction
+            | enums::IntentStatus::RequiresMerchantAction
+            | enums::IntentStatus::RequiresPaymentMethod
+            | enums::IntentStatus::RequiresConfirmation
+            | enums::IntentStatus::RequiresCapture => Self::Pending,
+        }
+    }
+}
+
+fn add_item_quantities(total: &mut ItemQuantities, quantities: ItemQuantities) {
+    for (item_id, quantity) in quantities {
+        let total_quantity = total.entry(item_id).or_default();
+        *total_quantity = total_quantity.saturating_add(quantity);
+    }
+}
+
+fn parse_item_quantities(
+    payment: &storage::ReusablePaymentLinkPayment,
+) -> RouterResult<ItemQuantities> {
+    payment
+        .item_quantities
+        .clone()
+        .parse_value("ItemQuantities")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse item quantities of reusable payment link payment")
+}
+
+fn parse_custom_amount(
+    reusable_payment_link: &storage::ReusablePaymentLink,
+) -> RouterResult<Option<ReusablePaymentLinkCustomAmount>> {
+    reusable_payment_link
+        .custom_amount
+        .clone()
+        .map(|custom_amount| custom_amount.parse_value("ReusablePaymentLinkCustomAmount"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse custom amount of reusable payment link")
+}
+
+fn build_reusable_payment_link_response(
+    reusable_payment_link: storage::ReusablePaymentLink,
+    items: Vec<storage::ReusablePaymentLinkItem>,
+) -> RouterResult<ReusablePaymentLinkResponse> {
+    let custom_amount = parse_custom_amount(&reusable_payment_link)?;
+    let line_items = items
+        .into_iter()
+        .map(|item| ReusablePaymentLinkLineItemResponse {
+            adjustable_quantity: item.min_quantity.zip(item.max_quantity).map(
+                |(minimum, maximum)| AdjustableQuantity {
+                    minimum: u16::try_from(minimum).unwrap_or_default(),
+                    maximum: u16::try_from(maximum).unwrap_or_default(),
+                },
+            ),
+            item_id: item.item_id,
+            name: item.name,
+            description: item.description,
+            image_url: item.image_url,
+            unit_amount: item.unit_amount,
+            quantity: u16::try_from(item.quantity).unwrap_or_default(),
+            inventory: item.inventory.map(to_u32),
+            quantity_sold: to_u32(item.quantity_sold),
+        })
+        .collect();
+
+    Ok(ReusablePaymentLinkResponse {
+        status: reusable_payment_link.get_effective_status(common_utils::date_time::now()),
+        reusable_link_id: reusable_payment_link.reusable_link_id,
+        merchant_id: reusable_payment_link.merchant_id,
+        profile_id: reusable_payment_link.profile_id,
+        link: reusable_payment_link.link_to_pay,
+        currency: reusable_payment_link.currency,
+        description: reusable_payment_link.description,
+        line_items,
+        custom_amount,
+        max_uses: reusable_payment_link.max_uses.map(to_u32),
+        times_used: to_u32(reusable_payment_link.times_used),
+        expires_at: reusable_payment_link.expires_at,
+        created_at: reusable_payment_link.created_at,
+    })
+}
+
+fn to_i32(value: u32) -> RouterResult<i32> {
+    i32::try_from(value).change_context(errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("{value} is too large"),
+    })
+}
+
+/// Converts a counter to `u32`, saturating at the bounds of `u32`
+fn to_u32<T>(value: T) -> u32
+where
+    T: TryInto<u32> + PartialOrd + Default,
+{
+    if value < T::default() {
+        0
+    } else {
+        value.try_into().unwrap_or(u32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use storage_impl::MockDb;
+
+    use super::*;
+
+    const REUSABLE_LINK_ID: &str = "rplink_test";
+
+    fn merchant_id() -> common_utils::id_type::MerchantId {
+        common_utils::id_type::MerchantId::try_from(Cow::from("merchant1")).unwrap()
+    }
+
+    fn reusable_payment_link(max_uses: Option<i32>) -> storage::ReusablePaymentLink {
+        let now = common_utils::date_time::now();
+        storage::ReusablePaymentLink {
+            reusable_link_id: REUSABLE_LINK_ID.to_string(),
+            merchant_id: merchant_id(),
+            profile_id: "pro_test".to_string(),
+            link_to_pay: format!(
+                "http://localhost:8080/payment_link/r/merchant1/{REUSABLE_LINK_ID}"
+            ),
+            status: enums::ReusablePaymentLinkStatus::Active,
+            currency: enums::Currency::USD,
+            description: None,
+            custom_amount: None,
+            max_uses,
+            times_used: 0,
+            payment_link_config: None,
+            expires_at: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    fn item(
+        item_id: &str,
+        unit_amount: i64,
+        adjustable_quantity: Option<(i32, i32)>,
+        inventory: Option<i64>,
+    ) -> storage::ReusablePaymentLinkItem {
+        storage::ReusablePaymentLinkItem {
+            reusable_link_id: REUSABLE_LINK_ID.to_string(),
+            item_id: item_id.to_string(),
+            name: item_id.to_string(),
+            description: None,
+            image_url: None,
+            unit_amount: MinorUnit::new(unit_amount),
+            quantity: 1,
+            min_quantity: adjustable_quantity.map(|(minimum, _)| minimum),
+            max_quantity: adjustable_quantity.map(|(_, maximum)| maximum),
+            inventory,
+            quantity_sold: 0,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    fn params(quantities: Option<&str>, amount: Option<i64>) -> ReusablePaymentLinkVisitParams {
+        ReusablePaymentLinkVisitParams {
+            quantities: quantities.map(str::to_string),
+            amount: amount.map(MinorUnit::new),
+        }
+    }
+
+    async fn mock_db(
+        reusable_payment_link: storage::ReusablePaymentLink,
+        items: Vec<storage::ReusablePaymentLinkItem>,
+    ) -> MockDb {
+        let mock_db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        mock_db
+            .reusable_payment_links
+            .lock()
+            .await
+            .push(reusable_payment_link);
+        mock_db
+            .reusable_payment_link_items
+            .lock()
+            .await
+            .extend(items);
+        mock_db
+    }
+
+    async fn times_used(mock_db: &MockDb) -> i32 {
+        mock_db.reusable_payment_links.lock().await[0].times_used
+    }
+
+    async fn quantities_sold(mock_db: &MockDb) -> Vec<i64> {
+        mock_db
+            .reusable_payment_link_items
+            .lock()
+            .await
+            .iter()
+            .map(|item| item.quantity_sold)
+            .collect()
+    }
+
+    #[test]
+    fn test_get_item_quantities() {
+        let items = vec![
+            item("tshirt", 2500, Some((0, 5)), None),
+            item("mug", 1000, None, None),
+        ];
+
+        let quantities = get_item_quantities(&items, &params(Some("tshirt:3"), None)).unwrap();
+        assert_eq!(quantities.get("tshirt"), Some(&3));
+        assert_eq!(quantities.get("mug"), Some(&1));
+
+        let quantities = get_item_quantities(&items, &params(None, None)).unwrap();
+        assert_eq!(quantities.get("tshirt"), Some(&1));
+
+        assert!(get_item_quantities(&items, &params(Some("tshirt:6"), None)).is_err());
+        assert!(get_item_quantities(&items, &params(Some("mug:2"), None)).is_err());
+        assert!(get_item_quantities(&items, &params(Some("hat:1"), None)).is_err());
+        assert!(get_item_quantities(&items, &params(Some("tshirt"), None)).is_err());
+    }
+
+    #[test]
+    fn test_get_payment_amount() {
+        let items = vec![item("tshirt", 2500, Some((0, 5)), None)];
+        let fixed_link = reusable_payment_link(None);
+        let quantities = get_item_quantities(&items, &params(Some("tshirt:2"), None)).unwrap();
+
+        assert_eq!(
+            get_payment_amount(&fixed_link, &items, &quantities, &params(None, None)).unwrap(),
+            MinorUnit::new(5000)
+        );
+        assert!(
+            get_payment_amount(&fixed_link, &items, &quantities, &params(None, Some(100))).is_err()
+        );
+
+        let donation_link = storage::ReusablePaymentLink {
+            custom_amount: Some(
+                ReusablePaymentLinkCustomAmount {
+                    minimum_amount: MinorUnit::new(500),
+                    maximum_amount: MinorUnit::new(10000),
+                    default_amount: None,
+                }
+                .encode_to_value()
+                .unwrap(),
+            ),
+            ..reusable_payment_link(None)
+        };
+        let no_items = ItemQuantities::new();
+        assert_eq!(
+            get_payment_amount(&donation_link, &[], &no_items, &params(None, Some(1000))).unwrap(),
+            MinorUnit::new(1000)
+        );
+        assert!(get_payment_amount(&donation_link, &[], &no_items, &params(None, None)).is_err());
+        assert!(
+            get_payment_amount(&donation_link, &[], &no_items, &params(None, Some(100))).is_err()
+        );
+        assert!(
+            get_payment_amount(&donation_link, &[], &no_items, &params(None, Some(20000))).is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_visit_does_not_reserve() {
+        let mock_db = mock_db(
+            reusable_payment_link(Some(1)),
+            vec![item("tshirt", 2500, Some((0, 5)), Some(2))],
+        )
+        .await;
+        let req = ReusablePaymentLinkVisitRequest {
+            merchant_id: merchant_id(),
+            reusable_link_id: REUSABLE_LINK_ID.to_string(),
+            params: params(Some("tshirt:2"), None),
+        };
+
+        for _ in 0..3 {
+            let (_, _, quantities, amount) = get_link_for_visit(&mock_db, &req).await.unwrap();
+            assert_eq!(quantities.get("tshirt"), Some(&2));
+            assert_eq!(amount, MinorUnit::new(5000));
+        }
+
+        assert_eq!(times_used(&mock_db).await, 0);
+        assert_eq!(quantities_sold(&mock_db).await, vec![0]);
+    }
+
+    #[tokio::test]
+    async fn test_reserve_and_release() {
+        let link = reusable_payment_link(Some(2));
+        let items = vec![
+            item("tshirt", 2500, Some((0, 5)), Some(3)),
+            item("mug", 1000, None, None),
+        ];
+        let mock_db = mock_db(link.clone(), items.clone()).await;
+        let quantities = ItemQuantities::from([("tshirt".to_string(), 2), ("mug".to_string(), 1)]);
+
+        assert_eq!(
+            reserve(&mock_db, &link, &items, &quantities).await.unwrap(),
+            Ok(())
+        );
+        assert_eq!(times_used(&mock_db).await, 1);
+        assert_eq!(quantities_sold(&mock_db).await, vec![2, 1]);
+
+        // The inventory of the t-shirt is exhausted, so the reservation of the use is returned
+        let error = reserve(&mock_db, &link, &items, &quantities)
+            .await
+            .unwrap()
+            .unwrap_err();
+        assert!(error.contains("sold out"));
+        assert_eq!(times_used(&mock_db).await, 1);
+        assert_eq!(quantities_sold(&mock_db).await, vec![2, 1]);
+
+        release(&mock_db, REUSABLE_LINK_ID, &quantities).await;
+        assert_eq!(times_used(&mock_db).await, 0);
+        assert_eq!(quantities_sold(&mock_db).await, vec![0, 0]);
+    }
+
+    #[tokio::test]
+    async fn test_reserve_respects_usage_limit() {
+        let link = reusable_payment_link(Some(1));
+        let items = vec![item("mug", 1000, None, None)];
+        let mock_db = mock_db(link.clone(), items.clone()).await;
+        let quantities = ItemQuantities::from([("mug".to_string(), 1)]);
+
+        assert!(reserve(&mock_db, &link, &items, &quantities)
+            .await
+            .unwrap()
+            .is_ok());
+        assert!(reserve(&mock_db, &link, &items, &quantities)
+            .await
+            .unwrap()
+            .is_err());
+        assert_eq!(times_used(&mock_db).await, 1);
+        assert_eq!(quantities_sold(&mock_db).await, vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_release_payment_releases_once() {
+        let link = reusable_payment_link(None);
+        let items = vec![item("mug", 1000, None, Some(5))];
+        let mock_db = mock_db(link.clone(), items.clone()).await;
+        let quantities = ItemQuantities::from([("mug".to_string(), 2)]);
+        reserve(&mock_db, &link, &items, &quantities)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let now = common_utils::date_time::now();
+        mock_db
+            .insert_reusable_payment_link_payment(storage::ReusablePaymentLinkPaymentNew {
+                payment_id: "pay_test".to_string(),
+                reusable_link_id: REUSABLE_LINK_ID.to_string(),
+                merchant_id: merchant_id(),
+                amount: MinorUnit::new(2000),
+                currency: enums::Currency::USD,
+                item_quantities: quantities.encode_to_value().unwrap(),
+                status: enums::IntentStatus::RequiresPaymentMethod,
+                released: false,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .unwrap();
+
+        release_payment(&mock_db, "pay_test", REUSABLE_LINK_ID).await;
+        release_payment(&mock_db, "pay_test", REUSABLE_LINK_ID).await;
+
+        assert_eq!(times_used(&mock_db).await, 0);
+        assert_eq!(quantities_sold(&mock_db).await, vec![0]);
+        let payment = mock_db.reusable_payment_link_payments.lock().await[0].clone();
+        assert!(payment.released);
+        assert_eq!(payment.status, enums::IntentStatus::Failed);
+    }
+}
@@ -8,6 +8,9 @@ pub mod operations;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+pub mod sca_exemption;
+pub mod split_payments;
+pub mod tax_calculation;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -253,6 +256,17 @@ where
             should_continue_capture,
         );
 
+        if is_operation_confirm(&operation) {
+            sca_exemption::apply_sca_exemption_if_eligible(
+                state,
+                &merchant_account,
+                &mut payment_data,
+                &connector_details,
+                &business_profile,
+            )
+            .await?;
+        }
+
         operation
             .to_domain()?
             .call_external_three_ds_authentication_if_eligible(
@@ -421,7 +435,14 @@ where
                             retry::config_should_call_gsm(&*state.store, merchant_account.get_id())
                                 .await;
 
-                        if config_bool && router_data.should_call_gsm() {
+                        // Exempted payments are always checked for a soft decline to fall back to
+                        // a challenge, the other gsm actions are taken only if enabled
+                        let is_sca_exemption_applied =
+                            payment_data.payment_attempt.sca_exemption.is_some();
+
+                        if (config_bool || is_sca_exemption_applied)
+                            && router_data.should_call_gsm()
+                        {
                             router_data = retry::do_gsm_actions(
                                 state,
                                 req_state.clone(),
@@ -440,6 +461,7 @@ where
                                 #[cfg(not(feature = "frm"))]
                                 None,
                                 &business_profile,
+                                config_bool,
                             )
                             .await?;
                         };
@@ -4341,6 +4363,7 @@ pub async fn payment_external_authentication(
         optional_customer.and_then(|customer| customer.email.map(pii::Email::from)),
         webhook_url,
         authentication_details.three_ds_requestor_url.clone(),
+        payment_attempt.sca_exemption,
     ))
     .await?;
     Ok(services::ApplicationResponse::Json(
@@ -41,6 +41,9 @@ mod dummy_connector_default_impl {
     impl<const T: u8> api::PaymentsPreProcessingV2 for connector::DummyConnector<T> {}
 
     impl<const T: u8> api::PaymentsPostProcessingV2 for connector::DummyConnector<T> {}
+    impl<const T: u8> api::PaymentsCalculateTaxV2 for connector::DummyConnector<T> {}
+    impl<const T: u8> api::PaymentsCommitTaxV2 for connector::DummyConnector<T> {}
+    impl<const T: u8> api::PaymentsReverseTaxV2 for connector::DummyConnector<T> {}
 
     impl<const T: u8>
         services::ConnectorIntegrationV2<
@@ -182,6 +185,36 @@ mod dummy_connector_default_impl {
     {
     }
 
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::CalculateTax,
+            types::PaymentFlowData,
+            types::PaymentsTaxCalculationData,
+            types::TaxCalculationResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
+
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::CommitTax,
+            types::PaymentFlowData,
+            types::TaxCommitData,
+            types::TaxTransactionResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
+
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::ReverseTax,
+            types::PaymentFlowData,
+            types::TaxReversalData,
+            types::TaxTransactionResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
+
     impl<const T: u8>
         services::ConnectorIntegrationV2<
             api::AuthorizeSessionToken,
@@ -557,6 +590,9 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             impl api::ConnectorCustomerV2 for $path::$connector{}
             impl api::PaymentsPreProcessingV2 for $path::$connector{}
             impl api::PaymentsPostProcessingV2 for $path::$connector{}
+            impl api::PaymentsCalculateTaxV2 for $path::$connector{}
+            impl api::PaymentsCommitTaxV2 for $path::$connector{}
+            impl api::PaymentsReverseTaxV2 for $path::$connector{}
             impl
             services::ConnectorIntegrationV2<api::Authorize,types::PaymentFlowData, types::PaymentsAuthorizeData, types::PaymentsResponseData>
             for $path::$connector{}
@@ -622,6 +658,24 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 types::PaymentsPostProcessingData,
                 types::PaymentsResponseData,
             > for $path::$connector{}
+            impl services::ConnectorIntegrationV2<
+            api::CalculateTax,
+            types::PaymentFlowData,
+                types::PaymentsTaxCalculationData,
+                types::TaxCalculationResponseData,
+            > for $path::$connector{}
+            impl services::ConnectorIntegrationV2<
+            api::CommitTax,
+            types::PaymentFlowData,
+                types::TaxCommitData,
+                types::TaxTransactionResponseData,
+            > for $path::$connector{}
+            impl services::ConnectorIntegrationV2<
+            api::ReverseTax,
+            types::PaymentFlowData,
+                types::TaxReversalData,
+                types::TaxTransactionResponseData,
+            > for $path::$connector{}
             impl
             services::ConnectorIntegrationV2<
                 api::AuthorizeSessionToken,
@@ -2923,6 +2923,138 @@ default_imp_for_revoking_mandates!(
     connector::Zsl
 );
 
+macro_rules! default_imp_for_tax_calculation {
+    ($($path:ident::$connector:ident),*) => {
+        $( impl api::PaymentsCalculateTax for $path::$connector {}
+            impl api::PaymentsCommitTax for $path::$connector {}
+            impl api::PaymentsReverseTax for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::CalculateTax,
+            types::PaymentsTaxCalculationData,
+            types::TaxCalculationResponseData,
+        > for $path::$connector
+        {}
+        impl
+            services::ConnectorIntegration<
+            api::CommitTax,
+            types::TaxCommitData,
+            types::TaxTransactionResponseData,
+        > for $path::$connector
+        {}
+        impl
+            services::ConnectorIntegration<
+            api::ReverseTax,
+            types::TaxReversalData,
+            types::TaxTransactionResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PaymentsCalculateTax for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PaymentsCommitTax for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PaymentsReverseTax for connector::DummyConnector<T> {}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::CalculateTax,
+        types::PaymentsTaxCalculationData,
+        types::TaxCalculationResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::CommitTax,
+        types::TaxCommitData,
+        types::TaxTransactionResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::ReverseTax,
+        types::TaxReversalData,
+        types::TaxTransactionResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+default_imp_for_tax_calculation!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Adyenplatform,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bamboraapac,
+    connector::Bankofamerica,
+    connector::Billwerk,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Cashtocode,
+    connector::Checkout,
+    connector::Cryptopay,
+    connector::Coinbase,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Dlocal,
+    connector::Ebanx,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Globepay,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Netcetera,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payeezy,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Payu,
+    connector::Placetopay,
+    connector::Powertranz,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Razorpay,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Square,
+    connector::Stripe,
+    connector::Shift4,
+    connector::Trustpay,
+    connector::Threedsecureio,
+    connector::Tsys,
+    connector::Volt,
+    connector::Wellsfargo,
+    connector::Wellsfargopayout,
+    connector::Wise,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen,
+    connector::Zsl,
+    connector::Plaid
+);
+
 macro_rules! default_imp_for_connector_authentication {
     ($($path:ident::$connector:ident),*) => {
         $( impl api::ExternalAuthentication for $path::$connector {}
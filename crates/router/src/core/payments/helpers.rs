@@ -3090,6 +3090,9 @@ mod tests {
             merchant_order_reference_id: None,
             shipping_details: None,
             is_payment_processor_token_flow: None,
+            tax_details: None,
+            l2_l3_data: None,
+            split_payments: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3155,6 +3158,9 @@ mod tests {
             merchant_order_reference_id: None,
             shipping_details: None,
             is_payment_processor_token_flow: None,
+            tax_details: None,
+            l2_l3_data: None,
+            split_payments: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3218,10 +3224,105 @@ mod tests {
             merchant_order_reference_id: None,
             shipping_details: None,
             is_payment_processor_token_flow: None,
+            tax_details: None,
+            l2_l3_data: None,
+            split_payments: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    fn l2_l3_line_item(quantity: u16, unit_amount: i64) -> api_models::payments::L2L3LineItem {
+        api_models::payments::L2L3LineItem {
+            product_name: "Copy paper".to_string(),
+            product_code: Some("CP-500".to_string()),
+            commodity_code: None,
+            quantity,
+            unit_of_measure: Some("box".to_string()),
+            unit_amount: MinorUnit::new(unit_amount),
+            tax_amount: None,
+            discount_amount: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_l2_l3_data() {
+        let l2_l3_data = api_models::payments::L2L3Data {
+            tax_amount: Some(MinorUnit::new(100)),
+            customer_reference: Some("CUSTREF123".to_string()),
+            line_items: Some(vec![l2_l3_line_item(2, 500)]),
+            ..Default::default()
+        };
+        assert!(validate_l2_l3_data(&l2_l3_data, Some(MinorUnit::new(1000))).is_ok());
+        assert!(validate_l2_l3_data(&l2_l3_data, None).is_ok());
+
+        let invalid_l2_l3_data = [
+            // The tax is not less than the amount
+            (l2_l3_data.clone(), Some(MinorUnit::new(100))),
+            (
+                api_models::payments::L2L3Data {
+                    tax_exempt: Some(true),
+                    ..l2_l3_data.clone()
+                },
+                None,
+            ),
+            (
+                api_models::payments::L2L3Data {
+                    freight_amount: Some(MinorUnit::new(-1)),
+                    ..l2_l3_data.clone()
+                },
+                None,
+            ),
+            (
+                api_models::payments::L2L3Data {
+                    customer_reference: Some("C".repeat(18)),
+                    ..l2_l3_data.clone()
+                },
+                None,
+            ),
+            (
+                api_models::payments::L2L3Data {
+                    line_items: Some(Vec::new()),
+                    ..l2_l3_data.clone()
+                },
+                None,
+            ),
+            (
+                api_models::payments::L2L3Data {
+                    line_items: Some(vec![l2_l3_line_item(0, 500)]),
+                    ..l2_l3_data.clone()
+                },
+                None,
+            ),
+            (
+                api_models::payments::L2L3Data {
+                    line_items: Some(vec![l2_l3_line_item(2, i64::MAX)]),
+                    ..l2_l3_data.clone()
+                },
+                None,
+            ),
+        ];
+        for (l2_l3_data, amount) in invalid_l2_l3_data {
+            assert!(
+                validate_l2_l3_data(&l2_l3_data, amount).is_err(),
+                "{l2_l3_data:?} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_l2_l3_data_for_amount_checks_the_stored_data() {
+        let stored_l2_l3_data = serde_json::json!({ "tax_amount": 500 });
+
+        assert!(validate_l2_l3_data_for_amount(None, MinorUnit::new(100)).is_ok());
+        assert!(
+            validate_l2_l3_data_for_amount(Some(&stored_l2_l3_data), MinorUnit::new(1000)).is_ok()
+        );
+        // The amount was updated below the stored tax
+        assert!(
+            validate_l2_l3_data_for_amount(Some(&stored_l2_l3_data), MinorUnit::new(400)).is_err()
+        );
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -3752,6 +3853,7 @@ impl AttemptType {
             client_source: old_payment_attempt.client_source,
             client_version: old_payment_attempt.client_version,
             customer_acceptance: old_payment_attempt.customer_acceptance,
+            sca_exemption: old_payment_attempt.sca_exemption,
         }
     }
 
@@ -3955,10 +4057,12 @@ pub async fn get_additional_payment_data(
                     },
                 )))
             } else {
-                let card_info = card_isin
+                // The lookup falls back to the 6 digit BIN when the 8 digit one is not known
+                let card_info = card_extended_bin
                     .clone()
-                    .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
+                    .or_else(|| card_isin.clone())
+                    .async_and_then(|card_bin| async move {
+                        db.get_card_info(&card_bin)
                             .await
                             .map_err(|error| services::logger::warn!(card_info_error=?error))
                             .ok()
@@ -4736,6 +4840,121 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+// This function validates the Level 2 and Level 3 data against the limits of the card networks
+pub fn validate_l2_l3_data(
+    l2_l3_data: &api_models::payments::L2L3Data,
+    amount: Option<MinorUnit>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let invalid_request_data = |message: &str| errors::ApiErrorResponse::InvalidRequestData {
+        message: message.to_string(),
+    };
+    let exceeds_length = |value: &Option<String>, max_length: usize| {
+        value
+            .as_ref()
+            .is_some_and(|value| value.chars().count() > max_length)
+    };
+    let is_negative =
+        |value: Option<MinorUnit>| value.is_some_and(|value| value < MinorUnit::zero());
+
+    if [
+        l2_l3_data.tax_amount,
+        l2_l3_data.duty_amount,
+        l2_l3_data.freight_amount,
+        l2_l3_data.discount_amount,
+    ]
+    .into_iter()
+    .any(is_negative)
+    {
+        return Err(invalid_request_data(
+            "amounts in l2_l3_data should not be negative",
+        ));
+    }
+    if l2_l3_data.tax_exempt == Some(true)
+        && l2_l3_data
+            .tax_amount
+            .is_some_and(|tax_amount| tax_amount > MinorUnit::zero())
+    {
+        return Err(invalid_request_data(
+            "l2_l3_data.tax_amount should be zero when l2_l3_data.tax_exempt is true",
+        ));
+    }
+    if let Some((tax_amount, amount)) = l2_l3_data.tax_amount.zip(amount) {
+        if tax_amount >= amount && amount > MinorUnit::zero() {
+            return Err(invalid_request_data(
+                "l2_l3_data.tax_amount should be less than the amount of the payment",
+            ));
+        }
+    }
+    if exceeds_length(&l2_l3_data.customer_reference, 17) {
+        return Err(invalid_request_data(
+            "l2_l3_data.customer_reference should not be more than 17 characters",
+        ));
+    }
+    if exceeds_length(&l2_l3_data.purchase_order_number, 25) {
+        return Err(invalid_request_data(
+            "l2_l3_data.purchase_order_number should not be more than 25 characters",
+        ));
+    }
+
+    if let Some(line_items) = &l2_l3_data.line_items {
+        if line_items.is_empty() {
+            return Err(invalid_request_data(
+                "l2_l3_data.line_items should not be empty",
+            ));
+        }
+        for line_item in line_items {
+            if line_item.quantity == 0 {
+                return Err(invalid_request_data(
+                    "l2_l3_data.line_items[].quantity should be greater than zero",
+                ));
+            }
+            if is_negative(Some(line_item.unit_amount))
+                || is_negative(line_item.tax_amount)
+                || is_negative(line_item.discount_amount)
+            {
+                return Err(invalid_request_data(
+                    "amounts in l2_l3_data.line_items should not be negative",
+                ));
+            }
+            if exceeds_length(&line_item.product_code, 12)
+                || exceeds_length(&line_item.commodity_code, 12)
+                || exceeds_length(&line_item.unit_of_measure, 12)
+            {
+                return Err(invalid_request_data(
+                    "product_code, commodity_code and unit_of_measure of l2_l3_data.line_items should not be more than 12 characters",
+                ));
+            }
+            if line_item.get_total_amount().is_none() {
+                return Err(invalid_request_data(
+                    "total amount of a line item in l2_l3_data.line_items is too large",
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the Level 2 and Level 3 data of the payment intent, which may have been provided by
+/// an earlier request, against the amount the payment is processed for
+pub fn validate_l2_l3_data_for_amount(
+    l2_l3_data: Option<&serde_json::Value>,
+    amount: MinorUnit,
+) -> RouterResult<()> {
+    let Some(l2_l3_data) = l2_l3_data else {
+        return Ok(());
+    };
+    let l2_l3_data = l2_l3_data
+        .clone()
+        .parse_value::<api_models::payments::L2L3Data>("L2L3Data")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "l2_l3_data",
+        })
+        .attach_printable("Unable to parse L2L3Data")?;
+
+    validate_l2_l3_data(&l2_l3_data, Some(amount)).map_err(|error| report!(error))
+}
+
 pub fn get_recipient_id_for_open_banking(
     merchant_data: &AdditionalMerchantData,
 ) -> Result<Option<String>, errors::ApiErrorResponse> {
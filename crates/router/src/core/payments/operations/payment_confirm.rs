@@ -10,7 +10,10 @@ use api_models::{
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use api_models::{payment_methods::PaymentMethodsData, payments::AdditionalPaymentData};
 use async_trait::async_trait;
-use common_utils::ext_traits::{AsyncExt, Encode, StringExt, ValueExt};
+use common_utils::{
+    ext_traits::{AsyncExt, Encode, StringExt, ValueExt},
+    types::MinorUnit,
+};
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use common_utils::{type_name, types::keymanager::Identifier};
 use error_stack::{report, ResultExt};
@@ -36,8 +39,8 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payments::{
-            self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
-            PaymentData,
+            self, helpers, operations, populate_surcharge_details, split_payments, CustomerDetails,
+            PaymentAddress, PaymentData,
         },
         utils as core_utils,
     },
@@ -339,12 +342,25 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 }
             };
 
+        split_payments::validate_amount_update(&payment_intent, request.amount)?;
+
         payment_intent.order_details = request
             .get_order_details_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to convert order details to value")?
             .or(payment_intent.order_details);
 
+        payment_intent.l2_l3_data = request
+            .get_l2_l3_data_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert l2_l3_data to Value")?
+            .or(payment_intent.l2_l3_data);
+
+        helpers::validate_l2_l3_data_for_amount(
+            payment_intent.l2_l3_data.as_ref(),
+            payment_intent.amount,
+        )?;
+
         payment_intent.setup_future_usage = request
             .setup_future_usage
             .or(payment_intent.setup_future_usage);
@@ -398,6 +414,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .map(masking::Secret::new)
             .or(payment_attempt.customer_acceptance);
 
+        payment_attempt.sca_exemption = request.sca_exemption.or(payment_attempt.sca_exemption);
+
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
 
@@ -1282,6 +1300,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             None => (None, None, None),
         };
 
+        let m_sca_exemption = payment_data.payment_attempt.sca_exemption;
+
         let payment_attempt_fut = tokio::spawn(
             async move {
                 m_db.update_payment_attempt_with_attempt_id(
@@ -1317,6 +1337,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         client_source,
                         client_version,
                         customer_acceptance: payment_data.payment_attempt.customer_acceptance,
+                        sca_exemption: m_sca_exemption,
                     },
                     storage_scheme,
                 )
@@ -1361,6 +1382,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let key_manager_state = state.into();
         let is_payment_processor_token_flow =
             payment_data.payment_intent.is_payment_processor_token_flow;
+        let l2_l3_data = payment_data.payment_intent.l2_l3_data.clone();
 
         let payment_intent_fut = tokio::spawn(
             async move {
@@ -1394,6 +1416,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         billing_details,
                         shipping_details,
                         is_payment_processor_token_flow,
+                        tax_details: None,
+                        l2_l3_data,
                     })),
                     &m_key_store,
                     storage_scheme,
@@ -1480,6 +1504,10 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
         if let Some(amount) = request.amount {
             helpers::validate_max_amount(amount)?;
         }
+        if let Some(l2_l3_data) = &request.l2_l3_data {
+            helpers::validate_l2_l3_data(l2_l3_data, request.amount.map(MinorUnit::from))?;
+        }
+        split_payments::validate_split_payments_request(request, false)?;
 
         let request_merchant_id = request.merchant_id.as_ref();
         helpers::validate_merchant_id(merchant_account.get_id(), request_merchant_id)
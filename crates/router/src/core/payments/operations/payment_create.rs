@@ -30,7 +30,10 @@ use crate::{
         mandate::helpers as m_helpers,
         payment_link,
         payment_methods::cards::create_encrypted_data,
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, helpers, operations, split_payments, tax_calculation, CustomerDetails,
+            PaymentAddress, PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
@@ -285,7 +288,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             _ => None,
         };
 
-        let payment_intent_new = Self::make_payment_intent(
+        let mut payment_intent_new = Self::make_payment_intent(
             state,
             &payment_id,
             merchant_account,
@@ -305,6 +308,23 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         )
         .await?;
 
+        // The payment is created without tax when the tax connector is unavailable
+        payment_intent_new.tax_details = tax_calculation::calculate_tax_details(
+            state,
+            merchant_key_store,
+            &payment_intent_new,
+            currency,
+            shipping_address
+                .as_ref()
+                .map(api_models::payments::Address::from)
+                .as_ref(),
+        )
+        .await
+        .unwrap_or_else(|error| {
+            logger::error!(?error, "Failed to calculate the tax of the payment");
+            None
+        });
+
         let (payment_attempt_new, additional_payment_data) = Self::make_payment_attempt(
             &payment_id,
             merchant_id,
@@ -778,6 +798,10 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
         if let Some(amount) = request.amount {
             helpers::validate_max_amount(amount)?;
         }
+        if let Some(l2_l3_data) = &request.l2_l3_data {
+            helpers::validate_l2_l3_data(l2_l3_data, request.amount.map(MinorUnit::from))?;
+        }
+        split_payments::validate_split_payments_request(request, true)?;
         if let Some(session_expiry) = &request.session_expiry {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
@@ -1065,6 +1089,7 @@ impl PaymentCreate {
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Failed to serialize customer_acceptance")?
                     .map(Secret::new),
+                sca_exemption: request.sca_exemption,
             },
             additional_pm_data,
         ))
@@ -1111,6 +1136,26 @@ impl PaymentCreate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error converting allowed_payment_types to Value")?;
 
+        let l2_l3_data = request
+            .get_l2_l3_data_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert l2_l3_data to Value")?;
+
+        let split_payments = request
+            .split_payments
+            .as_ref()
+            .map(|split_payments_request| {
+                split_payments::get_split_payments_details(
+                    split_payments_request,
+                    MinorUnit::from(amount),
+                )
+            })
+            .transpose()?
+            .map(|split_payments_details| split_payments_details.encode_to_value())
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize split payments")?;
+
         let connector_metadata = request
             .get_connector_metadata_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1246,6 +1291,9 @@ impl PaymentCreate {
             merchant_order_reference_id: request.merchant_order_reference_id.clone(),
             shipping_details,
             is_payment_processor_token_flow,
+            tax_details: None,
+            l2_l3_data,
+            split_payments,
         })
     }
 
@@ -19,13 +19,13 @@ use crate::{
     connector::utils::PaymentResponseRouterData,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        mandate, payment_methods,
+        invoices, ledger, mandate, payment_methods,
         payments::{
             helpers::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
             },
-            tokenization,
+            tax_calculation, tokenization,
             types::MultipleCaptureData,
             PaymentData,
         },
@@ -1354,6 +1354,59 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
+
+    // The tax calculated on the order is recorded with the tax connector once the payment is captured
+    if matches!(
+        payment_data.payment_intent.status,
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+    ) && payment_data.payment_intent.tax_details.is_some()
+    {
+        payment_data.payment_intent = tax_calculation::commit_tax_transaction_or_schedule_retry(
+            state,
+            key_store,
+            storage_scheme,
+            payment_data.payment_intent.clone(),
+        )
+        .await;
+    }
+
+    // Payments created for an invoice are credited to the invoice once they succeed
+    if matches!(
+        payment_data.payment_intent.status,
+        enums::IntentStatus::Succeeded
+            | enums::IntentStatus::Failed
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::RequiresMerchantAction
+    ) && invoices::get_invoice_id_from_payment_metadata(
+        payment_data.payment_intent.metadata.as_ref(),
+    )
+    .is_some()
+    {
+        if let Err(error) = invoices::add_invoice_payment_task(
+            state,
+            &payment_data.payment_intent.merchant_id,
+            &payment_data.payment_intent.payment_id,
+            payment_data.payment_intent.status,
+        )
+        .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to schedule the update of the invoice payment"
+            );
+        }
+    }
+
+    if let Err(error) = ledger::record_payment_attempt_entries(
+        state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to record the ledger entries of the payment");
+    }
+
     router_data.payment_method_status.and_then(|status| {
         payment_data
             .payment_method_info
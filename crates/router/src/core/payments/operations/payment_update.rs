@@ -7,13 +7,14 @@ use async_trait::async_trait;
 use common_utils::{
     ext_traits::{AsyncExt, Encode, ValueExt},
     pii::Email,
+    types::MinorUnit,
 };
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::payments::payment_intent::{
     CustomerData, PaymentIntentUpdateFields,
 };
 use router_derive::PaymentOperation;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
@@ -21,7 +22,10 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payment_methods::cards::create_encrypted_data,
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, helpers, operations, split_payments, tax_calculation, CustomerDetails,
+            PaymentAddress, PaymentData,
+        },
         utils as core_utils,
     },
     routes::{app::ReqState, SessionState},
@@ -106,12 +110,20 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
 
+        split_payments::validate_amount_update(&payment_intent, request.amount)?;
+
         payment_intent.order_details = request
             .get_order_details_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to convert order details to value")?
             .or(payment_intent.order_details);
 
+        payment_intent.l2_l3_data = request
+            .get_l2_l3_data_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert l2_l3_data to Value")?
+            .or(payment_intent.l2_l3_data);
+
         payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
                 payment_intent.payment_id.as_str(),
@@ -176,12 +188,19 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         payment_attempt.payment_method = payment_method.or(payment_attempt.payment_method);
         payment_attempt.payment_method_type =
             payment_method_type.or(payment_attempt.payment_method_type);
+        payment_attempt.sca_exemption = request.sca_exemption.or(payment_attempt.sca_exemption);
         let customer_details = helpers::get_customer_details_from_request(request);
 
         let amount = request
             .amount
             .unwrap_or_else(|| payment_attempt.amount.into());
 
+        // The amount or the Level 2 and Level 3 data may have been updated on their own
+        helpers::validate_l2_l3_data_for_amount(
+            payment_intent.l2_l3_data.as_ref(),
+            MinorUnit::from(amount),
+        )?;
+
         if request.confirm.unwrap_or(false) {
             helpers::validate_customer_id_mandatory_cases(
                 request.setup_future_usage.is_some(),
@@ -241,6 +260,29 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         payment_intent.shipping_address_id = shipping_address.clone().map(|x| x.address_id);
         payment_intent.billing_address_id = billing_address.clone().map(|x| x.address_id);
+
+        if request.shipping.is_some() || request.order_details.is_some() {
+            // The tax calculated for the previous order is dropped when the tax connector is
+            // unavailable, rather than failing the update or committing a stale tax
+            payment_intent.tax_details = match tax_calculation::calculate_tax_details(
+                state,
+                key_store,
+                &payment_intent,
+                currency,
+                shipping_address
+                    .as_ref()
+                    .map(api_models::payments::Address::from)
+                    .as_ref(),
+            )
+            .await
+            {
+                Ok(tax_details) => tax_details.or(payment_intent.tax_details),
+                Err(error) => {
+                    logger::error!(?error, "Failed to calculate the tax of the payment");
+                    None
+                }
+            };
+        }
         payment_attempt.payment_method_billing_address_id = payment_method_billing
             .as_ref()
             .map(|payment_method_billing| payment_method_billing.address_id.clone());
@@ -635,6 +677,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let payment_experience = payment_data.payment_attempt.payment_experience;
         let amount_to_capture = payment_data.payment_attempt.amount_to_capture;
         let capture_method = payment_data.payment_attempt.capture_method;
+        let sca_exemption = payment_data.payment_attempt.sca_exemption;
         let payment_method_billing_address_id = payment_data
             .payment_attempt
             .payment_method_billing_address_id
@@ -670,6 +713,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     fingerprint_id: None,
                     payment_method_billing_address_id,
                     updated_by: storage_scheme.to_string(),
+                    sca_exemption,
                 },
                 storage_scheme,
             )
@@ -772,6 +816,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     billing_details,
                     shipping_details,
                     is_payment_processor_token_flow: None,
+                    tax_details: payment_data.payment_intent.tax_details.clone(),
+                    l2_l3_data: payment_data.payment_intent.l2_l3_data.clone(),
                 })),
                 key_store,
                 storage_scheme,
@@ -813,6 +859,10 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentUpdate
         if let Some(amount) = request.amount {
             helpers::validate_max_amount(amount)?;
         }
+        if let Some(l2_l3_data) = &request.l2_l3_data {
+            helpers::validate_l2_l3_data(l2_l3_data, request.amount.map(MinorUnit::from))?;
+        }
+        split_payments::validate_split_payments_request(request, false)?;
         if let Some(session_expiry) = &request.session_expiry {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
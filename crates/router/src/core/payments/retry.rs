@@ -45,6 +45,7 @@ pub async fn do_gsm_actions<F, ApiRequest, FData>(
     schedule_time: Option<time::PrimitiveDateTime>,
     frm_suggestion: Option<storage_enums::FrmSuggestion>,
     business_profile: &domain::BusinessProfile,
+    is_auto_retry_enabled: bool,
 ) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
     F: Clone + Send + Sync,
@@ -70,17 +71,30 @@ where
         payment_data.payment_attempt.authentication_type,
         Some(storage_enums::AuthenticationType::NoThreeDs)
     );
+    // A soft decline of an exempted payment is always stepped up to a challenge, even when auto
+    // retries are not enabled for the merchant
+    let is_sca_exemption_applied = payment_data.payment_attempt.sca_exemption.is_some();
     let should_step_up = if step_up_possible && is_no_three_ds_payment {
-        is_step_up_enabled_for_merchant_connector(
-            state,
-            merchant_account.get_id(),
-            original_connector_data.connector_name,
-        )
-        .await
+        is_sca_exemption_applied
+            || (is_auto_retry_enabled
+                && is_step_up_enabled_for_merchant_connector(
+                    state,
+                    merchant_account.get_id(),
+                    original_connector_data.connector_name,
+                )
+                .await)
     } else {
         false
     };
 
+    if should_step_up && is_sca_exemption_applied {
+        metrics::SCA_EXEMPTION_CHALLENGE_FALLBACK_COUNT.add(&metrics::CONTEXT, 1, &[]);
+        logger::info!(
+            sca_exemption = ?payment_data.payment_attempt.sca_exemption,
+            "sca exemption was declined, falling back to challenge"
+        );
+    }
+
     if should_step_up {
         router_data = do_retry(
             &state.clone(),
@@ -101,7 +115,7 @@ where
         .await?;
     }
     // Step up is not applicable so proceed with auto retries flow
-    else {
+    else if is_auto_retry_enabled {
         loop {
             // Use initial_gsm for first time alone
             let gsm = match initial_gsm.as_ref() {
@@ -528,6 +542,12 @@ pub fn make_new_payment_attempt(
         payment_token: old_payment_attempt.payment_token,
         client_source: old_payment_attempt.client_source,
         client_version: old_payment_attempt.client_version,
+        // The exemption is dropped when stepping up, as the retry is authenticated with a challenge
+        sca_exemption: if is_step_up {
+            None
+        } else {
+            old_payment_attempt.sca_exemption
+        },
         created_at,
         modified_at,
         last_synced,
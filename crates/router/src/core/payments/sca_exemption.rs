@@ -0,0 +1,487 @@
+use api_models::payments::{
+    ScaExemptionConfig, TransactionRiskAnalysisExemptionConfig, TransactionRiskAnalysisThreshold,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    types::MinorUnit,
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use super::PaymentData;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::{metrics, SessionState},
+    services,
+    types::{api, domain, storage::enums as storage_enums},
+};
+
+/// Details of a payment considered while deciding its SCA exemption
+#[derive(Debug, Clone)]
+pub struct ScaExemptionEligibility<'a> {
+    pub requested_exemption: Option<storage_enums::ScaExemptionType>,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub is_merchant_initiated: bool,
+    pub fraud_score: Option<i32>,
+    pub card_issuer: Option<&'a str>,
+}
+
+async fn find_sca_exemption_config(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<Option<ScaExemptionConfig>> {
+    match state
+        .store
+        .find_config_by_key(&merchant_id.get_sca_exemption_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("ScaExemptionConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse sca exemption config")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch sca exemption config")),
+    }
+}
+
+fn validate_config(config: &ScaExemptionConfig) -> RouterResult<()> {
+    if config
+        .low_value
+        .as_ref()
+        .is_some_and(|low_value| low_value.max_amount <= MinorUnit::zero())
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Low value exemption amount must be greater than zero".to_string(),
+        })?
+    }
+    if let Some(transaction_risk_analysis) = config.transaction_risk_analysis.as_ref() {
+        if transaction_risk_analysis.max_fraud_score < 0 {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Maximum fraud score cannot be negative".to_string(),
+            })?
+        }
+        if let Some(thresholds) = transaction_risk_analysis.thresholds.as_ref() {
+            if thresholds.is_empty() {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Transaction risk analysis thresholds cannot be empty".to_string(),
+                })?
+            }
+            if thresholds
+                .iter()
+                .any(|threshold| threshold.max_amount <= MinorUnit::zero())
+            {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Transaction risk analysis amounts must be greater than zero"
+                        .to_string(),
+                })?
+            }
+        }
+    }
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_sca_exemption_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    config: ScaExemptionConfig,
+) -> RouterResponse<ScaExemptionConfig> {
+    validate_config(&config)?;
+
+    let db = state.store.as_ref();
+    let key = merchant_account.get_id().get_sca_exemption_config_key();
+    let config_value = config
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize sca exemption config")?;
+
+    match find_sca_exemption_config(&state, merchant_account.get_id()).await? {
+        Some(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config_value),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update sca exemption config")?,
+        None => db
+            .insert_config(configs::ConfigNew {
+                key,
+                config: config_value,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert sca exemption config")?,
+    };
+
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_sca_exemption_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<ScaExemptionConfig> {
+    let config = find_sca_exemption_config(&state, merchant_account.get_id())
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "SCA exemption config not found".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+fn get_default_tra_thresholds() -> Vec<TransactionRiskAnalysisThreshold> {
+    consts::DEFAULT_SCA_TRA_THRESHOLDS
+        .iter()
+        .map(
+            |(max_fraud_rate_bps, max_amount)| TransactionRiskAnalysisThreshold {
+                max_fraud_rate_bps: *max_fraud_rate_bps,
+                max_amount: MinorUnit::new(*max_amount),
+            },
+        )
+        .collect()
+}
+
+/// Highest amount up to which the transaction risk analysis exemption applies, based on the
+/// higher of the acquirer and the card issuer fraud rate
+fn get_tra_max_amount(
+    config: &TransactionRiskAnalysisExemptionConfig,
+    card_issuer: Option<&str>,
+) -> Option<MinorUnit> {
+    let issuer_fraud_rate_bps = card_issuer.and_then(|card_issuer| {
+        config
+            .issuer_fraud_rates_bps
+            .iter()
+            .find(|(issuer, _)| issuer.eq_ignore_ascii_case(card_issuer))
+            .map(|(_, fraud_rate_bps)| *fraud_rate_bps)
+    });
+    let fraud_rate_bps = config
+        .acquirer_fraud_rate_bps
+        .max(issuer_fraud_rate_bps.unwrap_or_default());
+
+    config
+        .thresholds
+        .clone()
+        .unwrap_or_else(get_default_tra_thresholds)
+        .into_iter()
+        .filter(|threshold| fraud_rate_bps <= threshold.max_fraud_rate_bps)
+        .map(|threshold| threshold.max_amount.get_amount_as_i64())
+        .max()
+        .map(MinorUnit::new)
+}
+
+fn is_eligible_for_exemption(
+    config: &ScaExemptionConfig,
+    payment: &ScaExemptionEligibility<'_>,
+    exemption: storage_enums::ScaExemptionType,
+) -> bool {
+    match exemption {
+        storage_enums::ScaExemptionType::MerchantInitiatedTransaction => {
+            config.merchant_initiated && payment.is_merchant_initiated
+        }
+        storage_enums::ScaExemptionType::TrustedBeneficiary => config.trusted_beneficiary,
+        storage_enums::ScaExemptionType::LowValue => {
+            payment.currency == config.currency
+                && config
+                    .low_value
+                    .as_ref()
+                    .is_some_and(|low_value| payment.amount <= low_value.max_amount)
+        }
+        storage_enums::ScaExemptionType::TransactionRiskAnalysis => {
+            payment.currency == config.currency
+                && config.transaction_risk_analysis.as_ref().is_some_and(
+                    |transaction_risk_analysis| {
+                        payment.fraud_score.is_some_and(|fraud_score| {
+                            fraud_score <= transaction_risk_analysis.max_fraud_score
+                        }) && get_tra_max_amount(transaction_risk_analysis, payment.card_issuer)
+                            .is_some_and(|max_amount| payment.amount <= max_amount)
+                    },
+                )
+        }
+    }
+}
+
+/// Decides the exemption to be applied to the payment. Merchant initiated payments are flagged as
+/// such, a requested exemption is applied only if the payment is eligible for it, otherwise the
+/// low value exemption is preferred over the transaction risk analysis one.
+pub fn decide_sca_exemption(
+    config: &ScaExemptionConfig,
+    payment: &ScaExemptionEligibility<'_>,
+) -> Option<storage_enums::ScaExemptionType> {
+    let candidates = match payment.requested_exemption {
+        Some(requested_exemption) => vec![
+            storage_enums::ScaExemptionType::MerchantInitiatedTransaction,
+            requested_exemption,
+        ],
+        None => vec![
+            storage_enums::ScaExemptionType::MerchantInitiatedTransaction,
+            storage_enums::ScaExemptionType::LowValue,
+            storage_enums::ScaExemptionType::TransactionRiskAnalysis,
+        ],
+    };
+
+    candidates
+        .into_iter()
+        .find(|exemption| is_eligible_for_exemption(config, payment, *exemption))
+}
+
+/// Whether the exemption can be conveyed for the payment, either to the 3DS server when the
+/// payment is authenticated externally or otherwise to the connectors it may be authorized with
+fn is_sca_exemption_supported(
+    is_external_authentication_requested: bool,
+    connector_call_type: &api::ConnectorCallType,
+    business_profile: &domain::BusinessProfile,
+) -> bool {
+    if is_external_authentication_requested {
+        return business_profile
+            .authentication_connector_details
+            .as_ref()
+            .and_then(|details| details.authentication_connectors.first())
+            .is_some_and(|connector| connector.supports_sca_exemption());
+    }
+
+    match connector_call_type {
+        api::ConnectorCallType::PreDetermined(connector_data) => {
+            connector_data.connector_name.supports_sca_exemption()
+        }
+        api::ConnectorCallType::Retryable(connectors) => {
+            !connectors.is_empty()
+                && connectors
+                    .iter()
+                    .all(|connector_data| connector_data.connector_name.supports_sca_exemption())
+        }
+        api::ConnectorCallType::SessionMultiple(_) => false,
+    }
+}
+
+/// Decides the exemption of a card payment before it is authenticated. An exempted payment is
+/// authorized without a challenge, unless it is authenticated by an external 3DS server, in which
+/// case the exemption is requested from the 3DS server instead. Exemptions are only applied when
+/// they can be conveyed, otherwise the payment would skip the challenge without the issuer being
+/// told why.
+#[instrument(skip_all)]
+pub async fn apply_sca_exemption_if_eligible<F: Clone>(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+    connector_call_type: &api::ConnectorCallType,
+    business_profile: &domain::BusinessProfile,
+) -> RouterResult<()> {
+    // The exemption was decided before the payment was authenticated
+    if payment_data
+        .payment_attempt
+        .external_three_ds_authentication_attempted
+        .unwrap_or(false)
+    {
+        return Ok(());
+    }
+
+    let is_external_authentication_requested = payment_data
+        .payment_intent
+        .request_external_three_ds_authentication
+        .unwrap_or(false)
+        && payment_data.payment_attempt.authentication_type
+            == Some(storage_enums::AuthenticationType::ThreeDs);
+    let requested_exemption = payment_data.payment_attempt.sca_exemption;
+    let config = match payment_data.payment_attempt.payment_method {
+        Some(storage_enums::PaymentMethod::Card)
+            if is_sca_exemption_supported(
+                is_external_authentication_requested,
+                connector_call_type,
+                business_profile,
+            ) =>
+        {
+            find_sca_exemption_config(state, merchant_account.get_id()).await?
+        }
+        _ => None,
+    };
+
+    let sca_exemption = match config {
+        Some(config) => {
+            let fraud_score = match config.transaction_risk_analysis {
+                Some(_) => state
+                    .store
+                    .find_fraud_check_by_payment_id_if_present(
+                        payment_data.payment_intent.payment_id.clone(),
+                        merchant_account.get_id().clone(),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the fraud check of the payment")?
+                    .and_then(|fraud_check| fraud_check.frm_score),
+                None => None,
+            };
+            let card_issuer = match payment_data.payment_method_data.as_ref() {
+                Some(domain::PaymentMethodData::Card(card)) => card.card_issuer.as_deref(),
+                _ => None,
+            };
+            let payment = ScaExemptionEligibility {
+                requested_exemption,
+                amount: payment_data.payment_attempt.get_total_amount(),
+                currency: payment_data.currency,
+                is_merchant_initiated: payment_data.mandate_id.is_some(),
+                fraud_score,
+                card_issuer,
+            };
+            decide_sca_exemption(&config, &payment)
+        }
+        None => None,
+    };
+
+    if requested_exemption.is_some() && sca_exemption.is_none() {
+        logger::info!(
+            ?requested_exemption,
+            "payment is not eligible for the requested sca exemption"
+        );
+    }
+
+    if let Some(exemption) = sca_exemption {
+        metrics::SCA_EXEMPTION_APPLIED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("exemption", exemption.to_string())]),
+        );
+
+        if !is_external_authentication_requested {
+            payment_data.payment_attempt.authentication_type =
+                Some(storage_enums::AuthenticationType::NoThreeDs);
+        }
+    }
+    payment_data.payment_attempt.sca_exemption = sca_exemption;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use api_models::payments::LowValueExemptionConfig;
+
+    use super::*;
+
+    fn get_config() -> ScaExemptionConfig {
+        ScaExemptionConfig {
+            currency: storage_enums::Currency::EUR,
+            low_value: Some(LowValueExemptionConfig {
+                max_amount: MinorUnit::new(3000),
+            }),
+            transaction_risk_analysis: Some(TransactionRiskAnalysisExemptionConfig {
+                acquirer_fraud_rate_bps: 5,
+                issuer_fraud_rates_bps: HashMap::from([("chase".to_string(), 10)]),
+                max_fraud_score: 30,
+                thresholds: None,
+            }),
+            trusted_beneficiary: false,
+            merchant_initiated: true,
+        }
+    }
+
+    fn get_payment(amount: i64) -> ScaExemptionEligibility<'static> {
+        ScaExemptionEligibility {
+            requested_exemption: None,
+            amount: MinorUnit::new(amount),
+            currency: storage_enums::Currency::EUR,
+            is_merchant_initiated: false,
+            fraud_score: Some(10),
+            card_issuer: None,
+        }
+    }
+
+    #[test]
+    fn test_low_value_is_preferred() {
+        let exemption = decide_sca_exemption(&get_config(), &get_payment(2000));
+        assert_eq!(exemption, Some(storage_enums::ScaExemptionType::LowValue));
+    }
+
+    #[test]
+    fn test_transaction_risk_analysis_uses_higher_fraud_rate() {
+        let config = get_config();
+        let payment = get_payment(20000);
+        assert_eq!(
+            decide_sca_exemption(&config, &payment),
+            Some(storage_enums::ScaExemptionType::TransactionRiskAnalysis)
+        );
+
+        let payment = ScaExemptionEligibility {
+            card_issuer: Some("CHASE"),
+            ..payment
+        };
+        assert_eq!(decide_sca_exemption(&config, &payment), None);
+    }
+
+    #[test]
+    fn test_transaction_risk_analysis_requires_fraud_score() {
+        let config = get_config();
+        let payment = ScaExemptionEligibility {
+            fraud_score: None,
+            ..get_payment(5000)
+        };
+        assert_eq!(decide_sca_exemption(&config, &payment), None);
+
+        let payment = ScaExemptionEligibility {
+            fraud_score: Some(50),
+            ..get_payment(5000)
+        };
+        assert_eq!(decide_sca_exemption(&config, &payment), None);
+    }
+
+    #[test]
+    fn test_requested_exemption_is_validated() {
+        let config = get_config();
+        let payment = ScaExemptionEligibility {
+            requested_exemption: Some(storage_enums::ScaExemptionType::TrustedBeneficiary),
+            ..get_payment(2000)
+        };
+        assert_eq!(decide_sca_exemption(&config, &payment), None);
+
+        let payment = ScaExemptionEligibility {
+            requested_exemption: Some(storage_enums::ScaExemptionType::TransactionRiskAnalysis),
+            ..get_payment(2000)
+        };
+        assert_eq!(
+            decide_sca_exemption(&config, &payment),
+            Some(storage_enums::ScaExemptionType::TransactionRiskAnalysis)
+        );
+    }
+
+    #[test]
+    fn test_merchant_initiated_payment() {
+        let payment = ScaExemptionEligibility {
+            is_merchant_initiated: true,
+            ..get_payment(100000)
+        };
+        assert_eq!(
+            decide_sca_exemption(&get_config(), &payment),
+            Some(storage_enums::ScaExemptionType::MerchantInitiatedTransaction)
+        );
+    }
+
+    #[test]
+    fn test_exemption_is_conveyed_only_by_supporting_connectors() {
+        assert!(api_models::enums::Connector::Adyen.supports_sca_exemption());
+        assert!(!api_models::enums::Connector::Stripe.supports_sca_exemption());
+        assert!(storage_enums::AuthenticationConnectors::Netcetera.supports_sca_exemption());
+        assert!(!storage_enums::AuthenticationConnectors::Threedsecureio.supports_sca_exemption());
+    }
+
+    #[test]
+    fn test_currency_mismatch() {
+        let payment = ScaExemptionEligibility {
+            currency: storage_enums::Currency::USD,
+            ..get_payment(2000)
+        };
+        assert_eq!(decide_sca_exemption(&get_config(), &payment), None);
+    }
+}
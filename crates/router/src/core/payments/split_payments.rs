@@ -0,0 +1,702 @@
+#[cfg(feature = "olap")]
+use std::collections::HashMap;
+use std::{collections::HashSet, str::FromStr};
+
+use api_models::payments::{
+    SplitPaymentAllocation, SplitPaymentsDetails, SplitPaymentsRequest, SplitRefundAllocation,
+    SplitShare,
+};
+#[cfg(feature = "olap")]
+use api_models::payments::{
+    SplitPaymentsReportRequest, SplitPaymentsReportResponse, SplitRecipientReport,
+};
+use common_utils::{
+    consts::SPLIT_PAYMENT_PERCENTAGE_PRECISION_LENGTH,
+    ext_traits::ValueExt,
+    types::{MinorUnit, Percentage},
+};
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use router_env::{instrument, tracing};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use super::tax_calculation;
+use crate::{
+    core::errors::{self, RouterResult},
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+    },
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::errors::{RouterResponse, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+const SPLIT_REFERENCE_MAX_LENGTH: usize = 64;
+
+fn invalid_split_payments(message: &str) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::InvalidRequestData {
+        message: message.to_string(),
+    }
+}
+
+/// Resolves the share of a recipient into an amount. Percentage shares are rounded down, the
+/// remainder of the rounding being retained by the platform.
+fn get_share_amount(share: &SplitShare, amount: MinorUnit) -> RouterResult<MinorUnit> {
+    match share {
+        SplitShare::Fixed { amount } => Ok(*amount),
+        SplitShare::Percentage { percentage } => {
+            let invalid_percentage = || {
+                invalid_split_payments(
+                    "the percentage share of a recipient in split_payments should be between 0 and 100, with up to 2 decimal places",
+                )
+            };
+            let percentage = Percentage::<SPLIT_PAYMENT_PERCENTAGE_PRECISION_LENGTH>::from_string(
+                percentage.to_string(),
+            )
+            .change_context(invalid_percentage())?;
+            // The validated percentage is applied as a decimal, as rounding down a float product
+            // would lose a unit for percentages such as 29% of 100
+            let percentage = Decimal::from_str(&percentage.get_percentage().to_string())
+                .change_context(invalid_percentage())?;
+            (Decimal::from(amount.get_amount_as_i64()) * percentage / Decimal::ONE_HUNDRED)
+                .floor()
+                .to_i64()
+                .map(MinorUnit::new)
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to apply the split percentage to the amount")
+        }
+    }
+}
+
+/// Resolves the shares of the recipients of the payment into amounts, validating them against the
+/// amount of the payment. The amount remaining after the shares is retained by the platform.
+pub fn get_split_payments_details(
+    split_payments: &SplitPaymentsRequest,
+    amount: MinorUnit,
+) -> RouterResult<SplitPaymentsDetails> {
+    if split_payments.recipients.is_empty() {
+        Err(invalid_split_payments(
+            "split_payments.recipients should not be empty",
+        ))?
+    }
+
+    let mut account_ids = HashSet::new();
+    let allocations = split_payments
+        .recipients
+        .iter()
+        .map(|recipient| {
+            if !account_ids.insert(recipient.account_id.as_str()) {
+                Err(invalid_split_payments(
+                    "a recipient should not be repeated in split_payments.recipients",
+                ))?
+            }
+            if recipient
+                .reference
+                .as_ref()
+                .is_some_and(|reference| reference.len() > SPLIT_REFERENCE_MAX_LENGTH)
+            {
+                Err(invalid_split_payments(
+                    "the reference of a recipient in split_payments should not be more than 64 characters",
+                ))?
+            }
+            let share_amount = get_share_amount(&recipient.share, amount)?;
+            if share_amount <= MinorUnit::zero() || share_amount > amount {
+                Err(invalid_split_payments(
+                    "the share of a recipient in split_payments should be greater than zero and not more than the amount of the payment",
+                ))?
+            }
+            Ok(SplitPaymentAllocation {
+                account_id: recipient.account_id.clone(),
+                amount: share_amount,
+                reference: recipient.reference.clone(),
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let allocated_amount = allocations
+        .iter()
+        .fold(MinorUnit::zero(), |total, allocation| {
+            total + allocation.amount
+        });
+    if allocated_amount > amount {
+        Err(invalid_split_payments(
+            "the shares of the recipients in split_payments should not exceed the amount of the payment",
+        ))?
+    }
+
+    if let Some(fee_bearer) = &split_payments.fee_bearer {
+        if !account_ids.contains(fee_bearer.as_str()) {
+            Err(invalid_split_payments(
+                "split_payments.fee_bearer should be one of the recipients",
+            ))?
+        }
+    }
+
+    Ok(SplitPaymentsDetails {
+        allocations,
+        platform_amount: amount - allocated_amount,
+        fee_bearer: split_payments.fee_bearer.clone(),
+    })
+}
+
+/// Validates that the split payments are provided only while creating a payment, along with the
+/// amount of the payment
+pub fn validate_split_payments_request(
+    request: &api::PaymentsRequest,
+    is_payment_create: bool,
+) -> RouterResult<()> {
+    let Some(split_payments) = &request.split_payments else {
+        return Ok(());
+    };
+    if !is_payment_create {
+        Err(invalid_split_payments(
+            "split_payments can be provided only while creating the payment",
+        ))?
+    }
+    if request.charges.is_some() {
+        Err(invalid_split_payments(
+            "split_payments and charges cannot be provided together",
+        ))?
+    }
+    if let Some(amount) = request.amount {
+        get_split_payments_details(split_payments, MinorUnit::from(amount))?;
+    }
+    Ok(())
+}
+
+/// The splits of a payment are resolved from its amount at creation, so the amount cannot be
+/// changed afterwards
+pub fn validate_amount_update(
+    payment_intent: &storage::PaymentIntent,
+    amount: Option<api::Amount>,
+) -> RouterResult<()> {
+    match amount {
+        Some(amount)
+            if payment_intent.split_payments.is_some()
+                && MinorUnit::from(amount) != payment_intent.amount =>
+        {
+            Err(invalid_split_payments(
+                "the amount of a split payment cannot be updated",
+            ))?
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn get_split_payments(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<SplitPaymentsDetails>> {
+    payment_intent
+        .split_payments
+        .clone()
+        .map(|split_payments| {
+            split_payments
+                .parse_value("SplitPaymentsDetails")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse split payments of the payment intent")
+        })
+        .transpose()
+}
+
+pub fn get_split_refunds(refund: &storage::Refund) -> RouterResult<Vec<SplitRefundAllocation>> {
+    refund
+        .split_refunds
+        .clone()
+        .map(|split_refunds| {
+            split_refunds
+                .parse_value("SplitRefundAllocations")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse split refunds of the refund")
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// The amount reversed from the recipient across the split refunds
+fn get_reversed_amount(split_refunds: &[SplitRefundAllocation], account_id: &str) -> MinorUnit {
+    split_refunds
+        .iter()
+        .filter(|allocation| allocation.account_id == account_id)
+        .fold(MinorUnit::zero(), |total, allocation| {
+            total + allocation.amount
+        })
+}
+
+/// The amounts to be reversed from the recipients of the payment for a refund.
+///
+/// The refunds of the payment which have not failed, including the pending ones, are considered
+/// as reversed already. The reversals are proportional to the shares of the recipients in the
+/// amount refunded so far, so that the rounding is caught up with by the later refunds and a
+/// full refund reverses the whole shares.
+pub fn get_refund_allocations(
+    split_payments: &SplitPaymentsDetails,
+    refunds: &[storage::Refund],
+    refund_amount: MinorUnit,
+    payment_amount: MinorUnit,
+) -> RouterResult<Vec<SplitRefundAllocation>> {
+    let mut refunded_amount = refund_amount;
+    let mut reversed_allocations = Vec::new();
+    for refund in refunds.iter().filter(|refund| {
+        !matches!(
+            refund.refund_status,
+            storage_enums::RefundStatus::Failure | storage_enums::RefundStatus::TransactionFailure
+        )
+    }) {
+        refunded_amount = refunded_amount + refund.refund_amount;
+        reversed_allocations.extend(get_split_refunds(refund)?);
+    }
+    let refunded_amount = if refunded_amount > payment_amount {
+        payment_amount
+    } else {
+        refunded_amount
+    };
+
+    split_payments
+        .allocations
+        .iter()
+        .map(|allocation| {
+            let reversible_amount = tax_calculation::get_proportional_amount(
+                allocation.amount,
+                refunded_amount,
+                payment_amount,
+            )?;
+            Ok(SplitRefundAllocation {
+                account_id: allocation.account_id.clone(),
+                amount: reversible_amount
+                    - get_reversed_amount(&reversed_allocations, &allocation.account_id),
+            })
+        })
+        .filter(|allocation| {
+            allocation
+                .as_ref()
+                .map_or(true, |allocation| allocation.amount > MinorUnit::zero())
+        })
+        .collect()
+}
+
+/// The amounts allocated to the recipients out of the amount captured for the payment. The shares
+/// of a partially captured payment are reduced in proportion to the amount captured, the same way
+/// a refund of the captured amount reverses them. Payments which did not capture funds allocate
+/// nothing.
+#[cfg(feature = "olap")]
+fn get_captured_allocations(
+    split_payments: &SplitPaymentsDetails,
+    status: storage_enums::IntentStatus,
+    amount: MinorUnit,
+    amount_captured: Option<MinorUnit>,
+) -> RouterResult<Vec<SplitPaymentAllocation>> {
+    match status {
+        storage_enums::IntentStatus::Succeeded => Ok(split_payments.allocations.clone()),
+        storage_enums::IntentStatus::PartiallyCaptured => {
+            let amount_captured = amount_captured.unwrap_or(MinorUnit::zero());
+            split_payments
+                .allocations
+                .iter()
+                .map(|allocation| {
+                    Ok(SplitPaymentAllocation {
+                        amount: tax_calculation::get_proportional_amount(
+                            allocation.amount,
+                            amount_captured,
+                            amount,
+                        )?,
+                        ..allocation.clone()
+                    })
+                })
+                .filter(|allocation| {
+                    allocation
+                        .as_ref()
+                        .map_or(true, |allocation| allocation.amount > MinorUnit::zero())
+                })
+                .collect()
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
+/// Reports the amounts allocated to each recipient across the succeeded and partially captured
+/// split payments created in the time range
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn get_split_payments_report(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: SplitPaymentsReportRequest,
+) -> RouterResponse<SplitPaymentsReportResponse> {
+    let payment_intents = state
+        .store
+        .filter_payment_intents_by_time_range_constraints(
+            &(&state).into(),
+            merchant_account.get_id(),
+            &request.time_range,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let mut reports: HashMap<(String, storage_enums::Currency), SplitRecipientReport> =
+        HashMap::new();
+    for payment_intent in payment_intents.iter() {
+        let (Some(split_payments), Some(currency)) =
+            (get_split_payments(payment_intent)?, payment_intent.currency)
+        else {
+            continue;
+        };
+        let allocations = get_captured_allocations(
+            &split_payments,
+            payment_intent.status,
+            payment_intent.amount,
+            payment_intent.amount_captured,
+        )?;
+        if allocations.is_empty() {
+            continue;
+        }
+        let mut reversed_allocations = Vec::new();
+        for refund in state
+            .store
+            .find_refund_by_payment_id_merchant_id(
+                &payment_intent.payment_id,
+                merchant_account.get_id(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refunds of the payment")?
+            .iter()
+            .filter(|refund| refund.refund_status == storage_enums::RefundStatus::Success)
+        {
+            reversed_allocations.extend(get_split_refunds(refund)?);
+        }
+        for allocation in allocations.iter().filter(|allocation| {
+            request
+                .account_id
+                .as_ref()
+                .map_or(true, |account_id| *account_id == allocation.account_id)
+        }) {
+            let reversed_amount =
+                get_reversed_amount(&reversed_allocations, &allocation.account_id);
+            let report = reports
+                .entry((allocation.account_id.clone(), currency))
+                .or_insert_with(|| SplitRecipientReport {
+                    account_id: allocation.account_id.clone(),
+                    currency,
+                    payment_count: 0,
+                    allocated_amount: MinorUnit::zero(),
+                    reversed_amount: MinorUnit::zero(),
+                    net_amount: MinorUnit::zero(),
+                });
+            report.payment_count += 1;
+            report.allocated_amount = report.allocated_amount + allocation.amount;
+            report.reversed_amount = report.reversed_amount + reversed_amount;
+            report.net_amount = report.allocated_amount - report.reversed_amount;
+        }
+    }
+
+    let mut recipients = reports.into_values().collect::<Vec<_>>();
+    recipients.sort_by(|first, second| {
+        (first.account_id.as_str(), first.currency.to_string())
+            .cmp(&(second.account_id.as_str(), second.currency.to_string()))
+    });
+    Ok(services::ApplicationResponse::Json(
+        SplitPaymentsReportResponse { recipients },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::payments::SplitPaymentRecipient;
+    use common_utils::ext_traits::Encode;
+
+    use super::*;
+
+    fn recipient(account_id: &str, share: SplitShare) -> SplitPaymentRecipient {
+        SplitPaymentRecipient {
+            account_id: account_id.to_string(),
+            share,
+            reference: None,
+        }
+    }
+
+    fn split_payments_request(recipients: Vec<SplitPaymentRecipient>) -> SplitPaymentsRequest {
+        SplitPaymentsRequest {
+            recipients,
+            fee_bearer: None,
+        }
+    }
+
+    fn refund(
+        refund_id: &str,
+        status: storage_enums::RefundStatus,
+        amount: i64,
+        split_refunds: Vec<(&str, i64)>,
+    ) -> storage::Refund {
+        storage::Refund {
+            connector: "adyen".to_string(),
+            total_amount: MinorUnit::new(10000),
+            split_refunds: Some(
+                split_refunds
+                    .into_iter()
+                    .map(|(account_id, amount)| SplitRefundAllocation {
+                        account_id: account_id.to_string(),
+                        amount: MinorUnit::new(amount),
+                    })
+                    .collect::<Vec<_>>()
+                    .encode_to_value()
+                    .unwrap(),
+            ),
+            ..storage::refund::test_utils::get_refund(refund_id, status, amount)
+        }
+    }
+
+    fn split_payments_details() -> SplitPaymentsDetails {
+        get_split_payments_details(
+            &split_payments_request(vec![
+                recipient(
+                    "acct_1",
+                    SplitShare::Fixed {
+                        amount: MinorUnit::new(6000),
+                    },
+                ),
+                recipient("acct_2", SplitShare::Percentage { percentage: 33.33 }),
+            ]),
+            MinorUnit::new(10000),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn percentage_shares_are_rounded_down_with_the_remainder_retained_by_the_platform() {
+        let details = get_split_payments_details(
+            &split_payments_request(vec![
+                recipient("acct_1", SplitShare::Percentage { percentage: 29.0 }),
+                recipient("acct_2", SplitShare::Percentage { percentage: 33.33 }),
+            ]),
+            MinorUnit::new(101),
+        )
+        .unwrap();
+
+        assert_eq!(
+            details
+                .allocations
+                .iter()
+                .map(|allocation| allocation.amount)
+                .collect::<Vec<_>>(),
+            vec![MinorUnit::new(29), MinorUnit::new(33)]
+        );
+        assert_eq!(details.platform_amount, MinorUnit::new(39));
+    }
+
+    #[test]
+    fn shares_exceeding_the_amount_are_rejected() {
+        let request = split_payments_request(vec![
+            recipient("acct_1", SplitShare::Percentage { percentage: 60.0 }),
+            recipient(
+                "acct_2",
+                SplitShare::Fixed {
+                    amount: MinorUnit::new(4001),
+                },
+            ),
+        ]);
+        assert!(get_split_payments_details(&request, MinorUnit::new(10000)).is_err());
+    }
+
+    #[test]
+    fn repeated_recipients_are_rejected() {
+        let request = split_payments_request(vec![
+            recipient("acct_1", SplitShare::Percentage { percentage: 10.0 }),
+            recipient("acct_1", SplitShare::Percentage { percentage: 20.0 }),
+        ]);
+        assert!(get_split_payments_details(&request, MinorUnit::new(10000)).is_err());
+    }
+
+    #[test]
+    fn zero_shares_are_rejected() {
+        let request = split_payments_request(vec![recipient(
+            "acct_1",
+            SplitShare::Percentage { percentage: 0.5 },
+        )]);
+        assert!(get_split_payments_details(&request, MinorUnit::new(100)).is_err());
+    }
+
+    #[test]
+    fn fee_bearer_should_be_a_recipient() {
+        let mut request = split_payments_request(vec![recipient(
+            "acct_1",
+            SplitShare::Percentage { percentage: 10.0 },
+        )]);
+        request.fee_bearer = Some("acct_2".to_string());
+        assert!(get_split_payments_details(&request, MinorUnit::new(10000)).is_err());
+
+        request.fee_bearer = Some("acct_1".to_string());
+        let details = get_split_payments_details(&request, MinorUnit::new(10000)).unwrap();
+        assert_eq!(details.fee_bearer.as_deref(), Some("acct_1"));
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn captured_allocations_are_reduced_for_partially_captured_payments() {
+        let details = split_payments_details();
+
+        let allocations = get_captured_allocations(
+            &details,
+            storage_enums::IntentStatus::Succeeded,
+            MinorUnit::new(10000),
+            Some(MinorUnit::new(10000)),
+        )
+        .unwrap();
+        assert_eq!(allocations, details.allocations);
+
+        let allocations = get_captured_allocations(
+            &details,
+            storage_enums::IntentStatus::PartiallyCaptured,
+            MinorUnit::new(10000),
+            Some(MinorUnit::new(5000)),
+        )
+        .unwrap();
+        assert_eq!(
+            allocations
+                .iter()
+                .map(|allocation| (allocation.account_id.as_str(), allocation.amount))
+                .collect::<Vec<_>>(),
+            vec![
+                ("acct_1", MinorUnit::new(3000)),
+                ("acct_2", MinorUnit::new(1666)),
+            ]
+        );
+
+        for status in [
+            storage_enums::IntentStatus::Failed,
+            storage_enums::IntentStatus::RequiresCapture,
+            storage_enums::IntentStatus::PartiallyCapturedAndCapturable,
+        ] {
+            assert!(get_captured_allocations(
+                &details,
+                status,
+                MinorUnit::new(10000),
+                Some(MinorUnit::new(5000)),
+            )
+            .unwrap()
+            .is_empty());
+        }
+    }
+
+    #[test]
+    fn refund_allocations_are_proportional_to_the_shares() {
+        let allocations = get_refund_allocations(
+            &split_payments_details(),
+            &[],
+            MinorUnit::new(2500),
+            MinorUnit::new(10000),
+        )
+        .unwrap();
+
+        assert_eq!(
+            allocations,
+            vec![
+                SplitRefundAllocation {
+                    account_id: "acct_1".to_string(),
+                    amount: MinorUnit::new(1500),
+                },
+                SplitRefundAllocation {
+                    account_id: "acct_2".to_string(),
+                    amount: MinorUnit::new(833),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn refund_allocations_count_pending_refunds_and_ignore_failed_refunds() {
+        let refunds = vec![
+            refund(
+                "ref_1",
+                storage_enums::RefundStatus::Pending,
+                2500,
+                vec![("acct_1", 1500), ("acct_2", 833)],
+            ),
+            refund(
+                "ref_2",
+                storage_enums::RefundStatus::Failure,
+                5000,
+                vec![("acct_1", 3000), ("acct_2", 1666)],
+            ),
+        ];
+        let allocations = get_refund_allocations(
+            &split_payments_details(),
+            &refunds,
+            MinorUnit::new(2500),
+            MinorUnit::new(10000),
+        )
+        .unwrap();
+
+        assert_eq!(
+            allocations,
+            vec![
+                SplitRefundAllocation {
+                    account_id: "acct_1".to_string(),
+                    amount: MinorUnit::new(1500),
+                },
+                SplitRefundAllocation {
+                    account_id: "acct_2".to_string(),
+                    amount: MinorUnit::new(833),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn full_refund_reverses_the_whole_shares() {
+        let refunds = vec![refund(
+            "ref_1",
+            storage_enums::RefundStatus::Success,
+            3333,
+            vec![("acct_1", 1999), ("acct_2", 1110)],
+        )];
+        let allocations = get_refund_allocations(
+            &split_payments_details(),
+            &refunds,
+            MinorUnit::new(6667),
+            MinorUnit::new(10000),
+        )
+        .unwrap();
+
+        assert_eq!(
+            allocations,
+            vec![
+                SplitRefundAllocation {
+                    account_id: "acct_1".to_string(),
+                    amount: MinorUnit::new(4001),
+                },
+                SplitRefundAllocation {
+                    account_id: "acct_2".to_string(),
+                    amount: MinorUnit::new(2223),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn fully_reversed_recipients_are_skipped() {
+        let refunds = vec![refund(
+            "ref_1",
+            storage_enums::RefundStatus::Success,
+            10000,
+            vec![("acct_1", 6000), ("acct_2", 3333)],
+        )];
+        let allocations = get_refund_allocations(
+            &split_payments_details(),
+            &refunds,
+            MinorUnit::new(100),
+            MinorUnit::new(10000),
+        )
+        .unwrap();
+        assert!(allocations.is_empty());
+    }
+}
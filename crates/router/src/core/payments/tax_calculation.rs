@@ -0,0 +1,954 @@
+use std::marker::PhantomData;
+
+use api_models::{
+    enums as api_enums,
+    payments::{OrderDetailsWithAmount, TaxDetails, TaxReversal},
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::router_data_v2::PaymentFlowData;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use super::helpers::{self, MerchantConnectorAccountType};
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    routes::{metrics, SessionState},
+    services,
+    types::{self, api, domain, storage, storage::enums as storage_enums},
+    utils::OptionExt,
+};
+
+const IRRELEVANT_ATTEMPT_ID_IN_TAX_FLOW: &str = "irrelevant_attempt_id_in_tax_flow";
+
+/// Finds the enabled tax connector account configured for the business profile, if any
+async fn find_tax_connector_account(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &str,
+) -> RouterResult<Option<(api_enums::TaxConnectors, domain::MerchantConnectorAccount)>> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts")?;
+
+    Ok(merchant_connector_accounts
+        .into_iter()
+        .filter(|mca| {
+            mca.profile_id == profile_id
+                && mca.connector_type == storage_enums::ConnectorType::FinOperations
+        })
+        .find_map(|mca| {
+            api_enums::convert_tax_connector(&mca.connector_name).map(|connector| (connector, mca))
+        }))
+}
+
+async fn find_merchant_connector_account_by_id(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &common_utils::id_type::MerchantId,
+    merchant_connector_id: &str,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_id,
+            merchant_connector_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.to_string(),
+        })
+}
+
+fn get_order_details(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<Vec<OrderDetailsWithAmount>>> {
+    payment_intent
+        .order_details
+        .as_ref()
+        .filter(|order_details| !order_details.is_empty())
+        .map(|order_details| {
+            order_details
+                .iter()
+                .map(|data| {
+                    data.to_owned()
+                        .parse_value("OrderDetailsWithAmount")
+                        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                            field_name: "OrderDetailsWithAmount",
+                        })
+                        .attach_printable("Unable to parse OrderDetailsWithAmount")
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+}
+
+fn get_order_amount(order_details: &[OrderDetailsWithAmount]) -> RouterResult<MinorUnit> {
+    order_details
+        .iter()
+        .try_fold(0i64, |total, order| {
+            order
+                .amount
+                .checked_mul(i64::from(order.quantity))
+                .and_then(|line_amount| total.checked_add(line_amount))
+        })
+        .map(MinorUnit::new)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Overflow while computing the total amount of the order details")
+}
+
+/// Computes `amount * numerator / denominator`, rounding towards zero
+pub fn get_proportional_amount(
+    amount: MinorUnit,
+    numerator: MinorUnit,
+    denominator: MinorUnit,
+) -> RouterResult<MinorUnit> {
+    amount
+        .get_amount_as_i64()
+        .checked_mul(numerator.get_amount_as_i64())
+        .and_then(|value| value.checked_div(denominator.get_amount_as_i64()))
+        .map(MinorUnit::new)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the proportional amount")
+}
+
+pub fn get_tax_details(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<TaxDetails>> {
+    payment_intent
+        .tax_details
+        .clone()
+        .map(|tax_details| {
+            tax_details
+                .parse_value("TaxDetails")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse tax details of the payment intent")
+        })
+        .transpose()
+}
+
+fn construct_tax_router_data<F: Clone, Req, Res>(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    payment_intent: &storage::PaymentIntent,
+    address: types::PaymentAddress,
+    request: Req,
+) -> RouterResult<types::RouterData<F, Req, Res>> {
+    let connector_name = merchant_connector_account.connector_name.clone();
+    let merchant_connector_account =
+        MerchantConnectorAccountType::DbVal(merchant_connector_account.clone());
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: payment_intent.merchant_id.clone(),
+        customer_id: payment_intent.customer_id.clone(),
+        connector_customer: None,
+        connector: connector_name,
+        payment_id: payment_intent.payment_id.clone(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_TAX_FLOW.to_owned(),
+        status: storage_enums::AttemptStatus::default(),
+        payment_method: storage_enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: payment_intent.description.clone(),
+        return_url: None,
+        address,
+        auth_type: storage_enums::AuthenticationType::NoThreeDs,
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        amount_captured: None,
+        minor_amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_api_version: None,
+        request,
+        response: Err(types::ErrorResponse::default()),
+        connector_request_reference_id: payment_intent.payment_id.clone(),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode: merchant_connector_account.is_test_mode_on(),
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        dispute_id: None,
+        refund_id: None,
+        payment_method_status: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+    })
+}
+
+async fn call_tax_connector<F, Req, Res>(
+    state: &SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    router_data: types::RouterData<F, Req, Res>,
+) -> RouterResult<Res>
+where
+    F: std::fmt::Debug + Clone + 'static,
+    Req: std::fmt::Debug + Clone + 'static,
+    Res: std::fmt::Debug + Clone + 'static,
+    dyn api::Connector + Sync: services::api::ConnectorIntegration<F, Req, Res>,
+    dyn api::ConnectorV2 + Sync:
+        services::api::ConnectorIntegrationV2<F, PaymentFlowData, Req, Res>,
+{
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &merchant_connector_account.connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the tax connector")?;
+
+    let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<F, Req, Res> =
+        connector_data.connector.get_connector_integration();
+
+    let router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_payment_failed_response()?;
+
+    router_data.response.map_err(|err| {
+        report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: merchant_connector_account.connector_name.clone(),
+            status_code: err.status_code,
+            reason: err.reason,
+        })
+    })
+}
+
+/// Calculates the tax on the order details of the payment intent, shipped to the given address.
+///
+/// Returns `None` when the business profile has no tax connector configured, or when the payment
+/// intent does not have a shipping address and order details to calculate the tax on.
+#[instrument(skip_all)]
+pub async fn calculate_tax_details(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    currency: storage_enums::Currency,
+    shipping_address: Option<&api_models::payments::Address>,
+) -> RouterResult<Option<serde_json::Value>> {
+    let Some(shipping_address) =
+        shipping_address.filter(|shipping_address| shipping_address.address.is_some())
+    else {
+        return Ok(None);
+    };
+    let Some(order_details) = get_order_details(payment_intent)? else {
+        return Ok(None);
+    };
+    let Some(profile_id) = payment_intent.profile_id.as_deref() else {
+        return Ok(None);
+    };
+    let Some((tax_connector, merchant_connector_account)) =
+        find_tax_connector_account(state, key_store, &payment_intent.merchant_id, profile_id)
+            .await?
+    else {
+        return Ok(None);
+    };
+
+    let request = types::PaymentsTaxCalculationData {
+        amount: get_order_amount(&order_details)?,
+        currency,
+        order_details,
+    };
+    let router_data: types::PaymentsTaxCalculationRouterData = construct_tax_router_data(
+        &merchant_connector_account,
+        payment_intent,
+        types::PaymentAddress::new(Some(shipping_address.clone()), None, None, None),
+        request,
+    )?;
+    let response = call_tax_connector(state, &merchant_connector_account, router_data).await?;
+
+    TaxDetails {
+        connector: tax_connector,
+        merchant_connector_id: merchant_connector_account.get_id(),
+        taxable_amount: response.taxable_amount,
+        order_tax_amount: response.order_tax_amount,
+        breakdown: response.breakdown,
+        committed_transaction_id: None,
+        committed_tax_amount: None,
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize tax details")
+    .map(Some)
+}
+
+async fn update_tax_details(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    payment_intent: storage::PaymentIntent,
+    tax_details: &TaxDetails,
+) -> RouterResult<storage::PaymentIntent> {
+    let tax_details = tax_details
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize tax details")?;
+
+    state
+        .store
+        .update_payment_intent(
+            &state.into(),
+            payment_intent,
+            storage::PaymentIntentUpdate::TaxDetailsUpdate {
+                tax_details,
+                updated_by: storage_scheme.to_string(),
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+}
+
+/// The tax connectors locate the transactions by the shipping address of the order
+async fn get_shipping_address(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<types::PaymentAddress> {
+    let shipping_address = helpers::create_or_find_address_for_payment_by_request(
+        state,
+        None,
+        payment_intent.shipping_address_id.as_deref(),
+        &payment_intent.merchant_id,
+        payment_intent.customer_id.as_ref(),
+        key_store,
+        &payment_intent.payment_id,
+        storage_scheme,
+    )
+    .await?;
+
+    Ok(types::PaymentAddress::new(
+        shipping_address
+            .as_ref()
+            .map(api_models::payments::Address::from),
+        None,
+        None,
+        None,
+    ))
+}
+
+/// The amount captured on the payment, which is the whole amount of the payment unless the
+/// payment was partially captured
+fn get_captured_amount(payment_intent: &storage::PaymentIntent) -> MinorUnit {
+    payment_intent
+        .amount_captured
+        .filter(|amount_captured| *amount_captured > MinorUnit::zero())
+        .unwrap_or(payment_intent.amount)
+}
+
+/// Records the calculated tax of a captured payment as a committed transaction on the tax
+/// connector, so that it is reported in the merchant's filings.
+///
+/// The committed tax is proportional to the captured amount when the payment was partially
+/// captured, in which case the order lines are not reported.
+#[instrument(skip_all)]
+pub async fn commit_tax_transaction(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    payment_intent: storage::PaymentIntent,
+) -> RouterResult<storage::PaymentIntent> {
+    let Some(mut tax_details) = get_tax_details(&payment_intent)? else {
+        return Ok(payment_intent);
+    };
+    if tax_details.committed_transaction_id.is_some() {
+        return Ok(payment_intent);
+    }
+    let Some(order_details) = get_order_details(&payment_intent)? else {
+        return Ok(payment_intent);
+    };
+
+    let merchant_connector_account = find_merchant_connector_account_by_id(
+        state,
+        key_store,
+        &payment_intent.merchant_id,
+        &tax_details.merchant_connector_id,
+    )
+    .await?;
+
+    let currency = payment_intent.currency.get_required_value("currency")?;
+    let captured_amount = get_captured_amount(&payment_intent);
+    let fully_captured = captured_amount >= payment_intent.amount;
+    let (amount, tax_amount, order_details) = if fully_captured {
+        (
+            get_order_amount(&order_details)?,
+            tax_details.order_tax_amount,
+            order_details,
+        )
+    } else {
+        (
+            get_proportional_amount(
+                get_order_amount(&order_details)?,
+                captured_amount,
+                payment_intent.amount,
+            )?,
+            get_proportional_amount(
+                tax_details.order_tax_amount,
+                captured_amount,
+                payment_intent.amount,
+            )?,
+            vec![],
+        )
+    };
+    let request = types::TaxCommitData {
+        transaction_id: payment_intent.payment_id.clone(),
+        transaction_date: common_utils::date_time::now(),
+        amount,
+        tax_amount,
+        currency,
+        order_details,
+    };
+    let address = get_shipping_address(state, key_store, storage_scheme, &payment_intent).await?;
+    let router_data: types::TaxCommitRouterData = construct_tax_router_data(
+        &merchant_connector_account,
+        &payment_intent,
+        address,
+        request,
+    )?;
+    let response = call_tax_connector(state, &merchant_connector_account, router_data).await?;
+
+    tax_details.committed_transaction_id = Some(response.connector_transaction_id);
+    tax_details.committed_tax_amount = Some(tax_amount);
+    update_tax_details(
+        state,
+        key_store,
+        storage_scheme,
+        payment_intent,
+        &tax_details,
+    )
+    .await
+}
+
+/// The tax reversed for the refunds of the payment other than the specified refund
+fn get_reversed_tax_amount(
+    refunds: &[storage::Refund],
+    refund_id: &str,
+) -> RouterResult<MinorUnit> {
+    refunds
+        .iter()
+        .filter(|refund| refund.refund_id != refund_id)
+        .filter_map(|refund| refund.tax_reversal.clone())
+        .try_fold(MinorUnit::zero(), |total, tax_reversal| {
+            tax_reversal
+                .parse_value::<TaxReversal>("TaxReversal")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse tax reversal of refund")
+                .map(|tax_reversal| total + tax_reversal.tax_amount)
+        })
+}
+
+/// The tax to be reversed for a refund, proportional to the refunded share of the captured amount
+/// and capped to the committed tax which is not reversed yet
+fn get_reversal_tax_amount(
+    tax_details: &TaxDetails,
+    captured_amount: MinorUnit,
+    refund_amount: MinorUnit,
+    reversed_tax_amount: MinorUnit,
+) -> RouterResult<MinorUnit> {
+    let committed_tax_amount = tax_details
+        .committed_tax_amount
+        .unwrap_or(tax_details.order_tax_amount);
+    let unreversed_tax_amount = committed_tax_amount - reversed_tax_amount;
+    let proportional_tax_amount =
+        get_proportional_amount(committed_tax_amount, refund_amount, captured_amount)?;
+
+    Ok(if proportional_tax_amount > unreversed_tax_amount {
+        unreversed_tax_amount
+    } else {
+        proportional_tax_amount
+    })
+}
+
+/// Reverses the share of the committed tax corresponding to a successful refund of the payment,
+/// and records the reversal on the refund.
+///
+/// Each refund records only its own reversal, so that the reversals of concurrent refunds of the
+/// payment never overwrite each other.
+#[instrument(skip_all)]
+pub async fn reverse_tax_transaction(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    refund: &storage::Refund,
+) -> RouterResult<storage::Refund> {
+    if refund.refund_status != storage_enums::RefundStatus::Success || refund.tax_reversal.is_some()
+    {
+        return Ok(refund.clone());
+    }
+
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &refund.payment_id,
+            &refund.merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let Some(tax_details) = get_tax_details(&payment_intent)? else {
+        return Ok(refund.clone());
+    };
+    // The refund of a captured payment may be processed before the retried commit of its tax
+    let committed_transaction_id = tax_details.committed_transaction_id.clone().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "The tax transaction of the payment is not committed yet".to_string(),
+        },
+    )?;
+
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &refund.payment_id,
+            &refund.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment")?;
+    let tax_amount = get_reversal_tax_amount(
+        &tax_details,
+        get_captured_amount(&payment_intent),
+        refund.refund_amount,
+        get_reversed_tax_amount(&refunds, &refund.refund_id)?,
+    )?;
+    if tax_amount <= MinorUnit::zero() {
+        return Ok(refund.clone());
+    }
+
+    let merchant_connector_account = find_merchant_connector_account_by_id(
+        state,
+        key_store,
+        &payment_intent.merchant_id,
+        &tax_details.merchant_connector_id,
+    )
+    .await?;
+
+    let currency = payment_intent.currency.get_required_value("currency")?;
+    let request = types::TaxReversalData {
+        transaction_id: refund.refund_id.clone(),
+        reference_transaction_id: committed_transaction_id,
+        transaction_date: common_utils::date_time::now(),
+        amount: get_proportional_amount(
+            tax_details.taxable_amount,
+            refund.refund_amount,
+            payment_intent.amount,
+        )?,
+        tax_amount,
+        currency,
+    };
+    let address = get_shipping_address(state, key_store, storage_scheme, &payment_intent).await?;
+    let router_data: types::TaxReversalRouterData = construct_tax_router_data(
+        &merchant_connector_account,
+        &payment_intent,
+        address,
+        request,
+    )?;
+    let response = call_tax_connector(state, &merchant_connector_account, router_data).await?;
+
+    let tax_reversal = TaxReversal {
+        connector_transaction_id: response.connector_transaction_id,
+        tax_amount,
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize tax reversal")?;
+    db.update_refund(
+        refund.clone(),
+        storage::RefundUpdate::TaxReversalUpdate {
+            tax_reversal,
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)
+}
+
+/// Commits the tax of a captured payment, scheduling a retry of the commit if it failed.
+///
+/// Returns the payment intent along with the committed tax transaction, or unchanged if the
+/// commit failed.
+pub async fn commit_tax_transaction_or_schedule_retry(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    payment_intent: storage::PaymentIntent,
+) -> storage::PaymentIntent {
+    match commit_tax_transaction(state, key_store, storage_scheme, payment_intent.clone()).await {
+        Ok(payment_intent) => payment_intent,
+        Err(error) => {
+            logger::error!(?error, "Failed to commit the tax transaction");
+            add_tax_transaction_task(
+                state,
+                TaxTransactionTrackingData {
+                    merchant_id: payment_intent.merchant_id.clone(),
+                    payment_id: payment_intent.payment_id.clone(),
+                    refund_id: None,
+                },
+            )
+            .await;
+            payment_intent
+        }
+    }
+}
+
+/// Reverses the tax of a successful refund, scheduling a retry of the reversal if it failed.
+///
+/// Returns the refund along with the recorded tax reversal, or unchanged if the reversal failed.
+pub async fn reverse_tax_transaction_or_schedule_retry(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+    refund: storage::Refund,
+) -> storage::Refund {
+    match reverse_tax_transaction(state, key_store, storage_scheme, &refund).await {
+        Ok(refund) => refund,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to reverse the tax transaction of the refund"
+            );
+            add_tax_transaction_task(
+                state,
+                TaxTransactionTrackingData {
+                    merchant_id: refund.merchant_id.clone(),
+                    payment_id: refund.payment_id.clone(),
+                    refund_id: Some(refund.refund_id.clone()),
+                },
+            )
+            .await;
+            refund
+        }
+    }
+}
+
+// ********************************************** PROCESS TRACKER **********************************************
+
+const TAX_TRANSACTION_TAG: &str = "TAX";
+const TAX_TRANSACTION_NAME: &str = "TAX_TRANSACTION";
+const TAX_TRANSACTION_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::TaxTransactionWorkflow;
+
+/// Key of the config of the attempts of tax transactions that could not be recorded with the tax
+/// connector
+const TAX_TRANSACTION_RETRY_MAPPING_KEY: &str = "pt_mapping_tax_transaction";
+
+/// Tracking data of a tax transaction retried by the process tracker
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct TaxTransactionTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: String,
+    /// The refund whose tax has to be reversed, or `None` to commit the tax of the payment
+    pub refund_id: Option<String>,
+}
+
+fn generate_task_id_for_tax_transaction_workflow(
+    tracking_data: &TaxTransactionTrackingData,
+) -> String {
+    format!(
+        "{TAX_TRANSACTION_RUNNER}_{TAX_TRANSACTION_NAME}_{}",
+        tracking_data
+            .refund_id
+            .as_deref()
+            .unwrap_or(&tracking_data.payment_id)
+    )
+}
+
+/// Schedules a retry of the tax transaction. Failures are logged, as the transaction can still be
+/// recorded with the tax connector from its dashboard.
+async fn add_tax_transaction_task(state: &SessionState, tracking_data: TaxTransactionTrackingData) {
+    let task_id = generate_task_id_for_tax_transaction_workflow(&tracking_data);
+    let schedule_time = core_utils::get_process_tracker_retry_schedule_time(
+        state.store.as_ref(),
+        TAX_TRANSACTION_RETRY_MAPPING_KEY,
+        &tracking_data.merchant_id,
+        0,
+    )
+    .await
+    .unwrap_or_else(common_utils::date_time::now);
+    let process_tracker_entry = match storage::ProcessTrackerNew::new(
+        task_id.clone(),
+        TAX_TRANSACTION_NAME,
+        TAX_TRANSACTION_RUNNER,
+        [TAX_TRANSACTION_TAG],
+        tracking_data,
+        schedule_time,
+    ) {
+        Ok(process_tracker_entry) => process_tracker_entry,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to construct tax transaction process tracker task"
+            );
+            return;
+        }
+    };
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => metrics::TASKS_ADDED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("flow", "TaxTransaction")]),
+        ),
+        // A retry of the transaction is already scheduled
+        Err(error) if error.current_context().is_db_unique_violation() => {}
+        Err(error) => logger::error!(
+            ?error,
+            %task_id,
+            "Failed while inserting tax transaction task to process_tracker"
+        ),
+    }
+}
+
+pub async fn start_tax_transaction_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: TaxTransactionTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("TaxTransactionTrackingData")?;
+
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let result = match &tracking_data.refund_id {
+        None => {
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    key_manager_state,
+                    &tracking_data.payment_id,
+                    &tracking_data.merchant_id,
+                    &key_store,
+                    storage_scheme,
+                )
+                .await?;
+            commit_tax_transaction(state, &key_store, storage_scheme, payment_intent)
+                .await
+                .map(|_| ())
+        }
+        Some(refund_id) => {
+            let refund = db
+                .find_refund_by_merchant_id_refund_id(
+                    &tracking_data.merchant_id,
+                    refund_id,
+                    storage_scheme,
+                )
+                .await?;
+            reverse_tax_transaction(state, &key_store, storage_scheme, &refund)
+                .await
+                .map(|_| ())
+        }
+    };
+
+    match result {
+        Ok(()) => {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process.clone(),
+                    business_status::COMPLETED_BY_PT,
+                )
+                .await?
+        }
+        Err(error) => {
+            logger::error!(?error, %process.id, "Failed to retry the tax transaction");
+            core_utils::retry_process_tracker_task(
+                db,
+                TAX_TRANSACTION_RETRY_MAPPING_KEY,
+                &tracking_data.merchant_id,
+                process.clone(),
+            )
+            .await?
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn tax_details(order_tax_amount: i64, committed_tax_amount: Option<i64>) -> TaxDetails {
+        TaxDetails {
+            connector: api_enums::TaxConnectors::Taxjar,
+            merchant_connector_id: "mca_1".to_string(),
+            taxable_amount: MinorUnit::new(10000),
+            order_tax_amount: MinorUnit::new(order_tax_amount),
+            breakdown: vec![],
+            committed_transaction_id: Some("pay_1".to_string()),
+            committed_tax_amount: committed_tax_amount.map(MinorUnit::new),
+        }
+    }
+
+    fn refund(refund_id: &str, tax_reversal: Option<i64>) -> storage::Refund {
+        storage::Refund {
+            total_amount: MinorUnit::new(10000),
+            tax_reversal: tax_reversal.map(|tax_amount| {
+                TaxReversal {
+                    connector_transaction_id: format!("{refund_id}_reversal"),
+                    tax_amount: MinorUnit::new(tax_amount),
+                }
+                .encode_to_value()
+                .unwrap()
+            }),
+            ..storage::refund::test_utils::get_refund(
+                refund_id,
+                storage_enums::RefundStatus::Success,
+                1000,
+            )
+        }
+    }
+
+    #[test]
+    fn proportional_amount_rounds_towards_zero() {
+        let amount = get_proportional_amount(
+            MinorUnit::new(475),
+            MinorUnit::new(1000),
+            MinorUnit::new(3000),
+        )
+        .unwrap();
+        assert_eq!(amount, MinorUnit::new(158));
+    }
+
+    #[test]
+    fn proportional_amount_fails_for_zero_denominator() {
+        assert!(get_proportional_amount(
+            MinorUnit::new(475),
+            MinorUnit::new(1000),
+            MinorUnit::zero()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn order_amount_sums_line_amounts() {
+        let order_details = vec![
+            OrderDetailsWithAmount {
+                product_name: "shirt".to_string(),
+                quantity: 2,
+                amount: 1500,
+                ..Default::default()
+            },
+            OrderDetailsWithAmount {
+                product_name: "cap".to_string(),
+                quantity: 1,
+                amount: 700,
+                ..Default::default()
+            },
+        ];
+        assert_eq!(
+            get_order_amount(&order_details).unwrap(),
+            MinorUnit::new(3700)
+        );
+    }
+
+    #[test]
+    fn order_amount_fails_on_overflow() {
+        let order_details = vec![OrderDetailsWithAmount {
+            product_name: "shirt".to_string(),
+            quantity: 2,
+            amount: i64::MAX,
+            ..Default::default()
+        }];
+        assert!(get_order_amount(&order_details).is_err());
+    }
+
+    #[test]
+    fn reversed_tax_amount_excludes_the_refund_being_reversed() {
+        let refunds = vec![
+            refund("ref_1", Some(40)),
+            refund("ref_2", None),
+            refund("ref_3", Some(25)),
+        ];
+        assert_eq!(
+            get_reversed_tax_amount(&refunds, "ref_3").unwrap(),
+            MinorUnit::new(40)
+        );
+        assert_eq!(
+            get_reversed_tax_amount(&refunds, "ref_2").unwrap(),
+            MinorUnit::new(65)
+        );
+    }
+
+    #[test]
+    fn reversal_is_proportional_to_the_committed_tax_of_a_partial_capture() {
+        // Half of the payment was captured, committing half of the order tax
+        let tax_amount = get_reversal_tax_amount(
+            &tax_details(800, Some(400)),
+            MinorUnit::new(5000),
+            MinorUnit::new(2500),
+            MinorUnit::zero(),
+        )
+        .unwrap();
+        assert_eq!(tax_amount, MinorUnit::new(200));
+    }
+
+    #[test]
+    fn reversal_is_capped_to_the_unreversed_tax() {
+        let tax_amount = get_reversal_tax_amount(
+            &tax_details(800, None),
+            MinorUnit::new(10000),
+            MinorUnit::new(5000),
+            MinorUnit::new(700),
+        )
+        .unwrap();
+        assert_eq!(tax_amount, MinorUnit::new(100));
+    }
+}
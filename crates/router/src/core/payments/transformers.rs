@@ -18,7 +18,7 @@ use crate::{
     connector::{Helcim, Nexinets},
     core::{
         errors::{self, RouterResponse, RouterResult},
-        payments::{self, helpers},
+        payments::{self, helpers, split_payments, tax_calculation},
         utils as core_utils,
     },
     headers::X_PAYMENT_CONFIRM_SOURCE,
@@ -414,6 +414,8 @@ where
             field_name: "amount",
         })?;
     let mandate_id = payment_attempt.mandate_id.clone();
+    let tax_details = tax_calculation::get_tax_details(&payment_intent)?;
+    let split_payments = split_payments::get_split_payments(&payment_intent)?;
     let refunds_response = if payment_data.refunds.is_empty() {
         None
     } else {
@@ -902,7 +904,10 @@ where
                 .set_updated(Some(payment_intent.modified_at))
                 .set_charges(charges_response)
                 .set_frm_metadata(payment_intent.frm_metadata)
+                .set_sca_exemption(payment_attempt.sca_exemption)
                 .set_merchant_order_reference_id(payment_intent.merchant_order_reference_id)
+                .set_tax_details(tax_details)
+                .set_split_payments(split_payments)
                 .to_owned(),
             headers,
         ))
@@ -1059,6 +1064,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             setup_future_usage: pi.setup_future_usage,
             capture_method: pa.capture_method,
             authentication_type: pa.authentication_type,
+            sca_exemption: pa.sca_exemption,
             connector_transaction_id: pa.connector_transaction_id,
             attempt_count: pi.attempt_count,
             profile_id: pi.profile_id,
@@ -1284,6 +1290,22 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             })
             .transpose()?;
 
+        let l2_l3_data = additional_data
+            .payment_data
+            .payment_intent
+            .l2_l3_data
+            .map(|l2_l3_data| {
+                l2_l3_data
+                    .parse_value::<api_models::payments::L2L3Data>("L2L3Data")
+                    .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "l2_l3_data",
+                    })
+                    .attach_printable("Unable to parse L2L3Data")
+            })
+            .transpose()?;
+
+        let split_payments = split_payments::get_split_payments(&payment_data.payment_intent)?;
+
         let complete_authorize_url = Some(helpers::create_complete_authorize_url(
             router_base_url,
             attempt,
@@ -1389,8 +1411,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 .transpose()?,
             customer_acceptance: payment_data.customer_acceptance,
             charges,
+            sca_exemption: payment_data.payment_attempt.sca_exemption,
             merchant_order_reference_id,
             integrity_object: None,
+            l2_l3_data,
+            split_payments,
         })
     }
 }
@@ -1,4 +1,5 @@
 pub mod access_token;
+pub mod batch;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
@@ -40,6 +41,7 @@ use crate::{
         errors::{
             self, ConnectorErrorExt, CustomResult, RouterResponse, RouterResult, StorageErrorExt,
         },
+        ledger,
         payments::{self, customers, helpers as payment_helpers},
         utils as core_utils,
     },
@@ -224,7 +226,7 @@ pub async fn make_connector_decision(
                 if config_multiple_connector_bool && payout_data.should_call_gsm() {
                     Box::pin(retry::do_gsm_multiple_connector_actions(
                         state,
-                        connectors,
+                        &mut connectors,
                         connector_data.clone(),
                         payout_data,
                         merchant_account,
@@ -233,6 +235,23 @@ pub async fn make_connector_decision(
                     .await?;
                 }
 
+                let config_fallback_bool = retry::config_should_fallback_payout_connector(
+                    &*state.store,
+                    merchant_account.get_id(),
+                )
+                .await;
+
+                if config_fallback_bool && payout_data.should_call_gsm() {
+                    Box::pin(retry::do_fallback_connector_actions(
+                        state,
+                        &mut connectors,
+                        payout_data,
+                        merchant_account,
+                        key_store,
+                    ))
+                    .await?;
+                }
+
                 let config_single_connector_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_account.get_id(),
@@ -2114,6 +2133,9 @@ pub async fn fulfill_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payouts in db")?;
+            if let Err(error) = ledger::record_payout_entries(state, &payout_data.payouts).await {
+                logger::error!(?error, "Failed to record the ledger entries of the payout");
+            }
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -0,0 +1,973 @@
+use std::collections::HashSet;
+
+use api_models::payouts::{
+    PayoutBatchApproveRequest, PayoutBatchCreateRequest, PayoutBatchListRequest,
+    PayoutBatchRejectRequest, PayoutBatchResponse,
+};
+use common_utils::{ext_traits::ValueExt, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use serde::Serialize;
+
+use super::{helpers, payouts_create_core};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::payouts,
+        domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+    utils,
+};
+
+const PAYOUT_BATCH_TASK: &str = "EXECUTE_PAYOUT_BATCH";
+const PAYOUT_BATCH_TAG: &str = "PAYOUT_BATCH";
+/// Config key of the schedule on which payouts still being processed are checked again
+const PAYOUT_BATCH_RETRY_MAPPING_KEY: &str = "pt_mapping_payout_batch";
+
+/// Maximum number of payouts accepted in a single batch
+const MAX_PAYOUT_BATCH_SIZE: usize = 5_000;
+/// Number of item errors returned to the merchant when validation fails
+const MAX_REPORTED_ITEM_ERRORS: usize = 20;
+/// Items are inserted in chunks to stay well within the bind parameter limit of postgres
+const PAYOUT_BATCH_ITEM_INSERT_CHUNK_SIZE: usize = 1_000;
+/// Number of payouts created in a single run of the scheduler task
+const PAYOUT_BATCH_PROCESSING_CHUNK_SIZE: i64 = 50;
+
+#[derive(Debug, Serialize)]
+struct PayoutBatchReportRecord {
+    line_number: i32,
+    customer_id: id_type::CustomerId,
+    amount: MinorUnit,
+    currency: enums::Currency,
+    status: enums::PayoutBatchItemStatus,
+    payout_id: Option<String>,
+    payout_status: Option<enums::PayoutStatus>,
+    error_message: Option<String>,
+}
+
+impl From<storage::PayoutBatchItem> for PayoutBatchReportRecord {
+    fn from(item: storage::PayoutBatchItem) -> Self {
+        Self {
+            line_number: item.line_number,
+            customer_id: item.customer_id,
+            amount: item.amount,
+            currency: item.currency,
+            status: item.status,
+            payout_id: item.payout_id,
+            payout_status: item.payout_status,
+            error_message: item.error_message,
+        }
+    }
+}
+
+impl ForeignFrom<storage::PayoutBatch> for PayoutBatchResponse {
+    fn foreign_from(batch: storage::PayoutBatch) -> Self {
+        Self {
+            batch_id: batch.batch_id,
+            merchant_id: batch.merchant_id,
+            profile_id: batch.profile_id,
+            status: batch.status,
+            total_count: batch.total_count,
+            succeeded_count: batch.succeeded_count,
+            failed_count: batch.failed_count,
+            created_by: batch.created_by,
+            reviewed_by: batch.reviewed_by,
+            reviewed_at: batch.reviewed_at,
+            review_comment: batch.review_comment,
+            scheduled_at: batch.scheduled_at,
+            created_at: batch.created_at,
+            modified_at: batch.modified_at,
+        }
+    }
+}
+
+/// Validates every item of the batch before anything is persisted, so that a batch is either
+/// accepted as a whole or rejected with the list of offending items.
+#[instrument(skip_all)]
+async fn validate_payout_batch_items(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &PayoutBatchCreateRequest,
+) -> RouterResult<()> {
+    utils::when(req.items.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A payout batch must contain at least one payout".to_string(),
+        }))
+    })?;
+    utils::when(req.items.len() > MAX_PAYOUT_BATCH_SIZE, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("A payout batch can contain at most {MAX_PAYOUT_BATCH_SIZE} payouts"),
+        }))
+    })?;
+
+    let db = &*state.store;
+    let mut item_errors = Vec::new();
+    let mut validated_customers = HashSet::new();
+
+    for (index, item) in req.items.iter().enumerate() {
+        let line_number = index + 1;
+        if item.amount <= MinorUnit::new(0) {
+            item_errors.push(format!(
+                "item {line_number}: amount must be a positive integer"
+            ));
+        }
+
+        if !validated_customers.insert(item.customer_id.clone()) {
+            continue;
+        }
+
+        let customer = db
+            .find_customer_optional_by_customer_id_merchant_id(
+                &state.into(),
+                &item.customer_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching customer for payout batch")?;
+        if customer.is_none() {
+            item_errors.push(format!(
+                "item {line_number}: customer {} does not exist",
+                item.customer_id.get_string_repr()
+            ));
+        }
+    }
+
+    if item_errors.is_empty() {
+        return Ok(());
+    }
+
+    let total_errors = item_errors.len();
+    item_errors.truncate(MAX_REPORTED_ITEM_ERRORS);
+    Err(errors::ApiErrorResponse::InvalidRequestData {
+        message: format!(
+            "{total_errors} item(s) failed validation: {}",
+            item_errors.join("; ")
+        ),
+    }
+    .into())
+}
+
+#[instrument(skip_all)]
+pub async fn create_payout_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
+    user_id: String,
+    req: PayoutBatchCreateRequest,
+) -> RouterResponse<PayoutBatchResponse> {
+    let db = &*state.store;
+    let profile_id = profile_id.or(req.profile_id.clone());
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        profile_id.as_ref(),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    validate_payout_batch_items(&state, &merchant_account, &key_store, &req).await?;
+
+    let now = common_utils::date_time::now();
+    let batch_id = common_utils::generate_id_with_default_len("payout_batch");
+    let total_count = i32::try_from(req.items.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payout batch size does not fit in an i32")?;
+
+    let payout_batch = db
+        .insert_payout_batch(storage::PayoutBatchNew {
+            batch_id: batch_id.clone(),
+            merchant_id: merchant_account.get_id().to_owned(),
+            profile_id,
+            status: enums::PayoutBatchStatus::PendingApproval,
+            total_count,
+            succeeded_count: 0,
+            failed_count: 0,
+            created_by: user_id,
+            reviewed_by: None,
+            reviewed_at: None,
+            review_comment: None,
+            scheduled_at: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting payout batch")?;
+
+    let items = req
+        .items
+        .into_iter()
+        .zip(1..)
+        .map(|(item, line_number)| storage::PayoutBatchItemNew {
+            batch_id: batch_id.clone(),
+            line_number,
+            customer_id: item.customer_id,
+            amount: item.amount,
+            currency: item.currency,
+            payout_type: item.payout_type,
+            payout_token: item.payout_token,
+            description: item.description,
+            status: enums::PayoutBatchItemStatus::Pending,
+            payout_id: None,
+            payout_status: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .collect::<Vec<_>>();
+
+    for chunk in items.chunks(PAYOUT_BATCH_ITEM_INSERT_CHUNK_SIZE) {
+        db.insert_payout_batch_items(chunk.to_vec())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while inserting payout batch items")?;
+    }
+
+    Ok(ApplicationResponse::Json(
+        PayoutBatchResponse::foreign_from(payout_batch),
+    ))
+}
+
+/// Fetches a batch which is still awaiting review, making sure that the reviewer is not the
+/// user who submitted it.
+async fn get_payout_batch_for_review(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+    user_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    let payout_batch = db
+        .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payout batch not found".to_string(),
+        })?;
+
+    utils::when(
+        payout_batch.status != enums::PayoutBatchStatus::PendingApproval,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Payout batch cannot be reviewed as it is in {} status",
+                    payout_batch.status
+                ),
+            }))
+        },
+    )?;
+
+    utils::when(payout_batch.created_by == user_id, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payout batch must be reviewed by a user other than the one who submitted it"
+                .to_string(),
+        }))
+    })?;
+
+    Ok(payout_batch)
+}
+
+#[instrument(skip_all)]
+pub async fn approve_payout_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    req: PayoutBatchApproveRequest,
+    locale: &str,
+) -> RouterResponse<PayoutBatchResponse> {
+    let db = &*state.store;
+    let payout_batch =
+        get_payout_batch_for_review(db, merchant_account.get_id(), &req.batch_id, &user_id).await?;
+
+    let now = common_utils::date_time::now();
+    let scheduled_at = req.scheduled_at.unwrap_or(now);
+    utils::when(scheduled_at < now, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "scheduled_at",
+        }))
+        .attach_printable("scheduled_at cannot be in the past")
+    })?;
+
+    let process_tracker_entry = get_payout_batch_execution_task(
+        &payout_batch,
+        locale,
+        scheduled_at,
+        storage::ProcessTrackerRunner::PayoutBatchWorkflow,
+    )?;
+
+    // The batch is scheduled in the same transaction as the insertion of the task executing it,
+    // so that an approved batch is never left without one
+    let payout_batch = db
+        .update_payout_batch_by_merchant_id_batch_id_status_and_insert_process(
+            merchant_account.get_id(),
+            &payout_batch.batch_id,
+            enums::PayoutBatchStatus::PendingApproval,
+            storage::PayoutBatchUpdate::ReviewUpdate {
+                status: enums::PayoutBatchStatus::Scheduled,
+                reviewed_by: user_id,
+                review_comment: req.comment,
+                scheduled_at: Some(scheduled_at),
+            },
+            process_tracker_entry,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payout batch has already been reviewed".to_string(),
+        })
+        .attach_printable("Failed while approving payout batch")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "PayoutBatch")]),
+    );
+
+    Ok(ApplicationResponse::Json(
+        PayoutBatchResponse::foreign_from(payout_batch),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn reject_payout_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    req: PayoutBatchRejectRequest,
+) -> RouterResponse<PayoutBatchResponse> {
+    let db = &*state.store;
+    let payout_batch =
+        get_payout_batch_for_review(db, merchant_account.get_id(), &req.batch_id, &user_id).await?;
+
+    let payout_batch = db
+        .update_payout_batch_by_merchant_id_batch_id_status(
+            merchant_account.get_id(),
+            &payout_batch.batch_id,
+            enums::PayoutBatchStatus::PendingApproval,
+            storage::PayoutBatchUpdate::ReviewUpdate {
+                status: enums::PayoutBatchStatus::Rejected,
+                reviewed_by: user_id,
+                review_comment: req.comment,
+                scheduled_at: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payout batch has already been reviewed".to_string(),
+        })
+        .attach_printable("Failed while rejecting payout batch")?;
+
+    Ok(ApplicationResponse::Json(
+        PayoutBatchResponse::foreign_from(payout_batch),
+    ))
+}
+
+fn get_payout_batch_execution_task(
+    payout_batch: &storage::PayoutBatch,
+    locale: &str,
+    schedule_time: time::PrimitiveDateTime,
+    runner: storage::ProcessTrackerRunner,
+) -> RouterResult<storage::ProcessTrackerNew> {
+    let task = PAYOUT_BATCH_TASK;
+    let process_tracker_id = format!("{runner}_{task}_{}", payout_batch.batch_id);
+    let tracking_data = storage::PayoutBatchTrackingData {
+        batch_id: payout_batch.batch_id.clone(),
+        merchant_id: payout_batch.merchant_id.clone(),
+        locale: locale.to_string(),
+    };
+    storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        [PAYOUT_BATCH_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payout batch process tracker task")
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payout_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    batch_id: String,
+) -> RouterResponse<PayoutBatchResponse> {
+    let payout_batch = state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(merchant_account.get_id(), &batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payout batch not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        PayoutBatchResponse::foreign_from(payout_batch),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_payout_batches(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: PayoutBatchListRequest,
+) -> RouterResponse<Vec<PayoutBatchResponse>> {
+    let limit = req
+        .limit
+        .unwrap_or(common_utils::consts::default_payouts_list_limit().into());
+    utils::when(
+        !(1..=i64::from(common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_GET)).contains(&limit),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between 1 and {}",
+                    common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_GET
+                ),
+            }))
+        },
+    )?;
+
+    let payout_batches = state
+        .store
+        .list_payout_batches_by_merchant_id(merchant_account.get_id(), Some(limit), req.offset)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing payout batches")?;
+
+    Ok(ApplicationResponse::Json(
+        payout_batches
+            .into_iter()
+            .map(PayoutBatchResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn download_payout_batch_report(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    batch_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let db = &*state.store;
+    let payout_batch = db
+        .find_payout_batch_by_merchant_id_batch_id(merchant_account.get_id(), &batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payout batch not found".to_string(),
+        })?;
+
+    let items = db
+        .list_payout_batch_items_by_batch_id(&payout_batch.batch_id, None, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching payout batch items")?;
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for item in items {
+        writer
+            .serialize(PayoutBatchReportRecord::from(item))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize payout batch report row")?;
+    }
+    let file = writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build payout batch report")?;
+
+    Ok(ApplicationResponse::FileData((file, mime::TEXT_CSV)))
+}
+
+fn get_aggregate_batch_status(
+    total_count: i32,
+    succeeded_count: i32,
+    failed_count: i32,
+) -> enums::PayoutBatchStatus {
+    if succeeded_count == total_count {
+        enums::PayoutBatchStatus::Completed
+    } else if failed_count == total_count {
+        enums::PayoutBatchStatus::Failed
+    } else {
+        enums::PayoutBatchStatus::PartiallyCompleted
+    }
+}
+
+/// Only payouts which reached a final status are counted as succeeded or failed, payouts still
+/// being processed are checked again later
+fn get_payout_batch_item_status(
+    payout_status: enums::PayoutStatus,
+) -> enums::PayoutBatchItemStatus {
+    if payout_status == enums::PayoutStatus::Success {
+        enums::PayoutBatchItemStatus::Succeeded
+    } else if helpers::is_payout_terminal_state(payout_status) {
+        enums::PayoutBatchItemStatus::Failed
+    } else {
+        enums::PayoutBatchItemStatus::Submitted
+    }
+}
+
+/// Returns the number of succeeded and failed payouts the update of an item adds to the batch
+fn get_item_update_counts(item_update: &storage::PayoutBatchItemUpdate) -> (i32, i32) {
+    match item_update {
+        storage::PayoutBatchItemUpdate::PayoutCreated { status, .. } => match status {
+            enums::PayoutBatchItemStatus::Succeeded => (1, 0),
+            enums::PayoutBatchItemStatus::Failed => (0, 1),
+            enums::PayoutBatchItemStatus::Pending | enums::PayoutBatchItemStatus::Submitted => {
+                (0, 0)
+            }
+        },
+        storage::PayoutBatchItemUpdate::PayoutFailed { .. } => (0, 1),
+    }
+}
+
+async fn get_item_update_for_existing_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_id: String,
+) -> RouterResult<storage::PayoutBatchItemUpdate> {
+    let payout = state
+        .store
+        .find_payout_by_merchant_id_payout_id(
+            merchant_account.get_id(),
+            &payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    Ok(storage::PayoutBatchItemUpdate::PayoutCreated {
+        status: get_payout_batch_item_status(payout.status),
+        payout_id,
+        payout_status: payout.status,
+        error_message: None,
+    })
+}
+
+/// Creates payouts for the next set of pending items of the batch and records the outcome of
+/// each item. Once every item has a payout, the payouts still being processed are checked for
+/// their final status. Returns the batch with the updated counts and status, along with whether
+/// items are left without a payout.
+#[instrument(skip_all)]
+async fn process_payout_batch_items(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_batch: storage::PayoutBatch,
+    locale: &String,
+) -> RouterResult<(storage::PayoutBatch, bool)> {
+    let db = &*state.store;
+    let items = db
+        .list_payout_batch_items_by_batch_id_status(
+            &payout_batch.batch_id,
+            enums::PayoutBatchItemStatus::Pending,
+            Some(PAYOUT_BATCH_PROCESSING_CHUNK_SIZE),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching pending payout batch items")?;
+
+    let is_last_chunk = i64::try_from(items.len())
+        .map(|count| count < PAYOUT_BATCH_PROCESSING_CHUNK_SIZE)
+        .unwrap_or(true);
+    let (mut succeeded_count, mut failed_count) =
+        (payout_batch.succeeded_count, payout_batch.failed_count);
+
+    for item in items {
+        // A deterministic payout id keeps the item idempotent if the task is picked up again
+        // after the payout was created but before the item was updated
+        let payout_id = format!("{}_{}", item.batch_id, item.line_number);
+        let payout_request = payouts::PayoutCreateRequest {
+            payout_id: Some(payout_id.clone()),
+            amount: Some(item.amount.into()),
+            currency: Some(item.currency),
+            customer_id: Some(item.customer_id.clone()),
+            payout_type: item.payout_type,
+            payout_token: item.payout_token.clone(),
+            description: item.description.clone(),
+            profile_id: payout_batch.profile_id.clone(),
+            confirm: Some(true),
+            auto_fulfill: Some(true),
+            ..Default::default()
+        };
+
+        let item_update = match Box::pin(payouts_create_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            payout_request,
+            locale,
+        ))
+        .await
+        {
+            Ok(ApplicationResponse::Json(payout)) => {
+                storage::PayoutBatchItemUpdate::PayoutCreated {
+                    status: get_payout_batch_item_status(payout.status),
+                    payout_id: payout.payout_id,
+                    payout_status: payout.status,
+                    error_message: payout.error_message,
+                }
+            }
+            Ok(_) => storage::PayoutBatchItemUpdate::PayoutFailed {
+                error_message: "Unexpected response while creating payout".to_string(),
+            },
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::DuplicatePayout { .. }
+                ) =>
+            {
+                get_item_update_for_existing_payout(state, merchant_account, payout_id).await?
+            }
+            Err(error) => {
+                logger::info!(batch_id = %item.batch_id, line_number = item.line_number, ?error, "Payout creation failed for payout batch item");
+                storage::PayoutBatchItemUpdate::PayoutFailed {
+                    error_message: error.current_context().to_string(),
+                }
+            }
+        };
+
+        let (succeeded, failed) = get_item_update_counts(&item_update);
+        succeeded_count += succeeded;
+        failed_count += failed;
+
+        db.update_payout_batch_item_by_batch_id_line_number(
+            &item.batch_id,
+            item.line_number,
+            item_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while updating payout batch item")?;
+    }
+
+    let mut submitted_count = 0;
+    if is_last_chunk {
+        let submitted_items = db
+            .list_payout_batch_items_by_batch_id_status(
+                &payout_batch.batch_id,
+                enums::PayoutBatchItemStatus::Submitted,
+                None,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching submitted payout batch items")?;
+
+        for item in submitted_items {
+            let payout_id = item
+                .payout_id
+                .clone()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Submitted payout batch item has no payout_id")?;
+            let item_update =
+                get_item_update_for_existing_payout(state, merchant_account, payout_id).await?;
+            if matches!(
+                item_update,
+                storage::PayoutBatchItemUpdate::PayoutCreated {
+                    status: enums::PayoutBatchItemStatus::Submitted,
+                    ..
+                }
+            ) {
+                submitted_count += 1;
+                continue;
+            }
+
+            let (succeeded, failed) = get_item_update_counts(&item_update);
+            succeeded_count += succeeded;
+            failed_count += failed;
+
+            db.update_payout_batch_item_by_batch_id_line_number(
+                &item.batch_id,
+                item.line_number,
+                item_update,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while updating payout batch item")?;
+        }
+    }
+
+    let status = if is_last_chunk && submitted_count == 0 {
+        get_aggregate_batch_status(payout_batch.total_count, succeeded_count, failed_count)
+    } else {
+        enums::PayoutBatchStatus::Processing
+    };
+
+    let payout_batch = db
+        .update_payout_batch_by_merchant_id_batch_id(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+            storage::PayoutBatchUpdate::ProgressUpdate {
+                status,
+                succeeded_count,
+                failed_count,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while updating payout batch")?;
+
+    Ok((payout_batch, !is_last_chunk))
+}
+
+#[instrument(skip_all)]
+pub async fn start_payout_batch_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::PayoutBatchTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("PayoutBatchTrackingData")?;
+
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+
+    let payout_batch = db
+        .find_payout_batch_by_merchant_id_batch_id(
+            &tracking_data.merchant_id,
+            &tracking_data.batch_id,
+        )
+        .await?;
+
+    let (payout_batch, has_pending_items) = match payout_batch.status {
+        enums::PayoutBatchStatus::Scheduled | enums::PayoutBatchStatus::Processing => {
+            Box::pin(process_payout_batch_items(
+                state,
+                &merchant_account,
+                &key_store,
+                payout_batch,
+                &tracking_data.locale,
+            ))
+            .await?
+        }
+        enums::PayoutBatchStatus::PendingApproval
+        | enums::PayoutBatchStatus::Rejected
+        | enums::PayoutBatchStatus::Completed
+        | enums::PayoutBatchStatus::PartiallyCompleted
+        | enums::PayoutBatchStatus::Failed => (payout_batch, false),
+    };
+
+    if payout_batch.status != enums::PayoutBatchStatus::Processing {
+        db.as_scheduler()
+            .finish_process_with_business_status(process.clone(), "COMPLETED_BY_PT")
+            .await?;
+    } else if has_pending_items {
+        // Pick up the next chunk of payouts in the following scheduler cycle
+        db.as_scheduler()
+            .reset_process(process.clone(), common_utils::date_time::now())
+            .await?;
+    } else {
+        // Check the payouts still being processed again later. Once the retries are exhausted,
+        // the batch is completed with the payouts which reached a final status by then.
+        match core_utils::get_process_tracker_retry_schedule_time(
+            db,
+            PAYOUT_BATCH_RETRY_MAPPING_KEY,
+            &payout_batch.merchant_id,
+            process.retry_count + 1,
+        )
+        .await
+        {
+            Some(schedule_time) => {
+                db.as_scheduler()
+                    .retry_process(process.clone(), schedule_time)
+                    .await?;
+            }
+            None => {
+                db.update_payout_batch_by_merchant_id_batch_id(
+                    &payout_batch.merchant_id,
+                    &payout_batch.batch_id,
+                    storage::PayoutBatchUpdate::ProgressUpdate {
+                        status: get_aggregate_batch_status(
+                            payout_batch.total_count,
+                            payout_batch.succeeded_count,
+                            payout_batch.failed_count,
+                        ),
+                        succeeded_count: payout_batch.succeeded_count,
+                        failed_count: payout_batch.failed_count,
+                    },
+                )
+                .await?;
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process.clone(),
+                        storage::business_status::RETRIES_EXCEEDED,
+                    )
+                    .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use scheduler::db::process_tracker::ProcessTrackerInterface;
+
+    use super::*;
+    use crate::{
+        db::{payout_batch::PayoutBatchInterface, MockDb},
+        types::storage::enums::{PayoutBatchItemStatus, PayoutBatchStatus, PayoutStatus},
+    };
+
+    #[tokio::test]
+    async fn test_payout_batch_is_scheduled_along_with_its_execution_task() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+        let now = common_utils::date_time::now();
+        let payout_batch = db
+            .insert_payout_batch(storage::PayoutBatchNew {
+                batch_id: "payout_batch_1".to_string(),
+                merchant_id: merchant_id.clone(),
+                profile_id: None,
+                status: PayoutBatchStatus::PendingApproval,
+                total_count: 1,
+                succeeded_count: 0,
+                failed_count: 0,
+                created_by: "user_1".to_string(),
+                reviewed_by: None,
+                reviewed_at: None,
+                review_comment: None,
+                scheduled_at: None,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .unwrap();
+        let approve = || async {
+            db.update_payout_batch_by_merchant_id_batch_id_status_and_insert_process(
+                &merchant_id,
+                &payout_batch.batch_id,
+                PayoutBatchStatus::PendingApproval,
+                storage::PayoutBatchUpdate::ReviewUpdate {
+                    status: PayoutBatchStatus::Scheduled,
+                    reviewed_by: "user_2".to_string(),
+                    review_comment: None,
+                    scheduled_at: Some(now),
+                },
+                get_payout_batch_execution_task(
+                    &payout_batch,
+                    "en",
+                    now,
+                    storage::ProcessTrackerRunner::PayoutBatchWorkflow,
+                )
+                .unwrap(),
+            )
+            .await
+        };
+
+        assert_eq!(
+            approve().await.unwrap().status,
+            PayoutBatchStatus::Scheduled
+        );
+        let process_tracker_id = format!(
+            "{}_{PAYOUT_BATCH_TASK}_{}",
+            storage::ProcessTrackerRunner::PayoutBatchWorkflow,
+            payout_batch.batch_id
+        );
+        assert_eq!(
+            db.find_process_by_id(&process_tracker_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .schedule_time,
+            Some(now)
+        );
+
+        // A batch which has already been reviewed is left as it is
+        assert!(approve()
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+        assert_eq!(
+            db.list_payout_batches_by_merchant_id(&merchant_id, None, None)
+                .await
+                .unwrap()[0]
+                .status,
+            PayoutBatchStatus::Scheduled
+        );
+    }
+
+    #[test]
+    fn test_aggregate_payout_batch_status() {
+        assert_eq!(
+            get_aggregate_batch_status(10, 10, 0),
+            PayoutBatchStatus::Completed
+        );
+        assert_eq!(
+            get_aggregate_batch_status(10, 0, 10),
+            PayoutBatchStatus::Failed
+        );
+        assert_eq!(
+            get_aggregate_batch_status(10, 7, 3),
+            PayoutBatchStatus::PartiallyCompleted
+        );
+        // Payouts which did not reach a final status are neither succeeded nor failed
+        assert_eq!(
+            get_aggregate_batch_status(10, 0, 0),
+            PayoutBatchStatus::PartiallyCompleted
+        );
+        assert_eq!(
+            get_aggregate_batch_status(10, 9, 0),
+            PayoutBatchStatus::PartiallyCompleted
+        );
+    }
+
+    #[test]
+    fn test_payout_batch_item_status() {
+        assert_eq!(
+            get_payout_batch_item_status(PayoutStatus::Success),
+            PayoutBatchItemStatus::Succeeded
+        );
+        for payout_status in [
+            PayoutStatus::Failed,
+            PayoutStatus::Cancelled,
+            PayoutStatus::Ineligible,
+            PayoutStatus::Reversed,
+        ] {
+            assert_eq!(
+                get_payout_batch_item_status(payout_status),
+                PayoutBatchItemStatus::Failed
+            );
+        }
+        for payout_status in [
+            PayoutStatus::Pending,
+            PayoutStatus::Initiated,
+            PayoutStatus::RequiresFulfillment,
+        ] {
+            assert_eq!(
+                get_payout_batch_item_status(payout_status),
+                PayoutBatchItemStatus::Submitted
+            );
+        }
+    }
+}
@@ -779,7 +779,7 @@ pub async fn decide_payout_connector(
                     &state.conf.connectors,
                     &conn.connector.to_string(),
                     api::GetToken::Connector,
-                    payout_attempt.merchant_connector_id.clone(),
+                    conn.merchant_connector_id,
                 )
             })
             .collect::<CustomResult<Vec<_>, _>>()
@@ -829,7 +829,7 @@ pub async fn decide_payout_connector(
                     &state.conf.connectors,
                     &conn.connector.to_string(),
                     api::GetToken::Connector,
-                    payout_attempt.merchant_connector_id.clone(),
+                    conn.merchant_connector_id,
                 )
             })
             .collect::<CustomResult<Vec<_>, _>>()
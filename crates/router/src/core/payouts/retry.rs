@@ -23,7 +23,7 @@ use crate::{
 #[allow(clippy::too_many_arguments)]
 pub async fn do_gsm_multiple_connector_actions(
     state: &app::SessionState,
-    mut connectors: IntoIter<api::ConnectorData>,
+    connectors: &mut IntoIter<api::ConnectorData>,
     original_connector_data: api::ConnectorData,
     payout_data: &mut PayoutData,
     merchant_account: &domain::MerchantAccount,
@@ -60,7 +60,7 @@ pub async fn do_gsm_multiple_connector_actions(
                     break;
                 }
 
-                connector = super::get_next_connector(&mut connectors)?;
+                connector = super::get_next_connector(connectors)?;
 
                 Box::pin(do_retry(
                     &state.clone(),
@@ -150,6 +150,63 @@ pub async fn do_gsm_single_connector_actions(
     Ok(())
 }
 
+/// Falls back to the next connector returned by routing while the payout keeps failing with an
+/// error for which no GSM rule is configured. Errors with a GSM rule are left to the GSM flows.
+#[instrument(skip_all)]
+pub async fn do_fallback_connector_actions(
+    state: &app::SessionState,
+    connectors: &mut IntoIter<api::ConnectorData>,
+    payout_data: &mut PayoutData,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<()> {
+    while payout_data.should_call_gsm() {
+        let gsm = payouts::helpers::get_gsm_record(
+            state,
+            payout_data.payout_attempt.error_code.to_owned(),
+            payout_data.payout_attempt.error_message.to_owned(),
+            payout_data.payout_attempt.connector.to_owned(),
+            "payout_flow".to_string(),
+        )
+        .await;
+
+        let Some(connector) = get_fallback_connector(gsm.as_ref(), connectors) else {
+            break;
+        };
+        logger::info!(
+            connector = %connector.connector_name,
+            "falling back to the next routed connector for payout"
+        );
+
+        Box::pin(do_retry(
+            &state.clone(),
+            connector,
+            merchant_account,
+            key_store,
+            payout_data,
+        ))
+        .await?;
+    }
+    Ok(())
+}
+
+/// Returns the next routed connector to fall back to, unless a GSM rule is configured for the
+/// error of the payout or all the routed connectors have been tried
+fn get_fallback_connector(
+    gsm: Option<&storage::gsm::GatewayStatusMap>,
+    connectors: &mut IntoIter<api::ConnectorData>,
+) -> Option<api::ConnectorData> {
+    if gsm.is_some() {
+        return None;
+    }
+
+    let connector = connectors.next();
+    if connector.is_none() {
+        logger::info!("connectors exhausted for payout connector fallback");
+    }
+    connector
+}
+
 #[instrument(skip_all)]
 pub async fn get_retries(
     state: &app::SessionState,
@@ -321,6 +378,23 @@ pub async fn config_should_call_gsm_payout(
     }
 }
 
+pub async fn config_should_fallback_payout_connector(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> bool {
+    let key = merchant_id.get_should_fallback_payout_connector_key();
+    let config = db
+        .find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(error) => {
+            logger::error!(?error);
+            false
+        }
+    }
+}
+
 pub trait GsmValidation {
     // TODO : move this function to appropriate place later.
     fn should_call_gsm(&self) -> bool;
@@ -346,3 +420,87 @@ impl GsmValidation for PayoutData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::{configs::settings::Connectors, db::configs::ConfigInterface};
+
+    fn connectors(names: &[&str]) -> IntoIter<api::ConnectorData> {
+        names
+            .iter()
+            .map(|name| {
+                api::ConnectorData::get_payout_connector_by_name(
+                    &Connectors::default(),
+                    name,
+                    api::GetToken::Connector,
+                    None,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn gsm_rule() -> storage::gsm::GatewayStatusMap {
+        let now = common_utils::date_time::now();
+        storage::gsm::GatewayStatusMap {
+            connector: "adyen".to_string(),
+            flow: "payout_flow".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: "card_declined".to_string(),
+            message: "Card declined".to_string(),
+            status: "failure".to_string(),
+            router_error: None,
+            decision: "retry".to_string(),
+            created_at: now,
+            last_modified: now,
+            step_up_possible: false,
+            unified_code: None,
+            unified_message: None,
+        }
+    }
+
+    #[test]
+    fn test_fallback_goes_through_the_routed_connectors_in_order() {
+        let mut connectors = connectors(&["wise", "adyen"]);
+
+        let connector = get_fallback_connector(None, &mut connectors).unwrap();
+        assert_eq!(connector.connector_name, api_models::enums::Connector::Wise);
+        let connector = get_fallback_connector(None, &mut connectors).unwrap();
+        assert_eq!(
+            connector.connector_name,
+            api_models::enums::Connector::Adyen
+        );
+        assert!(get_fallback_connector(None, &mut connectors).is_none());
+    }
+
+    #[test]
+    fn test_fallback_leaves_errors_with_a_gsm_rule_to_the_gsm_flows() {
+        let mut connectors = connectors(&["wise"]);
+
+        assert!(get_fallback_connector(Some(&gsm_rule()), &mut connectors).is_none());
+        assert_eq!(connectors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_is_disabled_unless_configured() {
+        let db = storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+
+        assert!(!config_should_fallback_payout_connector(&db, &merchant_id).await);
+
+        db.insert_config(storage::ConfigNew {
+            key: merchant_id.get_should_fallback_payout_connector_key(),
+            config: "true".to_string(),
+        })
+        .await
+        .unwrap();
+        assert!(config_should_fallback_payout_connector(&db, &merchant_id).await);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod batch;
 pub mod validator;
 
 #[cfg(feature = "olap")]
@@ -6,7 +7,7 @@ use std::collections::HashMap;
 #[cfg(feature = "olap")]
 use api_models::admin::MerchantConnectorInfo;
 use common_utils::{
-    ext_traits::{AsyncExt, ValueExt},
+    ext_traits::{AsyncExt, Encode, ValueExt},
     types::MinorUnit,
 };
 use diesel_models::process_tracker::business_status;
@@ -23,7 +24,8 @@ use crate::{
     consts,
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
-        payments::{self, access_token, types::PaymentCharges},
+        ledger,
+        payments::{self, access_token, split_payments, tax_calculation, types::PaymentCharges},
         utils as core_utils,
     },
     db, logger,
@@ -350,9 +352,47 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    let response = reverse_tax_for_successful_refund(
+        state,
+        key_store,
+        storage_scheme,
+        payment_intent,
+        response,
+    )
+    .await;
+    record_ledger_entries_for_refund(state, &response).await;
     Ok(response)
 }
 
+/// Reverses the tax recorded for the payment with the tax connector, once the refund has succeeded
+async fn reverse_tax_for_successful_refund(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    payment_intent: &storage::PaymentIntent,
+    refund: storage::Refund,
+) -> storage::Refund {
+    if refund.refund_status == enums::RefundStatus::Success && payment_intent.tax_details.is_some()
+    {
+        tax_calculation::reverse_tax_transaction_or_schedule_retry(
+            state,
+            key_store,
+            storage_scheme,
+            refund,
+        )
+        .await
+    } else {
+        refund
+    }
+}
+
+/// Records the refund in the ledger of the merchant, once it has succeeded
+async fn record_ledger_entries_for_refund(state: &SessionState, refund: &storage::Refund) {
+    if let Err(error) = ledger::record_refund_entries(state, refund).await {
+        logger::error!(?error, "Failed to record the ledger entries of the refund");
+    }
+}
+
 pub fn check_refund_integrity<T, Request>(
     request: &Request,
     refund_response_data: &Result<types::RefundsResponseData, ErrorResponse>,
@@ -642,6 +682,15 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    let response = reverse_tax_for_successful_refund(
+        state,
+        key_store,
+        storage_scheme,
+        payment_intent,
+        response,
+    )
+    .await;
+    record_ledger_entries_for_refund(state, &response).await;
     Ok(response)
 }
 
@@ -799,6 +848,20 @@ pub async fn validate_and_create_refund(
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("No connector populated in payment attempt")?;
 
+    let split_refunds = split_payments::get_split_payments(payment_intent)?
+        .map(|split_payments_details| {
+            split_payments::get_refund_allocations(
+                &split_payments_details,
+                &all_refunds,
+                refund_amount,
+                payment_intent.amount,
+            )?
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize split refunds")
+        })
+        .transpose()?;
+
     let refund_create_req = storage::RefundNew::default()
         .set_refund_id(refund_id.to_string())
         .set_internal_reference_id(utils::generate_id(consts::ID_LENGTH, "refid"))
@@ -821,6 +884,7 @@ pub async fn validate_and_create_refund(
         .set_profile_id(payment_intent.profile_id.clone())
         .set_merchant_connector_id(payment_attempt.merchant_connector_id.clone())
         .set_charges(req.charges)
+        .set_split_refunds(split_refunds)
         .to_owned();
 
     let refund = match db
@@ -1050,6 +1114,18 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             connector: refund.connector,
             merchant_connector_id: refund.merchant_connector_id,
             charges: refund.charges,
+            tax_reversal: refund.tax_reversal.and_then(|tax_reversal| {
+                tax_reversal
+                    .parse_value("TaxReversal")
+                    .map_err(|error| logger::error!(?error, "Failed to parse tax reversal"))
+                    .ok()
+            }),
+            split_refunds: refund.split_refunds.and_then(|split_refunds| {
+                split_refunds
+                    .parse_value("SplitRefundAllocations")
+                    .map_err(|error| logger::error!(?error, "Failed to parse split refunds"))
+                    .ok()
+            }),
         }
     }
 }
@@ -0,0 +1,618 @@
+use std::collections::HashMap;
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use api_models::refunds::{
+    RefundBatchCreateRequest, RefundBatchListRequest, RefundBatchRecord, RefundBatchResponse,
+};
+use common_utils::{ext_traits::ValueExt, types::MinorUnit};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use serde::Serialize;
+
+use super::{refund_create_core, validator};
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::refunds,
+        domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const REFUND_BATCH_TASK: &str = "PROCESS_REFUND_BATCH";
+const REFUND_BATCH_TAG: &str = "REFUND_BATCH";
+
+/// Maximum number of rows accepted in a single upload
+const MAX_REFUND_BATCH_SIZE: usize = 10_000;
+/// Number of row errors returned to the merchant when validation fails
+const MAX_REPORTED_ROW_ERRORS: usize = 20;
+/// Rows are inserted in chunks to stay well within the bind parameter limit of postgres
+const REFUND_BATCH_ITEM_INSERT_CHUNK_SIZE: usize = 1_000;
+/// Number of refunds created in a single run of the scheduler task
+const REFUND_BATCH_PROCESSING_CHUNK_SIZE: i64 = 100;
+
+#[derive(Debug, MultipartForm)]
+pub struct RefundBatchForm {
+    #[multipart(limit = "5MB")]
+    pub file: Bytes,
+}
+
+#[derive(Debug, Serialize)]
+struct RefundBatchResultRecord {
+    line_number: i32,
+    payment_id: String,
+    amount: MinorUnit,
+    reason: Option<String>,
+    status: enums::RefundBatchItemStatus,
+    refund_id: Option<String>,
+    error_message: Option<String>,
+}
+
+impl From<storage::RefundBatchItem> for RefundBatchResultRecord {
+    fn from(item: storage::RefundBatchItem) -> Self {
+        Self {
+            line_number: item.line_number,
+            payment_id: item.payment_id,
+            amount: item.amount,
+            reason: item.reason,
+            status: item.status,
+            refund_id: item.refund_id,
+            error_message: item.error_message,
+        }
+    }
+}
+
+impl ForeignFrom<storage::RefundBatch> for RefundBatchResponse {
+    fn foreign_from(batch: storage::RefundBatch) -> Self {
+        Self {
+            batch_id: batch.batch_id,
+            merchant_id: batch.merchant_id,
+            profile_id: batch.profile_id,
+            status: batch.status,
+            file_name: batch.file_name,
+            total_count: batch.total_count,
+            succeeded_count: batch.succeeded_count,
+            failed_count: batch.failed_count,
+            created_at: batch.created_at,
+            modified_at: batch.modified_at,
+        }
+    }
+}
+
+fn parse_csv(data: &[u8]) -> csv::Result<Vec<RefundBatchRecord>> {
+    let mut csv_reader = csv::Reader::from_reader(data);
+    let mut records = Vec::new();
+    let mut line_number = 0;
+    for result in csv_reader.deserialize() {
+        let mut record: RefundBatchRecord = result?;
+        line_number += 1;
+        record.line_number = Some(line_number);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+pub fn get_refund_batch_records(
+    form: RefundBatchForm,
+) -> Result<RefundBatchCreateRequest, errors::ApiErrorResponse> {
+    let records = parse_csv(&form.file.data).map_err(|error| {
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Failed to parse the uploaded file: {error}"),
+        }
+    })?;
+
+    if records.is_empty() {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No records found".to_string(),
+        });
+    }
+
+    if records.len() > MAX_REFUND_BATCH_SIZE {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("A batch can contain at most {MAX_REFUND_BATCH_SIZE} records"),
+        });
+    }
+
+    Ok(RefundBatchCreateRequest {
+        file_name: form.file.file_name,
+        records,
+    })
+}
+
+/// Validates every row of the batch before anything is persisted, so that a batch is either
+/// accepted as a whole or rejected with the list of offending rows.
+#[instrument(skip_all)]
+async fn validate_refund_batch_records(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    records: &[RefundBatchRecord],
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let mut row_errors = Vec::new();
+    // payment_id -> (first line referencing the payment, total amount requested in the batch)
+    let mut requested_amounts: HashMap<&str, (i32, MinorUnit)> = HashMap::new();
+
+    for record in records {
+        let line_number = record.line_number.unwrap_or_default();
+        if record.amount <= MinorUnit::new(0) {
+            row_errors.push(format!(
+                "line {line_number}: amount must be a positive integer"
+            ));
+            continue;
+        }
+        requested_amounts
+            .entry(record.payment_id.as_str())
+            .and_modify(|(_, total)| *total = *total + record.amount)
+            .or_insert((line_number, record.amount));
+    }
+
+    let mut requested_amounts = requested_amounts.into_iter().collect::<Vec<_>>();
+    requested_amounts.sort_by_key(|(_, (line_number, _))| *line_number);
+
+    for (payment_id, (line_number, requested_amount)) in requested_amounts {
+        let payment_intent = match db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &state.into(),
+                payment_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_intent) => payment_intent,
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    hyperswitch_domain_models::errors::StorageError::ValueNotFound(_)
+                ) =>
+            {
+                row_errors.push(format!(
+                    "line {line_number}: payment {payment_id} does not exist"
+                ));
+                continue;
+            }
+            Err(error) => {
+                return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Failed while fetching payment intent for refund batch")
+            }
+        };
+
+        if !matches!(
+            payment_intent.status,
+            enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+        ) {
+            row_errors.push(format!(
+                "line {line_number}: payment {payment_id} is in {} status and cannot be refunded",
+                payment_intent.status
+            ));
+            continue;
+        }
+
+        let all_refunds = db
+            .find_refund_by_payment_id_merchant_id(
+                payment_id,
+                merchant_account.get_id(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching refunds for refund batch")?;
+
+        let amount_captured = payment_intent
+            .amount_captured
+            .unwrap_or(payment_intent.amount);
+
+        if validator::validate_refund_amount(
+            amount_captured.get_amount_as_i64(),
+            &all_refunds,
+            requested_amount.get_amount_as_i64(),
+        )
+        .is_err()
+        {
+            row_errors.push(format!(
+                "line {line_number}: total refund amount {requested_amount} requested for payment {payment_id} exceeds the refundable amount"
+            ));
+        }
+    }
+
+    if row_errors.is_empty() {
+        return Ok(());
+    }
+
+    let total_errors = row_errors.len();
+    row_errors.truncate(MAX_REPORTED_ROW_ERRORS);
+    Err(errors::ApiErrorResponse::InvalidRequestData {
+        message: format!(
+            "{total_errors} row(s) failed validation: {}",
+            row_errors.join("; ")
+        ),
+    }
+    .into())
+}
+
+#[instrument(skip_all)]
+pub async fn create_refund_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
+    key_store: domain::MerchantKeyStore,
+    req: RefundBatchCreateRequest,
+) -> RouterResponse<RefundBatchResponse> {
+    let db = &*state.store;
+
+    validate_refund_batch_records(&state, &merchant_account, &key_store, &req.records).await?;
+
+    let now = common_utils::date_time::now();
+    let batch_id = common_utils::generate_id_with_default_len("refund_batch");
+    let total_count = i32::try_from(req.records.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Refund batch size does not fit in an i32")?;
+
+    let refund_batch = db
+        .insert_refund_batch(storage::RefundBatchNew {
+            batch_id: batch_id.clone(),
+            merchant_id: merchant_account.get_id().to_owned(),
+            profile_id,
+            status: enums::RefundBatchStatus::Pending,
+            file_name: req.file_name,
+            total_count,
+            succeeded_count: 0,
+            failed_count: 0,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting refund batch")?;
+
+    let items = req
+        .records
+        .into_iter()
+        .map(|record| storage::RefundBatchItemNew {
+            batch_id: batch_id.clone(),
+            line_number: record.line_number.unwrap_or_default(),
+            payment_id: record.payment_id,
+            amount: record.amount,
+            reason: record.reason,
+            status: enums::RefundBatchItemStatus::Pending,
+            refund_id: None,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .collect::<Vec<_>>();
+
+    for chunk in items.chunks(REFUND_BATCH_ITEM_INSERT_CHUNK_SIZE) {
+        db.insert_refund_batch_items(chunk.to_vec())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while inserting refund batch items")?;
+    }
+
+    add_refund_batch_process_task(
+        db,
+        &refund_batch,
+        storage::ProcessTrackerRunner::RefundBatchWorkflow,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        RefundBatchResponse::foreign_from(refund_batch),
+    ))
+}
+
+#[instrument(skip_all)]
+async fn add_refund_batch_process_task(
+    db: &dyn crate::db::StorageInterface,
+    refund_batch: &storage::RefundBatch,
+    runner: storage::ProcessTrackerRunner,
+) -> RouterResult<storage::ProcessTracker> {
+    let task = REFUND_BATCH_TASK;
+    let process_tracker_id = format!("{runner}_{task}_{}", refund_batch.batch_id);
+    let tracking_data = storage::RefundBatchTrackingData {
+        batch_id: refund_batch.batch_id.clone(),
+        merchant_id: refund_batch.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        [REFUND_BATCH_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct refund batch process tracker task")?;
+
+    let response = db
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: batch_id: {}",
+                refund_batch.batch_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "RefundBatch")]),
+    );
+
+    Ok(response)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_refund_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    batch_id: String,
+) -> RouterResponse<RefundBatchResponse> {
+    let refund_batch = state
+        .store
+        .find_refund_batch_by_merchant_id_batch_id(merchant_account.get_id(), &batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Refund batch not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        RefundBatchResponse::foreign_from(refund_batch),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_refund_batches(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: RefundBatchListRequest,
+) -> RouterResponse<Vec<RefundBatchResponse>> {
+    let limit = validator::validate_refund_list(req.limit)?;
+    let refund_batches = state
+        .store
+        .list_refund_batches_by_merchant_id(merchant_account.get_id(), Some(limit), req.offset)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing refund batches")?;
+
+    Ok(ApplicationResponse::Json(
+        refund_batches
+            .into_iter()
+            .map(RefundBatchResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn download_refund_batch_result(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    batch_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let db = &*state.store;
+    let refund_batch = db
+        .find_refund_batch_by_merchant_id_batch_id(merchant_account.get_id(), &batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Refund batch not found".to_string(),
+        })?;
+
+    let items = db
+        .list_refund_batch_items_by_batch_id(&refund_batch.batch_id, None, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching refund batch items")?;
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for item in items {
+        writer
+            .serialize(RefundBatchResultRecord::from(item))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize refund batch result row")?;
+    }
+    let file = writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build refund batch result file")?;
+
+    Ok(ApplicationResponse::FileData((file, mime::TEXT_CSV)))
+}
+
+/// Creates refunds for the next set of pending rows of the batch and records the outcome of
+/// each row. Returns the batch with the updated counts and status.
+#[instrument(skip_all)]
+async fn process_refund_batch_items(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    refund_batch: storage::RefundBatch,
+) -> RouterResult<storage::RefundBatch> {
+    let db = &*state.store;
+    let items = db
+        .list_refund_batch_items_by_batch_id_status(
+            &refund_batch.batch_id,
+            enums::RefundBatchItemStatus::Pending,
+            Some(REFUND_BATCH_PROCESSING_CHUNK_SIZE),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching pending refund batch items")?;
+
+    let is_last_chunk = i64::try_from(items.len())
+        .map(|count| count < REFUND_BATCH_PROCESSING_CHUNK_SIZE)
+        .unwrap_or(true);
+    let (mut succeeded_count, mut failed_count) =
+        (refund_batch.succeeded_count, refund_batch.failed_count);
+
+    for item in items {
+        // A deterministic refund id keeps the row idempotent if the task is picked up again
+        // after the refund was created but before the row was updated
+        let refund_id = format!("{}_{}", item.batch_id, item.line_number);
+        let refund_request = refunds::RefundRequest {
+            payment_id: item.payment_id.clone(),
+            refund_id: Some(refund_id.clone()),
+            amount: Some(item.amount),
+            reason: item.reason.clone(),
+            ..Default::default()
+        };
+
+        let item_update = match Box::pin(refund_create_core(
+            state.clone(),
+            merchant_account.clone(),
+            refund_batch.profile_id.clone(),
+            key_store.clone(),
+            refund_request,
+        ))
+        .await
+        {
+            Ok(ApplicationResponse::Json(refund)) => {
+                storage::RefundBatchItemUpdate::RefundCreated {
+                    refund_id: refund.refund_id,
+                }
+            }
+            Ok(_) => storage::RefundBatchItemUpdate::RefundFailed {
+                error_message: "Unexpected response while creating refund".to_string(),
+            },
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::DuplicateRefundRequest
+                ) =>
+            {
+                storage::RefundBatchItemUpdate::RefundCreated { refund_id }
+            }
+            Err(error) => {
+                logger::info!(batch_id = %item.batch_id, line_number = item.line_number, ?error, "Refund creation failed for refund batch row");
+                storage::RefundBatchItemUpdate::RefundFailed {
+                    error_message: error.current_context().to_string(),
+                }
+            }
+        };
+
+        match item_update {
+            storage::RefundBatchItemUpdate::RefundCreated { .. } => succeeded_count += 1,
+            storage::RefundBatchItemUpdate::RefundFailed { .. } => failed_count += 1,
+        }
+
+        db.update_refund_batch_item_by_batch_id_line_number(
+            &item.batch_id,
+            item.line_number,
+            item_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while updating refund batch item")?;
+    }
+
+    let status = if is_last_chunk {
+        enums::RefundBatchStatus::Completed
+    } else {
+        enums::RefundBatchStatus::Processing
+    };
+
+    db.update_refund_batch_by_merchant_id_batch_id(
+        &refund_batch.merchant_id,
+        &refund_batch.batch_id,
+        storage::RefundBatchUpdate::ProgressUpdate {
+            status,
+            succeeded_count,
+            failed_count,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while updating refund batch")
+}
+
+#[instrument(skip_all)]
+pub async fn start_refund_batch_workflow(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::RefundBatchTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("RefundBatchTrackingData")?;
+
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+
+    let refund_batch = db
+        .find_refund_batch_by_merchant_id_batch_id(
+            &tracking_data.merchant_id,
+            &tracking_data.batch_id,
+        )
+        .await?;
+
+    let refund_batch = if refund_batch.status == enums::RefundBatchStatus::Completed {
+        refund_batch
+    } else {
+        Box::pin(process_refund_batch_items(
+            state,
+            &merchant_account,
+            &key_store,
+            refund_batch,
+        ))
+        .await?
+    };
+
+    if refund_batch.status == enums::RefundBatchStatus::Completed {
+        db.as_scheduler()
+            .finish_process_with_business_status(process.clone(), "COMPLETED_BY_PT")
+            .await?;
+    } else {
+        // Pick up the next chunk of rows in the following scheduler cycle
+        db.as_scheduler()
+            .retry_process(process.clone(), common_utils::date_time::now())
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::parse_csv;
+
+    #[test]
+    fn test_parse_refund_batch_csv() {
+        let data = "payment_id,amount,reason\npay_1,100,duplicate\npay_2,250,\n";
+        let records = parse_csv(data.as_bytes()).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].payment_id, "pay_1");
+        assert_eq!(records[0].line_number, Some(1));
+        assert_eq!(records[0].reason.as_deref(), Some("duplicate"));
+        assert_eq!(records[1].amount.get_amount_as_i64(), 250);
+        assert_eq!(records[1].line_number, Some(2));
+        assert_eq!(records[1].reason, None);
+    }
+
+    #[test]
+    fn test_parse_refund_batch_csv_rejects_invalid_amount() {
+        let data = "payment_id,amount,reason\npay_1,ten,duplicate\n";
+        assert!(parse_csv(data.as_bytes()).is_err());
+    }
+}
@@ -161,6 +161,11 @@ pub async fn create_routing_algorithm_under_profile(
 
     algorithm_helper.validate_connectors_in_routing_config()?;
 
+    #[cfg(feature = "payouts")]
+    if transaction_type == &enums::TransactionType::Payout {
+        helpers::validate_payout_routing_algorithm(&request.algorithm)?;
+    }
+
     let algo = RoutingAlgorithmUpdate::create_new_routing_algorithm(
         &request,
         merchant_account.get_id(),
@@ -246,6 +251,11 @@ pub async fn create_routing_algorithm_under_profile(
     )
     .await?;
 
+    #[cfg(feature = "payouts")]
+    if transaction_type == &enums::TransactionType::Payout {
+        helpers::validate_payout_routing_algorithm(&algorithm)?;
+    }
+
     let timestamp = common_utils::date_time::now();
     let algo = RoutingAlgorithm {
         algorithm_id: algorithm_id.clone(),
@@ -2,10 +2,19 @@
 //!
 //! Functions that are used to perform the retrieval of merchant's
 //! routing dict, configs, defaults
+#[cfg(feature = "payouts")]
+use std::str::FromStr;
+
+#[cfg(feature = "payouts")]
+use api_models::enums as api_enums;
 use api_models::routing as routing_types;
 use common_utils::{ext_traits::Encode, types::keymanager::KeyManagerState};
 use diesel_models::configs;
+#[cfg(feature = "payouts")]
+use error_stack::report;
 use error_stack::ResultExt;
+#[cfg(feature = "payouts")]
+use euclid::frontend::{ast, dir};
 use rustc_hash::FxHashSet;
 use storage_impl::redis::cache;
 
@@ -498,6 +507,77 @@ pub async fn validate_connectors_in_routing_config(
     Ok(())
 }
 
+/// Ensures that a routing algorithm configured for payouts only selects payout connectors and,
+/// for advanced programs, only uses keys that can be evaluated for a payout
+#[cfg(feature = "payouts")]
+pub fn validate_payout_routing_algorithm(
+    routing_algorithm: &routing_types::RoutingAlgorithm,
+) -> RouterResult<()> {
+    let payout_connector = |choice: &routing_types::RoutableConnectorChoice| -> RouterResult<()> {
+        api_enums::PayoutConnectors::from_str(&choice.connector.to_string())
+            .map(|_| ())
+            .map_err(|_| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("connector '{}' does not support payouts", choice.connector),
+                })
+            })
+    };
+
+    let connector_selection = |selection: &routing_types::ConnectorSelection| -> RouterResult<()> {
+        match selection {
+            routing_types::ConnectorSelection::VolumeSplit(splits) => splits
+                .iter()
+                .try_for_each(|split| payout_connector(&split.connector)),
+            routing_types::ConnectorSelection::Priority(list) => {
+                list.iter().try_for_each(payout_connector)
+            }
+        }
+    };
+
+    match routing_algorithm {
+        routing_types::RoutingAlgorithm::Single(choice) => payout_connector(choice),
+        routing_types::RoutingAlgorithm::Priority(list) => {
+            list.iter().try_for_each(payout_connector)
+        }
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => splits
+            .iter()
+            .try_for_each(|split| payout_connector(&split.connector)),
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            connector_selection(&program.default_selection)?;
+            for rule in &program.rules {
+                connector_selection(&rule.connector_selection)?;
+                validate_payout_rule_statements(&rule.statements)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+fn validate_payout_rule_statements(statements: &[ast::IfStatement]) -> RouterResult<()> {
+    for statement in statements {
+        for comparison in &statement.condition {
+            let is_payout_key = dir::PayoutDirKeyKind::from_str(&comparison.lhs).is_ok()
+                || comparison.lhs == dir::DirKeyKind::MetaData.to_string();
+            error_stack::ensure!(
+                is_payout_key,
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "key '{}' cannot be used in a payout routing rule",
+                        comparison.lhs
+                    ),
+                }
+            );
+        }
+
+        if let Some(nested) = &statement.nested {
+            validate_payout_rule_statements(nested)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Provides the identifier for the specific merchant's routing_dictionary_key
 #[inline(always)]
 pub fn get_routing_dictionary_key(merchant_id: &str) -> String {
@@ -516,3 +596,115 @@ pub fn get_default_config_key(
         storage::enums::TransactionType::Payout => format!("routing_default_po_{merchant_id}"),
     }
 }
+
+#[cfg(all(test, feature = "payouts"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn advanced_algorithm(
+        default_connector: &str,
+        rule_connector: &str,
+        lhs: &str,
+    ) -> routing_types::RoutingAlgorithm {
+        serde_json::from_value(serde_json::json!({
+            "type": "advanced",
+            "data": {
+                "defaultSelection": { "type": "priority", "data": [default_connector] },
+                "rules": [{
+                    "name": "high_value_payouts",
+                    "connectorSelection": {
+                        "type": "volume_split",
+                        "data": [{ "connector": rule_connector, "split": 100 }],
+                    },
+                    "statements": [{
+                        "condition": [{
+                            "lhs": "amount",
+                            "comparison": "greater_than",
+                            "value": { "type": "number", "value": 100000 },
+                            "metadata": {},
+                        }],
+                        "nested": [{
+                            "condition": [{
+                                "lhs": lhs,
+                                "comparison": "equal",
+                                "value": { "type": "enum_variant", "value": "USD" },
+                                "metadata": {},
+                            }],
+                            "nested": null,
+                        }],
+                    }],
+                }],
+                "metadata": {},
+            },
+        }))
+        .unwrap()
+    }
+
+    fn error_message(result: RouterResult<()>) -> Option<String> {
+        match result.unwrap_err().current_context() {
+            errors::ApiErrorResponse::InvalidRequestData { message } => Some(message.clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_payout_routing_algorithm_accepts_payout_connectors_and_keys() {
+        for algorithm in [
+            serde_json::json!({ "type": "single", "data": "wise" }),
+            serde_json::json!({ "type": "priority", "data": ["adyen", "wise"] }),
+            serde_json::json!({
+                "type": "volume_split",
+                "data": [
+                    { "connector": "adyen", "split": 60 },
+                    { "connector": "paypal", "split": 40 },
+                ],
+            }),
+        ] {
+            let algorithm = serde_json::from_value(algorithm).unwrap();
+            assert!(validate_payout_routing_algorithm(&algorithm).is_ok());
+        }
+
+        assert!(validate_payout_routing_algorithm(&advanced_algorithm(
+            "adyen", "wise", "currency"
+        ))
+        .is_ok());
+    }
+
+    #[test]
+    fn test_payout_routing_algorithm_rejects_payment_only_connectors() {
+        let algorithm = serde_json::from_value(
+            serde_json::json!({ "type": "priority", "data": ["wise", "checkout"] }),
+        )
+        .unwrap();
+        assert_eq!(
+            error_message(validate_payout_routing_algorithm(&algorithm)),
+            Some("connector 'checkout' does not support payouts".to_string())
+        );
+
+        assert_eq!(
+            error_message(validate_payout_routing_algorithm(&advanced_algorithm(
+                "checkout", "wise", "currency"
+            ))),
+            Some("connector 'checkout' does not support payouts".to_string())
+        );
+        assert_eq!(
+            error_message(validate_payout_routing_algorithm(&advanced_algorithm(
+                "adyen", "checkout", "currency"
+            ))),
+            Some("connector 'checkout' does not support payouts".to_string())
+        );
+    }
+
+    #[test]
+    fn test_payout_routing_algorithm_rejects_payment_only_keys_in_nested_statements() {
+        assert_eq!(
+            error_message(validate_payout_routing_algorithm(&advanced_algorithm(
+                "adyen",
+                "wise",
+                "card_network"
+            ))),
+            Some("key 'card_network' cannot be used in a payout routing rule".to_string())
+        );
+    }
+}
@@ -0,0 +1,1080 @@
+use api_models::scim as scim_api;
+use common_utils::id_type;
+use diesel_models::{
+    enums::{UserRoleVersion, UserStatus},
+    scim::{ScimUser, ScimUserNew, ScimUserUpdate},
+    user as storage_user,
+    user_role::{UserRoleNew, UserRoleUpdate},
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use super::{
+    api_keys,
+    errors::{StorageErrorExt, UserErrors, UserResponse, UserResult},
+};
+use crate::{
+    consts,
+    routes::SessionState,
+    services::{
+        authentication as auth,
+        authorization::roles::{self, predefined_roles::PREDEFINED_ROLES},
+        ApplicationResponse,
+    },
+    types::{domain, storage},
+};
+
+/// Details of the merchant a SCIM token was issued for, stored against the hash of the token
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ScimTokenConfig {
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+pub fn get_scim_token_config_key(hashed_token: &str) -> String {
+    format!("scim_token_{hashed_token}")
+}
+
+/// Attributes supported in SCIM `eq` filters
+#[derive(Debug, PartialEq, Eq)]
+enum ScimFilter {
+    UserName(String),
+    ExternalId(String),
+    DisplayName(String),
+}
+
+/// Parses a SCIM filter of the form `<attribute> eq "<value>"`. Identity providers only use
+/// equality filters to look up existing resources before provisioning, so nothing else is
+/// supported.
+fn parse_scim_filter(filter: &str) -> UserResult<ScimFilter> {
+    let invalid_filter = || UserErrors::ScimInvalidFilter(format!("Unsupported filter: {filter}"));
+
+    let mut parts = filter.trim().splitn(3, ' ');
+    let attribute = parts.next().ok_or_else(invalid_filter)?;
+    let operator = parts.next().ok_or_else(invalid_filter)?;
+    let value = parts
+        .next()
+        .map(|value| value.trim().trim_matches('"').to_string())
+        .ok_or_else(invalid_filter)?;
+
+    if !operator.eq_ignore_ascii_case("eq") {
+        return Err(report!(invalid_filter()));
+    }
+
+    match attribute {
+        attribute if attribute.eq_ignore_ascii_case("userName") => Ok(ScimFilter::UserName(value)),
+        attribute if attribute.eq_ignore_ascii_case("externalId") => {
+            Ok(ScimFilter::ExternalId(value))
+        }
+        attribute if attribute.eq_ignore_ascii_case("displayName") => {
+            Ok(ScimFilter::DisplayName(value))
+        }
+        _ => Err(report!(invalid_filter())),
+    }
+}
+
+impl ScimFilter {
+    fn is_supported_for_users(&self) -> bool {
+        matches!(self, Self::UserName(_) | Self::ExternalId(_))
+    }
+
+    fn is_supported_for_groups(&self) -> bool {
+        matches!(self, Self::DisplayName(_))
+    }
+}
+
+/// Parses the filter of a list request. Filters on attributes the listed resource does not have
+/// are rejected rather than ignored, so that identity providers never mistake the full list for
+/// the result of their lookup.
+fn parse_list_filter(
+    filter: Option<&str>,
+    is_supported: fn(&ScimFilter) -> bool,
+) -> UserResult<Option<ScimFilter>> {
+    filter
+        .map(|filter| {
+            let scim_filter = parse_scim_filter(filter)?;
+            if is_supported(&scim_filter) {
+                Ok(scim_filter)
+            } else {
+                Err(report!(UserErrors::ScimInvalidFilter(format!(
+                    "Unsupported filter: {filter}"
+                ))))
+            }
+        })
+        .transpose()
+}
+
+fn paginate<T>(
+    resources: Vec<T>,
+    query: &scim_api::ScimListQuery,
+) -> scim_api::ScimListResponse<T> {
+    let total_results = resources.len();
+    let start_index = query.start_index.unwrap_or(1).max(1);
+    let count = query.count.unwrap_or(consts::user::SCIM_DEFAULT_PAGE_SIZE);
+    let resources: Vec<T> = resources
+        .into_iter()
+        .skip(start_index - 1)
+        .take(count)
+        .collect();
+
+    scim_api::ScimListResponse {
+        schemas: vec![scim_api::SCIM_LIST_RESPONSE_SCHEMA.to_string()],
+        total_results,
+        start_index,
+        items_per_page: resources.len(),
+        resources,
+    }
+}
+
+fn get_user_name_from_request(request: &scim_api::ScimUserRequest) -> UserResult<domain::UserName> {
+    let name = request.name.as_ref().and_then(|name| {
+        name.formatted.clone().or_else(|| {
+            match (name.given_name.as_ref(), name.family_name.as_ref()) {
+                (Some(given_name), Some(family_name)) => Some(Secret::new(format!(
+                    "{} {}",
+                    given_name.peek(),
+                    family_name.peek()
+                ))),
+                (Some(name), None) | (None, Some(name)) => Some(name.clone()),
+                (None, None) => None,
+            }
+        })
+    });
+
+    match name.or_else(|| request.display_name.clone()) {
+        Some(name) => domain::UserName::new(name),
+        None => domain::UserName::try_from(request.user_name.clone()),
+    }
+}
+
+fn get_scim_member_from_role(role_info: &roles::RoleInfo) -> scim_api::ScimMember {
+    scim_api::ScimMember {
+        value: role_info.get_role_id().to_string(),
+        display: Some(role_info.get_role_name().to_string()),
+    }
+}
+
+async fn get_role_info_for_group(
+    state: &SessionState,
+    role_id: &str,
+    merchant_id: &id_type::MerchantId,
+    org_id: &id_type::OrganizationId,
+) -> UserResult<roles::RoleInfo> {
+    let role_info = roles::RoleInfo::from_role_id(state, role_id, merchant_id, org_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    if !role_info.is_invitable() || role_info.is_internal() {
+        return Err(report!(UserErrors::ScimResourceNotFound)).attach_printable(format!(
+            "role_id = {role_id} cannot be mapped to a SCIM group"
+        ));
+    }
+
+    Ok(role_info)
+}
+
+async fn build_scim_user_response(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    user: storage_user::User,
+    scim_user: ScimUser,
+) -> UserResult<scim_api::ScimUserResponse> {
+    let role_info = roles::RoleInfo::from_role_id(
+        state,
+        &scim_user.role_id,
+        merchant_account.get_id(),
+        &merchant_account.organization_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+
+    Ok(scim_api::ScimUserResponse {
+        schemas: vec![scim_api::SCIM_USER_SCHEMA.to_string()],
+        id: user.user_id,
+        external_id: scim_user.external_id,
+        user_name: user.email.clone(),
+        name: scim_api::ScimName {
+            formatted: Some(user.name.clone()),
+            given_name: None,
+            family_name: None,
+        },
+        display_name: user.name,
+        emails: vec![scim_api::ScimEmail {
+            value: user.email,
+            email_type: Some("work".to_string()),
+            primary: Some(true),
+        }],
+        active: scim_user.active,
+        groups: vec![get_scim_member_from_role(&role_info)],
+        meta: scim_api::ScimMeta {
+            resource_type: scim_api::ScimResourceType::User,
+            created: Some(scim_user.created_at),
+            last_modified: Some(scim_user.last_modified_at),
+        },
+    })
+}
+
+async fn find_scim_user(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    user_id: &str,
+) -> UserResult<(storage_user::User, ScimUser)> {
+    let scim_user = state
+        .store
+        .find_scim_user_by_merchant_id_user_id(merchant_id, user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    let user = state
+        .global_store
+        .find_user_by_id(user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    Ok((user, scim_user))
+}
+
+async fn insert_user_role(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    user_id: &str,
+    role_id: &str,
+) -> UserResult<()> {
+    let now = common_utils::date_time::now();
+    state
+        .store
+        .insert_user_role(UserRoleNew {
+            user_id: user_id.to_string(),
+            merchant_id: Some(merchant_account.get_id().clone()),
+            role_id: role_id.to_string(),
+            org_id: Some(merchant_account.organization_id.clone()),
+            status: UserStatus::Active,
+            created_by: consts::user::SCIM_PROVISIONER_ID.to_string(),
+            last_modified_by: consts::user::SCIM_PROVISIONER_ID.to_string(),
+            created_at: now,
+            last_modified: now,
+            profile_id: None,
+            entity_id: None,
+            entity_type: None,
+            version: UserRoleVersion::V1,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to insert user role for SCIM user")?;
+    Ok(())
+}
+
+async fn delete_user_role(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    user_id: &str,
+) -> UserResult<()> {
+    match state
+        .store
+        .delete_user_role_by_user_id_and_lineage(
+            user_id,
+            &merchant_account.organization_id,
+            merchant_account.get_id(),
+            None,
+            UserRoleVersion::V1,
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) if e.current_context().is_db_not_found() => Ok(()),
+        Err(e) => Err(e.change_context(UserErrors::InternalServerError))
+            .attach_printable("Failed to delete user role for SCIM user"),
+    }?;
+
+    // Invalidate all existing sessions so that access is revoked immediately
+    auth::blacklist::insert_user_in_blacklist(state, user_id).await
+}
+
+/// Applies an `active` transition for a SCIM user, granting or revoking dashboard access in the
+/// merchant account
+async fn set_scim_user_active(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    scim_user: ScimUser,
+    active: bool,
+) -> UserResult<ScimUser> {
+    if scim_user.active == active {
+        return Ok(scim_user);
+    }
+
+    if active {
+        insert_user_role(
+            state,
+            merchant_account,
+            &scim_user.user_id,
+            &scim_user.role_id,
+        )
+        .await?;
+    } else {
+        delete_user_role(state, merchant_account, &scim_user.user_id).await?;
+    }
+
+    state
+        .store
+        .update_scim_user_by_merchant_id_user_id(
+            merchant_account.get_id(),
+            &scim_user.user_id,
+            ScimUserUpdate::StatusUpdate { active },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Maps the SCIM user to the role backing a SCIM group
+async fn set_scim_user_role(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    user_id: &str,
+    role_id: &str,
+) -> UserResult<ScimUser> {
+    let scim_user = state
+        .store
+        .find_scim_user_by_merchant_id_user_id(merchant_account.get_id(), user_id)
+        .await
+        .to_not_found_response(UserErrors::InvalidScimRequest(format!(
+            "User {user_id} was not provisioned through SCIM"
+        )))?;
+
+    if scim_user.role_id == role_id {
+        return Ok(scim_user);
+    }
+
+    if scim_user.active {
+        state
+            .store
+            .update_user_role_by_user_id_merchant_id(
+                user_id,
+                merchant_account.get_id(),
+                UserRoleUpdate::UpdateRole {
+                    role_id: role_id.to_string(),
+                    modified_by: consts::user::SCIM_PROVISIONER_ID.to_string(),
+                },
+                UserRoleVersion::V1,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to update user role for SCIM user")?;
+
+        auth::blacklist::insert_user_in_blacklist(state, user_id).await?;
+    }
+
+    state
+        .store
+        .update_scim_user_by_merchant_id_user_id(
+            merchant_account.get_id(),
+            user_id,
+            ScimUserUpdate::RoleUpdate {
+                role_id: role_id.to_string(),
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+async fn update_user_name(
+    state: &SessionState,
+    user_id: &str,
+    name: domain::UserName,
+) -> UserResult<storage_user::User> {
+    state
+        .global_store
+        .update_user_by_user_id(
+            user_id,
+            storage_user::UserUpdate::AccountUpdate {
+                name: Some(name.get_secret().expose()),
+                is_verified: None,
+                preferred_merchant_id: None,
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+#[instrument(skip_all)]
+pub async fn create_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let email = domain::UserEmail::from_pii_email(request.user_name.clone())?;
+    let name = get_user_name_from_request(&request)?;
+    let active = request.active.unwrap_or(true);
+
+    let user = match state
+        .global_store
+        .find_user_by_email(&email.clone().into_inner())
+        .await
+    {
+        Ok(user) => user,
+        Err(e) if e.current_context().is_db_not_found() => {
+            let now = common_utils::date_time::now();
+            state
+                .global_store
+                .insert_user(storage_user::UserNew {
+                    user_id: uuid::Uuid::new_v4().to_string(),
+                    email: email.into_inner(),
+                    name: name.get_secret(),
+                    password: None,
+                    // The identity provider is the source of truth for the email address
+                    is_verified: true,
+                    created_at: Some(now),
+                    last_modified_at: Some(now),
+                    preferred_merchant_id: None,
+                    totp_status: diesel_models::enums::TotpStatus::NotSet,
+                    totp_secret: None,
+                    totp_recovery_codes: None,
+                    last_password_modified_at: None,
+                })
+                .await
+                .map_err(|e| {
+                    if e.current_context().is_db_unique_violation() {
+                        e.change_context(UserErrors::UserExists)
+                    } else {
+                        e.change_context(UserErrors::InternalServerError)
+                    }
+                })?
+        }
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    };
+
+    // Users who were invited manually before SCIM was enabled keep their current role
+    let existing_role_id = match state
+        .store
+        .find_user_role_by_user_id_merchant_id(
+            &user.user_id,
+            merchant_account.get_id(),
+            UserRoleVersion::V1,
+        )
+        .await
+    {
+        Ok(user_role) => Some(user_role.role_id),
+        Err(e) if e.current_context().is_db_not_found() => None,
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    };
+
+    let is_role_assigned = existing_role_id.is_some();
+    let role_id = existing_role_id
+        .unwrap_or_else(|| consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY.to_string());
+
+    let now = common_utils::date_time::now();
+    let scim_user = state
+        .store
+        .insert_scim_user(ScimUserNew {
+            merchant_id: merchant_account.get_id().clone(),
+            user_id: user.user_id.clone(),
+            org_id: merchant_account.organization_id.clone(),
+            external_id: request.external_id,
+            role_id,
+            active: is_role_assigned,
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_unique_violation() {
+                e.change_context(UserErrors::UserExists)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    let scim_user = set_scim_user_active(&state, &merchant_account, scim_user, active).await?;
+
+    build_scim_user_response(&state, &merchant_account, user, scim_user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let (user, scim_user) = find_scim_user(&state, merchant_account.get_id(), &user_id).await?;
+
+    build_scim_user_response(&state, &merchant_account, user, scim_user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn list_users(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimListResponse<scim_api::ScimUserResponse>> {
+    let filter = parse_list_filter(query.filter.as_deref(), ScimFilter::is_supported_for_users)?;
+
+    let scim_users = state
+        .store
+        .list_scim_users_by_merchant_id(merchant_account.get_id(), None, None)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let mut resources = Vec::with_capacity(scim_users.len());
+    for scim_user in scim_users {
+        if let Some(ScimFilter::ExternalId(external_id)) = &filter {
+            if scim_user.external_id.as_ref() != Some(external_id) {
+                continue;
+            }
+        }
+
+        let user = state
+            .global_store
+            .find_user_by_id(&scim_user.user_id)
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+
+        if let Some(ScimFilter::UserName(user_name)) = &filter {
+            if !user.email.peek().eq_ignore_ascii_case(user_name) {
+                continue;
+            }
+        }
+
+        resources.push(build_scim_user_response(&state, &merchant_account, user, scim_user).await?);
+    }
+
+    Ok(ApplicationResponse::Json(paginate(resources, &query)))
+}
+
+#[instrument(skip_all)]
+pub async fn replace_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    request: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let (user, scim_user) = find_scim_user(&state, merchant_account.get_id(), &user_id).await?;
+
+    if !user
+        .email
+        .peek()
+        .eq_ignore_ascii_case(request.user_name.peek())
+    {
+        return Err(report!(UserErrors::ScimOperationNotSupported))
+            .attach_printable("Changing the userName of a SCIM user is not supported");
+    }
+
+    let user = update_user_name(&state, &user_id, get_user_name_from_request(&request)?).await?;
+
+    let scim_user = if scim_user.external_id != request.external_id {
+        state
+            .store
+            .update_scim_user_by_merchant_id_user_id(
+                merchant_account.get_id(),
+                &user_id,
+                ScimUserUpdate::ExternalIdUpdate {
+                    external_id: request.external_id,
+                },
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?
+    } else {
+        scim_user
+    };
+
+    let scim_user = set_scim_user_active(
+        &state,
+        &merchant_account,
+        scim_user,
+        request.active.unwrap_or(true),
+    )
+    .await?;
+
+    build_scim_user_response(&state, &merchant_account, user, scim_user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+fn get_patch_value<T: serde::de::DeserializeOwned>(
+    operation: &scim_api::ScimPatchOperation,
+) -> UserResult<T> {
+    operation
+        .value
+        .clone()
+        .ok_or(UserErrors::InvalidScimRequest(
+            "Missing value in patch operation".to_string(),
+        ))
+        .and_then(|value| {
+            serde_json::from_value(value).map_err(|_| {
+                UserErrors::InvalidScimRequest("Invalid value in patch operation".to_string())
+            })
+        })
+        .map_err(error_stack::Report::from)
+}
+
+#[instrument(skip_all)]
+pub async fn patch_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+    request: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let (mut user, mut scim_user) =
+        find_scim_user(&state, merchant_account.get_id(), &user_id).await?;
+
+    for operation in request.operations {
+        if operation.op == scim_api::ScimPatchOperationType::Remove {
+            return Err(report!(UserErrors::ScimOperationNotSupported))
+                .attach_printable("Remove operations are not supported on SCIM users");
+        }
+
+        // Some identity providers send attributes as a value object without a path
+        let attributes: Vec<(String, serde_json::Value)> = match operation.path.as_deref() {
+            Some(path) => vec![(
+                path.to_string(),
+                get_patch_value::<serde_json::Value>(&operation)?,
+            )],
+            None => get_patch_value::<serde_json::Map<String, serde_json::Value>>(&operation)?
+                .into_iter()
+                .collect(),
+        };
+
+        for (path, value) in attributes {
+            match path.as_str() {
+                "active" => {
+                    let active = value
+                        .as_bool()
+                        // Azure AD sends booleans as strings
+                        .or_else(|| value.as_str().and_then(|value| value.parse().ok()))
+                        .ok_or(UserErrors::InvalidScimRequest(
+                            "active must be a boolean".to_string(),
+                        ))?;
+                    scim_user =
+                        set_scim_user_active(&state, &merchant_account, scim_user, active).await?;
+                }
+                "externalId" => {
+                    scim_user = state
+                        .store
+                        .update_scim_user_by_merchant_id_user_id(
+                            merchant_account.get_id(),
+                            &user_id,
+                            ScimUserUpdate::ExternalIdUpdate {
+                                external_id: value.as_str().map(ToString::to_string),
+                            },
+                        )
+                        .await
+                        .change_context(UserErrors::InternalServerError)?;
+                }
+                "displayName" | "name.formatted" => {
+                    let name = value
+                        .as_str()
+                        .ok_or(UserErrors::InvalidScimRequest(format!(
+                            "{path} must be a string"
+                        )))?;
+                    user = update_user_name(
+                        &state,
+                        &user_id,
+                        domain::UserName::new(Secret::new(name.to_string()))?,
+                    )
+                    .await?;
+                }
+                // Given and family names are only used to derive the display name on creation
+                "name.givenName" | "name.familyName" => {}
+                _ => {
+                    return Err(report!(UserErrors::ScimOperationNotSupported))
+                        .attach_printable(format!("Unsupported patch path: {path}"));
+                }
+            }
+        }
+    }
+
+    build_scim_user_response(&state, &merchant_account, user, scim_user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn delete_user(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    user_id: String,
+) -> UserResponse<()> {
+    let (_, scim_user) = find_scim_user(&state, merchant_account.get_id(), &user_id).await?;
+
+    delete_user_role(&state, &merchant_account, &scim_user.user_id).await?;
+
+    state
+        .store
+        .delete_scim_user_by_merchant_id_user_id(merchant_account.get_id(), &user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let user_roles_v1 = state
+        .store
+        .list_user_roles_by_user_id(&user_id, UserRoleVersion::V1)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let user_roles_v2 = state
+        .store
+        .list_user_roles_by_user_id(&user_id, UserRoleVersion::V2)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    // Remove the user entirely once it is not associated with any other merchant
+    if user_roles_v1.is_empty() && user_roles_v2.is_empty() {
+        state
+            .global_store
+            .delete_user_by_user_id(&user_id)
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Error while deleting user entry")?;
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+async fn build_scim_group_response(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    role_info: &roles::RoleInfo,
+) -> UserResult<scim_api::ScimGroupResponse> {
+    let members = state
+        .store
+        .list_scim_users_by_merchant_id_role_id(merchant_account.get_id(), role_info.get_role_id())
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|scim_user| scim_api::ScimMember {
+            value: scim_user.user_id,
+            display: None,
+        })
+        .collect();
+
+    Ok(scim_api::ScimGroupResponse {
+        schemas: vec![scim_api::SCIM_GROUP_SCHEMA.to_string()],
+        id: role_info.get_role_id().to_string(),
+        display_name: role_info.get_role_name().to_string(),
+        members,
+        meta: scim_api::ScimMeta {
+            resource_type: scim_api::ScimResourceType::Group,
+            created: None,
+            last_modified: None,
+        },
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn list_groups(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimListResponse<scim_api::ScimGroupResponse>> {
+    let filter = parse_list_filter(query.filter.as_deref(), ScimFilter::is_supported_for_groups)?;
+
+    let custom_roles = state
+        .store
+        .list_all_roles(merchant_account.get_id(), &merchant_account.organization_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(roles::RoleInfo::from);
+
+    let mut role_infos: Vec<roles::RoleInfo> = PREDEFINED_ROLES
+        .values()
+        .cloned()
+        .chain(custom_roles)
+        .filter(|role_info| role_info.is_invitable() && !role_info.is_internal())
+        .filter(|role_info| match &filter {
+            Some(ScimFilter::DisplayName(display_name)) => {
+                role_info.get_role_name().eq_ignore_ascii_case(display_name)
+            }
+            _ => true,
+        })
+        .collect();
+    role_infos.sort_by(|a, b| a.get_role_id().cmp(b.get_role_id()));
+
+    let mut resources = Vec::with_capacity(role_infos.len());
+    for role_info in role_infos {
+        resources.push(build_scim_group_response(&state, &merchant_account, &role_info).await?);
+    }
+
+    Ok(ApplicationResponse::Json(paginate(resources, &query)))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_group(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    group_id: String,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let role_info = get_role_info_for_group(
+        &state,
+        &group_id,
+        merchant_account.get_id(),
+        &merchant_account.organization_id,
+    )
+    .await?;
+
+    build_scim_group_response(&state, &merchant_account, &role_info)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Extracts the user id from a member filter path such as `members[value eq "<user_id>"]`
+fn get_member_id_from_path(path: &str) -> Option<String> {
+    path.strip_prefix("members[")
+        .and_then(|filter| filter.strip_suffix(']'))
+        .and_then(|filter| filter.trim().strip_prefix("value"))
+        .and_then(|filter| filter.trim_start().strip_prefix("eq"))
+        .map(|user_id| user_id.trim().trim_matches('"').to_string())
+        .filter(|user_id| !user_id.is_empty())
+}
+
+#[instrument(skip_all)]
+pub async fn patch_group(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    group_id: String,
+    request: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let role_info = get_role_info_for_group(
+        &state,
+        &group_id,
+        merchant_account.get_id(),
+        &merchant_account.organization_id,
+    )
+    .await?;
+
+    for operation in request.operations {
+        let path = operation.path.as_deref().unwrap_or("members");
+        let member_from_path = get_member_id_from_path(path);
+
+        if path != "members" && member_from_path.is_none() {
+            return Err(report!(UserErrors::ScimOperationNotSupported))
+                .attach_printable(format!("Unsupported patch path: {path}"));
+        }
+
+        let members: Vec<String> = match member_from_path {
+            Some(member) => vec![member],
+            None => get_patch_value::<Vec<scim_api::ScimMember>>(&operation)?
+                .into_iter()
+                .map(|member| member.value)
+                .collect(),
+        };
+
+        match operation.op {
+            scim_api::ScimPatchOperationType::Add => {
+                for member in members {
+                    set_scim_user_role(&state, &merchant_account, &member, &group_id).await?;
+                }
+            }
+            scim_api::ScimPatchOperationType::Replace => {
+                let existing_members = state
+                    .store
+                    .list_scim_users_by_merchant_id_role_id(merchant_account.get_id(), &group_id)
+                    .await
+                    .change_context(UserErrors::InternalServerError)?;
+
+                for existing_member in existing_members
+                    .into_iter()
+                    .filter(|scim_user| !members.contains(&scim_user.user_id))
+                {
+                    set_scim_user_role(
+                        &state,
+                        &merchant_account,
+                        &existing_member.user_id,
+                        consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY,
+                    )
+                    .await?;
+                }
+
+                for member in members {
+                    set_scim_user_role(&state, &merchant_account, &member, &group_id).await?;
+                }
+            }
+            // Members removed from a group fall back to the least privileged role
+            scim_api::ScimPatchOperationType::Remove => {
+                for member in members {
+                    set_scim_user_role(
+                        &state,
+                        &merchant_account,
+                        &member,
+                        consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY,
+                    )
+                    .await?;
+                }
+            }
+        }
+    }
+
+    build_scim_group_response(&state, &merchant_account, &role_info)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Deletes the SCIM token of the merchant, if one was issued
+async fn delete_scim_token(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> UserResult<()> {
+    let hash_config_key = merchant_id.get_scim_token_hash_config_key();
+    let hashed_token = match state.store.find_config_by_key(&hash_config_key).await {
+        Ok(config) => config.config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(()),
+        Err(error) => {
+            return Err(error
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Failed to fetch the SCIM token of the merchant"))
+        }
+    };
+
+    for key in [get_scim_token_config_key(&hashed_token), hash_config_key] {
+        match state.store.delete_config_by_key(&key).await {
+            Ok(_) => {}
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => {
+                return Err(error
+                    .change_context(UserErrors::InternalServerError)
+                    .attach_printable("Failed to delete the SCIM token of the merchant"))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// SCIM tokens are issued by the organization admin. A token only grants access to the SCIM
+/// endpoints of the merchant it was issued for.
+fn validate_scim_token_issuer(user_from_token: &auth::UserFromToken) -> UserResult<()> {
+    if user_from_token.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "Only the organization admin can manage SCIM tokens".to_string()
+        )));
+    }
+    Ok(())
+}
+
+/// Issues the SCIM token of the merchant, replacing the token issued earlier if any
+#[instrument(skip_all)]
+pub async fn create_scim_token(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<scim_api::ScimTokenResponse> {
+    validate_scim_token_issuer(&user_from_token)?;
+    delete_scim_token(&state, &user_from_token.merchant_id).await?;
+
+    let token = api_keys::PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let hash_key = state
+        .conf
+        .api_keys
+        .get_inner()
+        .get_hash_key()
+        .change_context(UserErrors::InternalServerError)?;
+    let hashed_token = storage::HashedApiKey::from(token.keyed_hash(hash_key.peek())).into_inner();
+
+    let scim_token_config = ScimTokenConfig {
+        org_id: user_from_token.org_id,
+        merchant_id: user_from_token.merchant_id,
+        created_at: common_utils::date_time::now(),
+    };
+    state
+        .store
+        .insert_config(storage::ConfigNew {
+            key: get_scim_token_config_key(&hashed_token),
+            config: serde_json::to_string(&scim_token_config)
+                .change_context(UserErrors::InternalServerError)?,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to store the SCIM token")?;
+    state
+        .store
+        .insert_config(storage::ConfigNew {
+            key: scim_token_config
+                .merchant_id
+                .get_scim_token_hash_config_key(),
+            config: hashed_token,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to store the SCIM token")?;
+
+    Ok(ApplicationResponse::Json(scim_api::ScimTokenResponse {
+        token: Secret::new(token.peek().to_string()),
+        org_id: scim_token_config.org_id,
+        merchant_id: scim_token_config.merchant_id,
+        created_at: scim_token_config.created_at,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn revoke_scim_token(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<()> {
+    validate_scim_token_issuer(&user_from_token)?;
+    delete_scim_token(&state, &user_from_token.merchant_id).await?;
+    Ok(ApplicationResponse::StatusOk)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_parse_scim_filter() {
+        assert_eq!(
+            parse_scim_filter(r#"userName eq "jane@example.com""#).unwrap(),
+            ScimFilter::UserName("jane@example.com".to_string())
+        );
+        assert_eq!(
+            parse_scim_filter(r#"displayName EQ "Merchant Admin""#).unwrap(),
+            ScimFilter::DisplayName("Merchant Admin".to_string())
+        );
+        assert!(parse_scim_filter(r#"userName co "jane""#).is_err());
+        assert!(parse_scim_filter(r#"title eq "engineer""#).is_err());
+        assert!(parse_scim_filter("userName").is_err());
+    }
+
+    #[test]
+    fn test_parse_list_filter_rejects_attributes_of_other_resources() {
+        assert_eq!(
+            parse_list_filter(
+                Some(r#"externalId eq "00u1""#),
+                ScimFilter::is_supported_for_users
+            )
+            .unwrap(),
+            Some(ScimFilter::ExternalId("00u1".to_string()))
+        );
+        assert_eq!(
+            parse_list_filter(None, ScimFilter::is_supported_for_users).unwrap(),
+            None
+        );
+
+        let error = parse_list_filter(
+            Some(r#"displayName eq "Merchant Admin""#),
+            ScimFilter::is_supported_for_users,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UserErrors::ScimInvalidFilter(_)
+        ));
+        assert!(parse_list_filter(
+            Some(r#"userName eq "jane@example.com""#),
+            ScimFilter::is_supported_for_groups
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_member_id_from_path() {
+        assert_eq!(
+            get_member_id_from_path(r#"members[value eq "user_123"]"#),
+            Some("user_123".to_string())
+        );
+        assert_eq!(get_member_id_from_path("members"), None);
+        assert_eq!(get_member_id_from_path("displayName"), None);
+    }
+}
@@ -10,7 +10,10 @@ use crate::{
     routes::{app::ReqState, SessionState},
     services::{
         authentication::{blacklist, UserFromToken},
-        authorization::roles::{self, predefined_roles::PREDEFINED_ROLES},
+        authorization::{
+            permissions::Permission,
+            roles::{self, predefined_roles::PREDEFINED_ROLES},
+        },
         ApplicationResponse,
     },
     types::domain::user::RoleName,
@@ -61,8 +64,9 @@ pub async fn create_role(
 ) -> UserResponse<role_api::RoleInfoWithGroupsResponse> {
     let now = common_utils::date_time::now();
     let role_name = RoleName::new(req.role_name)?;
+    let permissions: Vec<Permission> = req.permissions.into_iter().map(Into::into).collect();
 
-    utils::user_role::validate_role_groups(&req.groups)?;
+    utils::user_role::validate_role_groups_and_permissions(&req.groups, &permissions)?;
     utils::user_role::validate_role_name(
         &state,
         &role_name,
@@ -78,6 +82,21 @@ pub async fn create_role(
             .attach_printable("Non org admin user creating org level role");
     }
 
+    if matches!(req.role_scope, RoleScope::Organization) && req.profile_ids.is_some() {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Org level role cannot be restricted to profiles");
+    }
+
+    let user_role_info = user_from_token.get_role_info_from_db(&state).await?;
+    utils::user_role::validate_permission_escalation(&user_role_info, &req.groups, &permissions)?;
+    utils::user_role::validate_role_profile_ids(
+        &state,
+        &user_role_info,
+        &user_from_token.merchant_id,
+        req.profile_ids.as_ref(),
+    )
+    .await?;
+
     let role = state
         .store
         .insert_role(RoleNew {
@@ -88,6 +107,8 @@ pub async fn create_role(
             groups: req.groups,
             scope: req.role_scope,
             entity_type: req.entity_type,
+            permissions: permissions.iter().map(ToString::to_string).collect(),
+            profile_ids: req.profile_ids,
             created_by: user_from_token.user_id.clone(),
             last_modified_by: user_from_token.user_id,
             created_at: now,
@@ -97,12 +118,7 @@ pub async fn create_role(
         .to_duplicate_response(UserErrors::RoleNameAlreadyExists)?;
 
     Ok(ApplicationResponse::Json(
-        role_api::RoleInfoWithGroupsResponse {
-            groups: role.groups,
-            role_id: role.role_id,
-            role_name: role.role_name,
-            role_scope: role.scope,
-        },
+        get_role_info_with_groups_response(&roles::RoleInfo::from(role)),
     ))
 }
 
@@ -121,6 +137,7 @@ pub async fn list_invitable_roles_with_permissions(
                     .into_iter()
                     .map(Into::into)
                     .collect(),
+                profile_ids: None,
                 role_id: role_id.to_string(),
                 role_name: role_info.get_role_name().to_string(),
                 role_scope: role_info.get_scope(),
@@ -144,6 +161,7 @@ pub async fn list_invitable_roles_with_permissions(
                             .into_iter()
                             .map(Into::into)
                             .collect(),
+                        profile_ids: role_info.get_profile_ids().cloned(),
                         role_id: role_info.get_role_id().to_string(),
                         role_name: role_info.get_role_name().to_string(),
                         role_scope: role_info.get_scope(),
@@ -163,13 +181,8 @@ pub async fn list_invitable_roles_with_groups(
     let predefined_roles_map = PREDEFINED_ROLES
         .iter()
         .filter(|(_, role_info)| role_info.is_invitable())
-        .map(|(role_id, role_info)| {
-            role_api::RoleInfoResponse::Groups(role_api::RoleInfoWithGroupsResponse {
-                groups: role_info.get_permission_groups().to_vec(),
-                role_id: role_id.to_string(),
-                role_name: role_info.get_role_name().to_string(),
-                role_scope: role_info.get_scope(),
-            })
+        .map(|(_, role_info)| {
+            role_api::RoleInfoResponse::Groups(get_role_info_with_groups_response(role_info))
         });
 
     let custom_roles_map = state
@@ -183,12 +196,7 @@ pub async fn list_invitable_roles_with_groups(
             role_info
                 .is_invitable()
                 .then_some(role_api::RoleInfoResponse::Groups(
-                    role_api::RoleInfoWithGroupsResponse {
-                        groups: role_info.get_permission_groups().to_vec(),
-                        role_id: role_info.get_role_id().to_string(),
-                        role_name: role_info.get_role_name().to_string(),
-                        role_scope: role_info.get_scope(),
-                    },
+                    get_role_info_with_groups_response(&role_info),
                 ))
         });
 
@@ -225,6 +233,7 @@ pub async fn get_role_with_permissions(
     Ok(ApplicationResponse::Json(
         role_api::RoleInfoResponse::Permissions(role_api::RoleInfoWithPermissionsResponse {
             permissions,
+            profile_ids: role_info.get_profile_ids().cloned(),
             role_id: role.role_id,
             role_name: role_info.get_role_name().to_string(),
             role_scope: role_info.get_scope(),
@@ -251,12 +260,7 @@ pub async fn get_role_with_groups(
     }
 
     Ok(ApplicationResponse::Json(
-        role_api::RoleInfoResponse::Groups(role_api::RoleInfoWithGroupsResponse {
-            groups: role_info.get_permission_groups().to_vec(),
-            role_id: role.role_id,
-            role_name: role_info.get_role_name().to_string(),
-            role_scope: role_info.get_scope(),
-        }),
+        role_api::RoleInfoResponse::Groups(get_role_info_with_groups_response(&role_info)),
     ))
 }
 
@@ -266,7 +270,16 @@ pub async fn update_role(
     req: role_api::UpdateRoleRequest,
     role_id: &str,
 ) -> UserResponse<role_api::RoleInfoWithGroupsResponse> {
+    if PREDEFINED_ROLES.contains_key(role_id) {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "Predefined roles cannot be updated".to_string()
+        )));
+    }
+
     let role_name = req.role_name.map(RoleName::new).transpose()?;
+    let permissions: Option<Vec<Permission>> = req
+        .permissions
+        .map(|permissions| permissions.into_iter().map(Into::into).collect());
 
     if let Some(ref role_name) = role_name {
         utils::user_role::validate_role_name(
@@ -278,10 +291,6 @@ pub async fn update_role(
         .await?;
     }
 
-    if let Some(ref groups) = req.groups {
-        utils::user_role::validate_role_groups(groups)?;
-    }
-
     let role_info = roles::RoleInfo::from_role_id(
         &state,
         role_id,
@@ -291,6 +300,11 @@ pub async fn update_role(
     .await
     .to_not_found_response(UserErrors::InvalidRoleOperation)?;
 
+    if !role_info.is_updatable() {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable(format!("role_id = {role_id} is not updatable"));
+    }
+
     if matches!(role_info.get_scope(), RoleScope::Organization)
         && user_from_token.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN
     {
@@ -298,6 +312,39 @@ pub async fn update_role(
             .attach_printable("Non org admin user changing org level role");
     }
 
+    if matches!(role_info.get_scope(), RoleScope::Organization)
+        && matches!(req.profile_ids, Some(Some(_)))
+    {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Org level role cannot be restricted to profiles");
+    }
+
+    let user_role_info = user_from_token.get_role_info_from_db(&state).await?;
+
+    if req.groups.is_some() || permissions.is_some() {
+        let groups = req
+            .groups
+            .as_deref()
+            .unwrap_or(role_info.get_permission_groups());
+        let permissions = permissions
+            .as_deref()
+            .unwrap_or(role_info.get_permissions());
+
+        utils::user_role::validate_role_groups_and_permissions(groups, permissions)?;
+        utils::user_role::validate_permission_escalation(&user_role_info, groups, permissions)?;
+    }
+
+    // Removing the restriction of the role is validated like creating a role for all profiles
+    if let Some(ref profile_ids) = req.profile_ids {
+        utils::user_role::validate_role_profile_ids(
+            &state,
+            &user_role_info,
+            &user_from_token.merchant_id,
+            profile_ids.as_ref(),
+        )
+        .await?;
+    }
+
     let updated_role = state
         .store
         .update_role_by_role_id(
@@ -305,6 +352,9 @@ pub async fn update_role(
             RoleUpdate::UpdateDetails {
                 groups: req.groups,
                 role_name: role_name.map(RoleName::get_role_name),
+                permissions: permissions
+                    .map(|permissions| permissions.iter().map(ToString::to_string).collect()),
+                profile_ids: req.profile_ids,
                 last_modified_at: common_utils::date_time::now(),
                 last_modified_by: user_from_token.user_id,
             },
@@ -315,11 +365,81 @@ pub async fn update_role(
     blacklist::insert_role_in_blacklist(&state, role_id).await?;
 
     Ok(ApplicationResponse::Json(
-        role_api::RoleInfoWithGroupsResponse {
-            groups: updated_role.groups,
-            role_id: updated_role.role_id,
-            role_name: updated_role.role_name,
-            role_scope: updated_role.scope,
-        },
+        get_role_info_with_groups_response(&roles::RoleInfo::from(updated_role)),
     ))
 }
+
+pub async fn delete_role(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    role_id: &str,
+) -> UserResponse<()> {
+    if PREDEFINED_ROLES.contains_key(role_id) {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "Predefined roles cannot be deleted".to_string()
+        )));
+    }
+
+    let role_info = roles::RoleInfo::from_role_id(
+        &state,
+        role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+    if !role_info.is_deletable() {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable(format!("role_id = {role_id} is not deletable"));
+    }
+
+    if matches!(role_info.get_scope(), RoleScope::Organization)
+        && user_from_token.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN
+    {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Non org admin user deleting org level role");
+    }
+
+    // Assignments of every user role version across the organization keep the role in use
+    let is_role_assigned = !state
+        .store
+        .list_user_roles_by_role_id_org_id(role_id, &user_from_token.org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .is_empty();
+
+    if is_role_assigned {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "Role is assigned to users, update their roles before deleting it".to_string()
+        )));
+    }
+
+    state
+        .store
+        .delete_role_by_role_id(role_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    blacklist::insert_role_in_blacklist(&state, role_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+fn get_role_info_with_groups_response(
+    role_info: &roles::RoleInfo,
+) -> role_api::RoleInfoWithGroupsResponse {
+    role_api::RoleInfoWithGroupsResponse {
+        groups: role_info.get_permission_groups().to_vec(),
+        permissions: role_info
+            .get_permissions()
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect(),
+        profile_ids: role_info.get_profile_ids().cloned(),
+        role_id: role_info.get_role_id().to_string(),
+        role_name: role_info.get_role_name().to_string(),
+        role_scope: role_info.get_scope(),
+    }
+}
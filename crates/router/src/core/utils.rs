@@ -1,4 +1,8 @@
-use std::{collections::HashSet, marker::PhantomData, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    marker::PhantomData,
+    str::FromStr,
+};
 
 use api_models::enums::{DisputeStage, DisputeStatus};
 #[cfg(feature = "payouts")]
@@ -6,7 +10,12 @@ use api_models::payouts::PayoutVendorAccountDetails;
 use common_enums::{IntentStatus, RequestIncrementalAuthorization};
 #[cfg(feature = "payouts")]
 use common_utils::{crypto::Encryptable, pii::Email};
-use common_utils::{errors::CustomResult, ext_traits::AsyncExt, types::MinorUnit};
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{AsyncExt, StringExt},
+    types::MinorUnit,
+};
+use diesel_models::process_tracker::business_status;
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
     merchant_connector_account::MerchantConnectorAccount, payment_address::PaymentAddress,
@@ -15,7 +24,8 @@ use hyperswitch_domain_models::{
 #[cfg(feature = "payouts")]
 use masking::{ExposeInterface, PeekInterface};
 use maud::{html, PreEscaped};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use scheduler::{consumer::types::process_data, utils as scheduler_utils};
 use uuid::Uuid;
 
 use super::payments::helpers;
@@ -309,6 +319,14 @@ pub async fn construct_refund_router_data<'a, F>(
             field_name: "browser_info",
         })?;
 
+    // The reversals are resolved when the refund is created, so that the connector is sent the
+    // same allocations as those recorded on the refund
+    let split_refunds = refund
+        .split_refunds
+        .is_some()
+        .then(|| payments::split_payments::get_split_refunds(refund))
+        .transpose()?;
+
     let router_data = types::RouterData {
         flow: PhantomData,
         merchant_id: merchant_account.get_id().clone(),
@@ -345,6 +363,7 @@ pub async fn construct_refund_router_data<'a, F>(
             connector_refund_id: refund.connector_refund_id.clone(),
             browser_info,
             charges,
+            split_refunds,
             integrity_object: None,
         },
 
@@ -449,6 +468,23 @@ mod tests {
         assert_eq!(result, payment_id);
     }
 
+    #[test]
+    fn test_default_process_tracker_retries_back_off_to_a_day() {
+        let merchant_id = common_utils::id_type::MerchantId::get_irrelevant_merchant_id();
+        let schedule_time = |retry_count| {
+            scheduler_utils::get_schedule_time(
+                get_default_process_tracker_retry_mapping(),
+                &merchant_id,
+                retry_count,
+            )
+        };
+
+        assert_eq!(schedule_time(0), Some(60));
+        assert_eq!(schedule_time(1), Some(300));
+        assert_eq!(schedule_time(4), Some(86400));
+        assert_eq!(schedule_time(5), None);
+    }
+
     #[test]
     fn test_generate_id() {
         let generated_id = generate_id(consts::ID_LENGTH, "ref");
@@ -1426,3 +1462,77 @@ pub(super) fn validate_profile_id_from_auth_layer<T: GetProfileId + std::fmt::De
         (None, None) | (None, Some(_)) => Ok(()),
     }
 }
+
+/// Returns the time of the attempt of a process tracker task following the specified number of
+/// retries, or `None` once the retries are exhausted.
+///
+/// The attempts can be configured in configs with a `ConnectorPTMapping` stored under the
+/// `mapping_key`. Without one, the attempts back off from a minute to a day.
+#[instrument(skip_all)]
+pub async fn get_process_tracker_retry_schedule_time(
+    db: &dyn StorageInterface,
+    mapping_key: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let mapping = db
+        .find_config_by_key(mapping_key)
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("ConnectorPTMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        })
+        .unwrap_or_else(|error| {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(?error, "Failed to read retry config `{mapping_key}`");
+            }
+            get_default_process_tracker_retry_mapping()
+        });
+    let time_delta = scheduler_utils::get_schedule_time(mapping, merchant_id, retry_count);
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
+
+fn get_default_process_tracker_retry_mapping() -> process_data::ConnectorPTMapping {
+    process_data::ConnectorPTMapping {
+        default_mapping: process_data::RetryMapping {
+            start_after: 60,
+            frequencies: vec![(300, 1), (1800, 1), (7200, 1), (86400, 1)],
+        },
+        custom_merchant_mapping: HashMap::new(),
+        max_retries_count: 4,
+    }
+}
+
+/// Schedules the next attempt of a process tracker task as per the retry config under the
+/// `mapping_key`, finishing the task once its retries are exhausted
+#[instrument(skip_all)]
+pub async fn retry_process_tracker_task(
+    db: &dyn StorageInterface,
+    mapping_key: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+    process: storage::ProcessTracker,
+) -> CustomResult<(), errors::StorageError> {
+    let schedule_time = get_process_tracker_retry_schedule_time(
+        db,
+        mapping_key,
+        merchant_id,
+        process.retry_count + 1,
+    )
+    .await;
+
+    match schedule_time {
+        Some(schedule_time) => {
+            db.as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await
+        }
+    }
+}
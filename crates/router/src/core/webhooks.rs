@@ -4,11 +4,13 @@ pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
+pub mod webhook_signing_key;
 
 pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
     outgoing::{
-        create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
+        create_event_and_trigger_outgoing_webhook,
+        create_event_with_idempotent_id_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
         trigger_webhook_and_raise_event,
     },
 };
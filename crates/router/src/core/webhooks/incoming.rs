@@ -21,9 +21,9 @@ use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
-        metrics, payments, refunds, utils as core_utils,
+        ledger, metrics, payments, refunds, utils as core_utils,
         webhooks::utils::construct_webhook_router_data,
     },
     db::StorageInterface,
@@ -811,14 +811,25 @@ async fn refunds_incoming_webhook_flow(
                 .attach_printable("failed refund status mapping from event type")?,
             updated_by: merchant_account.storage_scheme.to_string(),
         };
-        db.update_refund(
-            refund.to_owned(),
-            refund_update,
+        let updated_refund = db
+            .update_refund(
+                refund.to_owned(),
+                refund_update,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable_lazy(|| {
+                format!("Failed while updating refund: refund_id: {refund_id}")
+            })?;
+        // The refund sync reverses the tax of the refund, which has to be done here otherwise
+        payments::tax_calculation::reverse_tax_transaction_or_schedule_retry(
+            &state,
+            &key_store,
             merchant_account.storage_scheme,
+            updated_refund,
         )
         .await
-        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
-        .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
     } else {
         Box::pin(refunds::refund_retrieve_core(
             state.clone(),
@@ -834,6 +845,9 @@ async fn refunds_incoming_webhook_flow(
         .await
         .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
     };
+    if let Err(error) = ledger::record_refund_entries(&state, &updated_refund).await {
+        logger::error!(?error, "Failed to record the ledger entries of the refund");
+    }
     let event_type: Option<enums::EventType> = updated_refund.refund_status.foreign_into();
 
     // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
@@ -1397,6 +1411,14 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        let _ = disputes::deadlines::add_or_update_dispute_deadline_task(&state, &dispute_object)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to schedule dispute deadline task");
+            });
+        if let Err(error) = ledger::record_dispute_entries(&state, &dispute_object).await {
+            logger::error!(?error, "Failed to record the ledger entries of the dispute");
+        }
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
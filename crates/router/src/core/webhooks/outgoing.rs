@@ -60,10 +60,50 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        enums::WebhookDeliveryAttempt::InitialAttempt,
+    );
+
+    Box::pin(
+        create_event_with_idempotent_id_and_trigger_outgoing_webhook(
+            state,
+            merchant_account,
+            business_profile,
+            merchant_key_store,
+            event_type,
+            event_class,
+            primary_object_id,
+            primary_object_type,
+            content,
+            primary_object_created_at,
+            idempotent_event_id,
+        ),
+    )
+    .await
+}
+
+/// Creates an event with the provided idempotent event ID and triggers the outgoing webhook for
+/// it. This is used for events which may be raised more than once for the same resource and event
+/// type, such as dispute deadline reminders.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(crate) async fn create_event_with_idempotent_id_and_trigger_outgoing_webhook(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    business_profile: domain::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    idempotent_event_id: String,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
     let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
 
     if !state.conf.webhooks.outgoing_enabled
@@ -270,10 +310,18 @@ async fn trigger_webhook_to_merchant(
 
     let event_id = event.event_id;
 
+    let signature_headers = super::webhook_signing_key::get_webhook_signature_headers(
+        &state,
+        merchant_key_store,
+        &business_profile.profile_id,
+        request_content.body.peek(),
+    )
+    .await?;
     let headers = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
+        .chain(signature_headers)
         .collect();
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
@@ -931,6 +979,9 @@ impl ForeignFrom<(&api::OutgoingWebhookContent, &str)> for storage::EventMetadat
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::InvoiceDetails(invoice_response) => Self::Invoice {
+                invoice_id: invoice_response.invoice_id.clone(),
+            },
         }
     }
 }
@@ -975,5 +1026,11 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::Invoice { invoice_id } => {
+            OutgoingWebhookEventContent::Invoice {
+                invoice_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
@@ -0,0 +1,491 @@
+use api_models::webhook_signing_key::{
+    Jwk, Jwks, WebhookSigningKeyCreateRequest, WebhookSigningKeyId, WebhookSigningKeyListResponse,
+    WebhookSigningKeyProfileId, WebhookSigningKeyResponse,
+};
+use base64::Engine;
+use common_utils::{
+    type_name,
+    types::keymanager::{Identifier, KeyManagerState},
+};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use ring::{
+    rand::SystemRandom,
+    signature::{self, KeyPair},
+};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+    db::MasterKeyInterface,
+    headers,
+    routes::SessionState,
+    services::{request::Maskable, ApplicationResponse},
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+struct GeneratedKeyPair {
+    pkcs8_document: Vec<u8>,
+    public_key: Vec<u8>,
+}
+
+fn generate_key_pair(algorithm: enums::WebhookSigningAlgorithm) -> RouterResult<GeneratedKeyPair> {
+    let rng = SystemRandom::new();
+    match algorithm {
+        enums::WebhookSigningAlgorithm::Ed25519 => {
+            let pkcs8_document = signature::Ed25519KeyPair::generate_pkcs8(&rng)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to generate Ed25519 key pair")?;
+            let key_pair = signature::Ed25519KeyPair::from_pkcs8(pkcs8_document.as_ref())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse generated Ed25519 key pair")?;
+            Ok(GeneratedKeyPair {
+                pkcs8_document: pkcs8_document.as_ref().to_vec(),
+                public_key: key_pair.public_key().as_ref().to_vec(),
+            })
+        }
+        enums::WebhookSigningAlgorithm::EcdsaP256Sha256 => {
+            let pkcs8_document = signature::EcdsaKeyPair::generate_pkcs8(
+                &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                &rng,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate ECDSA key pair")?;
+            let key_pair = signature::EcdsaKeyPair::from_pkcs8(
+                &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                pkcs8_document.as_ref(),
+                &rng,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse generated ECDSA key pair")?;
+            Ok(GeneratedKeyPair {
+                pkcs8_document: pkcs8_document.as_ref().to_vec(),
+                public_key: key_pair.public_key().as_ref().to_vec(),
+            })
+        }
+    }
+}
+
+fn sign_message(
+    algorithm: enums::WebhookSigningAlgorithm,
+    pkcs8_document: &[u8],
+    message: &[u8],
+) -> CustomResult<Vec<u8>, errors::WebhooksFlowError> {
+    match algorithm {
+        enums::WebhookSigningAlgorithm::Ed25519 => {
+            let key_pair = signature::Ed25519KeyPair::from_pkcs8(pkcs8_document)
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+                .attach_printable("Failed to parse Ed25519 private key")?;
+            Ok(key_pair.sign(message).as_ref().to_vec())
+        }
+        enums::WebhookSigningAlgorithm::EcdsaP256Sha256 => {
+            let rng = SystemRandom::new();
+            let key_pair = signature::EcdsaKeyPair::from_pkcs8(
+                &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                pkcs8_document,
+                &rng,
+            )
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+            .attach_printable("Failed to parse ECDSA private key")?;
+            key_pair
+                .sign(&rng, message)
+                .map(|signature| signature.as_ref().to_vec())
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+                .attach_printable("Failed to sign message with ECDSA private key")
+        }
+    }
+}
+
+/// Converts a public key generated by `ring` to the JSON Web Key format. ECDSA public keys are
+/// uncompressed points, a `0x04` byte followed by the x and y coordinates.
+fn get_jwk(signing_key: &storage::WebhookSigningKey) -> RouterResult<Jwk> {
+    let encode = |bytes: &[u8]| consts::BASE64_ENGINE_URL_SAFE_NO_PAD.encode(bytes);
+    match signing_key.algorithm {
+        enums::WebhookSigningAlgorithm::Ed25519 => Ok(Jwk {
+            kty: "OKP".to_string(),
+            crv: "Ed25519".to_string(),
+            kid: signing_key.key_id.clone(),
+            alg: "EdDSA".to_string(),
+            key_use: "sig".to_string(),
+            x: encode(&signing_key.public_key),
+            y: None,
+        }),
+        enums::WebhookSigningAlgorithm::EcdsaP256Sha256 => {
+            let (x, y) = signing_key
+                .public_key
+                .get(1..33)
+                .zip(signing_key.public_key.get(33..65))
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid ECDSA public key length")?;
+            Ok(Jwk {
+                kty: "EC".to_string(),
+                crv: "P-256".to_string(),
+                kid: signing_key.key_id.clone(),
+                alg: "ES256".to_string(),
+                key_use: "sig".to_string(),
+                x: encode(x),
+                y: Some(encode(y)),
+            })
+        }
+    }
+}
+
+fn get_webhook_signing_key_response(
+    signing_key: storage::WebhookSigningKey,
+) -> RouterResult<WebhookSigningKeyResponse> {
+    let public_key = get_jwk(&signing_key)?;
+    Ok(WebhookSigningKeyResponse {
+        key_id: signing_key.key_id,
+        profile_id: signing_key.profile_id,
+        algorithm: signing_key.algorithm,
+        status: signing_key.status,
+        public_key,
+        created_at: signing_key.created_at,
+        expires_at: signing_key.expires_at,
+    })
+}
+
+async fn get_merchant_key_store(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<domain::MerchantKeyStore> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(
+        &state.into(),
+        merchant_id,
+        &db.get_master_key().to_vec().into(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
+
+/// Creates a new key to sign the webhooks of the profile. The key which was signing the webhooks
+/// so far remains published for the overlap period, so that merchants can verify in-flight
+/// webhooks while they pick up the new key.
+#[instrument(skip_all)]
+pub async fn create_webhook_signing_key(
+    state: SessionState,
+    profile: WebhookSigningKeyProfileId,
+    req: WebhookSigningKeyCreateRequest,
+) -> RouterResponse<WebhookSigningKeyResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state: &KeyManagerState = &(&state).into();
+    let key_store = get_merchant_key_store(&state, &profile.merchant_id).await?;
+    db.find_business_profile_by_merchant_id_profile_id(
+        key_manager_state,
+        &key_store,
+        &profile.merchant_id,
+        &profile.profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+        id: profile.profile_id.clone(),
+    })?;
+
+    let key_pair = generate_key_pair(req.algorithm)?;
+    let private_key: common_utils::crypto::Encryptable<Secret<Vec<u8>>> =
+        domain::types::crypto_operation(
+            key_manager_state,
+            type_name!(storage::WebhookSigningKey),
+            domain::types::CryptoOperation::Encrypt(Secret::new(key_pair.pkcs8_document)),
+            Identifier::Merchant(key_store.merchant_id.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt webhook signing key")?;
+
+    let current_time = common_utils::date_time::now();
+    let overlap_period = req
+        .overlap_period_in_secs
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SIGNING_KEY_OVERLAP_PERIOD_IN_SECS);
+    // The active key is rotated along with the insertion, so that a failed insertion leaves it
+    // active
+    let signing_key = db
+        .rotate_and_insert_webhook_signing_key(
+            storage::WebhookSigningKeyNew {
+                key_id: common_utils::generate_id_with_default_len("whsk"),
+                merchant_id: profile.merchant_id,
+                profile_id: profile.profile_id,
+                algorithm: req.algorithm,
+                status: enums::WebhookSigningKeyStatus::Active,
+                private_key: private_key.into(),
+                public_key: key_pair.public_key,
+                created_at: current_time,
+                modified_at: current_time,
+                expires_at: None,
+            },
+            current_time.saturating_add(time::Duration::seconds(i64::from(overlap_period))),
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "A webhook signing key is being created for the profile".to_string(),
+        })?;
+
+    get_webhook_signing_key_response(signing_key).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn list_webhook_signing_keys(
+    state: SessionState,
+    profile: WebhookSigningKeyProfileId,
+) -> RouterResponse<WebhookSigningKeyListResponse> {
+    let data = state
+        .store
+        .list_webhook_signing_keys_by_profile_id(&profile.profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook signing keys")?
+        .into_iter()
+        .filter(|signing_key| signing_key.merchant_id == profile.merchant_id)
+        .map(get_webhook_signing_key_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(ApplicationResponse::Json(WebhookSigningKeyListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+/// Revokes a key, the key is removed from the JWKS of the profile immediately. Webhooks are no
+/// longer signed with a private key once the active key is revoked.
+#[instrument(skip_all)]
+pub async fn revoke_webhook_signing_key(
+    state: SessionState,
+    req: WebhookSigningKeyId,
+) -> RouterResponse<WebhookSigningKeyResponse> {
+    let db = state.store.as_ref();
+    let signing_key = db
+        .find_webhook_signing_key_by_profile_id_key_id(&req.profile_id, &req.key_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook signing key {} does not exist", req.key_id),
+        })?;
+    if signing_key.merchant_id != req.merchant_id {
+        return Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook signing key {} does not exist", req.key_id),
+        }
+        .into());
+    }
+
+    let signing_key = match signing_key.status {
+        enums::WebhookSigningKeyStatus::Revoked => signing_key,
+        enums::WebhookSigningKeyStatus::Active | enums::WebhookSigningKeyStatus::Rotated => db
+            .update_webhook_signing_key_by_key_id(
+                &signing_key.key_id,
+                storage::WebhookSigningKeyUpdate::Revoked,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to revoke webhook signing key")?,
+    };
+
+    get_webhook_signing_key_response(signing_key).map(ApplicationResponse::Json)
+}
+
+/// Lists the public keys which can be used to verify the webhooks of the profile
+#[instrument(skip_all)]
+pub async fn retrieve_webhook_signing_jwks(
+    state: SessionState,
+    profile: WebhookSigningKeyProfileId,
+) -> RouterResponse<Jwks> {
+    let current_time = common_utils::date_time::now();
+    let keys = state
+        .store
+        .list_webhook_signing_keys_by_profile_id(&profile.profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook signing keys")?
+        .iter()
+        .filter(|signing_key| {
+            signing_key.merchant_id == profile.merchant_id && signing_key.is_published(current_time)
+        })
+        .map(get_jwk)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(ApplicationResponse::Json(Jwks { keys }))
+}
+
+/// Signs the payload of an outgoing webhook with the active key of the profile, if the profile
+/// has one. The signed message is `{timestamp}.{payload}`, where the timestamp is the Unix time at
+/// which the webhook is sent; merchants are expected to reject webhooks with a stale timestamp to
+/// prevent replays. The signature is computed for every delivery attempt, so that retried webhooks
+/// carry a fresh timestamp.
+#[instrument(skip_all)]
+pub(crate) async fn get_webhook_signature_headers(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+    payload: &str,
+) -> CustomResult<Vec<(String, Maskable<String>)>, errors::WebhooksFlowError> {
+    let Some(signing_key) = state
+        .store
+        .find_active_webhook_signing_key_by_profile_id(profile_id)
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to fetch active webhook signing key")?
+    else {
+        return Ok(Vec::new());
+    };
+
+    let private_key: common_utils::crypto::Encryptable<Secret<Vec<u8>>> =
+        domain::types::crypto_operation(
+            &state.into(),
+            type_name!(storage::WebhookSigningKey),
+            domain::types::CryptoOperation::Decrypt(signing_key.private_key),
+            Identifier::Merchant(merchant_key_store.merchant_id.clone()),
+            merchant_key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to decrypt webhook signing key")?;
+
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let signature = sign_message(
+        signing_key.algorithm,
+        private_key.get_inner().peek(),
+        format!("{timestamp}.{payload}").as_bytes(),
+    )?;
+
+    Ok(vec![
+        (
+            headers::X_WEBHOOK_TIMESTAMP.to_string(),
+            timestamp.to_string().into(),
+        ),
+        (
+            headers::X_WEBHOOK_KEY_ID.to_string(),
+            signing_key.key_id.into(),
+        ),
+        (
+            headers::X_WEBHOOK_ASYMMETRIC_SIGNATURE.to_string(),
+            consts::BASE64_ENGINE_URL_SAFE_NO_PAD
+                .encode(signature)
+                .into(),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_signing_key(
+        algorithm: enums::WebhookSigningAlgorithm,
+    ) -> (storage::WebhookSigningKey, Vec<u8>) {
+        let key_pair = generate_key_pair(algorithm).unwrap();
+        let current_time = common_utils::date_time::now();
+        let signing_key = storage::WebhookSigningKey {
+            key_id: "whsk_test".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            profile_id: "pro_test".to_string(),
+            algorithm,
+            status: enums::WebhookSigningKeyStatus::Active,
+            private_key: common_utils::encryption::Encryption::new(Secret::new(Vec::new())),
+            public_key: key_pair.public_key,
+            created_at: current_time,
+            modified_at: current_time,
+            expires_at: None,
+        };
+        (signing_key, key_pair.pkcs8_document)
+    }
+
+    /// Rebuilds the public key from its JSON Web Key, as a merchant verifying the webhooks would
+    fn get_public_key_from_jwk(jwk: &Jwk) -> Vec<u8> {
+        let decode = |value: &str| consts::BASE64_ENGINE_URL_SAFE_NO_PAD.decode(value).unwrap();
+        match jwk.y.as_deref() {
+            Some(y) => [vec![0x04], decode(&jwk.x), decode(y)].concat(),
+            None => decode(&jwk.x),
+        }
+    }
+
+    #[test]
+    fn test_signature_is_verified_with_the_published_jwk() {
+        let message = br#"1726000000.{"event_type":"payment_succeeded"}"#;
+        for algorithm in [
+            enums::WebhookSigningAlgorithm::Ed25519,
+            enums::WebhookSigningAlgorithm::EcdsaP256Sha256,
+        ] {
+            let (signing_key, pkcs8_document) = get_signing_key(algorithm);
+            let jwk = get_jwk(&signing_key).unwrap();
+            assert_eq!(jwk.kid, signing_key.key_id);
+
+            let verification_algorithm: &'static dyn signature::VerificationAlgorithm =
+                match algorithm {
+                    enums::WebhookSigningAlgorithm::Ed25519 => {
+                        assert_eq!((jwk.kty.as_str(), jwk.alg.as_str()), ("OKP", "EdDSA"));
+                        &signature::ED25519
+                    }
+                    enums::WebhookSigningAlgorithm::EcdsaP256Sha256 => {
+                        assert_eq!((jwk.kty.as_str(), jwk.alg.as_str()), ("EC", "ES256"));
+                        &signature::ECDSA_P256_SHA256_FIXED
+                    }
+                };
+            let public_key = signature::UnparsedPublicKey::new(
+                verification_algorithm,
+                get_public_key_from_jwk(&jwk),
+            );
+
+            let signed = sign_message(algorithm, &pkcs8_document, message).unwrap();
+            assert!(public_key.verify(message, &signed).is_ok());
+            assert!(public_key
+                .verify(br#"1726000001.{"event_type":"payment_succeeded"}"#, &signed)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_get_jwk_rejects_truncated_ecdsa_public_key() {
+        let (mut signing_key, _) = get_signing_key(enums::WebhookSigningAlgorithm::EcdsaP256Sha256);
+        signing_key.public_key.truncate(33);
+        assert!(get_jwk(&signing_key).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_active_key_is_rotated_when_a_new_key_is_inserted() {
+        use crate::db::webhook_signing_key::WebhookSigningKeyInterface;
+
+        let db = storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let (active_key, _) = get_signing_key(enums::WebhookSigningAlgorithm::Ed25519);
+        let new_key = |key_id: &str| storage::WebhookSigningKeyNew {
+            key_id: key_id.to_string(),
+            merchant_id: active_key.merchant_id.clone(),
+            profile_id: active_key.profile_id.clone(),
+            algorithm: active_key.algorithm,
+            status: enums::WebhookSigningKeyStatus::Active,
+            private_key: active_key.private_key.clone(),
+            public_key: active_key.public_key.clone(),
+            created_at: active_key.created_at,
+            modified_at: active_key.modified_at,
+            expires_at: None,
+        };
+        db.insert_webhook_signing_key(new_key(&active_key.key_id))
+            .await
+            .unwrap();
+
+        let expires_at = common_utils::date_time::now().saturating_add(time::Duration::hours(1));
+        let signing_key = db
+            .rotate_and_insert_webhook_signing_key(new_key("whsk_new"), expires_at)
+            .await
+            .unwrap();
+        assert_eq!(signing_key.status, enums::WebhookSigningKeyStatus::Active);
+
+        let rotated_key = db
+            .find_webhook_signing_key_by_profile_id_key_id(
+                &active_key.profile_id,
+                &active_key.key_id,
+            )
+            .await
+            .unwrap();
+        assert_eq!(rotated_key.status, enums::WebhookSigningKeyStatus::Rotated);
+        assert_eq!(rotated_key.expires_at, Some(expires_at));
+    }
+}
@@ -11,6 +11,8 @@ pub mod cards_info;
 pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
+pub mod data_subject_request;
 pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
@@ -19,23 +21,31 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod invoice;
 pub mod kafka_store;
+pub mod ledger;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_rotation;
 pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payout_batch;
 pub mod refund;
+pub mod refund_batch;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod scim;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_signing_key;
 use common_utils::id_type;
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckUpdate},
@@ -129,6 +139,16 @@ pub trait StorageInterface:
     + user_authentication_method::UserAuthenticationMethodInterface
     + authentication::AuthenticationInterface
     + generic_link::GenericLinkInterface
+    + scim::ScimUserInterface
+    + refund_batch::RefundBatchInterface
+    + payout_batch::PayoutBatchInterface
+    + data_retention::DataRetentionInterface
+    + data_subject_request::DataSubjectRequestInterface
+    + merchant_key_rotation::MerchantKeyRotationInterface
+    + webhook_signing_key::WebhookSigningKeyInterface
+    + reusable_payment_link::ReusablePaymentLinkInterface
+    + invoice::InvoiceInterface
+    + ledger::LedgerInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -335,6 +355,17 @@ impl FraudCheckInterface for KafkaStore {
         }
         Ok(frm)
     }
+    async fn list_fraud_checks_by_merchant_id_frm_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        frm_status: diesel_models::enums::FraudCheckStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, StorageError> {
+        self.diesel_store
+            .list_fraud_checks_by_merchant_id_frm_status(merchant_id, frm_status, limit, offset)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
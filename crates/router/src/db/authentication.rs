@@ -173,10 +173,21 @@ impl AuthenticationInterface for MockDb {
 
     async fn find_authentication_by_merchant_id_connector_authentication_id(
         &self,
-        _merchant_id: common_utils::id_type::MerchantId,
-        _connector_authentication_id: String,
+        merchant_id: common_utils::id_type::MerchantId,
+        connector_authentication_id: String,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let authentications = self.authentications.lock().await;
+        authentications
+            .iter()
+            .find(|a| {
+                a.merchant_id == merchant_id
+                    && a.connector_authentication_id.as_ref() == Some(&connector_authentication_id)
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find authentication for connector_authentication_id = {connector_authentication_id} and merchant_id = {merchant_id:?}"
+                )).into(),
+            ).cloned()
     }
 
     async fn update_authentication_by_merchant_id_authentication_id(
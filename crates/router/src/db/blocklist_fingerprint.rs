@@ -1,4 +1,4 @@
-use error_stack::report;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 use storage_impl::MockDb;
 
@@ -59,17 +59,53 @@ impl BlocklistFingerprintInterface for Store {
 impl BlocklistFingerprintInterface for MockDb {
     async fn insert_blocklist_fingerprint_entry(
         &self,
-        _pm_fingerprint_new: storage::BlocklistFingerprintNew,
+        pm_fingerprint_new: storage::BlocklistFingerprintNew,
     ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut fingerprints = self.blocklist_fingerprints.lock().await;
+        if fingerprints.iter().any(|fingerprint| {
+            fingerprint.merchant_id == pm_fingerprint_new.merchant_id
+                && fingerprint.fingerprint_id == pm_fingerprint_new.fingerprint_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "fingerprint_id",
+                key: Some(pm_fingerprint_new.fingerprint_id.clone()),
+            })?
+        }
+
+        let fingerprint = storage::BlocklistFingerprint {
+            id: i32::try_from(fingerprints.len())
+                .change_context(errors::StorageError::MockDbError)?,
+            merchant_id: pm_fingerprint_new.merchant_id,
+            fingerprint_id: pm_fingerprint_new.fingerprint_id,
+            data_kind: pm_fingerprint_new.data_kind,
+            encrypted_fingerprint: pm_fingerprint_new.encrypted_fingerprint,
+            created_at: pm_fingerprint_new.created_at,
+        };
+        fingerprints.push(fingerprint.clone());
+
+        Ok(fingerprint)
     }
 
     async fn find_blocklist_fingerprint_by_merchant_id_fingerprint_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _fingerprint_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
     ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.blocklist_fingerprints
+            .lock()
+            .await
+            .iter()
+            .find(|fingerprint| {
+                fingerprint.merchant_id == *merchant_id
+                    && fingerprint.fingerprint_id == fingerprint_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No blocklist fingerprint found for merchant_id = {merchant_id:?} and fingerprint_id = {fingerprint_id}"
+                ))
+                .into(),
+            )
     }
 }
 
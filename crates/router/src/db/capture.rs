@@ -206,21 +206,46 @@ impl CaptureInterface for MockDb {
     #[instrument(skip_all)]
     async fn update_capture_with_capture_id(
         &self,
-        _this: types::Capture,
-        _capture: types::CaptureUpdate,
+        this: types::Capture,
+        capture: types::CaptureUpdate,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::Capture, errors::StorageError> {
-        //Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut captures = self.captures.lock().await;
+        let capture_to_update = captures
+            .iter_mut()
+            .find(|item| item.capture_id == this.capture_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No capture found for capture_id = {}",
+                this.capture_id
+            )))?;
+
+        let updated_capture =
+            diesel_models::capture::CaptureUpdateInternal::from(capture).apply_changeset(this);
+        *capture_to_update = updated_capture.clone();
+
+        Ok(updated_capture)
     }
     async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payment_id: &str,
-        _authorized_attempt_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &str,
+        authorized_attempt_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::Capture>, errors::StorageError> {
-        //Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut captures = self
+            .captures
+            .lock()
+            .await
+            .iter()
+            .filter(|capture| {
+                capture.merchant_id == *merchant_id
+                    && capture.payment_id == payment_id
+                    && capture.authorized_attempt_id == authorized_attempt_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        captures.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        Ok(captures)
     }
 }
@@ -1,20 +1,94 @@
-use error_stack::report;
+use async_bb8_diesel::AsyncConnection;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
+use storage_impl::redis::cache::{self, CARD_INFO_CACHE};
 
 use crate::{
     connection,
     core::errors::{self, CustomResult},
     db::MockDb,
     services::Store,
-    types::storage::cards_info::CardInfo,
+    types::storage::cards_info::{CardBinRange, CardBinRangeNew, CardBinRangeUpdate, CardInfo},
 };
 
+/// BIN lengths for which ranges can be configured.
+pub const CARD_BIN_RANGE_LENGTHS: [usize; 2] = [8, 6];
+
+/// The BINs to be looked up for `card_iin`, most specific first: the given value followed by its
+/// 8 and 6 digit prefixes.
+fn candidate_bins(card_iin: &str) -> Vec<&str> {
+    let mut candidates = vec![card_iin];
+    candidates.extend(
+        CARD_BIN_RANGE_LENGTHS
+            .iter()
+            .filter(|length| card_iin.len() > **length)
+            .filter_map(|length| card_iin.get(..*length)),
+    );
+    candidates
+}
+
+/// Picks the narrowest of the ranges containing a BIN, so that a specific range can override a
+/// broader one.
+fn narrowest_range(ranges: Vec<CardBinRange>) -> Option<CardBinRange> {
+    ranges.into_iter().min_by_key(|range| {
+        range
+            .bin_end
+            .parse::<u64>()
+            .ok()
+            .zip(range.bin_start.parse::<u64>().ok())
+            .map_or(u64::MAX, |(end, start)| end.saturating_sub(start))
+    })
+}
+
 #[async_trait::async_trait]
 pub trait CardsInfoInterface {
+    /// Resolves the card details of a BIN, from an exact entry of `cards_info` or from the
+    /// narrowest BIN range containing it.
     async fn get_card_info(
         &self,
         _card_iin: &str,
     ) -> CustomResult<Option<CardInfo>, errors::StorageError>;
+
+    async fn insert_card_bin_range(
+        &self,
+        card_bin_range: CardBinRangeNew,
+    ) -> CustomResult<CardBinRange, errors::StorageError>;
+
+    /// Finds the ranges of any length starting at or before `last_bin` and ending at or after
+    /// `first_bin`, which include every range overlapping a range lying between them.
+    async fn find_card_bin_ranges_between(
+        &self,
+        first_bin: &str,
+        last_bin: &str,
+    ) -> CustomResult<Vec<CardBinRange>, errors::StorageError>;
+
+    /// Creates and updates the ranges of an upload atomically, the updated ranges being keyed by
+    /// their first and last BIN
+    async fn import_card_bin_ranges(
+        &self,
+        new_ranges: Vec<CardBinRangeNew>,
+        updated_ranges: Vec<(String, String, CardBinRangeUpdate)>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn update_card_bin_range(
+        &self,
+        bin_start: &str,
+        bin_end: &str,
+        card_bin_range_update: CardBinRangeUpdate,
+    ) -> CustomResult<CardBinRange, errors::StorageError>;
+
+    async fn delete_card_bin_range(
+        &self,
+        bin_start: &str,
+        bin_end: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn list_card_bin_ranges(
+        &self,
+        bin_length: Option<i32>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<CardBinRange>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -24,8 +98,128 @@ impl CardsInfoInterface for Store {
         &self,
         card_iin: &str,
     ) -> CustomResult<Option<CardInfo>, errors::StorageError> {
+        let find_card_info_from_db = || async {
+            let conn = connection::pg_connection_read(self).await?;
+            for card_bin in candidate_bins(card_iin) {
+                if let Some(card_info) = CardInfo::find_by_iin(&conn, card_bin)
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))?
+                {
+                    return Ok(Some(card_info));
+                }
+
+                if CARD_BIN_RANGE_LENGTHS.contains(&card_bin.len()) {
+                    let ranges = CardBinRange::find_by_containing_bin(&conn, card_bin)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))?;
+                    if let Some(range) = narrowest_range(ranges) {
+                        return Ok(Some(range.into_card_info(card_bin.to_owned())));
+                    }
+                }
+            }
+            Ok::<_, error_stack::Report<errors::StorageError>>(None)
+        };
+
+        cache::get_or_populate_in_memory_only(
+            self,
+            &cache::get_card_info_cache_key(card_iin),
+            find_card_info_from_db,
+            &CARD_INFO_CACHE,
+        )
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_card_bin_range(
+        &self,
+        card_bin_range: CardBinRangeNew,
+    ) -> CustomResult<CardBinRange, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        card_bin_range
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_card_bin_ranges_between(
+        &self,
+        first_bin: &str,
+        last_bin: &str,
+    ) -> CustomResult<Vec<CardBinRange>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        CardBinRange::find_by_bins_between(&conn, first_bin, last_bin)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn import_card_bin_ranges(
+        &self,
+        new_ranges: Vec<CardBinRangeNew>,
+        updated_ranges: Vec<(String, String, CardBinRangeUpdate)>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            for range in new_ranges {
+                range
+                    .insert(&conn)
+                    .await
+                    .map_err(|e| *e.current_context())?;
+            }
+            for (bin_start, bin_end, range_update) in updated_ranges {
+                CardBinRange::update_by_bin_start_bin_end(
+                    &conn,
+                    &bin_start,
+                    &bin_end,
+                    range_update,
+                )
+                .await
+                .map_err(|e| *e.current_context())?;
+            }
+            Ok::<_, diesel_models::errors::DatabaseError>(())
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_card_bin_range(
+        &self,
+        bin_start: &str,
+        bin_end: &str,
+        card_bin_range_update: CardBinRangeUpdate,
+    ) -> CustomResult<CardBinRange, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        CardBinRange::update_by_bin_start_bin_end(&conn, bin_start, bin_end, card_bin_range_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_card_bin_range(
+        &self,
+        bin_start: &str,
+        bin_end: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        CardBinRange::delete_by_bin_start_bin_end(&conn, bin_start, bin_end)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_card_bin_ranges(
+        &self,
+        bin_length: Option<i32>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<CardBinRange>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
-        CardInfo::find_by_iin(&conn, card_iin)
+        CardBinRange::list(&conn, bin_length, limit, offset)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
@@ -38,12 +232,249 @@ impl CardsInfoInterface for MockDb {
         &self,
         card_iin: &str,
     ) -> CustomResult<Option<CardInfo>, errors::StorageError> {
-        Ok(self
-            .cards_info
+        let cards_info = self.cards_info.lock().await;
+        let card_bin_ranges = self.card_bin_ranges.lock().await;
+        for card_bin in candidate_bins(card_iin) {
+            if let Some(card_info) = cards_info.iter().find(|ci| ci.card_iin == card_bin) {
+                return Ok(Some(card_info.clone()));
+            }
+
+            let ranges = card_bin_ranges
+                .iter()
+                .filter(|range| {
+                    range.bin_start.len() == card_bin.len()
+                        && range.bin_start.as_str() <= card_bin
+                        && range.bin_end.as_str() >= card_bin
+                })
+                .cloned()
+                .collect();
+            if let Some(range) = narrowest_range(ranges) {
+                return Ok(Some(range.into_card_info(card_bin.to_owned())));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn insert_card_bin_range(
+        &self,
+        card_bin_range: CardBinRangeNew,
+    ) -> CustomResult<CardBinRange, errors::StorageError> {
+        let mut card_bin_ranges = self.card_bin_ranges.lock().await;
+        if card_bin_ranges.iter().any(|range| {
+            range.bin_start == card_bin_range.bin_start && range.bin_end == card_bin_range.bin_end
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "card_bin_range",
+                key: Some(format!(
+                    "{}-{}",
+                    card_bin_range.bin_start, card_bin_range.bin_end
+                )),
+            })?
+        }
+
+        let range = CardBinRange {
+            bin_start: card_bin_range.bin_start,
+            bin_end: card_bin_range.bin_end,
+            bin_length: card_bin_range.bin_length,
+            card_issuer: card_bin_range.card_issuer,
+            card_network: card_bin_range.card_network,
+            card_type: card_bin_range.card_type,
+            card_subtype: card_bin_range.card_subtype,
+            card_issuing_country: card_bin_range.card_issuing_country,
+            last_updated_provider: card_bin_range.last_updated_provider,
+            created_at: card_bin_range.created_at,
+            modified_at: card_bin_range.modified_at,
+        };
+        card_bin_ranges.push(range.clone());
+        Ok(range)
+    }
+
+    async fn find_card_bin_ranges_between(
+        &self,
+        first_bin: &str,
+        last_bin: &str,
+    ) -> CustomResult<Vec<CardBinRange>, errors::StorageError> {
+        let mut ranges: Vec<_> = self
+            .card_bin_ranges
+            .lock()
+            .await
+            .iter()
+            .filter(|range| {
+                range.bin_start.as_str() <= last_bin && range.bin_end.as_str() >= first_bin
+            })
+            .cloned()
+            .collect();
+        ranges.sort_by(|a, b| a.bin_start.cmp(&b.bin_start));
+        Ok(ranges)
+    }
+
+    async fn import_card_bin_ranges(
+        &self,
+        new_ranges: Vec<CardBinRangeNew>,
+        updated_ranges: Vec<(String, String, CardBinRangeUpdate)>,
+    ) -> CustomResult<(), errors::StorageError> {
+        // Check every range before writing anything, so that a failed upload leaves the ranges as
+        // they were
+        {
+            let card_bin_ranges = self.card_bin_ranges.lock().await;
+            let exists = |bin_start: &str, bin_end: &str| {
+                card_bin_ranges
+                    .iter()
+                    .any(|range| range.bin_start == bin_start && range.bin_end == bin_end)
+            };
+            if let Some(range) = new_ranges
+                .iter()
+                .find(|range| exists(&range.bin_start, &range.bin_end))
+            {
+                Err(errors::StorageError::DuplicateValue {
+                    entity: "card_bin_range",
+                    key: Some(format!("{}-{}", range.bin_start, range.bin_end)),
+                })?
+            }
+            if let Some((bin_start, bin_end, _)) = updated_ranges
+                .iter()
+                .find(|(bin_start, bin_end, _)| !exists(bin_start, bin_end))
+            {
+                Err(errors::StorageError::ValueNotFound(format!(
+                    "No card bin range available for {bin_start}-{bin_end}"
+                )))?
+            }
+        }
+
+        for range in new_ranges {
+            self.insert_card_bin_range(range).await?;
+        }
+        for (bin_start, bin_end, range_update) in updated_ranges {
+            self.update_card_bin_range(&bin_start, &bin_end, range_update)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn update_card_bin_range(
+        &self,
+        bin_start: &str,
+        bin_end: &str,
+        card_bin_range_update: CardBinRangeUpdate,
+    ) -> CustomResult<CardBinRange, errors::StorageError> {
+        let mut card_bin_ranges = self.card_bin_ranges.lock().await;
+        let range = card_bin_ranges
+            .iter_mut()
+            .find(|range| range.bin_start == bin_start && range.bin_end == bin_end)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No card bin range available for {bin_start}-{bin_end}"
+            )))?;
+
+        match card_bin_range_update {
+            CardBinRangeUpdate::DetailsUpdate {
+                card_issuer,
+                card_network,
+                card_type,
+                card_subtype,
+                card_issuing_country,
+                last_updated_provider,
+            } => {
+                range.card_issuer = card_issuer;
+                range.card_network = card_network;
+                range.card_type = card_type;
+                range.card_subtype = card_subtype;
+                range.card_issuing_country = card_issuing_country;
+                range.last_updated_provider = last_updated_provider;
+            }
+        }
+        range.modified_at = common_utils::date_time::now();
+
+        Ok(range.clone())
+    }
+
+    async fn delete_card_bin_range(
+        &self,
+        bin_start: &str,
+        bin_end: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut card_bin_ranges = self.card_bin_ranges.lock().await;
+        let position = card_bin_ranges
+            .iter()
+            .position(|range| range.bin_start == bin_start && range.bin_end == bin_end)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No card bin range available for {bin_start}-{bin_end}"
+            )))?;
+        card_bin_ranges.remove(position);
+
+        Ok(true)
+    }
+
+    async fn list_card_bin_ranges(
+        &self,
+        bin_length: Option<i32>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<CardBinRange>, errors::StorageError> {
+        let mut ranges: Vec<_> = self
+            .card_bin_ranges
             .lock()
             .await
             .iter()
-            .find(|ci| ci.card_iin == card_iin)
-            .cloned())
+            .filter(|range| bin_length.map_or(true, |length| range.bin_length == length))
+            .cloned()
+            .collect();
+        ranges.sort_by(|a, b| {
+            (a.bin_length, &a.bin_start, &a.bin_end).cmp(&(b.bin_length, &b.bin_start, &b.bin_end))
+        });
+
+        Ok(ranges
+            .into_iter()
+            .skip(
+                offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or_default(),
+            )
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(bin_start: &str, bin_end: &str) -> CardBinRange {
+        let now = common_utils::date_time::now();
+        CardBinRange {
+            bin_start: bin_start.to_string(),
+            bin_end: bin_end.to_string(),
+            bin_length: 6,
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            card_subtype: None,
+            card_issuing_country: None,
+            last_updated_provider: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_candidate_bins() {
+        assert_eq!(
+            candidate_bins("4242424242"),
+            vec!["4242424242", "42424242", "424242"]
+        );
+        assert_eq!(candidate_bins("42424242"), vec!["42424242", "424242"]);
+        assert_eq!(candidate_bins("424242"), vec!["424242"]);
+    }
+
+    #[test]
+    fn test_narrowest_range() {
+        let narrowest = narrowest_range(vec![
+            range("400000", "499999"),
+            range("424200", "424299"),
+            range("420000", "429999"),
+        ]);
+        assert_eq!(
+            narrowest.map(|range| range.bin_start),
+            Some("424200".to_string())
+        );
     }
 }
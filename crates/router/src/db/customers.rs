@@ -92,6 +92,16 @@ where
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<customer::Customer>, errors::StorageError>;
 
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    async fn list_customers_by_merchant_id_with_pagination(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<customer::Customer>, errors::StorageError>;
+
     async fn insert_customer(
         &self,
         customer_data: customer::Customer,
@@ -543,6 +553,44 @@ mod storage {
             Ok(customers)
         }
 
+        #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+        #[instrument(skip_all)]
+        async fn list_customers_by_merchant_id_with_pagination(
+            &self,
+            state: &KeyManagerState,
+            merchant_id: &id_type::MerchantId,
+            key_store: &domain::MerchantKeyStore,
+            limit: i64,
+            offset: i64,
+        ) -> CustomResult<Vec<customer::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+
+            let encrypted_customers = storage_types::Customer::list_by_merchant_id_with_pagination(
+                &conn,
+                merchant_id,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            let customers = try_join_all(encrypted_customers.into_iter().map(
+                |encrypted_customer| async {
+                    encrypted_customer
+                        .convert(
+                            state,
+                            key_store.key.get_inner(),
+                            key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                },
+            ))
+            .await?;
+
+            Ok(customers)
+        }
+
         #[cfg(all(feature = "v2", feature = "customer_v2"))]
         #[instrument(skip_all)]
         async fn insert_customer(
@@ -1080,6 +1128,40 @@ mod storage {
             Ok(customers)
         }
 
+        #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+        #[instrument(skip_all)]
+        async fn list_customers_by_merchant_id_with_pagination(
+            &self,
+            state: &KeyManagerState,
+            merchant_id: &id_type::MerchantId,
+            key_store: &domain::MerchantKeyStore,
+            limit: i64,
+            offset: i64,
+        ) -> CustomResult<Vec<customer::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+
+            let encrypted_customers = storage_types::Customer::list_by_merchant_id_with_pagination(
+                &conn,
+                merchant_id,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            let customers = try_join_all(encrypted_customers.into_iter().map(
+                |encrypted_customer| async {
+                    encrypted_customer
+                        .convert(state, key_store.key.get_inner(), merchant_id.clone().into())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                },
+            ))
+            .await?;
+
+            Ok(customers)
+        }
+
         #[instrument(skip_all)]
         async fn insert_customer(
             &self,
@@ -1274,46 +1356,139 @@ impl CustomerInterface for MockDb {
         Ok(customers)
     }
 
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    async fn list_customers_by_merchant_id_with_pagination(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<customer::Customer>, errors::StorageError> {
+        let customers = self.customers.lock().await;
+
+        let customers = try_join_all(
+            customers
+                .iter()
+                .filter(|customer| customer.merchant_id == *merchant_id)
+                .skip(usize::try_from(offset).unwrap_or(usize::MAX))
+                .take(usize::try_from(limit).unwrap_or_default())
+                .map(|customer| async {
+                    customer
+                        .to_owned()
+                        .convert(
+                            state,
+                            key_store.key.get_inner(),
+                            key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                }),
+        )
+        .await?;
+
+        Ok(customers)
+    }
+
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
     #[instrument(skip_all)]
     async fn update_customer_by_customer_id_merchant_id(
         &self,
-        _state: &KeyManagerState,
-        _customer_id: id_type::CustomerId,
-        _merchant_id: id_type::MerchantId,
+        state: &KeyManagerState,
+        customer_id: id_type::CustomerId,
+        merchant_id: id_type::MerchantId,
         _customer: customer::Customer,
-        _customer_update: storage_types::CustomerUpdate,
-        _key_store: &domain::MerchantKeyStore,
+        customer_update: storage_types::CustomerUpdate,
+        key_store: &domain::MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<customer::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let customer_to_update = customers
+            .iter_mut()
+            .find(|customer| {
+                customer.customer_id == customer_id && customer.merchant_id == merchant_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer available with customer_id = {customer_id:?} and merchant_id = {merchant_id:?}"
+            )))?;
+
+        let updated_customer = diesel_models::CustomerUpdateInternal::from(customer_update)
+            .apply_changeset(customer_to_update.clone());
+        *customer_to_update = updated_customer.clone();
+
+        updated_customer
+            .convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
     async fn find_customer_by_customer_id_merchant_id(
         &self,
-        _state: &KeyManagerState,
-        _customer_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &domain::MerchantKeyStore,
+        state: &KeyManagerState,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<customer::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let customer = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .find(|customer| {
+                customer.customer_id == *customer_id && customer.merchant_id == *merchant_id
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer available with customer_id = {customer_id:?} and merchant_id = {merchant_id:?}"
+            )))?;
+
+        customer
+            .convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     async fn find_customer_by_merchant_reference_id_merchant_id(
         &self,
-        _state: &KeyManagerState,
-        _merchant_reference_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &domain::MerchantKeyStore,
+        state: &KeyManagerState,
+        merchant_reference_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<customer::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let customer = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .find(|customer| {
+                customer.merchant_reference_id.as_ref() == Some(merchant_reference_id)
+                    && customer.merchant_id == *merchant_id
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer available with merchant_reference_id = {merchant_reference_id:?} and merchant_id = {merchant_id:?}"
+            )))?;
+
+        customer
+            .convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     #[allow(clippy::panic)]
@@ -1345,39 +1520,84 @@ impl CustomerInterface for MockDb {
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &id_type::CustomerId,
-        _merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
     ) -> CustomResult<bool, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let position = customers
+            .iter()
+            .position(|customer| {
+                customer.customer_id == *customer_id && customer.merchant_id == *merchant_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer available with customer_id = {customer_id:?} and merchant_id = {merchant_id:?}"
+            )))?;
+        customers.remove(position);
+
+        Ok(true)
     }
 
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     #[allow(clippy::too_many_arguments)]
     async fn update_customer_by_global_id(
         &self,
-        _state: &KeyManagerState,
-        _id: String,
+        state: &KeyManagerState,
+        id: String,
         _customer: customer::Customer,
-        _merchant_id: &id_type::MerchantId,
-        _customer_update: storage_types::CustomerUpdate,
-        _key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        customer_update: storage_types::CustomerUpdate,
+        key_store: &domain::MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<customer::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let customer_to_update = customers
+            .iter_mut()
+            .find(|customer| customer.id == id && customer.merchant_id == *merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer available with id = {id} and merchant_id = {merchant_id:?}"
+            )))?;
+
+        let updated_customer = diesel_models::CustomerUpdateInternal::from(customer_update)
+            .apply_changeset(customer_to_update.clone());
+        *customer_to_update = updated_customer.clone();
+
+        updated_customer
+            .convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     async fn find_customer_by_global_id(
         &self,
-        _state: &KeyManagerState,
-        _id: &String,
-        _merchant_id: &id_type::MerchantId,
-        _key_store: &domain::MerchantKeyStore,
+        state: &KeyManagerState,
+        id: &String,
+        merchant_id: &id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<customer::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let customer = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .find(|customer| customer.id == *id && customer.merchant_id == *merchant_id)
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer available with id = {id} and merchant_id = {merchant_id:?}"
+            )))?;
+
+        customer
+            .convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 }
@@ -0,0 +1,838 @@
+use async_bb8_diesel::AsyncConnection;
+use common_utils::{id_type, types::keymanager::KeyManagerState};
+use diesel_models::{
+    data_retention as storage, enums, events::Event, payment_attempt::PaymentAttempt,
+    payment_intent::PaymentIntent, process_tracker::ProcessTracker,
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::behaviour::Conversion;
+use router_env::{instrument, tracing};
+use storage_impl::DataModelExt;
+use time::PrimitiveDateTime;
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+    types::domain,
+};
+
+#[async_trait::async_trait]
+pub trait DataRetentionInterface {
+    async fn insert_data_retention_policy(
+        &self,
+        policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError>;
+
+    async fn find_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError>;
+
+    async fn list_data_retention_policies(
+        &self,
+    ) -> CustomResult<Vec<storage::DataRetentionPolicy>, errors::StorageError>;
+
+    async fn update_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+        policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError>;
+
+    async fn delete_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn insert_data_retention_archive(
+        &self,
+        archive: storage::DataRetentionArchiveNew,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError>;
+
+    async fn find_data_retention_archive_by_archive_id(
+        &self,
+        archive_id: &str,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError>;
+
+    async fn list_data_retention_archives(
+        &self,
+        table_name: Option<enums::DataRetentionTable>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::DataRetentionArchive>, errors::StorageError>;
+
+    /// Lists the archives of the given tables which hold rows of the merchant, in the order of
+    /// their identifier
+    async fn list_data_retention_archives_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        table_names: Vec<enums::DataRetentionTable>,
+        after_archive_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::DataRetentionArchive>, errors::StorageError>;
+
+    async fn update_data_retention_archive_by_archive_id(
+        &self,
+        archive_id: &str,
+        archive_update: storage::DataRetentionArchiveUpdate,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError>;
+
+    async fn find_events_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<Event>, errors::StorageError>;
+
+    async fn delete_events_by_event_ids(
+        &self,
+        event_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn insert_archived_events(
+        &self,
+        events: Vec<Event>,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    /// Lists the oldest payment intents in one of the given statuses which were neither created
+    /// nor modified after the given time
+    async fn find_settled_payment_intents(
+        &self,
+        statuses: Vec<enums::IntentStatus>,
+        settled_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError>;
+
+    async fn find_payment_attempts_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError>;
+
+    /// Deletes the payment intents of the merchant along with all their attempts, atomically.
+    /// Returns the number of payment intents deleted.
+    async fn delete_archived_payments(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    /// Inserts payment intents restored from an archive along with their attempts, atomically.
+    /// Rows which already exist are skipped. Returns the number of payment intents inserted.
+    async fn insert_archived_payments(
+        &self,
+        state: &KeyManagerState,
+        payment_intents: Vec<PaymentIntent>,
+        payment_attempts: Vec<PaymentAttempt>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn find_finished_processes_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<ProcessTracker>, errors::StorageError>;
+
+    async fn delete_processes_by_ids(
+        &self,
+        ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn insert_archived_processes(
+        &self,
+        processes: Vec<ProcessTracker>,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_data_retention_policy(
+        &self,
+        policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        policy
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataRetentionPolicy::find_by_table_name(&conn, table_name)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_data_retention_policies(
+        &self,
+    ) -> CustomResult<Vec<storage::DataRetentionPolicy>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataRetentionPolicy::list_all(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+        policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DataRetentionPolicy::update_by_table_name(&conn, table_name, policy_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DataRetentionPolicy::delete_by_table_name(&conn, table_name)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_data_retention_archive(
+        &self,
+        archive: storage::DataRetentionArchiveNew,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        archive
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_data_retention_archive_by_archive_id(
+        &self,
+        archive_id: &str,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataRetentionArchive::find_by_archive_id(&conn, archive_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_data_retention_archives(
+        &self,
+        table_name: Option<enums::DataRetentionTable>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::DataRetentionArchive>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataRetentionArchive::list(&conn, table_name, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_data_retention_archives_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        table_names: Vec<enums::DataRetentionTable>,
+        after_archive_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::DataRetentionArchive>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataRetentionArchive::list_by_merchant_id(
+            &conn,
+            merchant_id,
+            table_names,
+            after_archive_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_data_retention_archive_by_archive_id(
+        &self,
+        archive_id: &str,
+        archive_update: storage::DataRetentionArchiveUpdate,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DataRetentionArchive::update_by_archive_id(&conn, archive_id, archive_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_events_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        Event::find_created_before(&conn, created_before, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_events_by_event_ids(
+        &self,
+        event_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        Event::delete_by_event_ids(&conn, event_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_archived_events(
+        &self,
+        events: Vec<Event>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        Event::insert_archived(&conn, events)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settled_payment_intents(
+        &self,
+        statuses: Vec<enums::IntentStatus>,
+        settled_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        PaymentIntent::find_settled_before(&conn, statuses, settled_before, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_attempts_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        PaymentAttempt::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_archived_payments(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            PaymentAttempt::delete_by_merchant_id_payment_ids(
+                &conn,
+                merchant_id,
+                payment_ids.clone(),
+            )
+            .await
+            .map_err(|error| *error.current_context())?;
+            PaymentIntent::delete_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+                .await
+                .map_err(|error| *error.current_context())
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_archived_payments(
+        &self,
+        _state: &KeyManagerState,
+        payment_intents: Vec<PaymentIntent>,
+        payment_attempts: Vec<PaymentAttempt>,
+        _key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            let inserted = PaymentIntent::insert_archived(&conn, payment_intents)
+                .await
+                .map_err(|error| *error.current_context())?;
+            PaymentAttempt::insert_archived(&conn, payment_attempts)
+                .await
+                .map_err(|error| *error.current_context())?;
+            Ok::<_, diesel_models::errors::DatabaseError>(inserted)
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_finished_processes_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        ProcessTracker::find_created_before(&conn, created_before, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_processes_by_ids(
+        &self,
+        ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        ProcessTracker::delete_by_ids(&conn, ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_archived_processes(
+        &self,
+        processes: Vec<ProcessTracker>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        ProcessTracker::insert_archived(&conn, processes)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+/// Keeps the oldest rows created before the given time, in the order they were created
+fn take_oldest<T>(
+    mut rows: Vec<T>,
+    created_at: impl Fn(&T) -> PrimitiveDateTime,
+    created_before: PrimitiveDateTime,
+    limit: i64,
+) -> Vec<T> {
+    rows.retain(|row| created_at(row) < created_before);
+    rows.sort_by_key(&created_at);
+    rows.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+    rows
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for MockDb {
+    async fn insert_data_retention_policy(
+        &self,
+        policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let mut policies = self.data_retention_policies.lock().await;
+        if policies
+            .iter()
+            .any(|existing| existing.table_name == policy.table_name)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "table_name",
+                key: Some(policy.table_name.to_string()),
+            })?
+        }
+        let policy = storage::DataRetentionPolicy {
+            table_name: policy.table_name,
+            retention_days: policy.retention_days,
+            batch_size: policy.batch_size,
+            is_enabled: policy.is_enabled,
+            last_run_at: policy.last_run_at,
+            created_at: policy.created_at,
+            modified_at: policy.modified_at,
+        };
+        policies.push(policy.clone());
+        Ok(policy)
+    }
+
+    async fn find_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.data_retention_policies
+            .lock()
+            .await
+            .iter()
+            .find(|policy| policy.table_name == table_name)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No data retention policy available for table_name = {table_name}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_data_retention_policies(
+        &self,
+    ) -> CustomResult<Vec<storage::DataRetentionPolicy>, errors::StorageError> {
+        Ok(self.data_retention_policies.lock().await.clone())
+    }
+
+    async fn update_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+        policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        let mut policies = self.data_retention_policies.lock().await;
+        let policy = policies
+            .iter_mut()
+            .find(|policy| policy.table_name == table_name)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No data retention policy available for table_name = {table_name}"
+            )))?;
+
+        *policy = storage::DataRetentionPolicyUpdateInternal::from(policy_update)
+            .apply_changeset(policy.clone());
+        Ok(policy.clone())
+    }
+
+    async fn delete_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut policies = self.data_retention_policies.lock().await;
+        let position = policies
+            .iter()
+            .position(|policy| policy.table_name == table_name)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No data retention policy available for table_name = {table_name}"
+            )))?;
+        policies.remove(position);
+        Ok(true)
+    }
+
+    async fn insert_data_retention_archive(
+        &self,
+        archive: storage::DataRetentionArchiveNew,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError> {
+        let mut archives = self.data_retention_archives.lock().await;
+        let archive = storage::DataRetentionArchive {
+            archive_id: archive.archive_id,
+            table_name: archive.table_name,
+            file_key: archive.file_key,
+            row_count: archive.row_count,
+            oldest_record_created_at: archive.oldest_record_created_at,
+            newest_record_created_at: archive.newest_record_created_at,
+            created_at: archive.created_at,
+            restored_at: archive.restored_at,
+            merchant_id: archive.merchant_id,
+        };
+        archives.push(archive.clone());
+        Ok(archive)
+    }
+
+    async fn find_data_retention_archive_by_archive_id(
+        &self,
+        archive_id: &str,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError> {
+        self.data_retention_archives
+            .lock()
+            .await
+            .iter()
+            .find(|archive| archive.archive_id == archive_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No data retention archive available for archive_id = {archive_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_data_retention_archives(
+        &self,
+        table_name: Option<enums::DataRetentionTable>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::DataRetentionArchive>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut archives: Vec<_> = self
+            .data_retention_archives
+            .lock()
+            .await
+            .iter()
+            .filter(|archive| {
+                table_name.map_or(true, |table_name| archive.table_name == table_name)
+            })
+            .cloned()
+            .collect();
+        archives.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(archives.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn list_data_retention_archives_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        table_names: Vec<enums::DataRetentionTable>,
+        after_archive_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::DataRetentionArchive>, errors::StorageError> {
+        let mut archives: Vec<_> = self
+            .data_retention_archives
+            .lock()
+            .await
+            .iter()
+            .filter(|archive| {
+                archive
+                    .merchant_id
+                    .as_ref()
+                    .map_or(true, |archive_merchant_id| {
+                        archive_merchant_id == merchant_id
+                    })
+                    && table_names.contains(&archive.table_name)
+                    && after_archive_id.as_ref().map_or(true, |after_archive_id| {
+                        archive.archive_id.as_str() > after_archive_id.as_str()
+                    })
+            })
+            .cloned()
+            .collect();
+        archives.sort_by(|a, b| a.archive_id.cmp(&b.archive_id));
+        archives.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(archives)
+    }
+
+    async fn update_data_retention_archive_by_archive_id(
+        &self,
+        archive_id: &str,
+        archive_update: storage::DataRetentionArchiveUpdate,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError> {
+        let mut archives = self.data_retention_archives.lock().await;
+        let archive = archives
+            .iter_mut()
+            .find(|archive| archive.archive_id == archive_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No data retention archive available for archive_id = {archive_id}"
+            )))?;
+
+        *archive = storage::DataRetentionArchiveUpdateInternal::from(archive_update)
+            .apply_changeset(archive.clone());
+        Ok(archive.clone())
+    }
+
+    async fn find_events_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<Event>, errors::StorageError> {
+        let events = self.events.lock().await.clone();
+        Ok(take_oldest(
+            events,
+            |event| event.created_at,
+            created_before,
+            limit,
+        ))
+    }
+
+    async fn delete_events_by_event_ids(
+        &self,
+        event_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut events = self.events.lock().await;
+        let initial_count = events.len();
+        events.retain(|event| !event_ids.contains(&event.event_id));
+        Ok(initial_count - events.len())
+    }
+
+    async fn insert_archived_events(
+        &self,
+        archived_events: Vec<Event>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut events = self.events.lock().await;
+        let initial_count = events.len();
+        for event in archived_events {
+            if !events
+                .iter()
+                .any(|existing| existing.event_id == event.event_id)
+            {
+                events.push(event);
+            }
+        }
+        Ok(events.len() - initial_count)
+    }
+
+    async fn find_settled_payment_intents(
+        &self,
+        statuses: Vec<enums::IntentStatus>,
+        settled_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        let payment_intents = self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_intent| {
+                statuses.contains(&payment_intent.status)
+                    && payment_intent.modified_at < settled_before
+            })
+            .cloned()
+            .collect();
+
+        let mut settled_payment_intents = Vec::new();
+        for payment_intent in take_oldest(
+            payment_intents,
+            |payment_intent| payment_intent.created_at,
+            settled_before,
+            limit,
+        ) {
+            settled_payment_intents.push(
+                payment_intent
+                    .convert()
+                    .await
+                    .change_context(errors::StorageError::EncryptionError)?,
+            );
+        }
+        Ok(settled_payment_intents)
+    }
+
+    async fn find_payment_attempts_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        Ok(self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && payment_ids.contains(&payment_attempt.payment_id)
+            })
+            .cloned()
+            .map(DataModelExt::to_storage_model)
+            .collect())
+    }
+
+    async fn delete_archived_payments(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let is_archived = |row_merchant_id: &id_type::MerchantId, payment_id: &String| {
+            row_merchant_id == merchant_id && payment_ids.contains(payment_id)
+        };
+        self.payment_attempts
+            .lock()
+            .await
+            .retain(|payment_attempt| {
+                !is_archived(&payment_attempt.merchant_id, &payment_attempt.payment_id)
+            });
+        let mut payment_intents = self.payment_intents.lock().await;
+        let initial_count = payment_intents.len();
+        payment_intents.retain(|payment_intent| {
+            !is_archived(&payment_intent.merchant_id, &payment_intent.payment_id)
+        });
+        Ok(initial_count - payment_intents.len())
+    }
+
+    async fn insert_archived_payments(
+        &self,
+        state: &KeyManagerState,
+        archived_payment_intents: Vec<PaymentIntent>,
+        archived_payment_attempts: Vec<PaymentAttempt>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut restored_payment_intents = Vec::new();
+        for payment_intent in archived_payment_intents {
+            restored_payment_intents.push(
+                hyperswitch_domain_models::payments::PaymentIntent::convert_back(
+                    state,
+                    payment_intent,
+                    key_store.key.get_inner(),
+                    key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+
+        let mut payment_intents = self.payment_intents.lock().await;
+        let initial_count = payment_intents.len();
+        for payment_intent in restored_payment_intents {
+            if !payment_intents.iter().any(|existing| {
+                existing.merchant_id == payment_intent.merchant_id
+                    && existing.payment_id == payment_intent.payment_id
+            }) {
+                payment_intents.push(payment_intent);
+            }
+        }
+
+        let mut payment_attempts = self.payment_attempts.lock().await;
+        for payment_attempt in archived_payment_attempts {
+            if !payment_attempts.iter().any(|existing| {
+                existing.merchant_id == payment_attempt.merchant_id
+                    && existing.attempt_id == payment_attempt.attempt_id
+            }) {
+                payment_attempts.push(DataModelExt::from_storage_model(payment_attempt));
+            }
+        }
+        Ok(payment_intents.len() - initial_count)
+    }
+
+    async fn find_finished_processes_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<ProcessTracker>, errors::StorageError> {
+        let processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| process.status == enums::ProcessTrackerStatus::Finish)
+            .cloned()
+            .collect();
+        Ok(take_oldest(
+            processes,
+            |process| process.created_at,
+            created_before,
+            limit,
+        ))
+    }
+
+    async fn delete_processes_by_ids(
+        &self,
+        ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut processes = self.processes.lock().await;
+        let initial_count = processes.len();
+        processes.retain(|process| !ids.contains(&process.id));
+        Ok(initial_count - processes.len())
+    }
+
+    async fn insert_archived_processes(
+        &self,
+        archived_processes: Vec<ProcessTracker>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut processes = self.processes.lock().await;
+        let initial_count = processes.len();
+        for process in archived_processes {
+            if !processes.iter().any(|existing| existing.id == process.id) {
+                processes.push(process);
+            }
+        }
+        Ok(processes.len() - initial_count)
+    }
+}
@@ -0,0 +1,217 @@
+use common_utils::id_type;
+use diesel_models::data_subject_request as storage;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait DataSubjectRequestInterface {
+    async fn insert_data_subject_request(
+        &self,
+        data_subject_request: storage::DataSubjectRequestNew,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError>;
+
+    async fn find_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError>;
+
+    async fn list_data_subject_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::DataSubjectRequest>, errors::StorageError>;
+
+    async fn update_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+        data_subject_request_update: storage::DataSubjectRequestUpdate,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataSubjectRequestInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_data_subject_request(
+        &self,
+        data_subject_request: storage::DataSubjectRequestNew,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        data_subject_request
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataSubjectRequest::find_by_merchant_id_request_id(&conn, merchant_id, request_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_data_subject_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::DataSubjectRequest>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataSubjectRequest::list_by_merchant_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+        data_subject_request_update: storage::DataSubjectRequestUpdate,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::DataSubjectRequest::update_by_merchant_id_request_id(
+            &conn,
+            merchant_id,
+            request_id,
+            data_subject_request_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataSubjectRequestInterface for MockDb {
+    async fn insert_data_subject_request(
+        &self,
+        data_subject_request: storage::DataSubjectRequestNew,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        let mut data_subject_requests = self.data_subject_requests.lock().await;
+        if data_subject_requests
+            .iter()
+            .any(|request| request.request_id == data_subject_request.request_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "request_id",
+                key: Some(data_subject_request.request_id.clone()),
+            })?
+        }
+        let data_subject_request = storage::DataSubjectRequest {
+            request_id: data_subject_request.request_id,
+            merchant_id: data_subject_request.merchant_id,
+            customer_id: data_subject_request.customer_id,
+            request_type: data_subject_request.request_type,
+            status: data_subject_request.status,
+            reason: data_subject_request.reason,
+            file_key: data_subject_request.file_key,
+            summary: data_subject_request.summary,
+            error_message: data_subject_request.error_message,
+            created_at: data_subject_request.created_at,
+            modified_at: data_subject_request.modified_at,
+            completed_at: data_subject_request.completed_at,
+            email_hash: data_subject_request.email_hash,
+            erased_object_ids: None,
+        };
+        data_subject_requests.push(data_subject_request.clone());
+        Ok(data_subject_request)
+    }
+
+    async fn find_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        self.data_subject_requests
+            .lock()
+            .await
+            .iter()
+            .find(|request| request.merchant_id == *merchant_id && request.request_id == request_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No data subject request available for request_id = {request_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_data_subject_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::DataSubjectRequest>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut data_subject_requests: Vec<_> = self
+            .data_subject_requests
+            .lock()
+            .await
+            .iter()
+            .filter(|request| {
+                request.merchant_id == *merchant_id
+                    && customer_id.map_or(true, |customer_id| {
+                        request.customer_id.as_ref() == Some(customer_id)
+                    })
+            })
+            .cloned()
+            .collect();
+        data_subject_requests.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(data_subject_requests
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
+    async fn update_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+        data_subject_request_update: storage::DataSubjectRequestUpdate,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        let mut data_subject_requests = self.data_subject_requests.lock().await;
+        let data_subject_request = data_subject_requests
+            .iter_mut()
+            .find(|request| request.merchant_id == *merchant_id && request.request_id == request_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No data subject request available for request_id = {request_id}"
+            )))?;
+
+        *data_subject_request =
+            storage::DataSubjectRequestUpdateInternal::from(data_subject_request_update)
+                .apply_changeset(data_subject_request.clone());
+        Ok(data_subject_request.clone())
+    }
+}
@@ -45,6 +45,14 @@ pub trait DisputeInterface {
         this: storage::Dispute,
         dispute: storage::DisputeUpdate,
     ) -> CustomResult<storage::Dispute, errors::StorageError>;
+
+    async fn find_open_disputes_by_merchant_id_challenge_required_by(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<String>,
+        challenge_required_by_range: (time::PrimitiveDateTime, time::PrimitiveDateTime),
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -126,6 +134,25 @@ impl DisputeInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+    #[instrument(skip_all)]
+    async fn find_open_disputes_by_merchant_id_challenge_required_by(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<String>,
+        challenge_required_by_range: (time::PrimitiveDateTime, time::PrimitiveDateTime),
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::filter_open_by_challenge_required_by(
+            &conn,
+            merchant_id,
+            profile_id,
+            challenge_required_by_range,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -134,7 +161,9 @@ impl DisputeInterface for MockDb {
         &self,
         dispute: storage::DisputeNew,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let evidence = dispute.evidence.ok_or(errors::StorageError::MockDbError)?;
+        let evidence = dispute
+            .evidence
+            .unwrap_or_else(|| masking::Secret::new(serde_json::json!({})));
 
         let mut locked_disputes = self.disputes.lock().await;
 
@@ -357,6 +386,41 @@ impl DisputeInterface for MockDb {
 
         Ok(dispute_to_update.clone())
     }
+    async fn find_open_disputes_by_merchant_id_challenge_required_by(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<String>,
+        challenge_required_by_range: (time::PrimitiveDateTime, time::PrimitiveDateTime),
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let (start, end) = challenge_required_by_range;
+        let locked_disputes = self.disputes.lock().await;
+
+        let mut disputes: Vec<storage::Dispute> = locked_disputes
+            .iter()
+            .filter(|d| {
+                d.merchant_id == *merchant_id
+                    && d.dispute_status == storage::enums::DisputeStatus::DisputeOpened
+                    && d.challenge_required_by
+                        .is_some_and(|deadline| start <= deadline && deadline <= end)
+                    && profile_id
+                        .as_ref()
+                        .map(|profile_id| d.profile_id.as_ref() == Some(profile_id))
+                        .unwrap_or(true)
+            })
+            .cloned()
+            .collect();
+        disputes.sort_by_key(|d| d.challenge_required_by);
+
+        Ok(disputes
+            .into_iter()
+            .take(
+                limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .collect())
+    }
 }
 
 #[cfg(test)]
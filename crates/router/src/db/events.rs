@@ -680,6 +680,10 @@ impl EventInterface for MockDb {
                 event_to_update.is_webhook_notified = is_webhook_notified;
                 event_to_update.response = response.map(Into::into);
             }
+            domain::EventUpdate::ContentRedaction { request, response } => {
+                event_to_update.request = request.map(Into::into);
+                event_to_update.response = response.map(Into::into);
+            }
         }
 
         event_to_update
@@ -759,6 +763,8 @@ mod tests {
                     .and_then(|val| val.try_into_operation())
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_key: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -88,36 +88,92 @@ impl FileMetadataInterface for Store {
 impl FileMetadataInterface for MockDb {
     async fn insert_file_metadata(
         &self,
-        _file: storage::FileMetadataNew,
+        file: storage::FileMetadataNew,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut locked_files = self.file_metadata.lock().await;
+        if locked_files
+            .iter()
+            .any(|f| f.file_id == file.file_id && f.merchant_id == file.merchant_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "file_id",
+                key: Some(file.file_id.clone()),
+            })?
+        }
+
+        let file_metadata = storage::FileMetadata {
+            file_id: file.file_id,
+            merchant_id: file.merchant_id,
+            file_name: file.file_name,
+            file_size: file.file_size,
+            file_type: file.file_type,
+            provider_file_id: file.provider_file_id,
+            file_upload_provider: file.file_upload_provider,
+            available: file.available,
+            created_at: common_utils::date_time::now(),
+            connector_label: file.connector_label,
+            profile_id: file.profile_id,
+            merchant_connector_id: file.merchant_connector_id,
+        };
+        locked_files.push(file_metadata.clone());
+
+        Ok(file_metadata)
     }
 
     async fn find_file_metadata_by_merchant_id_file_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _file_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        file_id: &str,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.file_metadata
+            .lock()
+            .await
+            .iter()
+            .find(|f| f.merchant_id == *merchant_id && f.file_id == file_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No file metadata available with merchant_id = {merchant_id:?} and file_id = {file_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn delete_file_metadata_by_merchant_id_file_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _file_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        file_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut locked_files = self.file_metadata.lock().await;
+        let position = locked_files
+            .iter()
+            .position(|f| f.merchant_id == *merchant_id && f.file_id == file_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No file metadata available with merchant_id = {merchant_id:?} and file_id = {file_id}"
+            )))?;
+        locked_files.remove(position);
+
+        Ok(true)
     }
 
     async fn update_file_metadata(
         &self,
-        _this: storage::FileMetadata,
-        _file_metadata: storage::FileMetadataUpdate,
+        this: storage::FileMetadata,
+        file_metadata: storage::FileMetadataUpdate,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut locked_files = self.file_metadata.lock().await;
+        let file_to_update = locked_files
+            .iter_mut()
+            .find(|f| f.file_id == this.file_id && f.merchant_id == this.merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No file metadata available with file_id = {}",
+                this.file_id
+            )))?;
+
+        let updated_file = diesel_models::file::FileMetadataUpdateInternal::from(file_metadata)
+            .apply_changeset(this);
+        *file_to_update = updated_file.clone();
+
+        Ok(updated_file)
     }
 }
@@ -33,6 +33,14 @@ pub trait FraudCheckInterface {
         payment_id: String,
         merchant_id: common_utils::id_type::MerchantId,
     ) -> CustomResult<Option<FraudCheck>, errors::StorageError>;
+
+    async fn list_fraud_checks_by_merchant_id_frm_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        frm_status: diesel_models::enums::FraudCheckStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -83,36 +91,136 @@ impl FraudCheckInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn list_fraud_checks_by_merchant_id_frm_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        frm_status: diesel_models::enums::FraudCheckStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        FraudCheck::list_by_merchant_id_frm_status(&conn, merchant_id, frm_status, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
 impl FraudCheckInterface for MockDb {
     async fn insert_fraud_check_response(
         &self,
-        _new: storage::FraudCheckNew,
+        new: storage::FraudCheckNew,
     ) -> CustomResult<FraudCheck, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut fraud_checks = self.fraud_checks.lock().await;
+        if fraud_checks
+            .iter()
+            .any(|fraud_check| fraud_check.frm_id == new.frm_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "frm_id",
+                key: Some(new.frm_id.clone()),
+            })?
+        }
+
+        let fraud_check = FraudCheck {
+            frm_id: new.frm_id,
+            payment_id: new.payment_id,
+            merchant_id: new.merchant_id,
+            attempt_id: new.attempt_id,
+            created_at: new.created_at,
+            frm_name: new.frm_name,
+            frm_transaction_id: new.frm_transaction_id,
+            frm_transaction_type: new.frm_transaction_type,
+            frm_status: new.frm_status,
+            frm_score: new.frm_score,
+            frm_reason: new.frm_reason,
+            frm_error: new.frm_error,
+            payment_details: new.payment_details,
+            metadata: new.metadata,
+            modified_at: new.modified_at,
+            last_step: new.last_step,
+            payment_capture_method: new.payment_capture_method,
+        };
+        fraud_checks.push(fraud_check.clone());
+
+        Ok(fraud_check)
     }
     async fn update_fraud_check_response_with_attempt_id(
         &self,
-        _this: FraudCheck,
-        _fraud_check: FraudCheckUpdate,
+        this: FraudCheck,
+        fraud_check: FraudCheckUpdate,
     ) -> CustomResult<FraudCheck, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut fraud_checks = self.fraud_checks.lock().await;
+        let fraud_check_to_update = fraud_checks
+            .iter_mut()
+            .find(|item| item.attempt_id == this.attempt_id && item.merchant_id == this.merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No fraud check available with attempt_id = {}",
+                this.attempt_id
+            )))?;
+
+        let updated_fraud_check =
+            storage::FraudCheckUpdateInternal::from(fraud_check).apply_changeset(this);
+        *fraud_check_to_update = updated_fraud_check.clone();
+
+        Ok(updated_fraud_check)
     }
     async fn find_fraud_check_by_payment_id(
         &self,
-        _payment_id: String,
-        _merchant_id: common_utils::id_type::MerchantId,
+        payment_id: String,
+        merchant_id: common_utils::id_type::MerchantId,
     ) -> CustomResult<FraudCheck, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.find_fraud_check_by_payment_id_if_present(payment_id.clone(), merchant_id.clone())
+            .await?
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No fraud check available with payment_id = {payment_id} and merchant_id = {merchant_id:?}"
+                ))
+                .into(),
+            )
     }
 
     async fn find_fraud_check_by_payment_id_if_present(
         &self,
-        _payment_id: String,
-        _merchant_id: common_utils::id_type::MerchantId,
+        payment_id: String,
+        merchant_id: common_utils::id_type::MerchantId,
     ) -> CustomResult<Option<FraudCheck>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        Ok(self
+            .fraud_checks
+            .lock()
+            .await
+            .iter()
+            .find(|fraud_check| {
+                fraud_check.payment_id == payment_id && fraud_check.merchant_id == merchant_id
+            })
+            .cloned())
+    }
+
+    async fn list_fraud_checks_by_merchant_id_frm_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        frm_status: diesel_models::enums::FraudCheckStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, errors::StorageError> {
+        let mut fraud_checks: Vec<FraudCheck> = self
+            .fraud_checks
+            .lock()
+            .await
+            .iter()
+            .filter(|fraud_check| {
+                fraud_check.merchant_id == *merchant_id && fraud_check.frm_status == frm_status
+            })
+            .cloned()
+            .collect();
+        fraud_checks.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(fraud_checks
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(0))
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect())
     }
 }
@@ -1,5 +1,6 @@
+use async_bb8_diesel::AsyncConnection;
 use diesel_models::gsm as storage;
-use error_stack::report;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::MockDb;
@@ -49,6 +50,43 @@ pub trait GsmInterface {
         code: String,
         message: String,
     ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
+
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
+
+    /// Creates, updates and deletes rules along with their history entries, atomically
+    async fn apply_gsm_rule_changes(
+        &self,
+        new_rules: Vec<storage::GatewayStatusMappingNew>,
+        updated_rules: Vec<storage::GatewayStatusMappingNew>,
+        deleted_rules: Vec<storage::GatewayStatusMap>,
+        history: Vec<storage::GatewayStatusMapHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn insert_gsm_rule_history(
+        &self,
+        entries: Vec<storage::GatewayStatusMapHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn find_gsm_rule_history_by_change_id(
+        &self,
+        change_id: &str,
+    ) -> CustomResult<Vec<storage::GatewayStatusMapHistory>, errors::StorageError>;
+
+    async fn list_gsm_rule_history(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::GatewayStatusMapHistory>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -126,59 +164,505 @@ impl GsmInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::list_by_connector_flow(&conn, connector, flow)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::list_by_connectors(&conn, connectors)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn apply_gsm_rule_changes(
+        &self,
+        new_rules: Vec<storage::GatewayStatusMappingNew>,
+        updated_rules: Vec<storage::GatewayStatusMappingNew>,
+        deleted_rules: Vec<storage::GatewayStatusMap>,
+        history: Vec<storage::GatewayStatusMapHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            for rule in new_rules {
+                rule.insert(&conn).await.map_err(|e| *e.current_context())?;
+            }
+            for rule in updated_rules {
+                let data = storage::GatewayStatusMapAttributes::from(&rule).into();
+                storage::GatewayStatusMap::update(
+                    &conn,
+                    rule.connector,
+                    rule.flow,
+                    rule.sub_flow,
+                    rule.code,
+                    rule.message,
+                    data,
+                )
+                .await
+                .map_err(|e| *e.current_context())?;
+            }
+            for rule in deleted_rules {
+                storage::GatewayStatusMap::delete(
+                    &conn,
+                    rule.connector,
+                    rule.flow,
+                    rule.sub_flow,
+                    rule.code,
+                    rule.message,
+                )
+                .await
+                .map_err(|e| *e.current_context())?;
+            }
+            if !history.is_empty() {
+                storage::GatewayStatusMapHistoryNew::batch_insert(history, &conn)
+                    .await
+                    .map_err(|e| *e.current_context())?;
+            }
+            Ok::<_, diesel_models::errors::DatabaseError>(())
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_gsm_rule_history(
+        &self,
+        entries: Vec<storage::GatewayStatusMapHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::GatewayStatusMapHistoryNew::batch_insert(entries, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_gsm_rule_history_by_change_id(
+        &self,
+        change_id: &str,
+    ) -> CustomResult<Vec<storage::GatewayStatusMapHistory>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMapHistory::find_by_change_id(&conn, change_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_gsm_rule_history(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::GatewayStatusMapHistory>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMapHistory::list_by_connector_flow(&conn, connector, flow, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
 impl GsmInterface for MockDb {
     async fn add_gsm_rule(
         &self,
-        _rule: storage::GatewayStatusMappingNew,
+        rule: storage::GatewayStatusMappingNew,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut gsm_rules = self.gsm_rules.lock().await;
+        if gsm_rules.iter().any(|gsm| {
+            gsm.connector == rule.connector
+                && gsm.flow == rule.flow
+                && gsm.sub_flow == rule.sub_flow
+                && gsm.code == rule.code
+                && gsm.message == rule.message
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "gateway_status_map",
+                key: None,
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let gsm_rule = storage::GatewayStatusMap {
+            connector: rule.connector,
+            flow: rule.flow,
+            sub_flow: rule.sub_flow,
+            code: rule.code,
+            message: rule.message,
+            status: rule.status,
+            router_error: rule.router_error,
+            decision: rule.decision,
+            created_at: now,
+            last_modified: now,
+            step_up_possible: rule.step_up_possible,
+            unified_code: rule.unified_code,
+            unified_message: rule.unified_message,
+        };
+        gsm_rules.push(gsm_rule.clone());
+
+        Ok(gsm_rule)
     }
 
     async fn find_gsm_decision(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<String, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.find_gsm_rule(connector, flow, sub_flow, code, message)
+            .await
+            .map(|gsm| gsm.decision)
     }
 
     async fn find_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        self.gsm_rules
+            .lock()
+            .await
+            .iter()
+            .find(|gsm| {
+                gsm.connector == connector
+                    && gsm.flow == flow
+                    && gsm.sub_flow == sub_flow
+                    && gsm.code == code
+                    && gsm.message == message
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No gsm rule available for connector = {connector}, flow = {flow}, code = {code}"
+                ))
+                .into(),
+            )
     }
 
     async fn update_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
-        _data: storage::GatewayStatusMappingUpdate,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+        data: storage::GatewayStatusMappingUpdate,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut gsm_rules = self.gsm_rules.lock().await;
+        let gsm_rule = gsm_rules
+            .iter_mut()
+            .find(|gsm| {
+                gsm.connector == connector
+                    && gsm.flow == flow
+                    && gsm.sub_flow == sub_flow
+                    && gsm.code == code
+                    && gsm.message == message
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No gsm rule available for connector = {connector}, flow = {flow}, code = {code}"
+            )))?;
+
+        let storage::GatewayStatusMappingUpdate {
+            status,
+            router_error,
+            decision,
+            step_up_possible,
+            unified_code,
+            unified_message,
+        } = data;
+        if let Some(status) = status {
+            gsm_rule.status = status;
+        }
+        if let Some(router_error) = router_error {
+            gsm_rule.router_error = router_error;
+        }
+        if let Some(decision) = decision {
+            gsm_rule.decision = decision;
+        }
+        if let Some(step_up_possible) = step_up_possible {
+            gsm_rule.step_up_possible = step_up_possible;
+        }
+        if let Some(unified_code) = unified_code {
+            gsm_rule.unified_code = unified_code;
+        }
+        if let Some(unified_message) = unified_message {
+            gsm_rule.unified_message = unified_message;
+        }
+        gsm_rule.last_modified = common_utils::date_time::now();
+
+        Ok(gsm_rule.clone())
     }
 
     async fn delete_gsm_rule(
         &self,
-        _connector: String,
-        _flow: String,
-        _sub_flow: String,
-        _code: String,
-        _message: String,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
     ) -> CustomResult<bool, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let mut gsm_rules = self.gsm_rules.lock().await;
+        let position = gsm_rules
+            .iter()
+            .position(|gsm| {
+                gsm.connector == connector
+                    && gsm.flow == flow
+                    && gsm.sub_flow == sub_flow
+                    && gsm.code == code
+                    && gsm.message == message
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No gsm rule available for connector = {connector}, flow = {flow}, code = {code}"
+            )))?;
+        gsm_rules.remove(position);
+
+        Ok(true)
+    }
+
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let mut gsm_rules: Vec<_> = self
+            .gsm_rules
+            .lock()
+            .await
+            .iter()
+            .filter(|gsm| {
+                connector
+                    .as_ref()
+                    .map_or(true, |connector| &gsm.connector == connector)
+                    && flow.as_ref().map_or(true, |flow| &gsm.flow == flow)
+            })
+            .cloned()
+            .collect();
+        gsm_rules.sort_by(|a, b| {
+            (&a.connector, &a.flow, &a.sub_flow, &a.code, &a.message).cmp(&(
+                &b.connector,
+                &b.flow,
+                &b.sub_flow,
+                &b.code,
+                &b.message,
+            ))
+        });
+
+        Ok(gsm_rules)
+    }
+
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        Ok(self
+            .gsm_rules
+            .lock()
+            .await
+            .iter()
+            .filter(|gsm| connectors.contains(&gsm.connector))
+            .cloned()
+            .collect())
+    }
+
+    async fn apply_gsm_rule_changes(
+        &self,
+        new_rules: Vec<storage::GatewayStatusMappingNew>,
+        updated_rules: Vec<storage::GatewayStatusMappingNew>,
+        deleted_rules: Vec<storage::GatewayStatusMap>,
+        history: Vec<storage::GatewayStatusMapHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut gsm_rules = self.gsm_rules.lock().await;
+        let position = |gsm_rules: &[storage::GatewayStatusMap],
+                        connector: &str,
+                        flow: &str,
+                        sub_flow: &str,
+                        code: &str,
+                        message: &str| {
+            gsm_rules.iter().position(|gsm| {
+                gsm.connector == connector
+                    && gsm.flow == flow
+                    && gsm.sub_flow == sub_flow
+                    && gsm.code == code
+                    && gsm.message == message
+            })
+        };
+        let new_rule_position =
+            |gsm_rules: &[storage::GatewayStatusMap], rule: &storage::GatewayStatusMappingNew| {
+                position(
+                    gsm_rules,
+                    &rule.connector,
+                    &rule.flow,
+                    &rule.sub_flow,
+                    &rule.code,
+                    &rule.message,
+                )
+            };
+        let not_found = |connector: &str, flow: &str, code: &str| {
+            errors::StorageError::ValueNotFound(format!(
+                "No gsm rule available for connector = {connector}, flow = {flow}, code = {code}"
+            ))
+        };
+
+        // Check every rule before writing anything, so that failed changes leave the rules as
+        // they were
+        if new_rules
+            .iter()
+            .any(|rule| new_rule_position(&gsm_rules, rule).is_some())
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "gateway_status_map",
+                key: None,
+            })?
+        }
+        let updated_positions = updated_rules
+            .iter()
+            .map(|rule| {
+                new_rule_position(&gsm_rules, rule)
+                    .ok_or_else(|| not_found(&rule.connector, &rule.flow, &rule.code))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for rule in &deleted_rules {
+            position(
+                &gsm_rules,
+                &rule.connector,
+                &rule.flow,
+                &rule.sub_flow,
+                &rule.code,
+                &rule.message,
+            )
+            .ok_or_else(|| not_found(&rule.connector, &rule.flow, &rule.code))?;
+        }
+
+        let now = common_utils::date_time::now();
+        for (position, rule) in updated_positions.into_iter().zip(updated_rules) {
+            let gsm_rule = &mut gsm_rules[position];
+            gsm_rule.status = rule.status;
+            gsm_rule.router_error = rule.router_error;
+            gsm_rule.decision = rule.decision;
+            gsm_rule.step_up_possible = rule.step_up_possible;
+            gsm_rule.unified_code = rule.unified_code;
+            gsm_rule.unified_message = rule.unified_message;
+            gsm_rule.last_modified = now;
+        }
+        gsm_rules.extend(new_rules.into_iter().map(|rule| storage::GatewayStatusMap {
+            connector: rule.connector,
+            flow: rule.flow,
+            sub_flow: rule.sub_flow,
+            code: rule.code,
+            message: rule.message,
+            status: rule.status,
+            router_error: rule.router_error,
+            decision: rule.decision,
+            created_at: now,
+            last_modified: now,
+            step_up_possible: rule.step_up_possible,
+            unified_code: rule.unified_code,
+            unified_message: rule.unified_message,
+        }));
+        gsm_rules.retain(|gsm| {
+            !deleted_rules.iter().any(|rule| {
+                gsm.connector == rule.connector
+                    && gsm.flow == rule.flow
+                    && gsm.sub_flow == rule.sub_flow
+                    && gsm.code == rule.code
+                    && gsm.message == rule.message
+            })
+        });
+        drop(gsm_rules);
+
+        self.insert_gsm_rule_history(history).await
+    }
+
+    async fn insert_gsm_rule_history(
+        &self,
+        entries: Vec<storage::GatewayStatusMapHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut gsm_rule_history = self.gsm_rule_history.lock().await;
+        gsm_rule_history.extend(entries.into_iter().map(|entry| {
+            storage::GatewayStatusMapHistory {
+                change_id: entry.change_id,
+                sequence_number: entry.sequence_number,
+                source: entry.source,
+                action: entry.action,
+                connector: entry.connector,
+                flow: entry.flow,
+                sub_flow: entry.sub_flow,
+                code: entry.code,
+                message: entry.message,
+                previous_rule: entry.previous_rule,
+                current_rule: entry.current_rule,
+                description: entry.description,
+                created_at: entry.created_at,
+            }
+        }));
+
+        Ok(())
+    }
+
+    async fn find_gsm_rule_history_by_change_id(
+        &self,
+        change_id: &str,
+    ) -> CustomResult<Vec<storage::GatewayStatusMapHistory>, errors::StorageError> {
+        let mut entries: Vec<_> = self
+            .gsm_rule_history
+            .lock()
+            .await
+            .iter()
+            .filter(|entry| entry.change_id == change_id)
+            .cloned()
+            .collect();
+        entries.sort_by_key(|entry| entry.sequence_number);
+
+        Ok(entries)
+    }
+
+    async fn list_gsm_rule_history(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::GatewayStatusMapHistory>, errors::StorageError> {
+        let mut entries: Vec<_> = self
+            .gsm_rule_history
+            .lock()
+            .await
+            .iter()
+            .filter(|entry| {
+                connector
+                    .as_ref()
+                    .map_or(true, |connector| &entry.connector == connector)
+                    && flow.as_ref().map_or(true, |flow| &entry.flow == flow)
+            })
+            .cloned()
+            .collect();
+        entries.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| a.change_id.cmp(&b.change_id))
+                .then_with(|| a.sequence_number.cmp(&b.sequence_number))
+        });
+        entries.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(entries)
     }
 }
@@ -0,0 +1,770 @@
+use async_bb8_diesel::AsyncConnection;
+use common_utils::{id_type, types::MinorUnit};
+use diesel_models::{enums as storage_enums, invoice as storage};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait InvoiceInterface {
+    async fn insert_invoice(
+        &self,
+        invoice: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+
+    async fn list_invoices_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        status: Option<storage_enums::InvoiceStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError>;
+
+    async fn update_invoice_by_merchant_id_invoice_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        current_status: storage_enums::InvoiceStatus,
+        invoice_update: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+
+    async fn insert_invoice_line_items(
+        &self,
+        line_items: Vec<storage::InvoiceLineItemNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_invoice_line_items_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<Vec<storage::InvoiceLineItem>, errors::StorageError>;
+
+    async fn delete_invoice_line_items_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn insert_invoice_number_sequence(
+        &self,
+        invoice_number_sequence: storage::InvoiceNumberSequenceNew,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError>;
+
+    async fn find_invoice_number_sequence_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError>;
+
+    async fn update_invoice_number_sequence_by_profile_id(
+        &self,
+        profile_id: &str,
+        invoice_number_sequence_update: storage::InvoiceNumberSequenceUpdate,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError>;
+
+    /// Finalizes the draft invoice with the next number of the invoice number sequence of its
+    /// profile in one transaction, so that a number is only taken by an invoice that is finalized.
+    ///
+    /// Fails with a not found error if the invoice is no longer a draft or the profile has no
+    /// invoice number sequence.
+    async fn finalize_invoice_with_next_invoice_number(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        profile_id: &str,
+        finalized_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+
+    async fn insert_invoice_payment(
+        &self,
+        payment: storage::InvoicePaymentNew,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError>;
+
+    async fn find_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError>;
+
+    async fn list_invoice_payments_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<Vec<storage::InvoicePayment>, errors::StorageError>;
+
+    async fn update_uncredited_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        payment_update: storage::InvoicePaymentUpdate,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError>;
+
+    /// Marks the invoice payment credited and adds the amount received through it to the amount
+    /// paid of its invoice, failing with a not found error if the payment was already credited.
+    async fn credit_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        status: storage_enums::IntentStatus,
+        amount_received: MinorUnit,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl InvoiceInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_invoice(
+        &self,
+        invoice: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        invoice
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Invoice::find_by_merchant_id_invoice_id(&conn, merchant_id, invoice_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_invoices_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        status: Option<storage_enums::InvoiceStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Invoice::list_by_merchant_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_invoice_by_merchant_id_invoice_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        current_status: storage_enums::InvoiceStatus,
+        invoice_update: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Invoice::update_by_merchant_id_invoice_id_status(
+            &conn,
+            merchant_id,
+            invoice_id,
+            current_status,
+            invoice_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_invoice_line_items(
+        &self,
+        line_items: Vec<storage::InvoiceLineItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::InvoiceLineItemNew::batch_insert(line_items, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_invoice_line_items_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<Vec<storage::InvoiceLineItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::InvoiceLineItem::list_by_invoice_id(&conn, invoice_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_invoice_line_items_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::InvoiceLineItem::delete_by_invoice_id(&conn, invoice_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_invoice_number_sequence(
+        &self,
+        invoice_number_sequence: storage::InvoiceNumberSequenceNew,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        invoice_number_sequence
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_invoice_number_sequence_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::InvoiceNumberSequence::find_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_invoice_number_sequence_by_profile_id(
+        &self,
+        profile_id: &str,
+        invoice_number_sequence_update: storage::InvoiceNumberSequenceUpdate,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::InvoiceNumberSequence::update_by_profile_id(
+            &conn,
+            profile_id,
+            invoice_number_sequence_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn finalize_invoice_with_next_invoice_number(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        profile_id: &str,
+        finalized_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            let sequence = storage::InvoiceNumberSequence::increment_next_number(&conn, profile_id)
+                .await
+                .map_err(|e| *e.current_context())?
+                .ok_or(diesel_models::errors::DatabaseError::NotFound)?;
+            let invoice = storage::Invoice::update_by_merchant_id_invoice_id_status(
+                &conn,
+                merchant_id,
+                invoice_id,
+                storage_enums::InvoiceStatus::Draft,
+                storage::InvoiceUpdate::Finalize {
+                    invoice_number: sequence.get_next_invoice_number(),
+                    finalized_at,
+                },
+            )
+            .await
+            .map_err(|e| *e.current_context())?;
+            Ok::<_, diesel_models::errors::DatabaseError>(invoice)
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_invoice_payment(
+        &self,
+        payment: storage::InvoicePaymentNew,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::InvoicePayment::find_by_payment_id(&conn, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_invoice_payments_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<Vec<storage::InvoicePayment>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::InvoicePayment::list_by_invoice_id(&conn, invoice_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_uncredited_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        payment_update: storage::InvoicePaymentUpdate,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::InvoicePayment::update_uncredited_by_payment_id(&conn, payment_id, payment_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn credit_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        status: storage_enums::IntentStatus,
+        amount_received: MinorUnit,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            let payment = storage::InvoicePayment::update_uncredited_by_payment_id(
+                &conn,
+                payment_id,
+                storage::InvoicePaymentUpdate::Credited {
+                    status,
+                    amount_received,
+                },
+            )
+            .await
+            .map_err(|e| *e.current_context())?;
+            let invoice = storage::Invoice::increment_amount_paid(
+                &conn,
+                &payment.invoice_id,
+                amount_received,
+            )
+            .await
+            .map_err(|e| *e.current_context())?;
+            Ok::<_, diesel_models::errors::DatabaseError>(invoice)
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+}
+
+#[async_trait::async_trait]
+impl InvoiceInterface for MockDb {
+    async fn insert_invoice(
+        &self,
+        invoice: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let mut invoices = self.invoices.lock().await;
+        if invoices
+            .iter()
+            .any(|existing| existing.invoice_id == invoice.invoice_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "invoice_id",
+                key: Some(invoice.invoice_id.clone()),
+            })?
+        }
+        let invoice = storage::Invoice {
+            invoice_id: invoice.invoice_id,
+            merchant_id: invoice.merchant_id,
+            profile_id: invoice.profile_id,
+            customer_id: invoice.customer_id,
+            invoice_number: invoice.invoice_number,
+            status: invoice.status,
+            currency: invoice.currency,
+            description: invoice.description,
+            discount: invoice.discount,
+            tax_lines: invoice.tax_lines,
+            subtotal: invoice.subtotal,
+            discount_amount: invoice.discount_amount,
+            tax_amount: invoice.tax_amount,
+            total: invoice.total,
+            amount_paid: invoice.amount_paid,
+            due_date: invoice.due_date,
+            reminder_schedule: invoice.reminder_schedule,
+            payment_id: invoice.payment_id,
+            payment_link: invoice.payment_link,
+            metadata: invoice.metadata,
+            finalized_at: invoice.finalized_at,
+            paid_at: invoice.paid_at,
+            voided_at: invoice.voided_at,
+            marked_uncollectible_at: invoice.marked_uncollectible_at,
+            created_at: invoice.created_at,
+            modified_at: invoice.modified_at,
+        };
+        invoices.push(invoice.clone());
+        Ok(invoice)
+    }
+
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.invoices
+            .lock()
+            .await
+            .iter()
+            .find(|invoice| invoice.merchant_id == *merchant_id && invoice.invoice_id == invoice_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No invoice available for invoice_id = {invoice_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_invoices_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        status: Option<storage_enums::InvoiceStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        let mut invoices: Vec<_> = self
+            .invoices
+            .lock()
+            .await
+            .iter()
+            .filter(|invoice| {
+                invoice.merchant_id == *merchant_id
+                    && customer_id.map_or(true, |customer_id| invoice.customer_id == *customer_id)
+                    && status.map_or(true, |status| invoice.status == status)
+            })
+            .cloned()
+            .collect();
+        invoices.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(invoices.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn update_invoice_by_merchant_id_invoice_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        current_status: storage_enums::InvoiceStatus,
+        invoice_update: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let mut invoices = self.invoices.lock().await;
+        let invoice = invoices
+            .iter_mut()
+            .find(|invoice| {
+                invoice.merchant_id == *merchant_id
+                    && invoice.invoice_id == invoice_id
+                    && invoice.status == current_status
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No {current_status} invoice available for invoice_id = {invoice_id}"
+            )))?;
+        *invoice =
+            storage::InvoiceUpdateInternal::from(invoice_update).apply_changeset(invoice.clone());
+        Ok(invoice.clone())
+    }
+
+    async fn insert_invoice_line_items(
+        &self,
+        line_items: Vec<storage::InvoiceLineItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.invoice_line_items
+            .lock()
+            .await
+            .extend(
+                line_items
+                    .into_iter()
+                    .map(|line_item| storage::InvoiceLineItem {
+                        invoice_id: line_item.invoice_id,
+                        line_item_id: line_item.line_item_id,
+                        position: line_item.position,
+                        description: line_item.description,
+                        quantity: line_item.quantity,
+                        unit_amount: line_item.unit_amount,
+                        amount: line_item.amount,
+                        discount: line_item.discount,
+                        discount_amount: line_item.discount_amount,
+                        tax_rates: line_item.tax_rates,
+                        tax_amount: line_item.tax_amount,
+                        created_at: line_item.created_at,
+                    }),
+            );
+        Ok(())
+    }
+
+    async fn list_invoice_line_items_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<Vec<storage::InvoiceLineItem>, errors::StorageError> {
+        let mut line_items: Vec<_> = self
+            .invoice_line_items
+            .lock()
+            .await
+            .iter()
+            .filter(|line_item| line_item.invoice_id == invoice_id)
+            .cloned()
+            .collect();
+        line_items.sort_by_key(|line_item| line_item.position);
+        Ok(line_items)
+    }
+
+    async fn delete_invoice_line_items_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut line_items = self.invoice_line_items.lock().await;
+        let count_before = line_items.len();
+        line_items.retain(|line_item| line_item.invoice_id != invoice_id);
+        if line_items.len() == count_before {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No invoice line items available for invoice_id = {invoice_id}"
+            )))?
+        }
+        Ok(true)
+    }
+
+    async fn insert_invoice_number_sequence(
+        &self,
+        invoice_number_sequence: storage::InvoiceNumberSequenceNew,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError> {
+        let mut sequences = self.invoice_number_sequences.lock().await;
+        if sequences
+            .iter()
+            .any(|existing| existing.profile_id == invoice_number_sequence.profile_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "profile_id",
+                key: Some(invoice_number_sequence.profile_id.clone()),
+            })?
+        }
+        let invoice_number_sequence = storage::InvoiceNumberSequence {
+            profile_id: invoice_number_sequence.profile_id,
+            merchant_id: invoice_number_sequence.merchant_id,
+            prefix: invoice_number_sequence.prefix,
+            next_number: invoice_number_sequence.next_number,
+            created_at: invoice_number_sequence.created_at,
+            modified_at: invoice_number_sequence.modified_at,
+        };
+        sequences.push(invoice_number_sequence.clone());
+        Ok(invoice_number_sequence)
+    }
+
+    async fn find_invoice_number_sequence_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError> {
+        self.invoice_number_sequences
+            .lock()
+            .await
+            .iter()
+            .find(|sequence| sequence.profile_id == profile_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No invoice number sequence available for profile_id = {profile_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_invoice_number_sequence_by_profile_id(
+        &self,
+        profile_id: &str,
+        invoice_number_sequence_update: storage::InvoiceNumberSequenceUpdate,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError> {
+        let mut sequences = self.invoice_number_sequences.lock().await;
+        let sequence = sequences
+            .iter_mut()
+            .find(|sequence| sequence.profile_id == profile_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No invoice number sequence available for profile_id = {profile_id}"
+            )))?;
+        *sequence =
+            storage::InvoiceNumberSequenceUpdateInternal::from(invoice_number_sequence_update)
+                .apply_changeset(sequence.clone());
+        Ok(sequence.clone())
+    }
+
+    async fn finalize_invoice_with_next_invoice_number(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        profile_id: &str,
+        finalized_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let mut sequences = self.invoice_number_sequences.lock().await;
+        let mut invoices = self.invoices.lock().await;
+        let sequence = sequences
+            .iter_mut()
+            .find(|sequence| sequence.profile_id == profile_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No invoice number sequence available for profile_id = {profile_id}"
+            )))?;
+        let invoice = invoices
+            .iter_mut()
+            .find(|invoice| {
+                invoice.merchant_id == *merchant_id
+                    && invoice.invoice_id == invoice_id
+                    && invoice.status == storage_enums::InvoiceStatus::Draft
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No draft invoice available for invoice_id = {invoice_id}"
+            )))?;
+
+        *invoice = storage::InvoiceUpdateInternal::from(storage::InvoiceUpdate::Finalize {
+            invoice_number: sequence.get_next_invoice_number(),
+            finalized_at,
+        })
+        .apply_changeset(invoice.clone());
+        sequence.next_number += 1;
+        sequence.modified_at = common_utils::date_time::now();
+        Ok(invoice.clone())
+    }
+
+    async fn insert_invoice_payment(
+        &self,
+        payment: storage::InvoicePaymentNew,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError> {
+        let mut payments = self.invoice_payments.lock().await;
+        if payments
+            .iter()
+            .any(|existing| existing.payment_id == payment.payment_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payment_id",
+                key: Some(payment.payment_id.clone()),
+            })?
+        }
+        let payment = storage::InvoicePayment {
+            payment_id: payment.payment_id,
+            invoice_id: payment.invoice_id,
+            merchant_id: payment.merchant_id,
+            source: payment.source,
+            amount: payment.amount,
+            amount_received: payment.amount_received,
+            currency: payment.currency,
+            status: payment.status,
+            reference: payment.reference,
+            credited: payment.credited,
+            created_at: payment.created_at,
+            modified_at: payment.modified_at,
+        };
+        payments.push(payment.clone());
+        Ok(payment)
+    }
+
+    async fn find_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError> {
+        self.invoice_payments
+            .lock()
+            .await
+            .iter()
+            .find(|payment| payment.payment_id == payment_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No invoice payment available for payment_id = {payment_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_invoice_payments_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<Vec<storage::InvoicePayment>, errors::StorageError> {
+        let mut payments: Vec<_> = self
+            .invoice_payments
+            .lock()
+            .await
+            .iter()
+            .filter(|payment| payment.invoice_id == invoice_id)
+            .cloned()
+            .collect();
+        payments.sort_by_key(|payment| payment.created_at);
+        Ok(payments)
+    }
+
+    async fn update_uncredited_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        payment_update: storage::InvoicePaymentUpdate,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError> {
+        let mut payments = self.invoice_payments.lock().await;
+        let payment = payments
+            .iter_mut()
+            .find(|payment| payment.payment_id == payment_id && !payment.credited)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No uncredited invoice payment available for payment_id = {payment_id}"
+            )))?;
+        *payment = storage::InvoicePaymentUpdateInternal::from(payment_update)
+            .apply_changeset(payment.clone());
+        Ok(payment.clone())
+    }
+
+    async fn credit_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        status: storage_enums::IntentStatus,
+        amount_received: MinorUnit,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        // Both locks are held until the invoice is updated, so that the payment is never observed
+        // as credited without its amount having been added to the invoice
+        let mut payments = self.invoice_payments.lock().await;
+        let mut invoices = self.invoices.lock().await;
+        let payment = payments
+            .iter_mut()
+            .find(|payment| payment.payment_id == payment_id && !payment.credited)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No uncredited invoice payment available for payment_id = {payment_id}"
+            )))?;
+        let invoice = invoices
+            .iter_mut()
+            .find(|invoice| invoice.invoice_id == payment.invoice_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No invoice available for invoice_id = {}",
+                payment.invoice_id
+            )))?;
+
+        *payment =
+            storage::InvoicePaymentUpdateInternal::from(storage::InvoicePaymentUpdate::Credited {
+                status,
+                amount_received,
+            })
+            .apply_changeset(payment.clone());
+        invoice.amount_paid = invoice.amount_paid + amount_received;
+        invoice.modified_at = common_utils::date_time::now();
+        Ok(invoice.clone())
+    }
+}
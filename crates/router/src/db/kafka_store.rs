@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
 use common_enums::enums::MerchantStorageScheme;
-use common_utils::{errors::CustomResult, id_type, pii, types::keymanager::KeyManagerState};
+use common_utils::{
+    errors::CustomResult,
+    id_type, pii,
+    types::{keymanager::KeyManagerState, MinorUnit},
+};
 use diesel_models::{
     enums,
     enums::ProcessTrackerStatus,
@@ -51,6 +55,8 @@ use crate::{
         cards_info::CardsInfoInterface,
         configs::ConfigInterface,
         customers::CustomerInterface,
+        data_retention::DataRetentionInterface,
+        data_subject_request::DataSubjectRequestInterface,
         dispute::DisputeInterface,
         ephemeral_key::EphemeralKeyInterface,
         events::EventInterface,
@@ -58,16 +64,24 @@ use crate::{
         generic_link::GenericLinkInterface,
         gsm::GsmInterface,
         health_check::HealthCheckDbInterface,
+        invoice::InvoiceInterface,
+        ledger::LedgerInterface,
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
         merchant_account::MerchantAccountInterface,
         merchant_connector_account::{ConnectorAccessToken, MerchantConnectorAccountInterface},
+        merchant_key_rotation::MerchantKeyRotationInterface,
         merchant_key_store::MerchantKeyStoreInterface,
         payment_link::PaymentLinkInterface,
         payment_method::PaymentMethodInterface,
+        payout_batch::PayoutBatchInterface,
         refund::RefundInterface,
+        refund_batch::RefundBatchInterface,
+        reusable_payment_link::ReusablePaymentLinkInterface,
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
+        scim::ScimUserInterface,
+        webhook_signing_key::WebhookSigningKeyInterface,
         CommonStorageInterface, GlobalStorageInterface, MasterKeyInterface, StorageInterface,
     },
     services::{authentication, kafka::KafkaProducer, Store},
@@ -278,6 +292,67 @@ impl CardsInfoInterface for KafkaStore {
     ) -> CustomResult<Option<storage::CardInfo>, errors::StorageError> {
         self.diesel_store.get_card_info(card_iin).await
     }
+
+    async fn insert_card_bin_range(
+        &self,
+        card_bin_range: storage::CardBinRangeNew,
+    ) -> CustomResult<storage::CardBinRange, errors::StorageError> {
+        self.diesel_store
+            .insert_card_bin_range(card_bin_range)
+            .await
+    }
+
+    async fn find_card_bin_ranges_between(
+        &self,
+        first_bin: &str,
+        last_bin: &str,
+    ) -> CustomResult<Vec<storage::CardBinRange>, errors::StorageError> {
+        self.diesel_store
+            .find_card_bin_ranges_between(first_bin, last_bin)
+            .await
+    }
+
+    async fn import_card_bin_ranges(
+        &self,
+        new_ranges: Vec<storage::CardBinRangeNew>,
+        updated_ranges: Vec<(String, String, storage::CardBinRangeUpdate)>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .import_card_bin_ranges(new_ranges, updated_ranges)
+            .await
+    }
+
+    async fn update_card_bin_range(
+        &self,
+        bin_start: &str,
+        bin_end: &str,
+        card_bin_range_update: storage::CardBinRangeUpdate,
+    ) -> CustomResult<storage::CardBinRange, errors::StorageError> {
+        self.diesel_store
+            .update_card_bin_range(bin_start, bin_end, card_bin_range_update)
+            .await
+    }
+
+    async fn delete_card_bin_range(
+        &self,
+        bin_start: &str,
+        bin_end: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_card_bin_range(bin_start, bin_end)
+            .await
+    }
+
+    async fn list_card_bin_ranges(
+        &self,
+        bin_length: Option<i32>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::CardBinRange>, errors::StorageError> {
+        self.diesel_store
+            .list_card_bin_ranges(bin_length, limit, offset)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -454,6 +529,26 @@ impl CustomerInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    async fn list_customers_by_merchant_id_with_pagination(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        self.diesel_store
+            .list_customers_by_merchant_id_with_pagination(
+                state,
+                merchant_id,
+                key_store,
+                limit,
+                offset,
+            )
+            .await
+    }
+
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
     async fn find_customer_by_customer_id_merchant_id(
         &self,
@@ -575,6 +670,23 @@ impl DisputeInterface for KafkaStore {
             .await
     }
 
+    async fn find_open_disputes_by_merchant_id_challenge_required_by(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<String>,
+        challenge_required_by_range: (PrimitiveDateTime, PrimitiveDateTime),
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        self.diesel_store
+            .find_open_disputes_by_merchant_id_challenge_required_by(
+                merchant_id,
+                profile_id,
+                challenge_required_by_range,
+                limit,
+            )
+            .await
+    }
+
     async fn update_dispute(
         &self,
         this: storage::Dispute,
@@ -2312,6 +2424,23 @@ impl MerchantKeyStoreInterface for KafkaStore {
             .await
     }
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        merchant_key_store_update: diesel_models::merchant_key_store::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        self.diesel_store
+            .update_merchant_key_store_by_merchant_id(
+                state,
+                merchant_id,
+                merchant_key_store_update,
+                key,
+            )
+            .await
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -2611,6 +2740,62 @@ impl GsmInterface for KafkaStore {
             .delete_gsm_rule(connector, flow, sub_flow, code, message)
             .await
     }
+
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store.list_gsm_rules(connector, flow).await
+    }
+
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store
+            .find_gsm_rules_by_connectors(connectors)
+            .await
+    }
+
+    async fn apply_gsm_rule_changes(
+        &self,
+        new_rules: Vec<storage::GatewayStatusMappingNew>,
+        updated_rules: Vec<storage::GatewayStatusMappingNew>,
+        deleted_rules: Vec<storage::GatewayStatusMap>,
+        history: Vec<storage::GatewayStatusMapHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .apply_gsm_rule_changes(new_rules, updated_rules, deleted_rules, history)
+            .await
+    }
+
+    async fn insert_gsm_rule_history(
+        &self,
+        entries: Vec<storage::GatewayStatusMapHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store.insert_gsm_rule_history(entries).await
+    }
+
+    async fn find_gsm_rule_history_by_change_id(
+        &self,
+        change_id: &str,
+    ) -> CustomResult<Vec<storage::GatewayStatusMapHistory>, errors::StorageError> {
+        self.diesel_store
+            .find_gsm_rule_history_by_change_id(change_id)
+            .await
+    }
+
+    async fn list_gsm_rule_history(
+        &self,
+        connector: Option<String>,
+        flow: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::GatewayStatusMapHistory>, errors::StorageError> {
+        self.diesel_store
+            .list_gsm_rule_history(connector, flow, limit)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2830,6 +3015,16 @@ impl UserRoleInterface for KafkaStore {
             .list_user_roles_by_merchant_id(merchant_id, version)
             .await
     }
+
+    async fn list_user_roles_by_role_id_org_id(
+        &self,
+        role_id: &str,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<user_storage::UserRole>, errors::StorageError> {
+        self.diesel_store
+            .list_user_roles_by_role_id_org_id(role_id, org_id)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3351,3 +3546,1104 @@ impl UserAuthenticationMethodInterface for KafkaStore {
             .await
     }
 }
+
+#[async_trait::async_trait]
+impl ScimUserInterface for KafkaStore {
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        self.diesel_store.insert_scim_user(scim_user).await
+    }
+
+    async fn find_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        self.diesel_store
+            .find_scim_user_by_merchant_id_user_id(merchant_id, user_id)
+            .await
+    }
+
+    async fn list_scim_users_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        self.diesel_store
+            .list_scim_users_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn list_scim_users_by_merchant_id_role_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        role_id: &str,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        self.diesel_store
+            .list_scim_users_by_merchant_id_role_id(merchant_id, role_id)
+            .await
+    }
+
+    async fn update_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+        scim_user_update: storage::ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        self.diesel_store
+            .update_scim_user_by_merchant_id_user_id(merchant_id, user_id, scim_user_update)
+            .await
+    }
+
+    async fn delete_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_scim_user_by_merchant_id_user_id(merchant_id, user_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl RefundBatchInterface for KafkaStore {
+    async fn insert_refund_batch(
+        &self,
+        refund_batch: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.diesel_store.insert_refund_batch(refund_batch).await
+    }
+
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.diesel_store
+            .find_refund_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn list_refund_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatch>, errors::StorageError> {
+        self.diesel_store
+            .list_refund_batches_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn update_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        refund_batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.diesel_store
+            .update_refund_batch_by_merchant_id_batch_id(merchant_id, batch_id, refund_batch_update)
+            .await
+    }
+
+    async fn insert_refund_batch_items(
+        &self,
+        refund_batch_items: Vec<storage::RefundBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_refund_batch_items(refund_batch_items)
+            .await
+    }
+
+    async fn list_refund_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_refund_batch_items_by_batch_id(batch_id, limit, offset)
+            .await
+    }
+
+    async fn list_refund_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::RefundBatchItemStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_refund_batch_items_by_batch_id_status(batch_id, status, limit)
+            .await
+    }
+
+    async fn update_refund_batch_item_by_batch_id_line_number(
+        &self,
+        batch_id: &str,
+        line_number: i32,
+        refund_batch_item_update: storage::RefundBatchItemUpdate,
+    ) -> CustomResult<storage::RefundBatchItem, errors::StorageError> {
+        self.diesel_store
+            .update_refund_batch_item_by_batch_id_line_number(
+                batch_id,
+                line_number,
+                refund_batch_item_update,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store.insert_payout_batch(payout_batch).await
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batches_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id, payout_batch_update)
+            .await
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_by_merchant_id_batch_id_status(
+                merchant_id,
+                batch_id,
+                status,
+                payout_batch_update,
+            )
+            .await
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id_status_and_insert_process(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+        process: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_by_merchant_id_batch_id_status_and_insert_process(
+                merchant_id,
+                batch_id,
+                status,
+                payout_batch_update,
+                process,
+            )
+            .await
+    }
+
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_payout_batch_items(payout_batch_items)
+            .await
+    }
+
+    async fn list_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batch_items_by_batch_id(batch_id, limit, offset)
+            .await
+    }
+
+    async fn list_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batch_items_by_batch_id_status(batch_id, status, limit)
+            .await
+    }
+
+    async fn update_payout_batch_item_by_batch_id_line_number(
+        &self,
+        batch_id: &str,
+        line_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_item_by_batch_id_line_number(
+                batch_id,
+                line_number,
+                payout_batch_item_update,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl DataSubjectRequestInterface for KafkaStore {
+    async fn insert_data_subject_request(
+        &self,
+        data_subject_request: storage::DataSubjectRequestNew,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        self.diesel_store
+            .insert_data_subject_request(data_subject_request)
+            .await
+    }
+
+    async fn find_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        self.diesel_store
+            .find_data_subject_request_by_merchant_id_request_id(merchant_id, request_id)
+            .await
+    }
+
+    async fn list_data_subject_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::DataSubjectRequest>, errors::StorageError> {
+        self.diesel_store
+            .list_data_subject_requests_by_merchant_id(merchant_id, customer_id, limit, offset)
+            .await
+    }
+
+    async fn update_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        request_id: &str,
+        data_subject_request_update: storage::DataSubjectRequestUpdate,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        self.diesel_store
+            .update_data_subject_request_by_merchant_id_request_id(
+                merchant_id,
+                request_id,
+                data_subject_request_update,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for KafkaStore {
+    async fn insert_data_retention_policy(
+        &self,
+        policy: storage::DataRetentionPolicyNew,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.diesel_store.insert_data_retention_policy(policy).await
+    }
+
+    async fn find_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.diesel_store
+            .find_data_retention_policy_by_table_name(table_name)
+            .await
+    }
+
+    async fn list_data_retention_policies(
+        &self,
+    ) -> CustomResult<Vec<storage::DataRetentionPolicy>, errors::StorageError> {
+        self.diesel_store.list_data_retention_policies().await
+    }
+
+    async fn update_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+        policy_update: storage::DataRetentionPolicyUpdate,
+    ) -> CustomResult<storage::DataRetentionPolicy, errors::StorageError> {
+        self.diesel_store
+            .update_data_retention_policy_by_table_name(table_name, policy_update)
+            .await
+    }
+
+    async fn delete_data_retention_policy_by_table_name(
+        &self,
+        table_name: enums::DataRetentionTable,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_data_retention_policy_by_table_name(table_name)
+            .await
+    }
+
+    async fn insert_data_retention_archive(
+        &self,
+        archive: storage::DataRetentionArchiveNew,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError> {
+        self.diesel_store
+            .insert_data_retention_archive(archive)
+            .await
+    }
+
+    async fn find_data_retention_archive_by_archive_id(
+        &self,
+        archive_id: &str,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError> {
+        self.diesel_store
+            .find_data_retention_archive_by_archive_id(archive_id)
+            .await
+    }
+
+    async fn list_data_retention_archives(
+        &self,
+        table_name: Option<enums::DataRetentionTable>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::DataRetentionArchive>, errors::StorageError> {
+        self.diesel_store
+            .list_data_retention_archives(table_name, limit, offset)
+            .await
+    }
+
+    async fn list_data_retention_archives_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        table_names: Vec<enums::DataRetentionTable>,
+        after_archive_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::DataRetentionArchive>, errors::StorageError> {
+        self.diesel_store
+            .list_data_retention_archives_by_merchant_id(
+                merchant_id,
+                table_names,
+                after_archive_id,
+                limit,
+            )
+            .await
+    }
+
+    async fn update_data_retention_archive_by_archive_id(
+        &self,
+        archive_id: &str,
+        archive_update: storage::DataRetentionArchiveUpdate,
+    ) -> CustomResult<storage::DataRetentionArchive, errors::StorageError> {
+        self.diesel_store
+            .update_data_retention_archive_by_archive_id(archive_id, archive_update)
+            .await
+    }
+
+    async fn find_events_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::events::Event>, errors::StorageError> {
+        self.diesel_store
+            .find_events_created_before(created_before, limit)
+            .await
+    }
+
+    async fn delete_events_by_event_ids(
+        &self,
+        event_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_events_by_event_ids(event_ids)
+            .await
+    }
+
+    async fn insert_archived_events(
+        &self,
+        events: Vec<diesel_models::events::Event>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store.insert_archived_events(events).await
+    }
+
+    async fn find_settled_payment_intents(
+        &self,
+        statuses: Vec<enums::IntentStatus>,
+        settled_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::payment_intent::PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .find_settled_payment_intents(statuses, settled_before, limit)
+            .await
+    }
+
+    async fn find_payment_attempts_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<diesel_models::payment_attempt::PaymentAttempt>, errors::StorageError>
+    {
+        self.diesel_store
+            .find_payment_attempts_by_merchant_id_payment_ids(merchant_id, payment_ids)
+            .await
+    }
+
+    async fn delete_archived_payments(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_archived_payments(merchant_id, payment_ids)
+            .await
+    }
+
+    async fn insert_archived_payments(
+        &self,
+        state: &KeyManagerState,
+        payment_intents: Vec<diesel_models::payment_intent::PaymentIntent>,
+        payment_attempts: Vec<diesel_models::payment_attempt::PaymentAttempt>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .insert_archived_payments(state, payment_intents, payment_attempts, key_store)
+            .await
+    }
+
+    async fn find_finished_processes_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::process_tracker::ProcessTracker>, errors::StorageError>
+    {
+        self.diesel_store
+            .find_finished_processes_created_before(created_before, limit)
+            .await
+    }
+
+    async fn delete_processes_by_ids(
+        &self,
+        ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store.delete_processes_by_ids(ids).await
+    }
+
+    async fn insert_archived_processes(
+        &self,
+        processes: Vec<diesel_models::process_tracker::ProcessTracker>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store.insert_archived_processes(processes).await
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyRotationInterface for KafkaStore {
+    async fn insert_merchant_key_rotation(
+        &self,
+        rotation: storage::MerchantKeyRotationNew,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.diesel_store
+            .insert_merchant_key_rotation(rotation)
+            .await
+    }
+
+    async fn find_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        rotation_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.diesel_store
+            .find_merchant_key_rotation_by_merchant_id_rotation_id(merchant_id, rotation_id)
+            .await
+    }
+
+    async fn list_merchant_key_rotations_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::MerchantKeyRotation>, errors::StorageError> {
+        self.diesel_store
+            .list_merchant_key_rotations_by_merchant_id(merchant_id, limit)
+            .await
+    }
+
+    async fn update_merchant_key_rotation_by_rotation_id(
+        &self,
+        rotation_id: &str,
+        rotation_update: storage::MerchantKeyRotationUpdate,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.diesel_store
+            .update_merchant_key_rotation_by_rotation_id(rotation_id, rotation_update)
+            .await
+    }
+
+    async fn find_encrypted_rows_for_key_rotation(
+        &self,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &id_type::MerchantId,
+        after_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EncryptedRow>, errors::StorageError> {
+        self.diesel_store
+            .find_encrypted_rows_for_key_rotation(table_name, merchant_id, after_id, limit)
+            .await
+    }
+
+    async fn update_encrypted_column_for_key_rotation(
+        &self,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+        column_index: usize,
+        current_value: common_utils::encryption::Encryption,
+        updated_value: common_utils::encryption::Encryption,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .update_encrypted_column_for_key_rotation(
+                table_name,
+                merchant_id,
+                id,
+                column_index,
+                current_value,
+                updated_value,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSigningKeyInterface for KafkaStore {
+    async fn insert_webhook_signing_key(
+        &self,
+        signing_key: storage::WebhookSigningKeyNew,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_signing_key(signing_key)
+            .await
+    }
+
+    async fn find_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_signing_key_by_profile_id_key_id(profile_id, key_id)
+            .await
+    }
+
+    async fn find_active_webhook_signing_key_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Option<storage::WebhookSigningKey>, errors::StorageError> {
+        self.diesel_store
+            .find_active_webhook_signing_key_by_profile_id(profile_id)
+            .await
+    }
+
+    async fn list_webhook_signing_keys_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookSigningKey>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_signing_keys_by_profile_id(profile_id)
+            .await
+    }
+
+    async fn update_webhook_signing_key_by_key_id(
+        &self,
+        key_id: &str,
+        signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_signing_key_by_key_id(key_id, signing_key_update)
+            .await
+    }
+
+    async fn rotate_and_insert_webhook_signing_key(
+        &self,
+        signing_key: storage::WebhookSigningKeyNew,
+        rotated_key_expires_at: PrimitiveDateTime,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.diesel_store
+            .rotate_and_insert_webhook_signing_key(signing_key, rotated_key_expires_at)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for KafkaStore {
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .insert_reusable_payment_link(reusable_payment_link)
+            .await
+    }
+
+    async fn find_reusable_payment_link_by_merchant_id_reusable_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .find_reusable_payment_link_by_merchant_id_reusable_link_id(
+                merchant_id,
+                reusable_link_id,
+            )
+            .await
+    }
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        self.diesel_store
+            .list_reusable_payment_links_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn update_reusable_payment_link_by_merchant_id_reusable_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+        reusable_payment_link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .update_reusable_payment_link_by_merchant_id_reusable_link_id(
+                merchant_id,
+                reusable_link_id,
+                reusable_payment_link_update,
+            )
+            .await
+    }
+
+    async fn increment_reusable_payment_link_times_used(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Option<storage::ReusablePaymentLink>, errors::StorageError> {
+        self.diesel_store
+            .increment_reusable_payment_link_times_used(reusable_link_id)
+            .await
+    }
+
+    async fn decrement_reusable_payment_link_times_used(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .decrement_reusable_payment_link_times_used(reusable_link_id)
+            .await
+    }
+
+    async fn insert_reusable_payment_link_items(
+        &self,
+        items: Vec<storage::ReusablePaymentLinkItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_reusable_payment_link_items(items)
+            .await
+    }
+
+    async fn list_reusable_payment_link_items_by_reusable_link_id(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkItem>, errors::StorageError> {
+        self.diesel_store
+            .list_reusable_payment_link_items_by_reusable_link_id(reusable_link_id)
+            .await
+    }
+
+    async fn increment_reusable_payment_link_item_quantity_sold(
+        &self,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> CustomResult<Option<storage::ReusablePaymentLinkItem>, errors::StorageError> {
+        self.diesel_store
+            .increment_reusable_payment_link_item_quantity_sold(reusable_link_id, item_id, quantity)
+            .await
+    }
+
+    async fn decrement_reusable_payment_link_item_quantity_sold(
+        &self,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> CustomResult<storage::ReusablePaymentLinkItem, errors::StorageError> {
+        self.diesel_store
+            .decrement_reusable_payment_link_item_quantity_sold(reusable_link_id, item_id, quantity)
+            .await
+    }
+
+    async fn insert_reusable_payment_link_payment(
+        &self,
+        payment: storage::ReusablePaymentLinkPaymentNew,
+    ) -> CustomResult<storage::ReusablePaymentLinkPayment, errors::StorageError> {
+        self.diesel_store
+            .insert_reusable_payment_link_payment(payment)
+            .await
+    }
+
+    async fn list_reusable_payment_link_payments_by_reusable_link_id(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkPayment>, errors::StorageError> {
+        self.diesel_store
+            .list_reusable_payment_link_payments_by_reusable_link_id(reusable_link_id)
+            .await
+    }
+
+    async fn update_unreleased_reusable_payment_link_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        payment_update: storage::ReusablePaymentLinkPaymentUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLinkPayment, errors::StorageError> {
+        self.diesel_store
+            .update_unreleased_reusable_payment_link_payment_by_payment_id(
+                payment_id,
+                payment_update,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl InvoiceInterface for KafkaStore {
+    async fn insert_invoice(
+        &self,
+        invoice: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store.insert_invoice(invoice).await
+    }
+
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store
+            .find_invoice_by_merchant_id_invoice_id(merchant_id, invoice_id)
+            .await
+    }
+
+    async fn list_invoices_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: Option<&id_type::CustomerId>,
+        status: Option<enums::InvoiceStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        self.diesel_store
+            .list_invoices_by_merchant_id(merchant_id, customer_id, status, limit, offset)
+            .await
+    }
+
+    async fn update_invoice_by_merchant_id_invoice_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        current_status: enums::InvoiceStatus,
+        invoice_update: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store
+            .update_invoice_by_merchant_id_invoice_id_status(
+                merchant_id,
+                invoice_id,
+                current_status,
+                invoice_update,
+            )
+            .await
+    }
+
+    async fn insert_invoice_line_items(
+        &self,
+        line_items: Vec<storage::InvoiceLineItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_invoice_line_items(line_items)
+            .await
+    }
+
+    async fn list_invoice_line_items_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<Vec<storage::InvoiceLineItem>, errors::StorageError> {
+        self.diesel_store
+            .list_invoice_line_items_by_invoice_id(invoice_id)
+            .await
+    }
+
+    async fn delete_invoice_line_items_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_invoice_line_items_by_invoice_id(invoice_id)
+            .await
+    }
+
+    async fn insert_invoice_number_sequence(
+        &self,
+        invoice_number_sequence: storage::InvoiceNumberSequenceNew,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError> {
+        self.diesel_store
+            .insert_invoice_number_sequence(invoice_number_sequence)
+            .await
+    }
+
+    async fn find_invoice_number_sequence_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError> {
+        self.diesel_store
+            .find_invoice_number_sequence_by_profile_id(profile_id)
+            .await
+    }
+
+    async fn update_invoice_number_sequence_by_profile_id(
+        &self,
+        profile_id: &str,
+        invoice_number_sequence_update: storage::InvoiceNumberSequenceUpdate,
+    ) -> CustomResult<storage::InvoiceNumberSequence, errors::StorageError> {
+        self.diesel_store
+            .update_invoice_number_sequence_by_profile_id(
+                profile_id,
+                invoice_number_sequence_update,
+            )
+            .await
+    }
+
+    async fn finalize_invoice_with_next_invoice_number(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+        profile_id: &str,
+        finalized_at: PrimitiveDateTime,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store
+            .finalize_invoice_with_next_invoice_number(
+                merchant_id,
+                invoice_id,
+                profile_id,
+                finalized_at,
+            )
+            .await
+    }
+
+    async fn insert_invoice_payment(
+        &self,
+        payment: storage::InvoicePaymentNew,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError> {
+        self.diesel_store.insert_invoice_payment(payment).await
+    }
+
+    async fn find_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError> {
+        self.diesel_store
+            .find_invoice_payment_by_payment_id(payment_id)
+            .await
+    }
+
+    async fn list_invoice_payments_by_invoice_id(
+        &self,
+        invoice_id: &str,
+    ) -> CustomResult<Vec<storage::InvoicePayment>, errors::StorageError> {
+        self.diesel_store
+            .list_invoice_payments_by_invoice_id(invoice_id)
+            .await
+    }
+
+    async fn update_uncredited_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        payment_update: storage::InvoicePaymentUpdate,
+    ) -> CustomResult<storage::InvoicePayment, errors::StorageError> {
+        self.diesel_store
+            .update_uncredited_invoice_payment_by_payment_id(payment_id, payment_update)
+            .await
+    }
+
+    async fn credit_invoice_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        status: enums::IntentStatus,
+        amount_received: MinorUnit,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store
+            .credit_invoice_payment_by_payment_id(payment_id, status, amount_received)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerInterface for KafkaStore {
+    async fn insert_ledger_entry(
+        &self,
+        entry: storage::LedgerEntryNew,
+        postings: Vec<storage::LedgerPostingNew>,
+    ) -> CustomResult<storage::LedgerEntry, errors::StorageError> {
+        self.diesel_store.insert_ledger_entry(entry, postings).await
+    }
+
+    async fn list_ledger_entries_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reference_id: Option<&str>,
+        currency: Option<enums::Currency>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        self.diesel_store
+            .list_ledger_entries_by_merchant_id(merchant_id, reference_id, currency, limit, offset)
+            .await
+    }
+
+    async fn list_ledger_entries_by_merchant_id_entry_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        self.diesel_store
+            .list_ledger_entries_by_merchant_id_entry_ids(merchant_id, entry_ids)
+            .await
+    }
+
+    async fn list_ledger_postings_by_entry_ids(
+        &self,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError> {
+        self.diesel_store
+            .list_ledger_postings_by_entry_ids(entry_ids)
+            .await
+    }
+
+    async fn list_ledger_postings_by_merchant_id_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        currency: enums::Currency,
+        account: Option<enums::LedgerAccount>,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError> {
+        self.diesel_store
+            .list_ledger_postings_by_merchant_id_currency(
+                merchant_id,
+                currency,
+                account,
+                effective_until,
+            )
+            .await
+    }
+
+    async fn sum_ledger_postings_by_merchant_id_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        currency: enums::Currency,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> CustomResult<
+        Vec<(
+            enums::LedgerAccount,
+            enums::LedgerPostingDirection,
+            MinorUnit,
+        )>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .sum_ledger_postings_by_merchant_id_currency(merchant_id, currency, effective_until)
+            .await
+    }
+
+    async fn replace_ledger_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reference_ids: Vec<String>,
+        recorded_before: PrimitiveDateTime,
+        entries: Vec<(storage::LedgerEntryNew, Vec<storage::LedgerPostingNew>)>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .replace_ledger_by_merchant_id(merchant_id, reference_ids, recorded_before, entries)
+            .await
+    }
+
+    async fn list_payment_intents_by_merchant_id_after_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        after_payment_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_intents_by_merchant_id_after_payment_id(
+                merchant_id,
+                after_payment_id,
+                limit,
+            )
+            .await
+    }
+
+    async fn find_refunds_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<diesel_models::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_refunds_by_merchant_id_payment_ids(merchant_id, payment_ids)
+            .await
+    }
+
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<diesel_models::Dispute>, errors::StorageError> {
+        self.diesel_store
+            .find_disputes_by_merchant_id_payment_ids(merchant_id, payment_ids)
+            .await
+    }
+
+    #[cfg(feature = "payouts")]
+    async fn list_payouts_by_merchant_id_after_payout_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        after_payout_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::Payouts>, errors::StorageError> {
+        self.diesel_store
+            .list_payouts_by_merchant_id_after_payout_id(merchant_id, after_payout_id, limit)
+            .await
+    }
+}
@@ -0,0 +1,685 @@
+use std::collections::{HashMap, HashSet};
+
+use async_bb8_diesel::AsyncConnection;
+use common_utils::{id_type, types::MinorUnit};
+#[cfg(feature = "payouts")]
+use diesel_models::payouts::Payouts;
+use diesel_models::{
+    dispute::Dispute, enums as storage_enums, ledger as storage, payment_intent::PaymentIntent,
+    refund::Refund,
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::behaviour::Conversion;
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait LedgerInterface {
+    /// Inserts the entry along with its postings, atomically
+    async fn insert_ledger_entry(
+        &self,
+        entry: storage::LedgerEntryNew,
+        postings: Vec<storage::LedgerPostingNew>,
+    ) -> CustomResult<storage::LedgerEntry, errors::StorageError>;
+
+    async fn list_ledger_entries_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reference_id: Option<&str>,
+        currency: Option<storage_enums::Currency>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError>;
+
+    async fn list_ledger_entries_by_merchant_id_entry_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError>;
+
+    async fn list_ledger_postings_by_entry_ids(
+        &self,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError>;
+
+    async fn list_ledger_postings_by_merchant_id_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        currency: storage_enums::Currency,
+        account: Option<storage_enums::LedgerAccount>,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError>;
+
+    /// Sums the postings made in the currency up to the given time, per account and direction
+    async fn sum_ledger_postings_by_merchant_id_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        currency: storage_enums::Currency,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> CustomResult<
+        Vec<(
+            storage_enums::LedgerAccount,
+            storage_enums::LedgerPostingDirection,
+            MinorUnit,
+        )>,
+        errors::StorageError,
+    >;
+
+    /// Replaces the entries and postings of the given payments and payouts recorded before the
+    /// given time with the specified entries, atomically. The entries of other payments and
+    /// payouts, and those recorded since then are kept, and the specified entries already
+    /// recorded for the same records are skipped. Returns the number of entries inserted.
+    async fn replace_ledger_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reference_ids: Vec<String>,
+        recorded_before: PrimitiveDateTime,
+        entries: Vec<(storage::LedgerEntryNew, Vec<storage::LedgerPostingNew>)>,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    /// Lists the payment intents of the merchant in the order of their IDs, starting after the
+    /// given payment ID
+    async fn list_payment_intents_by_merchant_id_after_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        after_payment_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError>;
+
+    async fn find_refunds_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<Refund>, errors::StorageError>;
+
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<Dispute>, errors::StorageError>;
+
+    /// Lists the payouts of the merchant in the order of their IDs, starting after the given
+    /// payout ID
+    #[cfg(feature = "payouts")]
+    async fn list_payouts_by_merchant_id_after_payout_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        after_payout_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<Payouts>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl LedgerInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_ledger_entry(
+        &self,
+        entry: storage::LedgerEntryNew,
+        postings: Vec<storage::LedgerPostingNew>,
+    ) -> CustomResult<storage::LedgerEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            let entry = entry
+                .insert(&conn)
+                .await
+                .map_err(|e| *e.current_context())?;
+            storage::LedgerPostingNew::batch_insert(postings, &conn)
+                .await
+                .map_err(|e| *e.current_context())?;
+            Ok::<_, diesel_models::errors::DatabaseError>(entry)
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_ledger_entries_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reference_id: Option<&str>,
+        currency: Option<storage_enums::Currency>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerEntry::list_by_merchant_id(
+            &conn,
+            merchant_id,
+            reference_id,
+            currency,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_ledger_entries_by_merchant_id_entry_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerEntry::list_by_merchant_id_entry_ids(&conn, merchant_id, entry_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_ledger_postings_by_entry_ids(
+        &self,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerPosting::list_by_entry_ids(&conn, entry_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_ledger_postings_by_merchant_id_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        currency: storage_enums::Currency,
+        account: Option<storage_enums::LedgerAccount>,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerPosting::list_by_merchant_id_currency(
+            &conn,
+            merchant_id,
+            currency,
+            account,
+            effective_until,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn sum_ledger_postings_by_merchant_id_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        currency: storage_enums::Currency,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> CustomResult<
+        Vec<(
+            storage_enums::LedgerAccount,
+            storage_enums::LedgerPostingDirection,
+            MinorUnit,
+        )>,
+        errors::StorageError,
+    > {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerPosting::sum_by_merchant_id_currency(
+            &conn,
+            merchant_id,
+            currency,
+            effective_until,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn replace_ledger_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reference_ids: Vec<String>,
+        recorded_before: PrimitiveDateTime,
+        entries: Vec<(storage::LedgerEntryNew, Vec<storage::LedgerPostingNew>)>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            let (replaced, kept): (Vec<_>, Vec<_>) =
+                storage::LedgerEntry::list_by_merchant_id_reference_ids(
+                    &conn,
+                    merchant_id,
+                    reference_ids,
+                )
+                .await
+                .map_err(|e| *e.current_context())?
+                .into_iter()
+                .partition(|entry| entry.created_at < recorded_before);
+
+            let replaced_entry_ids: Vec<_> =
+                replaced.into_iter().map(|entry| entry.entry_id).collect();
+            if !replaced_entry_ids.is_empty() {
+                storage::LedgerPosting::delete_by_merchant_id_entry_ids(
+                    &conn,
+                    merchant_id,
+                    replaced_entry_ids.clone(),
+                )
+                .await
+                .map_err(|e| *e.current_context())?;
+                storage::LedgerEntry::delete_by_merchant_id_entry_ids(
+                    &conn,
+                    merchant_id,
+                    replaced_entry_ids,
+                )
+                .await
+                .map_err(|e| *e.current_context())?;
+            }
+
+            let recorded: HashSet<_> = kept
+                .into_iter()
+                .map(|entry| (entry.entry_type, entry.source_id))
+                .collect();
+
+            let mut inserted = 0;
+            for (entry, postings) in entries {
+                if recorded.contains(&(entry.entry_type, entry.source_id.clone())) {
+                    continue;
+                }
+                entry
+                    .insert(&conn)
+                    .await
+                    .map_err(|e| *e.current_context())?;
+                storage::LedgerPostingNew::batch_insert(postings, &conn)
+                    .await
+                    .map_err(|e| *e.current_context())?;
+                inserted += 1;
+            }
+            Ok::<_, diesel_models::errors::DatabaseError>(inserted)
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payment_intents_by_merchant_id_after_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        after_payment_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        PaymentIntent::list_by_merchant_id_after_payment_id(
+            &conn,
+            merchant_id,
+            after_payment_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refunds_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<Refund>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        Refund::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        Dispute::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "payouts")]
+    #[instrument(skip_all)]
+    async fn list_payouts_by_merchant_id_after_payout_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        after_payout_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<Payouts>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        Payouts::list_by_merchant_id_after_payout_id(&conn, merchant_id, after_payout_id, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerInterface for MockDb {
+    async fn insert_ledger_entry(
+        &self,
+        entry: storage::LedgerEntryNew,
+        postings: Vec<storage::LedgerPostingNew>,
+    ) -> CustomResult<storage::LedgerEntry, errors::StorageError> {
+        let mut entries = self.ledger_entries.lock().await;
+        if entries.iter().any(|existing| {
+            existing.merchant_id == entry.merchant_id
+                && existing.entry_type == entry.entry_type
+                && existing.source_id == entry.source_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "ledger_entry",
+                key: Some(entry.source_id.clone()),
+            })?
+        }
+        let entry = storage::LedgerEntry {
+            entry_id: entry.entry_id,
+            merchant_id: entry.merchant_id,
+            currency: entry.currency,
+            entry_type: entry.entry_type,
+            source_id: entry.source_id,
+            reference_id: entry.reference_id,
+            description: entry.description,
+            effective_at: entry.effective_at,
+            created_at: entry.created_at,
+        };
+        entries.push(entry.clone());
+        self.ledger_postings
+            .lock()
+            .await
+            .extend(postings.into_iter().map(|posting| storage::LedgerPosting {
+                entry_id: posting.entry_id,
+                line_number: posting.line_number,
+                merchant_id: posting.merchant_id,
+                currency: posting.currency,
+                account: posting.account,
+                direction: posting.direction,
+                amount: posting.amount,
+                effective_at: posting.effective_at,
+                created_at: posting.created_at,
+            }));
+        Ok(entry)
+    }
+
+    async fn list_ledger_entries_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reference_id: Option<&str>,
+        currency: Option<storage_enums::Currency>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        let mut entries: Vec<_> = self
+            .ledger_entries
+            .lock()
+            .await
+            .iter()
+            .filter(|entry| {
+                entry.merchant_id == *merchant_id
+                    && reference_id.map_or(true, |reference_id| entry.reference_id == reference_id)
+                    && currency.map_or(true, |currency| entry.currency == currency)
+            })
+            .cloned()
+            .collect();
+        entries.sort_by(|a, b| (b.effective_at, b.created_at).cmp(&(a.effective_at, a.created_at)));
+        Ok(entries.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn list_ledger_entries_by_merchant_id_entry_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let mut entries: Vec<_> = self
+            .ledger_entries
+            .lock()
+            .await
+            .iter()
+            .filter(|entry| {
+                entry.merchant_id == *merchant_id && entry_ids.contains(&entry.entry_id)
+            })
+            .cloned()
+            .collect();
+        entries.sort_by_key(|entry| entry.effective_at);
+        Ok(entries)
+    }
+
+    async fn list_ledger_postings_by_entry_ids(
+        &self,
+        entry_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError> {
+        let mut postings: Vec<_> = self
+            .ledger_postings
+            .lock()
+            .await
+            .iter()
+            .filter(|posting| entry_ids.contains(&posting.entry_id))
+            .cloned()
+            .collect();
+        postings.sort_by_key(|posting| posting.line_number);
+        Ok(postings)
+    }
+
+    async fn list_ledger_postings_by_merchant_id_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        currency: storage_enums::Currency,
+        account: Option<storage_enums::LedgerAccount>,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> CustomResult<Vec<storage::LedgerPosting>, errors::StorageError> {
+        let mut postings: Vec<_> = self
+            .ledger_postings
+            .lock()
+            .await
+            .iter()
+            .filter(|posting| {
+                posting.merchant_id == *merchant_id
+                    && posting.currency == currency
+                    && account.map_or(true, |account| posting.account == account)
+                    && effective_until.map_or(true, |effective_until| {
+                        posting.effective_at <= effective_until
+                    })
+            })
+            .cloned()
+            .collect();
+        postings.sort_by_key(|posting| {
+            (
+                posting.effective_at,
+                posting.created_at,
+                posting.line_number,
+            )
+        });
+        Ok(postings)
+    }
+
+    async fn sum_ledger_postings_by_merchant_id_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        currency: storage_enums::Currency,
+        effective_until: Option<PrimitiveDateTime>,
+    ) -> CustomResult<
+        Vec<(
+            storage_enums::LedgerAccount,
+            storage_enums::LedgerPostingDirection,
+            MinorUnit,
+        )>,
+        errors::StorageError,
+    > {
+        let mut totals = HashMap::new();
+        for posting in self.ledger_postings.lock().await.iter().filter(|posting| {
+            posting.merchant_id == *merchant_id
+                && posting.currency == currency
+                && effective_until.map_or(true, |effective_until| {
+                    posting.effective_at <= effective_until
+                })
+        }) {
+            let total = totals
+                .entry((posting.account, posting.direction))
+                .or_insert(MinorUnit::zero());
+            *total = *total + posting.amount;
+        }
+        Ok(totals
+            .into_iter()
+            .map(|((account, direction), amount)| (account, direction, amount))
+            .collect())
+    }
+
+    async fn replace_ledger_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reference_ids: Vec<String>,
+        recorded_before: PrimitiveDateTime,
+        entries: Vec<(storage::LedgerEntryNew, Vec<storage::LedgerPostingNew>)>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut ledger_entries = self.ledger_entries.lock().await;
+        let mut ledger_postings = self.ledger_postings.lock().await;
+        let replaced_entry_ids: HashSet<_> = ledger_entries
+            .iter()
+            .filter(|entry| {
+                entry.merchant_id == *merchant_id
+                    && reference_ids.contains(&entry.reference_id)
+                    && entry.created_at < recorded_before
+            })
+            .map(|entry| entry.entry_id.clone())
+            .collect();
+        ledger_postings.retain(|posting| !replaced_entry_ids.contains(&posting.entry_id));
+        ledger_entries.retain(|entry| !replaced_entry_ids.contains(&entry.entry_id));
+
+        let mut inserted = 0;
+        for (entry, postings) in entries {
+            if ledger_entries.iter().any(|existing| {
+                existing.merchant_id == entry.merchant_id
+                    && existing.entry_type == entry.entry_type
+                    && existing.source_id == entry.source_id
+            }) {
+                continue;
+            }
+            ledger_entries.push(storage::LedgerEntry {
+                entry_id: entry.entry_id,
+                merchant_id: entry.merchant_id,
+                currency: entry.currency,
+                entry_type: entry.entry_type,
+                source_id: entry.source_id,
+                reference_id: entry.reference_id,
+                description: entry.description,
+                effective_at: entry.effective_at,
+                created_at: entry.created_at,
+            });
+            ledger_postings.extend(postings.into_iter().map(|posting| storage::LedgerPosting {
+                entry_id: posting.entry_id,
+                line_number: posting.line_number,
+                merchant_id: posting.merchant_id,
+                currency: posting.currency,
+                account: posting.account,
+                direction: posting.direction,
+                amount: posting.amount,
+                effective_at: posting.effective_at,
+                created_at: posting.created_at,
+            }));
+            inserted += 1;
+        }
+        Ok(inserted)
+    }
+
+    async fn list_payment_intents_by_merchant_id_after_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        after_payment_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        let mut payment_intents: Vec<_> = self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == *merchant_id
+                    && after_payment_id.as_ref().map_or(true, |after_payment_id| {
+                        payment_intent.payment_id > *after_payment_id
+                    })
+            })
+            .cloned()
+            .collect();
+        payment_intents.sort_by(|a, b| a.payment_id.cmp(&b.payment_id));
+        payment_intents.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        let mut storage_payment_intents = Vec::new();
+        for payment_intent in payment_intents {
+            storage_payment_intents.push(
+                payment_intent
+                    .convert()
+                    .await
+                    .change_context(errors::StorageError::EncryptionError)?,
+            );
+        }
+        Ok(storage_payment_intents)
+    }
+
+    async fn find_refunds_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<Refund>, errors::StorageError> {
+        Ok(self
+            .refunds
+            .lock()
+            .await
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == *merchant_id && payment_ids.contains(&refund.payment_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<Dispute>, errors::StorageError> {
+        Ok(self
+            .disputes
+            .lock()
+            .await
+            .iter()
+            .filter(|dispute| {
+                dispute.merchant_id == *merchant_id && payment_ids.contains(&dispute.payment_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    #[cfg(feature = "payouts")]
+    async fn list_payouts_by_merchant_id_after_payout_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        after_payout_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<Payouts>, errors::StorageError> {
+        let mut payouts: Vec<_> = self
+            .payouts
+            .lock()
+            .await
+            .iter()
+            .filter(|payouts| {
+                payouts.merchant_id == *merchant_id
+                    && after_payout_id
+                        .as_ref()
+                        .map_or(true, |after_payout_id| payouts.payout_id > *after_payout_id)
+            })
+            .cloned()
+            .collect();
+        payouts.sort_by(|a, b| a.payout_id.cmp(&b.payout_id));
+        payouts.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(payouts)
+    }
+}
@@ -498,76 +498,190 @@ impl MerchantAccountInterface for MockDb {
             .await
             .transpose()?;
 
-        match account {
-            Some(account) => Ok(account),
-            // [#172]: Implement function for `MockDb`
-            None => Err(errors::StorageError::MockDbError)?,
-        }
+        account.ok_or(
+            errors::StorageError::ValueNotFound(format!(
+                "Merchant account with merchant_id = {} not found",
+                merchant_id.get_string_repr()
+            ))
+            .into(),
+        )
     }
 
     async fn update_merchant(
         &self,
-        _state: &KeyManagerState,
-        _this: domain::MerchantAccount,
-        _merchant_account: storage::MerchantAccountUpdate,
-        _merchant_key_store: &domain::MerchantKeyStore,
+        state: &KeyManagerState,
+        this: domain::MerchantAccount,
+        merchant_account: storage::MerchantAccountUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_specific_fields_in_merchant(
+            state,
+            this.get_id(),
+            merchant_account,
+            merchant_key_store,
+        )
+        .await
     }
 
     async fn update_specific_fields_in_merchant(
         &self,
-        _state: &KeyManagerState,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _merchant_account: storage::MerchantAccountUpdate,
-        _merchant_key_store: &domain::MerchantKeyStore,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_account: storage::MerchantAccountUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
-        // [#TODO]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut accounts = self.merchant_accounts.lock().await;
+        let account = accounts
+            .iter_mut()
+            .find(|account| account.get_id() == merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "Merchant account with merchant_id = {} not found",
+                merchant_id.get_string_repr()
+            )))?;
+        *account =
+            MerchantAccountUpdateInternal::from(merchant_account).apply_changeset(account.clone());
+
+        account
+            .clone()
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     async fn find_merchant_account_by_publishable_key(
         &self,
-        _state: &KeyManagerState,
-        _publishable_key: &str,
+        state: &KeyManagerState,
+        publishable_key: &str,
     ) -> CustomResult<authentication::AuthenticationData, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let merchant_account = self
+            .merchant_accounts
+            .lock()
+            .await
+            .iter()
+            .find(|account| account.publishable_key.as_deref() == Some(publishable_key))
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "Merchant account with publishable_key = {publishable_key} not found"
+            )))?;
+        let key_store = self
+            .get_merchant_key_store_by_merchant_id(
+                state,
+                merchant_account.get_id(),
+                &self.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        Ok(authentication::AuthenticationData {
+            merchant_account: merchant_account
+                .convert(
+                    state,
+                    key_store.key.get_inner(),
+                    key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?,
+
+            key_store,
+            profile_id: None,
+        })
     }
 
     async fn update_all_merchant_account(
         &self,
-        _merchant_account_update: storage::MerchantAccountUpdate,
+        merchant_account_update: storage::MerchantAccountUpdate,
     ) -> CustomResult<usize, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let update = MerchantAccountUpdateInternal::from(merchant_account_update);
+        let mut accounts = self.merchant_accounts.lock().await;
+        for account in accounts.iter_mut() {
+            *account = update.clone().apply_changeset(account.clone());
+        }
+
+        Ok(accounts.len())
     }
 
     async fn delete_merchant_account_by_merchant_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<bool, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut accounts = self.merchant_accounts.lock().await;
+        let position = accounts
+            .iter()
+            .position(|account| account.get_id() == merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "Merchant account with merchant_id = {} not found",
+                merchant_id.get_string_repr()
+            )))?;
+        accounts.remove(position);
+
+        Ok(true)
     }
 
     #[cfg(feature = "olap")]
     async fn list_merchant_accounts_by_organization_id(
         &self,
-        _state: &KeyManagerState,
-        _organization_id: &str,
+        state: &KeyManagerState,
+        organization_id: &str,
     ) -> CustomResult<Vec<domain::MerchantAccount>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let accounts = self
+            .merchant_accounts
+            .lock()
+            .await
+            .iter()
+            .filter(|account| account.organization_id.get_string_repr() == organization_id)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        convert_mock_merchant_accounts(self, state, accounts).await
     }
 
     #[cfg(feature = "olap")]
     async fn list_multiple_merchant_accounts(
         &self,
-        _state: &KeyManagerState,
-        _merchant_ids: Vec<common_utils::id_type::MerchantId>,
+        state: &KeyManagerState,
+        merchant_ids: Vec<common_utils::id_type::MerchantId>,
     ) -> CustomResult<Vec<domain::MerchantAccount>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let accounts = self
+            .merchant_accounts
+            .lock()
+            .await
+            .iter()
+            .filter(|account| merchant_ids.contains(account.get_id()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        convert_mock_merchant_accounts(self, state, accounts).await
+    }
+}
+
+#[cfg(feature = "olap")]
+async fn convert_mock_merchant_accounts(
+    db: &MockDb,
+    state: &KeyManagerState,
+    accounts: Vec<storage::MerchantAccount>,
+) -> CustomResult<Vec<domain::MerchantAccount>, errors::StorageError> {
+    let db_master_key = db.get_master_key().to_vec().into();
+    let mut merchant_accounts = Vec::with_capacity(accounts.len());
+    for account in accounts {
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(state, account.get_id(), &db_master_key)
+            .await?;
+        merchant_accounts.push(
+            account
+                .convert(
+                    state,
+                    key_store.key.get_inner(),
+                    key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?,
+        );
     }
+
+    Ok(merchant_accounts)
 }
 
 #[cfg(feature = "accounts_cache")]
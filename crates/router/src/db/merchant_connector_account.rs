@@ -920,14 +920,23 @@ impl MerchantConnectorAccountInterface for Store {
 impl MerchantConnectorAccountInterface for MockDb {
     async fn update_multiple_merchant_connector_accounts(
         &self,
-        _merchant_connector_accounts: Vec<(
+        merchant_connector_accounts: Vec<(
             domain::MerchantConnectorAccount,
             storage::MerchantConnectorAccountUpdateInternal,
         )>,
     ) -> CustomResult<(), errors::StorageError> {
-        // No need to implement this function for `MockDb` as this function will be removed after the
-        // apple pay certificate migration
-        Err(errors::StorageError::MockDbError)?
+        let mut accounts = self.merchant_connector_accounts.lock().await;
+        for (merchant_connector_account, mca_update) in merchant_connector_accounts {
+            let account = accounts
+                .iter_mut()
+                .find(|account| account.get_id() == merchant_connector_account.get_id())
+                .ok_or(errors::StorageError::ValueNotFound(
+                    "cannot find merchant connector account to update".to_string(),
+                ))?;
+            *account = mca_update.create_merchant_connector_account(account.clone());
+        }
+
+        Ok(())
     }
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
@@ -1494,6 +1503,8 @@ mod merchant_connector_account_cache_tests {
                 .and_then(|val| val.try_into_operation())
                 .unwrap(),
                 created_at: datetime!(2023-02-01 0:00),
+                key_version: 1,
+                previous_key: None,
             },
             &master_key.to_vec().into(),
         )
@@ -1663,6 +1674,8 @@ mod merchant_connector_account_cache_tests {
                 .and_then(|val| val.try_into_operation())
                 .unwrap(),
                 created_at: datetime!(2023-02-01 0:00),
+                key_version: 1,
+                previous_key: None,
             },
             &master_key.to_vec().into(),
         )
@@ -0,0 +1,745 @@
+use common_utils::{crypto::Encryptable, encryption::Encryption, id_type};
+use diesel_models::{enums, merchant_key_rotation as storage};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait MerchantKeyRotationInterface {
+    async fn insert_merchant_key_rotation(
+        &self,
+        rotation: storage::MerchantKeyRotationNew,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError>;
+
+    async fn find_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        rotation_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError>;
+
+    async fn list_merchant_key_rotations_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::MerchantKeyRotation>, errors::StorageError>;
+
+    async fn update_merchant_key_rotation_by_rotation_id(
+        &self,
+        rotation_id: &str,
+        rotation_update: storage::MerchantKeyRotationUpdate,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError>;
+
+    async fn find_encrypted_rows_for_key_rotation(
+        &self,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &id_type::MerchantId,
+        after_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EncryptedRow>, errors::StorageError>;
+
+    async fn update_encrypted_column_for_key_rotation(
+        &self,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+        column_index: usize,
+        current_value: Encryption,
+        updated_value: Encryption,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyRotationInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_merchant_key_rotation(
+        &self,
+        rotation: storage::MerchantKeyRotationNew,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        rotation
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        rotation_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantKeyRotation::find_by_merchant_id_rotation_id(
+            &conn,
+            merchant_id,
+            rotation_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_merchant_key_rotations_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::MerchantKeyRotation>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantKeyRotation::list_by_merchant_id(&conn, merchant_id, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_merchant_key_rotation_by_rotation_id(
+        &self,
+        rotation_id: &str,
+        rotation_update: storage::MerchantKeyRotationUpdate,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::MerchantKeyRotation::update_by_rotation_id(&conn, rotation_id, rotation_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_encrypted_rows_for_key_rotation(
+        &self,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &id_type::MerchantId,
+        after_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EncryptedRow>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EncryptedRow::find_batch(&conn, table_name, merchant_id, after_id, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_encrypted_column_for_key_rotation(
+        &self,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+        column_index: usize,
+        current_value: Encryption,
+        updated_value: Encryption,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EncryptedRow::update_column(
+            &conn,
+            table_name,
+            merchant_id,
+            id,
+            column_index,
+            current_value,
+            updated_value,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyRotationInterface for MockDb {
+    async fn insert_merchant_key_rotation(
+        &self,
+        rotation: storage::MerchantKeyRotationNew,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let mut rotations = self.merchant_key_rotations.lock().await;
+        if rotations.iter().any(|existing| {
+            existing.merchant_id == rotation.merchant_id
+                && matches!(
+                    existing.status,
+                    enums::KeyRotationStatus::InProgress | enums::KeyRotationStatus::Verifying
+                )
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "merchant_key_rotation",
+                key: Some(rotation.merchant_id.get_string_repr().to_owned()),
+            })?
+        }
+        let rotation = storage::MerchantKeyRotation {
+            rotation_id: rotation.rotation_id,
+            merchant_id: rotation.merchant_id,
+            key_version: rotation.key_version,
+            status: rotation.status,
+            current_table: rotation.current_table,
+            last_processed_id: rotation.last_processed_id,
+            rows_processed: rotation.rows_processed,
+            error_message: rotation.error_message,
+            created_at: rotation.created_at,
+            modified_at: rotation.modified_at,
+            completed_at: rotation.completed_at,
+        };
+        rotations.push(rotation.clone());
+        Ok(rotation)
+    }
+
+    async fn find_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        rotation_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.merchant_key_rotations
+            .lock()
+            .await
+            .iter()
+            .find(|rotation| {
+                rotation.merchant_id == *merchant_id && rotation.rotation_id == rotation_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No merchant key rotation available for rotation_id = {rotation_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_merchant_key_rotations_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::MerchantKeyRotation>, errors::StorageError> {
+        let mut rotations: Vec<_> = self
+            .merchant_key_rotations
+            .lock()
+            .await
+            .iter()
+            .filter(|rotation| rotation.merchant_id == *merchant_id)
+            .cloned()
+            .collect();
+        rotations.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        if let Some(limit) = limit.and_then(|limit| usize::try_from(limit).ok()) {
+            rotations.truncate(limit);
+        }
+        Ok(rotations)
+    }
+
+    async fn update_merchant_key_rotation_by_rotation_id(
+        &self,
+        rotation_id: &str,
+        rotation_update: storage::MerchantKeyRotationUpdate,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let mut rotations = self.merchant_key_rotations.lock().await;
+        let rotation = rotations
+            .iter_mut()
+            .find(|rotation| rotation.rotation_id == rotation_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No merchant key rotation available for rotation_id = {rotation_id}"
+            )))?;
+
+        *rotation = storage::MerchantKeyRotationUpdateInternal::from(rotation_update)
+            .apply_changeset(rotation.clone());
+        Ok(rotation.clone())
+    }
+
+    async fn find_encrypted_rows_for_key_rotation(
+        &self,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &id_type::MerchantId,
+        after_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EncryptedRow>, errors::StorageError> {
+        let mut rows: Vec<_> = match table_name {
+            enums::KeyRotationTable::MerchantAccount => self
+                .merchant_accounts
+                .lock()
+                .await
+                .iter()
+                .filter(|account| account.get_id() == merchant_id)
+                .map(|account| storage::EncryptedRow {
+                    id: account.get_id().get_string_repr().to_owned(),
+                    columns: vec![
+                        account.merchant_name.clone(),
+                        account.merchant_details.clone(),
+                    ],
+                })
+                .collect(),
+            enums::KeyRotationTable::BusinessProfile => self
+                .business_profiles
+                .lock()
+                .await
+                .iter()
+                .filter(|profile| profile.merchant_id == *merchant_id)
+                .map(|profile| storage::EncryptedRow {
+                    id: profile.profile_id.clone(),
+                    columns: vec![profile.outgoing_webhook_custom_http_headers.clone()],
+                })
+                .collect(),
+            enums::KeyRotationTable::MerchantConnectorAccount => self
+                .merchant_connector_accounts
+                .lock()
+                .await
+                .iter()
+                .filter(|account| account.merchant_id == *merchant_id)
+                .map(|account| storage::EncryptedRow {
+                    id: account.get_id(),
+                    columns: vec![
+                        Some(account.connector_account_details.clone()),
+                        account.additional_merchant_data.clone(),
+                        account.connector_wallets_details.clone(),
+                    ],
+                })
+                .collect(),
+            #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+            enums::KeyRotationTable::Customers => self
+                .customers
+                .lock()
+                .await
+                .iter()
+                .filter(|customer| customer.merchant_id == *merchant_id)
+                .map(|customer| storage::EncryptedRow {
+                    id: customer.customer_id.get_string_repr().to_owned(),
+                    columns: vec![
+                        customer.name.clone(),
+                        customer.email.clone(),
+                        customer.phone.clone(),
+                    ],
+                })
+                .collect(),
+            #[cfg(all(feature = "v2", feature = "customer_v2"))]
+            enums::KeyRotationTable::Customers => self
+                .customers
+                .lock()
+                .await
+                .iter()
+                .filter(|customer| customer.merchant_id == *merchant_id)
+                .map(|customer| storage::EncryptedRow {
+                    id: customer.id.clone(),
+                    columns: vec![
+                        customer.name.clone(),
+                        customer.email.clone(),
+                        customer.phone.clone(),
+                        customer.default_billing_address.clone(),
+                        customer.default_shipping_address.clone(),
+                    ],
+                })
+                .collect(),
+            enums::KeyRotationTable::Address => self
+                .addresses
+                .lock()
+                .await
+                .iter()
+                .filter(|address| address.merchant_id == *merchant_id)
+                .map(|address| storage::EncryptedRow {
+                    id: address.address_id.clone(),
+                    columns: vec![
+                        address.line1.clone(),
+                        address.line2.clone(),
+                        address.line3.clone(),
+                        address.state.clone(),
+                        address.zip.clone(),
+                        address.first_name.clone(),
+                        address.last_name.clone(),
+                        address.phone_number.clone(),
+                        address.email.clone(),
+                    ],
+                })
+                .collect(),
+            enums::KeyRotationTable::PaymentMethods => self
+                .payment_methods
+                .lock()
+                .await
+                .iter()
+                .filter(|payment_method| payment_method.merchant_id == *merchant_id)
+                .map(|payment_method| storage::EncryptedRow {
+                    id: payment_method.payment_method_id.clone(),
+                    columns: vec![
+                        payment_method.payment_method_data.clone(),
+                        payment_method.payment_method_billing_address.clone(),
+                    ],
+                })
+                .collect(),
+            enums::KeyRotationTable::PaymentIntent => self
+                .payment_intents
+                .lock()
+                .await
+                .iter()
+                .filter(|payment_intent| payment_intent.merchant_id == *merchant_id)
+                .map(|payment_intent| storage::EncryptedRow {
+                    id: payment_intent.payment_id.clone(),
+                    columns: [
+                        &payment_intent.customer_details,
+                        &payment_intent.billing_details,
+                        &payment_intent.shipping_details,
+                    ]
+                    .into_iter()
+                    .map(|value| value.clone().map(Encryption::from))
+                    .collect(),
+                })
+                .collect(),
+            enums::KeyRotationTable::Events => self
+                .events
+                .lock()
+                .await
+                .iter()
+                .filter(|event| event.merchant_id.as_ref() == Some(merchant_id))
+                .map(|event| storage::EncryptedRow {
+                    id: event.event_id.clone(),
+                    columns: vec![event.request.clone(), event.response.clone()],
+                })
+                .collect(),
+            enums::KeyRotationTable::WebhookSigningKey => self
+                .webhook_signing_keys
+                .lock()
+                .await
+                .iter()
+                .filter(|signing_key| signing_key.merchant_id == *merchant_id)
+                .map(|signing_key| storage::EncryptedRow {
+                    id: signing_key.key_id.clone(),
+                    columns: vec![Some(signing_key.private_key.clone())],
+                })
+                .collect(),
+            // Data retention archives are not stored in a database table
+            enums::KeyRotationTable::DataRetentionArchive => {
+                Err(errors::StorageError::MockDbError)?
+            }
+        };
+
+        rows.retain(|row| {
+            after_id
+                .as_ref()
+                .map_or(true, |after_id| row.id.as_str() > after_id.as_str())
+        });
+        rows.sort_by(|row, other_row| row.id.cmp(&other_row.id));
+        rows.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(rows)
+    }
+
+    async fn update_encrypted_column_for_key_rotation(
+        &self,
+        table_name: enums::KeyRotationTable,
+        merchant_id: &id_type::MerchantId,
+        id: &str,
+        column_index: usize,
+        current_value: Encryption,
+        updated_value: Encryption,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let out_of_bounds = || {
+            errors::StorageError::ValueNotFound(format!(
+                "Column index {column_index} is out of bounds for {table_name}"
+            ))
+        };
+
+        let is_updated = match table_name {
+            enums::KeyRotationTable::MerchantAccount => {
+                let mut accounts = self.merchant_accounts.lock().await;
+                let Some(account) = accounts.iter_mut().find(|account| {
+                    account.get_id() == merchant_id && account.get_id().get_string_repr() == id
+                }) else {
+                    return Ok(false);
+                };
+                [
+                    account.merchant_name.as_mut(),
+                    account.merchant_details.as_mut(),
+                ]
+                .into_iter()
+                .nth(column_index)
+                .ok_or_else(out_of_bounds)
+                .map(|column| replace_encrypted_value(column, &current_value, updated_value))
+            }
+            enums::KeyRotationTable::BusinessProfile => {
+                let mut profiles = self.business_profiles.lock().await;
+                let Some(profile) = profiles.iter_mut().find(|profile| {
+                    profile.merchant_id == *merchant_id && profile.profile_id == id
+                }) else {
+                    return Ok(false);
+                };
+                [profile.outgoing_webhook_custom_http_headers.as_mut()]
+                    .into_iter()
+                    .nth(column_index)
+                    .ok_or_else(out_of_bounds)
+                    .map(|column| replace_encrypted_value(column, &current_value, updated_value))
+            }
+            enums::KeyRotationTable::MerchantConnectorAccount => {
+                let mut accounts = self.merchant_connector_accounts.lock().await;
+                let Some(account) = accounts
+                    .iter_mut()
+                    .find(|account| account.merchant_id == *merchant_id && account.get_id() == id)
+                else {
+                    return Ok(false);
+                };
+                [
+                    Some(&mut account.connector_account_details),
+                    account.additional_merchant_data.as_mut(),
+                    account.connector_wallets_details.as_mut(),
+                ]
+                .into_iter()
+                .nth(column_index)
+                .ok_or_else(out_of_bounds)
+                .map(|column| replace_encrypted_value(column, &current_value, updated_value))
+            }
+            #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+            enums::KeyRotationTable::Customers => {
+                let mut customers = self.customers.lock().await;
+                let Some(customer) = customers.iter_mut().find(|customer| {
+                    customer.merchant_id == *merchant_id
+                        && customer.customer_id.get_string_repr() == id
+                }) else {
+                    return Ok(false);
+                };
+                [
+                    customer.name.as_mut(),
+                    customer.email.as_mut(),
+                    customer.phone.as_mut(),
+                ]
+                .into_iter()
+                .nth(column_index)
+                .ok_or_else(out_of_bounds)
+                .map(|column| replace_encrypted_value(column, &current_value, updated_value))
+            }
+            #[cfg(all(feature = "v2", feature = "customer_v2"))]
+            enums::KeyRotationTable::Customers => {
+                let mut customers = self.customers.lock().await;
+                let Some(customer) = customers
+                    .iter_mut()
+                    .find(|customer| customer.merchant_id == *merchant_id && customer.id == id)
+                else {
+                    return Ok(false);
+                };
+                [
+                    customer.name.as_mut(),
+                    customer.email.as_mut(),
+                    customer.phone.as_mut(),
+                    customer.default_billing_address.as_mut(),
+                    customer.default_shipping_address.as_mut(),
+                ]
+                .into_iter()
+                .nth(column_index)
+                .ok_or_else(out_of_bounds)
+                .map(|column| replace_encrypted_value(column, &current_value, updated_value))
+            }
+            enums::KeyRotationTable::Address => {
+                let mut addresses = self.addresses.lock().await;
+                let Some(address) = addresses.iter_mut().find(|address| {
+                    address.merchant_id == *merchant_id && address.address_id == id
+                }) else {
+                    return Ok(false);
+                };
+                [
+                    address.line1.as_mut(),
+                    address.line2.as_mut(),
+                    address.line3.as_mut(),
+                    address.state.as_mut(),
+                    address.zip.as_mut(),
+                    address.first_name.as_mut(),
+                    address.last_name.as_mut(),
+                    address.phone_number.as_mut(),
+                    address.email.as_mut(),
+                ]
+                .into_iter()
+                .nth(column_index)
+                .ok_or_else(out_of_bounds)
+                .map(|column| replace_encrypted_value(column, &current_value, updated_value))
+            }
+            enums::KeyRotationTable::PaymentMethods => {
+                let mut payment_methods = self.payment_methods.lock().await;
+                let Some(payment_method) = payment_methods.iter_mut().find(|payment_method| {
+                    payment_method.merchant_id == *merchant_id
+                        && payment_method.payment_method_id == id
+                }) else {
+                    return Ok(false);
+                };
+                [
+                    payment_method.payment_method_data.as_mut(),
+                    payment_method.payment_method_billing_address.as_mut(),
+                ]
+                .into_iter()
+                .nth(column_index)
+                .ok_or_else(out_of_bounds)
+                .map(|column| replace_encrypted_value(column, &current_value, updated_value))
+            }
+            enums::KeyRotationTable::PaymentIntent => {
+                // The mock payment intents hold the decrypted values along with the encrypted ones
+                let mut payment_intents = self.payment_intents.lock().await;
+                let Some(payment_intent) = payment_intents.iter_mut().find(|payment_intent| {
+                    payment_intent.merchant_id == *merchant_id && payment_intent.payment_id == id
+                }) else {
+                    return Ok(false);
+                };
+                let column = [
+                    &mut payment_intent.customer_details,
+                    &mut payment_intent.billing_details,
+                    &mut payment_intent.shipping_details,
+                ]
+                .into_iter()
+                .nth(column_index)
+                .ok_or_else(out_of_bounds)?;
+                match column {
+                    Some(value) if Encryption::from(value.clone()) == current_value => {
+                        *value = Encryptable::new(
+                            value.clone().into_inner(),
+                            updated_value.into_inner(),
+                        );
+                        Ok(true)
+                    }
+                    _ => Ok(false),
+                }
+            }
+            enums::KeyRotationTable::Events => {
+                let mut events = self.events.lock().await;
+                let Some(event) = events.iter_mut().find(|event| {
+                    event.merchant_id.as_ref() == Some(merchant_id) && event.event_id == id
+                }) else {
+                    return Ok(false);
+                };
+                [event.request.as_mut(), event.response.as_mut()]
+                    .into_iter()
+                    .nth(column_index)
+                    .ok_or_else(out_of_bounds)
+                    .map(|column| replace_encrypted_value(column, &current_value, updated_value))
+            }
+            enums::KeyRotationTable::WebhookSigningKey => {
+                let mut signing_keys = self.webhook_signing_keys.lock().await;
+                let Some(signing_key) = signing_keys.iter_mut().find(|signing_key| {
+                    signing_key.merchant_id == *merchant_id && signing_key.key_id == id
+                }) else {
+                    return Ok(false);
+                };
+                [Some(&mut signing_key.private_key)]
+                    .into_iter()
+                    .nth(column_index)
+                    .ok_or_else(out_of_bounds)
+                    .map(|column| replace_encrypted_value(column, &current_value, updated_value))
+            }
+            // Data retention archives are not stored in a database table
+            enums::KeyRotationTable::DataRetentionArchive => Err(errors::StorageError::MockDbError),
+        }?;
+        Ok(is_updated)
+    }
+}
+
+/// Replaces the value of an encrypted column of a mock row, unless it was modified since it was
+/// read
+fn replace_encrypted_value(
+    column: Option<&mut Encryption>,
+    current_value: &Encryption,
+    updated_value: Encryption,
+) -> bool {
+    match column {
+        Some(value) if value == current_value => {
+            *value = updated_value;
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use masking::Secret;
+
+    use super::*;
+
+    fn get_encryption(value: &[u8]) -> Encryption {
+        Encryption::new(Secret::new(value.to_vec()))
+    }
+
+    fn get_address(
+        address_id: &str,
+        merchant_id: &id_type::MerchantId,
+        line1: &[u8],
+    ) -> diesel_models::address::Address {
+        let current_time = common_utils::date_time::now();
+        diesel_models::address::Address {
+            address_id: address_id.to_string(),
+            city: None,
+            country: None,
+            line1: Some(get_encryption(line1)),
+            line2: None,
+            line3: None,
+            state: None,
+            zip: None,
+            first_name: None,
+            last_name: None,
+            phone_number: None,
+            country_code: None,
+            created_at: current_time,
+            modified_at: current_time,
+            customer_id: None,
+            merchant_id: merchant_id.clone(),
+            payment_id: None,
+            updated_by: "postgres_only".to_string(),
+            email: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_db_key_rotation_reencrypts_unmodified_values() {
+        let mock_db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant1")).unwrap();
+        let other_merchant_id = id_type::MerchantId::try_from(Cow::from("merchant2")).unwrap();
+        mock_db.addresses.lock().await.extend([
+            get_address("add_2", &merchant_id, b"old_2"),
+            get_address("add_1", &merchant_id, b"old_1"),
+            get_address("add_3", &other_merchant_id, b"old_3"),
+        ]);
+
+        let first_batch = mock_db
+            .find_encrypted_rows_for_key_rotation(
+                enums::KeyRotationTable::Address,
+                &merchant_id,
+                None,
+                1,
+            )
+            .await
+            .unwrap();
+        assert_eq!(first_batch.len(), 1);
+        assert_eq!(first_batch[0].id, "add_1");
+        assert_eq!(first_batch[0].columns.len(), 9);
+        assert_eq!(first_batch[0].columns[0], Some(get_encryption(b"old_1")));
+
+        let second_batch = mock_db
+            .find_encrypted_rows_for_key_rotation(
+                enums::KeyRotationTable::Address,
+                &merchant_id,
+                Some("add_1".to_string()),
+                10,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            second_batch
+                .iter()
+                .map(|row| row.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["add_2"]
+        );
+
+        let update_column = |id: &'static str, column_index, current_value: &'static [u8]| {
+            mock_db.update_encrypted_column_for_key_rotation(
+                enums::KeyRotationTable::Address,
+                &merchant_id,
+                id,
+                column_index,
+                get_encryption(current_value),
+                get_encryption(b"new"),
+            )
+        };
+        assert!(update_column("add_1", 0, b"old_1").await.unwrap());
+        // The value no longer matches the one which was read
+        assert!(!update_column("add_1", 0, b"old_1").await.unwrap());
+        // Rows of other merchants are never updated
+        assert!(!update_column("add_3", 0, b"old_3").await.unwrap());
+        assert!(update_column("add_2", 9, b"old_2").await.is_err());
+
+        let addresses = mock_db.addresses.lock().await;
+        assert_eq!(addresses[1].line1, Some(get_encryption(b"new")));
+        assert_eq!(addresses[0].line1, Some(get_encryption(b"old_2")));
+        assert_eq!(addresses[2].line1, Some(get_encryption(b"old_3")));
+    }
+}
@@ -32,6 +32,14 @@ pub trait MerchantKeyStoreInterface {
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_key_store_update: diesel_models::merchant_key_store::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -121,6 +129,46 @@ impl MerchantKeyStoreInterface for Store {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_key_store_update: diesel_models::merchant_key_store::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let update_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            diesel_models::merchant_key_store::MerchantKeyStore::update_by_merchant_id(
+                &conn,
+                merchant_id,
+                merchant_key_store_update,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        let updated_key_store = update_func().await?;
+
+        #[cfg(feature = "accounts_cache")]
+        let updated_key_store = {
+            let key_store_cache_key =
+                format!("merchant_key_store_{}", merchant_id.get_string_repr());
+            cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(key_store_cache_key.into()),
+                update_func,
+            )
+            .await?
+        };
+
+        updated_key_store
+            .convert(state, key, merchant_id.clone().into())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     #[instrument(skip_all)]
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
@@ -259,6 +307,33 @@ impl MerchantKeyStoreInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_key_store_update: diesel_models::merchant_key_store::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let mut merchant_key_stores = self.merchant_key_store.lock().await;
+        let merchant_key_store = merchant_key_stores
+            .iter_mut()
+            .find(|merchant_key| merchant_key.merchant_id == *merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(String::from(
+                "merchant_key_store",
+            )))?;
+        *merchant_key_store =
+            diesel_models::merchant_key_store::MerchantKeyStoreUpdateInternal::from(
+                merchant_key_store_update,
+            )
+            .apply_changeset(merchant_key_store.clone());
+
+        merchant_key_store
+            .clone()
+            .convert(state, key, merchant_id.clone().into())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -377,6 +452,8 @@ mod tests {
                     .and_then(|val| val.try_into_operation())
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_key: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -413,6 +490,8 @@ mod tests {
                     .and_then(|val| val.try_into_operation())
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_key: None,
                 },
                 &master_key.to_vec().into(),
             )
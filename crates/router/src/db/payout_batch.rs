@@ -0,0 +1,503 @@
+use async_bb8_diesel::AsyncConnection;
+use common_utils::id_type;
+use diesel_models::{enums, payout_batch as storage, process_tracker::ProcessTrackerNew};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use scheduler::db::process_tracker::ProcessTrackerInterface;
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError>;
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn update_payout_batch_by_merchant_id_batch_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    /// Updates the batch if it is still in the specified status and inserts the process tracker
+    /// task which executes it in one transaction
+    async fn update_payout_batch_by_merchant_id_batch_id_status_and_insert_process(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+        process: ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn list_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn update_payout_batch_item_by_batch_id_line_number(
+        &self,
+        batch_id: &str,
+        line_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatch::update_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            payout_batch_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_merchant_id_batch_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatch::update_by_merchant_id_batch_id_status(
+            &conn,
+            merchant_id,
+            batch_id,
+            status,
+            payout_batch_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_merchant_id_batch_id_status_and_insert_process(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+        process: ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            let payout_batch = storage::PayoutBatch::update_by_merchant_id_batch_id_status(
+                &conn,
+                merchant_id,
+                batch_id,
+                status,
+                payout_batch_update,
+            )
+            .await
+            .map_err(|e| *e.current_context())?;
+            process
+                .insert_process(&conn)
+                .await
+                .map_err(|e| *e.current_context())?;
+            Ok::<_, diesel_models::errors::DatabaseError>(payout_batch)
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItemNew::batch_insert(payout_batch_items, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::list_by_batch_id(&conn, batch_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::list_by_batch_id_status(&conn, batch_id, status, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item_by_batch_id_line_number(
+        &self,
+        batch_id: &str,
+        line_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::update_by_batch_id_line_number(
+            &conn,
+            batch_id,
+            line_number,
+            payout_batch_item_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        if payout_batches
+            .iter()
+            .any(|batch| batch.batch_id == payout_batch.batch_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "batch_id",
+                key: Some(payout_batch.batch_id.clone()),
+            })?
+        }
+        let payout_batch = storage::PayoutBatch {
+            batch_id: payout_batch.batch_id,
+            merchant_id: payout_batch.merchant_id,
+            profile_id: payout_batch.profile_id,
+            status: payout_batch.status,
+            total_count: payout_batch.total_count,
+            succeeded_count: payout_batch.succeeded_count,
+            failed_count: payout_batch.failed_count,
+            created_by: payout_batch.created_by,
+            reviewed_by: payout_batch.reviewed_by,
+            reviewed_at: payout_batch.reviewed_at,
+            review_comment: payout_batch.review_comment,
+            scheduled_at: payout_batch.scheduled_at,
+            created_at: payout_batch.created_at,
+            modified_at: payout_batch.modified_at,
+        };
+        payout_batches.push(payout_batch.clone());
+        Ok(payout_batch)
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.payout_batches
+            .lock()
+            .await
+            .iter()
+            .find(|batch| batch.merchant_id == *merchant_id && batch.batch_id == batch_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout batch available for batch_id = {batch_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut payout_batches: Vec<_> = self
+            .payout_batches
+            .lock()
+            .await
+            .iter()
+            .filter(|batch| batch.merchant_id == *merchant_id)
+            .cloned()
+            .collect();
+        payout_batches.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(payout_batches
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        let payout_batch = payout_batches
+            .iter_mut()
+            .find(|batch| batch.merchant_id == *merchant_id && batch.batch_id == batch_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout batch available for batch_id = {batch_id}"
+            )))?;
+
+        *payout_batch = storage::PayoutBatchUpdateInternal::from(payout_batch_update)
+            .apply_changeset(payout_batch.clone());
+        Ok(payout_batch.clone())
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        let payout_batch = payout_batches
+            .iter_mut()
+            .find(|batch| {
+                batch.merchant_id == *merchant_id
+                    && batch.batch_id == batch_id
+                    && batch.status == status
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout batch available for batch_id = {batch_id} and status = {status}"
+            )))?;
+
+        *payout_batch = storage::PayoutBatchUpdateInternal::from(payout_batch_update)
+            .apply_changeset(payout_batch.clone());
+        Ok(payout_batch.clone())
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id_status_and_insert_process(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        status: enums::PayoutBatchStatus,
+        payout_batch_update: storage::PayoutBatchUpdate,
+        process: ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let mut payout_batches = self.payout_batches.lock().await;
+        let payout_batch = payout_batches
+            .iter_mut()
+            .find(|batch| {
+                batch.merchant_id == *merchant_id
+                    && batch.batch_id == batch_id
+                    && batch.status == status
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout batch available for batch_id = {batch_id} and status = {status}"
+            )))?;
+
+        self.insert_process(process).await?;
+        *payout_batch = storage::PayoutBatchUpdateInternal::from(payout_batch_update)
+            .apply_changeset(payout_batch.clone());
+        Ok(payout_batch.clone())
+    }
+
+    async fn insert_payout_batch_items(
+        &self,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut items = self.payout_batch_items.lock().await;
+        items.extend(
+            payout_batch_items
+                .into_iter()
+                .map(|item| storage::PayoutBatchItem {
+                    batch_id: item.batch_id,
+                    line_number: item.line_number,
+                    customer_id: item.customer_id,
+                    amount: item.amount,
+                    currency: item.currency,
+                    payout_type: item.payout_type,
+                    payout_token: item.payout_token,
+                    description: item.description,
+                    status: item.status,
+                    payout_id: item.payout_id,
+                    payout_status: item.payout_status,
+                    error_message: item.error_message,
+                    created_at: item.created_at,
+                    modified_at: item.modified_at,
+                }),
+        );
+        Ok(())
+    }
+
+    async fn list_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut items: Vec<_> = self
+            .payout_batch_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.batch_id == batch_id)
+            .cloned()
+            .collect();
+        items.sort_by_key(|item| item.line_number);
+
+        Ok(items.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn list_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut items: Vec<_> = self
+            .payout_batch_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.batch_id == batch_id && item.status == status)
+            .cloned()
+            .collect();
+        items.sort_by_key(|item| item.line_number);
+
+        Ok(items.into_iter().take(limit).collect())
+    }
+
+    async fn update_payout_batch_item_by_batch_id_line_number(
+        &self,
+        batch_id: &str,
+        line_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let mut items = self.payout_batch_items.lock().await;
+        let item = items
+            .iter_mut()
+            .find(|item| item.batch_id == batch_id && item.line_number == line_number)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No payout batch item available for batch_id = {batch_id} and line_number = {line_number}"
+            )))?;
+
+        *item = storage::PayoutBatchItemUpdateInternal::from(payout_batch_item_update)
+            .apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+}
@@ -398,6 +398,8 @@ mod storage {
                         updated_by: new.updated_by.clone(),
                         merchant_connector_id: new.merchant_connector_id.clone(),
                         charges: new.charges.clone(),
+                        tax_reversal: None,
+                        split_refunds: new.split_refunds.clone(),
                     };
 
                     let field = format!(
@@ -859,6 +861,8 @@ impl RefundInterface for MockDb {
             updated_by: new.updated_by,
             merchant_connector_id: new.merchant_connector_id,
             charges: new.charges,
+            tax_reversal: None,
+            split_refunds: new.split_refunds,
         };
         refunds.push(refund.clone());
         Ok(refund)
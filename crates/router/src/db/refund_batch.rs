@@ -0,0 +1,371 @@
+use common_utils::id_type;
+use diesel_models::{enums, refund_batch as storage};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait RefundBatchInterface {
+    async fn insert_refund_batch(
+        &self,
+        refund_batch: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError>;
+
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError>;
+
+    async fn list_refund_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatch>, errors::StorageError>;
+
+    async fn update_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        refund_batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError>;
+
+    async fn insert_refund_batch_items(
+        &self,
+        refund_batch_items: Vec<storage::RefundBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_refund_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatchItem>, errors::StorageError>;
+
+    async fn list_refund_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::RefundBatchItemStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatchItem>, errors::StorageError>;
+
+    async fn update_refund_batch_item_by_batch_id_line_number(
+        &self,
+        batch_id: &str,
+        line_number: i32,
+        refund_batch_item_update: storage::RefundBatchItemUpdate,
+    ) -> CustomResult<storage::RefundBatchItem, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RefundBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_refund_batch(
+        &self,
+        refund_batch: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        refund_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RefundBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_refund_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RefundBatch::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        refund_batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RefundBatch::update_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            refund_batch_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_refund_batch_items(
+        &self,
+        refund_batch_items: Vec<storage::RefundBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RefundBatchItemNew::batch_insert(refund_batch_items, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_refund_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RefundBatchItem::list_by_batch_id(&conn, batch_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_refund_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::RefundBatchItemStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RefundBatchItem::list_by_batch_id_status(&conn, batch_id, status, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_refund_batch_item_by_batch_id_line_number(
+        &self,
+        batch_id: &str,
+        line_number: i32,
+        refund_batch_item_update: storage::RefundBatchItemUpdate,
+    ) -> CustomResult<storage::RefundBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RefundBatchItem::update_by_batch_id_line_number(
+            &conn,
+            batch_id,
+            line_number,
+            refund_batch_item_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RefundBatchInterface for MockDb {
+    async fn insert_refund_batch(
+        &self,
+        refund_batch: storage::RefundBatchNew,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let mut refund_batches = self.refund_batches.lock().await;
+        if refund_batches
+            .iter()
+            .any(|batch| batch.batch_id == refund_batch.batch_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "batch_id",
+                key: Some(refund_batch.batch_id.clone()),
+            })?
+        }
+        let refund_batch = storage::RefundBatch {
+            batch_id: refund_batch.batch_id,
+            merchant_id: refund_batch.merchant_id,
+            profile_id: refund_batch.profile_id,
+            status: refund_batch.status,
+            file_name: refund_batch.file_name,
+            total_count: refund_batch.total_count,
+            succeeded_count: refund_batch.succeeded_count,
+            failed_count: refund_batch.failed_count,
+            created_at: refund_batch.created_at,
+            modified_at: refund_batch.modified_at,
+        };
+        refund_batches.push(refund_batch.clone());
+        Ok(refund_batch)
+    }
+
+    async fn find_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        self.refund_batches
+            .lock()
+            .await
+            .iter()
+            .find(|batch| batch.merchant_id == *merchant_id && batch.batch_id == batch_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No refund batch available for batch_id = {batch_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_refund_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatch>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut refund_batches: Vec<_> = self
+            .refund_batches
+            .lock()
+            .await
+            .iter()
+            .filter(|batch| batch.merchant_id == *merchant_id)
+            .cloned()
+            .collect();
+        refund_batches.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(refund_batches
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
+    async fn update_refund_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+        refund_batch_update: storage::RefundBatchUpdate,
+    ) -> CustomResult<storage::RefundBatch, errors::StorageError> {
+        let mut refund_batches = self.refund_batches.lock().await;
+        let refund_batch = refund_batches
+            .iter_mut()
+            .find(|batch| batch.merchant_id == *merchant_id && batch.batch_id == batch_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No refund batch available for batch_id = {batch_id}"
+            )))?;
+
+        *refund_batch = storage::RefundBatchUpdateInternal::from(refund_batch_update)
+            .apply_changeset(refund_batch.clone());
+        Ok(refund_batch.clone())
+    }
+
+    async fn insert_refund_batch_items(
+        &self,
+        refund_batch_items: Vec<storage::RefundBatchItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut items = self.refund_batch_items.lock().await;
+        items.extend(
+            refund_batch_items
+                .into_iter()
+                .map(|item| storage::RefundBatchItem {
+                    batch_id: item.batch_id,
+                    line_number: item.line_number,
+                    payment_id: item.payment_id,
+                    amount: item.amount,
+                    reason: item.reason,
+                    status: item.status,
+                    refund_id: item.refund_id,
+                    error_message: item.error_message,
+                    created_at: item.created_at,
+                    modified_at: item.modified_at,
+                }),
+        );
+        Ok(())
+    }
+
+    async fn list_refund_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatchItem>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut items: Vec<_> = self
+            .refund_batch_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.batch_id == batch_id)
+            .cloned()
+            .collect();
+        items.sort_by_key(|item| item.line_number);
+
+        Ok(items.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn list_refund_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::RefundBatchItemStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RefundBatchItem>, errors::StorageError> {
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut items: Vec<_> = self
+            .refund_batch_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.batch_id == batch_id && item.status == status)
+            .cloned()
+            .collect();
+        items.sort_by_key(|item| item.line_number);
+
+        Ok(items.into_iter().take(limit).collect())
+    }
+
+    async fn update_refund_batch_item_by_batch_id_line_number(
+        &self,
+        batch_id: &str,
+        line_number: i32,
+        refund_batch_item_update: storage::RefundBatchItemUpdate,
+    ) -> CustomResult<storage::RefundBatchItem, errors::StorageError> {
+        let mut items = self.refund_batch_items.lock().await;
+        let item = items
+            .iter_mut()
+            .find(|item| item.batch_id == batch_id && item.line_number == line_number)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No refund batch item available for batch_id = {batch_id} and line_number = {line_number}"
+            )))?;
+
+        *item = storage::RefundBatchItemUpdateInternal::from(refund_batch_item_update)
+            .apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+}
@@ -0,0 +1,573 @@
+use common_utils::id_type;
+use diesel_models::reusable_payment_link as storage;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait ReusablePaymentLinkInterface {
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn find_reusable_payment_link_by_merchant_id_reusable_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError>;
+
+    async fn update_reusable_payment_link_by_merchant_id_reusable_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+        reusable_payment_link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn increment_reusable_payment_link_times_used(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Option<storage::ReusablePaymentLink>, errors::StorageError>;
+
+    async fn decrement_reusable_payment_link_times_used(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn insert_reusable_payment_link_items(
+        &self,
+        items: Vec<storage::ReusablePaymentLinkItemNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_reusable_payment_link_items_by_reusable_link_id(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkItem>, errors::StorageError>;
+
+    async fn increment_reusable_payment_link_item_quantity_sold(
+        &self,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> CustomResult<Option<storage::ReusablePaymentLinkItem>, errors::StorageError>;
+
+    async fn decrement_reusable_payment_link_item_quantity_sold(
+        &self,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> CustomResult<storage::ReusablePaymentLinkItem, errors::StorageError>;
+
+    async fn insert_reusable_payment_link_payment(
+        &self,
+        payment: storage::ReusablePaymentLinkPaymentNew,
+    ) -> CustomResult<storage::ReusablePaymentLinkPayment, errors::StorageError>;
+
+    async fn list_reusable_payment_link_payments_by_reusable_link_id(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkPayment>, errors::StorageError>;
+
+    async fn update_unreleased_reusable_payment_link_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        payment_update: storage::ReusablePaymentLinkPaymentUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLinkPayment, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        reusable_payment_link
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_reusable_payment_link_by_merchant_id_reusable_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::find_by_merchant_id_reusable_link_id(
+            &conn,
+            merchant_id,
+            reusable_link_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_reusable_payment_link_by_merchant_id_reusable_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+        reusable_payment_link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLink::update_by_merchant_id_reusable_link_id(
+            &conn,
+            merchant_id,
+            reusable_link_id,
+            reusable_payment_link_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn increment_reusable_payment_link_times_used(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Option<storage::ReusablePaymentLink>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLink::increment_times_used(&conn, reusable_link_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn decrement_reusable_payment_link_times_used(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLink::decrement_times_used(&conn, reusable_link_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_reusable_payment_link_items(
+        &self,
+        items: Vec<storage::ReusablePaymentLinkItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLinkItemNew::batch_insert(items, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_reusable_payment_link_items_by_reusable_link_id(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLinkItem::list_by_reusable_link_id(&conn, reusable_link_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn increment_reusable_payment_link_item_quantity_sold(
+        &self,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> CustomResult<Option<storage::ReusablePaymentLinkItem>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLinkItem::increment_quantity_sold(
+            &conn,
+            reusable_link_id,
+            item_id,
+            quantity,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn decrement_reusable_payment_link_item_quantity_sold(
+        &self,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> CustomResult<storage::ReusablePaymentLinkItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLinkItem::decrement_quantity_sold(
+            &conn,
+            reusable_link_id,
+            item_id,
+            quantity,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_reusable_payment_link_payment(
+        &self,
+        payment: storage::ReusablePaymentLinkPaymentNew,
+    ) -> CustomResult<storage::ReusablePaymentLinkPayment, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_reusable_payment_link_payments_by_reusable_link_id(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkPayment>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLinkPayment::list_by_reusable_link_id(&conn, reusable_link_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_unreleased_reusable_payment_link_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        payment_update: storage::ReusablePaymentLinkPaymentUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLinkPayment, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLinkPayment::update_unreleased_by_payment_id(
+            &conn,
+            payment_id,
+            payment_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for MockDb {
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let mut reusable_payment_links = self.reusable_payment_links.lock().await;
+        if reusable_payment_links
+            .iter()
+            .any(|link| link.reusable_link_id == reusable_payment_link.reusable_link_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "reusable_link_id",
+                key: Some(reusable_payment_link.reusable_link_id.clone()),
+            })?
+        }
+        let reusable_payment_link = storage::ReusablePaymentLink {
+            reusable_link_id: reusable_payment_link.reusable_link_id,
+            merchant_id: reusable_payment_link.merchant_id,
+            profile_id: reusable_payment_link.profile_id,
+            link_to_pay: reusable_payment_link.link_to_pay,
+            status: reusable_payment_link.status,
+            currency: reusable_payment_link.currency,
+            description: reusable_payment_link.description,
+            custom_amount: reusable_payment_link.custom_amount,
+            max_uses: reusable_payment_link.max_uses,
+            times_used: reusable_payment_link.times_used,
+            payment_link_config: reusable_payment_link.payment_link_config,
+            expires_at: reusable_payment_link.expires_at,
+            created_at: reusable_payment_link.created_at,
+            modified_at: reusable_payment_link.modified_at,
+        };
+        reusable_payment_links.push(reusable_payment_link.clone());
+        Ok(reusable_payment_link)
+    }
+
+    async fn find_reusable_payment_link_by_merchant_id_reusable_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.reusable_payment_links
+            .lock()
+            .await
+            .iter()
+            .find(|link| {
+                link.merchant_id == *merchant_id && link.reusable_link_id == reusable_link_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No reusable payment link available for reusable_link_id = {reusable_link_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        let mut reusable_payment_links: Vec<_> = self
+            .reusable_payment_links
+            .lock()
+            .await
+            .iter()
+            .filter(|link| link.merchant_id == *merchant_id)
+            .cloned()
+            .collect();
+        reusable_payment_links.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(reusable_payment_links
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
+    async fn update_reusable_payment_link_by_merchant_id_reusable_link_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        reusable_link_id: &str,
+        reusable_payment_link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let mut reusable_payment_links = self.reusable_payment_links.lock().await;
+        let reusable_payment_link = reusable_payment_links
+            .iter_mut()
+            .find(|link| {
+                link.merchant_id == *merchant_id && link.reusable_link_id == reusable_link_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No reusable payment link available for reusable_link_id = {reusable_link_id}"
+            )))?;
+
+        *reusable_payment_link =
+            storage::ReusablePaymentLinkUpdateInternal::from(reusable_payment_link_update)
+                .apply_changeset(reusable_payment_link.clone());
+        Ok(reusable_payment_link.clone())
+    }
+
+    async fn increment_reusable_payment_link_times_used(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Option<storage::ReusablePaymentLink>, errors::StorageError> {
+        Ok(self
+            .reusable_payment_links
+            .lock()
+            .await
+            .iter_mut()
+            .find(|link| {
+                link.reusable_link_id == reusable_link_id
+                    && link
+                        .max_uses
+                        .map_or(true, |max_uses| link.times_used < max_uses)
+            })
+            .map(|link| {
+                link.times_used += 1;
+                link.modified_at = common_utils::date_time::now();
+                link.clone()
+            }))
+    }
+
+    async fn decrement_reusable_payment_link_times_used(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let mut reusable_payment_links = self.reusable_payment_links.lock().await;
+        let reusable_payment_link = reusable_payment_links
+            .iter_mut()
+            .find(|link| link.reusable_link_id == reusable_link_id && link.times_used > 0)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No used reusable payment link available for reusable_link_id = {reusable_link_id}"
+            )))?;
+
+        reusable_payment_link.times_used -= 1;
+        reusable_payment_link.modified_at = common_utils::date_time::now();
+        Ok(reusable_payment_link.clone())
+    }
+
+    async fn insert_reusable_payment_link_items(
+        &self,
+        items: Vec<storage::ReusablePaymentLinkItemNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.reusable_payment_link_items
+            .lock()
+            .await
+            .extend(
+                items
+                    .into_iter()
+                    .map(|item| storage::ReusablePaymentLinkItem {
+                        reusable_link_id: item.reusable_link_id,
+                        item_id: item.item_id,
+                        name: item.name,
+                        description: item.description,
+                        image_url: item.image_url,
+                        unit_amount: item.unit_amount,
+                        quantity: item.quantity,
+                        min_quantity: item.min_quantity,
+                        max_quantity: item.max_quantity,
+                        inventory: item.inventory,
+                        quantity_sold: item.quantity_sold,
+                        created_at: item.created_at,
+                    }),
+            );
+        Ok(())
+    }
+
+    async fn list_reusable_payment_link_items_by_reusable_link_id(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkItem>, errors::StorageError> {
+        let mut items: Vec<_> = self
+            .reusable_payment_link_items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.reusable_link_id == reusable_link_id)
+            .cloned()
+            .collect();
+        items.sort_by_key(|item| item.created_at);
+        Ok(items)
+    }
+
+    async fn increment_reusable_payment_link_item_quantity_sold(
+        &self,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> CustomResult<Option<storage::ReusablePaymentLinkItem>, errors::StorageError> {
+        Ok(self
+            .reusable_payment_link_items
+            .lock()
+            .await
+            .iter_mut()
+            .find(|item| {
+                item.reusable_link_id == reusable_link_id
+                    && item.item_id == item_id
+                    && item
+                        .inventory
+                        .map_or(true, |inventory| item.quantity_sold + quantity <= inventory)
+            })
+            .map(|item| {
+                item.quantity_sold += quantity;
+                item.clone()
+            }))
+    }
+
+    async fn decrement_reusable_payment_link_item_quantity_sold(
+        &self,
+        reusable_link_id: &str,
+        item_id: &str,
+        quantity: i64,
+    ) -> CustomResult<storage::ReusablePaymentLinkItem, errors::StorageError> {
+        let mut items = self.reusable_payment_link_items.lock().await;
+        let item = items
+            .iter_mut()
+            .find(|item| {
+                item.reusable_link_id == reusable_link_id
+                    && item.item_id == item_id
+                    && item.quantity_sold >= quantity
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No sold reusable payment link item available for item_id = {item_id}"
+            )))?;
+
+        item.quantity_sold -= quantity;
+        Ok(item.clone())
+    }
+
+    async fn insert_reusable_payment_link_payment(
+        &self,
+        payment: storage::ReusablePaymentLinkPaymentNew,
+    ) -> CustomResult<storage::ReusablePaymentLinkPayment, errors::StorageError> {
+        let mut payments = self.reusable_payment_link_payments.lock().await;
+        if payments
+            .iter()
+            .any(|existing| existing.payment_id == payment.payment_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payment_id",
+                key: Some(payment.payment_id.clone()),
+            })?
+        }
+        let payment = storage::ReusablePaymentLinkPayment {
+            payment_id: payment.payment_id,
+            reusable_link_id: payment.reusable_link_id,
+            merchant_id: payment.merchant_id,
+            amount: payment.amount,
+            currency: payment.currency,
+            item_quantities: payment.item_quantities,
+            status: payment.status,
+            released: payment.released,
+            created_at: payment.created_at,
+            modified_at: payment.modified_at,
+        };
+        payments.push(payment.clone());
+        Ok(payment)
+    }
+
+    async fn list_reusable_payment_link_payments_by_reusable_link_id(
+        &self,
+        reusable_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkPayment>, errors::StorageError> {
+        let mut payments: Vec<_> = self
+            .reusable_payment_link_payments
+            .lock()
+            .await
+            .iter()
+            .filter(|payment| payment.reusable_link_id == reusable_link_id)
+            .cloned()
+            .collect();
+        payments.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(payments)
+    }
+
+    async fn update_unreleased_reusable_payment_link_payment_by_payment_id(
+        &self,
+        payment_id: &str,
+        payment_update: storage::ReusablePaymentLinkPaymentUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLinkPayment, errors::StorageError> {
+        let mut payments = self.reusable_payment_link_payments.lock().await;
+        let payment = payments
+            .iter_mut()
+            .find(|payment| payment.payment_id == payment_id && !payment.released)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No unreleased reusable payment link payment available for payment_id = {payment_id}"
+            )))?;
+
+        *payment = storage::ReusablePaymentLinkPaymentUpdateInternal::from(payment_update)
+            .apply_changeset(payment.clone());
+        Ok(payment.clone())
+    }
+}
@@ -149,6 +149,8 @@ impl RoleInterface for MockDb {
             created_at: role.created_at,
             last_modified_at: role.last_modified_at,
             last_modified_by: role.last_modified_by,
+            permissions: role.permissions,
+            profile_ids: role.profile_ids,
         };
         roles.push(role.clone());
         Ok(role)
@@ -209,11 +211,15 @@ impl RoleInterface for MockDb {
                     storage::RoleUpdate::UpdateDetails {
                         groups,
                         role_name,
+                        permissions,
+                        profile_ids,
                         last_modified_at,
                         last_modified_by,
                     } => storage::Role {
                         groups: groups.unwrap_or(role.groups.to_owned()),
                         role_name: role_name.unwrap_or(role.role_name.to_owned()),
+                        permissions: permissions.unwrap_or(role.permissions.to_owned()),
+                        profile_ids: profile_ids.unwrap_or(role.profile_ids.to_owned()),
                         last_modified_by,
                         last_modified_at,
                         ..role.to_owned()
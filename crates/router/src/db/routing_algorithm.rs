@@ -1,5 +1,5 @@
 use diesel_models::routing_algorithm as routing_storage;
-use error_stack::report;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 use storage_impl::mock_db::MockDb;
 
@@ -178,60 +178,158 @@ impl RoutingAlgorithmInterface for Store {
 impl RoutingAlgorithmInterface for MockDb {
     async fn insert_routing_algorithm(
         &self,
-        _routing_algorithm: routing_storage::RoutingAlgorithm,
+        routing_algorithm: routing_storage::RoutingAlgorithm,
     ) -> StorageResult<routing_storage::RoutingAlgorithm> {
-        Err(errors::StorageError::MockDbError)?
+        let mut algorithms = self.routing_algorithms.lock().await;
+        if algorithms
+            .iter()
+            .any(|algorithm| algorithm.algorithm_id == routing_algorithm.algorithm_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "algorithm_id",
+                key: Some(routing_algorithm.algorithm_id.clone()),
+            })?
+        }
+        algorithms.push(routing_algorithm.clone());
+
+        Ok(routing_algorithm)
     }
 
     async fn find_routing_algorithm_by_profile_id_algorithm_id(
         &self,
-        _profile_id: &str,
-        _algorithm_id: &str,
+        profile_id: &str,
+        algorithm_id: &str,
     ) -> StorageResult<routing_storage::RoutingAlgorithm> {
-        Err(errors::StorageError::MockDbError)?
+        find_mock_routing_algorithm(self, |algorithm| {
+            algorithm.algorithm_id == algorithm_id && algorithm.profile_id == profile_id
+        })
+        .await
     }
 
     async fn find_routing_algorithm_by_algorithm_id_merchant_id(
         &self,
-        _algorithm_id: &str,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        algorithm_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> StorageResult<routing_storage::RoutingAlgorithm> {
-        Err(errors::StorageError::MockDbError)?
+        find_mock_routing_algorithm(self, |algorithm| {
+            algorithm.algorithm_id == algorithm_id && algorithm.merchant_id == *merchant_id
+        })
+        .await
     }
 
     async fn find_routing_algorithm_metadata_by_algorithm_id_profile_id(
         &self,
-        _algorithm_id: &str,
-        _profile_id: &str,
+        algorithm_id: &str,
+        profile_id: &str,
     ) -> StorageResult<routing_storage::RoutingProfileMetadata> {
-        Err(errors::StorageError::MockDbError)?
+        self.find_routing_algorithm_by_profile_id_algorithm_id(profile_id, algorithm_id)
+            .await
+            .map(routing_profile_metadata)
     }
 
     async fn list_routing_algorithm_metadata_by_profile_id(
         &self,
-        _profile_id: &str,
-        _limit: i64,
-        _offset: i64,
+        profile_id: &str,
+        limit: i64,
+        offset: i64,
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
-        Err(errors::StorageError::MockDbError)?
+        list_mock_routing_algorithm_metadata(
+            self,
+            |algorithm| algorithm.profile_id == profile_id,
+            limit,
+            offset,
+        )
+        .await
     }
 
     async fn list_routing_algorithm_metadata_by_merchant_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _limit: i64,
-        _offset: i64,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
-        Err(errors::StorageError::MockDbError)?
+        list_mock_routing_algorithm_metadata(
+            self,
+            |algorithm| algorithm.merchant_id == *merchant_id,
+            limit,
+            offset,
+        )
+        .await
     }
 
     async fn list_routing_algorithm_metadata_by_merchant_id_transaction_type(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _transaction_type: &common_enums::TransactionType,
-        _limit: i64,
-        _offset: i64,
+        merchant_id: &common_utils::id_type::MerchantId,
+        transaction_type: &common_enums::TransactionType,
+        limit: i64,
+        offset: i64,
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
-        Err(errors::StorageError::MockDbError)?
+        list_mock_routing_algorithm_metadata(
+            self,
+            |algorithm| {
+                algorithm.merchant_id == *merchant_id
+                    && algorithm.algorithm_for == *transaction_type
+            },
+            limit,
+            offset,
+        )
+        .await
+    }
+}
+
+async fn find_mock_routing_algorithm(
+    db: &MockDb,
+    predicate: impl Fn(&routing_storage::RoutingAlgorithm) -> bool,
+) -> StorageResult<routing_storage::RoutingAlgorithm> {
+    db.routing_algorithms
+        .lock()
+        .await
+        .iter()
+        .find(|algorithm| predicate(algorithm))
+        .cloned()
+        .ok_or(
+            errors::StorageError::ValueNotFound("Routing algorithm not found".to_string()).into(),
+        )
+}
+
+async fn list_mock_routing_algorithm_metadata(
+    db: &MockDb,
+    predicate: impl Fn(&routing_storage::RoutingAlgorithm) -> bool,
+    limit: i64,
+    offset: i64,
+) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
+    let limit = usize::try_from(limit).change_context(errors::StorageError::MockDbError)?;
+    let offset = usize::try_from(offset).change_context(errors::StorageError::MockDbError)?;
+
+    let mut algorithms = db
+        .routing_algorithms
+        .lock()
+        .await
+        .iter()
+        .filter(|algorithm| predicate(algorithm))
+        .cloned()
+        .collect::<Vec<_>>();
+    algorithms.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+
+    Ok(algorithms
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(routing_profile_metadata)
+        .collect())
+}
+
+fn routing_profile_metadata(
+    algorithm: routing_storage::RoutingAlgorithm,
+) -> routing_storage::RoutingProfileMetadata {
+    routing_storage::RoutingProfileMetadata {
+        profile_id: algorithm.profile_id,
+        algorithm_id: algorithm.algorithm_id,
+        name: algorithm.name,
+        description: algorithm.description,
+        kind: algorithm.kind,
+        created_at: algorithm.created_at,
+        modified_at: algorithm.modified_at,
+        algorithm_for: algorithm.algorithm_for,
     }
 }
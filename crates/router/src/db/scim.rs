@@ -0,0 +1,261 @@
+use common_utils::id_type;
+use diesel_models::scim as storage;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait ScimUserInterface {
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn find_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn list_scim_users_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError>;
+
+    async fn list_scim_users_by_merchant_id_role_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        role_id: &str,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError>;
+
+    async fn update_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+        scim_user_update: storage::ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn delete_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ScimUserInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        scim_user
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimUser::find_by_merchant_id_user_id(&conn, merchant_id, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_scim_users_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimUser::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_scim_users_by_merchant_id_role_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        role_id: &str,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimUser::list_by_merchant_id_role_id(&conn, merchant_id, role_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+        scim_user_update: storage::ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimUser::update_by_merchant_id_user_id(
+            &conn,
+            merchant_id,
+            user_id,
+            scim_user_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimUser::delete_by_merchant_id_user_id(&conn, merchant_id, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ScimUserInterface for MockDb {
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let mut scim_users = self.scim_users.lock().await;
+        if scim_users.iter().any(|user| {
+            user.merchant_id == scim_user.merchant_id && user.user_id == scim_user.user_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "scim_user",
+                key: Some(scim_user.user_id.clone()),
+            })?
+        }
+        let scim_user = storage::ScimUser {
+            merchant_id: scim_user.merchant_id,
+            user_id: scim_user.user_id,
+            org_id: scim_user.org_id,
+            external_id: scim_user.external_id,
+            role_id: scim_user.role_id,
+            active: scim_user.active,
+            created_at: scim_user.created_at,
+            last_modified_at: scim_user.last_modified_at,
+        };
+        scim_users.push(scim_user.clone());
+        Ok(scim_user)
+    }
+
+    async fn find_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        self.scim_users
+            .lock()
+            .await
+            .iter()
+            .find(|user| user.merchant_id == *merchant_id && user.user_id == user_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No scim user available for merchant_id = {merchant_id:?} and user_id = {user_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_scim_users_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        Ok(self
+            .scim_users
+            .lock()
+            .await
+            .iter()
+            .filter(|user| user.merchant_id == *merchant_id)
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_scim_users_by_merchant_id_role_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        role_id: &str,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        Ok(self
+            .scim_users
+            .lock()
+            .await
+            .iter()
+            .filter(|user| user.merchant_id == *merchant_id && user.role_id == role_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+        scim_user_update: storage::ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        self.scim_users
+            .lock()
+            .await
+            .iter_mut()
+            .find(|user| user.merchant_id == *merchant_id && user.user_id == user_id)
+            .map(|user| {
+                *user = storage::ScimUserUpdateInternal::from(scim_user_update)
+                    .apply_changeset(user.to_owned());
+                user.to_owned()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No scim user available for merchant_id = {merchant_id:?} and user_id = {user_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_scim_user_by_merchant_id_user_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut scim_users = self.scim_users.lock().await;
+        let initial_len = scim_users.len();
+        scim_users.retain(|user| !(user.merchant_id == *merchant_id && user.user_id == user_id));
+        if scim_users.len() == initial_len {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No scim user available for merchant_id = {merchant_id:?} and user_id = {user_id}"
+            )))?
+        }
+        Ok(true)
+    }
+}
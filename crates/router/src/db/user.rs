@@ -330,8 +330,13 @@ impl UserInterface for MockDb {
 
     async fn find_users_by_user_ids(
         &self,
-        _user_ids: Vec<String>,
+        user_ids: Vec<String>,
     ) -> CustomResult<Vec<storage::User>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let users = self.users.lock().await;
+        Ok(users
+            .iter()
+            .filter(|user| user_ids.contains(&user.user_id))
+            .cloned()
+            .collect())
     }
 }
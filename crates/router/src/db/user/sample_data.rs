@@ -1,4 +1,5 @@
-use common_utils::types::keymanager::KeyManagerState;
+use common_enums::MerchantStorageScheme;
+use common_utils::{errors::ReportSwitchExt, types::keymanager::KeyManagerState};
 use diesel_models::{
     errors::DatabaseError,
     query::user::sample_data as sample_data_queries,
@@ -11,11 +12,17 @@ use hyperswitch_domain_models::{
     behaviour::Conversion,
     errors::StorageError,
     merchant_key_store::MerchantKeyStore,
-    payments::{payment_attempt::PaymentAttempt, PaymentIntent},
+    payments::{
+        payment_attempt::{PaymentAttempt, PaymentAttemptInterface, PaymentAttemptNew},
+        PaymentIntent,
+    },
 };
 use storage_impl::DataModelExt;
 
-use crate::{connection::pg_connection_write, core::errors::CustomResult, services::Store};
+use crate::{
+    connection::pg_connection_write, core::errors::CustomResult, db::refund::RefundInterface,
+    services::Store,
+};
 
 #[async_trait::async_trait]
 pub trait BatchSampleDataInterface {
@@ -177,46 +184,111 @@ impl BatchSampleDataInterface for storage_impl::MockDb {
     async fn insert_payment_intents_batch_for_sample_data(
         &self,
         _state: &KeyManagerState,
-        _batch: Vec<PaymentIntent>,
+        batch: Vec<PaymentIntent>,
         _key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        Err(StorageError::MockDbError)?
+        self.payment_intents.lock().await.extend(batch.clone());
+        Ok(batch)
     }
 
     async fn insert_payment_attempts_batch_for_sample_data(
         &self,
-        _batch: Vec<PaymentAttemptBatchNew>,
+        batch: Vec<PaymentAttemptBatchNew>,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut payment_attempts = Vec::with_capacity(batch.len());
+        for payment_attempt in batch {
+            payment_attempts.push(
+                self.insert_payment_attempt(
+                    PaymentAttemptNew::from_storage_model(
+                        payment_attempt.convert_into_normal_attempt_insert(),
+                    ),
+                    MerchantStorageScheme::PostgresOnly,
+                )
+                .await?,
+            );
+        }
+        Ok(payment_attempts)
     }
 
     async fn insert_refunds_batch_for_sample_data(
         &self,
-        _batch: Vec<RefundNew>,
+        batch: Vec<RefundNew>,
     ) -> CustomResult<Vec<Refund>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut refunds = Vec::with_capacity(batch.len());
+        for refund in batch {
+            refunds.push(
+                self.insert_refund(refund, MerchantStorageScheme::PostgresOnly)
+                    .await
+                    .switch()?,
+            );
+        }
+        Ok(refunds)
     }
 
     async fn delete_payment_intents_for_sample_data(
         &self,
         _state: &KeyManagerState,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
         _key_store: &MerchantKeyStore,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut payment_intents = self.payment_intents.lock().await;
+        let (deleted, retained) = payment_intents.drain(..).partition(|payment_intent| {
+            is_sample_data(
+                &payment_intent.merchant_id,
+                &payment_intent.payment_id,
+                merchant_id,
+            )
+        });
+        *payment_intents = retained;
+        non_empty_deletion(deleted)
     }
+
     async fn delete_payment_attempts_for_sample_data(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut payment_attempts = self.payment_attempts.lock().await;
+        let (deleted, retained) = payment_attempts.drain(..).partition(|payment_attempt| {
+            is_sample_data(
+                &payment_attempt.merchant_id,
+                &payment_attempt.payment_id,
+                merchant_id,
+            )
+        });
+        *payment_attempts = retained;
+        non_empty_deletion(deleted)
     }
+
     async fn delete_refunds_for_sample_data(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        merchant_id: &common_utils::id_type::MerchantId,
     ) -> CustomResult<Vec<Refund>, StorageError> {
-        Err(StorageError::MockDbError)?
+        let mut refunds = self.refunds.lock().await;
+        let (deleted, retained) = refunds.drain(..).partition(|refund| {
+            is_sample_data(&refund.merchant_id, &refund.payment_id, merchant_id)
+        });
+        *refunds = retained;
+        non_empty_deletion(deleted)
+    }
+}
+
+/// Sample data is identified the same way as in the database queries, by the `test_` prefix on
+/// the payment id.
+fn is_sample_data(
+    record_merchant_id: &common_utils::id_type::MerchantId,
+    payment_id: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> bool {
+    record_merchant_id == merchant_id && payment_id.starts_with("test_")
+}
+
+fn non_empty_deletion<T>(deleted: Vec<T>) -> CustomResult<Vec<T>, StorageError> {
+    if deleted.is_empty() {
+        Err(StorageError::ValueNotFound(
+            "No records deleted".to_string(),
+        ))?
     }
+    Ok(deleted)
 }
 
 // TODO: This error conversion is re-used from storage_impl and is not DRY when it should be
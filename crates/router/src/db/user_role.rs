@@ -61,6 +61,12 @@ pub trait UserRoleInterface {
         version: enums::UserRoleVersion,
     ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError>;
 
+    async fn list_user_roles_by_role_id_org_id(
+        &self,
+        role_id: &str,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError>;
+
     async fn find_user_role_by_user_id_and_lineage(
         &self,
         user_id: &str,
@@ -196,6 +202,18 @@ impl UserRoleInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn list_user_roles_by_role_id_org_id(
+        &self,
+        role_id: &str,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::list_by_role_id_org_id(&conn, role_id.to_owned(), org_id.to_owned())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn find_user_role_by_user_id_and_lineage(
         &self,
@@ -645,6 +663,22 @@ impl UserRoleInterface for MockDb {
         Ok(filtered_roles)
     }
 
+    async fn list_user_roles_by_role_id_org_id(
+        &self,
+        role_id: &str,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let user_roles = self.user_roles.lock().await;
+
+        Ok(user_roles
+            .iter()
+            .filter(|user_role| {
+                user_role.role_id == role_id && user_role.org_id.as_ref() == Some(org_id)
+            })
+            .cloned()
+            .collect())
+    }
+
     async fn find_user_role_by_user_id_and_lineage(
         &self,
         user_id: &str,
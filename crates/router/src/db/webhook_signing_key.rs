@@ -0,0 +1,288 @@
+use async_bb8_diesel::AsyncConnection;
+use diesel_models::{enums, webhook_signing_key as storage};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookSigningKeyInterface {
+    async fn insert_webhook_signing_key(
+        &self,
+        signing_key: storage::WebhookSigningKeyNew,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError>;
+
+    async fn find_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError>;
+
+    async fn find_active_webhook_signing_key_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Option<storage::WebhookSigningKey>, errors::StorageError>;
+
+    async fn list_webhook_signing_keys_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookSigningKey>, errors::StorageError>;
+
+    async fn update_webhook_signing_key_by_key_id(
+        &self,
+        key_id: &str,
+        signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError>;
+
+    /// Rotates the active signing key of the profile of the new key, if any, to expire at the
+    /// specified time and inserts the new key as the active one in one transaction
+    async fn rotate_and_insert_webhook_signing_key(
+        &self,
+        signing_key: storage::WebhookSigningKeyNew,
+        rotated_key_expires_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookSigningKeyInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_signing_key(
+        &self,
+        signing_key: storage::WebhookSigningKeyNew,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        signing_key
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookSigningKey::find_by_profile_id_key_id(&conn, profile_id, key_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_active_webhook_signing_key_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Option<storage::WebhookSigningKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookSigningKey::find_optional_active_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_signing_keys_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookSigningKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookSigningKey::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_signing_key_by_key_id(
+        &self,
+        key_id: &str,
+        signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookSigningKey::update_by_key_id(&conn, key_id, signing_key_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn rotate_and_insert_webhook_signing_key(
+        &self,
+        signing_key: storage::WebhookSigningKeyNew,
+        rotated_key_expires_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        conn.transaction_async(|conn| async move {
+            if let Some(active_key) =
+                storage::WebhookSigningKey::find_optional_active_by_profile_id(
+                    &conn,
+                    &signing_key.profile_id,
+                )
+                .await
+                .map_err(|e| *e.current_context())?
+            {
+                storage::WebhookSigningKey::update_by_key_id(
+                    &conn,
+                    &active_key.key_id,
+                    storage::WebhookSigningKeyUpdate::Rotated {
+                        expires_at: rotated_key_expires_at,
+                    },
+                )
+                .await
+                .map_err(|e| *e.current_context())?;
+            }
+            let signing_key = signing_key
+                .insert(&conn)
+                .await
+                .map_err(|e| *e.current_context())?;
+            Ok::<_, diesel_models::errors::DatabaseError>(signing_key)
+        })
+        .await
+        .map_err(|error| report!(errors::StorageError::from(report!(error))))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSigningKeyInterface for MockDb {
+    async fn insert_webhook_signing_key(
+        &self,
+        signing_key: storage::WebhookSigningKeyNew,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let mut signing_keys = self.webhook_signing_keys.lock().await;
+        if signing_key.status == enums::WebhookSigningKeyStatus::Active
+            && signing_keys.iter().any(|existing| {
+                existing.profile_id == signing_key.profile_id
+                    && existing.status == enums::WebhookSigningKeyStatus::Active
+            })
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "webhook_signing_key",
+                key: Some(signing_key.profile_id.clone()),
+            })?
+        }
+        let signing_key = storage::WebhookSigningKey {
+            key_id: signing_key.key_id,
+            merchant_id: signing_key.merchant_id,
+            profile_id: signing_key.profile_id,
+            algorithm: signing_key.algorithm,
+            status: signing_key.status,
+            private_key: signing_key.private_key,
+            public_key: signing_key.public_key,
+            created_at: signing_key.created_at,
+            modified_at: signing_key.modified_at,
+            expires_at: signing_key.expires_at,
+        };
+        signing_keys.push(signing_key.clone());
+        Ok(signing_key)
+    }
+
+    async fn find_webhook_signing_key_by_profile_id_key_id(
+        &self,
+        profile_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        self.webhook_signing_keys
+            .lock()
+            .await
+            .iter()
+            .find(|signing_key| {
+                signing_key.profile_id == profile_id && signing_key.key_id == key_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No webhook signing key available for key_id = {key_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn find_active_webhook_signing_key_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Option<storage::WebhookSigningKey>, errors::StorageError> {
+        Ok(self
+            .webhook_signing_keys
+            .lock()
+            .await
+            .iter()
+            .find(|signing_key| {
+                signing_key.profile_id == profile_id
+                    && signing_key.status == enums::WebhookSigningKeyStatus::Active
+            })
+            .cloned())
+    }
+
+    async fn list_webhook_signing_keys_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookSigningKey>, errors::StorageError> {
+        let mut signing_keys: Vec<_> = self
+            .webhook_signing_keys
+            .lock()
+            .await
+            .iter()
+            .filter(|signing_key| signing_key.profile_id == profile_id)
+            .cloned()
+            .collect();
+        signing_keys.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(signing_keys)
+    }
+
+    async fn update_webhook_signing_key_by_key_id(
+        &self,
+        key_id: &str,
+        signing_key_update: storage::WebhookSigningKeyUpdate,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let mut signing_keys = self.webhook_signing_keys.lock().await;
+        let signing_key = signing_keys
+            .iter_mut()
+            .find(|signing_key| signing_key.key_id == key_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook signing key available for key_id = {key_id}"
+            )))?;
+
+        *signing_key = storage::WebhookSigningKeyUpdateInternal::from(signing_key_update)
+            .apply_changeset(signing_key.clone());
+        Ok(signing_key.clone())
+    }
+
+    async fn rotate_and_insert_webhook_signing_key(
+        &self,
+        signing_key: storage::WebhookSigningKeyNew,
+        rotated_key_expires_at: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::WebhookSigningKey, errors::StorageError> {
+        let mut signing_keys = self.webhook_signing_keys.lock().await;
+        if let Some(active_key) = signing_keys.iter_mut().find(|existing| {
+            existing.profile_id == signing_key.profile_id
+                && existing.status == enums::WebhookSigningKeyStatus::Active
+        }) {
+            *active_key = storage::WebhookSigningKeyUpdateInternal::from(
+                storage::WebhookSigningKeyUpdate::Rotated {
+                    expires_at: rotated_key_expires_at,
+                },
+            )
+            .apply_changeset(active_key.clone());
+        }
+        let signing_key = storage::WebhookSigningKey {
+            key_id: signing_key.key_id,
+            merchant_id: signing_key.merchant_id,
+            profile_id: signing_key.profile_id,
+            algorithm: signing_key.algorithm,
+            status: signing_key.status,
+            private_key: signing_key.private_key,
+            public_key: signing_key.public_key,
+            created_at: signing_key.created_at,
+            modified_at: signing_key.modified_at,
+            expires_at: signing_key.expires_at,
+        };
+        signing_keys.push(signing_key.clone());
+        Ok(signing_key)
+    }
+}
@@ -11,7 +11,9 @@ use crate::routes::dummy_connector::types::{
     DummyConnectorPaymentCompleteRequest, DummyConnectorPaymentConfirmRequest,
     DummyConnectorPaymentRequest, DummyConnectorPaymentResponse,
     DummyConnectorPaymentRetrieveRequest, DummyConnectorRefundRequest,
-    DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
+    DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest, DummyConnectorScenario,
+    DummyConnectorScenarioDeleteResponse, DummyConnectorScenarioRequest,
+    DummyConnectorScenarioRetrieveRequest,
 };
 use crate::{
     core::payments::PaymentsRedirectResponseData,
@@ -120,7 +122,11 @@ impl_api_event_type!(
         DummyConnectorPaymentConfirmRequest,
         DummyConnectorRefundRetrieveRequest,
         DummyConnectorRefundResponse,
-        DummyConnectorRefundRequest
+        DummyConnectorRefundRequest,
+        DummyConnectorScenarioRequest,
+        DummyConnectorScenario,
+        DummyConnectorScenarioRetrieveRequest,
+        DummyConnectorScenarioDeleteResponse
     )
 );
 
@@ -50,6 +50,10 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    Invoice {
+        invoice_id: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -87,6 +91,11 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::InvoiceDetails(invoice_payload) => Some(OutgoingWebhookEventContent::Invoice {
+                invoice_id: invoice_payload.invoice_id.clone(),
+                content: masking::masked_serialize(&invoice_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
         }
     }
 }
@@ -73,6 +73,9 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_ASYMMETRIC_SIGNATURE: &str = "X-Webhook-Signature-Asymmetric";
+    pub const X_WEBHOOK_KEY_ID: &str = "X-Webhook-Key-Id";
+    pub const X_WEBHOOK_TIMESTAMP: &str = "X-Webhook-Timestamp";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -156,12 +159,17 @@ pub fn mk_app(
             .service(routes::Routing::server(state.clone()))
             .service(routes::Blocklist::server(state.clone()))
             .service(routes::Gsm::server(state.clone()))
+            .service(routes::DataRetention::server(state.clone()))
             .service(routes::ApplePayCertificatesMigration::server(state.clone()))
             .service(routes::PaymentLink::server(state.clone()))
+            .service(routes::Invoices::server(state.clone()))
+            .service(routes::Ledger::server(state.clone()))
             .service(routes::User::server(state.clone()))
+            .service(routes::Scim::server(state.clone()))
             .service(routes::ConnectorOnboarding::server(state.clone()))
             .service(routes::Verify::server(state.clone()))
-            .service(routes::WebhookEvents::server(state.clone()));
+            .service(routes::WebhookEvents::server(state.clone()))
+            .service(routes::WebhookSigningKeys::server(state.clone()));
     }
 
     #[cfg(feature = "payouts")]
@@ -185,6 +193,11 @@ pub fn mk_app(
         server_app = server_app.service(routes::Recon::server(state.clone()));
     }
 
+    #[cfg(all(feature = "frm", feature = "oltp"))]
+    {
+        server_app = server_app.service(routes::Frm::server(state.clone()));
+    }
+
     server_app = server_app.service(routes::Cards::server(state.clone()));
     server_app = server_app.service(routes::Cache::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state.clone()));
@@ -216,6 +229,8 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
         })?,
     );
     let state = Box::pin(AppState::new(conf, tx, api_client)).await;
+    #[cfg(unix)]
+    state.reload_secrets_on_sighup()?;
     let request_body_limit = server.request_body_limit;
 
     let server_builder =
@@ -12,6 +12,8 @@ pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(feature = "olap")]
+pub mod data_retention;
 pub mod disputes;
 #[cfg(feature = "dummy_connector")]
 pub mod dummy_connector;
@@ -21,6 +23,10 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health;
+#[cfg(feature = "olap")]
+pub mod invoices;
+#[cfg(feature = "olap")]
+pub mod ledger;
 pub mod lock_utils;
 pub mod locker_migration;
 pub mod mandates;
@@ -41,6 +47,8 @@ pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
 #[cfg(feature = "olap")]
+pub mod scim;
+#[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
 pub mod user_role;
@@ -50,12 +58,16 @@ pub mod verification;
 pub mod verify_connector;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
+#[cfg(feature = "olap")]
+pub mod webhook_signing_key;
 pub mod webhooks;
 
 #[cfg(feature = "dummy_connector")]
 pub use self::app::DummyConnector;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub use self::app::Forex;
+#[cfg(all(feature = "frm", feature = "oltp"))]
+pub use self::app::Frm;
 #[cfg(all(feature = "olap", feature = "recon"))]
 pub use self::app::Recon;
 pub use self::app::{
@@ -65,7 +77,10 @@ pub use self::app::{
     Refunds, SessionState, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    Blocklist, DataRetention, Invoices, Ledger, Organization, Routing, Scim, Verify, WebhookEvents,
+    WebhookSigningKeys,
+};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(all(
@@ -6,6 +6,8 @@ use hyperswitch_domain_models::errors::api_error_response::ApiErrorResponse;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "olap")]
+use crate::core::merchant_key_rotation;
 #[cfg(all(feature = "v2", feature = "merchant_connector_account_v2"))]
 use crate::headers;
 use crate::{
@@ -817,6 +819,87 @@ pub async fn merchant_account_toggle_all_kv(
     .await
 }
 
+/// Merchant Account - Start Key Rotation
+///
+/// Replaces the encryption key of the merchant and re-encrypts its data in the background
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotationStart))]
+pub async fn merchant_key_rotation_start(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotationStart;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| {
+            merchant_key_rotation::start_merchant_key_rotation(state, merchant_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - List Key Rotations
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotationList))]
+pub async fn merchant_key_rotation_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotationList;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| {
+            merchant_key_rotation::list_merchant_key_rotations(state, merchant_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Retrieve Key Rotation
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotationRetrieve))]
+pub async fn merchant_key_rotation_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotationRetrieve;
+    let (merchant_id, rotation_id) = path.into_inner();
+    let payload = admin::MerchantKeyRotationId {
+        merchant_id,
+        rotation_id,
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            merchant_key_rotation::retrieve_merchant_key_rotation(state, payload)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::BusinessProfileCreate))]
 pub async fn business_profile_create(
     state: web::Data<AppState>,
@@ -18,7 +18,7 @@ use hyperswitch_interfaces::{
     encryption_interface::EncryptionManagementInterface,
     secrets_interface::secret_state::{RawSecret, SecuredSecret},
 };
-use router_env::tracing_actix_web::RequestId;
+use router_env::{logger, tracing_actix_web::RequestId};
 use scheduler::SchedulerInterface;
 use storage_impl::{config::TenantConfig, redis::RedisStore, MockDb};
 use tokio::sync::oneshot;
@@ -45,15 +45,15 @@ use super::pm_auth;
 #[cfg(feature = "oltp")]
 use super::poll::retrieve_poll_status;
 #[cfg(feature = "olap")]
-use super::routing;
-#[cfg(feature = "olap")]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
 use super::{
     admin::*, api_keys::*, apple_pay_certificates_migration, connector_onboarding::*, disputes::*,
-    files::*, gsm::*, payment_link::*, user::*, user_role::*, webhook_events::*,
+    files::*, gsm::*, payment_link::*, scim::*, user::*, user_role::*, webhook_events::*,
 };
 use super::{cache::*, health::*};
+#[cfg(feature = "olap")]
+use super::{cards_info, data_retention, invoices, ledger, routing, webhook_signing_key};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*};
 #[cfg(feature = "oltp")]
@@ -103,6 +103,8 @@ pub struct SessionState {
     pub tenant: Tenant,
     #[cfg(feature = "olap")]
     pub opensearch_client: Arc<OpenSearchClient>,
+    /// Previous keys of the merchant key stores loaded while a key rotation is in progress
+    pub previous_merchant_keys: common_utils::types::keymanager::PreviousKeys,
 }
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
@@ -188,7 +190,12 @@ pub struct AppState {
     pub flow_name: String,
     pub global_store: Box<dyn GlobalStorageInterface>,
     pub stores: HashMap<String, Box<dyn StorageInterface>>,
+    /// Settings the application was started with, used to set up the stores and clients
     pub conf: Arc<settings::Settings<RawSecret>>,
+    /// Settings with the current values of the secrets, used by the session states
+    pub reloadable_conf: settings::ReloadableSettings,
+    /// Settings before their secrets are resolved, used to resolve the secrets again on reload
+    pub secured_conf: Arc<settings::Settings<SecuredSecret>>,
     pub event_handler: EventsHandler,
     #[cfg(feature = "email")]
     pub email_client: Arc<dyn EmailService>,
@@ -224,7 +231,7 @@ static CHECKSUM_KEY: once_cell::sync::OnceCell<(
 
 impl AppStateInfo for AppState {
     fn conf(&self) -> settings::Settings<RawSecret> {
-        self.conf.as_ref().to_owned()
+        self.reloadable_conf.load().as_ref().to_owned()
     }
     #[cfg(feature = "email")]
     fn email_client(&self) -> Arc<dyn EmailService> {
@@ -278,11 +285,14 @@ impl AppState {
             .await
             .expect("Failed to create secret management client");
 
+        let secured_conf = Arc::new(conf.clone());
+        #[allow(clippy::expect_used)]
         let conf = Box::pin(secrets_transformers::fetch_raw_secrets(
             conf,
             &*secret_management_client,
         ))
-        .await;
+        .await
+        .expect("Failed to decrypt the secrets of the settings");
 
         #[allow(clippy::expect_used)]
         let encryption_client = conf
@@ -348,12 +358,15 @@ impl AppState {
             let email_client = Arc::new(create_email_client(&conf).await);
 
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
+            let conf = Arc::new(conf);
 
             Self {
                 flow_name: String::from("default"),
                 stores,
                 global_store,
-                conf: Arc::new(conf),
+                reloadable_conf: settings::ReloadableSettings::new(Arc::clone(&conf)),
+                secured_conf,
+                conf,
                 #[cfg(feature = "email")]
                 email_client,
                 api_client,
@@ -413,15 +426,46 @@ impl AppState {
         shut_down_signal: oneshot::Sender<()>,
         api_client: Box<dyn crate::services::ApiClient>,
     ) -> Self {
+        #[cfg(feature = "in_memory")]
+        let storage_impl = StorageImpl::Mock;
+        #[cfg(not(feature = "in_memory"))]
+        let storage_impl = StorageImpl::Postgresql;
+
         Box::pin(Self::with_storage(
             conf,
-            StorageImpl::Postgresql,
+            storage_impl,
             shut_down_signal,
             api_client,
         ))
         .await
     }
 
+    /// Spawns a task reloading the secrets of the settings whenever the process receives
+    /// `SIGHUP`. Session states created after the reload use the new values of the secrets, while
+    /// the stores and clients keep the values they were created with.
+    #[cfg(unix)]
+    pub fn reload_secrets_on_sighup(&self) -> std::io::Result<()> {
+        let mut hangup_signal =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())?;
+        let secured_conf = Arc::clone(&self.secured_conf);
+        let reloadable_conf = self.reloadable_conf.clone();
+
+        tokio::spawn(async move {
+            while hangup_signal.recv().await.is_some() {
+                match secrets_transformers::reload_raw_secrets(&secured_conf, &reloadable_conf)
+                    .await
+                {
+                    Ok(()) => logger::info!("Reloaded the secrets of the settings"),
+                    Err(error) => {
+                        logger::error!(?error, "Failed to reload the secrets of the settings")
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+
     pub fn get_session_state<E, F>(self: Arc<Self>, tenant: &str, err: F) -> Result<SessionState, E>
     where
         F: FnOnce() -> E + Copy,
@@ -432,7 +476,7 @@ impl AppState {
         Ok(SessionState {
             store: self.stores.get(tenant).ok_or_else(err)?.clone(),
             global_store: self.global_store.clone(),
-            conf: Arc::clone(&self.conf),
+            conf: self.reloadable_conf.load(),
             api_client: self.api_client.clone(),
             event_handler,
             #[cfg(feature = "olap")]
@@ -445,6 +489,7 @@ impl AppState {
             email_client: Arc::clone(&self.email_client),
             #[cfg(feature = "olap")]
             opensearch_client: Arc::clone(&self.opensearch_client),
+            previous_merchant_keys: Default::default(),
         })
     }
 }
@@ -484,6 +529,14 @@ impl DummyConnector {
             .service(
                 web::resource("/refunds/{refund_id}")
                     .route(web::get().to(dummy_connector_refund_data)),
+            )
+            .service(
+                web::resource("/scenarios").route(web::post().to(dummy_connector_scenario_create)),
+            )
+            .service(
+                web::resource("/scenarios/{scenario_id}")
+                    .route(web::get().to(dummy_connector_scenario_retrieve))
+                    .route(web::delete().to(dummy_connector_scenario_delete)),
             );
         web::scope("/dummy-connector")
             .app_data(web::Data::new(state))
@@ -539,6 +592,14 @@ impl Payments {
                 )
                 .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)))
                 .service(web::resource("/v2/filter").route(web::get().to(get_payment_filters)))
+                .service(
+                    web::resource("/sca_exemption_config")
+                        .route(web::get().to(retrieve_sca_exemption_config))
+                        .route(web::post().to(upsert_sca_exemption_config)),
+                )
+                .service(
+                    web::resource("/split_report").route(web::post().to(get_split_payments_report)),
+                )
                 .service(
                     web::resource("/{payment_id}/manual-update")
                         .route(web::put().to(payments_manual_update)),
@@ -902,6 +963,22 @@ impl Customers {
         #[cfg(feature = "olap")]
         {
             route = route
+                .service(
+                    web::resource("/data_subject_requests")
+                        .route(web::post().to(data_subject_request_create)),
+                )
+                .service(
+                    web::resource("/data_subject_requests/list")
+                        .route(web::get().to(data_subject_request_list)),
+                )
+                .service(
+                    web::resource("/data_subject_requests/{request_id}")
+                        .route(web::get().to(data_subject_request_retrieve)),
+                )
+                .service(
+                    web::resource("/data_subject_requests/{request_id}/archive")
+                        .route(web::get().to(data_subject_archive_download)),
+                )
                 .service(
                     web::resource("/{customer_id}/mandates")
                         .route(web::get().to(get_customer_mandates)),
@@ -952,6 +1029,15 @@ impl Refunds {
                 .service(
                     web::resource("/{id}/manual-update")
                         .route(web::put().to(refunds_manual_update)),
+                )
+                .service(web::resource("/batch").route(web::post().to(refund_batch_create)))
+                .service(web::resource("/batch/list").route(web::get().to(refund_batch_list)))
+                .service(
+                    web::resource("/batch/{batch_id}").route(web::get().to(refund_batch_retrieve)),
+                )
+                .service(
+                    web::resource("/batch/{batch_id}/download")
+                        .route(web::get().to(refund_batch_download)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -988,6 +1074,23 @@ impl Payouts {
                 )
                 .service(
                     web::resource("/filter").route(web::post().to(payouts_list_available_filters)),
+                )
+                .service(web::resource("/batch").route(web::post().to(payout_batch_create)))
+                .service(web::resource("/batch/list").route(web::get().to(payout_batch_list)))
+                .service(
+                    web::resource("/batch/{batch_id}").route(web::get().to(payout_batch_retrieve)),
+                )
+                .service(
+                    web::resource("/batch/{batch_id}/approve")
+                        .route(web::post().to(payout_batch_approve)),
+                )
+                .service(
+                    web::resource("/batch/{batch_id}/reject")
+                        .route(web::post().to(payout_batch_reject)),
+                )
+                .service(
+                    web::resource("/batch/{batch_id}/report")
+                        .route(web::get().to(payout_batch_report)),
                 );
         }
         route = route
@@ -1085,6 +1188,27 @@ impl Recon {
     }
 }
 
+#[cfg(all(feature = "frm", feature = "oltp"))]
+pub struct Frm;
+
+#[cfg(all(feature = "frm", feature = "oltp"))]
+impl Frm {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/frm")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/rules")
+                    .route(web::get().to(frm_routes::retrieve_native_frm_rules))
+                    .route(web::post().to(frm_routes::upsert_native_frm_rules))
+                    .route(web::delete().to(frm_routes::delete_native_frm_rules)),
+            )
+            .service(
+                web::resource("/review_queue")
+                    .route(web::get().to(frm_routes::list_frm_review_queue)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Blocklist;
 
@@ -1157,6 +1281,15 @@ impl MerchantAccount {
                 web::resource("/transfer").route(web::post().to(merchant_account_transfer_keys)),
             )
             .service(web::resource("/kv").route(web::post().to(merchant_account_toggle_all_kv)))
+            .service(
+                web::resource("/{id}/key_rotation")
+                    .route(web::post().to(merchant_key_rotation_start))
+                    .route(web::get().to(merchant_key_rotation_list)),
+            )
+            .service(
+                web::resource("/{id}/key_rotation/{rotation_id}")
+                    .route(web::get().to(merchant_key_rotation_retrieve)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -1365,6 +1498,15 @@ impl Disputes {
         web::scope("/disputes")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::get().to(retrieve_disputes_list)))
+            .service(
+                web::resource("/deadlines")
+                    .route(web::get().to(retrieve_upcoming_dispute_deadlines)),
+            )
+            .service(
+                web::resource("/deadline_policy")
+                    .route(web::get().to(retrieve_dispute_deadline_policy))
+                    .route(web::post().to(upsert_dispute_deadline_policy)),
+            )
             .service(web::resource("/accept/{dispute_id}").route(web::post().to(accept_dispute)))
             .service(
                 web::resource("/evidence")
@@ -1372,6 +1514,14 @@ impl Disputes {
                     .route(web::put().to(attach_dispute_evidence))
                     .route(web::delete().to(delete_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/draft/submit")
+                    .route(web::post().to(submit_dispute_evidence_draft)),
+            )
+            .service(
+                web::resource("/evidence/draft/{dispute_id}")
+                    .route(web::post().to(create_dispute_evidence_draft)),
+            )
             .service(
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(retrieve_dispute_evidence)),
@@ -1384,9 +1534,26 @@ pub struct Cards;
 
 impl Cards {
     pub fn server(state: AppState) -> Scope {
-        web::scope("/cards")
-            .app_data(web::Data::new(state))
-            .service(web::resource("/{bin}").route(web::get().to(card_iin_info)))
+        let mut route = web::scope("/cards").app_data(web::Data::new(state));
+
+        #[cfg(feature = "olap")]
+        {
+            route = route
+                .service(
+                    web::resource("/bin_ranges")
+                        .route(web::get().to(cards_info::list_card_bin_ranges)),
+                )
+                .service(
+                    web::resource("/bin_ranges/upload")
+                        .route(web::post().to(cards_info::upload_card_bin_ranges)),
+                )
+                .service(
+                    web::resource("/bin_ranges/delete")
+                        .route(web::post().to(cards_info::delete_card_bin_range)),
+                );
+        }
+
+        route.service(web::resource("/{bin}").route(web::get().to(card_iin_info)))
     }
 }
 
@@ -1423,9 +1590,35 @@ impl PaymentLink {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::post().to(payments_link_list)))
+            .service(web::resource("/reusable").route(web::post().to(reusable_payment_link_create)))
+            .service(
+                web::resource("/reusable/list").route(web::get().to(reusable_payment_link_list)),
+            )
+            .service(
+                web::resource("/reusable/{reusable_link_id}")
+                    .route(web::get().to(reusable_payment_link_retrieve))
+                    .route(web::post().to(reusable_payment_link_update)),
+            )
+            .service(
+                web::resource("/reusable/{reusable_link_id}/summary")
+                    .route(web::get().to(reusable_payment_link_sales_summary)),
+            )
+            .service(
+                web::resource("r/{merchant_id}/{reusable_link_id}")
+                    .route(web::get().to(visit_reusable_payment_link))
+                    .route(web::post().to(initiate_reusable_payment_link)),
+            )
             .service(
                 web::resource("/{payment_link_id}").route(web::get().to(payment_link_retrieve)),
             )
+            .service(
+                web::resource("/{payment_link_id}/qr")
+                    .route(web::get().to(payment_link_qr_code_retrieve)),
+            )
+            .service(
+                web::resource("/{payment_link_id}/invoice")
+                    .route(web::get().to(payment_link_invoice_retrieve)),
+            )
             .service(
                 web::resource("{merchant_id}/{payment_id}")
                     .route(web::get().to(initiate_payment_link)),
@@ -1557,6 +1750,104 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(delete_gsm_rule)))
+            .service(web::resource("/import").route(web::post().to(import_gsm_rules)))
+            .service(web::resource("/import/csv").route(web::post().to(import_gsm_rules_csv)))
+            .service(web::resource("/export").route(web::get().to(export_gsm_rules)))
+            .service(web::resource("/history").route(web::get().to(list_gsm_rule_history)))
+            .service(
+                web::resource("/history/{change_id}/revert")
+                    .route(web::post().to(revert_gsm_rule_change)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct DataRetention;
+
+#[cfg(feature = "olap")]
+impl DataRetention {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/data_retention")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/policies")
+                    .route(web::post().to(data_retention::upsert_data_retention_policy))
+                    .route(web::get().to(data_retention::list_data_retention_policies)),
+            )
+            .service(
+                web::resource("/policies/{table_name}")
+                    .route(web::delete().to(data_retention::delete_data_retention_policy)),
+            )
+            .service(
+                web::resource("/archives")
+                    .route(web::get().to(data_retention::list_data_retention_archives)),
+            )
+            .service(
+                web::resource("/archives/{archive_id}/restore")
+                    .route(web::post().to(data_retention::restore_data_retention_archive)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Invoices;
+
+#[cfg(feature = "olap")]
+impl Invoices {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/invoices")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(invoices::invoice_create)))
+            .service(web::resource("/list").route(web::get().to(invoices::invoice_list)))
+            .service(
+                web::resource("/number_sequences")
+                    .route(web::post().to(invoices::invoice_number_sequence_upsert)),
+            )
+            .service(
+                web::resource("/{invoice_id}")
+                    .route(web::get().to(invoices::invoice_retrieve))
+                    .route(web::post().to(invoices::invoice_update)),
+            )
+            .service(
+                web::resource("/{invoice_id}/finalize")
+                    .route(web::post().to(invoices::invoice_finalize)),
+            )
+            .service(
+                web::resource("/{invoice_id}/void").route(web::post().to(invoices::invoice_void)),
+            )
+            .service(
+                web::resource("/{invoice_id}/mark_uncollectible")
+                    .route(web::post().to(invoices::invoice_mark_uncollectible)),
+            )
+            .service(
+                web::resource("/{invoice_id}/payments")
+                    .route(web::post().to(invoices::invoice_record_payment)),
+            )
+            .service(
+                web::resource("/{invoice_id}/payment_link")
+                    .route(web::post().to(invoices::invoice_payment_link_create)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Ledger;
+
+#[cfg(feature = "olap")]
+impl Ledger {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/ledger")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/balances").route(web::get().to(ledger::ledger_balances)))
+            .service(
+                web::resource("/accounts/{account}/statement")
+                    .route(web::get().to(ledger::ledger_statement)),
+            )
+            .service(web::resource("/entries").route(web::get().to(ledger::ledger_entry_list)))
+            .service(
+                web::resource("/{merchant_id}/rebuild")
+                    .route(web::post().to(ledger::ledger_rebuild)),
+            )
     }
 }
 
@@ -1579,6 +1870,35 @@ impl Verify {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct Scim;
+
+#[cfg(feature = "olap")]
+impl Scim {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/scim/v2")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/Users")
+                    .route(web::get().to(list_scim_users))
+                    .route(web::post().to(create_scim_user)),
+            )
+            .service(
+                web::resource("/Users/{user_id}")
+                    .route(web::get().to(retrieve_scim_user))
+                    .route(web::put().to(replace_scim_user))
+                    .route(web::patch().to(patch_scim_user))
+                    .route(web::delete().to(delete_scim_user)),
+            )
+            .service(web::resource("/Groups").route(web::get().to(list_scim_groups)))
+            .service(
+                web::resource("/Groups/{group_id}")
+                    .route(web::get().to(retrieve_scim_group))
+                    .route(web::patch().to(patch_scim_group)),
+            )
+    }
+}
+
 pub struct User;
 
 #[cfg(feature = "olap")]
@@ -1664,6 +1984,12 @@ impl User {
                 .service(web::resource("/select").route(web::post().to(terminate_auth_select))),
         );
 
+        route = route.service(
+            web::resource("/scim_token")
+                .route(web::post().to(create_scim_token))
+                .route(web::delete().to(revoke_scim_token)),
+        );
+
         #[cfg(feature = "email")]
         {
             route = route
@@ -1728,7 +2054,8 @@ impl User {
                 .service(
                     web::resource("/{role_id}")
                         .route(web::get().to(get_role))
-                        .route(web::put().to(update_role)),
+                        .route(web::put().to(update_role))
+                        .route(web::delete().to(delete_role)),
                 ),
         );
 
@@ -1779,3 +2106,26 @@ impl WebhookEvents {
             )
     }
 }
+
+pub struct WebhookSigningKeys;
+
+#[cfg(feature = "olap")]
+impl WebhookSigningKeys {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/webhook_signing_keys/{merchant_id}/{profile_id}")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::post().to(webhook_signing_key::create_webhook_signing_key))
+                    .route(web::get().to(webhook_signing_key::list_webhook_signing_keys)),
+            )
+            .service(
+                web::resource("/jwks")
+                    .route(web::get().to(webhook_signing_key::retrieve_webhook_signing_jwks)),
+            )
+            .service(
+                web::resource("/{key_id}")
+                    .route(web::delete().to(webhook_signing_key::revoke_webhook_signing_key)),
+            )
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "olap")]
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, Responder};
 use router_env::{instrument, tracing, Flow};
 
@@ -54,3 +56,107 @@ pub async fn card_iin_info(
     ))
     .await
 }
+
+/// Cards Info - Upload BIN ranges
+///
+/// Create or update card BIN ranges from a CSV file having the columns `bin_start`, `bin_end`,
+/// `issuer`, `network`, `type`, `country` and `product`
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::CardBinRangesUpload))]
+pub async fn upload_card_bin_ranges(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<cards_info::bin_ranges::CardBinRangeForm>,
+) -> impl Responder {
+    let flow = Flow::CardBinRangesUpload;
+    let payload = match cards_info::bin_ranges::get_card_bin_range_records(form) {
+        Ok(payload) => payload,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| cards_info::bin_ranges::upload_card_bin_ranges(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Cards Info - List BIN ranges
+///
+/// List the configured card BIN ranges
+#[utoipa::path(
+    get,
+    path = "/cards/bin_ranges",
+    params(
+        ("bin_length" = Option<i32>, Query, description = "Only list the ranges of 6 or 8 digit BINs"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of ranges to be returned"),
+        ("offset" = Option<i64>, Query, description = "Number of ranges to be skipped"),
+    ),
+    responses(
+        (status = 200, description = "Card BIN ranges listed", body = CardBinRangeListResponse),
+    ),
+    operation_id = "List card BIN ranges",
+    security(("admin_api_key" = []))
+)]
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::CardBinRangesList))]
+pub async fn list_card_bin_ranges(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<api_models::cards_info::CardBinRangeListRequest>,
+) -> impl Responder {
+    let flow = Flow::CardBinRangesList;
+    let payload = query_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| cards_info::bin_ranges::list_card_bin_ranges(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Cards Info - Delete BIN range
+///
+/// Delete a card BIN range
+#[utoipa::path(
+    post,
+    path = "/cards/bin_ranges/delete",
+    request_body = CardBinRangeDeleteRequest,
+    responses(
+        (status = 200, description = "Card BIN range deleted", body = CardBinRangeDeleteResponse),
+        (status = 404, description = "Card BIN range not found")
+    ),
+    operation_id = "Delete card BIN range",
+    security(("admin_api_key" = []))
+)]
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::CardBinRangeDelete))]
+pub async fn delete_card_bin_range(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::cards_info::CardBinRangeDeleteRequest>,
+) -> impl Responder {
+    let flow = Flow::CardBinRangeDelete;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| cards_info::bin_ranges::delete_card_bin_range(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
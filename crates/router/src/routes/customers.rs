@@ -240,3 +240,142 @@ pub async fn get_customer_mandates(
     ))
     .await
 }
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::DataSubjectRequestCreate))]
+pub async fn data_subject_request_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::customers::DataSubjectRequestCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::DataSubjectRequestCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            data_subject_request::create_data_subject_request(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::DataSubjectRequestList))]
+pub async fn data_subject_request_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<api_models::customers::DataSubjectRequestListRequest>,
+) -> HttpResponse {
+    let flow = Flow::DataSubjectRequestList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth, req, _| {
+            data_subject_request::list_data_subject_requests(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::DataSubjectRequestRetrieve))]
+pub async fn data_subject_request_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DataSubjectRequestRetrieve;
+    let payload = api_models::customers::DataSubjectRequestId {
+        request_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            data_subject_request::retrieve_data_subject_request(
+                state,
+                auth.merchant_account,
+                req.request_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::DataSubjectArchiveDownload))]
+pub async fn data_subject_archive_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DataSubjectArchiveDownload;
+    let payload = api_models::customers::DataSubjectRequestId {
+        request_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            data_subject_request::download_data_subject_archive(
+                state,
+                auth.merchant_account,
+                req.request_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
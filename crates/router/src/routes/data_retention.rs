@@ -0,0 +1,124 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::{data_retention as data_retention_api_types, enums};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, data_retention},
+    services::{api, authentication as auth},
+};
+
+/// Data Retention - Create or update a policy
+///
+/// Sets the retention period of a table and schedules the archival of its expired rows
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPolicyUpsert))]
+pub async fn upsert_data_retention_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<data_retention_api_types::DataRetentionPolicyRequest>,
+) -> impl Responder {
+    let flow = Flow::DataRetentionPolicyUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| data_retention::upsert_data_retention_policy(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Data Retention - List policies
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPolicyList))]
+pub async fn list_data_retention_policies(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::DataRetentionPolicyList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| data_retention::list_data_retention_policies(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Data Retention - Delete a policy
+///
+/// Stops the archival of the table, existing archives are retained
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionPolicyDelete))]
+pub async fn delete_data_retention_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::DataRetentionTable>,
+) -> impl Responder {
+    let flow = Flow::DataRetentionPolicyDelete;
+    let payload = data_retention_api_types::DataRetentionTableName {
+        table_name: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            data_retention::delete_data_retention_policy(state, payload.table_name)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Data Retention - List archives
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionArchiveList))]
+pub async fn list_data_retention_archives(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<data_retention_api_types::DataRetentionArchiveListRequest>,
+) -> impl Responder {
+    let flow = Flow::DataRetentionArchiveList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, _, payload, _| data_retention::list_data_retention_archives(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Data Retention - Restore an archive
+///
+/// Inserts the rows of the archive back into their table
+#[instrument(skip_all, fields(flow = ?Flow::DataRetentionArchiveRestore))]
+pub async fn restore_data_retention_archive(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::DataRetentionArchiveRestore;
+    let payload = data_retention_api_types::DataRetentionArchiveId {
+        archive_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            data_retention::restore_data_retention_archive(state, payload.archive_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -104,6 +104,120 @@ pub async fn retrieve_disputes_list(
     ))
     .await
 }
+/// Disputes - List Upcoming Deadlines
+#[utoipa::path(
+    get,
+    path = "/disputes/deadlines",
+    params(
+        ("within_hours" = Option<u16>, Query, description = "Only disputes with an evidence deadline within these many hours are returned"),
+        ("profile_id" = Option<String>, Query, description = "The identifier for business profile"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of Dispute Objects to include in the response"),
+    ),
+    responses(
+        (status = 200, description = "The open disputes with upcoming evidence deadlines were retrieved successfully", body = Vec<DisputeResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "List Upcoming Dispute Deadlines",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeDeadlinesList))]
+pub async fn retrieve_upcoming_dispute_deadlines(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<dispute_models::DisputeDeadlineListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::DisputeDeadlinesList;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            disputes::deadlines::list_upcoming_deadlines(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::DisputeRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Disputes - Update Deadline Policy
+#[utoipa::path(
+    post,
+    path = "/disputes/deadline_policy",
+    request_body = DisputeDeadlinePolicy,
+    responses(
+        (status = 200, description = "The dispute deadline policy was updated successfully", body = DisputeDeadlinePolicy),
+        (status = 400, description = "Invalid dispute deadline policy")
+    ),
+    tag = "Disputes",
+    operation_id = "Update Dispute Deadline Policy",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeDeadlinePolicyUpsert))]
+pub async fn upsert_dispute_deadline_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::DisputeDeadlinePolicy>,
+) -> HttpResponse {
+    let flow = Flow::DisputeDeadlinePolicyUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            disputes::deadlines::upsert_dispute_deadline_policy(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Disputes - Retrieve Deadline Policy
+#[utoipa::path(
+    get,
+    path = "/disputes/deadline_policy",
+    responses(
+        (status = 200, description = "The dispute deadline policy was retrieved successfully", body = DisputeDeadlinePolicy),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve Dispute Deadline Policy",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeDeadlinePolicyRetrieve))]
+pub async fn retrieve_dispute_deadline_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::DisputeDeadlinePolicyRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _, _| {
+            disputes::deadlines::retrieve_dispute_deadline_policy(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::DisputeRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Disputes - Accept Dispute
 #[utoipa::path(
     get,
@@ -290,6 +404,105 @@ pub async fn retrieve_dispute_evidence(
     .await
 }
 
+/// Disputes - Create Evidence Draft
+///
+/// To assemble a draft of the evidence for a dispute from the stored payment data
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence/draft/{dispute_id}",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The evidence draft was assembled successfully", body = DisputeEvidenceDraftResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Create Dispute Evidence Draft",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeEvidenceDraftCreate))]
+pub async fn create_dispute_evidence_draft(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DisputeEvidenceDraftCreate;
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        dispute_id,
+        |state, auth, req, _| {
+            disputes::evidence_draft::create_evidence_draft(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Disputes - Submit Evidence Draft
+///
+/// To submit a reviewed evidence draft, along with a freshly rendered evidence document
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence/draft/submit",
+    request_body=SubmitEvidenceRequest,
+    responses(
+        (status = 200, description = "The evidence draft was submitted successfully", body = DisputeResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Submit Dispute Evidence Draft",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeEvidenceDraftSubmit))]
+pub async fn submit_dispute_evidence_draft(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::SubmitEvidenceRequest>,
+) -> HttpResponse {
+    let flow = Flow::DisputeEvidenceDraftSubmit;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            disputes::evidence_draft::submit_evidence_draft(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Disputes - Delete Evidence attached to a Dispute
 ///
 /// To delete an evidence file attached to a dispute
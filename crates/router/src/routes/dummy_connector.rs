@@ -137,3 +137,64 @@ pub async fn dummy_connector_refund_data(
     )
     .await
 }
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyScenarioCreate))]
+pub async fn dummy_connector_scenario_create(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorScenarioRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyScenarioCreate;
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            core::scenario_create(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyScenarioRetrieve))]
+pub async fn dummy_connector_scenario_retrieve(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyScenarioRetrieve;
+    let scenario_id = path.into_inner();
+    let payload = types::DummyConnectorScenarioRetrieveRequest { scenario_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| core::scenario_retrieve(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyScenarioDelete))]
+pub async fn dummy_connector_scenario_delete(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyScenarioDelete;
+    let scenario_id = path.into_inner();
+    let payload = types::DummyConnectorScenarioRetrieveRequest { scenario_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| core::scenario_delete(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
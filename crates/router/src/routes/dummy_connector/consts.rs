@@ -1,4 +1,8 @@
 pub const PAYMENT_ID_PREFIX: &str = "dummy_pay";
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
+pub const SCENARIO_ID_PREFIX: &str = "dummy_scenario";
+pub const SCENARIO_KEY_PREFIX: &str = "dummy_scenario_lookup";
+pub const MAX_SCENARIO_LATENCY_IN_MILLIS: u64 = 30_000;
+pub const MAX_SCENARIO_ASYNC_STATUS_DELAY_IN_SECS: u64 = 600;
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
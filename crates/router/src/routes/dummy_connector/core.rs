@@ -1,11 +1,14 @@
 use app::SessionState;
 use common_utils::generate_id_with_default_len;
 use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{logger, tracing::Instrument};
 
 use super::{errors, types, utils};
 use crate::{
     routes::{app, dummy_connector::consts},
     services::api,
+    types::domain,
     utils::OptionExt,
 };
 
@@ -13,15 +16,31 @@ pub async fn payment(
     state: SessionState,
     req: types::DummyConnectorPaymentRequest,
 ) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
-    utils::tokio_mock_sleep(
-        state.conf.dummy_connector.payment_duration,
-        state.conf.dummy_connector.payment_tolerance,
-    )
-    .await;
+    let scenario = utils::find_scenario_for_payment(&state, &req).await?;
+
+    match scenario
+        .as_ref()
+        .and_then(|scenario| scenario.definition.latency)
+    {
+        Some(latency) => utils::scenario_sleep(latency).await,
+        None => {
+            utils::tokio_mock_sleep(
+                state.conf.dummy_connector.payment_duration,
+                state.conf.dummy_connector.payment_tolerance,
+            )
+            .await
+        }
+    }
 
     let payment_attempt: types::DummyConnectorPaymentAttempt = req.into();
-    let payment_data =
-        types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?;
+    let payment_data = match &scenario {
+        Some(scenario) => types::DummyConnectorPaymentData::process_payment_attempt_with_scenario(
+            &state,
+            payment_attempt,
+            scenario,
+        ),
+        None => types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?,
+    };
 
     utils::store_data_in_redis(
         &state,
@@ -37,9 +56,96 @@ pub async fn payment(
         state.conf.dummy_connector.payment_ttl,
     )
     .await?;
+
+    if let Some(scenario) = scenario {
+        handle_scenario_payment_update(&state, scenario, &payment_data);
+    }
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
 
+/// Emits the webhook for a payment that reached a final status, or schedules the delayed status
+/// change of a pending payment.
+fn handle_scenario_payment_update(
+    state: &SessionState,
+    scenario: types::DummyConnectorScenario,
+    payment_data: &types::DummyConnectorPaymentData,
+) {
+    let state = state.clone();
+    let payment_id = payment_data.payment_id.clone();
+    let webhook = types::DummyConnectorWebhook::for_payment(payment_data);
+    let is_pending = payment_data.status == types::DummyConnectorStatus::Pending;
+
+    match (scenario.definition.async_status.clone(), webhook) {
+        (Some(async_status), _) if is_pending => {
+            tokio::spawn(
+                async move {
+                    tokio::time::sleep(std::time::Duration::from_secs(async_status.delay)).await;
+                    if let Err(error) =
+                        complete_async_payment(&state, &scenario, payment_id, async_status).await
+                    {
+                        logger::error!(?error, "Failed to complete the pending payment");
+                    }
+                }
+                .in_current_span(),
+            );
+        }
+        (_, Some(webhook)) => {
+            if let Some(webhook_url) = scenario.definition.webhook_url {
+                spawn_webhook(state, webhook_url, webhook);
+            }
+        }
+        (_, None) => {}
+    }
+}
+
+async fn complete_async_payment(
+    state: &SessionState,
+    scenario: &types::DummyConnectorScenario,
+    payment_id: String,
+    async_status: types::DummyConnectorAsyncStatus,
+) -> types::DummyConnectorResult<()> {
+    let payment_data = utils::get_payment_data_from_payment_id(state, payment_id).await?;
+    if payment_data.status != types::DummyConnectorStatus::Pending {
+        return Ok(());
+    }
+
+    let updated_payment_data = match async_status.status {
+        types::DummyConnectorStatus::Succeeded => {
+            payment_data.capture(scenario.definition.capture_amount)
+        }
+        status => types::DummyConnectorPaymentData {
+            status,
+            ..payment_data
+        },
+    };
+    utils::store_data_in_redis(
+        state,
+        updated_payment_data.payment_id.clone(),
+        updated_payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+
+    if let (Some(webhook_url), Some(webhook)) = (
+        scenario.definition.webhook_url.as_ref(),
+        types::DummyConnectorWebhook::for_payment(&updated_payment_data),
+    ) {
+        utils::send_webhook(state, webhook_url, webhook).await?;
+    }
+    Ok(())
+}
+
+fn spawn_webhook(state: SessionState, webhook_url: String, webhook: types::DummyConnectorWebhook) {
+    tokio::spawn(
+        async move {
+            if let Err(error) = utils::send_webhook(&state, &webhook_url, webhook).await {
+                logger::error!(?error, "Failed to send the dummy connector webhook");
+            }
+        }
+        .in_current_span(),
+    );
+}
+
 pub async fn payment_data(
     state: SessionState,
     req: types::DummyConnectorPaymentRetrieveRequest,
@@ -109,10 +215,19 @@ pub async fn payment_complete(
     let _ = redis_conn.delete_key(req.attempt_id.as_str()).await;
 
     if let Ok(payment_data) = payment_data {
-        let updated_payment_data = types::DummyConnectorPaymentData {
-            status: payment_status,
-            next_action: None,
-            ..payment_data
+        let scenario = match payment_data.scenario_id.as_deref() {
+            Some(scenario_id) => Some(utils::get_scenario(&state, scenario_id).await?),
+            None => None,
+        };
+        let updated_payment_data = match (&scenario, payment_status) {
+            (Some(scenario), types::DummyConnectorStatus::Succeeded) => {
+                scenario.resolve_payment(payment_data)
+            }
+            (_, status) => types::DummyConnectorPaymentData {
+                status,
+                next_action: None,
+                ..payment_data
+            },
         };
         utils::store_data_in_redis(
             &state,
@@ -121,6 +236,9 @@ pub async fn payment_complete(
             state.conf.dummy_connector.payment_ttl,
         )
         .await?;
+        if let Some(scenario) = scenario {
+            handle_scenario_payment_update(&state, scenario, &updated_payment_data);
+        }
         return Ok(api::ApplicationResponse::JsonForRedirection(
             api_models::payments::RedirectionResponse {
                 return_url: String::new(),
@@ -148,12 +266,6 @@ pub async fn refund_payment(
     state: SessionState,
     req: types::DummyConnectorRefundRequest,
 ) -> types::DummyConnectorResponse<types::DummyConnectorRefundResponse> {
-    utils::tokio_mock_sleep(
-        state.conf.dummy_connector.refund_duration,
-        state.conf.dummy_connector.refund_tolerance,
-    )
-    .await;
-
     let payment_id = req
         .payment_id
         .get_required_value("payment_id")
@@ -163,28 +275,58 @@ pub async fn refund_payment(
 
     let mut payment_data =
         utils::get_payment_data_from_payment_id(&state, payment_id.clone()).await?;
+    let scenario = match payment_data.scenario_id.as_deref() {
+        Some(scenario_id) => Some(utils::get_scenario(&state, scenario_id).await?),
+        None => None,
+    };
+
+    match scenario
+        .as_ref()
+        .and_then(|scenario| scenario.definition.latency)
+    {
+        Some(latency) => utils::scenario_sleep(latency).await,
+        None => {
+            utils::tokio_mock_sleep(
+                state.conf.dummy_connector.refund_duration,
+                state.conf.dummy_connector.refund_tolerance,
+            )
+            .await
+        }
+    }
 
     payment_data.is_eligible_for_refund(req.amount)?;
 
     let refund_id = generate_id_with_default_len(consts::REFUND_ID_PREFIX);
-    payment_data.eligible_amount -= req.amount;
+    let refund_failure = scenario
+        .as_ref()
+        .and_then(|scenario| scenario.definition.refund_failure.clone());
 
-    utils::store_data_in_redis(
-        &state,
-        payment_id,
-        payment_data.to_owned(),
-        state.conf.dummy_connector.payment_ttl,
-    )
-    .await?;
+    let refund_status = if refund_failure.is_some() {
+        types::DummyConnectorStatus::Failed
+    } else {
+        payment_data.eligible_amount -= req.amount;
 
-    let refund_data = types::DummyConnectorRefundResponse::new(
-        types::DummyConnectorStatus::Succeeded,
-        refund_id.to_owned(),
-        payment_data.currency,
-        common_utils::date_time::now(),
-        payment_data.amount,
-        req.amount,
-    );
+        utils::store_data_in_redis(
+            &state,
+            payment_id.clone(),
+            payment_data.to_owned(),
+            state.conf.dummy_connector.payment_ttl,
+        )
+        .await?;
+        types::DummyConnectorStatus::Succeeded
+    };
+
+    let refund_data = types::DummyConnectorRefundResponse {
+        error: refund_failure,
+        ..types::DummyConnectorRefundResponse::new(
+            refund_status,
+            refund_id.to_owned(),
+            payment_data.currency,
+            common_utils::date_time::now(),
+            payment_data.amount,
+            req.amount,
+        )
+    };
 
     utils::store_data_in_redis(
         &state,
@@ -193,6 +335,14 @@ pub async fn refund_payment(
         state.conf.dummy_connector.refund_ttl,
     )
     .await?;
+
+    if let Some(webhook_url) = scenario.and_then(|scenario| scenario.definition.webhook_url) {
+        spawn_webhook(
+            state,
+            webhook_url,
+            types::DummyConnectorWebhook::for_refund(payment_id, &refund_data),
+        );
+    }
     Ok(api::ApplicationResponse::Json(refund_data))
 }
 
@@ -221,3 +371,62 @@ pub async fn refund_data(
         .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
+
+pub async fn scenario_create(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: types::DummyConnectorScenarioRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorScenario> {
+    let merchant_webhook_urls = state
+        .store
+        .list_business_profile_by_merchant_id(
+            &(&state).into(),
+            &key_store,
+            merchant_account.get_id(),
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to list the business profiles of the merchant")?
+        .into_iter()
+        .filter_map(|business_profile| {
+            business_profile
+                .webhook_details
+                .and_then(|webhook_details| webhook_details.webhook_url)
+                .map(|webhook_url| webhook_url.peek().to_owned())
+        })
+        .collect::<Vec<_>>();
+
+    let scenario = types::DummyConnectorScenario::new(merchant_account.get_id().clone(), req);
+    scenario.validate(&merchant_webhook_urls)?;
+
+    utils::store_scenario(&state, &scenario).await?;
+    Ok(api::ApplicationResponse::Json(scenario))
+}
+
+pub async fn scenario_retrieve(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: types::DummyConnectorScenarioRetrieveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorScenario> {
+    let scenario =
+        utils::get_merchant_scenario(&state, merchant_account.get_id(), &req.scenario_id).await?;
+    Ok(api::ApplicationResponse::Json(scenario))
+}
+
+pub async fn scenario_delete(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: types::DummyConnectorScenarioRetrieveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorScenarioDeleteResponse> {
+    let scenario =
+        utils::get_merchant_scenario(&state, merchant_account.get_id(), &req.scenario_id).await?;
+    utils::delete_scenario(&state, &scenario).await?;
+
+    Ok(api::ApplicationResponse::Json(
+        types::DummyConnectorScenarioDeleteResponse {
+            scenario_id: scenario.scenario_id,
+            deleted: true,
+        },
+    ))
+}
@@ -37,6 +37,12 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_09", message = "Scenario does not exist in our records")]
+    ScenarioNotFound,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_10", message = "Invalid scenario: {message}")]
+    InvalidScenario { message: &'static str },
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +89,12 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::ScenarioNotFound => {
+                AER::NotFound(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::InvalidScenario { message: _ } => {
+                AER::BadRequest(ApiError::new("DC", 10, self.error_message(), None))
+            }
         }
     }
 }
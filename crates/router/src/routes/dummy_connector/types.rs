@@ -1,7 +1,7 @@
 use api_models::enums::Currency;
 use common_utils::{errors::CustomResult, generate_id_with_default_len};
 use error_stack::report;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use router_env::types::FlowMetric;
 use strum::Display;
 use time::PrimitiveDateTime;
@@ -18,6 +18,9 @@ pub enum Flow {
     DummyPaymentComplete,
     DummyRefundCreate,
     DummyRefundRetrieve,
+    DummyScenarioCreate,
+    DummyScenarioRetrieve,
+    DummyScenarioDelete,
 }
 
 impl FlowMetric for Flow {}
@@ -64,6 +67,9 @@ pub enum DummyConnectorStatus {
     #[default]
     Processing,
     Failed,
+    Pending,
+    #[serde(rename = "partially_captured")]
+    PartiallyCaptured,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -107,6 +113,9 @@ impl DummyConnectorPaymentAttempt {
             payment_method_type: self.payment_request.payment_method_data.into(),
             next_action,
             return_url,
+            amount_captured: None,
+            error: None,
+            scenario_id: None,
         }
     }
 }
@@ -118,6 +127,9 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    pub metadata: Option<serde_json::Value>,
+    /// Merchant on whose behalf the payment is made, scenarios of other merchants are never applied
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
 }
 
 pub trait GetPaymentMethodDetails {
@@ -259,6 +271,9 @@ pub struct DummyConnectorPaymentData {
     pub connector: DummyConnectors,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
+    pub amount_captured: Option<i64>,
+    pub error: Option<DummyConnectorScenarioError>,
+    pub scenario_id: Option<String>,
 }
 
 impl DummyConnectorPaymentData {
@@ -269,7 +284,10 @@ impl DummyConnectorPaymentData {
                     .attach_printable("Eligible amount is lesser than refund amount"),
             );
         }
-        if self.status != DummyConnectorStatus::Succeeded {
+        if !matches!(
+            self.status,
+            DummyConnectorStatus::Succeeded | DummyConnectorStatus::PartiallyCaptured
+        ) {
             return Err(report!(DummyConnectorErrors::PaymentNotSuccessful)
                 .attach_printable("Payment is not successful to process the refund"));
         }
@@ -293,6 +311,8 @@ pub struct DummyConnectorPaymentResponse {
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub next_action: Option<DummyConnectorNextAction>,
+    pub amount_captured: Option<i64>,
+    pub error: Option<DummyConnectorScenarioError>,
 }
 
 impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
@@ -305,6 +325,8 @@ impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
             created: value.created,
             payment_method_type: value.payment_method_type,
             next_action: value.next_action,
+            amount_captured: value.amount_captured,
+            error: value.error,
         }
     }
 }
@@ -345,6 +367,7 @@ pub struct DummyConnectorRefundResponse {
     pub created: PrimitiveDateTime,
     pub payment_amount: i64,
     pub refund_amount: i64,
+    pub error: Option<DummyConnectorScenarioError>,
 }
 
 impl DummyConnectorRefundResponse {
@@ -363,6 +386,7 @@ impl DummyConnectorRefundResponse {
             created,
             payment_amount,
             refund_amount,
+            error: None,
         }
     }
 }
@@ -372,6 +396,164 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+/// A scenario overrides how the dummy connector processes payments made with a specific card
+/// number, or carrying a specific metadata value, so that every outcome a real processor can
+/// produce is reproducible on demand.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorScenarioRequest {
+    pub trigger: DummyConnectorScenarioTrigger,
+    /// Time taken to respond to the payment and refund requests, in milliseconds, at most
+    /// `MAX_SCENARIO_LATENCY_IN_MILLIS`
+    pub latency: Option<u64>,
+    /// Decline the payment with this error
+    pub decline: Option<DummyConnectorScenarioError>,
+    /// Require the customer to complete a 3DS challenge before the payment is authorized
+    #[serde(default)]
+    pub three_ds: bool,
+    /// Capture only this amount of the payment
+    pub capture_amount: Option<i64>,
+    /// Keep the payment pending and move it to a final status after a delay
+    pub async_status: Option<DummyConnectorAsyncStatus>,
+    /// Send webhooks to this URL whenever the payment or one of its refunds reaches a final status,
+    /// it must be one of the webhook endpoints configured in the business profiles of the merchant
+    pub webhook_url: Option<String>,
+    /// Fail refunds of the payment with this error
+    pub refund_failure: Option<DummyConnectorScenarioError>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DummyConnectorScenarioTrigger {
+    CardNumber { card_number: cards::CardNumber },
+    Metadata { key: String, value: String },
+}
+
+impl DummyConnectorScenarioTrigger {
+    pub fn get_lookup_key(&self, merchant_id: &common_utils::id_type::MerchantId) -> String {
+        let merchant_id = merchant_id.get_string_repr();
+        match self {
+            Self::CardNumber { card_number } => {
+                format!(
+                    "{}_{merchant_id}_card_{}",
+                    consts::SCENARIO_KEY_PREFIX,
+                    card_number.peek()
+                )
+            }
+            Self::Metadata { key, value } => {
+                format!(
+                    "{}_{merchant_id}_metadata_{key}_{value}",
+                    consts::SCENARIO_KEY_PREFIX
+                )
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorScenarioError {
+    pub code: String,
+    pub message: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorAsyncStatus {
+    pub status: DummyConnectorStatus,
+    /// Delay after which the payment moves to `status`, in seconds, at most
+    /// `MAX_SCENARIO_ASYNC_STATUS_DELAY_IN_SECS`
+    pub delay: u64,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorScenario {
+    pub scenario_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    #[serde(flatten)]
+    pub definition: DummyConnectorScenarioRequest,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+impl DummyConnectorScenario {
+    pub fn new(
+        merchant_id: common_utils::id_type::MerchantId,
+        definition: DummyConnectorScenarioRequest,
+    ) -> Self {
+        Self {
+            scenario_id: generate_id_with_default_len(consts::SCENARIO_ID_PREFIX),
+            merchant_id,
+            definition,
+            created: common_utils::date_time::now(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorScenarioRetrieveRequest {
+    pub scenario_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorScenarioDeleteResponse {
+    pub scenario_id: String,
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEvent {
+    PaymentSucceeded,
+    PaymentFailed,
+    PaymentPartiallyCaptured,
+    RefundSucceeded,
+    RefundFailed,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorWebhook {
+    pub event: DummyConnectorWebhookEvent,
+    /// Identifier of the payment or the refund the event is about
+    pub id: String,
+    pub payment_id: String,
+    pub status: DummyConnectorStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+impl DummyConnectorWebhook {
+    pub fn for_payment(payment_data: &DummyConnectorPaymentData) -> Option<Self> {
+        let event = match payment_data.status {
+            DummyConnectorStatus::Succeeded => DummyConnectorWebhookEvent::PaymentSucceeded,
+            DummyConnectorStatus::Failed => DummyConnectorWebhookEvent::PaymentFailed,
+            DummyConnectorStatus::PartiallyCaptured => {
+                DummyConnectorWebhookEvent::PaymentPartiallyCaptured
+            }
+            DummyConnectorStatus::Processing | DummyConnectorStatus::Pending => return None,
+        };
+        Some(Self {
+            event,
+            id: payment_data.payment_id.clone(),
+            payment_id: payment_data.payment_id.clone(),
+            status: payment_data.status.clone(),
+            created: common_utils::date_time::now(),
+        })
+    }
+
+    pub fn for_refund(payment_id: String, refund_data: &DummyConnectorRefundResponse) -> Self {
+        let event = match refund_data.status {
+            DummyConnectorStatus::Failed => DummyConnectorWebhookEvent::RefundFailed,
+            _ => DummyConnectorWebhookEvent::RefundSucceeded,
+        };
+        Self {
+            event,
+            id: refund_data.id.clone(),
+            payment_id,
+            status: refund_data.status.clone(),
+            created: common_utils::date_time::now(),
+        }
+    }
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::{ext_traits::AsyncExt, request::RequestContent};
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use maud::html;
@@ -11,7 +11,7 @@ use super::{
     consts, errors,
     types::{self, GetPaymentMethodDetails},
 };
-use crate::{configs::settings, routes::SessionState};
+use crate::{configs::settings, headers, routes::SessionState, services};
 
 pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     let mut rng = rand::thread_rng();
@@ -25,6 +25,10 @@ pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     .await
 }
 
+pub async fn scenario_sleep(latency: u64) {
+    tokio::sleep(tokio::Duration::from_millis(latency)).await
+}
+
 pub async fn store_data_in_redis(
     state: &SessionState,
     key: String,
@@ -89,6 +93,151 @@ pub async fn get_payment_data_by_attempt_id(
         .change_context(errors::DummyConnectorErrors::PaymentNotFound)
 }
 
+pub async fn store_scenario(
+    state: &SessionState,
+    scenario: &types::DummyConnectorScenario,
+) -> types::DummyConnectorResult<()> {
+    let scenario_ttl = state.conf.dummy_connector.scenario_ttl;
+    store_data_in_redis(state, scenario.scenario_id.clone(), scenario, scenario_ttl).await?;
+    store_data_in_redis(
+        state,
+        scenario
+            .definition
+            .trigger
+            .get_lookup_key(&scenario.merchant_id),
+        scenario.scenario_id.clone(),
+        scenario_ttl,
+    )
+    .await
+}
+
+pub async fn get_scenario(
+    state: &SessionState,
+    scenario_id: &str,
+) -> types::DummyConnectorResult<types::DummyConnectorScenario> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorScenario>(
+            scenario_id,
+            "DummyConnectorScenario",
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::ScenarioNotFound)
+}
+
+pub async fn delete_scenario(
+    state: &SessionState,
+    scenario: &types::DummyConnectorScenario,
+) -> types::DummyConnectorResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let lookup_key = scenario
+        .definition
+        .trigger
+        .get_lookup_key(&scenario.merchant_id);
+    // A newer scenario registered with the same trigger replaces the lookup entry, which must be
+    // left untouched
+    let registered_scenario_id = redis_conn
+        .get_and_deserialize_key::<String>(&lookup_key, "String")
+        .await
+        .ok();
+    if registered_scenario_id.as_ref() == Some(&scenario.scenario_id) {
+        redis_conn
+            .delete_key(&lookup_key)
+            .await
+            .change_context(errors::DummyConnectorErrors::InternalServerError)
+            .attach_printable("Failed to delete the scenario lookup key")?;
+    }
+
+    redis_conn
+        .delete_key(&scenario.scenario_id)
+        .await
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to delete the scenario")?;
+    Ok(())
+}
+
+/// Retrieves a scenario on behalf of a merchant, scenarios of other merchants are reported as not
+/// found.
+pub async fn get_merchant_scenario(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    scenario_id: &str,
+) -> types::DummyConnectorResult<types::DummyConnectorScenario> {
+    let scenario = get_scenario(state, scenario_id).await?;
+    if &scenario.merchant_id != merchant_id {
+        Err(report!(errors::DummyConnectorErrors::ScenarioNotFound))?
+    }
+    Ok(scenario)
+}
+
+/// Finds the scenario the merchant of the payment registered for its card number or one of its
+/// metadata values, card numbers taking precedence.
+pub async fn find_scenario_for_payment(
+    state: &SessionState,
+    payment_request: &types::DummyConnectorPaymentRequest,
+) -> types::DummyConnectorResult<Option<types::DummyConnectorScenario>> {
+    let Some(merchant_id) = payment_request.merchant_id.as_ref() else {
+        return Ok(None);
+    };
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    for trigger in payment_request.get_scenario_triggers() {
+        let scenario_id = match redis_conn
+            .get_and_deserialize_key::<String>(&trigger.get_lookup_key(merchant_id), "String")
+            .await
+        {
+            Ok(scenario_id) => scenario_id,
+            Err(error) => match error.current_context() {
+                redis_interface::errors::RedisError::NotFound => continue,
+                _ => Err(error
+                    .change_context(errors::DummyConnectorErrors::InternalServerError)
+                    .attach_printable("Failed to look up the scenario for the payment"))?,
+            },
+        };
+
+        match get_scenario(state, &scenario_id).await {
+            Ok(scenario) => return Ok(Some(scenario)),
+            // The lookup entry can outlive a deleted scenario
+            Err(error) => router_env::logger::warn!(?error, "Scenario lookup entry is stale"),
+        }
+    }
+    Ok(None)
+}
+
+pub async fn send_webhook(
+    state: &SessionState,
+    webhook_url: &str,
+    webhook: types::DummyConnectorWebhook,
+) -> types::DummyConnectorResult<()> {
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url)
+        .attach_default_headers()
+        .header(headers::CONTENT_TYPE, "application/json")
+        .set_body(RequestContent::Json(Box::new(webhook)))
+        .build();
+
+    services::send_request(state, request, None)
+        .await
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to send the dummy connector webhook")?;
+    Ok(())
+}
+
 pub fn get_authorize_page(
     payment_data: types::DummyConnectorPaymentData,
     return_url: String,
@@ -349,4 +498,292 @@ impl types::DummyConnectorPaymentData {
             .payment_method_data
             .build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
     }
+
+    pub fn process_payment_attempt_with_scenario(
+        state: &SessionState,
+        payment_attempt: types::DummyConnectorPaymentAttempt,
+        scenario: &types::DummyConnectorScenario,
+    ) -> Self {
+        let payment_data = if scenario.definition.three_ds {
+            let redirect_url = format!(
+                "{}/dummy-connector/authorize/{}",
+                state.base_url, payment_attempt.attempt_id
+            );
+            let return_url = payment_attempt.payment_request.return_url.clone();
+            payment_attempt.build_payment_data(
+                types::DummyConnectorStatus::Processing,
+                Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
+                return_url,
+            )
+        } else {
+            scenario.resolve_payment(payment_attempt.build_payment_data(
+                types::DummyConnectorStatus::Processing,
+                None,
+                None,
+            ))
+        };
+
+        Self {
+            scenario_id: Some(scenario.scenario_id.clone()),
+            ..payment_data
+        }
+    }
+
+    /// Marks the payment as captured, partially if `capture_amount` is lower than the payment
+    /// amount.
+    pub fn capture(self, capture_amount: Option<i64>) -> Self {
+        let amount_captured = capture_amount
+            .map(|capture_amount| capture_amount.min(self.amount))
+            .unwrap_or(self.amount);
+        let status = if amount_captured < self.amount {
+            types::DummyConnectorStatus::PartiallyCaptured
+        } else {
+            types::DummyConnectorStatus::Succeeded
+        };
+
+        Self {
+            status,
+            amount_captured: Some(amount_captured),
+            eligible_amount: amount_captured,
+            next_action: None,
+            ..self
+        }
+    }
+}
+
+impl types::DummyConnectorScenario {
+    /// Validates the scenario, `merchant_webhook_urls` being the webhook endpoints configured in the
+    /// business profiles of the merchant, the only URLs the scenario may send webhooks to.
+    pub fn validate(&self, merchant_webhook_urls: &[String]) -> types::DummyConnectorResult<()> {
+        let definition = &self.definition;
+        if definition
+            .latency
+            .is_some_and(|latency| latency > consts::MAX_SCENARIO_LATENCY_IN_MILLIS)
+        {
+            Err(report!(errors::DummyConnectorErrors::InvalidScenario {
+                message: "latency cannot exceed 30000 milliseconds",
+            }))?
+        }
+        if definition.webhook_url.as_ref().is_some_and(|webhook_url| {
+            !merchant_webhook_urls
+                .iter()
+                .any(|merchant_webhook_url| merchant_webhook_url == webhook_url)
+        }) {
+            Err(report!(errors::DummyConnectorErrors::InvalidScenario {
+                message: "webhook_url must be a webhook endpoint configured for the merchant",
+            }))?
+        }
+        if definition
+            .capture_amount
+            .is_some_and(|capture_amount| capture_amount <= 0)
+        {
+            Err(report!(errors::DummyConnectorErrors::InvalidScenario {
+                message: "capture_amount must be greater than zero",
+            }))?
+        }
+        if let Some(async_status) = &definition.async_status {
+            if !matches!(
+                async_status.status,
+                types::DummyConnectorStatus::Succeeded | types::DummyConnectorStatus::Failed
+            ) {
+                Err(report!(errors::DummyConnectorErrors::InvalidScenario {
+                    message: "async_status can only move the payment to succeeded or failed",
+                }))?
+            }
+            if definition.decline.is_some() {
+                Err(report!(errors::DummyConnectorErrors::InvalidScenario {
+                    message: "async_status cannot be combined with decline",
+                }))?
+            }
+            if async_status.delay > consts::MAX_SCENARIO_ASYNC_STATUS_DELAY_IN_SECS {
+                Err(report!(errors::DummyConnectorErrors::InvalidScenario {
+                    message: "async_status delay cannot exceed 600 seconds",
+                }))?
+            }
+        }
+        Ok(())
+    }
+
+    /// Outcome of the payment once the customer is authenticated, or right away for payments that
+    /// don't require authentication.
+    pub fn resolve_payment(
+        &self,
+        payment_data: types::DummyConnectorPaymentData,
+    ) -> types::DummyConnectorPaymentData {
+        let definition = &self.definition;
+        if let Some(error) = &definition.decline {
+            types::DummyConnectorPaymentData {
+                status: types::DummyConnectorStatus::Failed,
+                error: Some(error.clone()),
+                next_action: None,
+                ..payment_data
+            }
+        } else if definition.async_status.is_some() {
+            types::DummyConnectorPaymentData {
+                status: types::DummyConnectorStatus::Pending,
+                next_action: None,
+                ..payment_data
+            }
+        } else {
+            payment_data.capture(definition.capture_amount)
+        }
+    }
+}
+
+impl types::DummyConnectorPaymentRequest {
+    pub fn get_scenario_triggers(&self) -> Vec<types::DummyConnectorScenarioTrigger> {
+        let card_trigger = match &self.payment_method_data {
+            types::DummyConnectorPaymentMethodData::Card(card) => {
+                Some(types::DummyConnectorScenarioTrigger::CardNumber {
+                    card_number: card.number.clone(),
+                })
+            }
+            types::DummyConnectorPaymentMethodData::Wallet(_)
+            | types::DummyConnectorPaymentMethodData::PayLater(_) => None,
+        };
+        let metadata_triggers = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.as_object())
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, value)| {
+                value
+                    .as_str()
+                    .map(|value| types::DummyConnectorScenarioTrigger::Metadata {
+                        key: key.to_owned(),
+                        value: value.to_owned(),
+                    })
+            });
+
+        card_trigger.into_iter().chain(metadata_triggers).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const MERCHANT_WEBHOOK_URL: &str = "https://merchant.example.com/webhooks";
+
+    fn get_merchant_id(merchant_id: &'static str) -> common_utils::id_type::MerchantId {
+        common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(merchant_id)).unwrap()
+    }
+
+    fn build_scenario(definition: serde_json::Value) -> types::DummyConnectorScenario {
+        types::DummyConnectorScenario::new(
+            get_merchant_id("merchant_1"),
+            serde_json::from_value(definition).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_scenario_latency_and_async_delay_are_capped() {
+        let merchant_webhook_urls = [MERCHANT_WEBHOOK_URL.to_string()];
+        let trigger = serde_json::json!({"type": "card_number", "card_number": "4242424242424242"});
+
+        assert!(build_scenario(serde_json::json!({
+            "trigger": trigger,
+            "latency": consts::MAX_SCENARIO_LATENCY_IN_MILLIS,
+            "async_status": {
+                "status": "succeeded",
+                "delay": consts::MAX_SCENARIO_ASYNC_STATUS_DELAY_IN_SECS,
+            },
+        }))
+        .validate(&merchant_webhook_urls)
+        .is_ok());
+        assert!(build_scenario(serde_json::json!({
+            "trigger": trigger,
+            "latency": consts::MAX_SCENARIO_LATENCY_IN_MILLIS + 1,
+        }))
+        .validate(&merchant_webhook_urls)
+        .is_err());
+        assert!(build_scenario(serde_json::json!({
+            "trigger": trigger,
+            "async_status": {
+                "status": "succeeded",
+                "delay": consts::MAX_SCENARIO_ASYNC_STATUS_DELAY_IN_SECS + 1,
+            },
+        }))
+        .validate(&merchant_webhook_urls)
+        .is_err());
+    }
+
+    #[test]
+    fn test_scenario_webhook_url_must_be_configured_for_the_merchant() {
+        let trigger =
+            serde_json::json!({"type": "metadata", "key": "scenario", "value": "decline"});
+        let scenario = build_scenario(serde_json::json!({
+            "trigger": trigger,
+            "webhook_url": MERCHANT_WEBHOOK_URL,
+        }));
+
+        assert!(scenario
+            .validate(&[MERCHANT_WEBHOOK_URL.to_string()])
+            .is_ok());
+        assert!(scenario.validate(&[]).is_err());
+
+        let scenario = build_scenario(serde_json::json!({
+            "trigger": trigger,
+            "webhook_url": "http://169.254.169.254/latest/meta-data",
+        }));
+        assert!(scenario
+            .validate(&[MERCHANT_WEBHOOK_URL.to_string()])
+            .is_err());
+    }
+
+    #[test]
+    fn test_scenario_lookup_keys_are_scoped_by_merchant() {
+        let trigger = types::DummyConnectorScenarioTrigger::Metadata {
+            key: "scenario".to_string(),
+            value: "decline".to_string(),
+        };
+
+        assert_eq!(
+            trigger.get_lookup_key(&get_merchant_id("merchant_1")),
+            "dummy_scenario_lookup_merchant_1_metadata_scenario_decline"
+        );
+        assert_ne!(
+            trigger.get_lookup_key(&get_merchant_id("merchant_1")),
+            trigger.get_lookup_key(&get_merchant_id("merchant_2"))
+        );
+    }
+
+    #[test]
+    fn test_get_scenario_triggers() {
+        let payment_request: types::DummyConnectorPaymentRequest =
+            serde_json::from_value(serde_json::json!({
+                "amount": 1000,
+                "currency": "USD",
+                "payment_method_data": {
+                    "card": {
+                        "name": "John Doe",
+                        "number": "4242424242424242",
+                        "expiry_month": "12",
+                        "expiry_year": "2030",
+                        "cvc": "123",
+                    },
+                },
+                "return_url": null,
+                "connector": "phonypay",
+                "metadata": {"scenario": "decline", "attempt": 1},
+                "merchant_id": "merchant_1",
+            }))
+            .unwrap();
+        let merchant_id = get_merchant_id("merchant_1");
+        let lookup_keys = payment_request
+            .get_scenario_triggers()
+            .iter()
+            .map(|trigger| trigger.get_lookup_key(&merchant_id))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lookup_keys,
+            vec![
+                "dummy_scenario_lookup_merchant_1_card_4242424242424242".to_string(),
+                "dummy_scenario_lookup_merchant_1_metadata_scenario_decline".to_string(),
+            ]
+        );
+    }
 }
@@ -1,9 +1,10 @@
 use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::fraud_check as frm_api_types;
 use router_env::Flow;
 
 use crate::{
     core::{api_locking, fraud_check as frm_core},
-    services::{self, api},
+    services::{self, api, authentication as auth, authorization::permissions::Permission},
     AppState,
 };
 
@@ -26,3 +27,94 @@ pub async fn frm_fulfillment(
     ))
     .await
 }
+
+pub async fn upsert_native_frm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<frm_api_types::FrmRulesRequest>,
+) -> HttpResponse {
+    let flow = Flow::FrmRulesUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, _| {
+            frm_core::native::upsert_native_frm_rules(state, auth.merchant_account, body)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn retrieve_native_frm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::FrmRulesRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, (), _| {
+            frm_core::native::retrieve_native_frm_rules(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_native_frm_rules(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::FrmRulesDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, (), _| {
+            frm_core::native::delete_native_frm_rules(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_frm_review_queue(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<frm_api_types::FrmReviewQueueConstraints>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewQueueList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            frm_core::native::list_review_queue(state, auth.merchant_account, constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
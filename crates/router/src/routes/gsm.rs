@@ -1,10 +1,14 @@
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, Responder};
 use api_models::gsm as gsm_api_types;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, gsm},
+    core::{
+        api_locking,
+        gsm::{self, bulk, history},
+    },
     services::{api, authentication as auth},
 };
 
@@ -159,3 +163,194 @@ pub async fn delete_gsm_rule(
     ))
     .await
 }
+
+/// Gsm - Bulk Import
+///
+/// To create or update Gsm Rules in bulk
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(
+        content = GsmBulkImportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules validated or imported", body = GsmBulkImportResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmBulkImportRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRulesImport;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| bulk::import_gsm_rules(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Bulk Import from CSV
+///
+/// To create or update Gsm Rules in bulk from a CSV file having the columns of the create request
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules_csv(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<bulk::GsmImportForm>,
+) -> impl Responder {
+    let flow = Flow::GsmRulesImport;
+    let payload = match bulk::get_gsm_import_request(form) {
+        Ok(payload) => payload,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| bulk::import_gsm_rules(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Export
+///
+/// To export Gsm Rules, optionally filtered by connector and flow
+#[utoipa::path(
+    get,
+    path = "/gsm/export",
+    params(
+        ("connector" = Option<String>, Query, description = "Only export the rules of this connector"),
+        ("flow" = Option<String>, Query, description = "Only export the rules of this flow"),
+        ("format" = Option<GsmExportFormat>, Query, description = "Format of the export, json or csv"),
+    ),
+    responses(
+        (status = 200, description = "Gsm rules exported", body = GsmExportResponse),
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesExport))]
+pub async fn export_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<gsm_api_types::GsmExportRequest>,
+) -> impl Responder {
+    let payload = query_payload.into_inner();
+
+    let flow = Flow::GsmRulesExport;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| bulk::export_gsm_rules(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - History
+///
+/// To list the changes made to Gsm Rules, latest first
+#[utoipa::path(
+    get,
+    path = "/gsm/history",
+    params(
+        ("connector" = Option<String>, Query, description = "Only list the changes of this connector"),
+        ("flow" = Option<String>, Query, description = "Only list the changes of this flow"),
+        ("change_id" = Option<String>, Query, description = "Only list the entries of this change"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of entries to be returned"),
+    ),
+    responses(
+        (status = 200, description = "Gsm rule history listed", body = GsmHistoryListResponse),
+    ),
+    tag = "Gsm",
+    operation_id = "List Gsm Rule History",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRuleHistoryList))]
+pub async fn list_gsm_rule_history(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<gsm_api_types::GsmHistoryListRequest>,
+) -> impl Responder {
+    let payload = query_payload.into_inner();
+
+    let flow = Flow::GsmRuleHistoryList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| history::list_gsm_rule_history(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Revert
+///
+/// To restore the Gsm Rules touched by a change to the state they had before it
+#[utoipa::path(
+    post,
+    path = "/gsm/history/{change_id}/revert",
+    params(
+        ("change_id" = String, Path, description = "The change to be reverted"),
+    ),
+    request_body(
+        content = GsmRevertRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm change reverted", body = GsmRevertResponse),
+        (status = 404, description = "Gsm change not found")
+    ),
+    tag = "Gsm",
+    operation_id = "Revert Gsm Rule Change",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRuleChangeRevert))]
+pub async fn revert_gsm_rule_change(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<gsm_api_types::GsmRevertRequest>,
+) -> impl Responder {
+    let mut payload = json_payload.into_inner();
+    payload.change_id = path.into_inner();
+
+    let flow = Flow::GsmRuleChangeRevert;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| history::revert_gsm_rule_change(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
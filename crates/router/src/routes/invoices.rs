@@ -0,0 +1,270 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::invoices as invoice_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, invoices},
+    services::{api, authentication as auth},
+};
+
+/// Invoices - Create
+///
+/// Create a draft invoice for a customer
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceCreate))]
+pub async fn invoice_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<invoice_types::InvoiceCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::InvoiceCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            invoices::create_invoice(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - List
+///
+/// List the invoices of the merchant, most recent first
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceList))]
+pub async fn invoice_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<invoice_types::InvoiceListConstraints>,
+) -> impl Responder {
+    let flow = Flow::InvoiceList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth, req, _| invoices::list_invoices(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Retrieve
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceRetrieve))]
+pub async fn invoice_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::InvoiceRetrieve;
+    let payload = invoice_types::InvoiceId {
+        invoice_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| invoices::retrieve_invoice(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Update
+///
+/// Update a draft invoice, replacing its line items when provided
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceUpdate))]
+pub async fn invoice_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<invoice_types::InvoiceUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::InvoiceUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.invoice_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| invoices::update_invoice(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Finalize
+///
+/// Assign the next invoice number to a draft invoice and create the payment link through which
+/// the customer pays it
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceFinalize))]
+pub async fn invoice_finalize(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::InvoiceFinalize;
+    let payload = invoice_types::InvoiceId {
+        invoice_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, req_state| {
+            invoices::finalize_invoice(state, req_state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Void
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceVoid))]
+pub async fn invoice_void(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::InvoiceVoid;
+    let payload = invoice_types::InvoiceId {
+        invoice_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            invoices::void_invoice(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Mark Uncollectible
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceMarkUncollectible))]
+pub async fn invoice_mark_uncollectible(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::InvoiceMarkUncollectible;
+    let payload = invoice_types::InvoiceId {
+        invoice_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            invoices::mark_invoice_uncollectible(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Record Payment
+///
+/// Record a payment of the invoice received out of band, such as a bank transfer
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceRecordPayment))]
+pub async fn invoice_record_payment(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<invoice_types::InvoiceRecordPaymentRequest>,
+) -> impl Responder {
+    let flow = Flow::InvoiceRecordPayment;
+    let mut payload = json_payload.into_inner();
+    payload.invoice_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            invoices::record_invoice_payment(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Create Payment Link
+///
+/// Create a new payment link for the amount remaining to be paid on the invoice
+#[instrument(skip_all, fields(flow = ?Flow::InvoicePaymentLinkCreate))]
+pub async fn invoice_payment_link_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::InvoicePaymentLinkCreate;
+    let payload = invoice_types::InvoiceId {
+        invoice_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, req_state| {
+            invoices::create_invoice_payment_link(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Number Sequence
+///
+/// Configure the prefix and the next number of the invoice numbers of a business profile
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceNumberSequenceUpsert))]
+pub async fn invoice_number_sequence_upsert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<invoice_types::InvoiceNumberSequenceRequest>,
+) -> impl Responder {
+    let flow = Flow::InvoiceNumberSequenceUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            invoices::upsert_invoice_number_sequence(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
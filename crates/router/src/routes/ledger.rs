@@ -0,0 +1,102 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::{enums as api_enums, ledger as ledger_types};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, ledger},
+    services::{api, authentication as auth},
+};
+
+/// Ledger - Balances
+///
+/// Retrieve the balances of the accounts of the ledger in a currency
+#[instrument(skip_all, fields(flow = ?Flow::LedgerBalancesRetrieve))]
+pub async fn ledger_balances(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<ledger_types::LedgerBalancesRequest>,
+) -> impl Responder {
+    let flow = Flow::LedgerBalancesRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth, req, _| ledger::retrieve_ledger_balances(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Ledger - Account Statement
+///
+/// Retrieve the postings to an account of the ledger over a period, with its running balance
+#[instrument(skip_all, fields(flow = ?Flow::LedgerStatementRetrieve))]
+pub async fn ledger_statement(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::LedgerAccount>,
+    payload: web::Query<ledger_types::LedgerStatementRequest>,
+) -> impl Responder {
+    let flow = Flow::LedgerStatementRetrieve;
+    let mut payload = payload.into_inner();
+    payload.account = Some(path.into_inner());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| ledger::retrieve_ledger_statement(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Ledger - List Entries
+///
+/// List the journal entries of the merchant along with their postings, most recent first
+#[instrument(skip_all, fields(flow = ?Flow::LedgerEntryList))]
+pub async fn ledger_entry_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<ledger_types::LedgerEntryListConstraints>,
+) -> impl Responder {
+    let flow = Flow::LedgerEntryList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth, req, _| ledger::list_ledger_entries(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Ledger - Rebuild
+///
+/// Schedule the ledger of a merchant to be recorded again from its payments, refunds, disputes
+/// and payouts
+#[instrument(skip_all, fields(flow = ?Flow::LedgerRebuild))]
+pub async fn ledger_rebuild(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> impl Responder {
+    let flow = Flow::LedgerRebuild;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| ledger::rebuild_ledger(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -37,6 +37,11 @@ pub enum ApiIdentifier {
     Recon,
     Poll,
     ApplePayCertificatesMigration,
+    Scim,
+    Frm,
+    DataRetention,
+    Invoices,
+    Ledger,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -47,6 +52,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
+            | Flow::MerchantKeyRotationStart
+            | Flow::MerchantKeyRotationList
+            | Flow::MerchantKeyRotationRetrieve
             | Flow::MerchantAccountList => Self::MerchantAccount,
 
             Flow::OrganizationCreate | Flow::OrganizationRetrieve | Flow::OrganizationUpdate => {
@@ -91,7 +99,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
-            | Flow::CustomersList => Self::Customers,
+            | Flow::CustomersList
+            | Flow::DataSubjectRequestCreate
+            | Flow::DataSubjectRequestList
+            | Flow::DataSubjectRequestRetrieve
+            | Flow::DataSubjectArchiveDownload => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
 
@@ -132,7 +144,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsAuthorize
             | Flow::GetExtendedCardInfo
             | Flow::PaymentsCompleteAuthorize
-            | Flow::PaymentsManualUpdate => Self::Payments,
+            | Flow::PaymentsManualUpdate
+            | Flow::ScaExemptionConfigUpsert
+            | Flow::ScaExemptionConfigRetrieve
+            | Flow::SplitPaymentsReport => Self::Payments,
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
@@ -143,7 +158,13 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
-            | Flow::PayoutLinkInitiate => Self::Payouts,
+            | Flow::PayoutLinkInitiate
+            | Flow::PayoutBatchCreate
+            | Flow::PayoutBatchApprove
+            | Flow::PayoutBatchReject
+            | Flow::PayoutBatchRetrieve
+            | Flow::PayoutBatchList
+            | Flow::PayoutBatchReport => Self::Payouts,
 
             Flow::RefundsCreate
             | Flow::RefundsRetrieve
@@ -151,13 +172,26 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsUpdate
             | Flow::RefundsList
             | Flow::RefundsFilters
-            | Flow::RefundsManualUpdate => Self::Refunds,
+            | Flow::RefundsManualUpdate
+            | Flow::RefundBatchCreate
+            | Flow::RefundBatchRetrieve
+            | Flow::RefundBatchList
+            | Flow::RefundBatchDownload => Self::Refunds,
+
+            Flow::FrmRulesUpsert
+            | Flow::FrmRulesRetrieve
+            | Flow::FrmRulesDelete
+            | Flow::FrmReviewQueueList => Self::Frm,
 
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookSigningKeyCreate
+            | Flow::WebhookSigningKeyList
+            | Flow::WebhookSigningKeyRevoke
+            | Flow::WebhookSigningJwksRetrieve => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
@@ -167,12 +201,20 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::DisputesRetrieve
             | Flow::DisputesList
+            | Flow::DisputeDeadlinesList
+            | Flow::DisputeDeadlinePolicyUpsert
+            | Flow::DisputeDeadlinePolicyRetrieve
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
+            | Flow::DisputeEvidenceDraftCreate
+            | Flow::DisputeEvidenceDraftSubmit
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
-            Flow::CardsInfo => Self::CardsInfo,
+            Flow::CardsInfo
+            | Flow::CardBinRangesUpload
+            | Flow::CardBinRangesList
+            | Flow::CardBinRangeDelete => Self::CardsInfo,
 
             Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
 
@@ -190,7 +232,16 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::PaymentLinkQrCodeRetrieve
+            | Flow::PaymentLinkInvoiceRetrieve
+            | Flow::ReusablePaymentLinkCreate
+            | Flow::ReusablePaymentLinkList
+            | Flow::ReusablePaymentLinkRetrieve
+            | Flow::ReusablePaymentLinkUpdate
+            | Flow::ReusablePaymentLinkSalesSummary
+            | Flow::ReusablePaymentLinkVisit
+            | Flow::ReusablePaymentLinkInitiate => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
@@ -198,7 +249,33 @@ impl From<Flow> for ApiIdentifier {
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
-            | Flow::GsmRuleDelete => Self::Gsm,
+            | Flow::GsmRuleDelete
+            | Flow::GsmRulesImport
+            | Flow::GsmRulesExport
+            | Flow::GsmRuleHistoryList
+            | Flow::GsmRuleChangeRevert => Self::Gsm,
+
+            Flow::DataRetentionPolicyUpsert
+            | Flow::DataRetentionPolicyList
+            | Flow::DataRetentionPolicyDelete
+            | Flow::DataRetentionArchiveList
+            | Flow::DataRetentionArchiveRestore => Self::DataRetention,
+
+            Flow::InvoiceCreate
+            | Flow::InvoiceList
+            | Flow::InvoiceRetrieve
+            | Flow::InvoiceUpdate
+            | Flow::InvoiceFinalize
+            | Flow::InvoiceVoid
+            | Flow::InvoiceMarkUncollectible
+            | Flow::InvoiceRecordPayment
+            | Flow::InvoicePaymentLinkCreate
+            | Flow::InvoiceNumberSequenceUpsert => Self::Invoices,
+
+            Flow::LedgerBalancesRetrieve
+            | Flow::LedgerStatementRetrieve
+            | Flow::LedgerEntryList
+            | Flow::LedgerRebuild => Self::Ledger,
 
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
 
@@ -256,6 +333,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::TransferOrgOwnership
             | Flow::CreateRole
             | Flow::UpdateRole
+            | Flow::DeleteRole
             | Flow::UserFromEmail => Self::UserRole,
 
             Flow::GetActionUrl | Flow::SyncOnboardingStatus | Flow::ResetTrackingId => {
@@ -268,6 +346,18 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ReconVerifyToken => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
+
+            Flow::ScimUserCreate
+            | Flow::ScimUserRetrieve
+            | Flow::ScimUserList
+            | Flow::ScimUserReplace
+            | Flow::ScimUserPatch
+            | Flow::ScimUserDelete
+            | Flow::ScimGroupList
+            | Flow::ScimGroupRetrieve
+            | Flow::ScimGroupPatch
+            | Flow::ScimTokenCreate
+            | Flow::ScimTokenRevoke => Self::Scim,
         }
     }
 }
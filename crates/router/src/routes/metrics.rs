@@ -108,6 +108,10 @@ counter_metric!(AUTO_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
 
+// Metrics for SCA exemptions
+counter_metric!(SCA_EXEMPTION_APPLIED_COUNT, GLOBAL_METER);
+counter_metric!(SCA_EXEMPTION_CHALLENGE_FALLBACK_COUNT, GLOBAL_METER);
+
 // Metrics for Payout Auto Retries
 counter_metric!(AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_PAYOUT_RETRY_GSM_MISS_COUNT, GLOBAL_METER);
@@ -161,6 +161,65 @@ pub async fn payments_link_list(
     .await
 }
 
+/// Payment Link - QR Code
+///
+/// To render a payment link as a QR code, for customers to scan at the counter
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkQrCodeRetrieve))]
+pub async fn payment_link_qr_code_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<api_models::payments::PaymentLinkQrCodeRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkQrCodeRetrieve;
+    let mut payload = query_payload.into_inner();
+    payload.payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            invoice::retrieve_payment_link_qr_code(state, auth.merchant_account, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link - Invoice
+///
+/// To render a payment link as a print-ready invoice carrying the QR code of the link
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkInvoiceRetrieve))]
+pub async fn payment_link_invoice_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<api_models::payments::PaymentLinkInvoiceRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkInvoiceRetrieve;
+    let mut payload = query_payload.into_inner();
+    payload.payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            invoice::retrieve_payment_link_invoice(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn payment_link_status(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -194,3 +253,200 @@ pub async fn payment_link_status(
     ))
     .await
 }
+
+/// Reusable Payment Link - Create
+///
+/// Create a payment link that creates a new payment for every customer visiting it
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkCreate))]
+pub async fn reusable_payment_link_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::reusable_payment_link::ReusablePaymentLinkCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            reusable::create_reusable_payment_link(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - List
+///
+/// List the reusable payment links of the merchant
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkList))]
+pub async fn reusable_payment_link_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<api_models::reusable_payment_link::ReusablePaymentLinkListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth, payload, _| {
+            reusable::list_reusable_payment_links(state, auth.merchant_account, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - Retrieve
+///
+/// Retrieve a reusable payment link along with its line items
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkRetrieve))]
+pub async fn reusable_payment_link_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkRetrieve;
+    let payload = api_models::reusable_payment_link::ReusablePaymentLinkId {
+        reusable_link_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            reusable::retrieve_reusable_payment_link(state, auth.merchant_account, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - Update
+///
+/// Deactivate or reactivate a reusable payment link, or change its limits
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkUpdate))]
+pub async fn reusable_payment_link_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::reusable_payment_link::ReusablePaymentLinkUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.reusable_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            reusable::update_reusable_payment_link(state, auth.merchant_account, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Reusable Payment Link - Sales Summary
+///
+/// Summarize the payments and items sold through a reusable payment link
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkSalesSummary))]
+pub async fn reusable_payment_link_sales_summary(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkSalesSummary;
+    let payload = api_models::reusable_payment_link::ReusablePaymentLinkId {
+        reusable_link_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            reusable::retrieve_reusable_payment_link_sales_summary(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn visit_reusable_payment_link(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    query_payload: web::Query<api_models::reusable_payment_link::ReusablePaymentLinkVisitParams>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkVisit;
+    let (merchant_id, reusable_link_id) = path.into_inner();
+    let payload = api_models::reusable_payment_link::ReusablePaymentLinkVisitRequest {
+        merchant_id: merchant_id.clone(),
+        reusable_link_id,
+        params: query_payload.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _auth, req, _| reusable::visit_reusable_payment_link(state, req),
+        &crate::services::authentication::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn initiate_reusable_payment_link(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    form_payload: web::Form<api_models::reusable_payment_link::ReusablePaymentLinkVisitParams>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkInitiate;
+    let (merchant_id, reusable_link_id) = path.into_inner();
+    let payload = api_models::reusable_payment_link::ReusablePaymentLinkVisitRequest {
+        merchant_id: merchant_id.clone(),
+        reusable_link_id,
+        params: form_payload.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, req_state| {
+            reusable::initiate_reusable_payment_link(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &crate::services::authentication::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
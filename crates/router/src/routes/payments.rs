@@ -1069,6 +1069,130 @@ pub async fn get_payment_filters(
     .await
 }
 
+/// Payments - Update SCA Exemption Config
+///
+/// Sets the conditions under which SCA exemptions are applied to the card payments of the merchant
+#[utoipa::path(
+    post,
+    path = "/payments/sca_exemption_config",
+    request_body = ScaExemptionConfig,
+    responses(
+        (status = 200, description = "The SCA exemption config was updated successfully", body = ScaExemptionConfig),
+        (status = 400, description = "Invalid SCA exemption config")
+    ),
+    tag = "Payments",
+    operation_id = "Update SCA Exemption Config",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ScaExemptionConfigUpsert))]
+#[cfg(feature = "olap")]
+pub async fn upsert_sca_exemption_config(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::ScaExemptionConfig>,
+) -> impl Responder {
+    let flow = Flow::ScaExemptionConfigUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::sca_exemption::upsert_sca_exemption_config(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payments - Retrieve SCA Exemption Config
+#[utoipa::path(
+    get,
+    path = "/payments/sca_exemption_config",
+    responses(
+        (status = 200, description = "The SCA exemption config was retrieved successfully", body = ScaExemptionConfig),
+        (status = 404, description = "SCA exemption config not found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve SCA Exemption Config",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ScaExemptionConfigRetrieve))]
+#[cfg(feature = "olap")]
+pub async fn retrieve_sca_exemption_config(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+) -> impl Responder {
+    let flow = Flow::ScaExemptionConfigRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            payments::sca_exemption::retrieve_sca_exemption_config(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payments - Split Payments Report
+///
+/// Reports the amounts allocated to each recipient of the split payments created in the time range
+#[utoipa::path(
+    post,
+    path = "/payments/split_report",
+    request_body = SplitPaymentsReportRequest,
+    responses(
+        (status = 200, description = "The split payments report was generated successfully", body = SplitPaymentsReportResponse),
+        (status = 400, description = "Invalid report constraints")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve Split Payments Report",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SplitPaymentsReport))]
+#[cfg(feature = "olap")]
+pub async fn get_split_payments_report(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::SplitPaymentsReportRequest>,
+) -> impl Responder {
+    let flow = Flow::SplitPaymentsReport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::split_payments::get_split_payments_report(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "oltp")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsApprove, payment_id))]
 // #[post("/{payment_id}/approve")]
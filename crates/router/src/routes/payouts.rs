@@ -279,6 +279,178 @@ pub async fn payouts_list_available_filters(
     .await
 }
 
+/// Payouts - Batch create
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchCreate))]
+pub async fn payout_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::payouts::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, _| {
+            batch::create_payout_batch(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                user_id,
+                req,
+            )
+        },
+        &auth::JWTAuth(Permission::PayoutWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch approve
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchApprove))]
+pub async fn payout_batch_approve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payouts::PayoutBatchApproveRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchApprove;
+    let locale = get_header_value_by_key(ACCEPT_LANGUAGE.into(), req.headers())
+        .ok()
+        .flatten()
+        .map(|val| val.to_string())
+        .unwrap_or(consts::DEFAULT_LOCALE.to_string());
+    let mut payload = json_payload.into_inner();
+    payload.batch_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, _| {
+            batch::approve_payout_batch(state, auth.merchant_account, user_id, req, &locale)
+        },
+        &auth::JWTAuth(Permission::PayoutApprove),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch reject
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchReject))]
+pub async fn payout_batch_reject(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payouts::PayoutBatchRejectRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchReject;
+    let mut payload = json_payload.into_inner();
+    payload.batch_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, _| {
+            batch::reject_payout_batch(state, auth.merchant_account, user_id, req)
+        },
+        &auth::JWTAuth(Permission::PayoutApprove),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch retrieve
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchRetrieve))]
+pub async fn payout_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchRetrieve;
+    let payload = api_models::payouts::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            batch::retrieve_payout_batch(state, auth.merchant_account, req.batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch list
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchList))]
+pub async fn payout_batch_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<api_models::payouts::PayoutBatchListRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth, req, _| batch::list_payout_batches(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch report
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchReport))]
+pub async fn payout_batch_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchReport;
+    let payload = api_models::payouts::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            batch::download_payout_batch_report(state, auth.merchant_account, req.batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
@@ -1,3 +1,5 @@
+#[cfg(feature = "olap")]
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
@@ -334,3 +336,121 @@ pub async fn refunds_manual_update(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundBatchCreate))]
+#[cfg(feature = "olap")]
+pub async fn refund_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<batch::RefundBatchForm>,
+) -> HttpResponse {
+    let flow = Flow::RefundBatchCreate;
+    let refund_batch_request = match batch::get_refund_batch_records(form) {
+        Ok(refund_batch_request) => refund_batch_request,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_batch_request,
+        |state, auth, req, _| {
+            batch::create_refund_batch(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RefundWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundBatchRetrieve))]
+#[cfg(feature = "olap")]
+pub async fn refund_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundBatchRetrieve;
+    let payload = api_models::refunds::RefundBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            batch::retrieve_refund_batch(state, auth.merchant_account, req.batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RefundRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundBatchList))]
+#[cfg(feature = "olap")]
+pub async fn refund_batch_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<api_models::refunds::RefundBatchListRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundBatchList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth, req, _| batch::list_refund_batches(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RefundRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundBatchDownload))]
+#[cfg(feature = "olap")]
+pub async fn refund_batch_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundBatchDownload;
+    let payload = api_models::refunds::RefundBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            batch::download_refund_batch_result(state, auth.merchant_account, req.batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RefundRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
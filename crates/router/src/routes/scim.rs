@@ -0,0 +1,237 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::scim as scim_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, scim},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimUserCreate))]
+pub async fn create_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            scim::create_user(state, auth.merchant_account, req)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimUserRetrieve))]
+pub async fn retrieve_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserRetrieve;
+    let user_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            scim::retrieve_user(state, auth.merchant_account, user_id.clone())
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimUserList))]
+pub async fn list_scim_users(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            scim::list_users(state, auth.merchant_account, query)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimUserReplace))]
+pub async fn replace_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserReplace;
+    let user_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            scim::replace_user(state, auth.merchant_account, user_id.clone(), req)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimUserPatch))]
+pub async fn patch_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserPatch;
+    let user_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            scim::patch_user(state, auth.merchant_account, user_id.clone(), req)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimUserDelete))]
+pub async fn delete_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimUserDelete;
+    let user_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            scim::delete_user(state, auth.merchant_account, user_id.clone())
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimGroupList))]
+pub async fn list_scim_groups(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query, _| {
+            scim::list_groups(state, auth.merchant_account, query)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimGroupRetrieve))]
+pub async fn retrieve_scim_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupRetrieve;
+    let group_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            scim::retrieve_group(state, auth.merchant_account, group_id.clone())
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimGroupPatch))]
+pub async fn patch_scim_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimGroupPatch;
+    let group_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            scim::patch_group(state, auth.merchant_account, group_id.clone(), req)
+        },
+        &auth::ScimAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimTokenCreate))]
+pub async fn create_scim_token(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ScimTokenCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| scim::create_scim_token(state, user),
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScimTokenRevoke))]
+pub async fn revoke_scim_token(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ScimTokenRevoke;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| scim::revoke_scim_token(state, user),
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
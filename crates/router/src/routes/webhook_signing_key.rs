@@ -0,0 +1,145 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::webhook_signing_key as webhook_signing_key_api_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, webhooks::webhook_signing_key},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Webhook Signing Keys - Create
+///
+/// Creates a key to sign the outgoing webhooks of the business profile, the key it replaces
+/// remains published for the overlap period
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningKeyCreate))]
+pub async fn create_webhook_signing_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    json_payload: web::Json<webhook_signing_key_api_types::WebhookSigningKeyCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookSigningKeyCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+    let profile = webhook_signing_key_api_types::WebhookSigningKeyProfileId {
+        merchant_id: merchant_id.clone(),
+        profile_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| {
+            webhook_signing_key::create_webhook_signing_key(state, profile.clone(), payload)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Webhook Signing Keys - List
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningKeyList))]
+pub async fn list_webhook_signing_keys(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookSigningKeyList;
+    let (merchant_id, profile_id) = path.into_inner();
+    let payload = webhook_signing_key_api_types::WebhookSigningKeyProfileId {
+        merchant_id: merchant_id.clone(),
+        profile_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| webhook_signing_key::list_webhook_signing_keys(state, payload),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Webhook Signing Keys - Revoke
+///
+/// Removes the key from the JWKS of the business profile immediately
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningKeyRevoke))]
+pub async fn revoke_webhook_signing_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookSigningKeyRevoke;
+    let (merchant_id, profile_id, key_id) = path.into_inner();
+    let payload = webhook_signing_key_api_types::WebhookSigningKeyId {
+        merchant_id: merchant_id.clone(),
+        profile_id,
+        key_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| webhook_signing_key::revoke_webhook_signing_key(state, payload),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Webhook Signing Keys - JWKS
+///
+/// Publishes the public keys used to verify the outgoing webhooks of the business profile
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningJwksRetrieve))]
+pub async fn retrieve_webhook_signing_jwks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookSigningJwksRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+    let payload = webhook_signing_key_api_types::WebhookSigningKeyProfileId {
+        merchant_id,
+        profile_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| webhook_signing_key::retrieve_webhook_signing_jwks(state, payload),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -499,7 +499,7 @@ where
     }
 }
 
-#[cfg(feature = "partial-auth")]
+#[cfg(any(feature = "partial-auth", feature = "olap"))]
 async fn construct_authentication_data<A>(
     state: &A,
     merchant_id: &id_type::MerchantId,
@@ -655,6 +655,71 @@ where
     }
 }
 
+/// Authenticates SCIM provisioning requests from identity providers, which send the SCIM token
+/// issued by the organization admin as a bearer token in the `Authorization` header. Merchant API
+/// keys are not accepted.
+#[cfg(feature = "olap")]
+#[derive(Debug)]
+pub struct ScimAuth;
+
+#[cfg(feature = "olap")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for ScimAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        use common_utils::ext_traits::StringExt;
+
+        use crate::core::scim;
+
+        let token = get_jwt_from_authorization_header(request_headers)
+            .change_context(errors::ApiErrorResponse::Unauthorized)?
+            .trim();
+        if token.is_empty() {
+            return Err(errors::ApiErrorResponse::Unauthorized)
+                .attach_printable("SCIM bearer token is empty");
+        }
+
+        let hash_key = {
+            let config = state.conf();
+            config.api_keys.get_inner().get_hash_key()?
+        };
+        let hashed_token = crate::types::storage::HashedApiKey::from(
+            api_keys::PlaintextApiKey::from(token).keyed_hash(hash_key.peek()),
+        )
+        .into_inner();
+
+        let scim_token_config: scim::ScimTokenConfig = state
+            .store()
+            .find_config_by_key(&scim::get_scim_token_config_key(&hashed_token))
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)
+            .attach_printable("SCIM client not authenticated")?
+            .config
+            .parse_struct("ScimTokenConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse SCIM token config")?;
+
+        let auth = construct_authentication_data(state, &scim_token_config.merchant_id).await?;
+        if auth.merchant_account.organization_id != scim_token_config.org_id {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("SCIM token was issued by another organization");
+        }
+
+        Ok((
+            auth,
+            AuthenticationType::MerchantId {
+                merchant_id: scim_token_config.merchant_id,
+            },
+        ))
+    }
+}
+
 #[derive(Debug)]
 pub struct AdminApiAuth;
 
@@ -899,8 +964,8 @@ where
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
 
-        let permissions = authorization::get_permissions(state, &payload).await?;
-        authorization::check_authorization(&self.0, &permissions)?;
+        let role_permissions = authorization::get_role_permissions(state, &payload).await?;
+        authorization::check_authorization(&self.0, &role_permissions.permissions)?;
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
             .store()
@@ -927,7 +992,7 @@ where
             AuthenticationDataWithMultipleProfiles {
                 key_store,
                 merchant_account: merchant,
-                profile_id_list: None,
+                profile_id_list: role_permissions.profile_ids,
             },
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
@@ -19,6 +19,23 @@ pub mod permission_groups;
 pub mod permissions;
 pub mod roles;
 
+/// Permissions of a role along with the business profiles they are restricted to, as stored in
+/// the role cache
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RolePermissions {
+    pub permissions: Vec<permissions::Permission>,
+    pub profile_ids: Option<Vec<String>>,
+}
+
+impl From<&roles::RoleInfo> for RolePermissions {
+    fn from(role_info: &roles::RoleInfo) -> Self {
+        Self {
+            permissions: role_info.get_permissions_set().into_iter().collect(),
+            profile_ids: role_info.get_profile_ids().cloned(),
+        }
+    }
+}
+
 pub async fn get_permissions<A>(
     state: &A,
     token: &AuthToken,
@@ -26,42 +43,80 @@ pub async fn get_permissions<A>(
 where
     A: SessionStateInfo + Sync,
 {
-    if let Some(permissions) = get_permissions_from_predefined_roles(&token.role_id) {
-        return Ok(permissions);
+    get_role_permissions(state, token)
+        .await
+        .map(|role_permissions| role_permissions.permissions)
+}
+
+pub async fn get_role_permissions<A>(state: &A, token: &AuthToken) -> RouterResult<RolePermissions>
+where
+    A: SessionStateInfo + Sync,
+{
+    let role_permissions = get_role_permissions_without_profile_check(state, token).await?;
+
+    check_profile_access(&role_permissions, token.profile_id.as_ref())?;
+
+    Ok(role_permissions)
+}
+
+/// Roles restricted to business profiles can only be used with a token issued for one of those
+/// profiles, a token which is not scoped to any profile would grant access to all of them
+fn check_profile_access(
+    role_permissions: &RolePermissions,
+    profile_id: Option<&String>,
+) -> RouterResult<()> {
+    match (role_permissions.profile_ids.as_ref(), profile_id) {
+        (None, _) => Ok(()),
+        (Some(profile_ids), Some(profile_id)) if profile_ids.contains(profile_id) => Ok(()),
+        (Some(_), profile_id) => Err(ApiErrorResponse::AccessForbidden {
+            resource: profile_id
+                .map(ToString::to_string)
+                .unwrap_or_else(|| "business_profile".to_string()),
+        }
+        .into()),
+    }
+}
+
+async fn get_role_permissions_without_profile_check<A>(
+    state: &A,
+    token: &AuthToken,
+) -> RouterResult<RolePermissions>
+where
+    A: SessionStateInfo + Sync,
+{
+    if let Some(role_permissions) = get_permissions_from_predefined_roles(&token.role_id) {
+        return Ok(role_permissions);
     }
 
-    if let Ok(permissions) = get_permissions_from_cache(state, &token.role_id)
+    if let Ok(role_permissions) = get_permissions_from_cache(state, &token.role_id)
         .await
         .map_err(|e| logger::error!("Failed to get permissions from cache {e:?}"))
     {
-        return Ok(permissions);
+        return Ok(role_permissions);
     }
 
-    let permissions =
+    let role_permissions =
         get_permissions_from_db(state, &token.role_id, &token.merchant_id, &token.org_id).await?;
 
     let token_expiry =
         i64::try_from(token.exp).change_context(ApiErrorResponse::InternalServerError)?;
     let cache_ttl = token_expiry - common_utils::date_time::now_unix_timestamp();
 
-    set_permissions_in_cache(state, &token.role_id, &permissions, cache_ttl)
+    set_permissions_in_cache(state, &token.role_id, &role_permissions, cache_ttl)
         .await
         .map_err(|e| logger::error!("Failed to set permissions in cache {e:?}"))
         .ok();
-    Ok(permissions)
+    Ok(role_permissions)
 }
 
-async fn get_permissions_from_cache<A>(
-    state: &A,
-    role_id: &str,
-) -> RouterResult<Vec<permissions::Permission>>
+async fn get_permissions_from_cache<A>(state: &A, role_id: &str) -> RouterResult<RolePermissions>
 where
     A: SessionStateInfo + Sync,
 {
     let redis_conn = get_redis_connection(state)?;
 
     redis_conn
-        .get_and_deserialize_key(&get_cache_key_from_role_id(role_id), "Vec<Permission>")
+        .get_and_deserialize_key(&get_cache_key_from_role_id(role_id), "RolePermissions")
         .await
         .change_context(ApiErrorResponse::InternalServerError)
 }
@@ -70,10 +125,10 @@ pub fn get_cache_key_from_role_id(role_id: &str) -> String {
     format!("{}{}", consts::ROLE_CACHE_PREFIX, role_id)
 }
 
-fn get_permissions_from_predefined_roles(role_id: &str) -> Option<Vec<permissions::Permission>> {
+fn get_permissions_from_predefined_roles(role_id: &str) -> Option<RolePermissions> {
     roles::predefined_roles::PREDEFINED_ROLES
         .get(role_id)
-        .map(|role_info| get_permissions_from_groups(role_info.get_permission_groups()))
+        .map(RolePermissions::from)
 }
 
 async fn get_permissions_from_db<A>(
@@ -81,7 +136,7 @@ async fn get_permissions_from_db<A>(
     role_id: &str,
     merchant_id: &id_type::MerchantId,
     org_id: &id_type::OrganizationId,
-) -> RouterResult<Vec<permissions::Permission>>
+) -> RouterResult<RolePermissions>
 where
     A: SessionStateInfo + Sync,
 {
@@ -89,14 +144,14 @@ where
         .store()
         .find_role_by_role_id_in_merchant_scope(role_id, merchant_id, org_id)
         .await
-        .map(|role| get_permissions_from_groups(&role.groups))
+        .map(|role| RolePermissions::from(&roles::RoleInfo::from(role)))
         .to_not_found_response(ApiErrorResponse::InvalidJwtToken)
 }
 
 pub async fn set_permissions_in_cache<A>(
     state: &A,
     role_id: &str,
-    permissions: &Vec<permissions::Permission>,
+    role_permissions: &RolePermissions,
     expiry: i64,
 ) -> RouterResult<()>
 where
@@ -107,7 +162,7 @@ where
     redis_conn
         .serialize_and_set_key_with_expiry(
             &get_cache_key_from_role_id(role_id),
-            permissions,
+            role_permissions,
             expiry,
         )
         .await
@@ -147,3 +202,29 @@ fn get_redis_connection<A: SessionStateInfo>(state: &A) -> RouterResult<Arc<Redi
         .change_context(ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to get redis connection")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn role_permissions(profile_ids: Option<Vec<String>>) -> RolePermissions {
+        RolePermissions {
+            permissions: vec![permissions::Permission::PaymentRead],
+            profile_ids,
+        }
+    }
+
+    #[test]
+    fn test_profile_restricted_role_requires_token_profile() {
+        let restricted = role_permissions(Some(vec!["pro_1".to_string()]));
+        let unrestricted = role_permissions(None);
+
+        assert!(check_profile_access(&restricted, Some(&"pro_1".to_string())).is_ok());
+        assert!(check_profile_access(&restricted, Some(&"pro_2".to_string())).is_err());
+        // A token which is not scoped to a profile must not grant access to all profiles
+        assert!(check_profile_access(&restricted, None).is_err());
+
+        assert!(check_profile_access(&unrestricted, Some(&"pro_2".to_string())).is_ok());
+        assert!(check_profile_access(&unrestricted, None).is_ok());
+    }
+}
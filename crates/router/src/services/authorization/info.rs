@@ -176,6 +176,7 @@ impl ModuleInfo {
                 permissions: get_permission_info_from_permissions(&[
                     Permission::PayoutRead,
                     Permission::PayoutWrite,
+                    Permission::PayoutApprove,
                 ]),
             },
         }
@@ -199,6 +200,7 @@ fn get_group_description(group: PermissionGroup) -> &'static str {
         PermissionGroup::OperationsManage => {
             "Create, modify and delete Payments, Refunds, Payouts, Mandates, Disputes and Customers"
         }
+        PermissionGroup::PayoutsApprove => "Approve and reject Payout batches pending approval",
         PermissionGroup::ConnectorsView => {
             "View connected Payment Processors, Payout Processors and Fraud & Risk Manager details"
         }
@@ -6,6 +6,7 @@ pub fn get_permissions_vec(permission_group: &PermissionGroup) -> &[Permission]
     match permission_group {
         PermissionGroup::OperationsView => &OPERATIONS_VIEW,
         PermissionGroup::OperationsManage => &OPERATIONS_MANAGE,
+        PermissionGroup::PayoutsApprove => &PAYOUTS_APPROVE,
         PermissionGroup::ConnectorsView => &CONNECTORS_VIEW,
         PermissionGroup::ConnectorsManage => &CONNECTORS_MANAGE,
         PermissionGroup::WorkflowsView => &WORKFLOWS_VIEW,
@@ -40,6 +41,9 @@ pub static OPERATIONS_MANAGE: [Permission; 7] = [
     Permission::MerchantAccountRead,
 ];
 
+pub static PAYOUTS_APPROVE: [Permission; 2] =
+    [Permission::PayoutApprove, Permission::MerchantAccountRead];
+
 pub static CONNECTORS_VIEW: [Permission; 2] = [
     Permission::MerchantConnectorAccountRead,
     Permission::MerchantAccountRead,
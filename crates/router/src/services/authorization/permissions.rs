@@ -1,7 +1,16 @@
-use strum::Display;
+use strum::{Display, EnumString};
 
 #[derive(
-    PartialEq, Display, Clone, Debug, Copy, Eq, Hash, serde::Deserialize, serde::Serialize,
+    PartialEq,
+    Display,
+    EnumString,
+    Clone,
+    Debug,
+    Copy,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
 )]
 pub enum Permission {
     PaymentRead,
@@ -34,6 +43,7 @@ pub enum Permission {
     WebhookEventWrite,
     PayoutRead,
     PayoutWrite,
+    PayoutApprove,
     GenerateReport,
 }
 
@@ -76,6 +86,7 @@ impl Permission {
             Self::WebhookEventWrite => "Trigger retries for webhook events",
             Self::PayoutRead => "View all payouts",
             Self::PayoutWrite => "Create payout, download payout data",
+            Self::PayoutApprove => "Approve or reject payout batches submitted by other users",
             Self::GenerateReport => "Generate reports for payments, refunds and disputes",
         }
     }
@@ -1,7 +1,8 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, str::FromStr};
 
 use common_enums::{EntityType, PermissionGroup, RoleScope};
 use common_utils::{errors::CustomResult, id_type};
+use router_env::logger;
 
 use super::{permission_groups::get_permissions_vec, permissions::Permission};
 use crate::{core::errors, routes::SessionState};
@@ -15,6 +16,10 @@ pub struct RoleInfo {
     groups: Vec<PermissionGroup>,
     scope: RoleScope,
     entity_type: EntityType,
+    /// Individual permissions granted on top of the permission groups
+    permissions: Vec<Permission>,
+    /// Business profiles the role is restricted to, `None` if it applies to all profiles
+    profile_ids: Option<Vec<String>>,
     is_invitable: bool,
     is_deletable: bool,
     is_updatable: bool,
@@ -34,6 +39,20 @@ impl RoleInfo {
         &self.groups
     }
 
+    pub fn get_permissions(&self) -> &Vec<Permission> {
+        &self.permissions
+    }
+
+    pub fn get_profile_ids(&self) -> Option<&Vec<String>> {
+        self.profile_ids.as_ref()
+    }
+
+    pub fn is_profile_accessible(&self, profile_id: &str) -> bool {
+        self.profile_ids.as_ref().map_or(true, |profile_ids| {
+            profile_ids.iter().any(|id| id == profile_id)
+        })
+    }
+
     pub fn get_scope(&self) -> RoleScope {
         self.scope
     }
@@ -62,13 +81,16 @@ impl RoleInfo {
         self.groups
             .iter()
             .flat_map(|group| get_permissions_vec(group).iter().copied())
+            .chain(self.permissions.iter().copied())
             .collect()
     }
 
     pub fn check_permission_exists(&self, required_permission: &Permission) -> bool {
-        self.groups
-            .iter()
-            .any(|group| get_permissions_vec(group).contains(required_permission))
+        self.permissions.contains(required_permission)
+            || self
+                .groups
+                .iter()
+                .any(|group| get_permissions_vec(group).contains(required_permission))
     }
 
     pub async fn from_role_id(
@@ -97,6 +119,16 @@ impl From<diesel_models::role::Role> for RoleInfo {
             groups: role.groups.into_iter().map(Into::into).collect(),
             scope: role.scope,
             entity_type: role.entity_type.unwrap_or(EntityType::Merchant),
+            permissions: role
+                .permissions
+                .iter()
+                .filter_map(|permission| {
+                    Permission::from_str(permission)
+                        .map_err(|_| logger::error!("Invalid permission {permission} in role"))
+                        .ok()
+                })
+                .collect(),
+            profile_ids: role.profile_ids,
             is_invitable: true,
             is_deletable: true,
             is_updatable: true,
@@ -14,6 +14,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             groups: vec![
                 PermissionGroup::OperationsView,
                 PermissionGroup::OperationsManage,
+                PermissionGroup::PayoutsApprove,
                 PermissionGroup::ConnectorsView,
                 PermissionGroup::ConnectorsManage,
                 PermissionGroup::WorkflowsView,
@@ -29,6 +30,8 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             role_name: "internal_admin".to_string(),
             scope: RoleScope::Organization,
             entity_type: EntityType::Internal,
+            permissions: Vec::new(),
+            profile_ids: None,
             is_invitable: false,
             is_deletable: false,
             is_updatable: false,
@@ -50,6 +53,8 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             role_name: "internal_view_only".to_string(),
             scope: RoleScope::Organization,
             entity_type: EntityType::Internal,
+            permissions: Vec::new(),
+            profile_ids: None,
             is_invitable: false,
             is_deletable: false,
             is_updatable: false,
@@ -63,6 +68,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             groups: vec![
                 PermissionGroup::OperationsView,
                 PermissionGroup::OperationsManage,
+                PermissionGroup::PayoutsApprove,
                 PermissionGroup::ConnectorsView,
                 PermissionGroup::ConnectorsManage,
                 PermissionGroup::WorkflowsView,
@@ -78,6 +84,8 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             role_name: "organization_admin".to_string(),
             scope: RoleScope::Organization,
             entity_type: EntityType::Organization,
+            permissions: Vec::new(),
+            profile_ids: None,
             is_invitable: false,
             is_deletable: false,
             is_updatable: false,
@@ -92,6 +100,7 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             groups: vec![
                 PermissionGroup::OperationsView,
                 PermissionGroup::OperationsManage,
+                PermissionGroup::PayoutsApprove,
                 PermissionGroup::ConnectorsView,
                 PermissionGroup::ConnectorsManage,
                 PermissionGroup::WorkflowsView,
@@ -106,6 +115,8 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             role_name: "admin".to_string(),
             scope: RoleScope::Organization,
             entity_type: EntityType::Merchant,
+            permissions: Vec::new(),
+            profile_ids: None,
             is_invitable: true,
             is_deletable: true,
             is_updatable: true,
@@ -127,6 +138,8 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             role_name: "view_only".to_string(),
             scope: RoleScope::Organization,
             entity_type: EntityType::Merchant,
+            permissions: Vec::new(),
+            profile_ids: None,
             is_invitable: true,
             is_deletable: true,
             is_updatable: true,
@@ -147,6 +160,8 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             role_name: "iam".to_string(),
             scope: RoleScope::Organization,
             entity_type: EntityType::Merchant,
+            permissions: Vec::new(),
+            profile_ids: None,
             is_invitable: true,
             is_deletable: true,
             is_updatable: true,
@@ -168,6 +183,8 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             role_name: "developer".to_string(),
             scope: RoleScope::Organization,
             entity_type: EntityType::Merchant,
+            permissions: Vec::new(),
+            profile_ids: None,
             is_invitable: true,
             is_deletable: true,
             is_updatable: true,
@@ -190,6 +207,8 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             role_name: "operator".to_string(),
             scope: RoleScope::Organization,
             entity_type: EntityType::Merchant,
+            permissions: Vec::new(),
+            profile_ids: None,
             is_invitable: true,
             is_deletable: true,
             is_updatable: true,
@@ -209,6 +228,8 @@ pub static PREDEFINED_ROLES: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|
             role_name: "customer_support".to_string(),
             scope: RoleScope::Organization,
             entity_type: EntityType::Merchant,
+            permissions: Vec::new(),
+            profile_ids: None,
             is_invitable: true,
             is_deletable: true,
             is_updatable: true,
@@ -56,6 +56,21 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    DisputeDeadlineReminder {
+        dispute_id: String,
+        payment_id: String,
+        amount: String,
+        currency: String,
+        hours_remaining: u16,
+    },
+    InvoiceReminder {
+        merchant_name: String,
+        invoice_number: String,
+        amount_remaining: String,
+        currency: String,
+        due_date_message: String,
+        payment_link: String,
+    },
 }
 
 pub mod html {
@@ -144,6 +159,36 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::DisputeDeadlineReminder {
+                dispute_id,
+                payment_id,
+                amount,
+                currency,
+                hours_remaining,
+            } => format!(
+                include_str!("assets/dispute_deadline_reminder.html"),
+                dispute_id = dispute_id,
+                payment_id = payment_id,
+                amount = amount,
+                currency = currency,
+                hours_remaining = hours_remaining,
+            ),
+            EmailBody::InvoiceReminder {
+                merchant_name,
+                invoice_number,
+                amount_remaining,
+                currency,
+                due_date_message,
+                payment_link,
+            } => format!(
+                include_str!("assets/invoice_reminder.html"),
+                merchant_name = merchant_name,
+                invoice_number = invoice_number,
+                amount_remaining = amount_remaining,
+                currency = currency,
+                due_date_message = due_date_message,
+                payment_link = payment_link,
+            ),
         }
     }
 }
@@ -529,3 +574,67 @@ impl EmailData for ApiKeyExpiryReminder {
         })
     }
 }
+
+pub struct DisputeDeadlineReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub dispute_id: String,
+    pub payment_id: String,
+    pub amount: String,
+    pub currency: String,
+    pub hours_remaining: u16,
+}
+
+#[async_trait::async_trait]
+impl EmailData for DisputeDeadlineReminder {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::DisputeDeadlineReminder {
+            dispute_id: self.dispute_id.clone(),
+            payment_id: self.payment_id.clone(),
+            amount: self.amount.clone(),
+            currency: self.currency.clone(),
+            hours_remaining: self.hours_remaining,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct InvoiceReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: String,
+    pub merchant_name: String,
+    pub invoice_number: String,
+    pub amount_remaining: String,
+    pub currency: String,
+    pub due_date_message: String,
+    pub payment_link: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for InvoiceReminder {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::InvoiceReminder {
+            merchant_name: self.merchant_name.clone(),
+            invoice_number: self.invoice_number.clone(),
+            amount_remaining: self.amount_remaining.clone(),
+            currency: self.currency.clone(),
+            due_date_message: self.due_date_message.clone(),
+            payment_link: self.payment_link.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.clone(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
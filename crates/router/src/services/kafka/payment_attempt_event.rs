@@ -55,6 +55,7 @@ pub struct KafkaPaymentAttemptEvent<'a> {
     pub mandate_data: Option<&'a MandateDetails>,
     pub client_source: Option<&'a String>,
     pub client_version: Option<&'a String>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
 }
 
 impl<'a> KafkaPaymentAttemptEvent<'a> {
@@ -101,6 +102,7 @@ impl<'a> KafkaPaymentAttemptEvent<'a> {
             mandate_data: attempt.mandate_data.as_ref(),
             client_source: attempt.client_source.as_ref(),
             client_version: attempt.client_version.as_ref(),
+            sca_exemption: attempt.sca_exemption,
         }
     }
 }
@@ -6,8 +6,9 @@ pub use api_models::{
         MerchantAccountCreate, MerchantAccountDeleteResponse, MerchantAccountResponse,
         MerchantAccountUpdate, MerchantConnectorCreate, MerchantConnectorDeleteResponse,
         MerchantConnectorDetails, MerchantConnectorDetailsWrap, MerchantConnectorId,
-        MerchantConnectorResponse, MerchantDetails, MerchantId, PaymentMethodsEnabled,
-        ToggleAllKVRequest, ToggleAllKVResponse, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+        MerchantConnectorResponse, MerchantDetails, MerchantId, MerchantKeyRotationId,
+        PaymentMethodsEnabled, ToggleAllKVRequest, ToggleAllKVResponse, ToggleKVRequest,
+        ToggleKVResponse, WebhookDetails,
     },
     organization::{OrganizationId, OrganizationRequest, OrganizationResponse},
 };
@@ -53,6 +53,11 @@ impl FraudCheckConnectorData {
             enums::FrmConnectors::Riskified => {
                 Ok(ConnectorEnum::Old(Box::new(&connector::Riskified)))
             }
+            enums::FrmConnectors::NativeFrm => Err(errors::ApiErrorResponse::NotSupported {
+                message: "native_frm is evaluated by the router and has no connector integration"
+                    .to_string(),
+            }
+            .into()),
         }
     }
 }
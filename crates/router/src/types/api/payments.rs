@@ -16,22 +16,25 @@ pub use api_models::payments::{
 };
 use error_stack::ResultExt;
 pub use hyperswitch_domain_models::router_flow_types::payments::{
-    Approve, Authorize, AuthorizeSessionToken, Balance, Capture, CompleteAuthorize,
-    CreateConnectorCustomer, IncrementalAuthorization, InitPayment, PSync, PaymentMethodToken,
-    PostProcessing, PreProcessing, Reject, Session, SetupMandate, Void,
+    Approve, Authorize, AuthorizeSessionToken, Balance, CalculateTax, Capture, CommitTax,
+    CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, InitPayment, PSync,
+    PaymentMethodToken, PostProcessing, PreProcessing, Reject, ReverseTax, Session, SetupMandate,
+    Void,
 };
 pub use hyperswitch_interfaces::api::payments::{
     ConnectorCustomer, MandateSetup, Payment, PaymentApprove, PaymentAuthorize,
     PaymentAuthorizeSessionToken, PaymentCapture, PaymentIncrementalAuthorization, PaymentReject,
-    PaymentSession, PaymentSync, PaymentToken, PaymentVoid, PaymentsCompleteAuthorize,
-    PaymentsPostProcessing, PaymentsPreProcessing,
+    PaymentSession, PaymentSync, PaymentToken, PaymentVoid, PaymentsCalculateTax,
+    PaymentsCommitTax, PaymentsCompleteAuthorize, PaymentsPostProcessing, PaymentsPreProcessing,
+    PaymentsReverseTax,
 };
 
 pub use super::payments_v2::{
     ConnectorCustomerV2, MandateSetupV2, PaymentApproveV2, PaymentAuthorizeSessionTokenV2,
     PaymentAuthorizeV2, PaymentCaptureV2, PaymentIncrementalAuthorizationV2, PaymentRejectV2,
     PaymentSessionV2, PaymentSyncV2, PaymentTokenV2, PaymentV2, PaymentVoidV2,
-    PaymentsCompleteAuthorizeV2, PaymentsPostProcessingV2, PaymentsPreProcessingV2,
+    PaymentsCalculateTaxV2, PaymentsCommitTaxV2, PaymentsCompleteAuthorizeV2,
+    PaymentsPostProcessingV2, PaymentsPreProcessingV2, PaymentsReverseTaxV2,
 };
 use crate::core::errors;
 
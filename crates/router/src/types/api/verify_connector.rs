@@ -55,8 +55,11 @@ impl VerifyConnectorData {
             authentication_data: None,
             customer_acceptance: None,
             charges: None,
+            sca_exemption: None,
             merchant_order_reference_id: None,
             integrity_object: None,
+            l2_l3_data: None,
+            split_payments: None,
         }
     }
 
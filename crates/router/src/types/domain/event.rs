@@ -42,6 +42,10 @@ pub enum EventUpdate {
         is_webhook_notified: bool,
         response: OptionalEncryptableSecretString,
     },
+    ContentRedaction {
+        request: OptionalEncryptableSecretString,
+        response: OptionalEncryptableSecretString,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -52,6 +56,12 @@ impl From<EventUpdate> for EventUpdateInternal {
                 response,
             } => Self {
                 is_webhook_notified: Some(is_webhook_notified),
+                request: None,
+                response: response.map(Into::into),
+            },
+            EventUpdate::ContentRedaction { request, response } => Self {
+                is_webhook_notified: None,
+                request: request.map(Into::into),
                 response: response.map(Into::into),
             },
         }
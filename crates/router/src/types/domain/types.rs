@@ -14,6 +14,7 @@ impl From<&crate::SessionState> for KeyManagerState {
             cert: conf.cert.clone(),
             #[cfg(feature = "keymanager_mtls")]
             ca: conf.ca.clone(),
+            previous_keys: state.previous_merchant_keys.clone(),
         }
     }
 }
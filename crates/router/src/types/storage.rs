@@ -11,6 +11,8 @@ pub mod cards_info;
 pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
+pub mod data_subject_request;
 pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
@@ -19,25 +21,33 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod invoice;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_rotation;
 pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod refund;
+pub mod refund_batch;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod scim;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_signing_key;
 
 use std::collections::HashMap;
 
@@ -60,11 +70,13 @@ pub use scheduler::db::process_tracker;
 pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, user::*, user_authentication_method::*, user_role::*,
+    configs::*, customers::*, dashboard_metadata::*, data_retention::*, data_subject_request::*,
+    dispute::*, ephemeral_key::*, events::*, file::*, fraud_check::*, generic_link::*, gsm::*,
+    invoice::*, ledger::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, merchant_key_rotation::*, merchant_key_store::*,
+    payment_link::*, payment_method::*, payout_batch::*, process_tracker::*, refund::*,
+    refund_batch::*, reusable_payment_link::*, reverse_lookup::*, role::*, routing_algorithm::*,
+    scim::*, user::*, user_authentication_method::*, user_role::*, webhook_signing_key::*,
 };
 use crate::types::api::routing;
 
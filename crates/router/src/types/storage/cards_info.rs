@@ -1 +1,3 @@
-pub use diesel_models::cards_info::CardInfo;
+pub use diesel_models::cards_info::{
+    CardBinRange, CardBinRangeNew, CardBinRangeUpdate, CardBinRangeUpdateInternal, CardInfo,
+};
@@ -0,0 +1 @@
+pub use diesel_models::data_subject_request::*;
@@ -1,9 +1,12 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
-pub use diesel_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
-use diesel_models::{errors, query::generics::db_metrics, schema::dispute::dsl};
+pub use diesel_models::dispute::{Dispute, DisputeDeadlineTrackingData, DisputeNew, DisputeUpdate};
+use diesel_models::{
+    enums as storage_enums, errors, query::generics::db_metrics, schema::dispute::dsl,
+};
 use error_stack::ResultExt;
+use time::PrimitiveDateTime;
 
 use crate::{connection::PgPooledConn, logger};
 
@@ -14,6 +17,14 @@ pub trait DisputeDbExt: Sized {
         merchant_id: &common_utils::id_type::MerchantId,
         dispute_list_constraints: api_models::disputes::DisputeListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
+    async fn filter_open_by_challenge_required_by(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<String>,
+        challenge_required_by_range: (PrimitiveDateTime, PrimitiveDateTime),
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -72,4 +83,38 @@ impl DisputeDbExt for Dispute {
         .change_context(errors::DatabaseError::NotFound)
         .attach_printable_lazy(|| "Error filtering records by predicate")
     }
+
+    async fn filter_open_by_challenge_required_by(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<String>,
+        challenge_required_by_range: (PrimitiveDateTime, PrimitiveDateTime),
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let (start, end) = challenge_required_by_range;
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::dispute_status.eq(storage_enums::DisputeStatus::DisputeOpened))
+            .filter(dsl::challenge_required_by.ge(start))
+            .filter(dsl::challenge_required_by.le(end))
+            .order(dsl::challenge_required_by.asc())
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            filter = filter.filter(dsl::profile_id.eq(profile_id));
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering disputes by evidence deadline")
+    }
 }
@@ -1,4 +1,5 @@
 pub use diesel_models::gsm::{
-    GatewayStatusMap, GatewayStatusMapperUpdateInternal, GatewayStatusMappingNew,
+    GatewayStatusMap, GatewayStatusMapAttributes, GatewayStatusMapHistory,
+    GatewayStatusMapHistoryNew, GatewayStatusMapperUpdateInternal, GatewayStatusMappingNew,
     GatewayStatusMappingUpdate,
 };
@@ -0,0 +1 @@
+pub use diesel_models::payout_batch::*;
@@ -289,3 +289,52 @@ impl RefundDbExt for Refund {
             .attach_printable_lazy(|| "Error filtering count of refunds")
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use common_utils::{id_type, types::MinorUnit};
+    use diesel_models::enums::{Currency, RefundStatus, RefundType};
+
+    use super::Refund;
+
+    /// Returns a refund of the full amount of the payment `pay_1`, for tests to
+    /// override the fields they depend on
+    pub(crate) fn get_refund(
+        refund_id: &str,
+        refund_status: RefundStatus,
+        refund_amount: i64,
+    ) -> Refund {
+        let now = common_utils::date_time::now();
+        Refund {
+            refund_id: refund_id.to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: id_type::MerchantId::get_irrelevant_merchant_id(),
+            internal_reference_id: format!("{refund_id}_internal"),
+            external_reference_id: None,
+            connector_transaction_id: "txn_1".to_string(),
+            connector: "stripe".to_string(),
+            connector_refund_id: None,
+            refund_type: RefundType::InstantRefund,
+            total_amount: MinorUnit::new(refund_amount),
+            currency: Currency::USD,
+            refund_amount: MinorUnit::new(refund_amount),
+            refund_status,
+            sent_to_gateway: true,
+            refund_error_message: None,
+            metadata: None,
+            refund_arn: None,
+            created_at: now,
+            modified_at: now,
+            description: None,
+            attempt_id: "pay_1_1".to_string(),
+            refund_reason: None,
+            refund_error_code: None,
+            profile_id: None,
+            updated_by: "admin".to_string(),
+            merchant_connector_id: None,
+            charges: None,
+            tax_reversal: None,
+            split_refunds: None,
+        }
+    }
+}
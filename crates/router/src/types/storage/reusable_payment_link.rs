@@ -0,0 +1 @@
+pub use diesel_models::reusable_payment_link::*;
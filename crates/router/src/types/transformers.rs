@@ -293,10 +293,19 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
                     message: "riskified is not a routable connector".to_string(),
                 })?
             }
+            api_enums::Connector::NativeFrm => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "native_frm is not a routable connector".to_string(),
+                })?
+            }
             api_enums::Connector::Square => Self::Square,
             api_enums::Connector::Stax => Self::Stax,
             api_enums::Connector::Stripe => Self::Stripe,
-            // api_enums::Connector::Taxjar => Self::Taxjar,
+            api_enums::Connector::Taxjar => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "taxjar is not a routable connector".to_string(),
+                })?
+            }
             api_enums::Connector::Trustpay => Self::Trustpay,
             api_enums::Connector::Tsys => Self::Tsys,
             api_enums::Connector::Volt => Self::Volt,
@@ -581,6 +590,25 @@ impl ForeignFrom<storage_enums::RefundStatus> for Option<storage_enums::EventTyp
     }
 }
 
+impl ForeignFrom<&api_models::invoices::InvoiceResponse> for Option<storage_enums::EventType> {
+    fn foreign_from(invoice: &api_models::invoices::InvoiceResponse) -> Self {
+        match invoice.status {
+            storage_enums::InvoiceStatus::Draft => Some(storage_enums::EventType::InvoiceCreated),
+            storage_enums::InvoiceStatus::Open
+                if invoice.amount_paid > common_utils::types::MinorUnit::zero() =>
+            {
+                Some(storage_enums::EventType::InvoicePartiallyPaid)
+            }
+            storage_enums::InvoiceStatus::Open => Some(storage_enums::EventType::InvoiceFinalized),
+            storage_enums::InvoiceStatus::Paid => Some(storage_enums::EventType::InvoicePaid),
+            storage_enums::InvoiceStatus::Void => Some(storage_enums::EventType::InvoiceVoided),
+            storage_enums::InvoiceStatus::Uncollectible => {
+                Some(storage_enums::EventType::InvoiceMarkedUncollectible)
+            }
+        }
+    }
+}
+
 impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventType> {
     fn foreign_from(value: storage_enums::PayoutStatus) -> Self {
         match value {
@@ -48,15 +48,70 @@ impl From<Permission> for user_role_api::Permission {
             Permission::WebhookEventWrite => Self::WebhookEventWrite,
             Permission::PayoutRead => Self::PayoutRead,
             Permission::PayoutWrite => Self::PayoutWrite,
+            Permission::PayoutApprove => Self::PayoutApprove,
             Permission::GenerateReport => Self::GenerateReport,
         }
     }
 }
 
-pub fn validate_role_groups(groups: &[PermissionGroup]) -> UserResult<()> {
-    if groups.is_empty() {
+impl From<user_role_api::Permission> for Permission {
+    fn from(value: user_role_api::Permission) -> Self {
+        match value {
+            user_role_api::Permission::PaymentRead => Self::PaymentRead,
+            user_role_api::Permission::PaymentWrite => Self::PaymentWrite,
+            user_role_api::Permission::RefundRead => Self::RefundRead,
+            user_role_api::Permission::RefundWrite => Self::RefundWrite,
+            user_role_api::Permission::ApiKeyRead => Self::ApiKeyRead,
+            user_role_api::Permission::ApiKeyWrite => Self::ApiKeyWrite,
+            user_role_api::Permission::MerchantAccountRead => Self::MerchantAccountRead,
+            user_role_api::Permission::MerchantAccountWrite => Self::MerchantAccountWrite,
+            user_role_api::Permission::MerchantConnectorAccountRead => {
+                Self::MerchantConnectorAccountRead
+            }
+            user_role_api::Permission::MerchantConnectorAccountWrite => {
+                Self::MerchantConnectorAccountWrite
+            }
+            user_role_api::Permission::RoutingRead => Self::RoutingRead,
+            user_role_api::Permission::RoutingWrite => Self::RoutingWrite,
+            user_role_api::Permission::DisputeRead => Self::DisputeRead,
+            user_role_api::Permission::DisputeWrite => Self::DisputeWrite,
+            user_role_api::Permission::MandateRead => Self::MandateRead,
+            user_role_api::Permission::MandateWrite => Self::MandateWrite,
+            user_role_api::Permission::CustomerRead => Self::CustomerRead,
+            user_role_api::Permission::CustomerWrite => Self::CustomerWrite,
+            user_role_api::Permission::Analytics => Self::Analytics,
+            user_role_api::Permission::ThreeDsDecisionManagerWrite => {
+                Self::ThreeDsDecisionManagerWrite
+            }
+            user_role_api::Permission::ThreeDsDecisionManagerRead => {
+                Self::ThreeDsDecisionManagerRead
+            }
+            user_role_api::Permission::SurchargeDecisionManagerWrite => {
+                Self::SurchargeDecisionManagerWrite
+            }
+            user_role_api::Permission::SurchargeDecisionManagerRead => {
+                Self::SurchargeDecisionManagerRead
+            }
+            user_role_api::Permission::UsersRead => Self::UsersRead,
+            user_role_api::Permission::UsersWrite => Self::UsersWrite,
+            user_role_api::Permission::MerchantAccountCreate => Self::MerchantAccountCreate,
+            user_role_api::Permission::WebhookEventRead => Self::WebhookEventRead,
+            user_role_api::Permission::WebhookEventWrite => Self::WebhookEventWrite,
+            user_role_api::Permission::PayoutRead => Self::PayoutRead,
+            user_role_api::Permission::PayoutWrite => Self::PayoutWrite,
+            user_role_api::Permission::PayoutApprove => Self::PayoutApprove,
+            user_role_api::Permission::GenerateReport => Self::GenerateReport,
+        }
+    }
+}
+
+pub fn validate_role_groups_and_permissions(
+    groups: &[PermissionGroup],
+    permissions: &[Permission],
+) -> UserResult<()> {
+    if groups.is_empty() && permissions.is_empty() {
         return Err(report!(UserErrors::InvalidRoleOperation))
-            .attach_printable("Role groups cannot be empty");
+            .attach_printable("Role groups and permissions cannot both be empty");
     }
 
     let unique_groups: HashSet<_> = groups.iter().cloned().collect();
@@ -71,6 +126,118 @@ pub fn validate_role_groups(groups: &[PermissionGroup]) -> UserResult<()> {
             .attach_printable("Duplicate permission group found");
     }
 
+    let unique_permissions: HashSet<_> = permissions.iter().collect();
+
+    if unique_permissions.contains(&Permission::MerchantAccountCreate) {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Merchant account create permission cannot be added to role");
+    }
+
+    if unique_permissions.len() != permissions.len() {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Duplicate permission found");
+    }
+
+    Ok(())
+}
+
+/// Ensures that a user cannot create or update a role with permissions that their own role
+/// does not have
+pub fn validate_permission_escalation(
+    user_role_info: &roles::RoleInfo,
+    groups: &[PermissionGroup],
+    permissions: &[Permission],
+) -> UserResult<()> {
+    let user_permissions = user_role_info.get_permissions_set();
+
+    let is_escalating = authz::get_permissions_from_groups(groups)
+        .iter()
+        .chain(permissions.iter())
+        .any(|permission| !user_permissions.contains(permission));
+
+    if is_escalating {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "Cannot grant permissions which are not available to the user".to_string()
+        )));
+    }
+
+    Ok(())
+}
+
+pub async fn validate_role_profile_ids(
+    state: &SessionState,
+    user_role_info: &roles::RoleInfo,
+    merchant_id: &id_type::MerchantId,
+    profile_ids: Option<&Vec<String>>,
+) -> UserResult<()> {
+    validate_role_profile_ids_access(user_role_info, profile_ids)?;
+
+    let Some(profile_ids) = profile_ids else {
+        return Ok(());
+    };
+
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let merchant_profile_ids: HashSet<_> = state
+        .store
+        .list_business_profile_by_merchant_id(key_manager_state, &key_store, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|profile| profile.profile_id)
+        .collect();
+
+    if let Some(profile_id) = profile_ids
+        .iter()
+        .find(|profile_id| !merchant_profile_ids.contains(*profile_id))
+    {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            format!("Profile {profile_id} does not belong to the merchant")
+        )));
+    }
+
+    Ok(())
+}
+
+/// Ensures that the profiles a role is restricted to are available to the user creating or
+/// updating the role
+fn validate_role_profile_ids_access(
+    user_role_info: &roles::RoleInfo,
+    profile_ids: Option<&Vec<String>>,
+) -> UserResult<()> {
+    let Some(profile_ids) = profile_ids else {
+        // Users restricted to some profiles cannot create roles that apply to all profiles
+        return match user_role_info.get_profile_ids() {
+            Some(_) => Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+                "Role must be restricted to the profiles available to the user".to_string()
+            ))),
+            None => Ok(()),
+        };
+    };
+
+    if profile_ids.is_empty() {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Role profile_ids cannot be empty");
+    }
+
+    if let Some(profile_id) = profile_ids
+        .iter()
+        .find(|profile_id| !user_role_info.is_profile_accessible(profile_id))
+    {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            format!("Profile {profile_id} is not available to the user")
+        )));
+    }
+
     Ok(())
 }
 
@@ -142,7 +309,7 @@ pub async fn set_role_permissions_in_cache_if_required(
     authz::set_permissions_in_cache(
         state,
         role_id,
-        &role_info.get_permissions_set().into_iter().collect(),
+        &authz::RolePermissions::from(&role_info),
         i64::try_from(consts::JWT_TOKEN_TIME_IN_SECS)
             .change_context(UserErrors::InternalServerError)?,
     )
@@ -172,3 +339,133 @@ pub async fn get_multiple_role_info_for_user_roles(
     }))
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_enums::RoleScope;
+
+    use super::*;
+
+    fn custom_role(
+        groups: Vec<PermissionGroup>,
+        permissions: &[Permission],
+        profile_ids: Option<Vec<&str>>,
+    ) -> roles::RoleInfo {
+        let now = common_utils::date_time::now();
+        roles::RoleInfo::from(diesel_models::role::Role {
+            role_name: "custom".to_string(),
+            role_id: "role_1".to_string(),
+            merchant_id: id_type::MerchantId::get_irrelevant_merchant_id(),
+            org_id: id_type::OrganizationId::default(),
+            groups,
+            scope: RoleScope::Merchant,
+            created_at: now,
+            created_by: "user_1".to_string(),
+            last_modified_at: now,
+            last_modified_by: "user_1".to_string(),
+            entity_type: None,
+            permissions: permissions.iter().map(ToString::to_string).collect(),
+            profile_ids: profile_ids
+                .map(|profile_ids| profile_ids.into_iter().map(String::from).collect()),
+        })
+    }
+
+    fn profile_ids(profile_ids: &[&str]) -> Vec<String> {
+        profile_ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_role_must_have_groups_or_permissions() {
+        assert!(validate_role_groups_and_permissions(&[], &[]).is_err());
+        assert!(
+            validate_role_groups_and_permissions(&[PermissionGroup::OperationsView], &[]).is_ok()
+        );
+        assert!(validate_role_groups_and_permissions(&[], &[Permission::PaymentRead]).is_ok());
+    }
+
+    #[test]
+    fn test_role_cannot_have_reserved_or_duplicate_entries() {
+        assert!(
+            validate_role_groups_and_permissions(&[PermissionGroup::OrganizationManage], &[])
+                .is_err()
+        );
+        assert!(validate_role_groups_and_permissions(
+            &[PermissionGroup::UsersView, PermissionGroup::UsersView],
+            &[]
+        )
+        .is_err());
+        assert!(
+            validate_role_groups_and_permissions(&[], &[Permission::MerchantAccountCreate])
+                .is_err()
+        );
+        assert!(validate_role_groups_and_permissions(
+            &[],
+            &[Permission::PaymentRead, Permission::PaymentRead]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_permissions_cannot_be_escalated() {
+        let user_role_info = custom_role(
+            vec![PermissionGroup::OperationsView],
+            &[Permission::RefundWrite],
+            None,
+        );
+
+        // Permissions available through the groups or individually can be granted
+        assert!(validate_permission_escalation(
+            &user_role_info,
+            &[PermissionGroup::OperationsView],
+            &[Permission::RefundWrite]
+        )
+        .is_ok());
+        assert!(
+            validate_permission_escalation(&user_role_info, &[], &[Permission::PaymentRead])
+                .is_ok()
+        );
+
+        // Groups are granted along with all of their permissions
+        assert!(validate_permission_escalation(
+            &user_role_info,
+            &[PermissionGroup::OperationsManage],
+            &[]
+        )
+        .is_err());
+        assert!(
+            validate_permission_escalation(&user_role_info, &[], &[Permission::PayoutApprove])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_role_profile_ids_must_be_available_to_the_user() {
+        let unrestricted_user = custom_role(vec![PermissionGroup::UsersManage], &[], None);
+        let restricted_user = custom_role(
+            vec![PermissionGroup::UsersManage],
+            &[],
+            Some(vec!["pro_1", "pro_2"]),
+        );
+
+        assert!(validate_role_profile_ids_access(&unrestricted_user, None).is_ok());
+        assert!(validate_role_profile_ids_access(
+            &unrestricted_user,
+            Some(&profile_ids(&["pro_3"]))
+        )
+        .is_ok());
+        assert!(validate_role_profile_ids_access(&unrestricted_user, Some(&vec![])).is_err());
+
+        // A user restricted to some profiles cannot create a role for all profiles
+        assert!(validate_role_profile_ids_access(&restricted_user, None).is_err());
+        assert!(
+            validate_role_profile_ids_access(&restricted_user, Some(&profile_ids(&["pro_2"])))
+                .is_ok()
+        );
+        assert!(validate_role_profile_ids_access(
+            &restricted_user,
+            Some(&profile_ids(&["pro_1", "pro_3"]))
+        )
+        .is_err());
+    }
+}
@@ -2,8 +2,23 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+#[cfg(feature = "olap")]
+pub mod data_retention;
+#[cfg(feature = "olap")]
+pub mod data_subject_request;
+pub mod dispute_deadline;
+pub mod invoice_payment;
+pub mod invoice_reminder;
+#[cfg(feature = "olap")]
+pub mod ledger_rebuild;
+#[cfg(feature = "olap")]
+pub mod merchant_key_rotation;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_batch;
+pub mod refund_batch;
 pub mod refund_router;
+pub mod tax_transaction;
 pub mod tokenized_data;
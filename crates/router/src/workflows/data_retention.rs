@@ -0,0 +1,32 @@
+use scheduler::consumer::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::data_retention as data_retention_flow, errors, logger::error, routes::SessionState,
+    types::storage,
+};
+
+pub struct DataRetentionWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DataRetentionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(Box::pin(data_retention_flow::start_data_retention_workflow(
+            state, &process,
+        ))
+        .await?)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use scheduler::consumer::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::customers::data_subject_request, errors, logger::error, routes::SessionState,
+    types::storage,
+};
+
+pub struct DataSubjectRequestWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DataSubjectRequestWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(
+            Box::pin(data_subject_request::start_data_subject_request_workflow(
+                state, &process,
+            ))
+            .await?,
+        )
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
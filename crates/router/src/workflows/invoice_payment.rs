@@ -0,0 +1,26 @@
+use scheduler::consumer::workflows::ProcessTrackerWorkflow;
+
+use crate::{core::invoices, errors, logger::error, routes::SessionState, types::storage};
+
+pub struct InvoicePaymentWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for InvoicePaymentWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(Box::pin(invoices::start_invoice_payment_workflow(state, &process)).await?)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
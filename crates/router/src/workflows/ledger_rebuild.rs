@@ -0,0 +1,28 @@
+use scheduler::consumer::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::ledger as ledger_flow, errors, logger::error, routes::SessionState, types::storage,
+};
+
+pub struct LedgerRebuildWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for LedgerRebuildWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(Box::pin(ledger_flow::start_ledger_rebuild_workflow(state, &process)).await?)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
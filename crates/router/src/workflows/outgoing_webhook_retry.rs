@@ -337,6 +337,7 @@ async fn get_outgoing_webhook_content_and_event_type(
     tracking_data: &OutgoingWebhookTrackingData,
 ) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
     use api_models::{
+        invoices::InvoiceId,
         mandates::MandateId,
         payments::{HeaderPayload, PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
         refunds::{RefundResponse, RefundsRetrieveRequest},
@@ -345,6 +346,7 @@ async fn get_outgoing_webhook_content_and_event_type(
     use crate::{
         core::{
             disputes::retrieve_dispute,
+            invoices::retrieve_invoice,
             mandate::get_mandate,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core,
@@ -496,6 +498,37 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::Invoices => {
+            let invoice_id = tracking_data.primary_object_id.clone();
+            let request = InvoiceId { invoice_id };
+
+            let invoice_response = match retrieve_invoice(state, merchant_account, request).await? {
+                ApplicationResponse::Json(invoice_response)
+                | ApplicationResponse::JsonWithHeaders((invoice_response, _)) => {
+                    Ok(invoice_response)
+                }
+                ApplicationResponse::StatusOk
+                | ApplicationResponse::TextPlain(_)
+                | ApplicationResponse::JsonForRedirection(_)
+                | ApplicationResponse::Form(_)
+                | ApplicationResponse::GenericLinkForm(_)
+                | ApplicationResponse::PaymentLinkForm(_)
+                | ApplicationResponse::FileData(_) => {
+                    Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                        resource_name: tracking_data.primary_object_id.clone(),
+                    })
+                }
+            }
+            .map(Box::new)?;
+            let event_type = Option::<EventType>::foreign_from(invoice_response.as_ref());
+            logger::debug!(current_resource_status=%invoice_response.status);
+
+            Ok((
+                OutgoingWebhookContent::InvoiceDetails(invoice_response),
+                event_type,
+            ))
+        }
         #[cfg(feature = "payouts")]
         diesel_models::enums::EventClass::Payouts => {
             let payout_id = tracking_data.primary_object_id.clone();
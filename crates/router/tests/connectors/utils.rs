@@ -405,6 +405,7 @@ pub trait ConnectorActions: Connector {
                 connector_refund_id: Some(refund_id),
                 browser_info: None,
                 charges: None,
+                split_refunds: None,
                 integrity_object: None,
             }),
             payment_info,
@@ -942,8 +943,11 @@ impl Default for PaymentAuthorizeType {
             authentication_data: None,
             customer_acceptance: None,
             charges: None,
+            sca_exemption: None,
             integrity_object: None,
             merchant_order_reference_id: None,
+            l2_l3_data: None,
+            split_payments: None,
         };
         Self(data)
     }
@@ -1026,6 +1030,7 @@ impl Default for PaymentRefundType {
             connector_refund_id: None,
             browser_info: None,
             charges: None,
+            split_refunds: None,
             integrity_object: None,
         };
         Self(data)
@@ -89,6 +89,12 @@ pub enum Flow {
     MerchantConnectorsList,
     /// Merchant Transfer Keys
     MerchantTransferKey,
+    /// Merchant key rotation start flow
+    MerchantKeyRotationStart,
+    /// Merchant key rotation list flow
+    MerchantKeyRotationList,
+    /// Merchant key rotation retrieve flow
+    MerchantKeyRotationRetrieve,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -129,6 +135,14 @@ pub enum Flow {
     CustomerPaymentMethodsList,
     /// List Customers for a merchant
     CustomersList,
+    /// Create a data subject export or erasure request for a customer
+    DataSubjectRequestCreate,
+    /// List data subject requests for a merchant
+    DataSubjectRequestList,
+    /// Retrieve a data subject request
+    DataSubjectRequestRetrieve,
+    /// Download the export archive of a data subject request
+    DataSubjectArchiveDownload,
     /// Retrieve countries and currencies for connector and payment method
     ListCountriesCurrencies,
     /// Payment method create collect link flow.
@@ -194,6 +208,18 @@ pub enum Flow {
     PayoutsAccounts,
     /// Payout link initiate flow
     PayoutLinkInitiate,
+    /// Payout batch create flow.
+    PayoutBatchCreate,
+    /// Payout batch approve flow.
+    PayoutBatchApprove,
+    /// Payout batch reject flow.
+    PayoutBatchReject,
+    /// Payout batch retrieve flow.
+    PayoutBatchRetrieve,
+    /// Payout batch list flow.
+    PayoutBatchList,
+    /// Payout batch report download flow.
+    PayoutBatchReport,
     /// Payments Redirect flow
     PaymentsRedirect,
     /// Payemnts Complete Authorize Flow
@@ -210,6 +236,14 @@ pub enum Flow {
     RefundsList,
     /// Refunds filters flow
     RefundsFilters,
+    /// Refund batch create flow.
+    RefundBatchCreate,
+    /// Refund batch retrieve flow.
+    RefundBatchRetrieve,
+    /// Refund batch list flow.
+    RefundBatchList,
+    /// Refund batch result download flow.
+    RefundBatchDownload,
     // Retrieve forex flow.
     RetrieveForexFlow,
     /// Toggles recon service for a merchant.
@@ -266,8 +300,20 @@ pub enum Flow {
     DisputesRetrieve,
     /// Dispute List flow
     DisputesList,
+    /// Upcoming dispute evidence deadlines list flow
+    DisputeDeadlinesList,
+    /// Dispute deadline policy upsert flow
+    DisputeDeadlinePolicyUpsert,
+    /// Dispute deadline policy retrieve flow
+    DisputeDeadlinePolicyRetrieve,
     /// Cards Info flow
     CardsInfo,
+    /// Card BIN ranges upload flow
+    CardBinRangesUpload,
+    /// Card BIN ranges list flow
+    CardBinRangesList,
+    /// Card BIN range delete flow
+    CardBinRangeDelete,
     /// Create File flow
     CreateFile,
     /// Delete File flow
@@ -284,6 +330,10 @@ pub enum Flow {
     DeleteDisputeEvidence,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Dispute Evidence draft assembly flow
+    DisputeEvidenceDraftCreate,
+    /// Dispute Evidence draft submit flow
+    DisputeEvidenceDraftSubmit,
     /// Invalidate cache flow
     CacheInvalidate,
     /// Payment Link Retrieve flow
@@ -296,6 +346,52 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Payment Link QR code retrieve flow
+    PaymentLinkQrCodeRetrieve,
+    /// Payment Link invoice retrieve flow
+    PaymentLinkInvoiceRetrieve,
+    /// Reusable Payment Link Create flow
+    ReusablePaymentLinkCreate,
+    /// Reusable Payment Link List flow
+    ReusablePaymentLinkList,
+    /// Reusable Payment Link Retrieve flow
+    ReusablePaymentLinkRetrieve,
+    /// Reusable Payment Link Update flow
+    ReusablePaymentLinkUpdate,
+    /// Reusable Payment Link Sales Summary flow
+    ReusablePaymentLinkSalesSummary,
+    /// Reusable Payment Link Visit flow
+    ReusablePaymentLinkVisit,
+    /// Reusable Payment Link Initiate flow
+    ReusablePaymentLinkInitiate,
+    /// Invoice Create flow
+    InvoiceCreate,
+    /// Invoice List flow
+    InvoiceList,
+    /// Invoice Retrieve flow
+    InvoiceRetrieve,
+    /// Invoice Update flow
+    InvoiceUpdate,
+    /// Invoice Finalize flow
+    InvoiceFinalize,
+    /// Invoice Void flow
+    InvoiceVoid,
+    /// Invoice Mark Uncollectible flow
+    InvoiceMarkUncollectible,
+    /// Invoice out of band payment record flow
+    InvoiceRecordPayment,
+    /// Invoice payment link create flow
+    InvoicePaymentLinkCreate,
+    /// Invoice number sequence create or update flow
+    InvoiceNumberSequenceUpsert,
+    /// Ledger balances retrieve flow
+    LedgerBalancesRetrieve,
+    /// Ledger account statement retrieve flow
+    LedgerStatementRetrieve,
+    /// Ledger journal entries list flow
+    LedgerEntryList,
+    /// Ledger rebuild from historical data flow
+    LedgerRebuild,
     /// Create a business profile
     BusinessProfileCreate,
     /// Update a business profile
@@ -320,6 +416,24 @@ pub enum Flow {
     ApplePayCertificatesMigration,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Gsm Rules bulk import flow
+    GsmRulesImport,
+    /// Gsm Rules export flow
+    GsmRulesExport,
+    /// Gsm Rule history list flow
+    GsmRuleHistoryList,
+    /// Gsm Rule change revert flow
+    GsmRuleChangeRevert,
+    /// Data retention policy create or update flow
+    DataRetentionPolicyUpsert,
+    /// Data retention policy list flow
+    DataRetentionPolicyList,
+    /// Data retention policy delete flow
+    DataRetentionPolicyDelete,
+    /// Data retention archive list flow
+    DataRetentionArchiveList,
+    /// Data retention archive restore flow
+    DataRetentionArchiveRestore,
     /// User Sign Up
     UserSignUp,
     /// User Sign Up
@@ -338,6 +452,14 @@ pub enum Flow {
     DecisionManagerRetrieveConfig,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
+    /// Upsert native FRM rules flow
+    FrmRulesUpsert,
+    /// Retrieve native FRM rules flow
+    FrmRulesRetrieve,
+    /// Delete native FRM rules flow
+    FrmRulesDelete,
+    /// List fraud checks pending manual review
+    FrmReviewQueueList,
     /// Change password flow
     ChangePassword,
     /// Signout flow
@@ -422,6 +544,8 @@ pub enum Flow {
     CreateRole,
     /// Update Role
     UpdateRole,
+    /// Delete Role
+    DeleteRole,
     /// User email flow start
     UserFromEmail,
     /// Begin TOTP
@@ -458,6 +582,14 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Create a key to sign the outgoing webhooks of a business profile
+    WebhookSigningKeyCreate,
+    /// List the webhook signing keys of a business profile
+    WebhookSigningKeyList,
+    /// Revoke a webhook signing key
+    WebhookSigningKeyRevoke,
+    /// Retrieve the public keys to verify the outgoing webhooks of a business profile
+    WebhookSigningJwksRetrieve,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -470,6 +602,34 @@ pub enum Flow {
     RefundsManualUpdate,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
+    /// SCA exemption config upsert flow
+    ScaExemptionConfigUpsert,
+    /// SCA exemption config retrieve flow
+    ScaExemptionConfigRetrieve,
+    /// Split payments report flow
+    SplitPaymentsReport,
+    /// Provision a user through SCIM
+    ScimUserCreate,
+    /// Retrieve a SCIM provisioned user
+    ScimUserRetrieve,
+    /// List SCIM provisioned users
+    ScimUserList,
+    /// Replace the attributes of a SCIM provisioned user
+    ScimUserReplace,
+    /// Partially update a SCIM provisioned user
+    ScimUserPatch,
+    /// Deprovision a SCIM provisioned user
+    ScimUserDelete,
+    /// List roles exposed as SCIM groups
+    ScimGroupList,
+    /// Retrieve a role exposed as a SCIM group
+    ScimGroupRetrieve,
+    /// Update the members of a SCIM group
+    ScimGroupPatch,
+    /// Issue the SCIM token of a merchant
+    ScimTokenCreate,
+    /// Revoke the SCIM token of a merchant
+    ScimTokenRevoke,
 }
 
 ///
@@ -189,6 +189,15 @@ pub async fn fetch_consumer_tasks(
 ) -> CustomResult<Vec<storage::ProcessTracker>, errors::ProcessTrackerError> {
     let batches = pt_utils::get_batches(redis_conn, stream_name, group_name, consumer_name).await?;
 
+    start_batch_tasks(db, batches).await
+}
+
+/// Marks the tasks of the fetched `batches` with a valid business status as started and returns
+/// them.
+pub async fn start_batch_tasks(
+    db: &dyn ProcessTrackerInterface,
+    batches: Vec<types::ProcessTrackerBatch>,
+) -> CustomResult<Vec<storage::ProcessTracker>, errors::ProcessTrackerError> {
     // Returning early to avoid execution of database queries when `batches` is empty
     if batches.is_empty() {
         return Ok(Vec::new());
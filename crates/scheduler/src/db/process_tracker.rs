@@ -212,22 +212,50 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn reinitialize_limbo_processes(
         &self,
-        _ids: Vec<String>,
-        _schedule_time: PrimitiveDateTime,
+        ids: Vec<String>,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let mut count = 0;
+        for process in processes.iter_mut().filter(|process| {
+            process.status == storage_enums::ProcessTrackerStatus::ProcessStarted
+                && ids.contains(&process.id)
+        }) {
+            process.status = storage_enums::ProcessTrackerStatus::Processing;
+            process.schedule_time = Some(schedule_time);
+            count += 1;
+        }
+
+        Ok(count)
     }
 
     async fn find_processes_by_time_status(
         &self,
-        _time_lower_limit: PrimitiveDateTime,
-        _time_upper_limit: PrimitiveDateTime,
-        _status: storage_enums::ProcessTrackerStatus,
-        _limit: Option<i64>,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let limit = limit
+            .map(usize::try_from)
+            .transpose()
+            .change_context(errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+
+        Ok(self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                process.status == status
+                    && process.schedule_time.map_or(false, |schedule_time| {
+                        (time_lower_limit..=time_upper_limit).contains(&schedule_time)
+                    })
+            })
+            .take(limit)
+            .cloned()
+            .collect())
     }
 
     async fn insert_process(
@@ -256,46 +284,90 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn update_process(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let stored = processes
+            .iter_mut()
+            .find(|stored| stored.id == this.id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No process found for id = {}",
+                this.id
+            )))?;
+        *stored = storage::ProcessTrackerUpdateInternal::from(process).apply_changeset(this);
+
+        Ok(stored.clone())
     }
 
     async fn reset_process(
         &self,
-        _this: storage::ProcessTracker,
-        _schedule_time: PrimitiveDateTime,
+        this: storage::ProcessTracker,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::New,
+                retry_count: 0,
+                schedule_time,
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn retry_process(
         &self,
-        _this: storage::ProcessTracker,
-        _schedule_time: PrimitiveDateTime,
+        this: storage::ProcessTracker,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let retry_count = this.retry_count + 1;
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::Pending,
+                retry_count,
+                schedule_time,
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn finish_process_with_business_status(
         &self,
-        _this: storage::ProcessTracker,
-        _business_status: &'static str,
+        this: storage::ProcessTracker,
+        business_status: &'static str,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: storage_enums::ProcessTrackerStatus::Finish,
+                business_status: Some(String::from(business_status)),
+            },
+        )
+        .await
+        .attach_printable("Failed to update business status of process")?;
+        Ok(())
     }
 
     async fn process_tracker_update_process_status_by_ids(
         &self,
-        _task_ids: Vec<String>,
-        _task_update: storage::ProcessTrackerUpdate,
+        task_ids: Vec<String>,
+        task_update: storage::ProcessTrackerUpdate,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let update = storage::ProcessTrackerUpdateInternal::from(task_update);
+        let mut processes = self.processes.lock().await;
+        let mut count = 0;
+        for process in processes
+            .iter_mut()
+            .filter(|process| task_ids.contains(&process.id))
+        {
+            *process = update.clone().apply_changeset(process.clone());
+            count += 1;
+        }
+
+        Ok(count)
     }
 }
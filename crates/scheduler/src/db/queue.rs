@@ -1,10 +1,16 @@
+use std::time::{Duration, Instant};
+
 use common_utils::errors::CustomResult;
 use diesel_models::process_tracker as storage;
+use error_stack::{report, ResultExt};
 use redis_interface::{errors::RedisError, RedisEntryId, SetnxReply};
 use router_env::logger;
-use storage_impl::{mock_db::MockDb, redis::kv_store::RedisConnInterface};
+use storage_impl::{
+    mock_db::{MockDb, MockLock},
+    redis::kv_store::RedisConnInterface,
+};
 
-use crate::{errors::ProcessTrackerError, scheduler::Store};
+use crate::{consumer::types::ProcessTrackerBatch, errors::ProcessTrackerError, scheduler::Store};
 
 #[async_trait::async_trait]
 pub trait QueueInterface {
@@ -138,53 +144,363 @@ impl QueueInterface for Store {
 impl QueueInterface for MockDb {
     async fn fetch_consumer_tasks(
         &self,
-        _stream_name: &str,
-        _group_name: &str,
+        stream_name: &str,
+        group_name: &str,
         _consumer_name: &str,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
-        // [#172]: Implement function for `MockDb`
-        Err(ProcessTrackerError::ResourceFetchingFailed {
-            resource_name: "consumer_tasks".to_string(),
-        })?
+        // Reading an entry acknowledges and deletes it right away, as `get_batches` does
+        let entry = {
+            let mut streams = self.streams.lock().await;
+            let stream = streams
+                .get_mut(stream_name)
+                .ok_or(ProcessTrackerError::BatchNotFound)
+                .attach_printable_lazy(|| format!("Stream {stream_name} does not exist"))?;
+            let last_delivered_id = stream
+                .groups
+                .get(group_name)
+                .copied()
+                .ok_or(ProcessTrackerError::BatchNotFound)
+                .attach_printable_lazy(|| format!("Consumer group {group_name} does not exist"))?;
+
+            stream
+                .entries
+                .iter()
+                .position(|(id, _)| *id > last_delivered_id)
+                .map(|position| {
+                    let (id, fields) = stream.entries.remove(position);
+                    stream.groups.insert(group_name.to_owned(), id);
+                    fields
+                })
+        };
+
+        let batches = entry
+            .map(|fields| {
+                ProcessTrackerBatch::from_redis_stream_entry(
+                    fields
+                        .into_iter()
+                        .map(|(field, value)| (field, Some(value)))
+                        .collect(),
+                )
+            })
+            .transpose()?
+            .into_iter()
+            .collect();
+
+        crate::consumer::start_batch_tasks(self, batches).await
     }
 
     async fn consumer_group_create(
         &self,
-        _stream: &str,
-        _group: &str,
-        _id: &RedisEntryId,
+        stream: &str,
+        group: &str,
+        id: &RedisEntryId,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::ConsumerGroupCreateFailed)?
+        let mut streams = self.streams.lock().await;
+        let stream = streams.entry(stream.to_owned()).or_default();
+        let last_delivered_id = match id {
+            RedisEntryId::AfterLastID => stream.last_id,
+            RedisEntryId::UserSpecifiedID { .. } => parse_entry_id(id)?,
+            RedisEntryId::AutoGeneratedID | RedisEntryId::UndeliveredEntryID => {
+                Err(RedisError::InvalidRedisEntryId)?
+            }
+        };
+
+        if stream.groups.contains_key(group) {
+            return Err(report!(RedisError::ConsumerGroupCreateFailed))
+                .attach_printable_lazy(|| format!("Consumer group {group} already exists"));
+        }
+        stream.groups.insert(group.to_owned(), last_delivered_id);
+
+        Ok(())
     }
 
     async fn acquire_pt_lock(
         &self,
-        _tag: &str,
-        _lock_key: &str,
-        _lock_val: &str,
-        _ttl: i64,
+        tag: &str,
+        lock_key: &str,
+        lock_val: &str,
+        ttl: i64,
     ) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+        let now = Instant::now();
+        let mut locks = self.locks.lock().await;
+
+        if locks
+            .get(lock_key)
+            .map_or(false, |lock| lock.expires_at > now)
+        {
+            logger::error!(%tag, "Lock not acquired, previous fetch still in progress");
+            return Ok(false);
+        }
+        locks.insert(
+            lock_key.to_owned(),
+            MockLock {
+                value: lock_val.to_owned(),
+                expires_at: now + Duration::from_secs(u64::try_from(ttl).unwrap_or_default()),
+            },
+        );
+
+        Ok(true)
     }
 
-    async fn release_pt_lock(&self, _tag: &str, _lock_key: &str) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+    async fn release_pt_lock(&self, _tag: &str, lock_key: &str) -> CustomResult<bool, RedisError> {
+        self.locks.lock().await.remove(lock_key);
+        Ok(true)
     }
 
     async fn stream_append_entry(
         &self,
-        _stream: &str,
-        _entry_id: &RedisEntryId,
-        _fields: Vec<(&str, String)>,
+        stream: &str,
+        entry_id: &RedisEntryId,
+        fields: Vec<(&str, String)>,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::StreamAppendFailed)?
+        let mut streams = self.streams.lock().await;
+        let stream = streams.entry(stream.to_owned()).or_default();
+        let (last_milliseconds, last_sequence_number) = stream.last_id;
+        let id = match entry_id {
+            RedisEntryId::AutoGeneratedID => {
+                let milliseconds = u64::try_from(
+                    time::OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000,
+                )
+                .change_context(RedisError::StreamAppendFailed)?;
+                if milliseconds > last_milliseconds {
+                    (milliseconds, 0)
+                } else {
+                    (last_milliseconds, last_sequence_number + 1)
+                }
+            }
+            RedisEntryId::UserSpecifiedID { .. } => {
+                parse_entry_id(entry_id).change_context(RedisError::StreamAppendFailed)?
+            }
+            RedisEntryId::AfterLastID | RedisEntryId::UndeliveredEntryID => {
+                Err(RedisError::InvalidRedisEntryId)?
+            }
+        };
+
+        if id <= stream.last_id {
+            return Err(report!(RedisError::StreamAppendFailed)).attach_printable(
+                "The entry id must be greater than the id of the last entry of the stream",
+            );
+        }
+        stream.last_id = id;
+        stream.entries.push((
+            id,
+            fields
+                .into_iter()
+                .map(|(field, value)| (field.to_owned(), value))
+                .collect(),
+        ));
+
+        Ok(())
+    }
+
+    async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
+        self.get_redis_conn()?.get_key::<Vec<u8>>(key).await
     }
+}
+
+fn parse_entry_id(id: &RedisEntryId) -> CustomResult<(u64, u64), RedisError> {
+    match id {
+        RedisEntryId::UserSpecifiedID {
+            milliseconds,
+            sequence_number,
+        } => Ok((
+            milliseconds
+                .parse()
+                .change_context(RedisError::InvalidRedisEntryId)?,
+            sequence_number
+                .parse()
+                .change_context(RedisError::InvalidRedisEntryId)?,
+        )),
+        RedisEntryId::AutoGeneratedID
+        | RedisEntryId::AfterLastID
+        | RedisEntryId::UndeliveredEntryID => Err(RedisError::InvalidRedisEntryId.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use diesel_models::{enums::ProcessTrackerStatus, process_tracker::ProcessTrackerRunner};
+
+    use super::*;
+    use crate::{
+        configs::settings::SchedulerSettings, db::process_tracker::ProcessTrackerInterface,
+        flow::SchedulerFlow, producer, utils as pt_utils,
+    };
+
+    async fn setup() -> (MockDb, SchedulerSettings) {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let settings = SchedulerSettings::default();
+        db.consumer_group_create(
+            &settings.stream,
+            &settings.consumer.consumer_group,
+            &RedisEntryId::AfterLastID,
+        )
+        .await
+        .unwrap();
 
-    async fn get_key(&self, _key: &str) -> CustomResult<Vec<u8>, RedisError> {
-        Err(RedisError::RedisConnectionError.into())
+        (db, settings)
+    }
+
+    async fn insert_process(db: &MockDb, id: &str) {
+        db.insert_process(
+            storage::ProcessTrackerNew::new(
+                id,
+                "PAYMENTS_SYNC",
+                ProcessTrackerRunner::PaymentsSyncWorkflow,
+                ["PAYMENT"],
+                serde_json::json!({}),
+                common_utils::date_time::now() - time::Duration::minutes(1),
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    }
+
+    async fn run_producer(db: &MockDb, settings: &SchedulerSettings) {
+        pt_utils::lock_acquire_release(db, settings, move || async move {
+            let tasks = producer::fetch_producer_tasks(db, settings).await?;
+            pt_utils::divide_and_append_tasks(db, SchedulerFlow::Producer, tasks, settings).await
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_producer_to_consumer_cycle() {
+        let (db, settings) = setup().await;
+        insert_process(&db, "process_1").await;
+        insert_process(&db, "process_2").await;
+
+        run_producer(&db, &settings).await;
+
+        let processes = db.processes.lock().await.clone();
+        assert!(processes
+            .iter()
+            .all(|process| process.status == ProcessTrackerStatus::Processing));
+        assert!(db.locks.lock().await.is_empty());
+
+        let tasks = db
+            .fetch_consumer_tasks(
+                &settings.stream,
+                &settings.consumer.consumer_group,
+                "consumer_1",
+            )
+            .await
+            .unwrap();
+        let mut task_ids = tasks
+            .iter()
+            .map(|task| task.id.as_str())
+            .collect::<Vec<_>>();
+        task_ids.sort_unstable();
+
+        assert_eq!(task_ids, ["process_1", "process_2"]);
+        assert!(tasks
+            .iter()
+            .all(|task| task.status == ProcessTrackerStatus::ProcessStarted));
+        let processes = db.processes.lock().await.clone();
+        assert!(processes
+            .iter()
+            .all(|process| process.status == ProcessTrackerStatus::ProcessStarted));
+
+        // The batch is consumed only once
+        let tasks = db
+            .fetch_consumer_tasks(
+                &settings.stream,
+                &settings.consumer.consumer_group,
+                "consumer_2",
+            )
+            .await
+            .unwrap();
+        assert!(tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_consumer_group_reads_entries_appended_after_its_creation() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let settings = SchedulerSettings::default();
+        insert_process(&db, "process_1").await;
+        run_producer(&db, &settings).await;
+
+        db.consumer_group_create(
+            &settings.stream,
+            &settings.consumer.consumer_group,
+            &RedisEntryId::AfterLastID,
+        )
+        .await
+        .unwrap();
+        let tasks = db
+            .fetch_consumer_tasks(
+                &settings.stream,
+                &settings.consumer.consumer_group,
+                "consumer_1",
+            )
+            .await
+            .unwrap();
+        assert!(tasks.is_empty());
+
+        let result = db
+            .consumer_group_create(
+                &settings.stream,
+                &settings.consumer.consumer_group,
+                &RedisEntryId::AfterLastID,
+            )
+            .await;
+        assert_eq!(
+            *result.unwrap_err().current_context(),
+            RedisError::ConsumerGroupCreateFailed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_append_entry_rejects_non_increasing_ids() {
+        let (db, settings) = setup().await;
+        let entry_id = RedisEntryId::UserSpecifiedID {
+            milliseconds: "1".to_string(),
+            sequence_number: "0".to_string(),
+        };
+
+        db.stream_append_entry(&settings.stream, &entry_id, vec![("id", "1".to_string())])
+            .await
+            .unwrap();
+        let result = db
+            .stream_append_entry(&settings.stream, &entry_id, vec![("id", "2".to_string())])
+            .await;
+
+        assert_eq!(
+            *result.unwrap_err().current_context(),
+            RedisError::StreamAppendFailed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pt_lock_is_exclusive_until_released_or_expired() {
+        let (db, _settings) = setup().await;
+
+        assert!(db
+            .acquire_pt_lock("TAG", "LOCK", "LOCKED", 60)
+            .await
+            .unwrap());
+        assert!(!db
+            .acquire_pt_lock("TAG", "LOCK", "LOCKED", 60)
+            .await
+            .unwrap());
+
+        assert!(db.release_pt_lock("TAG", "LOCK").await.unwrap());
+        assert!(db
+            .acquire_pt_lock("TAG", "LOCK", "LOCKED", 0)
+            .await
+            .unwrap());
+
+        // A lock with no time to live left has expired
+        assert!(db
+            .acquire_pt_lock("TAG", "LOCK", "LOCKED", 60)
+            .await
+            .unwrap());
     }
 }
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc, time::Instant};
 
 use diesel_models::{self as store};
 use error_stack::ResultExt;
@@ -37,6 +37,7 @@ pub struct MockDb {
     pub api_keys: Arc<Mutex<Vec<store::ApiKey>>>,
     pub ephemeral_keys: Arc<Mutex<Vec<store::EphemeralKey>>>,
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
+    pub card_bin_ranges: Arc<Mutex<Vec<store::CardBinRange>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
@@ -60,6 +61,57 @@ pub struct MockDb {
     pub user_key_store: Arc<Mutex<Vec<store::user_key_store::UserKeyStore>>>,
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
+    pub scim_users: Arc<Mutex<Vec<store::scim::ScimUser>>>,
+    pub refund_batches: Arc<Mutex<Vec<store::refund_batch::RefundBatch>>>,
+    pub refund_batch_items: Arc<Mutex<Vec<store::refund_batch::RefundBatchItem>>>,
+    pub payout_batches: Arc<Mutex<Vec<store::payout_batch::PayoutBatch>>>,
+    pub payout_batch_items: Arc<Mutex<Vec<store::payout_batch::PayoutBatchItem>>>,
+    pub blocklists: Arc<Mutex<Vec<store::blocklist::Blocklist>>>,
+    pub blocklist_fingerprints: Arc<Mutex<Vec<store::blocklist_fingerprint::BlocklistFingerprint>>>,
+    pub blocklist_lookups: Arc<Mutex<Vec<store::blocklist_lookup::BlocklistLookup>>>,
+    pub file_metadata: Arc<Mutex<Vec<store::file::FileMetadata>>>,
+    pub fraud_checks: Arc<Mutex<Vec<store::fraud_check::FraudCheck>>>,
+    pub generic_links: Arc<Mutex<Vec<store::generic_link::GenericLink>>>,
+    pub gsm_rules: Arc<Mutex<Vec<store::gsm::GatewayStatusMap>>>,
+    pub gsm_rule_history: Arc<Mutex<Vec<store::gsm::GatewayStatusMapHistory>>>,
+    pub data_retention_policies: Arc<Mutex<Vec<store::data_retention::DataRetentionPolicy>>>,
+    pub data_retention_archives: Arc<Mutex<Vec<store::data_retention::DataRetentionArchive>>>,
+    pub data_subject_requests: Arc<Mutex<Vec<store::data_subject_request::DataSubjectRequest>>>,
+    pub merchant_key_rotations: Arc<Mutex<Vec<store::merchant_key_rotation::MerchantKeyRotation>>>,
+    pub routing_algorithms: Arc<Mutex<Vec<store::routing_algorithm::RoutingAlgorithm>>>,
+    pub webhook_signing_keys: Arc<Mutex<Vec<store::webhook_signing_key::WebhookSigningKey>>>,
+    pub reusable_payment_links: Arc<Mutex<Vec<store::reusable_payment_link::ReusablePaymentLink>>>,
+    pub reusable_payment_link_items:
+        Arc<Mutex<Vec<store::reusable_payment_link::ReusablePaymentLinkItem>>>,
+    pub reusable_payment_link_payments:
+        Arc<Mutex<Vec<store::reusable_payment_link::ReusablePaymentLinkPayment>>>,
+    pub invoices: Arc<Mutex<Vec<store::invoice::Invoice>>>,
+    pub invoice_line_items: Arc<Mutex<Vec<store::invoice::InvoiceLineItem>>>,
+    pub invoice_number_sequences: Arc<Mutex<Vec<store::invoice::InvoiceNumberSequence>>>,
+    pub invoice_payments: Arc<Mutex<Vec<store::invoice::InvoicePayment>>>,
+    pub ledger_entries: Arc<Mutex<Vec<store::ledger::LedgerEntry>>>,
+    pub ledger_postings: Arc<Mutex<Vec<store::ledger::LedgerPosting>>>,
+    pub streams: Arc<Mutex<HashMap<String, MockStream>>>,
+    pub locks: Arc<Mutex<HashMap<String, MockLock>>>,
+}
+
+/// In-memory stand-in for a redis stream and the consumer groups reading it.
+#[derive(Debug, Default)]
+pub struct MockStream {
+    /// Entries not yet consumed, with their `(milliseconds, sequence_number)` ids in the order
+    /// they were appended
+    pub entries: Vec<((u64, u64), Vec<(String, String)>)>,
+    /// Id of the last entry delivered to each consumer group
+    pub groups: HashMap<String, (u64, u64)>,
+    /// Id of the last entry appended to the stream
+    pub last_id: (u64, u64),
+}
+
+/// In-memory stand-in for a redis key set with an expiry.
+#[derive(Debug, Clone)]
+pub struct MockLock {
+    pub value: String,
+    pub expires_at: Instant,
 }
 
 impl MockDb {
@@ -83,6 +135,7 @@ impl MockDb {
             api_keys: Default::default(),
             ephemeral_keys: Default::default(),
             cards_info: Default::default(),
+            card_bin_ranges: Default::default(),
             events: Default::default(),
             disputes: Default::default(),
             lockers: Default::default(),
@@ -105,6 +158,36 @@ impl MockDb {
             roles: Default::default(),
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
+            scim_users: Default::default(),
+            refund_batches: Default::default(),
+            refund_batch_items: Default::default(),
+            payout_batches: Default::default(),
+            payout_batch_items: Default::default(),
+            blocklists: Default::default(),
+            blocklist_fingerprints: Default::default(),
+            blocklist_lookups: Default::default(),
+            file_metadata: Default::default(),
+            fraud_checks: Default::default(),
+            generic_links: Default::default(),
+            gsm_rules: Default::default(),
+            gsm_rule_history: Default::default(),
+            data_retention_policies: Default::default(),
+            data_retention_archives: Default::default(),
+            data_subject_requests: Default::default(),
+            merchant_key_rotations: Default::default(),
+            routing_algorithms: Default::default(),
+            webhook_signing_keys: Default::default(),
+            reusable_payment_links: Default::default(),
+            reusable_payment_link_items: Default::default(),
+            reusable_payment_link_payments: Default::default(),
+            invoices: Default::default(),
+            invoice_line_items: Default::default(),
+            invoice_number_sequences: Default::default(),
+            invoice_payments: Default::default(),
+            ledger_entries: Default::default(),
+            ledger_postings: Default::default(),
+            streams: Default::default(),
+            locks: Default::default(),
         })
     }
 }
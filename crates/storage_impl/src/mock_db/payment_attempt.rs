@@ -1,6 +1,9 @@
+use std::collections::HashSet;
+
 use api_models::enums::{AuthenticationType, Connector, PaymentMethod, PaymentMethodType};
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     errors::StorageError,
     payments::payment_attempt::{
@@ -15,79 +18,151 @@ use crate::DataModelExt;
 impl PaymentAttemptInterface for MockDb {
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
         &self,
-        _payment_id: &str,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _attempt_id: &str,
+        payment_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_mock_payment_attempt(|payment_attempt| {
+            payment_attempt.payment_id == payment_id
+                && payment_attempt.merchant_id == *merchant_id
+                && payment_attempt.attempt_id == attempt_id
+        })
+        .await
     }
 
     async fn get_filters_for_payments(
         &self,
-        _pi: &[hyperswitch_domain_models::payments::PaymentIntent],
-        _merchant_id: &common_utils::id_type::MerchantId,
+        pi: &[hyperswitch_domain_models::payments::PaymentIntent],
+        merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         hyperswitch_domain_models::payments::payment_attempt::PaymentListFilters,
         StorageError,
     > {
-        Err(StorageError::MockDbError)?
+        let active_attempt_ids = pi
+            .iter()
+            .map(|payment_intent| payment_intent.active_attempt.get_id())
+            .collect::<HashSet<_>>();
+        let payment_attempts = self.payment_attempts.lock().await;
+        let active_attempts = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && active_attempt_ids.contains(&payment_attempt.attempt_id)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(
+            hyperswitch_domain_models::payments::payment_attempt::PaymentListFilters {
+                connector: distinct(active_attempts.iter().map(|pa| pa.connector.clone())),
+                currency: distinct(active_attempts.iter().map(|pa| pa.currency)),
+                status: distinct(pi.iter().map(|payment_intent| Some(payment_intent.status))),
+                payment_method: distinct(active_attempts.iter().map(|pa| pa.payment_method)),
+                payment_method_type: distinct(
+                    active_attempts.iter().map(|pa| pa.payment_method_type),
+                ),
+                authentication_type: distinct(
+                    active_attempts.iter().map(|pa| pa.authentication_type),
+                ),
+            },
+        )
     }
 
     async fn get_total_count_of_filtered_payment_attempts(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _active_attempt_ids: &[String],
-        _connector: Option<Vec<Connector>>,
-        _payment_method: Option<Vec<PaymentMethod>>,
-        _payment_method_type: Option<Vec<PaymentMethodType>>,
-        _authentication_type: Option<Vec<AuthenticationType>>,
-        _merchanat_connector_id: Option<Vec<String>>,
+        merchant_id: &common_utils::id_type::MerchantId,
+        active_attempt_ids: &[String],
+        connector: Option<Vec<Connector>>,
+        payment_method: Option<Vec<PaymentMethod>>,
+        payment_method_type: Option<Vec<PaymentMethodType>>,
+        authentication_type: Option<Vec<AuthenticationType>>,
+        merchant_connector_id: Option<Vec<String>>,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<i64, StorageError> {
-        Err(StorageError::MockDbError)?
+        let connector = connector.map(|connector| {
+            connector
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+        });
+        let count = self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && active_attempt_ids.contains(&payment_attempt.attempt_id)
+                    && matches_any(&connector, &payment_attempt.connector)
+                    && matches_any(&payment_method, &payment_attempt.payment_method)
+                    && matches_any(&payment_method_type, &payment_attempt.payment_method_type)
+                    && matches_any(&authentication_type, &payment_attempt.authentication_type)
+                    && matches_any(
+                        &merchant_connector_id,
+                        &payment_attempt.merchant_connector_id,
+                    )
+            })
+            .count();
+
+        i64::try_from(count).change_context(StorageError::MockDbError)
     }
 
     async fn find_payment_attempt_by_attempt_id_merchant_id(
         &self,
-        _attempt_id: &str,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_mock_payment_attempt(|payment_attempt| {
+            payment_attempt.attempt_id == attempt_id && payment_attempt.merchant_id == *merchant_id
+        })
+        .await
     }
 
     async fn find_payment_attempt_by_preprocessing_id_merchant_id(
         &self,
-        _preprocessing_id: &str,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        preprocessing_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_mock_payment_attempt(|payment_attempt| {
+            payment_attempt.preprocessing_step_id.as_deref() == Some(preprocessing_id)
+                && payment_attempt.merchant_id == *merchant_id
+        })
+        .await
     }
 
     async fn find_payment_attempt_by_merchant_id_connector_txn_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _connector_txn_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_txn_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_mock_payment_attempt(|payment_attempt| {
+            payment_attempt.merchant_id == *merchant_id
+                && payment_attempt.connector_transaction_id.as_deref() == Some(connector_txn_id)
+        })
+        .await
     }
 
     async fn find_attempts_by_merchant_id_payment_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payment_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        Ok(self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && payment_attempt.payment_id == payment_id
+            })
+            .cloned()
+            .collect())
     }
 
     #[allow(clippy::panic)]
@@ -158,6 +233,7 @@ impl PaymentAttemptInterface for MockDb {
             client_source: payment_attempt.client_source,
             client_version: payment_attempt.client_version,
             customer_acceptance: payment_attempt.customer_acceptance,
+            sca_exemption: payment_attempt.sca_exemption,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -189,13 +265,17 @@ impl PaymentAttemptInterface for MockDb {
 
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
-        _connector_transaction_id: &str,
-        _payment_id: &str,
-        _merchant_id: &common_utils::id_type::MerchantId,
+        connector_transaction_id: &str,
+        payment_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_mock_payment_attempt(|payment_attempt| {
+            payment_attempt.connector_transaction_id.as_deref() == Some(connector_transaction_id)
+                && payment_attempt.payment_id == payment_id
+                && payment_attempt.merchant_id == *merchant_id
+        })
+        .await
     }
 
     // safety: only used for testing
@@ -238,3 +318,32 @@ impl PaymentAttemptInterface for MockDb {
             .unwrap())
     }
 }
+
+impl MockDb {
+    async fn find_mock_payment_attempt(
+        &self,
+        predicate: impl Fn(&PaymentAttempt) -> bool,
+    ) -> CustomResult<PaymentAttempt, StorageError> {
+        self.payment_attempts
+            .lock()
+            .await
+            .iter()
+            .find(|payment_attempt| predicate(payment_attempt))
+            .cloned()
+            .ok_or(StorageError::ValueNotFound("cannot find payment attempt".to_string()).into())
+    }
+}
+
+fn distinct<T: Eq + std::hash::Hash>(values: impl Iterator<Item = Option<T>>) -> Vec<T> {
+    values
+        .flatten()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn matches_any<T: PartialEq>(filter: &Option<Vec<T>>, value: &Option<T>) -> bool {
+    filter.as_ref().map_or(true, |filter| {
+        value.as_ref().map_or(false, |value| filter.contains(value))
+    })
+}
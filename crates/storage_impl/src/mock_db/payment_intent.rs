@@ -20,47 +20,120 @@ impl PaymentIntentInterface for MockDb {
     async fn filter_payment_intent_by_constraints(
         &self,
         _state: &KeyManagerState,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _filters: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
         _key_store: &MerchantKeyStore,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payment_intents = self
+            .filter_mock_payment_intents(merchant_id, filters)
+            .await?;
+        payment_intents.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(paginate(payment_intents, filters))
     }
     #[cfg(feature = "olap")]
     async fn filter_payment_intents_by_time_range_constraints(
         &self,
-        _state: &KeyManagerState,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _time_range: &api_models::payments::TimeRange,
-        _key_store: &MerchantKeyStore,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &api_models::payments::TimeRange,
+        key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_filters = (*time_range).into();
+        self.filter_payment_intent_by_constraints(
+            state,
+            merchant_id,
+            &payment_filters,
+            key_store,
+            storage_scheme,
+        )
+        .await
     }
     #[cfg(feature = "olap")]
     async fn get_filtered_active_attempt_ids_for_total_count(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<String>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payment_intents = self
+            .filter_mock_payment_intents(merchant_id, constraints)
+            .await?;
+        payment_intents.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(payment_intents
+            .into_iter()
+            .map(|payment_intent| payment_intent.active_attempt.get_id())
+            .collect())
     }
     #[cfg(feature = "olap")]
     async fn get_filtered_payment_intents_attempt(
         &self,
         _state: &KeyManagerState,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
         _key_store: &MerchantKeyStore,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<(PaymentIntent, PaymentAttempt)>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        use api_models::payments::{SortBy, SortOn};
+        use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+
+        let payment_intents = self
+            .filter_mock_payment_intents(merchant_id, constraints)
+            .await?;
+        let payment_attempts = self.payment_attempts.lock().await;
+        let mut results = payment_intents
+            .into_iter()
+            .filter_map(|payment_intent| {
+                let active_attempt_id = payment_intent.active_attempt.get_id();
+                payment_attempts
+                    .iter()
+                    .find(|payment_attempt| payment_attempt.attempt_id == active_attempt_id)
+                    .cloned()
+                    .map(|payment_attempt| (payment_intent, payment_attempt))
+            })
+            .collect::<Vec<_>>();
+        drop(payment_attempts);
+
+        if let PaymentIntentFetchConstraints::List(params) = constraints {
+            let connectors = params
+                .connector
+                .as_ref()
+                .map(|c| c.iter().map(|c| c.to_string()).collect::<Vec<String>>());
+            results.retain(|(_, payment_attempt)| {
+                matches_any(&connectors, &payment_attempt.connector)
+                    && matches_any(&params.payment_method, &payment_attempt.payment_method)
+                    && matches_any(
+                        &params.payment_method_type,
+                        &payment_attempt.payment_method_type,
+                    )
+                    && matches_any(
+                        &params.authentication_type,
+                        &payment_attempt.authentication_type,
+                    )
+                    && matches_any(
+                        &params.merchant_connector_id,
+                        &payment_attempt.merchant_connector_id,
+                    )
+            });
+
+            match (&params.order.on, &params.order.by) {
+                (SortOn::Amount, SortBy::Asc) => {
+                    results.sort_by_key(|(pi, _)| pi.amount.get_amount_as_i64())
+                }
+                (SortOn::Amount, SortBy::Desc) => {
+                    results.sort_by_key(|(pi, _)| std::cmp::Reverse(pi.amount.get_amount_as_i64()))
+                }
+                (SortOn::Created, SortBy::Asc) => results.sort_by_key(|(pi, _)| pi.created_at),
+                (SortOn::Created, SortBy::Desc) => {
+                    results.sort_by_key(|(pi, _)| std::cmp::Reverse(pi.created_at))
+                }
+            }
+        }
+
+        Ok(paginate(results, constraints))
     }
 
     #[allow(clippy::panic)]
@@ -153,3 +226,117 @@ impl PaymentIntentInterface for MockDb {
         }
     }
 }
+
+#[cfg(feature = "olap")]
+impl MockDb {
+    /// Applies the payment intent level constraints, leaving ordering and pagination to the caller.
+    async fn filter_mock_payment_intents(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+    ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
+        use api_models::payments::AmountFilter;
+        use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+
+        let payment_intents = self.payment_intents.lock().await;
+        let created_at_of = |payment_id: &String| {
+            payment_intents
+                .iter()
+                .find(|payment_intent| {
+                    payment_intent.payment_id == *payment_id
+                        && payment_intent.merchant_id == *merchant_id
+                })
+                .map(|payment_intent| payment_intent.created_at)
+                .ok_or(StorageError::ValueNotFound(format!(
+                    "cannot find payment intent with payment_id = {payment_id}"
+                )))
+        };
+
+        match constraints {
+            PaymentIntentFetchConstraints::Single { payment_intent_id } => Ok(payment_intents
+                .iter()
+                .filter(|payment_intent| {
+                    payment_intent.merchant_id == *merchant_id
+                        && payment_intent.payment_id == *payment_intent_id
+                })
+                .cloned()
+                .collect()),
+            PaymentIntentFetchConstraints::List(params) => {
+                let starting_at = match (params.starting_at, &params.starting_after_id) {
+                    (Some(starting_at), _) => Some(starting_at),
+                    (None, Some(starting_after_id)) => Some(created_at_of(starting_after_id)?),
+                    (None, None) => None,
+                };
+                let ending_at = match (params.ending_at, &params.ending_before_id) {
+                    (Some(ending_at), _) => Some(ending_at),
+                    (None, Some(ending_before_id)) => Some(created_at_of(ending_before_id)?),
+                    (None, None) => None,
+                };
+
+                Ok(payment_intents
+                    .iter()
+                    .filter(|payment_intent| {
+                        let amount = payment_intent.amount.get_amount_as_i64();
+                        let amount_matches = match params.amount_filter {
+                            Some(AmountFilter {
+                                start_amount,
+                                end_amount,
+                            }) => {
+                                start_amount.map_or(true, |start| amount >= start)
+                                    && end_amount.map_or(true, |end| amount <= end)
+                            }
+                            None => true,
+                        };
+
+                        payment_intent.merchant_id == *merchant_id
+                            && amount_matches
+                            && params.customer_id.as_ref().map_or(true, |customer_id| {
+                                payment_intent.customer_id.as_ref() == Some(customer_id)
+                            })
+                            && params.profile_id.as_ref().map_or(true, |profile_id| {
+                                payment_intent.profile_id.as_ref() == Some(profile_id)
+                            })
+                            && starting_at.map_or(true, |starting_at| {
+                                payment_intent.created_at >= starting_at
+                            })
+                            && ending_at
+                                .map_or(true, |ending_at| payment_intent.created_at <= ending_at)
+                            && matches_any(&params.currency, &payment_intent.currency)
+                            && params
+                                .status
+                                .as_ref()
+                                .map_or(true, |status| status.contains(&payment_intent.status))
+                    })
+                    .cloned()
+                    .collect())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+fn paginate<T>(
+    items: Vec<T>,
+    constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+) -> Vec<T> {
+    use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+
+    match constraints {
+        PaymentIntentFetchConstraints::Single { .. } => items,
+        PaymentIntentFetchConstraints::List(params) => {
+            let offset = usize::try_from(params.offset).unwrap_or(usize::MAX);
+            let limit = params
+                .limit
+                .and_then(|limit| usize::try_from(limit).ok())
+                .unwrap_or(usize::MAX);
+            items.into_iter().skip(offset).take(limit).collect()
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+fn matches_any<T: PartialEq>(filter: &Option<Vec<T>>, value: &Option<T>) -> bool {
+    filter.as_ref().map_or(true, |filter| {
+        value.as_ref().map_or(false, |value| filter.contains(value))
+    })
+}
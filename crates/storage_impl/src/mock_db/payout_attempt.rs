@@ -1,3 +1,6 @@
+use std::{collections::HashSet, str::FromStr};
+
+use api_models::enums::PayoutConnectors;
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
 use hyperswitch_domain_models::{
@@ -11,59 +14,171 @@ use hyperswitch_domain_models::{
 };
 
 use super::MockDb;
+use crate::DataModelExt;
 
 #[async_trait::async_trait]
 impl PayoutAttemptInterface for MockDb {
     async fn update_payout_attempt(
         &self,
-        _this: &PayoutAttempt,
-        _payout_attempt_update: PayoutAttemptUpdate,
+        this: &PayoutAttempt,
+        payout_attempt_update: PayoutAttemptUpdate,
         _payouts: &Payouts,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payout_attempts = self.payout_attempt.lock().await;
+        let payout_attempt = payout_attempts
+            .iter_mut()
+            .find(|payout_attempt| payout_attempt.payout_attempt_id == this.payout_attempt_id)
+            .ok_or(StorageError::ValueNotFound(format!(
+                "cannot find payout attempt with payout_attempt_id = {}",
+                this.payout_attempt_id
+            )))?;
+        *payout_attempt = payout_attempt_update
+            .to_storage_model()
+            .apply_changeset(payout_attempt.clone());
+
+        Ok(PayoutAttempt::from_storage_model(payout_attempt.clone()))
     }
 
     async fn insert_payout_attempt(
         &self,
-        _payout_attempt: PayoutAttemptNew,
+        payout_attempt: PayoutAttemptNew,
         _payouts: &Payouts,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payout_attempts = self.payout_attempt.lock().await;
+        if payout_attempts
+            .iter()
+            .any(|stored| stored.payout_attempt_id == payout_attempt.payout_attempt_id)
+        {
+            Err(StorageError::DuplicateValue {
+                entity: "payout_attempt_id",
+                key: Some(payout_attempt.payout_attempt_id.clone()),
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let new = payout_attempt.to_storage_model();
+        let payout_attempt = diesel_models::PayoutAttempt {
+            payout_attempt_id: new.payout_attempt_id,
+            payout_id: new.payout_id,
+            customer_id: new.customer_id,
+            merchant_id: new.merchant_id,
+            address_id: new.address_id,
+            connector: new.connector,
+            connector_payout_id: new.connector_payout_id,
+            payout_token: new.payout_token,
+            status: new.status,
+            is_eligible: new.is_eligible,
+            error_message: new.error_message,
+            error_code: new.error_code,
+            business_country: new.business_country,
+            business_label: new.business_label,
+            created_at: new.created_at.unwrap_or(now),
+            last_modified_at: new.last_modified_at.unwrap_or(now),
+            profile_id: new.profile_id,
+            merchant_connector_id: new.merchant_connector_id,
+            routing_info: new.routing_info,
+        };
+        payout_attempts.push(payout_attempt.clone());
+
+        Ok(PayoutAttempt::from_storage_model(payout_attempt))
     }
 
     async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payout_attempt_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_attempt_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_mock_payout_attempt(|payout_attempt| {
+            payout_attempt.merchant_id == *merchant_id
+                && payout_attempt.payout_attempt_id == payout_attempt_id
+        })
+        .await
     }
 
     async fn find_payout_attempt_by_merchant_id_connector_payout_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _connector_payout_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector_payout_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PayoutAttempt, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_mock_payout_attempt(|payout_attempt| {
+            payout_attempt.merchant_id == *merchant_id
+                && payout_attempt.connector_payout_id.as_deref() == Some(connector_payout_id)
+        })
+        .await
     }
 
     async fn get_filters_for_payouts(
         &self,
-        _payouts: &[Payouts],
-        _merchant_id: &common_utils::id_type::MerchantId,
+        payouts: &[Payouts],
+        merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         hyperswitch_domain_models::payouts::payout_attempt::PayoutListFilters,
         StorageError,
     > {
-        Err(StorageError::MockDbError)?
+        let active_attempt_ids = payouts
+            .iter()
+            .map(|payout| format!("{}_{}", payout.payout_id, payout.attempt_count))
+            .collect::<HashSet<_>>();
+        let connector = self
+            .payout_attempt
+            .lock()
+            .await
+            .iter()
+            .filter(|payout_attempt| {
+                payout_attempt.merchant_id == *merchant_id
+                    && active_attempt_ids.contains(&payout_attempt.payout_attempt_id)
+            })
+            .filter_map(|payout_attempt| payout_attempt.connector.clone())
+            .collect::<HashSet<_>>()
+            .iter()
+            .filter_map(|connector| PayoutConnectors::from_str(connector).ok())
+            .collect();
+        let merchant_payouts = payouts
+            .iter()
+            .filter(|payout| payout.merchant_id == *merchant_id);
+
+        Ok(
+            hyperswitch_domain_models::payouts::payout_attempt::PayoutListFilters {
+                connector,
+                currency: merchant_payouts
+                    .clone()
+                    .map(|payout| payout.destination_currency)
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect(),
+                status: payouts
+                    .iter()
+                    .map(|payout| payout.status)
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect(),
+                payout_method: merchant_payouts
+                    .filter_map(|payout| payout.payout_type)
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect(),
+            },
+        )
+    }
+}
+
+impl MockDb {
+    async fn find_mock_payout_attempt(
+        &self,
+        predicate: impl Fn(&diesel_models::PayoutAttempt) -> bool,
+    ) -> CustomResult<PayoutAttempt, StorageError> {
+        self.payout_attempt
+            .lock()
+            .await
+            .iter()
+            .find(|payout_attempt| predicate(payout_attempt))
+            .cloned()
+            .map(PayoutAttempt::from_storage_model)
+            .ok_or(StorageError::ValueNotFound("cannot find payout attempt".to_string()).into())
     }
 }
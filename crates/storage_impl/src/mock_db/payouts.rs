@@ -9,104 +9,366 @@ use hyperswitch_domain_models::{
 };
 
 use super::MockDb;
+use crate::DataModelExt;
 
 #[async_trait::async_trait]
 impl PayoutsInterface for MockDb {
     async fn find_payout_by_merchant_id_payout_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payout_id: &str,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_optional_payout_by_merchant_id_payout_id(merchant_id, payout_id, storage_scheme)
+            .await?
+            .ok_or(
+                StorageError::ValueNotFound(format!(
+                    "cannot find payout with payout_id = {payout_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn update_payout(
         &self,
-        _this: &Payouts,
-        _payout_update: PayoutsUpdate,
+        this: &Payouts,
+        payout_update: PayoutsUpdate,
         _payout_attempt: &PayoutAttempt,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payouts = self.payouts.lock().await;
+        let payout = payouts
+            .iter_mut()
+            .find(|payout| payout.payout_id == this.payout_id)
+            .ok_or(StorageError::ValueNotFound(format!(
+                "cannot find payout with payout_id = {}",
+                this.payout_id
+            )))?;
+        *payout = payout_update
+            .to_storage_model()
+            .apply_changeset(payout.clone());
+
+        Ok(Payouts::from_storage_model(payout.clone()))
     }
 
     async fn insert_payout(
         &self,
-        _payout: PayoutsNew,
+        payout: PayoutsNew,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Payouts, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let mut payouts = self.payouts.lock().await;
+        if payouts
+            .iter()
+            .any(|stored| stored.payout_id == payout.payout_id)
+        {
+            Err(StorageError::DuplicateValue {
+                entity: "payout_id",
+                key: Some(payout.payout_id.clone()),
+            })?
+        }
+
+        let new = payout.to_storage_model();
+        let payout = diesel_models::Payouts {
+            payout_id: new.payout_id,
+            merchant_id: new.merchant_id,
+            customer_id: new.customer_id,
+            address_id: new.address_id,
+            payout_type: new.payout_type,
+            payout_method_id: new.payout_method_id,
+            amount: new.amount,
+            destination_currency: new.destination_currency,
+            source_currency: new.source_currency,
+            description: new.description,
+            recurring: new.recurring,
+            auto_fulfill: new.auto_fulfill,
+            return_url: new.return_url,
+            entity_type: new.entity_type,
+            metadata: new.metadata,
+            created_at: new.created_at,
+            last_modified_at: new.last_modified_at,
+            attempt_count: new.attempt_count,
+            profile_id: new.profile_id,
+            status: new.status,
+            confirm: new.confirm,
+            payout_link_id: new.payout_link_id,
+            client_secret: new.client_secret,
+            priority: new.priority,
+        };
+        payouts.push(payout.clone());
+
+        Ok(Payouts::from_storage_model(payout))
     }
 
     async fn find_optional_payout_by_merchant_id_payout_id(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _payout_id: &str,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Option<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        Ok(self
+            .payouts
+            .lock()
+            .await
+            .iter()
+            .find(|payout| payout.merchant_id == *merchant_id && payout.payout_id == payout_id)
+            .cloned()
+            .map(Payouts::from_storage_model))
     }
 
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payouts = self.filter_mock_payouts(merchant_id, filters).await?;
+
+        Ok(paginate(payouts, filters)
+            .into_iter()
+            .map(Payouts::from_storage_model)
+            .collect())
     }
 
     #[cfg(feature = "olap")]
     async fn filter_payouts_and_attempts(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<(Payouts, PayoutAttempt, Option<diesel_models::Customer>)>, StorageError>
     {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let results = self
+            .join_mock_payouts_and_attempts(merchant_id, filters)
+            .await?;
+        let customers = self.customers.lock().await;
+
+        Ok(paginate(results, filters)
+            .into_iter()
+            .map(|(payout, payout_attempt)| {
+                let customer = payout.customer_id.as_ref().and_then(|customer_id| {
+                    customers
+                        .iter()
+                        .find(|customer| {
+                            customer.get_customer_id() == *customer_id
+                                && customer.merchant_id == payout.merchant_id
+                        })
+                        .cloned()
+                });
+                (
+                    Payouts::from_storage_model(payout),
+                    PayoutAttempt::from_storage_model(payout_attempt),
+                    customer,
+                )
+            })
+            .collect())
     }
 
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_time_range_constraints(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _time_range: &api_models::payments::TimeRange,
-        _storage_scheme: storage_enums::MerchantStorageScheme,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &api_models::payments::TimeRange,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<Payouts>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payout_filters = (*time_range).into();
+        self.filter_payouts_by_constraints(merchant_id, &payout_filters, storage_scheme)
+            .await
     }
 
     #[cfg(feature = "olap")]
     async fn get_total_count_of_filtered_payouts(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _active_payout_ids: &[String],
-        _connector: Option<Vec<api_models::enums::PayoutConnectors>>,
-        _currency: Option<Vec<storage_enums::Currency>>,
-        _status: Option<Vec<storage_enums::PayoutStatus>>,
-        _payout_method: Option<Vec<storage_enums::PayoutType>>,
+        merchant_id: &common_utils::id_type::MerchantId,
+        active_payout_ids: &[String],
+        connector: Option<Vec<api_models::enums::PayoutConnectors>>,
+        currency: Option<Vec<storage_enums::Currency>>,
+        status: Option<Vec<storage_enums::PayoutStatus>>,
+        payout_method: Option<Vec<storage_enums::PayoutType>>,
     ) -> CustomResult<i64, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        use error_stack::ResultExt;
+
+        let connector = connector.map(|connectors| {
+            connectors
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+        });
+        let payouts = self.payouts.lock().await;
+        let payout_attempts = self.payout_attempt.lock().await;
+        let count = payouts
+            .iter()
+            .filter(|payout| {
+                payout.merchant_id == *merchant_id
+                    && active_payout_ids.contains(&payout.payout_id)
+                    && currency.as_ref().map_or(true, |currency| {
+                        currency.contains(&payout.destination_currency)
+                    })
+                    && status
+                        .as_ref()
+                        .map_or(true, |status| status.contains(&payout.status))
+                    && matches_any(&payout_method, &payout.payout_type)
+            })
+            .flat_map(|payout| {
+                payout_attempts
+                    .iter()
+                    .filter(move |payout_attempt| payout_attempt.payout_id == payout.payout_id)
+            })
+            .filter(|payout_attempt| matches_any(&connector, &payout_attempt.connector))
+            .count();
+
+        i64::try_from(count).change_context(StorageError::MockDbError)
     }
 
     #[cfg(feature = "olap")]
     async fn filter_active_payout_ids_by_constraints(
         &self,
-        _merchant_id: &common_utils::id_type::MerchantId,
-        _constraints: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
     ) -> CustomResult<Vec<String>, StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        Ok(self
+            .join_mock_payouts_and_attempts(merchant_id, constraints)
+            .await?
+            .into_iter()
+            .map(|(payout, _)| payout.payout_id)
+            .collect())
     }
 }
+
+#[cfg(feature = "olap")]
+impl MockDb {
+    /// Applies the payout level constraints and orders the result by creation time, newest first.
+    async fn filter_mock_payouts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+    ) -> CustomResult<Vec<diesel_models::Payouts>, StorageError> {
+        use hyperswitch_domain_models::payouts::PayoutFetchConstraints;
+
+        let payouts = self.payouts.lock().await;
+        let created_at_of = |payout_id: &String| {
+            payouts
+                .iter()
+                .find(|payout| payout.payout_id == *payout_id && payout.merchant_id == *merchant_id)
+                .map(|payout| payout.created_at)
+                .ok_or(StorageError::ValueNotFound(format!(
+                    "cannot find payout with payout_id = {payout_id}"
+                )))
+        };
+
+        let mut filtered = match constraints {
+            PayoutFetchConstraints::Single { payout_id } => payouts
+                .iter()
+                .filter(|payout| {
+                    payout.merchant_id == *merchant_id && payout.payout_id == *payout_id
+                })
+                .cloned()
+                .collect::<Vec<_>>(),
+            PayoutFetchConstraints::List(params) => {
+                let starting_at = match (params.starting_at, &params.starting_after_id) {
+                    (Some(starting_at), _) => Some(starting_at),
+                    (None, Some(starting_after_id)) => Some(created_at_of(starting_after_id)?),
+                    (None, None) => None,
+                };
+                let ending_at = match (params.ending_at, &params.ending_before_id) {
+                    (Some(ending_at), _) => Some(ending_at),
+                    (None, Some(ending_before_id)) => Some(created_at_of(ending_before_id)?),
+                    (None, None) => None,
+                };
+
+                payouts
+                    .iter()
+                    .filter(|payout| {
+                        payout.merchant_id == *merchant_id
+                            && params.customer_id.as_ref().map_or(true, |customer_id| {
+                                payout.customer_id.as_ref() == Some(customer_id)
+                            })
+                            && params
+                                .profile_id
+                                .as_ref()
+                                .map_or(true, |profile_id| payout.profile_id == *profile_id)
+                            && starting_at
+                                .map_or(true, |starting_at| payout.created_at >= starting_at)
+                            && ending_at.map_or(true, |ending_at| payout.created_at <= ending_at)
+                            && params.currency.as_ref().map_or(true, |currency| {
+                                currency.contains(&payout.destination_currency)
+                            })
+                            && params
+                                .status
+                                .as_ref()
+                                .map_or(true, |status| status.contains(&payout.status))
+                    })
+                    .cloned()
+                    .collect()
+            }
+        };
+        filtered.sort_by_key(|payout| std::cmp::Reverse(payout.created_at));
+
+        Ok(filtered)
+    }
+
+    /// Joins the filtered payouts with their attempts and applies the attempt level constraints.
+    async fn join_mock_payouts_and_attempts(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+    ) -> CustomResult<Vec<(diesel_models::Payouts, diesel_models::PayoutAttempt)>, StorageError>
+    {
+        use hyperswitch_domain_models::payouts::PayoutFetchConstraints;
+
+        let payouts = self.filter_mock_payouts(merchant_id, constraints).await?;
+        let payout_attempts = self.payout_attempt.lock().await;
+        let (connectors, payout_method) = match constraints {
+            PayoutFetchConstraints::Single { .. } => (None, None),
+            PayoutFetchConstraints::List(params) => (
+                params
+                    .connector
+                    .as_ref()
+                    .map(|c| c.iter().map(|c| c.to_string()).collect::<Vec<String>>()),
+                params.payout_method.clone(),
+            ),
+        };
+
+        Ok(payouts
+            .into_iter()
+            .filter(|payout| matches_any(&payout_method, &payout.payout_type))
+            .flat_map(|payout| {
+                payout_attempts
+                    .iter()
+                    .filter(|payout_attempt| {
+                        payout_attempt.payout_id == payout.payout_id
+                            && matches_any(&connectors, &payout_attempt.connector)
+                    })
+                    .map(|payout_attempt| (payout.clone(), payout_attempt.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect())
+    }
+}
+
+#[cfg(feature = "olap")]
+fn paginate<T>(
+    items: Vec<T>,
+    constraints: &hyperswitch_domain_models::payouts::PayoutFetchConstraints,
+) -> Vec<T> {
+    use hyperswitch_domain_models::payouts::PayoutFetchConstraints;
+
+    match constraints {
+        PayoutFetchConstraints::Single { .. } => items,
+        PayoutFetchConstraints::List(params) => {
+            let offset = usize::try_from(params.offset).unwrap_or(usize::MAX);
+            let limit = params
+                .limit
+                .and_then(|limit| usize::try_from(limit).ok())
+                .unwrap_or(usize::MAX);
+            items.into_iter().skip(offset).take(limit).collect()
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+fn matches_any<T: PartialEq>(filter: &Option<Vec<T>>, value: &Option<T>) -> bool {
+    filter.as_ref().map_or(true, |filter| {
+        value.as_ref().map_or(false, |value| filter.contains(value))
+    })
+}
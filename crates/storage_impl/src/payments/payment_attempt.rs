@@ -419,6 +419,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     client_source: payment_attempt.client_source.clone(),
                     client_version: payment_attempt.client_version.clone(),
                     customer_acceptance: payment_attempt.customer_acceptance.clone(),
+                    sca_exemption: payment_attempt.sca_exemption,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1215,6 +1216,7 @@ impl DataModelExt for PaymentAttempt {
             client_source: self.client_source,
             client_version: self.client_version,
             customer_acceptance: self.customer_acceptance,
+            sca_exemption: self.sca_exemption,
         }
     }
 
@@ -1282,6 +1284,7 @@ impl DataModelExt for PaymentAttempt {
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
             customer_acceptance: storage_model.customer_acceptance,
+            sca_exemption: storage_model.sca_exemption,
         }
     }
 }
@@ -1356,6 +1359,7 @@ impl DataModelExt for PaymentAttempt {
             client_source: self.client_source,
             client_version: self.client_version,
             customer_acceptance: self.customer_acceptance,
+            sca_exemption: self.sca_exemption,
         }
     }
 
@@ -1423,6 +1427,7 @@ impl DataModelExt for PaymentAttempt {
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
             customer_acceptance: storage_model.customer_acceptance,
+            sca_exemption: storage_model.sca_exemption,
         }
     }
 }
@@ -1497,6 +1502,7 @@ impl DataModelExt for PaymentAttemptNew {
             client_source: self.client_source,
             client_version: self.client_version,
             customer_acceptance: self.customer_acceptance,
+            sca_exemption: self.sca_exemption,
         }
     }
 
@@ -1563,6 +1569,7 @@ impl DataModelExt for PaymentAttemptNew {
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
             customer_acceptance: storage_model.customer_acceptance,
+            sca_exemption: storage_model.sca_exemption,
         }
     }
 }
@@ -1590,6 +1597,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 fingerprint_id,
                 payment_method_billing_address_id,
                 updated_by,
+                sca_exemption,
             } => DieselPaymentAttemptUpdate::Update {
                 amount: amount.get_amount_as_i64(),
                 currency,
@@ -1610,6 +1618,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 fingerprint_id,
                 payment_method_billing_address_id,
                 updated_by,
+                sca_exemption,
             },
             Self::UpdateTrackers {
                 payment_token,
@@ -1688,6 +1697,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 client_source,
                 client_version,
                 customer_acceptance,
+                sca_exemption,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: amount.get_amount_as_i64(),
                 currency,
@@ -1721,6 +1731,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 client_source,
                 client_version,
                 customer_acceptance,
+                sca_exemption,
             },
             Self::VoidUpdate {
                 status,
@@ -1949,6 +1960,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 fingerprint_id,
                 updated_by,
                 payment_method_billing_address_id,
+                sca_exemption,
             } => Self::Update {
                 amount: MinorUnit::new(amount),
                 currency,
@@ -1967,6 +1979,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 fingerprint_id,
                 payment_method_billing_address_id,
                 updated_by,
+                sca_exemption,
             },
             DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -2025,6 +2038,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 client_source,
                 client_version,
                 customer_acceptance,
+                sca_exemption,
             } => Self::ConfirmUpdate {
                 amount: MinorUnit::new(amount),
                 currency,
@@ -2056,6 +2070,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 client_source,
                 client_version,
                 customer_acceptance,
+                sca_exemption,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
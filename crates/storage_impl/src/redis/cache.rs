@@ -24,7 +24,7 @@ use crate::{
 pub const IMC_INVALIDATION_CHANNEL: &str = "hyperswitch_invalidate";
 
 /// Time to live 30 mins
-const CACHE_TTL: u64 = 30 * 60;
+pub const CACHE_TTL: u64 = 30 * 60;
 
 /// Time to idle 10 mins
 const CACHE_TTI: u64 = 10 * 60;
@@ -72,18 +72,29 @@ pub static PM_FILTERS_CGRAPH_CACHE: Lazy<Cache> = Lazy::new(|| {
     )
 });
 
+/// Card Info Cache, holding the card details resolved for a BIN
+pub static CARD_INFO_CACHE: Lazy<Cache> =
+    Lazy::new(|| Cache::new("CARD_INFO_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+
+const CARD_INFO_CACHE_KEY_PREFIX: &str = "card_info_";
+
+/// Key of the card details cached for a BIN in the card info cache
+pub fn get_card_info_cache_key(card_iin: &str) -> String {
+    format!("{CARD_INFO_CACHE_KEY_PREFIX}{card_iin}")
+}
+
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
     fn as_any(&self) -> &dyn Any;
 }
 
-#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct CacheRedact<'a> {
     pub tenant: String,
     pub kind: CacheKind<'a>,
 }
 
-#[derive(serde::Serialize, serde::Deserialize)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub enum CacheKind<'a> {
     Config(Cow<'a, str>),
     Accounts(Cow<'a, str>),
@@ -92,6 +103,10 @@ pub enum CacheKind<'a> {
     Surcharge(Cow<'a, str>),
     CGraph(Cow<'a, str>),
     PmFiltersCGraph(Cow<'a, str>),
+    /// Invalidates the card details cached for the BINs of a range, the key being the first and
+    /// the last BIN of the range joined by `-`. The entries of the BINs longer than the range whose
+    /// prefix lies in the range are invalidated as well, since their lookup falls back to it.
+    CardInfo(Cow<'a, str>),
     All(Cow<'a, str>),
 }
 
@@ -182,7 +197,8 @@ impl Cache {
         let mut cache_builder = MokaCache::builder()
             .time_to_live(std::time::Duration::from_secs(time_to_live))
             .time_to_idle(std::time::Duration::from_secs(time_to_idle))
-            .eviction_listener(eviction_listener);
+            .eviction_listener(eviction_listener)
+            .support_invalidation_closures();
 
         if let Some(capacity) = max_capacity {
             cache_builder = cache_builder.max_capacity(capacity * 1024 * 1024);
@@ -230,6 +246,28 @@ impl Cache {
         self.inner.invalidate::<String>(&key.into()).await;
     }
 
+    /// Removes all the entries of the cache, across tenants
+    pub fn remove_all(&self) {
+        self.inner.invalidate_all();
+    }
+
+    /// Removes the entries of the cache whose key, stripped of the prefix, satisfies `predicate`
+    pub fn remove_if(
+        &self,
+        prefix: &str,
+        predicate: impl Fn(&str) -> bool + Send + Sync + 'static,
+    ) -> Result<(), moka::PredicateError> {
+        let prefix = String::from(CacheKey {
+            key: String::new(),
+            prefix: prefix.to_owned(),
+        });
+        self.inner
+            .invalidate_entries_if(move |key, _| {
+                key.strip_prefix(prefix.as_str()).is_some_and(&predicate)
+            })
+            .map(|_| ())
+    }
+
     /// Performs any pending maintenance operations needed by the cache.
     async fn run_pending_tasks(&self) {
         self.inner.run_pending_tasks().await;
@@ -331,6 +369,39 @@ where
     }
 }
 
+/// Looks up the in-memory cache only, populating it from `fun` on a miss. This is meant for caches
+/// that are invalidated as a whole, which cannot be backed by per key entries in redis.
+#[instrument(skip_all)]
+pub async fn get_or_populate_in_memory_only<T, F, Fut>(
+    store: &(dyn RedisConnInterface + Send + Sync),
+    key: &str,
+    fun: F,
+    cache: &Cache,
+) -> CustomResult<T, StorageError>
+where
+    T: Cacheable + Debug + Clone,
+    F: FnOnce() -> Fut + Send,
+    Fut: futures::Future<Output = CustomResult<T, StorageError>> + Send,
+{
+    let redis = &store
+        .get_redis_conn()
+        .change_context(StorageError::RedisError(
+            RedisError::RedisConnectionError.into(),
+        ))
+        .attach_printable("Failed to get redis connection")?;
+    let cache_key = CacheKey {
+        key: key.to_string(),
+        prefix: redis.key_prefix.clone(),
+    };
+    if let Some(val) = cache.get_val::<T>(cache_key.clone()).await {
+        Ok(val)
+    } else {
+        let val = fun().await?;
+        cache.push(cache_key, val.clone()).await;
+        Ok(val)
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn redact_cache<T, F, Fut>(
     store: &(dyn RedisConnInterface + Send + Sync),
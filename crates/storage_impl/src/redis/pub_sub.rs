@@ -5,8 +5,9 @@ use redis_interface::{errors as redis_errors, PubsubInterface, RedisValue};
 use router_env::{logger, tracing::Instrument};
 
 use crate::redis::cache::{
-    CacheKey, CacheKind, CacheRedact, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE,
-    DECISION_MANAGER_CACHE, PM_FILTERS_CGRAPH_CACHE, ROUTING_CACHE, SURCHARGE_CACHE,
+    get_card_info_cache_key, CacheKey, CacheKind, CacheRedact, ACCOUNTS_CACHE, CARD_INFO_CACHE,
+    CGRAPH_CACHE, CONFIG_CACHE, DECISION_MANAGER_CACHE, PM_FILTERS_CGRAPH_CACHE, ROUTING_CACHE,
+    SURCHARGE_CACHE,
 };
 
 #[async_trait::async_trait]
@@ -71,6 +72,13 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
             tenant: self.key_prefix.clone(),
         };
 
+        // The in-memory redis never delivers published messages to subscribers, so the caches of
+        // this process are invalidated right away instead.
+        #[cfg(feature = "in_memory")]
+        if channel == super::cache::IMC_INVALIDATION_CHANNEL {
+            invalidate(self, key.clone()).await;
+        }
+
         self.publisher
             .publish(
                 channel,
@@ -100,125 +108,11 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                         }
                     };
 
-                    let key = match message.kind {
-                        CacheKind::Config(key) => {
-                            CONFIG_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::Accounts(key) => {
-                            ACCOUNTS_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::CGraph(key) => {
-                            CGRAPH_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::PmFiltersCGraph(key) => {
-                            PM_FILTERS_CGRAPH_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::Routing(key) => {
-                            ROUTING_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::DecisionManager(key) => {
-                            DECISION_MANAGER_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::Surcharge(key) => {
-                            SURCHARGE_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            key
-                        }
-                        CacheKind::All(key) => {
-                            CONFIG_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            ACCOUNTS_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            CGRAPH_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            PM_FILTERS_CGRAPH_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            ROUTING_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            DECISION_MANAGER_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-                            SURCHARGE_CACHE
-                                .remove(CacheKey {
-                                    key: key.to_string(),
-                                    prefix: message.tenant.clone(),
-                                })
-                                .await;
-
-                            key
-                        }
-                    };
-
-                    self.delete_key(key.as_ref())
-                        .await
-                        .map_err(|err| logger::error!("Error while deleting redis key: {err:?}"))
-                        .ok();
+                    let key_prefix = message.tenant.clone();
+                    let key = invalidate(self, message).await;
 
                     logger::debug!(
-                        key_prefix=?message.tenant.clone(),
+                        key_prefix=?key_prefix,
                         channel_name=?channel_name,
                         "Done invalidating {key}"
                     );
@@ -231,3 +125,155 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
         Ok(())
     }
 }
+
+/// Removes the key described by `message` from the in-memory caches and from redis, returning the
+/// key that was invalidated.
+async fn invalidate<'a>(
+    redis: &std::sync::Arc<redis_interface::RedisConnectionPool>,
+    message: CacheRedact<'a>,
+) -> std::borrow::Cow<'a, str> {
+    let key = match message.kind {
+        CacheKind::Config(key) => {
+            CONFIG_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::Accounts(key) => {
+            ACCOUNTS_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::CGraph(key) => {
+            CGRAPH_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::PmFiltersCGraph(key) => {
+            PM_FILTERS_CGRAPH_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::Routing(key) => {
+            ROUTING_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::DecisionManager(key) => {
+            DECISION_MANAGER_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::Surcharge(key) => {
+            SURCHARGE_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            key
+        }
+        CacheKind::CardInfo(key) => {
+            match key.split_once('-') {
+                Some((bin_start, bin_end)) => {
+                    let (bin_start, bin_end) = (bin_start.to_owned(), bin_end.to_owned());
+                    let key_prefix = get_card_info_cache_key("");
+                    CARD_INFO_CACHE
+                        .remove_if(&message.tenant, move |cache_key| {
+                            cache_key
+                                .strip_prefix(key_prefix.as_str())
+                                .and_then(|card_iin| card_iin.get(..bin_start.len()))
+                                .is_some_and(|card_bin| {
+                                    bin_start.as_str() <= card_bin && card_bin <= bin_end.as_str()
+                                })
+                        })
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to invalidate card info cache")
+                        })
+                        .ok();
+                }
+                None => logger::error!(%key, "Invalid card bin range to invalidate"),
+            }
+            // Card info is only cached in memory, there is no redis key to delete
+            return key;
+        }
+        CacheKind::All(key) => {
+            CONFIG_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            ACCOUNTS_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            CGRAPH_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            PM_FILTERS_CGRAPH_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            ROUTING_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            DECISION_MANAGER_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            SURCHARGE_CACHE
+                .remove(CacheKey {
+                    key: key.to_string(),
+                    prefix: message.tenant.clone(),
+                })
+                .await;
+            CARD_INFO_CACHE.remove_all();
+
+            key
+        }
+    };
+
+    redis
+        .delete_key(key.as_ref())
+        .await
+        .map_err(|err| logger::error!("Error while deleting redis key: {err:?}"))
+        .ok();
+
+    key
+}
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS scim_users_org_id_index;
+DROP TABLE IF EXISTS scim_users;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS scim_users (
+    merchant_id VARCHAR(64) NOT NULL,
+    user_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(64) NOT NULL,
+    external_id VARCHAR(255),
+    role_id VARCHAR(64) NOT NULL,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS scim_users_org_id_index ON scim_users (org_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE roles DROP COLUMN IF EXISTS permissions;
+
+ALTER TABLE roles DROP COLUMN IF EXISTS profile_ids;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE roles ADD COLUMN IF NOT EXISTS permissions TEXT[] NOT NULL DEFAULT '{}';
+
+ALTER TABLE roles ADD COLUMN IF NOT EXISTS profile_ids TEXT[];
@@ -0,0 +1,29 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS refund_batch (
+    batch_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    file_name VARCHAR(255),
+    total_count INTEGER NOT NULL,
+    succeeded_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS refund_batch_merchant_id_index ON refund_batch (merchant_id);
+
+CREATE TABLE IF NOT EXISTS refund_batch_item (
+    batch_id VARCHAR(64) NOT NULL,
+    line_number INTEGER NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    reason VARCHAR(255),
+    status VARCHAR(32) NOT NULL,
+    refund_id VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (batch_id, line_number)
+);
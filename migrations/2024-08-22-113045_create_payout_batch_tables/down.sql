@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS payout_batch_item;
+
+DROP INDEX IF EXISTS payout_batch_merchant_id_index;
+
+DROP TABLE IF EXISTS payout_batch;
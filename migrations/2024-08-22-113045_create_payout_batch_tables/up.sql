@@ -0,0 +1,37 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_batch (
+    batch_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    total_count INTEGER NOT NULL,
+    succeeded_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    created_by VARCHAR(64) NOT NULL,
+    reviewed_by VARCHAR(64),
+    reviewed_at TIMESTAMP,
+    review_comment VARCHAR(255),
+    scheduled_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_merchant_id_index ON payout_batch (merchant_id);
+
+CREATE TABLE IF NOT EXISTS payout_batch_item (
+    batch_id VARCHAR(64) NOT NULL,
+    line_number INTEGER NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    payout_type "PayoutType",
+    payout_token VARCHAR(64),
+    description VARCHAR(255),
+    status VARCHAR(32) NOT NULL,
+    payout_id VARCHAR(64),
+    payout_status "PayoutStatus",
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (batch_id, line_number)
+);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS gateway_status_map_history_created_at_index;
+
+DROP INDEX IF EXISTS gateway_status_map_history_connector_flow_index;
+
+DROP TABLE IF EXISTS gateway_status_map_history;
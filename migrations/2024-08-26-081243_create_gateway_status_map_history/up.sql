@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS gateway_status_map_history (
+    change_id VARCHAR(64) NOT NULL,
+    sequence_number INTEGER NOT NULL,
+    source VARCHAR(32) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    flow VARCHAR(64) NOT NULL,
+    sub_flow VARCHAR(64) NOT NULL,
+    code VARCHAR(255) NOT NULL,
+    message VARCHAR(1024) NOT NULL,
+    previous_rule JSONB,
+    current_rule JSONB,
+    description VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (change_id, sequence_number)
+);
+
+CREATE INDEX IF NOT EXISTS gateway_status_map_history_connector_flow_index ON gateway_status_map_history (connector, flow);
+
+CREATE INDEX IF NOT EXISTS gateway_status_map_history_created_at_index ON gateway_status_map_history (created_at);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS card_bin_range_bin_length_bin_start_index;
+
+DROP TABLE IF EXISTS card_bin_range;
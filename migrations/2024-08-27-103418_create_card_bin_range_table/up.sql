@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS card_bin_range (
+    bin_start VARCHAR(8) NOT NULL,
+    bin_end VARCHAR(8) NOT NULL,
+    bin_length INTEGER NOT NULL,
+    card_issuer TEXT,
+    card_network TEXT,
+    card_type TEXT,
+    card_subtype TEXT,
+    card_issuing_country TEXT,
+    last_updated_provider TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (bin_start, bin_end)
+);
+
+CREATE INDEX IF NOT EXISTS card_bin_range_bin_length_bin_start_index ON card_bin_range (bin_length, bin_start);
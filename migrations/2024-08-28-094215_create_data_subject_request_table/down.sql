@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS data_subject_request_merchant_id_customer_id_index;
+
+DROP TABLE IF EXISTS data_subject_request;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS data_subject_request (
+    request_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    request_type VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    reason VARCHAR(255),
+    file_key VARCHAR(255),
+    summary JSONB,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS data_subject_request_merchant_id_customer_id_index ON data_subject_request (merchant_id, customer_id);
@@ -0,0 +1,12 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS process_tracker_created_at_index;
+
+DROP INDEX IF EXISTS payment_attempt_created_at_index;
+
+DROP INDEX IF EXISTS events_created_at_index;
+
+DROP INDEX IF EXISTS data_retention_archive_table_name_created_at_index;
+
+DROP TABLE IF EXISTS data_retention_archive;
+
+DROP TABLE IF EXISTS data_retention_policy;
@@ -0,0 +1,30 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS data_retention_policy (
+    table_name VARCHAR(64) PRIMARY KEY,
+    retention_days INTEGER NOT NULL,
+    batch_size INTEGER NOT NULL,
+    is_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    last_run_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS data_retention_archive (
+    archive_id VARCHAR(64) PRIMARY KEY,
+    table_name VARCHAR(64) NOT NULL,
+    file_key VARCHAR(255) NOT NULL,
+    row_count INTEGER NOT NULL,
+    oldest_record_created_at TIMESTAMP NOT NULL,
+    newest_record_created_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    restored_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS data_retention_archive_table_name_created_at_index ON data_retention_archive (table_name, created_at DESC);
+
+-- Expired rows are looked up by their creation time
+CREATE INDEX IF NOT EXISTS events_created_at_index ON events (created_at);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_created_at_index ON payment_attempt (created_at);
+
+CREATE INDEX IF NOT EXISTS process_tracker_created_at_index ON process_tracker (created_at);
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS merchant_key_rotation_in_progress_index;
+
+DROP INDEX IF EXISTS merchant_key_rotation_merchant_id_created_at_index;
+
+DROP TABLE IF EXISTS merchant_key_rotation;
+
+ALTER TABLE merchant_key_store
+DROP COLUMN IF EXISTS previous_key,
+DROP COLUMN IF EXISTS key_version;
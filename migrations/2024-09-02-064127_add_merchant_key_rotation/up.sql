@@ -0,0 +1,24 @@
+-- Your SQL goes here
+ALTER TABLE merchant_key_store
+ADD COLUMN IF NOT EXISTS key_version INTEGER NOT NULL DEFAULT 1,
+ADD COLUMN IF NOT EXISTS previous_key BYTEA;
+
+CREATE TABLE IF NOT EXISTS merchant_key_rotation (
+    rotation_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    key_version INTEGER NOT NULL,
+    status VARCHAR(64) NOT NULL,
+    current_table VARCHAR(64),
+    last_processed_id VARCHAR(255),
+    rows_processed BIGINT NOT NULL DEFAULT 0,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS merchant_key_rotation_merchant_id_created_at_index ON merchant_key_rotation (merchant_id, created_at DESC);
+
+-- A merchant's key can only be rotated once the previous rotation has finished
+CREATE UNIQUE INDEX IF NOT EXISTS merchant_key_rotation_in_progress_index ON merchant_key_rotation (merchant_id)
+WHERE status = 'in_progress';
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS webhook_signing_key_active_index;
+
+DROP INDEX IF EXISTS webhook_signing_key_profile_id_index;
+
+DROP TABLE IF EXISTS webhook_signing_key;
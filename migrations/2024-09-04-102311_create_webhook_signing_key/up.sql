@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS webhook_signing_key (
+    key_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    algorithm VARCHAR(64) NOT NULL,
+    status VARCHAR(64) NOT NULL,
+    private_key BYTEA NOT NULL,
+    public_key BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    expires_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS webhook_signing_key_profile_id_index ON webhook_signing_key (profile_id);
+
+-- Webhooks of a profile are signed with a single key at a time
+CREATE UNIQUE INDEX IF NOT EXISTS webhook_signing_key_active_index ON webhook_signing_key (profile_id)
+WHERE status = 'active';
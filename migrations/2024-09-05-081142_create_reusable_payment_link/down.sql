@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS reusable_payment_link_payment_link_id_index;
+
+DROP TABLE IF EXISTS reusable_payment_link_payment;
+
+DROP TABLE IF EXISTS reusable_payment_link_item;
+
+DROP INDEX IF EXISTS reusable_payment_link_merchant_id_index;
+
+DROP TABLE IF EXISTS reusable_payment_link;
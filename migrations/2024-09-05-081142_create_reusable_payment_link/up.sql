@@ -0,0 +1,50 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS reusable_payment_link (
+    reusable_link_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    link_to_pay VARCHAR(255) NOT NULL,
+    status VARCHAR(64) NOT NULL,
+    currency "Currency" NOT NULL,
+    description VARCHAR(255),
+    custom_amount JSONB,
+    max_uses INTEGER,
+    times_used INTEGER NOT NULL DEFAULT 0,
+    payment_link_config JSONB,
+    expires_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS reusable_payment_link_merchant_id_index ON reusable_payment_link (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS reusable_payment_link_item (
+    reusable_link_id VARCHAR(64) NOT NULL,
+    item_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description VARCHAR(255),
+    image_url VARCHAR(255),
+    unit_amount BIGINT NOT NULL,
+    quantity INTEGER NOT NULL,
+    min_quantity INTEGER,
+    max_quantity INTEGER,
+    inventory BIGINT,
+    quantity_sold BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (reusable_link_id, item_id)
+);
+
+CREATE TABLE IF NOT EXISTS reusable_payment_link_payment (
+    payment_id VARCHAR(64) PRIMARY KEY,
+    reusable_link_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    item_quantities JSONB NOT NULL,
+    status "IntentStatus" NOT NULL,
+    released BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS reusable_payment_link_payment_link_id_index ON reusable_payment_link_payment (reusable_link_id);
@@ -0,0 +1,14 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS invoice_payment_invoice_id_index;
+
+DROP TABLE IF EXISTS invoice_payment;
+
+DROP TABLE IF EXISTS invoice_number_sequence;
+
+DROP TABLE IF EXISTS invoice_line_item;
+
+DROP INDEX IF EXISTS invoice_profile_id_invoice_number_index;
+
+DROP INDEX IF EXISTS invoice_merchant_id_index;
+
+DROP TABLE IF EXISTS invoice;
@@ -0,0 +1,86 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS invoice (
+    invoice_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    invoice_number VARCHAR(64),
+    status VARCHAR(64) NOT NULL,
+    currency "Currency" NOT NULL,
+    description VARCHAR(255),
+    discount JSONB,
+    tax_lines JSONB NOT NULL,
+    subtotal BIGINT NOT NULL,
+    discount_amount BIGINT NOT NULL,
+    tax_amount BIGINT NOT NULL,
+    total BIGINT NOT NULL,
+    amount_paid BIGINT NOT NULL DEFAULT 0,
+    due_date TIMESTAMP,
+    reminder_schedule JSONB,
+    payment_id VARCHAR(64),
+    payment_link VARCHAR(255),
+    metadata JSONB,
+    finalized_at TIMESTAMP,
+    paid_at TIMESTAMP,
+    voided_at TIMESTAMP,
+    marked_uncollectible_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS invoice_merchant_id_index ON invoice (merchant_id, created_at);
+
+CREATE UNIQUE INDEX IF NOT EXISTS invoice_profile_id_invoice_number_index ON invoice (profile_id, invoice_number);
+
+CREATE TABLE IF NOT EXISTS invoice_line_item (
+    invoice_id VARCHAR(64) NOT NULL,
+    line_item_id VARCHAR(64) NOT NULL,
+    position INTEGER NOT NULL,
+    description VARCHAR(255) NOT NULL,
+    quantity INTEGER NOT NULL,
+    unit_amount BIGINT NOT NULL,
+    amount BIGINT NOT NULL,
+    discount JSONB,
+    discount_amount BIGINT NOT NULL,
+    tax_rates JSONB NOT NULL,
+    tax_amount BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (invoice_id, line_item_id)
+);
+
+CREATE TABLE IF NOT EXISTS invoice_number_sequence (
+    profile_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    prefix VARCHAR(32) NOT NULL,
+    next_number BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS invoice_payment (
+    payment_id VARCHAR(64) PRIMARY KEY,
+    invoice_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    source VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    amount_received BIGINT,
+    currency "Currency" NOT NULL,
+    status "IntentStatus" NOT NULL,
+    reference VARCHAR(255),
+    credited BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS invoice_payment_invoice_id_index ON invoice_payment (invoice_id);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'invoices';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'invoice_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_created';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_finalized';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_partially_paid';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_paid';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_voided';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_marked_uncollectible';
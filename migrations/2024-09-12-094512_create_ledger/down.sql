@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS ledger_posting_merchant_id_currency_account_index;
+
+DROP TABLE IF EXISTS ledger_posting;
+
+DROP INDEX IF EXISTS ledger_entry_merchant_id_reference_id_index;
+
+DROP INDEX IF EXISTS ledger_entry_merchant_id_entry_type_source_id_index;
+
+DROP TABLE IF EXISTS ledger_entry;
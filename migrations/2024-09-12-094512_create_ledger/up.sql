@@ -0,0 +1,31 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS ledger_entry (
+    entry_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    currency "Currency" NOT NULL,
+    entry_type VARCHAR(64) NOT NULL,
+    source_id VARCHAR(64) NOT NULL,
+    reference_id VARCHAR(64) NOT NULL,
+    description VARCHAR(255),
+    effective_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS ledger_entry_merchant_id_entry_type_source_id_index ON ledger_entry (merchant_id, entry_type, source_id);
+
+CREATE INDEX IF NOT EXISTS ledger_entry_merchant_id_reference_id_index ON ledger_entry (merchant_id, reference_id);
+
+CREATE TABLE IF NOT EXISTS ledger_posting (
+    entry_id VARCHAR(64) NOT NULL,
+    line_number SMALLINT NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    currency "Currency" NOT NULL,
+    account VARCHAR(64) NOT NULL,
+    direction VARCHAR(16) NOT NULL,
+    amount BIGINT NOT NULL,
+    effective_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (entry_id, line_number)
+);
+
+CREATE INDEX IF NOT EXISTS ledger_posting_merchant_id_currency_account_index ON ledger_posting (merchant_id, currency, account, effective_at);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE data_subject_request DROP COLUMN IF EXISTS email_hash;
+
+DELETE FROM data_subject_request WHERE customer_id IS NULL;
+
+ALTER TABLE data_subject_request ALTER COLUMN customer_id SET NOT NULL;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE data_subject_request ALTER COLUMN customer_id DROP NOT NULL;
+
+ALTER TABLE data_subject_request ADD COLUMN IF NOT EXISTS email_hash VARCHAR(64);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE data_subject_request DROP COLUMN IF EXISTS erased_object_ids;
+
+DROP INDEX IF EXISTS data_retention_archive_merchant_id_archive_id_index;
+
+ALTER TABLE data_retention_archive DROP COLUMN IF EXISTS merchant_id;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+-- Archives written before this column was added hold the rows of several merchants and have no merchant_id
+ALTER TABLE data_retention_archive ADD COLUMN IF NOT EXISTS merchant_id VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS data_retention_archive_merchant_id_archive_id_index ON data_retention_archive (merchant_id, archive_id);
+
+-- Payments, refunds, disputes and mandates of the erased customer, their archived webhook events are redacted on restore
+ALTER TABLE data_subject_request ADD COLUMN IF NOT EXISTS erased_object_ids TEXT[];
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS merchant_key_rotation_in_progress_index;
+
+CREATE UNIQUE INDEX IF NOT EXISTS merchant_key_rotation_in_progress_index ON merchant_key_rotation (merchant_id)
+WHERE status = 'in_progress';
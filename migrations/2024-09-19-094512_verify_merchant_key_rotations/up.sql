@@ -0,0 +1,6 @@
+-- Your SQL goes here
+-- A rotation which is verifying the re-encrypted data still holds the previous key of the merchant
+DROP INDEX IF EXISTS merchant_key_rotation_in_progress_index;
+
+CREATE UNIQUE INDEX IF NOT EXISTS merchant_key_rotation_in_progress_index ON merchant_key_rotation (merchant_id)
+WHERE status IN ('in_progress', 'verifying');